@@ -1,7 +1,10 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Only compile protos when the grpc feature is enabled
     if std::env::var("CARGO_FEATURE_GRPC").is_ok() {
+        // Descriptor set served by gRPC reflection
+        let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
         tonic_prost_build::configure()
+            .file_descriptor_set_path(out_dir.join("this_grpc_descriptor.bin"))
             .build_server(true)
             .build_client(true)
             .compile_protos(&["proto/this_grpc.proto"], &["proto"])?;
@@ -52,6 +52,13 @@ async fn main() -> Result<()> {
         entity_fetchers: Arc::new(HashMap::new()),
         entity_creators: Arc::new(HashMap::new()),
         event_bus: None,
+        enrichment_fallback: Default::default(),
+        enrichment_concurrency: DEFAULT_ENRICHMENT_CONCURRENCY,
+        auth_provider: None,
+        audit_log: None,
+        allow_duplicate_links: false,
+        id_strategy: IdStrategy::default(),
+        route_prefix: String::new(),
     };
 
     // Setup some test data
@@ -5,6 +5,7 @@ use anyhow::Result;
 use serde_json::{self, Value};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
+use this::core::Query;
 use this::prelude::*;
 use uuid::Uuid;
 
@@ -106,7 +107,12 @@ impl EntityCreator for InvoiceStore {
 }
 
 /// Implement QueryableStore for InvoiceStore
+#[async_trait::async_trait]
 impl QueryableStore<Invoice> for InvoiceStore {
+    async fn query(&self, query: &Query) -> Result<Vec<Invoice>> {
+        Ok(query.apply(self.list()))
+    }
+
     fn apply_filters(&self, data: Vec<Invoice>, filter: &Value) -> Vec<Invoice> {
         let mut result = data;
 
@@ -157,16 +163,12 @@ impl QueryableStore<Invoice> for InvoiceStore {
             }
             "amount:desc" => data.sort_by(|a, b| b.amount.partial_cmp(&a.amount).unwrap()),
 
-            "created_at" | "created_at:asc" => data.sort_by(|a, b| a.created_at.cmp(&b.created_at)),
-            "created_at:desc" => data.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+            "created_at" | "created_at:asc" => data.sort_by_key(|a| a.created_at),
+            "created_at:desc" => data.sort_by_key(|a| std::cmp::Reverse(a.created_at)),
 
             _ => {}
         }
 
         data
     }
-
-    fn list_all(&self) -> Vec<Invoice> {
-        self.list()
-    }
 }
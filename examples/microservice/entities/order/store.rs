@@ -5,6 +5,7 @@ use anyhow::Result;
 use serde_json::{self, Value};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
+use this::core::Query;
 use this::prelude::*;
 use uuid::Uuid;
 
@@ -114,7 +115,12 @@ impl EntityCreator for OrderStore {
 /// Implement QueryableStore for OrderStore
 ///
 /// This allows filtering and sorting of orders with generic query parameters.
+#[async_trait::async_trait]
 impl QueryableStore<Order> for OrderStore {
+    async fn query(&self, query: &Query) -> Result<Vec<Order>> {
+        Ok(query.apply(self.list()))
+    }
+
     fn apply_filters(&self, data: Vec<Order>, filter: &Value) -> Vec<Order> {
         let mut result = data;
 
@@ -193,19 +199,15 @@ impl QueryableStore<Order> for OrderStore {
             }
             "amount:desc" => data.sort_by(|a, b| b.amount.partial_cmp(&a.amount).unwrap()),
 
-            "created_at" | "created_at:asc" => data.sort_by(|a, b| a.created_at.cmp(&b.created_at)),
-            "created_at:desc" => data.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+            "created_at" | "created_at:asc" => data.sort_by_key(|a| a.created_at),
+            "created_at:desc" => data.sort_by_key(|a| std::cmp::Reverse(a.created_at)),
 
-            "updated_at" | "updated_at:asc" => data.sort_by(|a, b| a.updated_at.cmp(&b.updated_at)),
-            "updated_at:desc" => data.sort_by(|a, b| b.updated_at.cmp(&a.updated_at)),
+            "updated_at" | "updated_at:asc" => data.sort_by_key(|a| a.updated_at),
+            "updated_at:desc" => data.sort_by_key(|a| std::cmp::Reverse(a.updated_at)),
 
             _ => {}
         }
 
         data
     }
-
-    fn list_all(&self) -> Vec<Order> {
-        self.list()
-    }
 }
@@ -0,0 +1,14 @@
+-- Create the entity_versions table for optional entity history.
+--
+-- Written by PostgresDataService::with_history(): each update stores the
+-- entity as it was before the change, in the same transaction.
+
+CREATE TABLE IF NOT EXISTS entity_versions (
+    entity_id       UUID            NOT NULL,
+    entity_type     VARCHAR(255)    NOT NULL,
+    version         BIGINT          NOT NULL,
+    snapshot        JSONB           NOT NULL,
+    changed_at      TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    actor           VARCHAR(255),
+    PRIMARY KEY (entity_type, entity_id, version)
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_entities_search;
+ALTER TABLE entities DROP COLUMN IF EXISTS search_vector;
@@ -0,0 +1,14 @@
+-- Add a full-text search vector to the entities table.
+--
+-- Read by PostgresDataService::search_text(). The column is generated from
+-- the name (weight A) and every string value of the JSONB data (weight B),
+-- so a match on the name ranks above a match on another field. The 'simple'
+-- configuration keeps the tokens unstemmed, whatever the language.
+
+ALTER TABLE entities ADD COLUMN IF NOT EXISTS search_vector TSVECTOR
+    GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', name), 'A')
+        || setweight(jsonb_to_tsvector('simple', data, '["string"]'), 'B')
+    ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_entities_search ON entities USING GIN(search_vector);
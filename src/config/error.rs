@@ -0,0 +1,86 @@
+//! Configuration errors
+
+/// Error returned when a configuration is loaded but not usable
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConfigError {
+    /// A link definition is inconsistent
+    #[error("invalid link definition '{link_type}' ({source_type} -> {target_type}): {reason}")]
+    InvalidLinkDefinition {
+        link_type: String,
+        source_type: String,
+        target_type: String,
+        reason: String,
+    },
+
+    /// A JSON Schema registered for an entity type does not compile
+    #[error("invalid JSON Schema for entity '{entity_type}': {reason}")]
+    InvalidEntitySchema { entity_type: String, reason: String },
+
+    /// A `${VAR}` reference names an unset variable and gives no default
+    #[error("environment variable '{name}' is not set and has no default")]
+    MissingEnvVar { name: String },
+
+    /// A configuration file could not be parsed
+    #[error("failed to parse config file '{file}': {reason}")]
+    ParseError { file: String, reason: String },
+
+    /// Entity types used by link definitions lack a fetcher or creator
+    #[error("missing entity registrations: {}", join(.0))]
+    MissingRegistrations(Vec<MissingRegistration>),
+}
+
+/// Handler an entity type needs for the links it takes part in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityCapability {
+    /// `EntityFetcher`, used to enrich links with their source/target
+    Fetcher,
+    /// `EntityCreator`, used to create an entity and link it in one request
+    Creator,
+}
+
+impl std::fmt::Display for EntityCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Fetcher => "EntityFetcher",
+            Self::Creator => "EntityCreator",
+        })
+    }
+}
+
+/// An entity type missing a capability required by a link definition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingRegistration {
+    pub entity_type: String,
+    pub capability: EntityCapability,
+    /// First link definition that needs it
+    pub link_type: String,
+}
+
+impl std::fmt::Display for MissingRegistration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' has no {} (needed by link '{}')",
+            self.entity_type, self.capability, self.link_type
+        )
+    }
+}
+
+fn join(missing: &[MissingRegistration]) -> String {
+    missing
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl ConfigError {
+    pub(crate) fn invalid_link(def: &crate::core::LinkDefinition, reason: &str) -> Self {
+        Self::InvalidLinkDefinition {
+            link_type: def.link_type.clone(),
+            source_type: def.source_type.clone(),
+            target_type: def.target_type.clone(),
+            reason: reason.to_string(),
+        }
+    }
+}
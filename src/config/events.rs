@@ -108,13 +108,13 @@ pub struct FlowConfig {
 ///
 /// ```yaml
 /// trigger:
-///   kind: link.created      # link.created, link.deleted, entity.created, entity.updated, entity.deleted
+///   kind: link.created      # link.created, link.updated, link.deleted, entity.created, entity.updated, entity.deleted
 ///   link_type: follows       # optional: filter by link type
 ///   entity_type: user        # optional: filter by entity type
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TriggerConfig {
-    /// Event kind to match: "link.created", "link.deleted", "entity.created", "entity.updated", "entity.deleted"
+    /// Event kind to match: "link.created", "link.updated", "link.deleted", "entity.created", "entity.updated", "entity.deleted"
     pub kind: String,
 
     /// Optional link type filter (only for link events)
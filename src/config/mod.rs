@@ -1,17 +1,83 @@
 //! Configuration loading and management
 
+pub mod error;
 pub mod events;
 pub mod sinks;
 
 use crate::core::LinkDefinition;
+use crate::core::pluralize::Pluralizer;
+use crate::core::validation::{FieldConstraints, FieldError, ValidationError};
 use anyhow::Result;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
+use std::time::Duration;
+use uuid::Uuid;
 
+pub use error::{ConfigError, EntityCapability, MissingRegistration};
 pub use events::*;
 pub use sinks::*;
 
+/// Wrap a parser error into a [`ConfigError::ParseError`] naming `file`
+///
+/// Errors that already are [`ConfigError`]s are passed through.
+fn parse_error(file: &str, error: anyhow::Error) -> anyhow::Error {
+    if error.is::<ConfigError>() {
+        return error;
+    }
+    ConfigError::ParseError {
+        file: file.to_string(),
+        reason: error.to_string(),
+    }
+    .into()
+}
+
+/// Replace `${VAR}` and `${VAR:-default}` in every string value of `value`
+fn interpolate_env_values(value: &mut Value) -> std::result::Result<(), ConfigError> {
+    match value {
+        Value::String(s) if s.contains("${") => *s = interpolate_env(s)?,
+        Value::Array(items) => items.iter_mut().try_for_each(interpolate_env_values)?,
+        Value::Object(map) => map.values_mut().try_for_each(interpolate_env_values)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Replace `${VAR}` and `${VAR:-default}` tokens in `text`
+///
+/// The default applies when the variable is unset or empty. An unterminated
+/// `${` is kept as written.
+fn interpolate_env(text: &str) -> std::result::Result<String, ConfigError> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let token = &rest[start + 2..];
+        let Some(end) = token.find('}') else {
+            out.push_str(&rest[start..]);
+            return Ok(out);
+        };
+        let (name, default) = match token[..end].split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (&token[..end], None),
+        };
+        match (std::env::var(name), default) {
+            (Ok(v), Some(default)) if v.is_empty() => out.push_str(default),
+            (Ok(v), _) => out.push_str(&v),
+            (Err(_), Some(default)) => out.push_str(default),
+            (Err(_), None) => {
+                return Err(ConfigError::MissingEnvVar {
+                    name: name.to_string(),
+                });
+            }
+        }
+        rest = &token[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
 /// Authorization configuration for an entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EntityAuthConfig {
@@ -79,6 +145,100 @@ pub struct EntityConfig {
     /// Authorization configuration
     #[serde(default)]
     pub auth: EntityAuthConfig,
+
+    /// Who assigns the entity's `id` on creation
+    #[serde(default)]
+    pub id_policy: IdPolicy,
+
+    /// Constraints on the entity's fields (field name -> constraints)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub fields: HashMap<String, FieldConstraints>,
+
+    /// Shortest time allowed between two updates of the same entity
+    ///
+    /// Written as a duration (`500ms`, `5s`, `1m`, `1h`). Updates arriving
+    /// sooner after the stored `updated_at` are rejected with
+    /// `429 Too Many Requests`. Unset by default.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "duration_string"
+    )]
+    pub min_update_interval: Option<Duration>,
+}
+
+/// (De)serialize an optional [`Duration`] as a string such as `"5s"`
+mod duration_string {
+    use crate::events::operators::deduplicate::parse_duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) => serializer.serialize_str(&format!("{}ms", duration.as_millis())),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| parse_duration(&s).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+/// How an entity's `id` is assigned on creation
+///
+/// Written in snake_case in YAML (`id_policy: server_generated`); an unknown
+/// value fails config loading.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdPolicy {
+    /// The server always generates the id; a client-supplied `id` is dropped
+    ServerGenerated,
+    /// The client must supply the id (sync and import flows)
+    ClientRequired,
+    /// The client may supply the id; otherwise the server generates one
+    #[default]
+    ClientOptional,
+}
+
+impl IdPolicy {
+    /// Apply the policy to a create payload
+    ///
+    /// Drops the `id` under `ServerGenerated`. Otherwise a supplied `id` must
+    /// be a UUID string, and `ClientRequired` rejects payloads without one.
+    /// Non-object payloads are left for the creator to reject.
+    pub fn apply(self, payload: &mut Value) -> std::result::Result<(), ValidationError> {
+        let Some(obj) = payload.as_object_mut() else {
+            return Ok(());
+        };
+        if self == IdPolicy::ServerGenerated {
+            obj.remove("id");
+            return Ok(());
+        }
+
+        match obj.get("id") {
+            None | Some(Value::Null) if self == IdPolicy::ClientRequired => Err(
+                ValidationError::FieldErrors(vec![FieldError::new("/id", "id is required")]),
+            ),
+            None => Ok(()),
+            Some(Value::Null) => {
+                obj.remove("id");
+                Ok(())
+            }
+            Some(id) if id.as_str().is_some_and(|s| Uuid::parse_str(s).is_ok()) => Ok(()),
+            Some(_) => Err(ValidationError::FieldErrors(vec![FieldError::new(
+                "/id",
+                "id must be a UUID",
+            )])),
+        }
+    }
 }
 
 /// Validation rule for a link type
@@ -111,19 +271,62 @@ pub struct LinksConfig {
     /// Optional sink configurations (notification destinations)
     #[serde(default)]
     pub sinks: Option<Vec<SinkConfig>>,
+
+    /// Optional irregular plurals (singular -> plural), see [`LinksConfig::pluralizer`]
+    #[serde(default)]
+    pub pluralization: Option<HashMap<String, String>>,
 }
 
 impl LinksConfig {
+    /// Load configuration from a file, picking the format from its extension
+    ///
+    /// `.json` files are read as JSON, `.yaml` and `.yml` files as YAML.
+    ///
+    /// Whatever the format, string values may reference the environment as
+    /// `${VAR}` or `${VAR:-default}`; a variable that is unset without a
+    /// default fails the load with [`ConfigError::MissingEnvVar`].
+    pub fn from_file(path: &str) -> Result<Self> {
+        let extension = std::path::Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("json") => Self::from_json_file(path),
+            Some("yaml" | "yml") => Self::from_yaml_file(path),
+            _ => Err(ConfigError::ParseError {
+                file: path.to_string(),
+                reason: "unsupported extension (expected .json, .yaml or .yml)".to_string(),
+            }
+            .into()),
+        }
+    }
+
     /// Load configuration from a YAML file
     pub fn from_yaml_file(path: &str) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
-        let config: Self = serde_yaml::from_str(&content)?;
-        Ok(config)
+        Self::from_yaml_str(&content).map_err(|e| parse_error(path, e))
     }
 
     /// Load configuration from a YAML string
     pub fn from_yaml_str(yaml: &str) -> Result<Self> {
-        let config: Self = serde_yaml::from_str(yaml)?;
+        Self::from_raw(serde_yaml::from_str(yaml)?)
+    }
+
+    /// Load configuration from a JSON file
+    pub fn from_json_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_json_str(&content).map_err(|e| parse_error(path, e))
+    }
+
+    /// Load configuration from a JSON string
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        Self::from_raw(serde_json::from_str(json)?)
+    }
+
+    /// Interpolate environment variables into a parsed document, then type it
+    fn from_raw(mut raw: Value) -> Result<Self> {
+        interpolate_env_values(&mut raw)?;
+        let config: Self = serde_json::from_value(raw)?;
         Ok(config)
     }
 
@@ -141,6 +344,7 @@ impl LinksConfig {
                 validation_rules: None,
                 events: None,
                 sinks: None,
+                pluralization: None,
             };
         }
 
@@ -227,6 +431,16 @@ impl LinksConfig {
             Some(sinks_map.into_values().collect())
         };
 
+        // Merge pluralization overrides (last one wins for the same singular)
+        let mut pluralization: Option<HashMap<String, String>> = None;
+        for config in &configs {
+            if let Some(overrides) = &config.pluralization {
+                pluralization
+                    .get_or_insert_with(HashMap::new)
+                    .extend(overrides.clone());
+            }
+        }
+
         // Convert back to vectors
         let entities: Vec<EntityConfig> = entities_map.into_values().collect();
         let links: Vec<LinkDefinition> = links_map.into_values().collect();
@@ -242,6 +456,128 @@ impl LinksConfig {
             validation_rules,
             events: merged_events,
             sinks: merged_sinks,
+            pluralization,
+        }
+    }
+
+    /// A pluralizer applying the `pluralization` overrides of this config
+    ///
+    /// Used where the framework derives a plural itself (entity types missing
+    /// from `entities`); configured `plural` names are always taken as is.
+    pub fn pluralizer(&self) -> Pluralizer {
+        match &self.pluralization {
+            Some(overrides) => Pluralizer::with_overrides(overrides.clone()),
+            None => Pluralizer::new(),
+        }
+    }
+
+    /// Check the configuration for definitions that cannot work at runtime
+    ///
+    /// Called by `ServerBuilder::build_host` on the merged configuration so
+    /// that misconfigurations fail at startup instead of on the first request.
+    /// Returns the first problem found:
+    ///
+    /// - a link definition with an empty `link_type`, `source_type` or `target_type`
+    /// - a `symmetric` link whose source and target types differ
+    /// - a link between two entities of the same type whose forward and
+    ///   reverse route names are identical (both routes would collide)
+    /// - two links exposing the same route name on the same entity type
+    /// - a `metadata_schema` that does not compile, or any `metadata_schema`
+    ///   without the `json-schema` feature
+    ///
+    /// Route names only need to be unique per entity type: `users/{id}/members`
+    /// and `companies/{id}/members` may belong to different links.
+    pub fn validate(&self) -> std::result::Result<(), ConfigError> {
+        let mut routes: HashMap<(&str, &str), &LinkDefinition> = HashMap::new();
+        for def in &self.links {
+            if def.link_type.is_empty() || def.source_type.is_empty() || def.target_type.is_empty()
+            {
+                return Err(ConfigError::invalid_link(
+                    def,
+                    "link_type, source_type and target_type must not be empty",
+                ));
+            }
+            if def.symmetric && def.source_type != def.target_type {
+                return Err(ConfigError::invalid_link(
+                    def,
+                    "symmetric links require identical source and target types",
+                ));
+            }
+            if def.source_type == def.target_type
+                && def.forward_route_name == def.reverse_route_name
+            {
+                return Err(ConfigError::invalid_link(
+                    def,
+                    "forward and reverse route names must differ when source and target types are the same",
+                ));
+            }
+            if let Some(schema) = &def.metadata_schema {
+                #[cfg(feature = "json-schema")]
+                if let Err(e) = jsonschema::validator_for(schema) {
+                    return Err(ConfigError::invalid_link(
+                        def,
+                        &format!("invalid metadata_schema: {}", e),
+                    ));
+                }
+                #[cfg(not(feature = "json-schema"))]
+                {
+                    let _ = schema;
+                    return Err(ConfigError::invalid_link(
+                        def,
+                        "metadata_schema requires the json-schema feature",
+                    ));
+                }
+            }
+            for route in [
+                (def.source_type.as_str(), def.forward_route_name.as_str()),
+                (def.target_type.as_str(), def.reverse_route_name.as_str()),
+            ] {
+                if let Some(other) = routes.insert(route, def) {
+                    return Err(ConfigError::invalid_link(
+                        def,
+                        &format!(
+                            "route '{}' on '{}' is already used by link '{}'",
+                            route.1, route.0, other.link_type
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every entity type used by a link has the handlers it needs
+    ///
+    /// Both ends of a link need an `EntityFetcher` (links are enriched with
+    /// their source and target) and an `EntityCreator` (entities can be
+    /// created and linked in one request from either side). `registered`
+    /// reports whether a type has a capability. All missing registrations
+    /// are reported together, in link definition order.
+    pub fn check_registrations(
+        &self,
+        registered: impl Fn(&str, EntityCapability) -> bool,
+    ) -> std::result::Result<(), ConfigError> {
+        let mut missing: Vec<MissingRegistration> = Vec::new();
+        for def in &self.links {
+            for entity_type in [&def.source_type, &def.target_type] {
+                for capability in [EntityCapability::Fetcher, EntityCapability::Creator] {
+                    let reported = missing
+                        .iter()
+                        .any(|m| &m.entity_type == entity_type && m.capability == capability);
+                    if !reported && !registered(entity_type, capability) {
+                        missing.push(MissingRegistration {
+                            entity_type: entity_type.clone(),
+                            capability,
+                            link_type: def.link_type.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::MissingRegistrations(missing))
         }
     }
 
@@ -279,6 +615,32 @@ impl LinksConfig {
         })
     }
 
+    /// Id policy of an entity type (`ClientOptional` for unknown types)
+    pub fn id_policy(&self, entity_type: &str) -> IdPolicy {
+        self.entities
+            .iter()
+            .find(|e| e.singular == entity_type)
+            .map(|e| e.id_policy)
+            .unwrap_or_default()
+    }
+
+    /// Minimum update interval of an entity type, if it has one
+    pub fn min_update_interval(&self, entity_type: &str) -> Option<Duration> {
+        self.entities
+            .iter()
+            .find(|e| e.singular == entity_type)
+            .and_then(|e| e.min_update_interval)
+    }
+
+    /// Field constraints of an entity type (empty for unknown types)
+    pub fn field_constraints(&self, entity_type: &str) -> HashMap<String, FieldConstraints> {
+        self.entities
+            .iter()
+            .find(|e| e.singular == entity_type)
+            .map(|e| e.fields.clone())
+            .unwrap_or_default()
+    }
+
     /// Create a default configuration for testing
     pub fn default_config() -> Self {
         Self {
@@ -287,16 +649,25 @@ impl LinksConfig {
                     singular: "user".to_string(),
                     plural: "users".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: IdPolicy::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "company".to_string(),
                     plural: "companies".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: IdPolicy::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "car".to_string(),
                     plural: "cars".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: IdPolicy::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![
@@ -308,7 +679,12 @@ impl LinksConfig {
                     reverse_route_name: "users-owners".to_string(),
                     description: Some("User owns a car".to_string()),
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
                 LinkDefinition {
                     link_type: "driver".to_string(),
@@ -318,7 +694,12 @@ impl LinksConfig {
                     reverse_route_name: "users-drivers".to_string(),
                     description: Some("User drives a car".to_string()),
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
                 LinkDefinition {
                     link_type: "worker".to_string(),
@@ -328,12 +709,18 @@ impl LinksConfig {
                     reverse_route_name: "users-workers".to_string(),
                     description: Some("User works at a company".to_string()),
                     required_fields: Some(vec!["role".to_string()]),
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
             ],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         }
     }
 }
@@ -341,6 +728,7 @@ impl LinksConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::LinkCardinality;
 
     #[test]
     fn test_default_config() {
@@ -361,6 +749,372 @@ mod tests {
         assert_eq!(parsed.links.len(), config.links.len());
     }
 
+    #[test]
+    fn test_json_and_yaml_round_trip() {
+        let config = LinksConfig::default_config();
+        let expected = serde_json::to_value(&config).unwrap();
+
+        let json = serde_json::to_string(&config).unwrap();
+        let from_json = LinksConfig::from_json_str(&json).unwrap();
+        assert_eq!(serde_json::to_value(&from_json).unwrap(), expected);
+
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        let from_yaml = LinksConfig::from_yaml_str(&yaml).unwrap();
+        assert_eq!(serde_json::to_value(&from_yaml).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_from_file_detects_format_and_names_file_on_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = LinksConfig::default_config();
+
+        let json_path = dir.path().join("links.json");
+        std::fs::write(&json_path, serde_json::to_string(&config).unwrap()).unwrap();
+        let yml_path = dir.path().join("links.yml");
+        std::fs::write(&yml_path, serde_yaml::to_string(&config).unwrap()).unwrap();
+        for path in [&json_path, &yml_path] {
+            let loaded = LinksConfig::from_file(path.to_str().unwrap()).unwrap();
+            assert_eq!(loaded.links.len(), config.links.len());
+        }
+
+        // YAML content in a .json file is a JSON parse error for that file
+        let bad_path = dir.path().join("bad.json");
+        std::fs::write(&bad_path, "entities: []\nlinks: []\n").unwrap();
+        let bad_path = bad_path.to_str().unwrap();
+        let err = LinksConfig::from_file(bad_path).unwrap_err();
+        match err.downcast_ref::<ConfigError>() {
+            Some(ConfigError::ParseError { file, .. }) => assert_eq!(file, bad_path),
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+
+        assert!(LinksConfig::from_file("links.toml").is_err());
+    }
+
+    #[test]
+    fn test_env_interpolation_in_string_values() {
+        // SAFETY: the variable name is unique to this test
+        unsafe { std::env::set_var("THIS_TEST_INVOICE_ROUTE", "bills") };
+
+        let yaml = r#"
+entities:
+  - singular: order
+    plural: orders
+    auth:
+      list: "${THIS_TEST_ORDER_POLICY:-authenticated}"
+links:
+  - link_type: has_invoice
+    source_type: order
+    target_type: invoice
+    forward_route_name: "${THIS_TEST_INVOICE_ROUTE}"
+    reverse_route_name: order
+    description: "Invoices (${THIS_TEST_INVOICE_ROUTE})"
+"#;
+        let config = LinksConfig::from_yaml_str(yaml).unwrap();
+        assert_eq!(config.links[0].forward_route_name, "bills");
+        assert_eq!(
+            config.links[0].description.as_deref(),
+            Some("Invoices (bills)")
+        );
+        assert_eq!(config.entities[0].auth.list, "authenticated");
+    }
+
+    #[test]
+    fn test_env_interpolation_missing_variable_is_an_error() {
+        let yaml = r#"
+entities: []
+links:
+  - link_type: has_invoice
+    source_type: order
+    target_type: invoice
+    forward_route_name: "${THIS_TEST_UNSET_ROUTE}"
+    reverse_route_name: order
+"#;
+        let err = LinksConfig::from_yaml_str(yaml).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ConfigError>(),
+            Some(&ConfigError::MissingEnvVar {
+                name: "THIS_TEST_UNSET_ROUTE".to_string()
+            })
+        );
+
+        assert_eq!(
+            interpolate_env("${THIS_TEST_UNSET_ROUTE:-fallback}/x").unwrap(),
+            "fallback/x"
+        );
+        assert_eq!(interpolate_env("no ${ token").unwrap(), "no ${ token");
+    }
+
+    #[test]
+    fn test_validate_accepts_default_config() {
+        assert_eq!(LinksConfig::default_config().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_symmetric_link_types() {
+        let yaml = r#"
+entities:
+  - singular: user
+    plural: users
+  - singular: car
+    plural: cars
+
+links:
+  - link_type: friend
+    source_type: user
+    target_type: user
+    forward_route_name: friends
+    reverse_route_name: friend-of
+    symmetric: true
+"#;
+        let mut config = LinksConfig::from_yaml_str(yaml).unwrap();
+        assert!(config.links[0].symmetric);
+        assert_eq!(config.validate(), Ok(()));
+
+        config.links[0].target_type = "car".to_string();
+        let err = config.validate().unwrap_err();
+        assert!(matches!(
+            &err,
+            ConfigError::InvalidLinkDefinition { link_type, .. } if link_type == "friend"
+        ));
+        assert!(err.to_string().contains("user -> car"));
+    }
+
+    #[test]
+    fn test_validate_rejects_colliding_self_link_routes() {
+        let mut config = LinksConfig::default_config();
+        let mut def = config.links[0].clone();
+        def.link_type = "manages".to_string();
+        def.source_type = "user".to_string();
+        def.target_type = "user".to_string();
+        def.forward_route_name = "team".to_string();
+        def.reverse_route_name = "team".to_string();
+        config.links.push(def);
+
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("route names must differ"));
+    }
+
+    #[test]
+    fn test_validate_route_names_are_scoped_by_entity_type() {
+        let yaml = r#"
+entities:
+  - singular: user
+    plural: users
+  - singular: company
+    plural: companies
+  - singular: team
+    plural: teams
+
+links:
+  - link_type: employment
+    source_type: company
+    target_type: user
+    forward_route_name: members
+    reverse_route_name: employers
+  - link_type: mentoring
+    source_type: user
+    target_type: team
+    forward_route_name: members
+    reverse_route_name: mentors
+"#;
+        let mut config = LinksConfig::from_yaml_str(yaml).unwrap();
+        assert_eq!(config.validate(), Ok(()));
+
+        // A second `members` route on `company` is ambiguous
+        let mut def = config.links[1].clone();
+        def.link_type = "sponsorship".to_string();
+        def.source_type = "company".to_string();
+        def.reverse_route_name = "sponsors".to_string();
+        config.links.push(def);
+        let err = config.validate().unwrap_err();
+        assert!(matches!(
+            &err,
+            ConfigError::InvalidLinkDefinition { link_type, .. } if link_type == "sponsorship"
+        ));
+        assert!(
+            err.to_string()
+                .contains("already used by link 'employment'")
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_link_type() {
+        let mut config = LinksConfig::default_config();
+        config.links[0].link_type.clear();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_checks_metadata_schema() {
+        let mut config = LinksConfig::default_config();
+        config.links[0].metadata_schema = Some(serde_json::json!({"type": "not-a-type"}));
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("metadata_schema"));
+
+        config.links[0].metadata_schema = Some(serde_json::json!({"type": "object"}));
+        assert_eq!(config.validate().is_ok(), cfg!(feature = "json-schema"));
+    }
+
+    #[test]
+    fn test_check_registrations_lists_each_missing_capability() {
+        let config = LinksConfig::default_config();
+        assert_eq!(config.check_registrations(|_, _| true), Ok(()));
+
+        // Only users and cars can be fetched, only users created
+        let err = config
+            .check_registrations(|entity_type, capability| match capability {
+                EntityCapability::Fetcher => entity_type != "company",
+                EntityCapability::Creator => entity_type == "user",
+            })
+            .unwrap_err();
+        let ConfigError::MissingRegistrations(missing) = &err else {
+            panic!("unexpected error: {err}");
+        };
+        let missing: Vec<_> = missing
+            .iter()
+            .map(|m| (m.entity_type.as_str(), m.capability, m.link_type.as_str()))
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                ("car", EntityCapability::Creator, "owner"),
+                ("company", EntityCapability::Fetcher, "worker"),
+                ("company", EntityCapability::Creator, "worker"),
+            ]
+        );
+        assert!(
+            err.to_string()
+                .contains("'company' has no EntityFetcher (needed by link 'worker')")
+        );
+    }
+
+    #[test]
+    fn test_id_policy_parsing() {
+        let yaml = r#"
+entities:
+  - singular: order
+    plural: orders
+    id_policy: server_generated
+  - singular: import
+    plural: imports
+    id_policy: client_required
+  - singular: note
+    plural: notes
+links: []
+"#;
+        let config = LinksConfig::from_yaml_str(yaml).unwrap();
+        assert_eq!(config.id_policy("order"), IdPolicy::ServerGenerated);
+        assert_eq!(config.id_policy("import"), IdPolicy::ClientRequired);
+        assert_eq!(config.id_policy("note"), IdPolicy::ClientOptional);
+
+        let bad = yaml.replace("client_required", "client_maybe");
+        assert!(LinksConfig::from_yaml_str(&bad).is_err());
+    }
+
+    #[test]
+    fn test_min_update_interval_parsing() {
+        let yaml = r#"
+entities:
+  - singular: device
+    plural: devices
+    min_update_interval: 5s
+  - singular: note
+    plural: notes
+links: []
+"#;
+        let config = LinksConfig::from_yaml_str(yaml).unwrap();
+        assert_eq!(
+            config.min_update_interval("device"),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(config.min_update_interval("note"), None);
+
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        let parsed = LinksConfig::from_yaml_str(&yaml).unwrap();
+        assert_eq!(
+            parsed.entities[0].min_update_interval,
+            Some(Duration::from_secs(5))
+        );
+
+        let bad = yaml.replace("5000ms", "soon");
+        assert!(LinksConfig::from_yaml_str(&bad).is_err());
+    }
+
+    #[test]
+    fn test_id_policy_apply() {
+        let id = Uuid::new_v4().to_string();
+
+        let mut payload = serde_json::json!({"id": id, "name": "x"});
+        IdPolicy::ServerGenerated.apply(&mut payload).unwrap();
+        assert!(payload.get("id").is_none());
+
+        let mut payload = serde_json::json!({"name": "x"});
+        let err = IdPolicy::ClientRequired.apply(&mut payload).unwrap_err();
+        assert_eq!(err.field_errors()[0].field, "/id");
+        let mut payload = serde_json::json!({"id": id});
+        assert!(IdPolicy::ClientRequired.apply(&mut payload).is_ok());
+
+        let mut payload = serde_json::json!({"name": "x"});
+        assert!(IdPolicy::ClientOptional.apply(&mut payload).is_ok());
+        let mut payload = serde_json::json!({"id": "not-a-uuid"});
+        assert!(IdPolicy::ClientOptional.apply(&mut payload).is_err());
+    }
+
+    #[test]
+    fn test_field_constraints_parsing() {
+        let yaml = r#"
+entities:
+  - singular: user
+    plural: users
+    fields:
+      name: { min_length: 1, max_length: 255 }
+      bio: { max_length: 2000 }
+  - singular: note
+    plural: notes
+links: []
+"#;
+        let config = LinksConfig::from_yaml_str(yaml).unwrap();
+        let fields = config.field_constraints("user");
+        assert_eq!(fields["name"].min_length, Some(1));
+        assert_eq!(fields["name"].max_length, Some(255));
+        assert_eq!(fields["bio"].min_length, None);
+        assert!(config.field_constraints("note").is_empty());
+        assert!(config.field_constraints("unknown").is_empty());
+    }
+
+    #[test]
+    fn test_link_cardinality_parsing() {
+        let yaml = r#"
+entities:
+  - singular: user
+    plural: users
+  - singular: car
+    plural: cars
+
+links:
+  - link_type: owner
+    source_type: user
+    target_type: car
+    forward_route_name: cars-owned
+    reverse_route_name: users-owners
+    cardinality: one_to_many
+  - link_type: driver
+    source_type: user
+    target_type: car
+    forward_route_name: cars-driven
+    reverse_route_name: users-drivers
+"#;
+        let config = LinksConfig::from_yaml_str(yaml).unwrap();
+        assert_eq!(
+            config.links[0].cardinality,
+            Some(LinkCardinality::OneToMany)
+        );
+        assert_eq!(config.links[1].cardinality, None);
+
+        let bad = yaml.replace("one_to_many", "one_to_some");
+        assert!(LinksConfig::from_yaml_str(&bad).is_err());
+    }
+
     #[test]
     fn test_link_auth_config_parsing() {
         let yaml = r#"
@@ -481,11 +1235,15 @@ links:
                 singular: "order".to_string(),
                 plural: "orders".to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: IdPolicy::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let config2 = LinksConfig {
@@ -493,11 +1251,15 @@ links:
                 singular: "invoice".to_string(),
                 plural: "invoices".to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: IdPolicy::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let merged = LinksConfig::merge(vec![config1, config2]);
@@ -516,11 +1278,15 @@ links:
                 singular: "user".to_string(),
                 plural: "users".to_string(),
                 auth: auth1,
+                id_policy: IdPolicy::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let auth2 = EntityAuthConfig {
@@ -533,11 +1299,15 @@ links:
                 singular: "user".to_string(),
                 plural: "users".to_string(),
                 auth: auth2,
+                id_policy: IdPolicy::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let merged = LinksConfig::merge(vec![config1, config2]);
@@ -564,6 +1334,7 @@ links:
             validation_rules: Some(rules1),
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut rules2 = HashMap::new();
@@ -581,6 +1352,7 @@ links:
             validation_rules: Some(rules2),
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let merged = LinksConfig::merge(vec![config1, config2]);
@@ -591,6 +1363,38 @@ links:
         assert_eq!(rules["works_at"].len(), 2);
     }
 
+    #[test]
+    fn test_pluralization_section_feeds_the_pluralizer() {
+        let base = LinksConfig::from_yaml_str(
+            r#"
+entities: []
+links: []
+pluralization:
+  datum: data
+  criterion: criteria
+"#,
+        )
+        .unwrap();
+        let app = LinksConfig::from_yaml_str(
+            r#"
+entities: []
+links: []
+pluralization:
+  criterion: criterions
+"#,
+        )
+        .unwrap();
+
+        let merged = LinksConfig::merge(vec![base, app]);
+        let pluralizer = merged.pluralizer();
+        assert_eq!(pluralizer.pluralize("datum"), "data");
+        assert_eq!(pluralizer.pluralize("criterion"), "criterions");
+        assert_eq!(pluralizer.pluralize("order"), "orders");
+
+        let plain = LinksConfig::default_config().pluralizer();
+        assert_eq!(plain.pluralize("datum"), "datums");
+    }
+
     #[test]
     fn test_find_link_definition_found() {
         let config = LinksConfig::default_config();
@@ -632,6 +1436,7 @@ links:
             validation_rules: Some(rules),
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         // Correct combination
@@ -667,6 +1472,7 @@ links:
             validation_rules: Some(rules),
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         // With empty targets, no target type can match
@@ -807,6 +1613,9 @@ sinks:
                 singular: "user".to_string(),
                 plural: "users".to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: IdPolicy::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
@@ -829,6 +1638,7 @@ sinks:
                 sink_type: SinkType::Push,
                 config: HashMap::new(),
             }]),
+            pluralization: None,
         };
 
         let config2 = LinksConfig {
@@ -857,6 +1667,7 @@ sinks:
                 sink_type: SinkType::InApp,
                 config: HashMap::new(),
             }]),
+            pluralization: None,
         };
 
         let merged = LinksConfig::merge(vec![config1, config2]);
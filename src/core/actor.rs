@@ -0,0 +1,88 @@
+//! Who is acting in the current request, for `created_by`/`updated_by`
+//!
+//! The REST exposure runs each request inside a [`scope`] holding the
+//! caller's [`AuthContext::subject`] (`None` for anonymous callers).
+//! `DataService::create` and `update` implementations call [`stamp_create`]
+//! and [`stamp_update`], so entities record who wrote them without handlers
+//! passing the caller around. Outside a scope (background jobs, direct
+//! service calls) entities are stored as given.
+
+use crate::core::auth::AuthContext;
+use crate::core::entity::Entity;
+
+tokio::task_local! {
+    static ACTOR: Option<String>;
+}
+
+/// The subject of the enclosing [`scope`]
+///
+/// `None` outside a scope, `Some(None)` for an anonymous caller.
+pub fn current() -> Option<Option<String>> {
+    ACTOR.try_with(Clone::clone).ok()
+}
+
+/// Run `future` with `context`'s subject as the [`current`] actor
+pub async fn scope<F: std::future::Future>(context: &AuthContext, future: F) -> F::Output {
+    let subject = match context {
+        AuthContext::Anonymous => None,
+        _ => Some(context.subject()),
+    };
+    ACTOR.scope(subject, future).await
+}
+
+/// Record the current actor as creator and last updater of `entity`
+pub fn stamp_create<T: Entity>(entity: &mut T) {
+    if let Some(actor) = current() {
+        entity.set_created_by(actor.clone());
+        entity.set_updated_by(actor);
+    }
+}
+
+/// Record the current actor as last updater of `entity`
+pub fn stamp_update<T: Entity>(entity: &mut T) {
+    if let Some(actor) = current() {
+        entity.set_updated_by(actor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    crate::impl_data_entity!(Memo, "memo", ["name"], {
+        text: String,
+    });
+
+    fn user(user_id: Uuid) -> AuthContext {
+        AuthContext::User {
+            user_id,
+            tenant_id: Uuid::nil(),
+            roles: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stamps_follow_the_scoped_subject() {
+        let alice = user(Uuid::new_v4());
+        let mut memo = Memo::new("m".into(), "active".into(), "hi".into());
+
+        scope(&alice, async { stamp_create(&mut memo) }).await;
+        assert_eq!(memo.created_by.as_deref(), Some(alice.subject().as_str()));
+        assert_eq!(memo.updated_by, memo.created_by);
+
+        scope(&AuthContext::Anonymous, async { stamp_update(&mut memo) }).await;
+        assert_eq!(memo.created_by.as_deref(), Some(alice.subject().as_str()));
+        assert_eq!(memo.updated_by, None);
+    }
+
+    #[test]
+    fn test_outside_a_scope_entities_are_left_alone() {
+        let mut memo = Memo::new("m".into(), "active".into(), "hi".into());
+        memo.created_by = Some("service:import".to_string());
+
+        stamp_create(&mut memo);
+        assert_eq!(memo.created_by.as_deref(), Some("service:import"));
+        assert_eq!(current(), None);
+    }
+}
@@ -0,0 +1,145 @@
+//! Audit log of entity and link mutations
+//!
+//! When an audit log is configured (`ServerBuilder::with_audit_log`), every
+//! create, update and delete served over REST appends an [`AuditEntry`]
+//! naming who made the change, with JSON snapshots of the record before and
+//! after it. Entries are never updated or removed: the trait has no way to.
+//!
+//! Links are logged under the entity type [`LINK_ENTITY_TYPE`], keyed by the
+//! link's own ID.
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Entity type under which link mutations are logged
+pub const LINK_ENTITY_TYPE: &str = "link";
+
+/// Kind of mutation an audit entry records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+impl AuditOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditOperation::Create => "create",
+            AuditOperation::Update => "update",
+            AuditOperation::Delete => "delete",
+        }
+    }
+}
+
+impl fmt::Display for AuditOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AuditOperation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "create" => Ok(AuditOperation::Create),
+            "update" => Ok(AuditOperation::Update),
+            "delete" => Ok(AuditOperation::Delete),
+            other => Err(anyhow!("Unknown audit operation: {}", other)),
+        }
+    }
+}
+
+/// One recorded mutation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// ID of the entry itself
+    pub id: Uuid,
+
+    /// Singular entity type (e.g., "order"), or [`LINK_ENTITY_TYPE`]
+    pub entity_type: String,
+
+    /// ID of the mutated entity or link
+    pub entity_id: Uuid,
+
+    pub operation: AuditOperation,
+
+    /// Who made the change ([`AuthContext::subject`]), when known
+    ///
+    /// [`AuthContext::subject`]: crate::core::AuthContext::subject
+    pub actor: Option<String>,
+
+    /// The record before the change (`None` for creates, or when unknown)
+    pub before: Option<Value>,
+
+    /// The record after the change (`None` for deletes)
+    pub after: Option<Value>,
+
+    /// When the change was made
+    pub at: DateTime<Utc>,
+}
+
+impl AuditEntry {
+    /// A new entry stamped with a fresh ID and the current time
+    pub fn new(
+        entity_type: impl Into<String>,
+        entity_id: Uuid,
+        operation: AuditOperation,
+        actor: Option<String>,
+        before: Option<Value>,
+        after: Option<Value>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            entity_type: entity_type.into(),
+            entity_id,
+            operation,
+            actor,
+            before,
+            after,
+            at: Utc::now(),
+        }
+    }
+}
+
+/// Append-only storage for audit entries
+#[async_trait]
+pub trait AuditLogService: Send + Sync {
+    /// Append an entry
+    async fn record(&self, entry: AuditEntry) -> Result<()>;
+
+    /// List the entries of one entity or link, oldest first
+    async fn list(&self, entity_type: &str, entity_id: &Uuid) -> Result<Vec<AuditEntry>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_round_trips_through_its_name() {
+        for operation in [
+            AuditOperation::Create,
+            AuditOperation::Update,
+            AuditOperation::Delete,
+        ] {
+            assert_eq!(
+                operation.as_str().parse::<AuditOperation>().unwrap(),
+                operation
+            );
+            assert_eq!(
+                serde_json::to_value(operation).unwrap(),
+                Value::String(operation.to_string())
+            );
+        }
+        assert!("upsert".parse::<AuditOperation>().is_err());
+    }
+}
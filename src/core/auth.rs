@@ -5,12 +5,25 @@
 //! - Owner-based access
 //! - Service-to-service
 //! - Admin access
+//!
+//! # Auditing
+//!
+//! [`AuthPolicy::evaluate`] records every decision as a `tracing` event with
+//! target [`AUTH_DECISION_TARGET`], carrying `subject`, `roles`, `policy`,
+//! `route` and `allowed` fields. Denials are logged at `WARN` and grants at
+//! `DEBUG`, so `RUST_LOG=this::auth=debug` turns on the full audit trail
+//! while the default filter keeps only denials.
 
 use anyhow::Result;
 use async_trait::async_trait;
-use axum::http::Request;
+use axum::http::request::Parts;
+use futures::future::{BoxFuture, FutureExt};
+use std::fmt;
 use uuid::Uuid;
 
+/// `tracing` target of auth decision events
+pub const AUTH_DECISION_TARGET: &str = "this::auth";
+
 /// Authorization context extracted from a request
 #[derive(Debug, Clone)]
 pub enum AuthContext {
@@ -72,6 +85,25 @@ impl AuthContext {
             _ => None,
         }
     }
+
+    /// Identify who is acting, for audit logs (e.g. `user:<id>`, `service:billing`)
+    pub fn subject(&self) -> String {
+        match self {
+            AuthContext::User { user_id, .. } => format!("user:{}", user_id),
+            AuthContext::Owner { user_id, .. } => format!("owner:{}", user_id),
+            AuthContext::Service { service_name, .. } => format!("service:{}", service_name),
+            AuthContext::Admin { admin_id } => format!("admin:{}", admin_id),
+            AuthContext::Anonymous => "anonymous".to_string(),
+        }
+    }
+
+    /// Roles carried by the context (only users have roles)
+    pub fn roles(&self) -> &[String] {
+        match self {
+            AuthContext::User { roles, .. } => roles,
+            _ => &[],
+        }
+    }
 }
 
 /// Authorization policy for an operation
@@ -132,6 +164,151 @@ impl AuthPolicy {
         }
     }
 
+    /// Check the policy and record the decision for auditing
+    ///
+    /// Same outcome as [`check`](Self::check), plus a `tracing` event (see
+    /// the [module docs](self)). `route` names what is being accessed, e.g.
+    /// `"DELETE /links"`. Enforcement points should call this rather than
+    /// `check` so every decision leaves a trace.
+    pub fn evaluate(&self, context: &AuthContext, route: &str) -> bool {
+        let allowed = self.check(context);
+        self.record(context, route, allowed);
+        allowed
+    }
+
+    /// Evaluate the policy for access to one resource
+    ///
+    /// Like [`evaluate`](Self::evaluate), except that `owner` only holds when
+    /// the subject owns `resource_type`/`resource_id`: an `Owner` context for
+    /// that very resource, or a user `provider` reports as its owner.
+    pub async fn evaluate_for(
+        &self,
+        context: &AuthContext,
+        provider: &dyn AuthProvider,
+        resource_type: &str,
+        resource_id: &Uuid,
+        route: &str,
+    ) -> Result<bool> {
+        let allowed = self
+            .check_for(context, provider, resource_type, resource_id)
+            .await?;
+        self.record(context, route, allowed);
+        Ok(allowed)
+    }
+
+    /// Evaluate the policy for an entity recording its owner
+    ///
+    /// Like [`evaluate`](Self::evaluate), except that `owner` holds exactly
+    /// when the subject's user id is `owner_id` (see
+    /// [`Entity::owner_id`](crate::core::entity::Entity::owner_id)). An
+    /// entity without an owner is owned by nobody.
+    pub fn evaluate_for_owner(
+        &self,
+        context: &AuthContext,
+        owner_id: Option<Uuid>,
+        route: &str,
+    ) -> bool {
+        let allowed = self.check_owned(context, owner_id);
+        self.record(context, route, allowed);
+        allowed
+    }
+
+    fn check_owned(&self, context: &AuthContext, owner_id: Option<Uuid>) -> bool {
+        match self {
+            AuthPolicy::Owner => owner_id.is_some() && context.user_id() == owner_id,
+            AuthPolicy::And(policies) => policies.iter().all(|p| p.check_owned(context, owner_id)),
+            AuthPolicy::Or(policies) => policies.iter().any(|p| p.check_owned(context, owner_id)),
+            policy => policy.check(context),
+        }
+    }
+
+    /// Whether deciding the policy requires knowing who owns the resource
+    pub fn involves_owner(&self) -> bool {
+        match self {
+            AuthPolicy::Owner => true,
+            AuthPolicy::And(policies) | AuthPolicy::Or(policies) => {
+                policies.iter().any(AuthPolicy::involves_owner)
+            }
+            _ => false,
+        }
+    }
+
+    fn check_for<'a>(
+        &'a self,
+        context: &'a AuthContext,
+        provider: &'a dyn AuthProvider,
+        resource_type: &'a str,
+        resource_id: &'a Uuid,
+    ) -> BoxFuture<'a, Result<bool>> {
+        async move {
+            match self {
+                AuthPolicy::Owner => match context {
+                    AuthContext::Owner {
+                        resource_id: owned_id,
+                        resource_type: owned_type,
+                        ..
+                    } => Ok(owned_id == resource_id && owned_type == resource_type),
+                    AuthContext::User { user_id, .. } => {
+                        provider.is_owner(user_id, resource_id, resource_type).await
+                    }
+                    _ => Ok(false),
+                },
+                AuthPolicy::And(policies) => {
+                    for policy in policies {
+                        if !policy
+                            .check_for(context, provider, resource_type, resource_id)
+                            .await?
+                        {
+                            return Ok(false);
+                        }
+                    }
+                    Ok(true)
+                }
+                AuthPolicy::Or(policies) => {
+                    for policy in policies {
+                        if policy
+                            .check_for(context, provider, resource_type, resource_id)
+                            .await?
+                        {
+                            return Ok(true);
+                        }
+                    }
+                    Ok(false)
+                }
+                policy => Ok(policy.check(context)),
+            }
+        }
+        .boxed()
+    }
+
+    /// Record an auth decision (see the [module docs](self))
+    fn record(&self, context: &AuthContext, route: &str, allowed: bool) {
+        let subject = context.subject();
+        let roles = context.roles().join(",");
+        if allowed {
+            tracing::debug!(
+                target: AUTH_DECISION_TARGET,
+                subject = %subject,
+                roles = %roles,
+                policy = %self,
+                route,
+                allowed,
+                "auth decision: allow"
+            );
+        } else {
+            tracing::warn!(
+                target: AUTH_DECISION_TARGET,
+                subject = %subject,
+                roles = %roles,
+                tenant_id = ?context.tenant_id(),
+                policy = %self,
+                route,
+                allowed,
+                "auth decision: deny"
+            );
+        }
+    }
+
     /// Parse policy from string (for YAML config)
     pub fn parse_policy(s: &str) -> Self {
         match s {
@@ -153,11 +330,43 @@ impl AuthPolicy {
     }
 }
 
+/// Renders policies in the [`parse_policy`](AuthPolicy::parse_policy) syntax
+/// where one exists, e.g. `role:admin` or `or(owner, role:editor)`
+impl fmt::Display for AuthPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn join(f: &mut fmt::Formatter<'_>, name: &str, policies: &[AuthPolicy]) -> fmt::Result {
+            write!(f, "{}(", name)?;
+            for (i, policy) in policies.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{}", policy)?;
+            }
+            f.write_str(")")
+        }
+
+        match self {
+            AuthPolicy::Public => f.write_str("public"),
+            AuthPolicy::Authenticated => f.write_str("authenticated"),
+            AuthPolicy::Owner => f.write_str("owner"),
+            AuthPolicy::HasRole(roles) => write!(f, "role:{}", roles.join("|")),
+            AuthPolicy::ServiceOnly => f.write_str("service_only"),
+            AuthPolicy::AdminOnly => f.write_str("admin_only"),
+            AuthPolicy::And(policies) => join(f, "and", policies),
+            AuthPolicy::Or(policies) => join(f, "or", policies),
+            AuthPolicy::Custom(_) => f.write_str("custom"),
+        }
+    }
+}
+
 /// Trait for auth providers
 #[async_trait]
 pub trait AuthProvider: Send + Sync {
-    /// Extract auth context from HTTP request
-    async fn extract_context<B>(&self, req: &Request<B>) -> Result<AuthContext>;
+    /// Extract auth context from the HTTP request head (headers, extensions, ...)
+    ///
+    /// Return [`AuthContext::Anonymous`] for requests without credentials;
+    /// an error is treated as invalid credentials.
+    async fn extract_context(&self, parts: &Parts) -> Result<AuthContext>;
 
     /// Check if user is owner of a resource
     async fn is_owner(
@@ -176,7 +385,7 @@ pub struct NoAuthProvider;
 
 #[async_trait]
 impl AuthProvider for NoAuthProvider {
-    async fn extract_context<B>(&self, _req: &Request<B>) -> Result<AuthContext> {
+    async fn extract_context(&self, _parts: &Parts) -> Result<AuthContext> {
         Ok(AuthContext::Anonymous)
     }
 
@@ -389,6 +598,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_evaluate_for_owner_compares_the_recorded_owner() {
+        let user_id = Uuid::new_v4();
+        let user = AuthContext::User {
+            user_id,
+            tenant_id: Uuid::new_v4(),
+            roles: vec!["manager".to_string()],
+        };
+        let route = "PUT /orders/{id}";
+
+        assert!(AuthPolicy::Owner.evaluate_for_owner(&user, Some(user_id), route));
+        assert!(!AuthPolicy::Owner.evaluate_for_owner(&user, Some(Uuid::new_v4()), route));
+        assert!(!AuthPolicy::Owner.evaluate_for_owner(&user, None, route));
+        assert!(!AuthPolicy::Owner.evaluate_for_owner(&AuthContext::Anonymous, None, route));
+
+        let owner_or_manager = AuthPolicy::parse_policy("owner_or_role:manager");
+        assert!(owner_or_manager.involves_owner());
+        assert!(owner_or_manager.evaluate_for_owner(&user, None, route));
+        assert!(!AuthPolicy::parse_policy("role:manager").involves_owner());
+    }
+
     #[test]
     fn test_parse_policy_unknown_defaults_to_authenticated() {
         assert!(matches!(
@@ -519,11 +749,12 @@ mod tests {
     #[tokio::test]
     async fn test_no_auth_provider_extract_context() {
         let provider = NoAuthProvider;
-        let req = Request::builder()
+        let (parts, _) = axum::http::Request::builder()
             .body(())
-            .expect("failed to build request");
+            .expect("failed to build request")
+            .into_parts();
         let ctx = provider
-            .extract_context(&req)
+            .extract_context(&parts)
             .await
             .expect("extract_context should succeed");
         assert!(matches!(ctx, AuthContext::Anonymous));
@@ -548,4 +779,159 @@ mod tests {
             .expect("has_role should succeed");
         assert!(!result);
     }
+
+    // --- AuthPolicy::evaluate_for ---
+
+    /// Provider for which `owner_id` owns every resource
+    struct SingleOwnerProvider {
+        owner_id: Uuid,
+    }
+
+    #[async_trait]
+    impl AuthProvider for SingleOwnerProvider {
+        async fn extract_context(&self, _parts: &Parts) -> Result<AuthContext> {
+            Ok(AuthContext::Anonymous)
+        }
+
+        async fn is_owner(&self, user_id: &Uuid, _: &Uuid, _: &str) -> Result<bool> {
+            Ok(*user_id == self.owner_id)
+        }
+
+        async fn has_role(&self, _: &Uuid, _: &str) -> Result<bool> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_for_resolves_owner_through_provider() {
+        let owner_id = Uuid::new_v4();
+        let provider = SingleOwnerProvider { owner_id };
+        let resource_id = Uuid::new_v4();
+        let user = |user_id, roles: &[&str]| AuthContext::User {
+            user_id,
+            tenant_id: Uuid::new_v4(),
+            roles: roles.iter().map(|r| r.to_string()).collect(),
+        };
+        let evaluate = |policy: &'static str, context: AuthContext| {
+            let provider = &provider;
+            async move {
+                AuthPolicy::parse_policy(policy)
+                    .evaluate_for(&context, provider, "car", &resource_id, "test")
+                    .await
+                    .expect("evaluation should succeed")
+            }
+        };
+
+        assert!(evaluate("owner", user(owner_id, &[])).await);
+        assert!(!evaluate("owner", user(Uuid::new_v4(), &[])).await);
+        assert!(evaluate("owner_or_role:admin", user(Uuid::new_v4(), &["admin"])).await);
+
+        // Owner contexts only own their own resource
+        let owner_of = |resource_id, resource_type: &str| AuthContext::Owner {
+            user_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            resource_id,
+            resource_type: resource_type.to_string(),
+        };
+        assert!(evaluate("owner", owner_of(resource_id, "car")).await);
+        assert!(!evaluate("owner", owner_of(Uuid::new_v4(), "car")).await);
+        assert!(!evaluate("owner", owner_of(resource_id, "user")).await);
+    }
+
+    // --- AuthPolicy::evaluate ---
+
+    /// Level and `(name, value)` fields of one captured event
+    type Captured = (tracing::Level, Vec<(String, String)>);
+
+    /// Collects the auth decision events emitted while it is the default subscriber
+    #[derive(Clone, Default)]
+    struct CapturedEvents(std::sync::Arc<std::sync::Mutex<Vec<Captured>>>);
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for CapturedEvents {
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            struct Fields(Vec<(String, String)>);
+            impl tracing::field::Visit for Fields {
+                fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn fmt::Debug) {
+                    self.0
+                        .push((field.name().to_string(), format!("{:?}", value)));
+                }
+            }
+
+            if event.metadata().target() == AUTH_DECISION_TARGET {
+                let mut fields = Fields(Vec::new());
+                event.record(&mut fields);
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((*event.metadata().level(), fields.0));
+            }
+        }
+    }
+
+    fn capture(f: impl FnOnce()) -> Vec<Captured> {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let events = CapturedEvents::default();
+        let subscriber = tracing_subscriber::registry().with(events.clone());
+        tracing::subscriber::with_default(subscriber, f);
+        events.0.lock().unwrap().clone()
+    }
+
+    fn field<'a>(fields: &'a [(String, String)], name: &str) -> &'a str {
+        fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+            .unwrap_or_else(|| panic!("missing field {}", name))
+    }
+
+    #[test]
+    fn test_evaluate_logs_denial_at_warn() {
+        let user_id = Uuid::new_v4();
+        let ctx = AuthContext::User {
+            user_id,
+            tenant_id: Uuid::new_v4(),
+            roles: vec!["viewer".to_string(), "billing".to_string()],
+        };
+
+        let events = capture(|| {
+            assert!(!AuthPolicy::AdminOnly.evaluate(&ctx, "DELETE /links"));
+        });
+
+        assert_eq!(events.len(), 1);
+        let (level, fields) = &events[0];
+        assert_eq!(*level, tracing::Level::WARN);
+        assert_eq!(field(fields, "subject"), format!("user:{}", user_id));
+        assert_eq!(field(fields, "roles"), "viewer,billing");
+        assert_eq!(field(fields, "policy"), "admin_only");
+        assert_eq!(field(fields, "route"), "\"DELETE /links\"");
+        assert_eq!(field(fields, "allowed"), "false");
+    }
+
+    #[test]
+    fn test_evaluate_logs_grant_at_debug() {
+        let ctx = AuthContext::Service {
+            service_name: "billing".to_string(),
+            tenant_id: None,
+        };
+        let policy = AuthPolicy::Or(vec![
+            AuthPolicy::ServiceOnly,
+            AuthPolicy::HasRole(vec!["admin".into()]),
+        ]);
+
+        let events = capture(|| {
+            assert!(policy.evaluate(&ctx, "GET /orders"));
+        });
+
+        assert_eq!(events.len(), 1);
+        let (level, fields) = &events[0];
+        assert_eq!(*level, tracing::Level::DEBUG);
+        assert_eq!(field(fields, "subject"), "service:billing");
+        assert_eq!(field(fields, "policy"), "or(service_only, role:admin)");
+        assert_eq!(field(fields, "allowed"), "true");
+    }
 }
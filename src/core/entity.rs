@@ -69,6 +69,48 @@ pub trait Entity: Clone + Send + Sync + 'static {
         None
     }
 
+    /// Get the user who created the entity, for `owner` auth policies
+    ///
+    /// Entities declared with [`impl_data_entity!`](crate::impl_data_entity)
+    /// carry a serialized `owner_id` field, which the REST layer fills from
+    /// the caller's [`AuthContext`](crate::core::AuthContext) on create.
+    /// Returns None by default.
+    fn owner_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    /// Get the subject that created the entity (see [`AuthContext::subject`](crate::core::AuthContext::subject))
+    ///
+    /// Entities declared with [`impl_data_entity!`](crate::impl_data_entity)
+    /// carry serialized `created_by` and `updated_by` fields, which storage
+    /// backends stamp from the request's actor (see [`crate::core::actor`]).
+    /// Returns None by default.
+    fn created_by(&self) -> Option<&str> {
+        None
+    }
+
+    /// Get the subject that last updated the entity; None by default
+    fn updated_by(&self) -> Option<&str> {
+        None
+    }
+
+    /// Record the subject that created the entity; ignored by default
+    fn set_created_by(&mut self, _subject: Option<String>) {}
+
+    /// Record the subject that last updated the entity; ignored by default
+    fn set_updated_by(&mut self, _subject: Option<String>) {}
+
+    /// Get the version the entity was read at, for optimistic concurrency
+    ///
+    /// Types opt in by carrying a serialized `version: u64` field and
+    /// returning it here. Backends that support it (MySQL) then reject an
+    /// update whose version is no longer the stored one with
+    /// [`StorageError::IntegrityError`](crate::storage::StorageError::IntegrityError),
+    /// and bump the version on every successful update. Returns 0 by default.
+    fn version(&self) -> u64 {
+        0
+    }
+
     /// Check if the entity has been soft-deleted
     fn is_deleted(&self) -> bool {
         self.deleted_at().is_some()
@@ -80,6 +122,9 @@ pub trait Entity: Clone + Send + Sync + 'static {
     }
 }
 
+/// Computed field values by field name, see [`Data::computed_fields`]
+pub type ComputedFields = serde_json::Map<String, serde_json::Value>;
+
 /// Trait for data entities that represent concrete domain objects.
 ///
 /// Data entities extend the base Entity with:
@@ -96,6 +141,37 @@ pub trait Data: Entity {
     /// Get the value of a specific field by name
     fn field_value(&self, field: &str) -> Option<crate::core::field::FieldValue>;
 
+    /// Fields that must not be sent to a caller acting as `context`
+    ///
+    /// The exposures strip them from responses (see
+    /// [`redaction`](crate::core::redaction)). Default implementation redacts
+    /// nothing; `impl_data_entity!` can declare fields that are always redacted.
+    fn redacted_fields(_context: &crate::core::auth::AuthContext) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Fields whose values no two live entities of this type may share
+    ///
+    /// Backends reject a create or update repeating the value of another
+    /// live entity with
+    /// [`StorageError::AlreadyExists`](crate::storage::StorageError::AlreadyExists)
+    /// (409). Values are read through [`field_value`](Self::field_value);
+    /// null values never collide. Default implementation declares none;
+    /// `impl_data_entity!` can declare them.
+    fn unique_fields() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Values derived from the entity's fields, served on reads only
+    ///
+    /// Merged into the entity's JSON by REST `GET` responses and by the
+    /// [`EntityFetcher`](crate::core::EntityFetcher)s behind GraphQL and
+    /// gRPC, and never stored. Default implementation computes nothing;
+    /// `impl_data_entity!` can declare methods whose results are served.
+    fn computed_fields(&self) -> ComputedFields {
+        ComputedFields::new()
+    }
+
     /// Display the entity for debugging
     fn display(&self) {
         println!(
@@ -108,6 +184,23 @@ pub trait Data: Entity {
     }
 }
 
+/// The JSON form of `entity` served to readers, computed fields included
+pub fn to_read_json<T: Data + serde::Serialize>(
+    entity: &T,
+) -> serde_json::Result<serde_json::Value> {
+    let mut json = serde_json::to_value(entity)?;
+    if let Some(object) = json.as_object_mut() {
+        object.extend(entity.computed_fields());
+    }
+    Ok(json)
+}
+
+/// The JSON value of a computed field, `null` if it cannot be serialized
+#[doc(hidden)]
+pub fn computed_value<V: serde::Serialize>(value: &V) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
 /// Trait for link entities that represent relationships between entities.
 ///
 /// Links extend the base Entity with:
@@ -233,6 +326,7 @@ mod tests {
             status: "active".to_string(),
         };
         assert_eq!(entity.tenant_id(), None);
+        assert_eq!(entity.owner_id(), None);
     }
 
     #[test]
@@ -0,0 +1,257 @@
+//! ETag helpers and conditional fetch results
+//!
+//! Entity ETags are derived from the entity's id, `updated_at` and version
+//! (see [`etag_for`]), so any write that touches the entity produces a new
+//! tag without storing anything extra, and backends can answer a
+//! conditional fetch from those columns alone.
+//!
+//! A write made under `If-Match` runs inside an [`if_match`] scope holding
+//! the [`Precondition`] the request was checked against. Backends apply it
+//! in the same statement (or locked transaction) as the write, so a change
+//! landing between the check and the write is refused rather than
+//! overwritten.
+
+use crate::core::entity::Entity;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::cell::Cell;
+use uuid::Uuid;
+
+/// Outcome of a conditional fetch with [`DataService::get_if_modified`]
+///
+/// [`DataService::get_if_modified`]: crate::core::service::DataService::get_if_modified
+#[derive(Debug, Clone, PartialEq)]
+pub enum CacheResult<T> {
+    /// The stored entity still matches the client's ETag
+    NotModified,
+    /// The entity changed since the client's ETag (or no ETag was given)
+    Modified(T),
+    /// No entity exists with this ID
+    NotFound,
+}
+
+impl<T> CacheResult<T> {
+    /// Returns the fresh entity, if the fetch produced one
+    pub fn into_modified(self) -> Option<T> {
+        match self {
+            CacheResult::Modified(entity) => Some(entity),
+            _ => None,
+        }
+    }
+}
+
+/// Compute the weak ETag of entity `id` as of `updated_at` and `version`
+///
+/// The tag is a stable 64-bit FNV-1a hash of the three, so it is the same
+/// across builds and servers and does not reveal the modification time.
+/// Entities without a version pass 0 (the [`Entity::version`] default);
+/// two writes within the same microsecond then share a tag, which is why
+/// it is weak.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use this::core::etag::etag_for;
+/// use uuid::Uuid;
+///
+/// let id = Uuid::nil();
+/// let ts = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+/// let tag = etag_for(&id, &ts, 0);
+/// assert!(tag.starts_with("W/\""));
+/// assert_ne!(tag, etag_for(&id, &ts, 1));
+/// ```
+pub fn etag_for(id: &Uuid, updated_at: &DateTime<Utc>, version: u64) -> String {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+    let micros = updated_at.timestamp_micros().to_be_bytes();
+    let version = version.to_be_bytes();
+    let hash = id
+        .as_bytes()
+        .iter()
+        .chain(&micros)
+        .chain(&version)
+        .fold(FNV_OFFSET, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+        });
+    format!("W/\"{:016x}\"", hash)
+}
+
+/// The ETag of a typed entity (see [`etag_for`])
+pub fn etag_of<T: Entity>(entity: &T) -> String {
+    etag_for(&entity.id(), &entity.updated_at(), entity.version())
+}
+
+/// The ETag of an entity serialized as JSON (see [`etag_for`])
+///
+/// `None` unless the object has an `id` and an RFC 3339 `updated_at`; a
+/// missing or non-integer `version` counts as 0.
+pub fn etag_of_json(entity: &Value) -> Option<String> {
+    let id = entity
+        .get("id")
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())?;
+    let stored = Precondition::of_json(entity)?;
+    Some(etag_for(&id, &stored.updated_at, stored.version))
+}
+
+/// The stored state an `If-Match` write was checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Precondition {
+    pub updated_at: DateTime<Utc>,
+    pub version: u64,
+}
+
+impl Precondition {
+    /// The state of an entity serialized as JSON
+    ///
+    /// `None` without an RFC 3339 `updated_at`; a missing `version` counts
+    /// as 0, as in [`etag_of_json`].
+    pub fn of_json(entity: &Value) -> Option<Self> {
+        let updated_at = entity
+            .get("updated_at")
+            .and_then(Value::as_str)
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())?
+            .with_timezone(&Utc);
+        let version = entity.get("version").and_then(Value::as_u64).unwrap_or(0);
+        Some(Self {
+            updated_at,
+            version,
+        })
+    }
+
+    /// Whether an entity stored as of `updated_at` and `version` is still
+    /// the one that was checked
+    pub fn holds(&self, updated_at: &DateTime<Utc>, version: u64) -> bool {
+        self.updated_at == *updated_at && self.version == version
+    }
+}
+
+struct Pending {
+    precondition: Precondition,
+    failed: Cell<bool>,
+}
+
+tokio::task_local! {
+    static IF_MATCH: Pending;
+}
+
+/// The precondition of the enclosing [`if_match`] scope, if any
+pub fn precondition() -> Option<Precondition> {
+    IF_MATCH.try_with(|pending| pending.precondition).ok()
+}
+
+/// Record that a write in the enclosing [`if_match`] scope was refused
+///
+/// Called by backends when the [`precondition`] no longer holds.
+pub fn reject_precondition() {
+    let _ = IF_MATCH.try_with(|pending| pending.failed.set(true));
+}
+
+/// Run `future` with writes conditioned on `precondition`
+///
+/// Also returns whether a write was refused because the precondition no
+/// longer held (see [`reject_precondition`]).
+pub async fn if_match<F: std::future::Future>(
+    precondition: Precondition,
+    future: F,
+) -> (F::Output, bool) {
+    let pending = Pending {
+        precondition,
+        failed: Cell::new(false),
+    };
+    IF_MATCH
+        .scope(pending, async {
+            let output = future.await;
+            (output, IF_MATCH.with(|pending| pending.failed.get()))
+        })
+        .await
+}
+
+/// Check whether an `If-None-Match` header value matches `etag`
+///
+/// Accepts `*`, comma-separated lists and weak (`W/`) validators, following
+/// the weak comparison rules used for `If-None-Match`.
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let etag = etag.trim_start_matches("W/");
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[test]
+    fn test_etag_changes_with_id_updated_at_and_version() {
+        let id = Uuid::new_v4();
+        let at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let later = Utc.timestamp_opt(1_700_000_000, 1_000).unwrap();
+        let tag = etag_for(&id, &at, 0);
+
+        assert_eq!(tag, etag_for(&id, &at, 0));
+        assert_ne!(tag, etag_for(&id, &later, 0));
+        assert_ne!(tag, etag_for(&Uuid::new_v4(), &at, 0));
+        assert_ne!(tag, etag_for(&id, &at, 1));
+        assert!(!tag.contains("1700000000"));
+    }
+
+    #[test]
+    fn test_etag_is_stable_across_builds() {
+        let at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        assert_eq!(etag_for(&Uuid::nil(), &at, 0), "W/\"b2b95e177bb11153\"");
+    }
+
+    #[test]
+    fn test_etag_of_json_reads_id_updated_at_and_version() {
+        let id = Uuid::new_v4();
+        let at = Utc.timestamp_opt(1_700_000_000, 5_000).unwrap();
+        let entity = json!({ "id": id, "updated_at": at, "version": 3, "name": "a" });
+        assert_eq!(etag_of_json(&entity), Some(etag_for(&id, &at, 3)));
+
+        let unversioned = json!({ "id": id, "updated_at": at });
+        assert_eq!(etag_of_json(&unversioned), Some(etag_for(&id, &at, 0)));
+        assert_eq!(etag_of_json(&json!({ "id": id })), None);
+    }
+
+    #[tokio::test]
+    async fn test_if_match_scope_reports_rejected_writes() {
+        let at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let expected = Precondition {
+            updated_at: at,
+            version: 2,
+        };
+        assert!(expected.holds(&at, 2));
+        assert!(!expected.holds(&at, 3));
+
+        let (seen, failed) = if_match(expected, async { precondition() }).await;
+        assert_eq!(seen, Some(expected));
+        assert!(!failed);
+
+        let ((), failed) = if_match(expected, async { reject_precondition() }).await;
+        assert!(failed);
+        assert_eq!(precondition(), None);
+    }
+
+    #[test]
+    fn test_etag_matches_lists_weak_and_wildcard() {
+        let etag = "\"42\"";
+        assert!(etag_matches("\"42\"", etag));
+        assert!(etag_matches("W/\"42\"", etag));
+        assert!(etag_matches("\"1\", \"42\"", etag));
+        assert!(etag_matches("*", etag));
+        assert!(!etag_matches("\"43\"", etag));
+    }
+
+    #[test]
+    fn test_cache_result_into_modified() {
+        assert_eq!(CacheResult::Modified(1).into_modified(), Some(1));
+        assert_eq!(CacheResult::<i32>::NotModified.into_modified(), None);
+        assert_eq!(CacheResult::<i32>::NotFound.into_modified(), None);
+    }
+}
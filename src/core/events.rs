@@ -32,11 +32,13 @@
 //! }
 //! ```
 
+use crate::core::redaction::redact_entity;
 use crate::events::log::EventLog;
 use crate::events::types::SeqNo;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, PoisonError};
 use tokio::sync::broadcast;
 use uuid::Uuid;
 
@@ -63,7 +65,7 @@ pub enum EntityEvent {
     },
 }
 
-/// Events related to link mutations (create, delete)
+/// Events related to link mutations (create, update, delete)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "action", rename_all = "snake_case")]
 pub enum LinkEvent {
@@ -75,6 +77,14 @@ pub enum LinkEvent {
         target_id: Uuid,
         metadata: Option<serde_json::Value>,
     },
+    /// A link's metadata was updated
+    Updated {
+        link_type: String,
+        link_id: Uuid,
+        source_id: Uuid,
+        target_id: Uuid,
+        metadata: Option<serde_json::Value>,
+    },
     /// A link was deleted
     Deleted {
         link_type: String,
@@ -125,13 +135,25 @@ impl FrameworkEvent {
                 | EntityEvent::Deleted { entity_id, .. } => Some(*entity_id),
             },
             FrameworkEvent::Link(l) => match l {
-                LinkEvent::Created { link_id, .. } | LinkEvent::Deleted { link_id, .. } => {
-                    Some(*link_id)
-                }
+                LinkEvent::Created { link_id, .. }
+                | LinkEvent::Updated { link_id, .. }
+                | LinkEvent::Deleted { link_id, .. } => Some(*link_id),
             },
         }
     }
 
+    /// Get the link type this event relates to (link events only)
+    pub fn link_type(&self) -> Option<&str> {
+        match self {
+            FrameworkEvent::Entity(_) => None,
+            FrameworkEvent::Link(
+                LinkEvent::Created { link_type, .. }
+                | LinkEvent::Updated { link_type, .. }
+                | LinkEvent::Deleted { link_type, .. },
+            ) => Some(link_type),
+        }
+    }
+
     /// Get the action name (created, updated, deleted)
     pub fn action(&self) -> &str {
         match self {
@@ -142,6 +164,7 @@ impl FrameworkEvent {
             },
             FrameworkEvent::Link(l) => match l {
                 LinkEvent::Created { .. } => "created",
+                LinkEvent::Updated { .. } => "updated",
                 LinkEvent::Deleted { .. } => "deleted",
             },
         }
@@ -157,7 +180,8 @@ pub struct EventEnvelope {
     pub timestamp: DateTime<Utc>,
     /// The actual event
     pub event: FrameworkEvent,
-    /// Sequence number assigned by the EventLog (None if not yet persisted)
+    /// Sequence number assigned by the EventLog, or by the bus when it has a
+    /// replay buffer (None otherwise)
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub seq_no: Option<SeqNo>,
 }
@@ -174,6 +198,55 @@ impl EventEnvelope {
     }
 }
 
+/// Last published events, kept for clients catching up after a reconnect
+#[derive(Debug)]
+struct ReplayBuffer {
+    capacity: usize,
+    last_seq_no: SeqNo,
+    events: VecDeque<EventEnvelope>,
+}
+
+impl ReplayBuffer {
+    /// Number `envelope` and retain a copy, evicting the oldest event when full
+    fn record(&mut self, envelope: &mut EventEnvelope) {
+        self.last_seq_no += 1;
+        envelope.seq_no = Some(self.last_seq_no);
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(envelope.clone());
+    }
+}
+
+/// The services whose mutations record their events in the outbox
+///
+/// Built by `ServerBuilder::with_outbox` from the services'
+/// `writes_outbox()` flags.
+#[derive(Debug, Clone, Default)]
+pub struct OutboxSources {
+    /// Entity types whose data service writes the outbox
+    pub entity_types: HashSet<String>,
+    /// Whether the link service writes the outbox
+    pub links: bool,
+}
+
+impl OutboxSources {
+    /// Whether no service writes the outbox
+    pub fn is_empty(&self) -> bool {
+        self.entity_types.is_empty() && !self.links
+    }
+
+    /// Whether `event` is recorded in the outbox by its service
+    pub fn records(&self, event: &FrameworkEvent) -> bool {
+        match event {
+            FrameworkEvent::Entity(_) => event
+                .entity_type()
+                .is_some_and(|entity_type| self.entity_types.contains(entity_type)),
+            FrameworkEvent::Link(_) => self.links,
+        }
+    }
+}
+
 /// Broadcast-based event bus for the framework
 ///
 /// Uses `tokio::sync::broadcast` which allows multiple receivers and is
@@ -191,11 +264,30 @@ impl EventEnvelope {
 /// publish(event) ──┬──▶ broadcast channel (real-time, fire-and-forget)
 ///                  └──▶ EventLog.append() (persistent, replayable)
 /// ```
+///
+/// # Replay Buffer
+///
+/// With `with_replay_buffer(n)`, the bus numbers every published event
+/// (`seq_no`, starting at 1) and retains the last `n` in memory, so that
+/// reconnecting clients can fetch what they missed via `replay_since()`.
+///
+/// # Outbox
+///
+/// With `with_outbox_sources()`, `publish()` drops the events whose service
+/// records them in the outbox: they reach subscribers when the outbox poller
+/// hands them to `deliver()` (see [`crate::core::outbox`]), so none is
+/// delivered twice. Other events are published as usual.
 #[derive(Clone)]
 pub struct EventBus {
     sender: broadcast::Sender<EventEnvelope>,
     /// Optional persistent event log (bridge)
     event_log: Option<Arc<dyn EventLog>>,
+    /// Optional in-memory buffer of the last published events
+    replay: Option<Arc<Mutex<ReplayBuffer>>>,
+    /// Events that only arrive through `deliver()`
+    outbox: Option<Arc<OutboxSources>>,
+    /// Entity type -> fields removed from `Created`/`Updated` data
+    redacted: Option<Arc<HashMap<String, &'static [&'static str]>>>,
 }
 
 impl std::fmt::Debug for EventBus {
@@ -203,6 +295,9 @@ impl std::fmt::Debug for EventBus {
         f.debug_struct("EventBus")
             .field("sender", &self.sender)
             .field("has_event_log", &self.event_log.is_some())
+            .field("has_replay_buffer", &self.replay.is_some())
+            .field("outbox", &self.outbox)
+            .field("redacted", &self.redacted)
             .finish()
     }
 }
@@ -221,6 +316,9 @@ impl EventBus {
         Self {
             sender,
             event_log: None,
+            replay: None,
+            outbox: None,
+            redacted: None,
         }
     }
 
@@ -236,6 +334,65 @@ impl EventBus {
         self
     }
 
+    /// Retain the last `capacity` published events for replay
+    ///
+    /// Events are numbered with monotonic sequence numbers (`seq_no`) as they
+    /// are published. Older events are evicted once `capacity` is reached.
+    pub fn with_replay_buffer(mut self, capacity: usize) -> Self {
+        self.replay = Some(Arc::new(Mutex::new(ReplayBuffer {
+            capacity: capacity.max(1),
+            last_seq_no: 0,
+            events: VecDeque::with_capacity(capacity),
+        })));
+        self
+    }
+
+    /// Leave delivery of the events recorded by `sources` to the outbox poller
+    ///
+    /// `publish()` drops those events: their services record every mutation
+    /// in the outbox, and [`poll_outbox`](crate::core::outbox::poll_outbox)
+    /// hands each event to [`deliver`](Self::deliver).
+    pub fn with_outbox_sources(mut self, sources: OutboxSources) -> Self {
+        self.outbox = Some(Arc::new(sources));
+        self
+    }
+
+    /// Remove `fields[entity_type]` from the data of entity events
+    ///
+    /// Subscribers (WebSocket, SSE, GraphQL subscriptions, gRPC streams,
+    /// sinks) are not identified per event, so the bus strips the fields an
+    /// entity type redacts from anonymous callers (see
+    /// [`redaction`](crate::core::redaction)) before anyone receives them.
+    pub fn with_redacted_fields(
+        mut self,
+        fields: HashMap<String, &'static [&'static str]>,
+    ) -> Self {
+        self.redacted = Some(Arc::new(fields));
+        self
+    }
+
+    /// The events that only arrive through [`deliver`](Self::deliver), if any
+    pub fn outbox_sources(&self) -> Option<&OutboxSources> {
+        self.outbox.as_deref()
+    }
+
+    /// Buffered events with a sequence number greater than `seq_no`, oldest first
+    ///
+    /// Empty when the bus has no replay buffer. Events evicted from the buffer
+    /// are not returned.
+    pub fn replay_since(&self, seq_no: SeqNo) -> Vec<EventEnvelope> {
+        let Some(replay) = &self.replay else {
+            return Vec::new();
+        };
+        let replay = replay.lock().unwrap_or_else(PoisonError::into_inner);
+        replay
+            .events
+            .iter()
+            .filter(|envelope| envelope.seq_no.is_some_and(|seq| seq > seq_no))
+            .cloned()
+            .collect()
+    }
+
     /// Get a reference to the attached EventLog, if any
     pub fn event_log(&self) -> Option<&Arc<dyn EventLog>> {
         self.event_log.as_ref()
@@ -251,9 +408,46 @@ impl EventBus {
     /// asynchronously (fire-and-forget via tokio::spawn).
     ///
     /// Returns the number of broadcast receivers that will receive the event.
+    /// Events recorded in an outbox (see
+    /// [`with_outbox_sources`](Self::with_outbox_sources)) are dropped and 0
+    /// is returned.
     pub fn publish(&self, event: FrameworkEvent) -> usize {
-        // Create a single envelope shared between broadcast and EventLog
-        let envelope = EventEnvelope::new(event);
+        if self
+            .outbox
+            .as_ref()
+            .is_some_and(|sources| sources.records(&event))
+        {
+            return 0;
+        }
+        self.deliver(EventEnvelope::new(event))
+    }
+
+    /// Publish an already enveloped event, keeping its ID and timestamp
+    ///
+    /// Used by the outbox poller; behaves like [`publish`](Self::publish)
+    /// otherwise, also for events recorded in the outbox.
+    pub fn deliver(&self, mut envelope: EventEnvelope) -> usize {
+        if let Some(redacted) = &self.redacted
+            && let FrameworkEvent::Entity(
+                EntityEvent::Created {
+                    entity_type, data, ..
+                }
+                | EntityEvent::Updated {
+                    entity_type, data, ..
+                },
+            ) = &mut envelope.event
+            && let Some(fields) = redacted.get(entity_type.as_str())
+        {
+            redact_entity(data, fields);
+        }
+
+        // Number, buffer and broadcast under one lock, so that live events
+        // reach receivers in sequence order
+        let _replay = self.replay.as_ref().map(|replay| {
+            let mut replay = replay.lock().unwrap_or_else(PoisonError::into_inner);
+            replay.record(&mut envelope);
+            replay
+        });
 
         // If an EventLog is attached, append a clone to it (non-blocking)
         if let Some(event_log) = &self.event_log {
@@ -295,6 +489,76 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn test_outbox_bus_drops_recorded_publishes_but_delivers() {
+        let bus = EventBus::new(16).with_outbox_sources(OutboxSources {
+            entity_types: HashSet::from(["order".to_string()]),
+            links: false,
+        });
+        let mut rx = bus.subscribe();
+
+        let event = FrameworkEvent::Entity(EntityEvent::Deleted {
+            entity_type: "order".to_string(),
+            entity_id: Uuid::new_v4(),
+        });
+        assert_eq!(bus.publish(event.clone()), 0);
+        assert!(rx.try_recv().is_err());
+
+        let envelope = EventEnvelope::new(event);
+        assert_eq!(bus.deliver(envelope.clone()), 1);
+        assert_eq!(rx.try_recv().unwrap().id, envelope.id);
+
+        // Services without an outbox still publish directly
+        let invoice = FrameworkEvent::Entity(EntityEvent::Deleted {
+            entity_type: "invoice".to_string(),
+            entity_id: Uuid::new_v4(),
+        });
+        assert_eq!(bus.publish(invoice), 1);
+        let link = FrameworkEvent::Link(LinkEvent::Deleted {
+            link_type: "billing".to_string(),
+            link_id: Uuid::new_v4(),
+            source_id: Uuid::new_v4(),
+            target_id: Uuid::new_v4(),
+        });
+        assert_eq!(bus.publish(link), 1);
+        assert_eq!(rx.try_recv().unwrap().event.entity_type(), Some("invoice"));
+        assert!(matches!(
+            rx.try_recv().unwrap().event,
+            FrameworkEvent::Link(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_redacted_fields_are_stripped_before_delivery() {
+        let bus = EventBus::new(16).with_redacted_fields(HashMap::from([(
+            "user".to_string(),
+            &["password_hash"][..],
+        )]));
+        let mut rx = bus.subscribe();
+
+        bus.publish(FrameworkEvent::Entity(EntityEvent::Updated {
+            entity_type: "user".to_string(),
+            entity_id: Uuid::new_v4(),
+            data: json!({"name": "ada", "password_hash": "x"}),
+        }));
+        bus.publish(FrameworkEvent::Entity(EntityEvent::Created {
+            entity_type: "order".to_string(),
+            entity_id: Uuid::new_v4(),
+            data: json!({"password_hash": "kept"}),
+        }));
+
+        let user = rx.recv().await.unwrap();
+        let FrameworkEvent::Entity(EntityEvent::Updated { data, .. }) = user.event else {
+            panic!("expected an update");
+        };
+        assert_eq!(data, json!({"name": "ada"}));
+        let order = rx.recv().await.unwrap();
+        let FrameworkEvent::Entity(EntityEvent::Created { data, .. }) = order.event else {
+            panic!("expected a creation");
+        };
+        assert_eq!(data, json!({"password_hash": "kept"}));
+    }
+
     #[test]
     fn test_entity_event_created() {
         let event = EntityEvent::Created {
@@ -436,6 +700,30 @@ mod tests {
         assert_eq!(receivers, 0);
     }
 
+    #[tokio::test]
+    async fn test_event_bus_replay_since() {
+        let bus = EventBus::new(16).with_replay_buffer(3);
+        let mut rx = bus.subscribe();
+
+        for i in 0..5 {
+            bus.publish(FrameworkEvent::Entity(EntityEvent::Created {
+                entity_type: "order".to_string(),
+                entity_id: Uuid::new_v4(),
+                data: json!({ "n": i }),
+            }));
+        }
+
+        assert_eq!(rx.recv().await.unwrap().seq_no, Some(1));
+        let seqs = |events: Vec<EventEnvelope>| -> Vec<_> {
+            events.iter().filter_map(|e| e.seq_no).collect()
+        };
+        // Only the last 3 events are retained
+        assert_eq!(seqs(bus.replay_since(0)), vec![3, 4, 5]);
+        assert_eq!(seqs(bus.replay_since(3)), vec![4, 5]);
+        assert!(bus.replay_since(5).is_empty());
+        assert!(EventBus::new(16).replay_since(0).is_empty());
+    }
+
     #[test]
     fn test_event_bus_default() {
         let bus = EventBus::default();
@@ -11,6 +11,8 @@ use uuid::Uuid;
 
 use crate::config::LinksConfig;
 use crate::core::LinkDefinition;
+use crate::core::link::LinkError;
+use crate::core::validation::ValidationError;
 use crate::links::registry::{LinkDirection, LinkRouteRegistry};
 
 /// Errors that can occur during extraction
@@ -20,7 +22,21 @@ pub enum ExtractorError {
     InvalidEntityId,
     RouteNotFound(String),
     LinkNotFound,
+    /// The entity does not exist, or belongs to another tenant
+    EntityNotFound(Uuid),
     JsonError(String),
+    /// The request body is larger than the server buffers
+    PayloadTooLarge,
+    /// The request carries no authenticated context
+    Unauthorized,
+    /// The authenticated context does not satisfy the required policy
+    Forbidden(String),
+    /// The request conflicts with existing state (e.g. a link cardinality)
+    Conflict(String),
+    /// The request payload is missing or has invalid fields
+    Validation(ValidationError),
+    /// The server cannot handle the request as configured
+    Internal(String),
 }
 
 impl std::fmt::Display for ExtractorError {
@@ -30,27 +46,131 @@ impl std::fmt::Display for ExtractorError {
             ExtractorError::InvalidEntityId => write!(f, "Invalid entity ID format"),
             ExtractorError::RouteNotFound(route) => write!(f, "Route not found: {}", route),
             ExtractorError::LinkNotFound => write!(f, "Link not found"),
+            ExtractorError::EntityNotFound(id) => write!(f, "Entity not found: {}", id),
             ExtractorError::JsonError(msg) => write!(f, "JSON error: {}", msg),
+            ExtractorError::PayloadTooLarge => write!(f, "Request body too large"),
+            ExtractorError::Unauthorized => write!(f, "Authentication required"),
+            ExtractorError::Forbidden(msg) => write!(f, "Forbidden: {}", msg),
+            ExtractorError::Conflict(msg) => write!(f, "Conflict: {}", msg),
+            ExtractorError::Validation(err) => write!(f, "{}", err),
+            ExtractorError::Internal(msg) => write!(f, "Internal error: {}", msg),
         }
     }
 }
 
 impl std::error::Error for ExtractorError {}
 
+impl From<LinkError> for ExtractorError {
+    fn from(err: LinkError) -> Self {
+        match err {
+            LinkError::AlreadyExists { .. } => ExtractorError::Conflict(err.to_string()),
+            LinkError::OperationFailed { .. } => ExtractorError::Internal(err.to_string()),
+        }
+    }
+}
+
+impl From<ValidationError> for ExtractorError {
+    fn from(err: ValidationError) -> Self {
+        ExtractorError::Validation(err)
+    }
+}
+
+impl ExtractorError {
+    /// Machine-readable kind of error, sent as the body's `code`
+    pub fn code(&self) -> &'static str {
+        match self {
+            ExtractorError::InvalidPath => "INVALID_PATH",
+            ExtractorError::InvalidEntityId => "INVALID_ENTITY_ID",
+            ExtractorError::RouteNotFound(_) => "ROUTE_NOT_FOUND",
+            ExtractorError::LinkNotFound => "LINK_NOT_FOUND",
+            ExtractorError::EntityNotFound(_) => "ENTITY_NOT_FOUND",
+            ExtractorError::JsonError(_) => "INVALID_JSON",
+            ExtractorError::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            ExtractorError::Unauthorized => "UNAUTHORIZED",
+            ExtractorError::Forbidden(_) => "FORBIDDEN",
+            ExtractorError::Conflict(_) => "CONFLICT",
+            ExtractorError::Validation(_) => "VALIDATION_FAILED",
+            ExtractorError::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    /// HTTP status of the response
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ExtractorError::InvalidPath
+            | ExtractorError::InvalidEntityId
+            | ExtractorError::JsonError(_)
+            | ExtractorError::Validation(_) => StatusCode::BAD_REQUEST,
+            ExtractorError::RouteNotFound(_)
+            | ExtractorError::LinkNotFound
+            | ExtractorError::EntityNotFound(_) => StatusCode::NOT_FOUND,
+            ExtractorError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            ExtractorError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ExtractorError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ExtractorError::Conflict(_) => StatusCode::CONFLICT,
+            ExtractorError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// The `{"code", "message", "error", "details"}` body of every error response
+///
+/// `details` is only present when the error carries structured data. `error`
+/// repeats the message for clients of the older `{"error"}` body.
+pub fn error_body(
+    code: &str,
+    message: &str,
+    details: Option<serde_json::Value>,
+) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "code": code,
+        "message": message,
+        "error": message,
+    });
+    if let Some(details) = details {
+        body["details"] = details;
+    }
+    body
+}
+
+/// An error response with the [`error_body`] shape and no details
+pub fn error_response(status: StatusCode, code: &str, message: impl AsRef<str>) -> Response {
+    (status, Json(error_body(code, message.as_ref(), None))).into_response()
+}
+
+/// Answers the [`error_body`] shape
+///
+/// `details` holds the field errors of a validation failure or the unknown
+/// route name. Validation failures keep their `errors` array for clients of
+/// the older body.
 impl IntoResponse for ExtractorError {
     fn into_response(self) -> Response {
-        let (status, message) = match self {
-            ExtractorError::InvalidPath => (StatusCode::BAD_REQUEST, self.to_string()),
-            ExtractorError::InvalidEntityId => (StatusCode::BAD_REQUEST, self.to_string()),
-            ExtractorError::RouteNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
-            ExtractorError::LinkNotFound => (StatusCode::NOT_FOUND, self.to_string()),
-            ExtractorError::JsonError(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+        let body = match &self {
+            ExtractorError::Validation(err) => validation_body(err),
+            ExtractorError::RouteNotFound(route) => error_body(
+                self.code(),
+                &self.to_string(),
+                Some(serde_json::json!({ "route": route })),
+            ),
+            _ => error_body(self.code(), &self.to_string(), None),
         };
 
-        (status, Json(serde_json::json!({ "error": message }))).into_response()
+        (self.status(), Json(body)).into_response()
     }
 }
 
+/// The body of a validation failure, shared with [`ValidationError`]'s own
+/// response
+pub(crate) fn validation_body(err: &ValidationError) -> serde_json::Value {
+    let mut body = error_body(
+        "VALIDATION_FAILED",
+        "Validation failed",
+        Some(serde_json::json!(err.field_errors())),
+    );
+    body["errors"] = serde_json::json!(err.field_errors());
+    body
+}
+
 /// Extractor for link information from path
 ///
 /// Automatically parses the path and resolves link definitions.
@@ -189,6 +309,11 @@ pub struct RecursiveLinkExtractor {
 
 impl RecursiveLinkExtractor {
     /// Parse un chemin complet dynamiquement
+    ///
+    /// Each route name is resolved against the entity type of the current
+    /// segment (the target of the previous hop), never globally:
+    /// `companies/{id}/members` and `users/{id}/members` can name two
+    /// different links in the same chain.
     pub fn from_segments(
         segments: Vec<String>,
         registry: &LinkRouteRegistry,
@@ -350,16 +475,25 @@ mod tests {
                     singular: "user".to_string(),
                     plural: "users".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![
@@ -371,7 +505,12 @@ mod tests {
                     reverse_route_name: "owner".to_string(),
                     description: None,
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
                 LinkDefinition {
                     link_type: "billing".to_string(),
@@ -381,17 +520,55 @@ mod tests {
                     reverse_route_name: "order".to_string(),
                     description: None,
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
             ],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         });
         let registry = LinkRouteRegistry::new(config.clone());
         (config, registry)
     }
 
+    /// `company` and `user` both expose a `members` route, to different targets
+    fn shared_route_config_and_registry() -> (Arc<LinksConfig>, LinkRouteRegistry) {
+        let config = LinksConfig::from_yaml_str(
+            r#"
+entities:
+  - singular: user
+    plural: users
+  - singular: company
+    plural: companies
+  - singular: team
+    plural: teams
+
+links:
+  - link_type: employment
+    source_type: company
+    target_type: user
+    forward_route_name: members
+    reverse_route_name: employers
+  - link_type: mentoring
+    source_type: user
+    target_type: team
+    forward_route_name: members
+    reverse_route_name: mentors
+"#,
+        )
+        .expect("valid config");
+        assert_eq!(config.validate(), Ok(()));
+        let config = Arc::new(config);
+        let registry = LinkRouteRegistry::new(config.clone());
+        (config, registry)
+    }
+
     // === ExtractorError Display + IntoResponse ===
 
     #[test]
@@ -459,6 +636,37 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn test_extractor_error_body_has_code_message_and_details() {
+        use crate::core::validation::FieldError;
+
+        let body = |err: ExtractorError| async move {
+            let bytes = axum::body::to_bytes(err.into_response().into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+
+        let route = body(ExtractorError::RouteNotFound("drivers".to_string())).await;
+        assert_eq!(route["code"], "ROUTE_NOT_FOUND");
+        assert_eq!(route["message"], "Route not found: drivers");
+        assert_eq!(route["error"], route["message"]);
+        assert_eq!(route["details"]["route"], "drivers");
+
+        let invalid = ValidationError::FieldErrors(vec![FieldError::new("/name", "is required")]);
+        let validation = body(ExtractorError::Validation(invalid)).await;
+        assert_eq!(validation["code"], "VALIDATION_FAILED");
+        assert_eq!(validation["details"][0]["field"], "/name");
+        assert_eq!(validation["errors"], validation["details"]);
+
+        let too_large = body(ExtractorError::PayloadTooLarge).await;
+        assert_eq!(too_large["code"], "PAYLOAD_TOO_LARGE");
+
+        let conflict = body(ExtractorError::Conflict("taken".to_string())).await;
+        assert_eq!(conflict["code"], "CONFLICT");
+        assert!(conflict.get("details").is_none());
+    }
+
     // === LinkExtractor ===
 
     #[test]
@@ -744,6 +952,85 @@ mod tests {
         assert!(!ext.is_list); // 6 segments → specific item
     }
 
+    #[test]
+    fn test_recursive_shared_route_name_resolves_per_hop() {
+        let (config, registry) = shared_route_config_and_registry();
+        let company_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let ext = RecursiveLinkExtractor::from_segments(
+            vec![
+                "companies".to_string(),
+                company_id.to_string(),
+                "members".to_string(),
+                user_id.to_string(),
+                "members".to_string(),
+            ],
+            &registry,
+            &config,
+        )
+        .expect("should succeed");
+
+        // company -members-> user (employment), then user -members-> team (mentoring)
+        let link_types: Vec<_> = ext
+            .chain
+            .iter()
+            .map(|s| s.link_definition.as_ref().map(|d| d.link_type.as_str()))
+            .collect();
+        assert_eq!(
+            link_types,
+            [Some("employment"), Some("mentoring"), None],
+            "each `members` must resolve against its own segment's type"
+        );
+        let types: Vec<_> = ext.chain.iter().map(|s| s.entity_type.as_str()).collect();
+        assert_eq!(types, ["company", "user", "team"]);
+        assert_eq!(ext.final_target().1, "team");
+        assert!(ext.is_list);
+    }
+
+    #[test]
+    fn test_recursive_shared_route_name_after_reverse_hop() {
+        let (config, registry) = shared_route_config_and_registry();
+        // teams/{id}/mentors -> user, whose `members` is mentoring, not employment
+        let ext = RecursiveLinkExtractor::from_segments(
+            vec![
+                "teams".to_string(),
+                Uuid::new_v4().to_string(),
+                "mentors".to_string(),
+                Uuid::new_v4().to_string(),
+                "members".to_string(),
+            ],
+            &registry,
+            &config,
+        )
+        .expect("should succeed");
+        assert_eq!(ext.chain[1].entity_type, "user");
+        assert_eq!(
+            ext.chain[1]
+                .link_definition
+                .as_ref()
+                .map(|d| d.link_type.as_str()),
+            Some("mentoring")
+        );
+        assert_eq!(ext.chain[2].entity_type, "team");
+
+        // A team has no `members` route of its own, even though other types do
+        let result = RecursiveLinkExtractor::from_segments(
+            vec![
+                "users".to_string(),
+                Uuid::new_v4().to_string(),
+                "members".to_string(),
+                Uuid::new_v4().to_string(),
+                "members".to_string(),
+            ],
+            &registry,
+            &config,
+        );
+        assert!(matches!(
+            result.unwrap_err(),
+            ExtractorError::RouteNotFound(route) if route == "members"
+        ));
+    }
+
     #[test]
     fn test_recursive_route_not_found_mid_chain() {
         let (config, registry) = test_config_and_registry();
@@ -48,6 +48,35 @@ impl FieldValue {
     pub fn is_null(&self) -> bool {
         matches!(self, FieldValue::Null)
     }
+
+    /// Get the value as a float if it is numeric
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            FieldValue::Integer(i) => Some(*i as f64),
+            FieldValue::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// The value of the top-level `field` of `value` serialized to JSON
+    pub fn from_serialized<T: Serialize>(value: &T, field: &str) -> Option<Self> {
+        Self::from_json(serde_json::to_value(value).ok()?.get(field)?)
+    }
+
+    /// Convert a scalar JSON value (arrays and objects have no field value)
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        use serde_json::Value;
+        match value {
+            Value::Null => Some(FieldValue::Null),
+            Value::Bool(b) => Some(FieldValue::Boolean(*b)),
+            Value::Number(n) => n
+                .as_i64()
+                .map(FieldValue::Integer)
+                .or_else(|| n.as_f64().map(FieldValue::Float)),
+            Value::String(s) => Some(FieldValue::String(s.clone())),
+            Value::Array(_) | Value::Object(_) => None,
+        }
+    }
 }
 
 /// Field format validators for automatic validation
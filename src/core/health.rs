@@ -0,0 +1,123 @@
+//! Readiness checks of the backends a server depends on
+//!
+//! Storage services implement [`HealthCheck`] with the cheapest round trip
+//! their backend offers (`SELECT 1`, `PING`, ...). Checks registered with
+//! [`ServerBuilder::with_health_checks`](crate::server::ServerBuilder::with_health_checks)
+//! run on every `GET /health`, which answers `503 Service Unavailable` when
+//! any of them fails. `GET /healthz` never runs them: it only tells that the
+//! process is alive.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How long a single check may take before it counts as failed
+pub const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A dependency whose connectivity can be probed
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    /// Name of the dependency in health reports, e.g. `"mysql"`
+    fn name(&self) -> &str;
+
+    /// Succeed if the dependency can currently serve requests
+    async fn check(&self) -> Result<()>;
+}
+
+/// Outcome of one dependency's check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum DependencyStatus {
+    Ok,
+    Error { error: String },
+}
+
+/// Outcome of all registered checks, keyed by dependency name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    pub checks: BTreeMap<String, DependencyStatus>,
+}
+
+impl HealthReport {
+    /// Run `checks` concurrently, failing those that exceed `timeout`
+    pub async fn collect(checks: &[Arc<dyn HealthCheck>], timeout: Duration) -> Self {
+        let results = futures::future::join_all(checks.iter().map(|check| async move {
+            let status = match tokio::time::timeout(timeout, check.check()).await {
+                Ok(Ok(())) => DependencyStatus::Ok,
+                Ok(Err(e)) => DependencyStatus::Error {
+                    error: e.to_string(),
+                },
+                Err(_) => DependencyStatus::Error {
+                    error: format!("timed out after {}ms", timeout.as_millis()),
+                },
+            };
+            (check.name().to_string(), status)
+        }))
+        .await;
+        Self {
+            checks: results.into_iter().collect(),
+        }
+    }
+
+    /// Whether every dependency passed its check
+    pub fn is_healthy(&self) -> bool {
+        self.checks
+            .values()
+            .all(|status| *status == DependencyStatus::Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Probe(&'static str, Option<Duration>, bool);
+
+    #[async_trait]
+    impl HealthCheck for Probe {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        async fn check(&self) -> Result<()> {
+            if let Some(delay) = self.1 {
+                tokio::time::sleep(delay).await;
+            }
+            if self.2 {
+                Ok(())
+            } else {
+                anyhow::bail!("connection refused")
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_names_failing_and_slow_dependencies() {
+        let checks: Vec<Arc<dyn HealthCheck>> = vec![
+            Arc::new(Probe("mysql", None, true)),
+            Arc::new(Probe("redis", None, false)),
+            Arc::new(Probe("search", Some(Duration::from_secs(1)), true)),
+        ];
+
+        let report = HealthReport::collect(&checks, Duration::from_millis(50)).await;
+
+        assert!(!report.is_healthy());
+        assert_eq!(report.checks["mysql"], DependencyStatus::Ok);
+        assert_eq!(
+            report.checks["redis"],
+            DependencyStatus::Error {
+                error: "connection refused".to_string()
+            }
+        );
+        assert_eq!(
+            report.checks["search"],
+            DependencyStatus::Error {
+                error: "timed out after 50ms".to_string()
+            }
+        );
+        assert!(HealthReport::default().is_healthy());
+    }
+}
@@ -0,0 +1,151 @@
+//! Entity version history
+//!
+//! When history is enabled, every update stores the entity as it was before
+//! the change, so any earlier state can be reconstructed. This is heavier than
+//! an audit log: each version is a complete snapshot, not a diff.
+//!
+//! Version `n` of an entity is the `n`-th state it has been in: version 1 is
+//! the state it was created in, and the version after the last recorded
+//! snapshot is its current state. [`state_at`] and [`state_as_of`] resolve
+//! a version number or a point in time against the snapshots; data services
+//! keeping history expose them as
+//! [`DataService::get_at`](crate::core::DataService::get_at) and
+//! [`DataService::get_as_of`](crate::core::DataService::get_as_of).
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Error returned when a past state of an entity is requested
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HistoryError {
+    /// The data service does not record versions
+    #[error("version history is not enabled for this entity type")]
+    NotEnabled,
+}
+
+/// A stored snapshot of an entity before one of its updates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntityVersion {
+    /// ID of the versioned entity
+    pub entity_id: Uuid,
+
+    /// Singular entity type (e.g., "order")
+    pub entity_type: String,
+
+    /// Version number, starting at 1 for the first recorded snapshot
+    pub version: i64,
+
+    /// Full JSON representation of the entity at that version
+    pub snapshot: Value,
+
+    /// When this version was superseded
+    pub changed_at: DateTime<Utc>,
+
+    /// Who made the change, when known
+    pub actor: Option<String>,
+}
+
+/// Storage for entity version snapshots
+///
+/// SQL backends write versions from their `update` path inside the same
+/// transaction as the update itself (see `PostgresDataService::with_history`
+/// and `MysqlDataService::with_history`); this trait is the read side used by
+/// the `/history` and `/versions/{n}` endpoints.
+#[async_trait]
+pub trait HistoryService: Send + Sync {
+    /// Append a snapshot as the next version of an entity
+    async fn record(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        snapshot: Value,
+        actor: Option<&str>,
+    ) -> Result<EntityVersion>;
+
+    /// List all versions of an entity, oldest first
+    async fn list_versions(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+    ) -> Result<Vec<EntityVersion>>;
+
+    /// Get a specific version of an entity
+    async fn get_version(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        version: i64,
+    ) -> Result<Option<EntityVersion>>;
+}
+
+/// The state an entity was in at `version`
+///
+/// `snapshots` are its recorded versions, oldest first, and `current` its
+/// current state. Returns `None` for versions that do not exist.
+pub fn state_at<S>(snapshots: Vec<S>, current: Option<S>, version: i64) -> Option<S> {
+    let index = usize::try_from(version).ok()?.checked_sub(1)?;
+    snapshots.into_iter().chain(current).nth(index)
+}
+
+/// The state an entity was in at time `at`
+///
+/// `snapshots` pairs each recorded version, oldest first, with the time it
+/// was superseded ([`EntityVersion::changed_at`]); `current` is the current
+/// state. Returns `None` if `created_at` says the entity did not exist yet.
+pub fn state_as_of<S>(
+    snapshots: Vec<(S, DateTime<Utc>)>,
+    current: Option<S>,
+    at: DateTime<Utc>,
+    created_at: impl Fn(&S) -> Option<DateTime<Utc>>,
+) -> Option<S> {
+    let state = match snapshots
+        .into_iter()
+        .find(|(_, changed_at)| *changed_at > at)
+    {
+        Some((snapshot, _)) => Some(snapshot),
+        None => current,
+    }?;
+    created_at(&state)
+        .is_some_and(|created| created <= at)
+        .then_some(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_state_at_counts_the_current_state_last() {
+        let snapshots = vec!["created", "renamed"];
+        assert_eq!(
+            state_at(snapshots.clone(), Some("current"), 1),
+            Some("created")
+        );
+        assert_eq!(
+            state_at(snapshots.clone(), Some("current"), 3),
+            Some("current")
+        );
+        assert_eq!(state_at(snapshots.clone(), Some("current"), 4), None);
+        assert_eq!(state_at(snapshots, Some("current"), 0), None);
+    }
+
+    #[test]
+    fn test_state_as_of_picks_the_state_live_at_that_time() {
+        let created = Utc::now() - Duration::hours(3);
+        let first_update = created + Duration::hours(1);
+        let second_update = created + Duration::hours(2);
+        let snapshots = vec![("v1", first_update), ("v2", second_update)];
+        let created_at = |_: &&str| Some(created);
+
+        let as_of = |at| state_as_of(snapshots.clone(), Some("v3"), at, created_at);
+        assert_eq!(as_of(created - Duration::minutes(1)), None);
+        assert_eq!(as_of(created), Some("v1"));
+        assert_eq!(as_of(first_update), Some("v2"));
+        assert_eq!(as_of(Utc::now()), Some("v3"));
+    }
+}
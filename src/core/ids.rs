@@ -0,0 +1,274 @@
+//! Normalization of client-supplied entity ids
+//!
+//! Clients format UUIDs differently depending on their language or library:
+//! `{AAAAAAAA-...}`, upper case, no hyphens. An [`IdNormalizer`] maps every
+//! spelling it accepts onto a single [`Uuid`], so a path or a body resolves
+//! the same entity whichever form was sent.
+//!
+//! New ids are minted by the server's [`IdStrategy`], random UUIDv4 unless
+//! `ServerBuilder::with_id_strategy` configures another one.
+
+use crate::core::extractors::ExtractorError;
+use crate::core::module::EntityCreator;
+use crate::core::soft_delete::SoftDeleteStatus;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// How new entity and link ids are minted
+///
+/// A server keeps its strategy in
+/// [`ServerHost::id_strategy`](crate::server::host::ServerHost::id_strategy):
+/// links created through any exposure and entities created without an `id`
+/// (see [`IdStrategyCreator`]) get their id from it. Constructors with no
+/// server at hand, such as `LinkEntity::new` and the `new()` generated by
+/// `impl_data_entity!`, use the default through [`new_id`].
+#[derive(Debug, Clone, Copy, Default)]
+pub enum IdStrategy {
+    /// Random UUIDs (version 4)
+    #[default]
+    UuidV4,
+    /// Time-ordered UUIDs (version 7): ids minted by one process sort by
+    /// creation
+    UuidV7,
+    /// Ids from a user function
+    Custom(fn() -> Uuid),
+}
+
+impl IdStrategy {
+    /// Mint an id with this strategy
+    pub fn generate(&self) -> Uuid {
+        match self {
+            Self::UuidV4 => Uuid::new_v4(),
+            Self::UuidV7 => Uuid::now_v7(),
+            Self::Custom(generate) => generate(),
+        }
+    }
+}
+
+/// Mint a new entity or link id with the default [`IdStrategy`]
+///
+/// Always a random UUIDv4, whatever strategy a server is configured with:
+/// `LinkEntity::new` and the `new()` generated by `impl_data_entity!` and
+/// `impl_link_entity!` call it without a server at hand. The server's
+/// strategy only applies to what goes through the server: links created by
+/// [`ServerHost::create_link`](crate::server::host::ServerHost::create_link)
+/// or the REST routes, whose id is replaced, and entities created through
+/// its creators (REST, GraphQL, gRPC). Code building entities itself should
+/// mint their id with `host.id_strategy.generate()`.
+pub fn new_id() -> Uuid {
+    IdStrategy::default().generate()
+}
+
+/// [`EntityCreator`] wrapper minting the id of created entities
+///
+/// Applied by the server builder to every creator when an [`IdStrategy`]
+/// is configured. A create payload without an `id` gets one from the
+/// strategy; a supplied `id` is kept. Other operations pass through.
+pub struct IdStrategyCreator {
+    inner: Arc<dyn EntityCreator>,
+    strategy: IdStrategy,
+}
+
+impl IdStrategyCreator {
+    pub fn new(inner: Arc<dyn EntityCreator>, strategy: IdStrategy) -> Self {
+        Self { inner, strategy }
+    }
+
+    fn with_id(&self, mut entity_data: Value) -> Value {
+        if let Some(obj) = entity_data.as_object_mut()
+            && obj.get("id").is_none_or(Value::is_null)
+        {
+            obj.insert(
+                "id".to_string(),
+                Value::String(self.strategy.generate().to_string()),
+            );
+        }
+        entity_data
+    }
+}
+
+#[async_trait]
+impl EntityCreator for IdStrategyCreator {
+    async fn create_from_json(&self, entity_data: Value) -> Result<Value> {
+        self.inner.create_from_json(self.with_id(entity_data)).await
+    }
+
+    async fn create_owned_from_json(&self, entity_data: Value, owner_id: Uuid) -> Result<Value> {
+        self.inner
+            .create_owned_from_json(self.with_id(entity_data), owner_id)
+            .await
+    }
+
+    async fn update_from_json(&self, entity_id: &Uuid, entity_data: Value) -> Result<Value> {
+        self.inner.update_from_json(entity_id, entity_data).await
+    }
+
+    async fn patch_from_json(&self, entity_id: &Uuid, partial: Value) -> Result<Value> {
+        self.inner.patch_from_json(entity_id, partial).await
+    }
+
+    async fn delete(&self, entity_id: &Uuid) -> Result<()> {
+        self.inner.delete(entity_id).await
+    }
+
+    async fn soft_delete(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.soft_delete(entity_id).await
+    }
+
+    async fn restore(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.restore(entity_id).await
+    }
+
+    async fn soft_delete_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.soft_delete_with_status(entity_id, status).await
+    }
+
+    async fn restore_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.restore_with_status(entity_id, status).await
+    }
+}
+
+/// Turns a raw id string into an entity id
+///
+/// Implement this to accept more (or fewer) spellings than
+/// [`DefaultIdNormalizer`], then install it with
+/// `ServerBuilder::with_id_normalizer`.
+pub trait IdNormalizer: Send + Sync {
+    /// Parse `raw`, or `None` if it is not an acceptable id
+    fn normalize(&self, raw: &str) -> Option<Uuid>;
+
+    /// Canonical text form of `raw`: lower case and hyphenated
+    fn canonicalize(&self, raw: &str) -> Option<String> {
+        self.normalize(raw).map(|id| id.hyphenated().to_string())
+    }
+
+    /// Parse `raw`, rejecting it with [`ExtractorError::InvalidEntityId`]
+    fn parse(&self, raw: &str) -> Result<Uuid, ExtractorError> {
+        self.normalize(raw).ok_or(ExtractorError::InvalidEntityId)
+    }
+}
+
+/// Accepts UUIDs in any case, hyphenated or not, optionally wrapped in braces
+///
+/// Surrounding whitespace is ignored. URNs (`urn:uuid:...`) are rejected.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultIdNormalizer;
+
+impl IdNormalizer for DefaultIdNormalizer {
+    fn normalize(&self, raw: &str) -> Option<Uuid> {
+        let raw = raw.trim();
+        let raw = raw
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .unwrap_or(raw);
+        if !raw.chars().all(|c| c.is_ascii_hexdigit() || c == '-') {
+            return None;
+        }
+        Uuid::try_parse(raw).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_id_strategies_mint_their_uuid_version() {
+        assert!(matches!(IdStrategy::default(), IdStrategy::UuidV4));
+        assert_eq!(IdStrategy::UuidV4.generate().get_version_num(), 4);
+        assert_eq!(IdStrategy::UuidV7.generate().get_version_num(), 7);
+        assert_eq!(IdStrategy::Custom(Uuid::nil).generate(), Uuid::nil());
+
+        let ids: Vec<Uuid> = (0..1000).map(|_| IdStrategy::UuidV7.generate()).collect();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    struct EchoCreator;
+
+    #[async_trait]
+    impl EntityCreator for EchoCreator {
+        async fn create_from_json(&self, entity_data: Value) -> Result<Value> {
+            Ok(entity_data)
+        }
+        async fn update_from_json(&self, _: &Uuid, entity_data: Value) -> Result<Value> {
+            Ok(entity_data)
+        }
+        async fn delete(&self, _: &Uuid) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_id_strategy_creator_mints_missing_ids_only() {
+        let creator = IdStrategyCreator::new(Arc::new(EchoCreator), IdStrategy::Custom(Uuid::nil));
+
+        let created = creator
+            .create_from_json(json!({ "name": "a" }))
+            .await
+            .unwrap();
+        assert_eq!(created["id"], json!(Uuid::nil()));
+        let created = creator
+            .create_from_json(json!({ "name": "a", "id": null }))
+            .await
+            .unwrap();
+        assert_eq!(created["id"], json!(Uuid::nil()));
+
+        let supplied = Uuid::new_v4();
+        let created = creator
+            .create_from_json(json!({ "name": "a", "id": supplied }))
+            .await
+            .unwrap();
+        assert_eq!(created["id"], json!(supplied));
+    }
+
+    const CANONICAL: &str = "a1b2c3d4-e5f6-4789-abcd-ef0123456789";
+
+    #[test]
+    fn test_spellings_resolve_to_the_same_id() {
+        let expected = Uuid::parse_str(CANONICAL).unwrap();
+        for raw in [
+            CANONICAL,
+            "A1B2C3D4-E5F6-4789-ABCD-EF0123456789",
+            "{A1B2C3D4-E5F6-4789-ABCD-EF0123456789}",
+            "{a1b2c3d4-e5f6-4789-abcd-ef0123456789}",
+            "a1b2c3d4e5f64789abcdef0123456789",
+            "  a1b2c3d4-e5f6-4789-abcd-ef0123456789\n",
+        ] {
+            assert_eq!(DefaultIdNormalizer.normalize(raw), Some(expected), "{raw}");
+            assert_eq!(
+                DefaultIdNormalizer.canonicalize(raw).as_deref(),
+                Some(CANONICAL)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_ids_are_rejected() {
+        for raw in [
+            "",
+            "not-a-uuid",
+            "{a1b2c3d4-e5f6-4789-abcd-ef0123456789",
+            "{{a1b2c3d4-e5f6-4789-abcd-ef0123456789}}",
+            "a1b2c3d4-e5f6-4789-abcd-ef012345678",
+            "urn:uuid:a1b2c3d4-e5f6-4789-abcd-ef0123456789",
+        ] {
+            assert!(DefaultIdNormalizer.normalize(raw).is_none(), "{raw}");
+            assert!(matches!(
+                DefaultIdNormalizer.parse(raw),
+                Err(ExtractorError::InvalidEntityId)
+            ));
+        }
+    }
+}
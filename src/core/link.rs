@@ -1,6 +1,9 @@
 //! Link system for managing relationships between entities
 
+use crate::core::ids::new_id;
 use crate::core::pluralize::Pluralizer;
+use crate::core::validation::{FieldError, ValidationError};
+use crate::links::registry::LinkDirection;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -46,6 +49,17 @@ pub struct LinkEntity {
     /// The ID of the target entity
     pub target_id: Uuid,
 
+    /// Entity type of the source (e.g., "user"), when known
+    ///
+    /// Populated from the `LinkDefinition` by the link handlers so storage
+    /// backends can honor `target_type`/`source_type` filters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+
+    /// Entity type of the target (e.g., "car"), when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<String>,
+
     /// Optional metadata for the relationship
     pub metadata: Option<serde_json::Value>,
 }
@@ -53,7 +67,9 @@ pub struct LinkEntity {
 impl LinkEntity {
     /// Create a new link without tenant context
     ///
-    /// For multi-tenant applications, use `new_with_tenant()` instead.
+    /// For multi-tenant applications, use `new_with_tenant()` instead. The
+    /// id is a random UUIDv4 (see [`new_id`]); a server's `IdStrategy`
+    /// replaces it when the link is created through the server.
     pub fn new(
         link_type: impl Into<String>,
         source_id: Uuid,
@@ -62,7 +78,7 @@ impl LinkEntity {
     ) -> Self {
         let now = Utc::now();
         Self {
-            id: Uuid::new_v4(),
+            id: new_id(),
             entity_type: "link".to_string(),
             created_at: now,
             updated_at: now,
@@ -72,6 +88,8 @@ impl LinkEntity {
             link_type: link_type.into(),
             source_id,
             target_id,
+            source_type: None,
+            target_type: None,
             metadata,
         }
     }
@@ -103,7 +121,7 @@ impl LinkEntity {
     ) -> Self {
         let now = Utc::now();
         Self {
-            id: Uuid::new_v4(),
+            id: new_id(),
             entity_type: "link".to_string(),
             created_at: now,
             updated_at: now,
@@ -113,10 +131,52 @@ impl LinkEntity {
             link_type: link_type.into(),
             source_id,
             target_id,
+            source_type: None,
+            target_type: None,
             metadata,
         }
     }
 
+    /// Set the source and target entity types of this link
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let link = LinkEntity::new("owner", user_id, car_id, None)
+    ///     .with_entity_types("user", "car");
+    /// assert_eq!(link.target_type.as_deref(), Some("car"));
+    /// ```
+    pub fn with_entity_types(
+        mut self,
+        source_type: impl Into<String>,
+        target_type: impl Into<String>,
+    ) -> Self {
+        self.source_type = Some(source_type.into());
+        self.target_type = Some(target_type.into());
+        self
+    }
+
+    /// Whether this link matches an optional source type filter
+    ///
+    /// Links without a recorded source type (created before types were
+    /// tracked) always match.
+    pub fn matches_source_type(&self, source_type: Option<&str>) -> bool {
+        match (source_type, self.source_type.as_deref()) {
+            (Some(wanted), Some(actual)) => wanted == actual,
+            _ => true,
+        }
+    }
+
+    /// Whether this link matches an optional target type filter
+    ///
+    /// Links without a recorded target type always match.
+    pub fn matches_target_type(&self, target_type: Option<&str>) -> bool {
+        match (target_type, self.target_type.as_deref()) {
+            (Some(wanted), Some(actual)) => wanted == actual,
+            _ => true,
+        }
+    }
+
     /// Soft delete this link
     pub fn soft_delete(&mut self) {
         self.deleted_at = Some(Utc::now());
@@ -134,15 +194,248 @@ impl LinkEntity {
         self.updated_at = Utc::now();
     }
 
+    /// Set new metadata, or deep-merge it into the current one
+    ///
+    /// With `merge`, objects are merged recursively: each key of `metadata`
+    /// overwrites the same key of the current object, nested objects are
+    /// merged the same way, and the other keys are kept; `None` leaves the
+    /// metadata untouched. Without `merge`, or when either side is not an
+    /// object, `metadata` replaces the current value.
+    pub fn apply_metadata(&mut self, metadata: Option<serde_json::Value>, merge: bool) {
+        if !merge {
+            self.metadata = metadata;
+            return;
+        }
+        match (self.metadata.as_mut(), metadata) {
+            (_, None) => {}
+            (Some(current), Some(patch)) => merge_json(current, patch),
+            (None, Some(patch)) => self.metadata = Some(patch),
+        }
+    }
+
     /// Check if the link is deleted
     pub fn is_deleted(&self) -> bool {
         self.deleted_at.is_some()
     }
 
+    /// Whether this is a live link of the same type between the same
+    /// entities as `other`
+    pub fn duplicates(&self, other: &LinkEntity) -> bool {
+        !self.is_deleted()
+            && self.link_type == other.link_type
+            && self.source_id == other.source_id
+            && self.target_id == other.target_id
+    }
+
     /// Check if the link is active
     pub fn is_active(&self) -> bool {
         self.status == "active" && !self.is_deleted()
     }
+
+    /// Keep only the given top-level keys in the link metadata
+    ///
+    /// Metadata that ends up empty (or was not an object) becomes `None`,
+    /// matching how storage backends represent links without metadata.
+    pub fn project_metadata(&mut self, fields: &[String]) {
+        self.metadata = match self.metadata.take() {
+            Some(serde_json::Value::Object(mut obj)) => {
+                obj.retain(|key, _| fields.iter().any(|f| f == key));
+                if obj.is_empty() {
+                    None
+                } else {
+                    Some(serde_json::Value::Object(obj))
+                }
+            }
+            _ => None,
+        };
+    }
+}
+
+/// Which links of an entity a relations query returns
+///
+/// `Out` follows links where the entity is the source, `In` links where it is
+/// the target, and `Both` (the default) returns either.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RelationDirection {
+    /// Links pointing to the entity (entity is the target)
+    In,
+    /// Links leaving the entity (entity is the source)
+    Out,
+    /// Links in either direction
+    #[default]
+    Both,
+}
+
+impl std::str::FromStr for RelationDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "in" => Ok(Self::In),
+            "out" => Ok(Self::Out),
+            "both" => Ok(Self::Both),
+            other => Err(format!(
+                "Invalid direction '{}': expected in, out or both",
+                other
+            )),
+        }
+    }
+}
+
+/// Caps on how many live links of one type may share an endpoint
+///
+/// Used with [`LinkService::create_within_limit`](crate::core::LinkService::create_within_limit),
+/// which checks the caps and inserts atomically. Soft-deleted links do not count.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LinkLimit {
+    /// Maximum links of the type leaving one source entity
+    pub per_source: Option<usize>,
+    /// Maximum links of the type reaching one target entity
+    pub per_target: Option<usize>,
+}
+
+impl LinkLimit {
+    /// Cap the links of a type per source entity
+    pub fn per_source(max: usize) -> Self {
+        Self {
+            per_source: Some(max),
+            per_target: None,
+        }
+    }
+
+    /// Cap the links of a type per target entity
+    pub fn per_target(max: usize) -> Self {
+        Self {
+            per_source: None,
+            per_target: Some(max),
+        }
+    }
+
+    /// Whether one more link fits, given the live links already sharing its
+    /// source and target
+    pub fn allows(&self, source_count: usize, target_count: usize) -> bool {
+        self.per_source.is_none_or(|max| source_count < max)
+            && self.per_target.is_none_or(|max| target_count < max)
+    }
+
+    /// Count the live links of `link`'s type sharing its source and target
+    pub fn count<'a>(
+        link: &LinkEntity,
+        existing: impl IntoIterator<Item = &'a LinkEntity>,
+    ) -> (usize, usize) {
+        existing
+            .into_iter()
+            .filter(|l| l.link_type == link.link_type && !l.is_deleted())
+            .fold((0, 0), |(sources, targets), l| {
+                (
+                    sources + usize::from(l.source_id == link.source_id),
+                    targets + usize::from(l.target_id == link.target_id),
+                )
+            })
+    }
+}
+
+/// A field that a bulk link filter can match on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkFilterField {
+    /// One of the link columns in [`LinkFilterCondition::COLUMNS`]
+    Column(&'static str),
+    /// A top-level metadata key, written `metadata.<key>` in filters
+    Metadata(String),
+}
+
+/// One condition of a bulk link filter: the field must equal one of `values`
+///
+/// Values are compared as text, so `{"metadata.priority": 3}` matches both
+/// the number `3` and the string `"3"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkFilterCondition {
+    pub field: LinkFilterField,
+    pub values: Vec<String>,
+}
+
+impl LinkFilterCondition {
+    /// Link columns a filter can reference directly
+    pub const COLUMNS: &'static [&'static str] = &[
+        "status",
+        "source_id",
+        "target_id",
+        "source_type",
+        "target_type",
+    ];
+
+    /// Parse a JSON filter object into conditions
+    ///
+    /// Keys are link columns or `metadata.<key>` (ASCII alphanumerics, `_`
+    /// or `-`); values are scalars or arrays of scalars (an IN set). Unknown
+    /// keys are rejected rather than ignored, since a silently dropped
+    /// condition would widen a destructive operation.
+    pub fn parse_all(filter: &serde_json::Value) -> Result<Vec<Self>, String> {
+        let obj = filter
+            .as_object()
+            .ok_or_else(|| "filter must be a JSON object".to_string())?;
+
+        obj.iter()
+            .map(|(key, value)| {
+                let field = if let Some(column) = Self::COLUMNS.iter().find(|c| **c == key) {
+                    LinkFilterField::Column(column)
+                } else if let Some(meta_key) = key.strip_prefix("metadata.").filter(|k| {
+                    !k.is_empty()
+                        && k.chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+                }) {
+                    LinkFilterField::Metadata(meta_key.to_string())
+                } else {
+                    return Err(format!("unsupported filter field '{}'", key));
+                };
+
+                let items = match value {
+                    serde_json::Value::Array(items) => items.as_slice(),
+                    scalar => std::slice::from_ref(scalar),
+                };
+                let values = items
+                    .iter()
+                    .map(|v| match v {
+                        serde_json::Value::String(s) => Ok(s.clone()),
+                        serde_json::Value::Number(n) => Ok(n.to_string()),
+                        serde_json::Value::Bool(b) => Ok(b.to_string()),
+                        _ => Err(format!("filter value for '{}' must be a scalar", key)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if values.is_empty() {
+                    return Err(format!("filter value for '{}' must not be empty", key));
+                }
+
+                Ok(Self { field, values })
+            })
+            .collect()
+    }
+
+    /// Whether a link satisfies this condition
+    pub fn matches(&self, link: &LinkEntity) -> bool {
+        let actual = match &self.field {
+            LinkFilterField::Column(column) => match *column {
+                "status" => Some(link.status.clone()),
+                "source_id" => Some(link.source_id.to_string()),
+                "target_id" => Some(link.target_id.to_string()),
+                "source_type" => link.source_type.clone(),
+                "target_type" => link.target_type.clone(),
+                _ => None,
+            },
+            LinkFilterField::Metadata(key) => link
+                .metadata
+                .as_ref()
+                .and_then(|m| m.get(key))
+                .and_then(|v| match v {
+                    serde_json::Value::String(s) => Some(s.clone()),
+                    serde_json::Value::Number(n) => Some(n.to_string()),
+                    serde_json::Value::Bool(b) => Some(b.to_string()),
+                    _ => None,
+                }),
+        };
+        actual.is_some_and(|a| self.values.contains(&a))
+    }
 }
 
 /// Authorization configuration for link operations
@@ -212,9 +505,106 @@ pub struct LinkDefinition {
     /// Optional list of required metadata fields
     pub required_fields: Option<Vec<String>>,
 
+    /// Required metadata fields when the link is created from the source side
+    ///
+    /// Overrides `required_fields` for [`LinkDirection::Forward`] when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_fields_forward: Option<Vec<String>>,
+
+    /// Required metadata fields when the link is created from the target side
+    ///
+    /// Overrides `required_fields` for [`LinkDirection::Reverse`] when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_fields_reverse: Option<Vec<String>>,
+
+    /// Whether the link has no inherent direction (e.g., `friend`)
+    ///
+    /// Symmetric links connect two entities of the same type, so
+    /// `source_type` and `target_type` must be identical; this is checked by
+    /// [`LinksConfig::validate`](crate::config::LinksConfig::validate).
+    #[serde(default)]
+    pub symmetric: bool,
+
     /// Authorization configuration specific to this link type
     #[serde(default)]
     pub auth: Option<LinkAuthConfig>,
+
+    /// How many links of this type an entity may take part in
+    ///
+    /// Enforced when links are created over REST; unset means unbounded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardinality: Option<LinkCardinality>,
+
+    /// JSON Schema the link metadata must satisfy
+    ///
+    /// Checked on REST link creation and update (requires the `json-schema`
+    /// feature); unset means free-form metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_schema: Option<serde_json::Value>,
+}
+
+/// How many entities a link type may connect on each side
+///
+/// Read "one source to many targets" for `OneToMany`: a target has at most
+/// one source (a car has one `owner`), while a source may have many targets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkCardinality {
+    /// Each source has at most one target and each target at most one source
+    OneToOne,
+    /// Each target has at most one source
+    OneToMany,
+    /// Each source has at most one target
+    ManyToOne,
+    /// No constraint
+    #[default]
+    ManyToMany,
+}
+
+impl LinkCardinality {
+    /// The per-endpoint caps this cardinality implies
+    pub fn limit(self) -> LinkLimit {
+        match self {
+            Self::OneToOne => LinkLimit {
+                per_source: Some(1),
+                per_target: Some(1),
+            },
+            Self::OneToMany => LinkLimit::per_target(1),
+            Self::ManyToOne => LinkLimit::per_source(1),
+            Self::ManyToMany => LinkLimit::default(),
+        }
+    }
+}
+
+impl std::fmt::Display for LinkCardinality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::OneToOne => "one_to_one",
+            Self::OneToMany => "one_to_many",
+            Self::ManyToOne => "many_to_one",
+            Self::ManyToMany => "many_to_many",
+        })
+    }
+}
+
+/// Error returned when a link cannot be created
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LinkError {
+    /// An existing link already takes the slot the cardinality allows, or
+    /// already connects the same two entities
+    #[error("a '{link_type}' link already exists for this entity ({cardinality})")]
+    AlreadyExists {
+        link_type: String,
+        cardinality: LinkCardinality,
+    },
+
+    /// The server is not set up to carry out a link operation
+    #[error("cannot {operation} '{link_type}' link: {reason}")]
+    OperationFailed {
+        link_type: String,
+        operation: String,
+        reason: String,
+    },
 }
 
 impl LinkDefinition {
@@ -222,8 +612,8 @@ impl LinkDefinition {
     pub fn default_forward_route_name(target_type: &str, link_type: &str) -> String {
         format!(
             "{}-{}",
-            Pluralizer::pluralize(target_type),
-            Pluralizer::pluralize(link_type)
+            Pluralizer::standard().pluralize(target_type),
+            Pluralizer::standard().pluralize(link_type)
         )
     }
 
@@ -231,10 +621,88 @@ impl LinkDefinition {
     pub fn default_reverse_route_name(source_type: &str, link_type: &str) -> String {
         format!(
             "{}-{}",
-            Pluralizer::pluralize(source_type),
-            Pluralizer::pluralize(link_type)
+            Pluralizer::standard().pluralize(source_type),
+            Pluralizer::standard().pluralize(link_type)
         )
     }
+
+    /// Required metadata fields for links created in the given direction
+    ///
+    /// A direction-specific list takes precedence over `required_fields`, so
+    /// an empty `required_fields_reverse` makes every field optional on the
+    /// reverse side.
+    pub fn required_fields_for(&self, direction: LinkDirection) -> &[String] {
+        let specific = match direction {
+            LinkDirection::Forward => &self.required_fields_forward,
+            LinkDirection::Reverse => &self.required_fields_reverse,
+        };
+        specific
+            .as_ref()
+            .or(self.required_fields.as_ref())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Required metadata fields that are absent, null or empty strings in `metadata`
+    pub fn missing_required_fields(
+        &self,
+        direction: LinkDirection,
+        metadata: Option<&serde_json::Value>,
+    ) -> Vec<String> {
+        self.required_fields_for(direction)
+            .iter()
+            .filter(|field| {
+                metadata
+                    .and_then(|m| m.get(field.as_str()))
+                    .is_none_or(|v| v.is_null() || v.as_str() == Some(""))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Check link metadata for `direction` against the required fields and
+    /// the `metadata_schema` (with the `json-schema` feature)
+    ///
+    /// Errors point into the request body (`/metadata/...`).
+    pub fn validate_metadata(
+        &self,
+        direction: LinkDirection,
+        metadata: Option<&serde_json::Value>,
+    ) -> Result<(), ValidationError> {
+        let missing = self.missing_required_fields(direction, metadata);
+        if !missing.is_empty() {
+            let errors = missing
+                .into_iter()
+                .map(|field| {
+                    FieldError::new(
+                        format!("/metadata/{}", field),
+                        format!("required by link '{}'", self.link_type),
+                    )
+                })
+                .collect();
+            return Err(ValidationError::FieldErrors(errors));
+        }
+        #[cfg(feature = "json-schema")]
+        crate::core::validation::validate_link_metadata(self, metadata)?;
+        Ok(())
+    }
+}
+
+/// Merge `patch` into `current`, recursing into objects present on both sides
+fn merge_json(current: &mut serde_json::Value, patch: serde_json::Value) {
+    match (current, patch) {
+        (serde_json::Value::Object(current), serde_json::Value::Object(patch)) => {
+            for (key, value) in patch {
+                match current.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        current.insert(key, value);
+                    }
+                }
+            }
+        }
+        (current, patch) => *current = patch,
+    }
 }
 
 #[cfg(test)]
@@ -258,6 +726,55 @@ mod tests {
         assert!(link.is_active());
     }
 
+    #[test]
+    fn test_apply_metadata_replaces_or_merges() {
+        let metadata = serde_json::json!({"role": "driver", "since": 2020});
+        let mut link = LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), Some(metadata));
+
+        link.apply_metadata(Some(serde_json::json!({"since": 2021, "notes": "x"})), true);
+        assert_eq!(
+            link.metadata,
+            Some(serde_json::json!({"role": "driver", "since": 2021, "notes": "x"}))
+        );
+
+        link.apply_metadata(None, true);
+        assert!(link.metadata.is_some());
+
+        link.apply_metadata(Some(serde_json::json!({"role": "owner"})), false);
+        assert_eq!(link.metadata, Some(serde_json::json!({"role": "owner"})));
+
+        link.apply_metadata(None, false);
+        assert!(link.metadata.is_none());
+    }
+
+    #[test]
+    fn test_apply_metadata_merges_nested_objects() {
+        let metadata = serde_json::json!({
+            "billing": {"address": "1 Main St", "terms": "net-30"},
+            "tier": "gold"
+        });
+        let mut link = LinkEntity::new("customer", Uuid::new_v4(), Uuid::new_v4(), Some(metadata));
+
+        link.apply_metadata(
+            Some(serde_json::json!({"billing": {"terms": "net-60"}})),
+            true,
+        );
+        assert_eq!(
+            link.metadata,
+            Some(serde_json::json!({
+                "billing": {"address": "1 Main St", "terms": "net-60"},
+                "tier": "gold"
+            }))
+        );
+
+        // A non-object value still replaces the nested object
+        link.apply_metadata(Some(serde_json::json!({"billing": null})), true);
+        assert_eq!(
+            link.metadata,
+            Some(serde_json::json!({"billing": null, "tier": "gold"}))
+        );
+    }
+
     #[test]
     fn test_link_creation_without_tenant() {
         let user_id = Uuid::new_v4();
@@ -369,6 +886,37 @@ mod tests {
         assert!(link.is_active());
     }
 
+    #[test]
+    fn test_project_metadata_keeps_requested_keys() {
+        let mut link = LinkEntity::new(
+            "worker",
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Some(serde_json::json!({"priority": 1, "role": "CTO", "notes": "long text"})),
+        );
+
+        link.project_metadata(&["priority".to_string(), "missing".to_string()]);
+
+        assert_eq!(link.metadata, Some(serde_json::json!({"priority": 1})));
+    }
+
+    #[test]
+    fn test_project_metadata_without_match_becomes_none() {
+        let mut link = LinkEntity::new(
+            "worker",
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Some(serde_json::json!({"role": "CTO"})),
+        );
+
+        link.project_metadata(&["priority".to_string()]);
+        assert!(link.metadata.is_none());
+
+        let mut no_metadata = LinkEntity::new("worker", Uuid::new_v4(), Uuid::new_v4(), None);
+        no_metadata.project_metadata(&["priority".to_string()]);
+        assert!(no_metadata.metadata.is_none());
+    }
+
     #[test]
     fn test_default_route_names() {
         let forward = LinkDefinition::default_forward_route_name("car", "owner");
@@ -426,6 +974,61 @@ mod tests {
         assert_eq!(auth.delete, "admin_only");
     }
 
+    #[test]
+    fn test_required_fields_per_direction() {
+        let yaml = r#"
+            link_type: worker
+            source_type: user
+            target_type: company
+            forward_route_name: companies-work
+            reverse_route_name: users-workers
+            required_fields: [role, since]
+            required_fields_forward: [role]
+            required_fields_reverse: []
+        "#;
+        let def: LinkDefinition = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(def.required_fields_for(LinkDirection::Forward), ["role"]);
+        assert!(def.required_fields_for(LinkDirection::Reverse).is_empty());
+
+        let metadata = serde_json::json!({"role": null});
+        assert_eq!(
+            def.missing_required_fields(LinkDirection::Forward, Some(&metadata)),
+            vec!["role".to_string()]
+        );
+        assert!(
+            def.missing_required_fields(LinkDirection::Reverse, None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_required_fields_fall_back_to_shared_list() {
+        let yaml = r#"
+            link_type: worker
+            source_type: user
+            target_type: company
+            forward_route_name: companies-work
+            reverse_route_name: users-workers
+            required_fields: [role]
+        "#;
+        let def: LinkDefinition = serde_yaml::from_str(yaml).unwrap();
+
+        for direction in [LinkDirection::Forward, LinkDirection::Reverse] {
+            assert_eq!(def.required_fields_for(direction), ["role"]);
+            let metadata = serde_json::json!({"role": "CTO"});
+            assert!(
+                def.missing_required_fields(direction, Some(&metadata))
+                    .is_empty()
+            );
+            let metadata = serde_json::json!({"role": ""});
+            assert_eq!(
+                def.missing_required_fields(direction, Some(&metadata)),
+                ["role"]
+            );
+        }
+    }
+
     #[test]
     fn test_touch_updates_updated_at() {
         let mut link = LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None);
@@ -479,4 +1082,64 @@ mod tests {
         assert_eq!(link.entity_type, "link");
         assert!(link.deleted_at.is_none());
     }
+
+    #[test]
+    fn test_link_filter_parse_and_match() {
+        let filter = serde_json::json!({
+            "status": "expired",
+            "metadata.priority": [1, 2],
+        });
+        let conditions = LinkFilterCondition::parse_all(&filter).unwrap();
+        assert_eq!(conditions.len(), 2);
+
+        let mut link = LinkEntity::new(
+            "driver",
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Some(serde_json::json!({"priority": 2})),
+        );
+        link.status = "expired".to_string();
+        assert!(conditions.iter().all(|c| c.matches(&link)));
+
+        link.metadata = Some(serde_json::json!({"priority": 3}));
+        assert!(!conditions.iter().all(|c| c.matches(&link)));
+    }
+
+    #[test]
+    fn test_link_filter_rejects_unknown_fields_and_values() {
+        for filter in [
+            serde_json::json!({"created_at": "2024-01-01"}),
+            serde_json::json!({"metadata.a.b": 1}),
+            serde_json::json!({"status": null}),
+            serde_json::json!({"status": []}),
+            serde_json::json!(["status"]),
+        ] {
+            assert!(
+                LinkFilterCondition::parse_all(&filter).is_err(),
+                "{} should be rejected",
+                filter
+            );
+        }
+    }
+
+    #[test]
+    fn test_link_limit_counts_live_links_of_same_type() {
+        let user = Uuid::new_v4();
+        let group = Uuid::new_v4();
+        let candidate = LinkEntity::new("member", user, group, None);
+
+        let mut removed = LinkEntity::new("member", user, Uuid::new_v4(), None);
+        removed.soft_delete();
+        let existing = [
+            LinkEntity::new("member", user, Uuid::new_v4(), None),
+            LinkEntity::new("member", Uuid::new_v4(), group, None),
+            LinkEntity::new("owner", user, group, None),
+            removed,
+        ];
+
+        assert_eq!(LinkLimit::count(&candidate, &existing), (1, 1));
+        assert!(LinkLimit::per_source(2).allows(1, 1));
+        assert!(!LinkLimit::per_target(1).allows(0, 1));
+        assert!(LinkLimit::default().allows(usize::MAX, usize::MAX));
+    }
 }
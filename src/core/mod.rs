@@ -1,26 +1,54 @@
 //! Core module containing fundamental traits and types for the framework
 
+pub mod actor;
+pub mod audit;
 pub mod auth;
 pub mod entity;
+pub mod etag;
 pub mod events;
 pub mod extractors;
 pub mod field;
+pub mod health;
+pub mod history;
+pub mod ids;
 pub mod link;
 pub mod module;
+pub mod outbox;
+pub mod ownership;
+pub mod patch;
 pub mod pluralize;
 pub mod query;
+pub mod redaction;
 pub mod service;
+pub mod soft_delete;
 pub mod store;
+pub mod tenant;
+pub mod update_interval;
 pub mod validation;
+pub mod warning;
 
+pub use audit::{AuditEntry, AuditLogService, AuditOperation};
 pub use auth::{AuthContext, AuthPolicy, AuthProvider, NoAuthProvider};
 pub use entity::{Data, Entity, Link};
-pub use events::{EntityEvent, EventBus, EventEnvelope, FrameworkEvent, LinkEvent};
+pub use etag::CacheResult;
+pub use events::{EntityEvent, EventBus, EventEnvelope, FrameworkEvent, LinkEvent, OutboxSources};
 pub use field::{FieldFormat, FieldValue};
-pub use link::{LinkAuthConfig, LinkDefinition};
-pub use module::{EntityCreator, EntityFetcher, Module};
+pub use health::HealthCheck;
+pub use history::{EntityVersion, HistoryError, HistoryService};
+pub use ids::{DefaultIdNormalizer, IdNormalizer, IdStrategy, IdStrategyCreator};
+pub use link::{
+    LinkAuthConfig, LinkCardinality, LinkDefinition, LinkError, LinkFilterCondition,
+    LinkFilterField, LinkLimit, RelationDirection,
+};
+pub use module::{BoxedLayer, EntityCreator, EntityFetcher, HookedCreator, Module};
+pub use outbox::{OutboxEntry, OutboxService};
 pub use pluralize::Pluralizer;
-pub use query::{PaginatedResponse, PaginationMeta, QueryParams};
+pub use query::{
+    Cursor, CursorMeta, CursorPaginatedResponse, FilterClause, FilterOp, PageCursor,
+    PaginatedResponse, PaginationConfig, PaginationMeta, QueryParams,
+};
 pub use service::{DataService, LinkService};
-pub use store::QueryableStore;
+pub use store::{Query, QueryBuilder, QueryOp, QueryableStore, SortDirection};
+pub use tenant::TenantContext;
 pub use validation::{EntityValidationConfig, Validated};
+pub use warning::{PartialResponse, Warning};
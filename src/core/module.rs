@@ -3,12 +3,27 @@
 //! Defines traits for microservice modules
 
 use crate::config::LinksConfig;
+use crate::core::auth::AuthContext;
+use crate::core::entity::ComputedFields;
+use crate::core::etag::{CacheResult, etag_matches, etag_of_json};
+use crate::core::query::Cursor;
+use crate::core::soft_delete::SoftDeleteStatus;
+use crate::core::tenant::TenantContext;
 use crate::server::entity_registry::EntityRegistry;
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Entities fetched at once by the default [`EntityFetcher::fetch_many_as_json`]
+const FETCH_MANY_CONCURRENCY: usize = 10;
+
+/// Entities listed per call by the default [`EntityFetcher::list_after_as_json`]
+pub const LIST_SCAN_PAGE_SIZE: usize = 500;
+
 /// Trait for fetching entities dynamically
 ///
 /// This allows the link system to enrich links with full entity data
@@ -24,6 +39,76 @@ pub trait EntityFetcher: Send + Sync {
     /// The entity serialized as JSON, or an error if not found
     async fn fetch_as_json(&self, entity_id: &Uuid) -> Result<serde_json::Value>;
 
+    /// Fetch an entity by ID, including a soft-deleted one
+    ///
+    /// Lets the REST layers check the tenant and owner of an entity before
+    /// restoring it. Typically backed by `DataService::get_with_deleted`.
+    ///
+    /// Default implementation calls [`fetch_as_json`](Self::fetch_as_json).
+    async fn fetch_with_deleted_as_json(&self, entity_id: &Uuid) -> Result<serde_json::Value> {
+        self.fetch_as_json(entity_id).await
+    }
+
+    /// Fetch an entity by ID if it belongs to `tenant`
+    ///
+    /// Entities of other tenants fail as if they did not exist, like
+    /// [`DataService::get_for_tenant`](crate::core::DataService::get_for_tenant).
+    ///
+    /// Default implementation checks the `tenant_id` of
+    /// [`fetch_as_json`](Self::fetch_as_json).
+    async fn fetch_for_tenant_as_json(
+        &self,
+        entity_id: &Uuid,
+        tenant: &TenantContext,
+    ) -> Result<serde_json::Value> {
+        let entity = self.fetch_as_json(entity_id).await?;
+        if !tenant.owns_json(&entity) {
+            anyhow::bail!("Entity not found: {}", entity_id);
+        }
+        Ok(entity)
+    }
+
+    /// Fetch several entities at once, keyed by ID
+    ///
+    /// Entities that do not exist are absent from the map. Link enrichment
+    /// calls this once per entity type, so backends able to read many rows in
+    /// one round-trip (`IN (...)`, batch gets) should override it.
+    ///
+    /// Default implementation calls [`fetch_as_json`](Self::fetch_as_json)
+    /// for each ID, a few at a time, treating failures as missing entities.
+    async fn fetch_many_as_json(&self, ids: &[Uuid]) -> Result<HashMap<Uuid, serde_json::Value>> {
+        Ok(stream::iter(ids.iter().copied())
+            .map(|id| async move { (id, self.fetch_as_json(&id).await) })
+            .buffer_unordered(FETCH_MANY_CONCURRENCY)
+            .filter_map(|(id, fetched)| async move { Some((id, fetched.ok()?)) })
+            .collect()
+            .await)
+    }
+
+    /// Fetch an entity as JSON only if it changed since the client's ETag
+    ///
+    /// The JSON counterpart of
+    /// [`DataService::get_if_modified`](crate::core::DataService::get_if_modified),
+    /// used by the REST exposure to answer `If-None-Match` and `If-Match`.
+    ///
+    /// Default implementation fetches the entity with
+    /// [`fetch_as_json`](Self::fetch_as_json) and compares the ETag of its
+    /// id, `updated_at` and version ([`etag_of_json`]); entities without
+    /// them are always `Modified`.
+    async fn fetch_if_modified_as_json(
+        &self,
+        entity_id: &Uuid,
+        etag: Option<&str>,
+    ) -> Result<CacheResult<serde_json::Value>> {
+        let entity = self.fetch_as_json(entity_id).await?;
+        match (etag, etag_of_json(&entity)) {
+            (Some(tag), Some(current)) if etag_matches(tag, &current) => {
+                Ok(CacheResult::NotModified)
+            }
+            _ => Ok(CacheResult::Modified(entity)),
+        }
+    }
+
     /// Get a sample entity for schema introspection
     ///
     /// This method returns an entity with all fields populated (can be dummy data)
@@ -51,6 +136,90 @@ pub trait EntityFetcher: Send + Sync {
     ) -> Result<Vec<serde_json::Value>> {
         Ok(vec![])
     }
+
+    /// List up to `limit` entities older than `cursor`, newest first
+    ///
+    /// The JSON counterpart of
+    /// [`DataService::list_after`](crate::core::DataService::list_after),
+    /// used by GraphQL connections and the REST export. Implementations
+    /// should run a keyset query rather than list the whole table, e.g. by
+    /// delegating to `DataService::list_after`.
+    ///
+    /// Default implementation scans [`list_as_json`](Self::list_as_json)
+    /// with offset pages of [`LIST_SCAN_PAGE_SIZE`] entities, keeping the
+    /// `limit` newest entities older than `cursor`; entities without an `id`
+    /// and `created_at` are left out. Each call reads the whole table, but
+    /// never holds more than a page and the result.
+    async fn list_after_as_json(
+        &self,
+        cursor: Option<Cursor>,
+        limit: usize,
+    ) -> Result<Vec<serde_json::Value>> {
+        let mut newest: BTreeMap<Cursor, Value> = BTreeMap::new();
+        let mut offset = 0;
+        loop {
+            let page = self
+                .list_as_json(Some(LIST_SCAN_PAGE_SIZE as i32), Some(offset as i32))
+                .await?;
+            let page_len = page.len();
+            for entity in page {
+                let Some(position) = Cursor::of_json(&entity) else {
+                    continue;
+                };
+                if cursor.is_some_and(|c| position >= c) {
+                    continue;
+                }
+                newest.insert(position, entity);
+                if newest.len() > limit {
+                    newest.pop_first();
+                }
+            }
+            if page_len < LIST_SCAN_PAGE_SIZE {
+                break;
+            }
+            offset += page_len;
+        }
+        Ok(newest.into_values().rev().collect())
+    }
+
+    /// Fields accepted by [`search_as_json`](Self::search_as_json)
+    ///
+    /// Typically `Data::indexed_fields()`. The REST exposure mounts
+    /// `GET /{entity_type}/search` for entity types with searchable fields
+    /// and refuses any other field.
+    ///
+    /// Default implementation returns no fields (not searchable).
+    fn searchable_fields(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Fields the exposures remove before sending entities to `context`
+    ///
+    /// Typically `Data::redacted_fields(context)`.
+    ///
+    /// Default implementation redacts nothing.
+    fn redacted_fields(&self, _context: &AuthContext) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The computed fields of `entity`, given in its stored JSON form
+    ///
+    /// Typically `Data::computed_fields()` of the entity. The REST exposure
+    /// merges them into `GET` responses.
+    ///
+    /// Default implementation computes nothing.
+    fn computed_fields(&self, _entity: &serde_json::Value) -> ComputedFields {
+        ComputedFields::new()
+    }
+
+    /// Find entities whose `field` equals `value`, excluding soft-deleted ones
+    ///
+    /// Typically backed by `DataService::search`.
+    ///
+    /// Default implementation returns an empty list.
+    async fn search_as_json(&self, _field: &str, _value: &str) -> Result<Vec<serde_json::Value>> {
+        Ok(vec![])
+    }
 }
 
 /// Trait for creating entities dynamically
@@ -68,6 +237,26 @@ pub trait EntityCreator: Send + Sync {
     /// The created entity serialized as JSON (with generated ID, timestamps, etc.)
     async fn create_from_json(&self, entity_data: serde_json::Value) -> Result<serde_json::Value>;
 
+    /// Create a new entity owned by the user `owner_id`
+    ///
+    /// Called instead of [`create_from_json`](Self::create_from_json) when
+    /// the caller is a known user; `owner` auth policies compare against it
+    /// (see [`Entity::owner_id`](crate::core::entity::Entity::owner_id)).
+    ///
+    /// Default implementation sets `owner_id` in `entity_data` and calls
+    /// `create_from_json`, which is enough for creators that deserialize the
+    /// whole payload into an entity.
+    async fn create_owned_from_json(
+        &self,
+        mut entity_data: serde_json::Value,
+        owner_id: Uuid,
+    ) -> Result<serde_json::Value> {
+        if let Some(obj) = entity_data.as_object_mut() {
+            obj.insert("owner_id".to_string(), serde_json::json!(owner_id));
+        }
+        self.create_from_json(entity_data).await
+    }
+
     /// Update an existing entity from JSON data
     ///
     /// # Arguments
@@ -88,6 +277,68 @@ pub trait EntityCreator: Send + Sync {
         ))
     }
 
+    /// Apply a partial update to an existing entity
+    ///
+    /// Only the keys present in `partial` change and a `null` value clears
+    /// the field; see [`DataService::patch`](crate::core::DataService::patch).
+    ///
+    /// Default implementation returns [`PatchError::Unsupported`](crate::core::patch::PatchError::Unsupported).
+    async fn patch_from_json(
+        &self,
+        _entity_id: &Uuid,
+        _partial: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        Err(crate::core::patch::PatchError::Unsupported.into())
+    }
+
+    /// Soft-delete an entity, returning it with its `deleted_at` set
+    ///
+    /// See [`DataService::soft_delete`](crate::core::DataService::soft_delete).
+    ///
+    /// Default implementation returns [`SoftDeleteError::Unsupported`](crate::core::soft_delete::SoftDeleteError::Unsupported).
+    async fn soft_delete(&self, _entity_id: &Uuid) -> Result<serde_json::Value> {
+        Err(crate::core::soft_delete::SoftDeleteError::Unsupported.into())
+    }
+
+    /// Restore a soft-deleted entity, returning it
+    ///
+    /// See [`DataService::restore`](crate::core::DataService::restore).
+    ///
+    /// Default implementation returns [`SoftDeleteError::Unsupported`](crate::core::soft_delete::SoftDeleteError::Unsupported).
+    async fn restore(&self, _entity_id: &Uuid) -> Result<serde_json::Value> {
+        Err(crate::core::soft_delete::SoftDeleteError::Unsupported.into())
+    }
+
+    /// Soft-delete an entity, also moving its status to `status.on_delete`
+    ///
+    /// Called instead of [`soft_delete`](Self::soft_delete) once the server
+    /// has a [`SoftDeleteStatus`]; see
+    /// [`DataService::soft_delete_with_status`](crate::core::DataService::soft_delete_with_status).
+    ///
+    /// Default implementation returns [`SoftDeleteError::Unsupported`](crate::core::soft_delete::SoftDeleteError::Unsupported).
+    async fn soft_delete_with_status(
+        &self,
+        _entity_id: &Uuid,
+        _status: &SoftDeleteStatus,
+    ) -> Result<serde_json::Value> {
+        Err(crate::core::soft_delete::SoftDeleteError::Unsupported.into())
+    }
+
+    /// Restore a soft-deleted entity, also reverting its status
+    ///
+    /// Called instead of [`restore`](Self::restore) once the server has a
+    /// [`SoftDeleteStatus`]; see
+    /// [`DataService::restore_with_status`](crate::core::DataService::restore_with_status).
+    ///
+    /// Default implementation returns [`SoftDeleteError::Unsupported`](crate::core::soft_delete::SoftDeleteError::Unsupported).
+    async fn restore_with_status(
+        &self,
+        _entity_id: &Uuid,
+        _status: &SoftDeleteStatus,
+    ) -> Result<serde_json::Value> {
+        Err(crate::core::soft_delete::SoftDeleteError::Unsupported.into())
+    }
+
     /// Delete an entity by ID
     ///
     /// # Arguments
@@ -102,9 +353,38 @@ pub trait EntityCreator: Send + Sync {
             "Delete operation not implemented for this entity type"
         ))
     }
+
+    /// Whether the underlying data service records its events in the outbox
+    ///
+    /// Creators backed by such a service (e.g. `MysqlDataService::with_outbox`)
+    /// forward its [`DataService::writes_outbox`](crate::core::DataService::writes_outbox).
+    fn writes_outbox(&self) -> bool {
+        false
+    }
 }
 
+/// A type-erased tower layer wrapped around a module's routes
+///
+/// See [`Module::layers`]. Build one with [`BoxedLayer::new`], e.g. from
+/// [`axum::middleware::from_fn`].
+pub type BoxedLayer = tower::util::BoxCloneSyncServiceLayer<
+    axum::routing::Route,
+    axum::extract::Request,
+    axum::response::Response,
+    std::convert::Infallible,
+>;
+
 /// Trait for a microservice module
+///
+/// Besides describing its entities, a module can run side effects around
+/// their mutations through the lifecycle hooks (`before_create`,
+/// `after_update`, ...). The hooks run for REST entity routes and for every
+/// mutation going through an [`EntityCreator`] (PATCH, GraphQL, gRPC, linked
+/// entity creation). `before_*` hooks may rewrite the payload before it is
+/// stored, or return an error to abort the mutation; `after_*` hooks see the
+/// stored entity and may rewrite the response. Delete hooks receive
+/// `{"id": ...}`.
+#[async_trait]
 pub trait Module: Send + Sync {
     /// Unique module name
     fn name(&self) -> &str;
@@ -147,4 +427,235 @@ pub trait Module: Send + Sync {
     /// # Returns
     /// An `EntityCreator` implementation, or `None` if the entity type is not managed by this module
     fn get_entity_creator(&self, entity_type: &str) -> Option<Arc<dyn EntityCreator>>;
+
+    /// Tower layers wrapped around the REST routes of this module's entities
+    ///
+    /// Lets a module attach its own auth, logging or request context (e.g.
+    /// an extension its handlers extract). The layers run inside the
+    /// framework's entity middleware, in order: the last one is outermost.
+    /// Link routes are shared by all modules and are not wrapped.
+    fn layers(&self) -> Vec<BoxedLayer> {
+        Vec::new()
+    }
+
+    /// Called with the create payload before it is stored
+    async fn before_create(&self, _entity_type: &str, _entity: &mut Value) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the created entity
+    async fn after_create(&self, _entity_type: &str, _entity: &mut Value) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the update (or patch) payload before it is stored
+    async fn before_update(&self, _entity_type: &str, _entity: &mut Value) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the updated entity
+    async fn after_update(&self, _entity_type: &str, _entity: &mut Value) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with `{"id": ...}` before the entity is deleted
+    async fn before_delete(&self, _entity_type: &str, _entity: &mut Value) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with `{"id": ...}` once the entity is deleted
+    async fn after_delete(&self, _entity_type: &str, _entity: &mut Value) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// [`EntityCreator`] wrapper running a module's lifecycle hooks
+///
+/// Applied by the server builder to every creator, so mutations outside the
+/// REST entity routes run the same hooks.
+pub struct HookedCreator {
+    inner: Arc<dyn EntityCreator>,
+    module: Arc<dyn Module>,
+    entity_type: String,
+}
+
+impl HookedCreator {
+    pub fn new(
+        inner: Arc<dyn EntityCreator>,
+        module: Arc<dyn Module>,
+        entity_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            inner,
+            module,
+            entity_type: entity_type.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl EntityCreator for HookedCreator {
+    async fn create_from_json(&self, mut entity_data: Value) -> Result<Value> {
+        let module = &self.module;
+        module
+            .before_create(&self.entity_type, &mut entity_data)
+            .await?;
+        let mut created = self.inner.create_from_json(entity_data).await?;
+        module.after_create(&self.entity_type, &mut created).await?;
+        Ok(created)
+    }
+
+    async fn create_owned_from_json(
+        &self,
+        mut entity_data: Value,
+        owner_id: Uuid,
+    ) -> Result<Value> {
+        let module = &self.module;
+        module
+            .before_create(&self.entity_type, &mut entity_data)
+            .await?;
+        let mut created = self
+            .inner
+            .create_owned_from_json(entity_data, owner_id)
+            .await?;
+        module.after_create(&self.entity_type, &mut created).await?;
+        Ok(created)
+    }
+
+    async fn update_from_json(&self, entity_id: &Uuid, mut entity_data: Value) -> Result<Value> {
+        let module = &self.module;
+        module
+            .before_update(&self.entity_type, &mut entity_data)
+            .await?;
+        let mut updated = self.inner.update_from_json(entity_id, entity_data).await?;
+        module.after_update(&self.entity_type, &mut updated).await?;
+        Ok(updated)
+    }
+
+    async fn patch_from_json(&self, entity_id: &Uuid, mut partial: Value) -> Result<Value> {
+        let module = &self.module;
+        module
+            .before_update(&self.entity_type, &mut partial)
+            .await?;
+        let mut updated = self.inner.patch_from_json(entity_id, partial).await?;
+        module.after_update(&self.entity_type, &mut updated).await?;
+        Ok(updated)
+    }
+
+    async fn delete(&self, entity_id: &Uuid) -> Result<()> {
+        let mut entity = serde_json::json!({ "id": entity_id });
+        self.module
+            .before_delete(&self.entity_type, &mut entity)
+            .await?;
+        self.inner.delete(entity_id).await?;
+        self.module
+            .after_delete(&self.entity_type, &mut entity)
+            .await
+    }
+
+    /// Not hooked: the REST hooks layer already runs the delete hooks for
+    /// `DELETE /{entity_type}/{id}?soft=true`
+    async fn soft_delete(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.soft_delete(entity_id).await
+    }
+
+    async fn restore(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.restore(entity_id).await
+    }
+
+    async fn soft_delete_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.soft_delete_with_status(entity_id, status).await
+    }
+
+    async fn restore_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.restore_with_status(entity_id, status).await
+    }
+
+    fn writes_outbox(&self) -> bool {
+        self.inner.writes_outbox()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DataService;
+    use crate::storage::InMemoryDataService;
+    use serde_json::json;
+
+    crate::impl_data_entity!(Customer, "customer", ["name"], {});
+
+    /// Creator storing customers in memory
+    struct CustomerCreator(Arc<InMemoryDataService<Customer>>);
+
+    #[async_trait]
+    impl EntityCreator for CustomerCreator {
+        async fn create_from_json(&self, entity_data: Value) -> Result<Value> {
+            let name = entity_data["name"].as_str().unwrap_or_default().to_string();
+            let customer = self.0.create(Customer::new(name, "active".into())).await?;
+            Ok(serde_json::to_value(customer)?)
+        }
+    }
+
+    /// Uppercases `name` before customers are stored
+    struct UppercaseModule;
+
+    #[async_trait]
+    impl Module for UppercaseModule {
+        fn name(&self) -> &str {
+            "uppercase"
+        }
+
+        fn entity_types(&self) -> Vec<&str> {
+            vec!["customer"]
+        }
+
+        fn links_config(&self) -> Result<LinksConfig> {
+            unimplemented!()
+        }
+
+        fn register_entities(&self, _registry: &mut EntityRegistry) {}
+
+        fn get_entity_fetcher(&self, _entity_type: &str) -> Option<Arc<dyn EntityFetcher>> {
+            None
+        }
+
+        fn get_entity_creator(&self, _entity_type: &str) -> Option<Arc<dyn EntityCreator>> {
+            None
+        }
+
+        async fn before_create(&self, _entity_type: &str, entity: &mut Value) -> Result<()> {
+            let name = entity["name"].as_str().unwrap_or_default().to_uppercase();
+            entity["name"] = json!(name);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_before_create_rewrites_stored_entity() {
+        let store = Arc::new(InMemoryDataService::<Customer>::new());
+        let creator = HookedCreator::new(
+            Arc::new(CustomerCreator(store.clone())),
+            Arc::new(UppercaseModule),
+            "customer",
+        );
+
+        let created = creator
+            .create_from_json(json!({"name": "acme"}))
+            .await
+            .unwrap();
+        assert_eq!(created["name"], "ACME");
+
+        let id: Uuid = serde_json::from_value(created["id"].clone()).unwrap();
+        let stored = store.get(&id).await.unwrap().unwrap();
+        assert_eq!(stored.name, "ACME");
+    }
 }
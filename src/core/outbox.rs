@@ -0,0 +1,166 @@
+//! Transactional outbox for reliable event delivery
+//!
+//! [`EventBus`] events live only in memory: if the process dies between a
+//! write and its `publish()`, subscribers never hear of the change. With an
+//! outbox, data services record each mutation's event as a row written in
+//! the same transaction as the change (e.g. `MysqlDataService::with_outbox`),
+//! and a background poller publishes the unsent rows and then marks them
+//! sent. A crash between the two publishes the row again after the restart,
+//! so delivery is at-least-once; the envelope keeps the row's ID, which lets
+//! consumers drop duplicates.
+//!
+//! ```text
+//! update ──▶ [entities + outbox, one transaction]
+//!                               │
+//!          poll_outbox() ───────┴──▶ EventBus::deliver() ──▶ mark_sent()
+//! ```
+
+use crate::core::events::{EventBus, EventEnvelope, FrameworkEvent};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// How often the poller started by `ServerBuilder::with_outbox` looks for unsent rows
+pub const DEFAULT_OUTBOX_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Most rows published per poll cycle
+pub const DEFAULT_OUTBOX_BATCH_SIZE: usize = 100;
+
+/// An event waiting in (or delivered from) the outbox
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Row ID, reused as the delivered envelope's ID
+    pub id: Uuid,
+
+    pub event: FrameworkEvent,
+
+    /// When the mutation was committed
+    pub created_at: DateTime<Utc>,
+
+    /// When the event was published, `None` while pending
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxEntry {
+    /// A pending entry for `event`
+    pub fn new(event: FrameworkEvent) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event,
+            created_at: Utc::now(),
+            sent_at: None,
+        }
+    }
+
+    /// The envelope subscribers receive for this entry
+    pub fn envelope(&self) -> EventEnvelope {
+        EventEnvelope {
+            id: self.id,
+            timestamp: self.created_at,
+            event: self.event.clone(),
+            seq_no: None,
+        }
+    }
+}
+
+/// Storage for outbox rows
+///
+/// Data services with an outbox insert rows inside their own transactions;
+/// [`enqueue`](Self::enqueue) is for writers without one.
+#[async_trait]
+pub trait OutboxService: Send + Sync {
+    /// Append a pending entry for `event`
+    async fn enqueue(&self, event: FrameworkEvent) -> Result<OutboxEntry>;
+
+    /// Up to `limit` pending entries, oldest first
+    async fn unsent(&self, limit: usize) -> Result<Vec<OutboxEntry>>;
+
+    /// Mark entries as published
+    async fn mark_sent(&self, ids: &[Uuid]) -> Result<()>;
+}
+
+/// Run one poll cycle: publish up to `batch_size` pending entries, then mark them sent
+///
+/// Returns the number of entries published.
+pub async fn poll_outbox(
+    outbox: &dyn OutboxService,
+    event_bus: &EventBus,
+    batch_size: usize,
+) -> Result<usize> {
+    let entries = outbox.unsent(batch_size).await?;
+    if entries.is_empty() {
+        return Ok(0);
+    }
+    for entry in &entries {
+        event_bus.deliver(entry.envelope());
+    }
+    let ids: Vec<Uuid> = entries.iter().map(|entry| entry.id).collect();
+    outbox.mark_sent(&ids).await?;
+    Ok(ids.len())
+}
+
+/// Poll the outbox every `interval` until the task is aborted
+///
+/// A full batch is followed by another cycle right away. Failed cycles are
+/// logged and retried on the next tick.
+pub fn spawn_outbox_poller(
+    outbox: Arc<dyn OutboxService>,
+    event_bus: Arc<EventBus>,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            loop {
+                match poll_outbox(outbox.as_ref(), &event_bus, DEFAULT_OUTBOX_BATCH_SIZE).await {
+                    Ok(n) if n == DEFAULT_OUTBOX_BATCH_SIZE => continue,
+                    Ok(_) => break,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "outbox poll failed");
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::EntityEvent;
+    use crate::storage::InMemoryOutboxService;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_poll_publishes_unsent_rows_and_marks_them_sent() {
+        let outbox = InMemoryOutboxService::new();
+        let bus = EventBus::new(16);
+        let mut rx = bus.subscribe();
+
+        let entry = outbox
+            .enqueue(FrameworkEvent::Entity(EntityEvent::Created {
+                entity_type: "order".to_string(),
+                entity_id: Uuid::new_v4(),
+                data: json!({"amount": 10}),
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(poll_outbox(&outbox, &bus, 10).await.unwrap(), 1);
+
+        let delivered = rx.try_recv().unwrap();
+        assert_eq!(delivered.id, entry.id);
+        assert_eq!(delivered.event.action(), "created");
+        assert!(outbox.unsent(10).await.unwrap().is_empty());
+
+        // Nothing left: the next cycle publishes nothing
+        assert_eq!(poll_outbox(&outbox, &bus, 10).await.unwrap(), 0);
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! Entity ownership, shared by every exposure
+//!
+//! An entity records the user who created it in its `owner_id` field (see
+//! [`Entity::owner_id`](crate::core::entity::Entity::owner_id)). Clients
+//! never write that field: creation sets it from the caller, and updates
+//! keep the stored one. The `owner` auth policy then compares the caller
+//! against it (see [`AuthPolicy::evaluate_for_owner`]).
+//!
+//! [`ServerHost`](crate::server::host::ServerHost) applies these rules to
+//! GraphQL and gRPC mutations, and the REST ownership layer to entity
+//! routes.
+
+use crate::core::auth::{AuthContext, AuthPolicy};
+use crate::core::module::EntityCreator;
+use anyhow::Result;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Field of an entity holding its owner's user id
+pub const OWNER_FIELD: &str = "owner_id";
+
+/// Error returned when the caller does not own the entity a policy requires
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum OwnershipError {
+    /// The caller is anonymous
+    #[error("authentication required")]
+    Unauthenticated,
+    /// The caller is known but the policy rejects them
+    #[error("{0} is not allowed")]
+    Forbidden(String),
+}
+
+/// Parse `policy`, keeping it only when deciding it requires the owner
+pub fn owner_policy(policy: &str) -> Option<AuthPolicy> {
+    let policy = AuthPolicy::parse_policy(policy);
+    policy.involves_owner().then_some(policy)
+}
+
+/// Owner recorded in a stored entity
+pub fn stored_owner(entity: &Value) -> Option<Uuid> {
+    entity
+        .get(OWNER_FIELD)
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+}
+
+/// Set the `owner_id` of a JSON object, or drop it when `owner_id` is `None`
+///
+/// Returns `false`, leaving `data` unchanged, when it is not an object.
+pub fn set_owner(data: &mut Value, owner_id: Option<Uuid>) -> bool {
+    let Some(obj) = data.as_object_mut() else {
+        return false;
+    };
+    match owner_id {
+        Some(owner_id) => obj.insert(OWNER_FIELD.to_string(), Value::String(owner_id.to_string())),
+        None => obj.remove(OWNER_FIELD),
+    };
+    true
+}
+
+/// Check `policy` for the caller against an entity owned by `owner_id`
+///
+/// `operation` names the rejected operation in the error, e.g.
+/// `"PUT /notes/{id}"` or `"updateNote"`.
+pub fn check_owner(
+    policy: &AuthPolicy,
+    context: &AuthContext,
+    owner_id: Option<Uuid>,
+    operation: &str,
+) -> Result<(), OwnershipError> {
+    if policy.evaluate_for_owner(context, owner_id, operation) {
+        return Ok(());
+    }
+    match context {
+        AuthContext::Anonymous => Err(OwnershipError::Unauthenticated),
+        _ => Err(OwnershipError::Forbidden(operation.to_string())),
+    }
+}
+
+/// Create an entity owned by `owner_id`
+///
+/// A client-supplied `owner_id` in `data` is dropped first; without an
+/// owner the entity is created unowned.
+pub async fn create_owned(
+    creator: &dyn EntityCreator,
+    mut data: Value,
+    owner_id: Option<Uuid>,
+) -> Result<Value> {
+    set_owner(&mut data, None);
+    match owner_id {
+        Some(owner_id) => creator.create_owned_from_json(data, owner_id).await,
+        None => creator.create_from_json(data).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_set_owner_replaces_or_drops_the_owner() {
+        let owner = Uuid::new_v4();
+        let mut data = json!({ "name": "a", "owner_id": Uuid::new_v4() });
+
+        assert!(set_owner(&mut data, Some(owner)));
+        assert_eq!(stored_owner(&data), Some(owner));
+
+        assert!(set_owner(&mut data, None));
+        assert_eq!(data, json!({ "name": "a" }));
+
+        let mut list = json!([1, 2]);
+        assert!(!set_owner(&mut list, Some(owner)));
+        assert_eq!(list, json!([1, 2]));
+    }
+
+    #[test]
+    fn test_check_owner_tells_anonymous_and_other_callers_apart() {
+        let owner = Uuid::new_v4();
+        let policy = owner_policy("owner").expect("owner policy");
+        let user = |user_id| AuthContext::User {
+            user_id,
+            tenant_id: Uuid::new_v4(),
+            roles: vec![],
+        };
+
+        assert_eq!(
+            check_owner(&policy, &user(owner), Some(owner), "get"),
+            Ok(())
+        );
+        assert_eq!(
+            check_owner(&policy, &user(Uuid::new_v4()), Some(owner), "get"),
+            Err(OwnershipError::Forbidden("get".to_string()))
+        );
+        assert_eq!(
+            check_owner(&policy, &AuthContext::Anonymous, Some(owner), "get"),
+            Err(OwnershipError::Unauthenticated)
+        );
+        assert!(owner_policy("authenticated").is_none());
+    }
+}
@@ -0,0 +1,153 @@
+//! Partial updates (PATCH semantics) for entities
+//!
+//! A patch is a JSON object whose keys replace the matching top-level fields
+//! of the stored entity. Absent keys are left untouched, and a key set to
+//! `null` clears the field. Nested objects are replaced as a whole.
+
+use chrono::Utc;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Fields a patch never changes
+///
+/// Every field the server manages: `updated_at` is bumped by the patch
+/// itself, and soft deletion has its own lifecycle. An entity stays with the
+/// tenant and owner it was created for, `created_by`/`updated_by` are stamped
+/// from the request's actor (see [`crate::core::actor`]), and `version` is
+/// bumped by the backend on every update.
+pub const READ_ONLY_FIELDS: &[&str] = &[
+    "id",
+    "type",
+    "entity_type",
+    "created_at",
+    "updated_at",
+    "deleted_at",
+    "tenant_id",
+    "owner_id",
+    "created_by",
+    "updated_by",
+    "version",
+];
+
+/// Error returned when a patch cannot be applied
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PatchError {
+    /// No live entity exists with this ID
+    #[error("entity not found: {0}")]
+    NotFound(Uuid),
+    /// The patch body is not a JSON object
+    #[error("patch body must be a JSON object")]
+    NotAnObject,
+    /// The merged document no longer deserializes into the entity type,
+    /// e.g. because a required field was set to `null`
+    #[error("patched entity is invalid: {0}")]
+    Invalid(String),
+    /// The entity type does not support partial updates
+    #[error("patch is not supported for this entity type")]
+    Unsupported,
+}
+
+/// Merge `patch` into the serialized entity `stored` and bump `updated_at`
+///
+/// Keys listed in [`READ_ONLY_FIELDS`] are ignored.
+pub fn merge_patch(stored: &mut Value, patch: &Value) -> Result<(), PatchError> {
+    let Some(patch) = patch.as_object() else {
+        return Err(PatchError::NotAnObject);
+    };
+    let Some(stored) = stored.as_object_mut() else {
+        return Err(PatchError::Invalid(
+            "stored entity is not a JSON object".to_string(),
+        ));
+    };
+
+    for (key, value) in patch {
+        if !READ_ONLY_FIELDS.contains(&key.as_str()) {
+            stored.insert(key.clone(), value.clone());
+        }
+    }
+    stored.insert("updated_at".to_string(), serde_json::json!(Utc::now()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_absent_keys_are_untouched_and_null_clears() {
+        let mut stored = json!({"id": "a", "name": "Ada", "email": "ada@example.com", "age": 36});
+        merge_patch(&mut stored, &json!({"name": "Ada L.", "email": null})).unwrap();
+
+        assert_eq!(stored["name"], "Ada L.");
+        assert_eq!(stored["email"], Value::Null);
+        assert_eq!(stored["age"], 36);
+        assert!(stored["updated_at"].is_string());
+    }
+
+    #[test]
+    fn test_read_only_fields_are_ignored() {
+        let mut stored = json!({"id": "a", "created_at": "2024-01-01T00:00:00Z"});
+        merge_patch(
+            &mut stored,
+            &json!({"id": "b", "created_at": "2000-01-01T00:00:00Z", "deleted_at": "x"}),
+        )
+        .unwrap();
+
+        assert_eq!(stored["id"], "a");
+        assert_eq!(stored["created_at"], "2024-01-01T00:00:00Z");
+        assert!(stored.get("deleted_at").is_none());
+    }
+
+    /// Patch `field` from `stored` to `client` and check the stored value won
+    fn assert_ignored(field: &str, stored: Value, client: Value) {
+        let mut entity = json!({"id": "a", "name": "Ada"});
+        entity[field] = stored.clone();
+        merge_patch(&mut entity, &json!({field: client, "name": "Ada L."})).unwrap();
+
+        assert_eq!(entity[field], stored, "{field} was patched");
+        assert_eq!(entity["name"], "Ada L.");
+    }
+
+    #[test]
+    fn test_tenant_id_is_ignored() {
+        assert_ignored(
+            "tenant_id",
+            json!("5f0c7b9e-8a4d-4c1e-9a53-1f0e4f1b2c3d"),
+            json!("0b6c1a2e-3d4f-4a5b-8c6d-7e8f9a0b1c2d"),
+        );
+    }
+
+    #[test]
+    fn test_owner_id_is_ignored() {
+        assert_ignored(
+            "owner_id",
+            json!("5f0c7b9e-8a4d-4c1e-9a53-1f0e4f1b2c3d"),
+            json!("0b6c1a2e-3d4f-4a5b-8c6d-7e8f9a0b1c2d"),
+        );
+    }
+
+    #[test]
+    fn test_created_by_is_ignored() {
+        assert_ignored("created_by", json!("user:alice"), json!("user:mallory"));
+    }
+
+    #[test]
+    fn test_updated_by_is_ignored() {
+        assert_ignored("updated_by", json!("user:alice"), json!("user:mallory"));
+    }
+
+    #[test]
+    fn test_version_is_ignored() {
+        assert_ignored("version", json!(3), json!(99));
+    }
+
+    #[test]
+    fn test_non_object_patch_is_rejected() {
+        let mut stored = json!({"id": "a"});
+        assert_eq!(
+            merge_patch(&mut stored, &json!(["name"])),
+            Err(PatchError::NotAnObject)
+        );
+    }
+}
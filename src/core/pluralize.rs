@@ -1,11 +1,122 @@
 //! Intelligent pluralization for English nouns
 //!
 //! Handles common English pluralization rules including irregular forms
+//! and uncountable nouns. The same [`Pluralizer`] drives route generation,
+//! so applications can use it to build names that match the framework's.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// Irregular singular/plural pairs known to every [`Pluralizer`]
+const IRREGULARS: &[(&str, &str)] = &[
+    ("person", "people"),
+    ("child", "children"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("mouse", "mice"),
+    ("goose", "geese"),
+    ("tooth", "teeth"),
+    ("foot", "feet"),
+    ("ox", "oxen"),
+];
+
+/// Nouns whose plural is identical to their singular
+const UNCOUNTABLES: &[&str] = &[
+    "sheep",
+    "fish",
+    "deer",
+    "series",
+    "species",
+    "news",
+    "equipment",
+    "information",
+];
+
+static STANDARD: LazyLock<Pluralizer> = LazyLock::new(Pluralizer::new);
 
 /// Utility for converting between singular and plural forms of English nouns
-pub struct Pluralizer;
+///
+/// Irregular and uncountable nouns are looked up first; everything else goes
+/// through the suffix rules (`s`, `es`, `ies`, `ves`).
+///
+/// The irregular table can be extended with [`Pluralizer::with_overrides`] or
+/// [`Pluralizer::register_irregular`], which take precedence over both the
+/// built-in tables and the rules. Irregulars are looked up ignoring case and
+/// answer with the form exactly as registered.
+///
+/// # Examples
+///
+/// ```
+/// use this::core::pluralize::Pluralizer;
+///
+/// let pluralizer = Pluralizer::new();
+/// assert_eq!(pluralizer.pluralize("company"), "companies");
+/// assert_eq!(pluralizer.pluralize("person"), "people");
+/// assert_eq!(pluralizer.singularize("people"), "person");
+/// assert_eq!(pluralizer.pluralize("sheep"), "sheep");
+///
+/// let pluralizer = Pluralizer::with_overrides([("datum".to_string(), "data".to_string())].into());
+/// assert_eq!(pluralizer.pluralize("datum"), "data");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pluralizer {
+    /// lowercased singular -> plural
+    irregular_plurals: HashMap<String, String>,
+    /// lowercased plural -> singular
+    irregular_singulars: HashMap<String, String>,
+    uncountables: HashSet<String>,
+}
+
+impl Default for Pluralizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Pluralizer {
+    /// Create a pluralizer with the built-in irregular and uncountable tables
+    pub fn new() -> Self {
+        Self {
+            irregular_plurals: IRREGULARS
+                .iter()
+                .map(|(s, p)| (s.to_string(), p.to_string()))
+                .collect(),
+            irregular_singulars: IRREGULARS
+                .iter()
+                .map(|(s, p)| (p.to_string(), s.to_string()))
+                .collect(),
+            uncountables: UNCOUNTABLES.iter().map(|w| w.to_string()).collect(),
+        }
+    }
+
+    /// Create a pluralizer with the built-in tables plus `overrides` (singular -> plural)
+    pub fn with_overrides(overrides: HashMap<String, String>) -> Self {
+        let mut pluralizer = Self::new();
+        for (singular, plural) in overrides {
+            pluralizer.register_irregular(singular, plural);
+        }
+        pluralizer
+    }
+
+    /// Register an irregular pair, replacing any previous entry for either form
+    pub fn register_irregular(
+        &mut self,
+        singular: impl Into<String>,
+        plural: impl Into<String>,
+    ) -> &mut Self {
+        let (singular, plural) = (singular.into(), plural.into());
+        self.irregular_singulars
+            .insert(plural.to_lowercase(), singular.clone());
+        self.irregular_plurals
+            .insert(singular.to_lowercase(), plural);
+        self
+    }
+
+    /// The shared pluralizer used by the framework for route names
+    pub fn standard() -> &'static Pluralizer {
+        &STANDARD
+    }
+
     /// Convert a singular noun to its plural form
     ///
     /// # Examples
@@ -13,12 +124,47 @@ impl Pluralizer {
     /// ```
     /// use this::core::pluralize::Pluralizer;
     ///
-    /// assert_eq!(Pluralizer::pluralize("user"), "users");
-    /// assert_eq!(Pluralizer::pluralize("company"), "companies");
-    /// assert_eq!(Pluralizer::pluralize("address"), "addresses");
-    /// assert_eq!(Pluralizer::pluralize("knife"), "knives");
+    /// let pluralizer = Pluralizer::new();
+    /// assert_eq!(pluralizer.pluralize("user"), "users");
+    /// assert_eq!(pluralizer.pluralize("company"), "companies");
+    /// assert_eq!(pluralizer.pluralize("address"), "addresses");
+    /// assert_eq!(pluralizer.pluralize("knife"), "knives");
+    /// assert_eq!(pluralizer.pluralize("child"), "children");
+    /// ```
+    pub fn pluralize(&self, singular: &str) -> String {
+        if let Some(plural) = self.irregular_plurals.get(&singular.to_lowercase()) {
+            return plural.clone();
+        }
+        if self.uncountables.contains(singular) {
+            return singular.to_string();
+        }
+        Self::pluralize_by_rules(singular)
+    }
+
+    /// Convert a plural noun to its singular form
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use this::core::pluralize::Pluralizer;
+    ///
+    /// let pluralizer = Pluralizer::new();
+    /// assert_eq!(pluralizer.singularize("users"), "user");
+    /// assert_eq!(pluralizer.singularize("companies"), "company");
+    /// assert_eq!(pluralizer.singularize("addresses"), "address");
+    /// assert_eq!(pluralizer.singularize("children"), "child");
     /// ```
-    pub fn pluralize(singular: &str) -> String {
+    pub fn singularize(&self, plural: &str) -> String {
+        if let Some(singular) = self.irregular_singulars.get(&plural.to_lowercase()) {
+            return singular.clone();
+        }
+        if self.uncountables.contains(plural) {
+            return plural.to_string();
+        }
+        Self::singularize_by_rules(plural)
+    }
+
+    fn pluralize_by_rules(singular: &str) -> String {
         // Handle empty strings
         if singular.is_empty() {
             return singular.to_string();
@@ -77,18 +223,7 @@ impl Pluralizer {
         }
     }
 
-    /// Convert a plural noun to its singular form
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use this::core::pluralize::Pluralizer;
-    ///
-    /// assert_eq!(Pluralizer::singularize("users"), "user");
-    /// assert_eq!(Pluralizer::singularize("companies"), "company");
-    /// assert_eq!(Pluralizer::singularize("addresses"), "address");
-    /// ```
-    pub fn singularize(plural: &str) -> String {
+    fn singularize_by_rules(plural: &str) -> String {
         // Handle empty strings
         if plural.is_empty() {
             return plural.to_string();
@@ -132,102 +267,187 @@ impl Pluralizer {
 mod tests {
     use super::*;
 
+    fn p() -> Pluralizer {
+        Pluralizer::new()
+    }
+
     #[test]
     fn test_pluralize_regular() {
-        assert_eq!(Pluralizer::pluralize("user"), "users");
-        assert_eq!(Pluralizer::pluralize("car"), "cars");
-        assert_eq!(Pluralizer::pluralize("dog"), "dogs");
+        assert_eq!(p().pluralize("user"), "users");
+        assert_eq!(p().pluralize("car"), "cars");
+        assert_eq!(p().pluralize("dog"), "dogs");
     }
 
     #[test]
     fn test_pluralize_y_ending() {
-        assert_eq!(Pluralizer::pluralize("company"), "companies");
-        assert_eq!(Pluralizer::pluralize("category"), "categories");
-        assert_eq!(Pluralizer::pluralize("fly"), "flies");
+        assert_eq!(p().pluralize("company"), "companies");
+        assert_eq!(p().pluralize("category"), "categories");
+        assert_eq!(p().pluralize("fly"), "flies");
 
         // Vowel + y = just add s
-        assert_eq!(Pluralizer::pluralize("day"), "days");
-        assert_eq!(Pluralizer::pluralize("key"), "keys");
+        assert_eq!(p().pluralize("day"), "days");
+        assert_eq!(p().pluralize("key"), "keys");
     }
 
     #[test]
     fn test_pluralize_sibilants() {
-        assert_eq!(Pluralizer::pluralize("address"), "addresses");
-        assert_eq!(Pluralizer::pluralize("box"), "boxes");
-        assert_eq!(Pluralizer::pluralize("buzz"), "buzzes");
-        assert_eq!(Pluralizer::pluralize("church"), "churches");
-        assert_eq!(Pluralizer::pluralize("dish"), "dishes");
+        assert_eq!(p().pluralize("address"), "addresses");
+        assert_eq!(p().pluralize("box"), "boxes");
+        assert_eq!(p().pluralize("buzz"), "buzzes");
+        assert_eq!(p().pluralize("church"), "churches");
+        assert_eq!(p().pluralize("dish"), "dishes");
     }
 
     #[test]
     fn test_pluralize_f_endings() {
-        assert_eq!(Pluralizer::pluralize("knife"), "knives");
-        assert_eq!(Pluralizer::pluralize("life"), "lives");
-        assert_eq!(Pluralizer::pluralize("wolf"), "wolves");
+        assert_eq!(p().pluralize("knife"), "knives");
+        assert_eq!(p().pluralize("life"), "lives");
+        assert_eq!(p().pluralize("wolf"), "wolves");
     }
 
     #[test]
     fn test_pluralize_o_endings() {
-        assert_eq!(Pluralizer::pluralize("hero"), "heroes");
-        assert_eq!(Pluralizer::pluralize("potato"), "potatoes");
+        assert_eq!(p().pluralize("hero"), "heroes");
+        assert_eq!(p().pluralize("potato"), "potatoes");
 
         // Exceptions
-        assert_eq!(Pluralizer::pluralize("photo"), "photos");
-        assert_eq!(Pluralizer::pluralize("piano"), "pianos");
+        assert_eq!(p().pluralize("photo"), "photos");
+        assert_eq!(p().pluralize("piano"), "pianos");
     }
 
     #[test]
     fn test_singularize_regular() {
-        assert_eq!(Pluralizer::singularize("users"), "user");
-        assert_eq!(Pluralizer::singularize("cars"), "car");
-        assert_eq!(Pluralizer::singularize("dogs"), "dog");
+        assert_eq!(p().singularize("users"), "user");
+        assert_eq!(p().singularize("cars"), "car");
+        assert_eq!(p().singularize("dogs"), "dog");
     }
 
     #[test]
     fn test_singularize_ies() {
-        assert_eq!(Pluralizer::singularize("companies"), "company");
-        assert_eq!(Pluralizer::singularize("categories"), "category");
-        assert_eq!(Pluralizer::singularize("flies"), "fly");
+        assert_eq!(p().singularize("companies"), "company");
+        assert_eq!(p().singularize("categories"), "category");
+        assert_eq!(p().singularize("flies"), "fly");
     }
 
     #[test]
     fn test_singularize_sibilants() {
-        assert_eq!(Pluralizer::singularize("addresses"), "address");
-        assert_eq!(Pluralizer::singularize("boxes"), "box");
-        assert_eq!(Pluralizer::singularize("buzzes"), "buzz");
+        assert_eq!(p().singularize("addresses"), "address");
+        assert_eq!(p().singularize("boxes"), "box");
+        assert_eq!(p().singularize("buzzes"), "buzz");
     }
 
     #[test]
     fn test_singularize_ves() {
-        assert_eq!(Pluralizer::singularize("knives"), "knif");
-        assert_eq!(Pluralizer::singularize("lives"), "lif");
+        assert_eq!(p().singularize("knives"), "knif");
+        assert_eq!(p().singularize("lives"), "lif");
     }
 
     #[test]
     fn test_roundtrip() {
         let words = vec!["user", "company", "address", "box", "day"];
         for word in words {
-            let plural = Pluralizer::pluralize(word);
-            let back_to_singular = Pluralizer::singularize(&plural);
+            let plural = p().pluralize(word);
+            let back_to_singular = p().singularize(&plural);
             assert_eq!(word, back_to_singular, "Roundtrip failed for: {}", word);
         }
     }
 
     #[test]
     fn test_pluralize_empty_string() {
-        assert_eq!(Pluralizer::pluralize(""), "");
+        assert_eq!(p().pluralize(""), "");
     }
 
     #[test]
     fn test_singularize_empty_string() {
-        assert_eq!(Pluralizer::singularize(""), "");
+        assert_eq!(p().singularize(""), "");
     }
 
     #[test]
     fn test_singularize_word_not_ending_in_s() {
         // A word that does not end in "s" should be returned unchanged
-        assert_eq!(Pluralizer::singularize("child"), "child");
-        assert_eq!(Pluralizer::singularize("deer"), "deer");
-        assert_eq!(Pluralizer::singularize("x"), "x");
+        assert_eq!(p().singularize("child"), "child");
+        assert_eq!(p().singularize("deer"), "deer");
+        assert_eq!(p().singularize("x"), "x");
+    }
+
+    #[test]
+    fn test_pluralize_irregulars() {
+        assert_eq!(p().pluralize("person"), "people");
+        assert_eq!(p().pluralize("child"), "children");
+        assert_eq!(p().pluralize("mouse"), "mice");
+        assert_eq!(p().pluralize("foot"), "feet");
+    }
+
+    #[test]
+    fn test_singularize_irregulars() {
+        assert_eq!(p().singularize("people"), "person");
+        assert_eq!(p().singularize("children"), "child");
+        assert_eq!(p().singularize("women"), "woman");
+        assert_eq!(p().singularize("oxen"), "ox");
+    }
+
+    #[test]
+    fn test_uncountables_are_unchanged() {
+        for word in ["sheep", "fish", "series", "news", "information"] {
+            assert_eq!(p().pluralize(word), word);
+            assert_eq!(p().singularize(word), word);
+        }
+    }
+
+    #[test]
+    fn test_pluralize_s_and_es_suffixes() {
+        assert_eq!(p().pluralize("bus"), "buses");
+        assert_eq!(p().pluralize("tax"), "taxes");
+        assert_eq!(p().pluralize("order"), "orders");
+        assert_eq!(p().singularize("buses"), "bus");
+        assert_eq!(p().singularize("heroes"), "hero");
+    }
+
+    fn overridden() -> Pluralizer {
+        Pluralizer::with_overrides(HashMap::from([
+            ("person".to_string(), "people".to_string()),
+            ("child".to_string(), "children".to_string()),
+            ("Cactus".to_string(), "Cacti".to_string()),
+        ]))
+    }
+
+    #[test]
+    fn test_overrides_replace_rules() {
+        let pluralizer = overridden();
+        assert_eq!(pluralizer.pluralize("person"), "people");
+        assert_eq!(pluralizer.pluralize("child"), "children");
+        assert_eq!(pluralizer.singularize("people"), "person");
+        assert_eq!(pluralizer.singularize("children"), "child");
+
+        // Words without an override still follow the rules
+        assert_eq!(pluralizer.pluralize("company"), "companies");
+        assert_eq!(pluralizer.pluralize("user"), "users");
+    }
+
+    #[test]
+    fn test_overrides_match_any_case_and_keep_stored_casing() {
+        let pluralizer = overridden();
+        assert_eq!(pluralizer.pluralize("cactus"), "Cacti");
+        assert_eq!(pluralizer.pluralize("CACTUS"), "Cacti");
+        assert_eq!(pluralizer.singularize("cacti"), "Cactus");
+    }
+
+    #[test]
+    fn test_register_irregular_takes_precedence() {
+        let mut pluralizer = p();
+        pluralizer
+            .register_irregular("datum", "data")
+            .register_irregular("fish", "fishes");
+        assert_eq!(pluralizer.pluralize("datum"), "data");
+        assert_eq!(pluralizer.singularize("data"), "datum");
+        assert_eq!(pluralizer.pluralize("fish"), "fishes");
+        assert_eq!(p().pluralize("datum"), "datums");
+    }
+
+    #[test]
+    fn test_standard_matches_new() {
+        for word in ["user", "company", "person", "sheep", "knife"] {
+            assert_eq!(Pluralizer::standard().pluralize(word), p().pluralize(word));
+        }
     }
 }
@@ -1,7 +1,17 @@
 //! Query parameters and pagination utilities
 
+use crate::core::entity::{Data, Entity};
+use crate::core::field::FieldValue;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::de::{Deserializer, IgnoredAny, MapAccess, Visitor};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use uuid::Uuid;
 
 /// Query parameters for pagination and filtering
 ///
@@ -14,69 +24,520 @@ use serde_json::Value;
 /// pub async fn list_items(
 ///     Query(params): Query<QueryParams>,
 /// ) -> Json<PaginatedResponse<Item>> {
-///     // params.page defaults to 1
-///     // params.limit defaults to 20
+///     // params.page() defaults to 1
+///     // params.limit() defaults to 20, at most 100 (see PaginationConfig)
 /// }
 ///
 /// // Usage:
 /// GET /items?page=2&limit=10
 /// GET /items?filter={"status": "active"}
-/// GET /items?page=1&limit=20&filter={"amount>": 100}&sort=created_at:desc
+/// GET /items?page=1&limit=20&filter={"amount": {"$gt": 100}}&sort=created_at:desc
+/// GET /items?filter[status]=active&filter[status]=pending
+/// GET /items?limit=20&after=MjAyNC0wMS0wMVQwMDowMDowMC4wMDAwMDAwMDBafDEyMzQ
 /// ```
-#[derive(Debug, Clone, Deserialize)]
-#[serde(default)]
+#[derive(Debug, Clone)]
 pub struct QueryParams {
     /// Page number (starts at 1)
-    #[serde(default = "default_page")]
     pub page: usize,
 
-    /// Number of items per page
-    #[serde(default = "default_limit")]
+    /// Number of items per page, `0` when not given
+    ///
+    /// Read it through [`QueryParams::limit`], which applies the
+    /// [`PaginationConfig`] default and maximum.
     pub limit: usize,
 
     /// Filters as JSON object
     ///
     /// # Format
     /// - Exact match: `{"field": "value"}`
-    /// - Comparison: `{"field>": value, "field<": value, "field>=": value, "field<=": value}`
+    /// - IN set: `{"field": ["a", "b"]}` or `{"field": {"$in": ["a", "b"]}}`
+    /// - Operators: `$gt`, `$gte`, `$lt`, `$lte`, `$ne`, `$contains`
+    ///
+    /// Parsed into clauses by [`FilterClause::parse_all`].
     ///
     /// # Example
     /// ```text
-    /// filter={"status": "active", "amount>": 100, "customer_name": "Acme"}
+    /// filter={"status": "active", "amount": {"$gt": 100}, "customer_name": "Acme"}
     /// ```
     pub filter: Option<String>,
 
-    /// Sort field and direction
+    /// Sort fields and directions, in priority order
     ///
     /// # Format
     /// - `field:asc` or `field` (ascending)
     /// - `field:desc` (descending)
+    /// - comma-separated keys, later keys breaking ties of earlier ones
+    ///
+    /// Parsed into keys by [`QueryParams::sort_keys`].
     ///
     /// # Example
     /// ```text
     /// sort=amount:desc
     /// sort=created_at:asc
+    /// sort=status:asc,age:desc
     /// ```
     pub sort: Option<String>,
+
+    /// Link field projection (link list endpoints only)
+    ///
+    /// Comma-separated list of `metadata.<key>` paths. When present, only
+    /// the listed metadata keys are returned for each link; the link's own
+    /// fields (id, type, link_type, ...) are always included.
+    ///
+    /// # Example
+    /// ```text
+    /// link_fields=metadata.priority
+    /// link_fields=metadata.priority,metadata.role
+    /// ```
+    pub link_fields: Option<String>,
+
+    /// Cursor of the last item seen; requests the next (older) page
+    ///
+    /// An opaque token from [`CursorMeta::next_cursor`]. Takes the place of
+    /// `page`; see [`QueryParams::cursor`].
+    pub after: Option<String>,
+
+    /// Cursor of the first item seen; requests the previous (newer) page
+    ///
+    /// An opaque token from [`CursorMeta::prev_cursor`].
+    pub before: Option<String>,
+
+    /// Validate a nested link path without fetching anything
+    ///
+    /// Only read by the nested path handler, which then answers whether
+    /// every link of the chain exists.
+    pub dry_run: bool,
+
+    /// `filter[field]=value` parameters, grouped by field
+    ///
+    /// Only parameters of that form are field filters; other parameters the
+    /// endpoint does not read are ignored. Repeating a parameter builds an
+    /// implicit IN set, so `?filter[status]=active&filter[status]=pending`
+    /// matches either status. See [`QueryParams::filter_value`] for how
+    /// these combine with `filter`.
+    pub field_filters: BTreeMap<String, Vec<String>>,
 }
 
 fn default_page() -> usize {
     1
 }
 
-fn default_limit() -> usize {
-    20
-}
-
 impl Default for QueryParams {
     fn default() -> Self {
         Self {
             page: default_page(),
-            limit: default_limit(),
+            limit: 0,
             filter: None,
             sort: None,
+            link_fields: None,
+            after: None,
+            before: None,
+            dry_run: false,
+            field_filters: BTreeMap::new(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for QueryParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(QueryParamsVisitor)
+    }
+}
+
+/// Map visitor that keeps every occurrence of repeated parameters
+///
+/// A derived `Deserialize` would only see the last value of a repeated key.
+struct QueryParamsVisitor;
+
+impl<'de> Visitor<'de> for QueryParamsVisitor {
+    type Value = QueryParams;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("query parameters")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<QueryParams, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut params = QueryParams::default();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "page" => params.page = map.next_value()?,
+                "limit" => params.limit = map.next_value()?,
+                "filter" => params.filter = Some(map.next_value()?),
+                "sort" => params.sort = Some(map.next_value()?),
+                "link_fields" => params.link_fields = Some(map.next_value()?),
+                "after" => params.after = Some(map.next_value()?),
+                "before" => params.before = Some(map.next_value()?),
+                "dry_run" => params.dry_run = map.next_value()?,
+                _ => match field_filter_name(&key) {
+                    Some(field) => {
+                        let values = params.field_filters.entry(field.to_string()).or_default();
+                        match map.next_value::<Value>()? {
+                            Value::Array(items) => {
+                                values.extend(items.iter().filter_map(scalar_to_string))
+                            }
+                            other => values.extend(scalar_to_string(&other)),
+                        }
+                    }
+                    // Options of the endpoint itself (`direction`, `format`, ...)
+                    None => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                },
+            }
+        }
+
+        params.field_filters.retain(|_, values| !values.is_empty());
+        Ok(params)
+    }
+}
+
+/// The field of a `filter[field]` parameter name
+fn field_filter_name(key: &str) -> Option<&str> {
+    key.strip_prefix("filter[")?
+        .strip_suffix(']')
+        .filter(|field| !field.is_empty())
+}
+
+/// Render a scalar JSON value as a filter string (objects/arrays/null are skipped)
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Check a field value against a single filter entry
+///
+/// An array on the filter side is an IN set: the field matches if it equals
+/// the whole array or any of its elements. String filter values also match
+/// numbers and booleans with the same text, since plain query parameters
+/// carry no type information (`?age=30` matches `30`).
+pub fn filter_matches(actual: &Value, expected: &Value) -> bool {
+    if actual == expected {
+        return true;
+    }
+    match (actual, expected) {
+        (_, Value::Array(set)) => set.iter().any(|item| filter_matches(actual, item)),
+        (Value::Number(n), Value::String(s)) => n.to_string() == *s,
+        (Value::Bool(b), Value::String(s)) => b.to_string() == *s,
+        _ => false,
+    }
+}
+
+/// Comparison applied by a [`FilterClause`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterOp {
+    /// A plain value: `{"status": "active"}`
+    Eq(FieldValue),
+    /// `$ne`; also matches entities where the field is absent or null
+    Ne(FieldValue),
+    /// `$gt`
+    Gt(FieldValue),
+    /// `$gte`
+    Gte(FieldValue),
+    /// `$lt`
+    Lt(FieldValue),
+    /// `$lte`
+    Lte(FieldValue),
+    /// `$in`, or a plain array value
+    In(Vec<FieldValue>),
+    /// `$contains`: substring match on string fields
+    Contains(String),
+}
+
+/// One typed condition of a list filter
+///
+/// Built from the filter JSON by [`FilterClause::parse_all`]. A field value
+/// is either a plain value (equality), an array (IN set), or an object of
+/// operators, which all apply:
+///
+/// ```text
+/// {"age": {"$gte": 18, "$lt": 65}, "status": {"$in": ["active", "pending"]}}
+/// ```
+///
+/// Ordering comparisons only hold between values of the same kind (numbers
+/// with numbers, strings with strings); a string operand is first read as
+/// the field's type, since plain query parameters carry no type information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterClause {
+    pub field: String,
+    pub op: FilterOp,
+}
+
+impl FilterClause {
+    /// Parse a JSON filter object into clauses
+    pub fn parse_all(filter: &Value) -> Result<Vec<Self>, String> {
+        let obj = filter
+            .as_object()
+            .ok_or_else(|| "filter must be a JSON object".to_string())?;
+
+        let mut clauses = Vec::new();
+        for (field, value) in obj {
+            let clause = |op| Self {
+                field: field.clone(),
+                op,
+            };
+            match value {
+                Value::Object(ops) if ops.keys().any(|k| k.starts_with('$')) => {
+                    for (op, operand) in ops {
+                        clauses.push(clause(FilterOp::parse(field, op, operand)?));
+                    }
+                }
+                Value::Array(items) => clauses.push(clause(FilterOp::In(scalars(field, items)?))),
+                value => clauses.push(clause(FilterOp::Eq(scalar(field, value)?))),
+            }
+        }
+        Ok(clauses)
+    }
+
+    /// Whether a field value satisfies this clause (`None` if the field is absent)
+    pub fn matches(&self, actual: Option<&FieldValue>) -> bool {
+        let actual = actual.unwrap_or(&FieldValue::Null);
+        let cmp = |operand| compare_field_values(actual, operand);
+        match &self.op {
+            FilterOp::Eq(v) => cmp(v) == Some(Ordering::Equal),
+            FilterOp::Ne(v) => cmp(v) != Some(Ordering::Equal),
+            FilterOp::Gt(v) => cmp(v) == Some(Ordering::Greater),
+            FilterOp::Gte(v) => matches!(cmp(v), Some(Ordering::Greater | Ordering::Equal)),
+            FilterOp::Lt(v) => cmp(v) == Some(Ordering::Less),
+            FilterOp::Lte(v) => matches!(cmp(v), Some(Ordering::Less | Ordering::Equal)),
+            FilterOp::In(set) => set.iter().any(|v| cmp(v) == Some(Ordering::Equal)),
+            FilterOp::Contains(needle) => {
+                matches!(actual, FieldValue::String(s) if s.contains(needle.as_str()))
+            }
+        }
+    }
+
+    /// Whether a JSON value satisfies this clause
+    ///
+    /// Arrays and objects never match.
+    pub fn matches_json(&self, actual: Option<&Value>) -> bool {
+        match actual.map(FieldValue::from_json) {
+            Some(None) => false,
+            Some(Some(value)) => self.matches(Some(&value)),
+            None => self.matches(None),
         }
     }
+
+    /// Whether an entity satisfies this clause
+    ///
+    /// The field is read through [`Data::field_value`], falling back to the
+    /// base entity fields (`id`, `created_at`, `updated_at`, `status`).
+    pub fn matches_entity<T: Data>(&self, entity: &T) -> bool {
+        self.matches(entity_field_value(entity, &self.field).as_ref())
+    }
+}
+
+/// Read a field of an entity, falling back to the base entity fields
+fn entity_field_value<T: Data>(entity: &T, field: &str) -> Option<FieldValue> {
+    entity.field_value(field).or_else(|| match field {
+        "id" => Some(FieldValue::Uuid(entity.id())),
+        "created_at" => Some(FieldValue::DateTime(entity.created_at())),
+        "updated_at" => Some(FieldValue::DateTime(entity.updated_at())),
+        "status" => Some(FieldValue::String(entity.status().to_string())),
+        _ => None,
+    })
+}
+
+/// One key of a `sort` parameter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: String,
+    pub descending: bool,
+}
+
+impl SortKey {
+    /// Parse a comma-separated `sort` parameter (`status:asc,age:desc`)
+    ///
+    /// Empty entries and entries with a direction other than `asc` or
+    /// `desc` are skipped.
+    pub fn parse_all(sort: &str) -> Vec<Self> {
+        sort.split(',')
+            .filter_map(|entry| {
+                let (field, direction) = match entry.trim().split_once(':') {
+                    Some((field, direction)) => (field.trim(), direction.trim()),
+                    None => (entry.trim(), "asc"),
+                };
+                let descending = match direction.to_ascii_lowercase().as_str() {
+                    "asc" => false,
+                    "desc" => true,
+                    _ => return None,
+                };
+                (!field.is_empty()).then(|| Self {
+                    field: field.to_string(),
+                    descending,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Sort entities by `keys`, in priority order
+///
+/// Fields are read like in [`FilterClause::matches_entity`]. The sort is
+/// stable, so entities equal on every key keep their order. A key no entity
+/// has is ignored, and entities missing a field (or holding `Null`) sort
+/// after the rest in either direction. Values of different kinds never
+/// compare equal: they are grouped by kind, numbers forming a single kind.
+pub fn sort_entities<T: Data>(entities: &mut [T], keys: &[SortKey]) {
+    if keys.is_empty() {
+        return;
+    }
+    entities.sort_by_cached_key(|entity| {
+        keys.iter()
+            .map(|key| SortValue {
+                value: entity_field_value(entity, &key.field)
+                    .filter(|value| *value != FieldValue::Null),
+                descending: key.descending,
+            })
+            .collect::<Vec<_>>()
+    });
+}
+
+/// A field value ordered for [`sort_entities`]
+struct SortValue {
+    /// `None` for a missing or `Null` field
+    value: Option<FieldValue>,
+    descending: bool,
+}
+
+impl Ord for SortValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.value, &other.value) {
+            (Some(a), Some(b)) => {
+                let ordering = total_cmp(a, b);
+                if self.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// Total order over non-null field values, for sorting
+fn total_cmp(a: &FieldValue, b: &FieldValue) -> Ordering {
+    use FieldValue::*;
+    fn kind(value: &FieldValue) -> u8 {
+        match value {
+            Integer(_) | Float(_) => 0,
+            String(_) => 1,
+            Boolean(_) => 2,
+            Uuid(_) => 3,
+            DateTime(_) => 4,
+            Null => 5,
+        }
+    }
+    match (a, b) {
+        (Integer(a), Integer(b)) => a.cmp(b),
+        (Integer(_) | Float(_), Integer(_) | Float(_)) => {
+            let as_f64 = |v: &FieldValue| v.as_f64().unwrap_or(f64::NAN);
+            as_f64(a).total_cmp(&as_f64(b))
+        }
+        (String(a), String(b)) => a.cmp(b),
+        (Boolean(a), Boolean(b)) => a.cmp(b),
+        (Uuid(a), Uuid(b)) => a.cmp(b),
+        (DateTime(a), DateTime(b)) => a.cmp(b),
+        _ => kind(a).cmp(&kind(b)),
+    }
+}
+
+impl PartialOrd for SortValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SortValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortValue {}
+
+impl FilterOp {
+    pub(crate) fn parse(field: &str, op: &str, operand: &Value) -> Result<Self, String> {
+        Ok(match op {
+            "$ne" => Self::Ne(scalar(field, operand)?),
+            "$gt" => Self::Gt(scalar(field, operand)?),
+            "$gte" => Self::Gte(scalar(field, operand)?),
+            "$lt" => Self::Lt(scalar(field, operand)?),
+            "$lte" => Self::Lte(scalar(field, operand)?),
+            "$in" => match operand {
+                Value::Array(items) => Self::In(scalars(field, items)?),
+                _ => return Err(format!("$in on '{}' expects an array", field)),
+            },
+            "$contains" => match operand {
+                Value::String(s) => Self::Contains(s.clone()),
+                _ => return Err(format!("$contains on '{}' expects a string", field)),
+            },
+            _ => {
+                return Err(format!(
+                    "unsupported filter operator '{}' on '{}'",
+                    op, field
+                ));
+            }
+        })
+    }
+}
+
+fn scalar(field: &str, value: &Value) -> Result<FieldValue, String> {
+    FieldValue::from_json(value)
+        .ok_or_else(|| format!("filter value for '{}' must be a scalar", field))
+}
+
+fn scalars(field: &str, items: &[Value]) -> Result<Vec<FieldValue>, String> {
+    items.iter().map(|item| scalar(field, item)).collect()
+}
+
+/// Order a field value against a filter operand, if they are comparable
+fn compare_field_values(actual: &FieldValue, operand: &FieldValue) -> Option<Ordering> {
+    use FieldValue::*;
+    match (actual, operand) {
+        (Integer(a), Integer(b)) => Some(a.cmp(b)),
+        (Integer(_) | Float(_), Integer(_) | Float(_)) => {
+            actual.as_f64()?.partial_cmp(&operand.as_f64()?)
+        }
+        (String(a), String(b)) => Some(a.cmp(b)),
+        (Boolean(a), Boolean(b)) => Some(a.cmp(b)),
+        (Uuid(a), Uuid(b)) => Some(a.cmp(b)),
+        (DateTime(a), DateTime(b)) => Some(a.cmp(b)),
+        (Null, Null) => Some(Ordering::Equal),
+        // Untyped operand (e.g. `?age=30`): read it as the field's type
+        (Integer(_) | Float(_) | Boolean(_) | Uuid(_) | DateTime(_), String(s)) => {
+            let typed = match actual {
+                Integer(_) => s
+                    .parse()
+                    .map(Integer)
+                    .or_else(|_| s.parse().map(Float))
+                    .ok()?,
+                Float(_) => Float(s.parse().ok()?),
+                Boolean(_) => Boolean(s.parse().ok()?),
+                Uuid(_) => Uuid(uuid::Uuid::parse_str(s).ok()?),
+                _ => DateTime(
+                    chrono::DateTime::parse_from_rfc3339(s)
+                        .ok()?
+                        .with_timezone(&Utc),
+                ),
+            };
+            compare_field_values(actual, &typed)
+        }
+        _ => None,
+    }
 }
 
 impl QueryParams {
@@ -85,19 +546,173 @@ impl QueryParams {
         self.page.max(1)
     }
 
-    /// Get limit, ensuring it doesn't exceed the maximum
+    /// Get the effective limit under the current [`PaginationConfig`]
+    ///
+    /// A missing or zero `limit` means the configured default; larger
+    /// values are capped at the configured maximum.
     pub fn limit(&self) -> usize {
-        self.limit.clamp(1, 100) // Maximum 100 per page, minimum 1
+        self.limit_within(PaginationConfig::current())
     }
 
-    /// Parse filter JSON string into Value
+    /// Get the effective limit under `config`
+    pub fn limit_within(&self, config: PaginationConfig) -> usize {
+        let max = config.max_limit.max(1);
+        match self.limit {
+            0 => config.default_limit.clamp(1, max),
+            limit => limit.min(max),
+        }
+    }
+
+    /// Build the effective filter object
+    ///
+    /// Combines the JSON `filter` parameter with `filter[field]` parameters. A
+    /// field given once becomes an exact match; a repeated field becomes an
+    /// array, read as an IN set by [`filter_matches`]. When the same field
+    /// appears in both, the JSON `filter` entry takes precedence.
     pub fn filter_value(&self) -> Option<Value> {
-        self.filter
+        let json_filter: Option<Value> = self
+            .filter
             .as_ref()
-            .and_then(|s| serde_json::from_str(s).ok())
+            .and_then(|s| serde_json::from_str(s).ok());
+
+        if self.field_filters.is_empty() {
+            return json_filter;
+        }
+
+        let mut merged: serde_json::Map<String, Value> = self
+            .field_filters
+            .iter()
+            .map(|(field, values)| {
+                let value = match values.as_slice() {
+                    [single] => Value::String(single.clone()),
+                    many => Value::Array(many.iter().cloned().map(Value::String).collect()),
+                };
+                (field.clone(), value)
+            })
+            .collect();
+
+        if let Some(Value::Object(obj)) = json_filter {
+            merged.extend(obj);
+        }
+
+        Some(Value::Object(merged))
+    }
+
+    /// Decode the `after` / `before` cursor, if any
+    ///
+    /// Returns `Ok(None)` for offset pagination (no cursor given). Giving
+    /// both cursors, or a token that does not decode, is an error.
+    pub fn cursor(&self) -> Result<Option<PageCursor>, String> {
+        match (&self.after, &self.before) {
+            (None, None) => Ok(None),
+            (Some(token), None) => Cursor::decode(token).map(|c| Some(PageCursor::After(c))),
+            (None, Some(token)) => Cursor::decode(token).map(|c| Some(PageCursor::Before(c))),
+            (Some(_), Some(_)) => Err("`after` and `before` cannot be combined".to_string()),
+        }
+    }
+
+    /// Parse `sort` into keys, in priority order (see [`SortKey::parse_all`])
+    pub fn sort_keys(&self) -> Vec<SortKey> {
+        self.sort
+            .as_deref()
+            .map(SortKey::parse_all)
+            .unwrap_or_default()
+    }
+
+    /// Parse `link_fields` into the list of requested metadata keys
+    ///
+    /// Only `metadata.<key>` entries are retained, where `<key>` is made of
+    /// ASCII alphanumerics, `_` or `-`. Returns `None` when no projection
+    /// was requested (or none of the entries are usable).
+    pub fn metadata_fields(&self) -> Option<Vec<String>> {
+        let fields: Vec<String> = self
+            .link_fields
+            .as_deref()?
+            .split(',')
+            .filter_map(|entry| entry.trim().strip_prefix("metadata."))
+            .filter(|key| {
+                !key.is_empty()
+                    && key
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            })
+            .map(str::to_string)
+            .collect();
+
+        if fields.is_empty() {
+            None
+        } else {
+            Some(fields)
+        }
     }
 }
 
+/// Position of an entity in the newest-first keyset order
+///
+/// Entities are ordered by `created_at` descending, with the ID breaking
+/// ties so the order is total. A cursor names one position in that order;
+/// on the wire it is an opaque URL-safe base64 token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    /// The cursor pointing at `entity`
+    pub fn of<T: Entity>(entity: &T) -> Self {
+        Self {
+            created_at: entity.created_at(),
+            id: entity.id(),
+        }
+    }
+
+    /// The cursor pointing at an entity serialized as JSON
+    ///
+    /// `None` unless the object has an `id` and an RFC 3339 `created_at`.
+    pub fn of_json(entity: &Value) -> Option<Self> {
+        Some(Self {
+            created_at: DateTime::parse_from_rfc3339(entity.get("created_at")?.as_str()?)
+                .ok()?
+                .with_timezone(&Utc),
+            id: Uuid::parse_str(entity.get("id")?.as_str()?).ok()?,
+        })
+    }
+
+    /// Encode as an opaque token
+    pub fn encode(&self) -> String {
+        let raw = format!(
+            "{}|{}",
+            self.created_at.to_rfc3339_opts(SecondsFormat::Nanos, true),
+            self.id
+        );
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    /// Decode a token produced by [`encode`](Self::encode)
+    pub fn decode(token: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid cursor: {}", token);
+        let raw = URL_SAFE_NO_PAD.decode(token).map_err(|_| invalid())?;
+        let raw = String::from_utf8(raw).map_err(|_| invalid())?;
+        let (created_at, id) = raw.split_once('|').ok_or_else(invalid)?;
+        Ok(Self {
+            created_at: DateTime::parse_from_rfc3339(created_at)
+                .map_err(|_| invalid())?
+                .with_timezone(&Utc),
+            id: Uuid::parse_str(id).map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// A decoded `after` or `before` query parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageCursor {
+    /// Items older than the cursor (next page)
+    After(Cursor),
+    /// Items newer than the cursor (previous page)
+    Before(Cursor),
+}
+
 /// Paginated response structure
 ///
 /// This structure wraps paginated data with metadata about pagination state.
@@ -110,6 +725,59 @@ pub struct PaginatedResponse<T> {
     pub pagination: PaginationMeta,
 }
 
+/// Default page size when a request gives no `limit`
+pub const DEFAULT_PAGE_LIMIT: usize = 20;
+
+/// Largest page size a request can ask for
+pub const DEFAULT_MAX_PAGE_LIMIT: usize = 100;
+
+tokio::task_local! {
+    static PAGINATION: PaginationConfig;
+}
+
+/// Page size bounds applied by [`QueryParams::limit`]
+///
+/// Set on the host with `ServerBuilder::with_pagination_limits`; the REST
+/// exposure runs each request [`scope`](Self::scope)d to it, so handlers
+/// need nothing beyond `QueryParams::limit()`. Outside such a scope the
+/// defaults apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaginationConfig {
+    /// Page size when a request gives no `limit` (or `limit=0`)
+    pub default_limit: usize,
+
+    /// Largest page size served; larger requests are capped
+    pub max_limit: usize,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            default_limit: DEFAULT_PAGE_LIMIT,
+            max_limit: DEFAULT_MAX_PAGE_LIMIT,
+        }
+    }
+}
+
+impl PaginationConfig {
+    pub fn new(default_limit: usize, max_limit: usize) -> Self {
+        Self {
+            default_limit,
+            max_limit,
+        }
+    }
+
+    /// The configuration of the enclosing [`scope`](Self::scope), or the defaults
+    pub fn current() -> Self {
+        PAGINATION.try_with(|config| *config).unwrap_or_default()
+    }
+
+    /// Run `future` with this configuration as [`current`](Self::current)
+    pub async fn scope<F: std::future::Future>(self, future: F) -> F::Output {
+        PAGINATION.scope(self, future).await
+    }
+}
+
 /// Pagination metadata
 #[derive(Debug, Serialize)]
 pub struct PaginationMeta {
@@ -151,6 +819,74 @@ impl PaginationMeta {
     }
 }
 
+/// Cursor-paginated response structure
+///
+/// The keyset counterpart of [`PaginatedResponse`]: no page numbers or
+/// totals, just the tokens to move one page in either direction.
+#[derive(Debug, Serialize)]
+pub struct CursorPaginatedResponse<T> {
+    /// The page of data, newest first
+    pub data: Vec<T>,
+
+    /// Cursor pagination metadata
+    pub pagination: CursorMeta,
+}
+
+/// Cursor pagination metadata
+#[derive(Debug, Serialize)]
+pub struct CursorMeta {
+    /// Number of items per page
+    pub limit: usize,
+
+    /// Token for the next (older) page, as the `after` parameter
+    pub next_cursor: Option<String>,
+
+    /// Token for the previous (newer) page, as the `before` parameter
+    pub prev_cursor: Option<String>,
+}
+
+impl<T: Entity> CursorPaginatedResponse<T> {
+    /// Build a page from items fetched with one extra row
+    ///
+    /// `items` holds up to `limit + 1` entities in newest-first order; the
+    /// extra row only signals that the page in the direction of travel is
+    /// not the last one and is dropped. `request` is the cursor the page
+    /// was fetched with.
+    pub fn new(mut items: Vec<T>, limit: usize, request: Option<PageCursor>) -> Self {
+        let more = items.len() > limit;
+        if more {
+            match request {
+                // The extra row is the newest one when paging backwards
+                Some(PageCursor::Before(_)) => {
+                    items.remove(0);
+                }
+                _ => items.truncate(limit),
+            }
+        }
+
+        let (has_next, has_prev) = match request {
+            None => (more, false),
+            Some(PageCursor::After(_)) => (more, true),
+            Some(PageCursor::Before(_)) => (true, more),
+        };
+        let next_cursor = has_next
+            .then(|| items.last().map(|e| Cursor::of(e).encode()))
+            .flatten();
+        let prev_cursor = has_prev
+            .then(|| items.first().map(|e| Cursor::of(e).encode()))
+            .flatten();
+
+        Self {
+            data: items,
+            pagination: CursorMeta {
+                limit,
+                next_cursor,
+                prev_cursor,
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,12 +930,12 @@ mod tests {
     // --- QueryParams::limit edge cases ---
 
     #[test]
-    fn test_query_params_limit_zero_clamps_to_one() {
+    fn test_query_params_limit_zero_uses_default() {
         let params = QueryParams {
             limit: 0,
             ..Default::default()
         };
-        assert_eq!(params.limit(), 1);
+        assert_eq!(params.limit(), 20);
     }
 
     #[test]
@@ -220,6 +956,132 @@ mod tests {
         assert_eq!(params.limit(), 50);
     }
 
+    #[tokio::test]
+    async fn test_query_params_limit_follows_scoped_config() {
+        let config = PaginationConfig::new(25, 500);
+        let unspecified: QueryParams = serde_json::from_value(serde_json::json!({})).unwrap();
+        let zero = QueryParams {
+            limit: 0,
+            ..Default::default()
+        };
+        let huge = QueryParams {
+            limit: 10_000,
+            ..Default::default()
+        };
+
+        let limits = config
+            .scope(async { (unspecified.limit(), zero.limit(), huge.limit()) })
+            .await;
+        assert_eq!(limits, (25, 25, 500));
+
+        // Outside the scope the defaults apply again
+        assert_eq!(huge.limit(), 100);
+    }
+
+    // --- FilterClause ---
+
+    fn all_match(filter: Value, doc: &Value) -> bool {
+        FilterClause::parse_all(&filter)
+            .unwrap()
+            .iter()
+            .all(|clause| clause.matches_json(doc.get(&clause.field)))
+    }
+
+    #[test]
+    fn test_filter_clause_parses_operators() {
+        let clauses = FilterClause::parse_all(&serde_json::json!({
+            "age": {"$gte": 18, "$lt": 65},
+            "status": ["active", "pending"],
+        }))
+        .unwrap();
+        assert_eq!(
+            clauses,
+            vec![
+                FilterClause {
+                    field: "age".to_string(),
+                    op: FilterOp::Gte(FieldValue::Integer(18)),
+                },
+                FilterClause {
+                    field: "age".to_string(),
+                    op: FilterOp::Lt(FieldValue::Integer(65)),
+                },
+                FilterClause {
+                    field: "status".to_string(),
+                    op: FilterOp::In(vec![
+                        FieldValue::String("active".to_string()),
+                        FieldValue::String("pending".to_string()),
+                    ]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_clause_rejects_bad_operators() {
+        for filter in [
+            serde_json::json!({"age": {"$between": [1, 2]}}),
+            serde_json::json!({"age": {"$in": 3}}),
+            serde_json::json!({"age": {"$gt": [1]}}),
+            serde_json::json!({"name": {"$contains": 1}}),
+            serde_json::json!({"meta": {"nested": true}}),
+            serde_json::json!(["age"]),
+        ] {
+            assert!(
+                FilterClause::parse_all(&filter).is_err(),
+                "{} should be rejected",
+                filter
+            );
+        }
+    }
+
+    #[test]
+    fn test_filter_clause_numeric_ranges() {
+        let doc = serde_json::json!({"age": 30, "score": 4.5, "name": "Ada"});
+
+        assert!(all_match(serde_json::json!({"age": {"$gt": 18}}), &doc));
+        assert!(all_match(
+            serde_json::json!({"age": {"$gte": 30, "$lte": 30}}),
+            &doc
+        ));
+        assert!(!all_match(serde_json::json!({"age": {"$lt": 30}}), &doc));
+        assert!(all_match(serde_json::json!({"score": {"$lte": 4.5}}), &doc));
+        assert!(all_match(serde_json::json!({"score": {"$gt": 4}}), &doc));
+        assert!(!all_match(serde_json::json!({"score": {"$gt": 4.5}}), &doc));
+        // Untyped operands are read as numbers; strings never order against numbers
+        assert!(all_match(serde_json::json!({"age": {"$gt": "18"}}), &doc));
+        assert!(!all_match(serde_json::json!({"name": {"$gt": 1}}), &doc));
+        // Absent fields satisfy no range, but do satisfy $ne
+        assert!(!all_match(serde_json::json!({"height": {"$gt": 0}}), &doc));
+        assert!(all_match(serde_json::json!({"height": {"$ne": 0}}), &doc));
+    }
+
+    #[test]
+    fn test_filter_clause_in_and_contains_over_strings() {
+        let doc = serde_json::json!({"status": "pending", "email": "ada@example.com"});
+
+        assert!(all_match(
+            serde_json::json!({"status": {"$in": ["active", "pending"]}}),
+            &doc
+        ));
+        assert!(!all_match(
+            serde_json::json!({"status": {"$in": ["active", "archived"]}}),
+            &doc
+        ));
+        assert!(!all_match(serde_json::json!({"status": {"$in": []}}), &doc));
+        assert!(all_match(
+            serde_json::json!({"status": {"$ne": "active"}}),
+            &doc
+        ));
+        assert!(all_match(
+            serde_json::json!({"email": {"$contains": "@example."}}),
+            &doc
+        ));
+        assert!(!all_match(
+            serde_json::json!({"email": {"$contains": "ADA"}}),
+            &doc
+        ));
+    }
+
     // --- filter_value ---
 
     #[test]
@@ -252,6 +1114,70 @@ mod tests {
         assert!(params.filter_value().is_none());
     }
 
+    // --- metadata_fields ---
+
+    #[test]
+    fn test_sort_keys_parse_in_priority_order() {
+        let params = QueryParams {
+            sort: Some("status:asc, age:DESC,name,,score:sideways".to_string()),
+            ..Default::default()
+        };
+        let key = |field: &str, descending| SortKey {
+            field: field.to_string(),
+            descending,
+        };
+        assert_eq!(
+            params.sort_keys(),
+            [key("status", false), key("age", true), key("name", false)]
+        );
+        assert!(QueryParams::default().sort_keys().is_empty());
+    }
+
+    #[test]
+    fn test_sort_total_order_groups_kinds() {
+        use FieldValue::*;
+        assert_eq!(total_cmp(&Integer(2), &Float(1.5)), Ordering::Greater);
+        assert_eq!(total_cmp(&Boolean(false), &Boolean(true)), Ordering::Less);
+        assert_eq!(
+            total_cmp(&String("9".into()), &Integer(1)),
+            Ordering::Greater
+        );
+        assert_eq!(total_cmp(&Integer(1), &String("9".into())), Ordering::Less);
+    }
+
+    #[test]
+    fn test_metadata_fields_none_by_default() {
+        let params = QueryParams::default();
+        assert!(params.metadata_fields().is_none());
+    }
+
+    #[test]
+    fn test_metadata_fields_parses_comma_separated_paths() {
+        let params = QueryParams {
+            link_fields: Some("metadata.priority, metadata.role".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            params.metadata_fields(),
+            Some(vec!["priority".to_string(), "role".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_metadata_fields_ignores_non_metadata_and_invalid_keys() {
+        let params = QueryParams {
+            link_fields: Some("status,metadata.,metadata.a'b,metadata.ok_key".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(params.metadata_fields(), Some(vec!["ok_key".to_string()]));
+
+        let params = QueryParams {
+            link_fields: Some("status,source_id".to_string()),
+            ..Default::default()
+        };
+        assert!(params.metadata_fields().is_none());
+    }
+
     // --- PaginationMeta edge cases ---
 
     #[test]
@@ -294,4 +1220,128 @@ mod tests {
         assert_eq!(meta.limit, 1);
         assert_eq!(meta.total_pages, 10);
     }
+
+    // --- Repeated field parameters ---
+
+    fn parse(query: &str) -> QueryParams {
+        let uri: axum::http::Uri = format!("/items?{}", query).parse().unwrap();
+        axum::extract::Query::<QueryParams>::try_from_uri(&uri)
+            .expect("query should parse")
+            .0
+    }
+
+    #[test]
+    fn test_repeated_params_build_in_set() {
+        let params =
+            parse("page=2&filter[status]=active&filter[status]=pending&filter%5Bname%5D=Acme");
+        assert_eq!(params.page(), 2);
+        assert_eq!(
+            params.field_filters.get("status"),
+            Some(&vec!["active".to_string(), "pending".to_string()])
+        );
+        assert_eq!(
+            params.filter_value(),
+            Some(serde_json::json!({
+                "status": ["active", "pending"],
+                "name": "Acme"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_reserved_params_are_not_field_filters() {
+        let params = parse("page=1&limit=5&sort=name&link_fields=metadata.a");
+        assert!(params.field_filters.is_empty());
+        assert!(params.filter_value().is_none());
+    }
+
+    #[test]
+    fn test_only_prefixed_params_are_field_filters() {
+        let params = parse("status=active&direction=out&filter[]=x&filter[name=y&filter[kind]=car");
+        assert_eq!(params.field_filters.len(), 1);
+        assert_eq!(
+            params.filter_value(),
+            Some(serde_json::json!({ "kind": "car" }))
+        );
+    }
+
+    #[test]
+    fn test_json_filter_takes_precedence_over_field_params() {
+        let params = parse(
+            "filter[status]=active&filter[status]=pending&filter=%7B%22status%22%3A%22archived%22%7D",
+        );
+        assert_eq!(
+            params.filter_value(),
+            Some(serde_json::json!({"status": "archived"}))
+        );
+    }
+
+    #[test]
+    fn test_invalid_page_is_rejected() {
+        let uri: axum::http::Uri = "/items?page=abc".parse().unwrap();
+        assert!(axum::extract::Query::<QueryParams>::try_from_uri(&uri).is_err());
+    }
+
+    #[test]
+    fn test_filter_matches_in_set_and_text() {
+        let set = serde_json::json!(["active", "pending"]);
+        assert!(filter_matches(&serde_json::json!("pending"), &set));
+        assert!(!filter_matches(&serde_json::json!("archived"), &set));
+        assert!(filter_matches(
+            &serde_json::json!(30),
+            &serde_json::json!("30")
+        ));
+        assert!(filter_matches(
+            &serde_json::json!(true),
+            &serde_json::json!(["true"])
+        ));
+        assert!(filter_matches(
+            &serde_json::json!(["a", "b"]),
+            &serde_json::json!(["a", "b"])
+        ));
+    }
+
+    // --- Cursors ---
+
+    #[test]
+    fn test_cursor_round_trips_through_token() {
+        let cursor = Cursor {
+            created_at: Utc::now(),
+            id: Uuid::new_v4(),
+        };
+        let token = cursor.encode();
+        assert!(
+            token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+        assert_eq!(Cursor::decode(&token), Ok(cursor));
+    }
+
+    #[test]
+    fn test_invalid_cursor_is_rejected() {
+        assert!(Cursor::decode("not base64!").is_err());
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode("2024-01-01|nope")).is_err());
+    }
+
+    #[test]
+    fn test_cursor_params() {
+        let token = Cursor {
+            created_at: Utc::now(),
+            id: Uuid::new_v4(),
+        }
+        .encode();
+
+        let params = parse(&format!("limit=10&after={}", token));
+        assert!(params.field_filters.is_empty());
+        assert!(matches!(params.cursor(), Ok(Some(PageCursor::After(_)))));
+
+        let params = parse(&format!("before={}", token));
+        assert!(matches!(params.cursor(), Ok(Some(PageCursor::Before(_)))));
+
+        let params = parse(&format!("after={0}&before={0}", token));
+        assert!(params.cursor().is_err());
+
+        assert_eq!(parse("page=2").cursor(), Ok(None));
+    }
 }
@@ -0,0 +1,76 @@
+//! Field-level redaction of entities
+//!
+//! [`Data::redacted_fields`](crate::core::Data::redacted_fields) names the
+//! fields a caller may not see, given its [`AuthContext`](crate::core::AuthContext).
+//! The REST exposure strips them from entity responses and from the
+//! `source`/`target` entities embedded in links, gRPC from the entities it
+//! returns, and GraphQL resolves them to `null`. Requests without an auth
+//! provider, or whose credentials cannot be read, are redacted as
+//! `AuthContext::Anonymous`. Entity events carry the data of the anonymous
+//! view (see [`EventBus::with_redacted_fields`]).
+//!
+//! [`EventBus::with_redacted_fields`]: crate::core::events::EventBus::with_redacted_fields
+
+use serde_json::Value;
+
+/// Remove `fields` from an entity's JSON form
+pub fn redact_entity(entity: &mut Value, fields: &[&str]) {
+    if let Some(object) = entity.as_object_mut() {
+        for field in fields {
+            object.remove(*field);
+        }
+    }
+}
+
+/// Remove `fields` from every entity of a response body
+///
+/// The body may be one entity, an array of entities, or a paginated
+/// response whose `data` holds the entities.
+pub fn redact_body(body: &mut Value, fields: &[&str]) {
+    if fields.is_empty() {
+        return;
+    }
+    match body {
+        Value::Array(entities) => {
+            for entity in entities {
+                redact_entity(entity, fields);
+            }
+        }
+        Value::Object(object) => match object.get_mut("data") {
+            Some(Value::Array(entities)) => {
+                for entity in entities {
+                    redact_entity(entity, fields);
+                }
+            }
+            _ => redact_entity(body, fields),
+        },
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redact_body_handles_entities_lists_and_pages() {
+        let mut entity = json!({ "name": "alice", "password_hash": "x" });
+        redact_body(&mut entity, &["password_hash"]);
+        assert_eq!(entity, json!({ "name": "alice" }));
+
+        let mut list = json!([{ "name": "a", "password_hash": "x" }, { "name": "b" }]);
+        redact_body(&mut list, &["password_hash"]);
+        assert_eq!(list, json!([{ "name": "a" }, { "name": "b" }]));
+
+        let mut page = json!({
+            "data": [{ "name": "a", "password_hash": "x" }],
+            "pagination": { "total": 1 }
+        });
+        redact_body(&mut page, &["password_hash"]);
+        assert_eq!(
+            page,
+            json!({ "data": [{ "name": "a" }], "pagination": { "total": 1 } })
+        );
+    }
+}
@@ -1,8 +1,21 @@
 //! Service traits for data and link operations
 
-use crate::core::{Data, link::LinkEntity};
-use anyhow::Result;
+use crate::core::etag::{CacheResult, etag_matches, etag_of};
+use crate::core::history::HistoryError;
+use crate::core::patch::{PatchError, merge_patch};
+use crate::core::query::{Cursor, FilterClause};
+use crate::core::soft_delete::{SoftDeleteError, SoftDeleteStatus, with_deletion_state};
+use crate::core::tenant::TenantContext;
+use crate::core::{
+    Data,
+    link::{LinkCardinality, LinkEntity, LinkFilterCondition, LinkLimit, RelationDirection},
+};
+use anyhow::{Result, anyhow};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
 use uuid::Uuid;
 
 /// Service trait for managing data entities
@@ -14,20 +27,342 @@ pub trait DataService<T: Data>: Send + Sync {
     /// Create a new entity
     async fn create(&self, entity: T) -> Result<T>;
 
+    /// Create several entities at once
+    ///
+    /// Returns the stored entities in input order. The default implementation
+    /// calls [`create`](Self::create) for each entity; backends override it
+    /// to save round-trips.
+    async fn create_many(&self, entities: Vec<T>) -> Result<Vec<T>> {
+        let mut created = Vec::with_capacity(entities.len());
+        for entity in entities {
+            created.push(self.create(entity).await?);
+        }
+        Ok(created)
+    }
+
     /// Get an entity by ID
-    async fn get(&self, id: &Uuid) -> Result<Option<T>>;
+    ///
+    /// Soft-deleted entities (non-null `deleted_at`) are treated as absent;
+    /// use [`get_with_deleted`](Self::get_with_deleted) to read tombstones.
+    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
+        Ok(self
+            .get_with_deleted(id)
+            .await?
+            .filter(|entity| entity.deleted_at().is_none()))
+    }
+
+    /// Get an entity by ID, including soft-deleted entities
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>>;
+
+    /// List all entities, excluding soft-deleted ones
+    async fn list(&self) -> Result<Vec<T>> {
+        self.list_filtered(false).await
+    }
+
+    /// List all entities, including soft-deleted ones
+    async fn list_with_deleted(&self) -> Result<Vec<T>> {
+        self.list_filtered(true).await
+    }
+
+    /// List entities, with or without soft-deleted ones
+    ///
+    /// This is the single listing query a backend implements; `list` and
+    /// `list_with_deleted` delegate to it.
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>>;
+
+    /// List up to `limit` entities older than `cursor`, newest first
+    ///
+    /// Walks the keyset order of [`Cursor`] (`created_at` descending, then
+    /// ID descending), so pages stay stable under concurrent inserts. With
+    /// no cursor, starts from the newest entity. Soft-deleted entities are
+    /// excluded. The default implementation sorts the full list in memory;
+    /// backends override it with a keyset query.
+    async fn list_after(&self, cursor: Option<Cursor>, limit: usize) -> Result<Vec<T>> {
+        let mut entities = self.list().await?;
+        entities.sort_by_key(|e| std::cmp::Reverse(Cursor::of(e)));
+        Ok(entities
+            .into_iter()
+            .filter(|e| cursor.is_none_or(|c| Cursor::of(e) < c))
+            .take(limit)
+            .collect())
+    }
+
+    /// List up to `limit` entities newer than `cursor`, newest first
+    ///
+    /// The backwards counterpart of [`list_after`](Self::list_after): returns
+    /// the `limit` entities closest to the cursor.
+    async fn list_before(&self, cursor: Cursor, limit: usize) -> Result<Vec<T>> {
+        let mut entities = self.list().await?;
+        entities.retain(|e| Cursor::of(e) > cursor);
+        entities.sort_by_key(Cursor::of);
+        entities.truncate(limit);
+        entities.reverse();
+        Ok(entities)
+    }
 
-    /// List all entities
-    async fn list(&self) -> Result<Vec<T>>;
+    /// List one page of up to `limit` entities, with the token of the next page
+    ///
+    /// `after` is the token returned for the previous page (`None` for the
+    /// first one), typically the REST `after` query parameter; the returned
+    /// token is `None` on the last page. Tokens are opaque and only
+    /// meaningful to the backend that issued them: the default walks
+    /// [`list_after`](Self::list_after) with [`Cursor`] tokens, while
+    /// backends without a keyset order hand out their native continuation
+    /// key (e.g. DynamoDB's `LastEvaluatedKey`).
+    async fn list_page(
+        &self,
+        limit: usize,
+        after: Option<String>,
+    ) -> Result<(Vec<T>, Option<String>)> {
+        let cursor = after
+            .as_deref()
+            .map(Cursor::decode)
+            .transpose()
+            .map_err(|e| anyhow!(e))?;
+        let mut entities = self.list_after(cursor, limit + 1).await?;
+        if entities.len() <= limit {
+            return Ok((entities, None));
+        }
+        entities.truncate(limit);
+        let next = entities.last().map(|e| Cursor::of(e).encode());
+        Ok((entities, next))
+    }
+
+    /// Count entities, excluding soft-deleted ones
+    ///
+    /// Always equal to `list().len()`. The default implementation loads the
+    /// full list; backends override it with a native count query.
+    async fn count(&self) -> Result<usize> {
+        Ok(self.list().await?.len())
+    }
 
     /// Update an existing entity
     async fn update(&self, id: &Uuid, entity: T) -> Result<T>;
 
+    /// Apply a partial update and return the merged entity
+    ///
+    /// Only the keys present in `partial` change; a key set to `null` clears
+    /// the field (see [`merge_patch`]). `updated_at` is bumped. Fails with
+    /// [`PatchError::NotFound`] if no live entity has this ID, and with
+    /// [`PatchError::Invalid`] if the merged fields no longer form a valid
+    /// entity, in which case nothing is written.
+    async fn patch(&self, id: &Uuid, partial: Value) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let existing = self.get(id).await?.ok_or(PatchError::NotFound(*id))?;
+        let mut merged = serde_json::to_value(&existing)?;
+        merge_patch(&mut merged, &partial)?;
+        let merged: T =
+            serde_json::from_value(merged).map_err(|e| PatchError::Invalid(e.to_string()))?;
+        self.update(id, merged).await
+    }
+
+    /// Get an entity as it was at `version`
+    ///
+    /// Version 1 is the state the entity was created in, each update adds
+    /// one, and the last version is the current state (see
+    /// [`crate::core::history`]). Returns `None` if the entity or the version
+    /// does not exist. Fails with [`HistoryError::NotEnabled`] unless the
+    /// backend records versions (e.g. `MysqlDataService::with_history`).
+    async fn get_at(&self, _id: &Uuid, _version: i64) -> Result<Option<T>> {
+        Err(HistoryError::NotEnabled.into())
+    }
+
+    /// Get an entity as it was at time `at`
+    ///
+    /// Returns `None` if the entity did not exist yet. Requires history, as
+    /// [`get_at`](Self::get_at) does.
+    async fn get_as_of(&self, _id: &Uuid, _at: DateTime<Utc>) -> Result<Option<T>> {
+        Err(HistoryError::NotEnabled.into())
+    }
+
     /// Delete an entity
     async fn delete(&self, id: &Uuid) -> Result<()>;
 
-    /// Search entities by field values
+    /// Soft-delete an entity and return it
+    ///
+    /// Sets `deleted_at`, which hides the entity from [`get`](Self::get),
+    /// [`list`](Self::list) and searches while keeping it in storage. Fails
+    /// with [`SoftDeleteError::NotFound`] if no live entity has this ID. The
+    /// default implementation writes the tombstone through
+    /// [`update`](Self::update).
+    async fn soft_delete(&self, id: &Uuid) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let existing = self.get(id).await?.ok_or(SoftDeleteError::NotFound(*id))?;
+        let tombstone = with_deletion_state(&existing, Some(chrono::Utc::now()), None)?;
+        self.update(id, tombstone).await
+    }
+
+    /// Soft-delete an entity, setting its status to `status.on_delete`
+    ///
+    /// Like [`soft_delete`](Self::soft_delete). Backends that can keep the
+    /// previous status for [`restore_with_status`](Self::restore_with_status)
+    /// override this; the default implementation does not keep it.
+    async fn soft_delete_with_status(&self, id: &Uuid, status: &SoftDeleteStatus) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let existing = self.get(id).await?.ok_or(SoftDeleteError::NotFound(*id))?;
+        let tombstone =
+            with_deletion_state(&existing, Some(chrono::Utc::now()), Some(&status.on_delete))?;
+        self.update(id, tombstone).await
+    }
+
+    /// Clear the `deleted_at` of an entity and return it
+    ///
+    /// Restoring an entity that is not deleted changes nothing. Fails with
+    /// [`SoftDeleteError::NotFound`] if no entity, deleted or not, has this ID.
+    async fn restore(&self, id: &Uuid) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let existing = self
+            .get_with_deleted(id)
+            .await?
+            .ok_or(SoftDeleteError::NotFound(*id))?;
+        if existing.deleted_at().is_none() {
+            return Ok(existing);
+        }
+        self.update(id, with_deletion_state(&existing, None, None)?)
+            .await
+    }
+
+    /// Restore an entity, reverting the status it had before its deletion
+    ///
+    /// Like [`restore`](Self::restore). The status becomes `status.on_restore`
+    /// when the previous one was not kept, which is always the case with the
+    /// default implementation.
+    async fn restore_with_status(&self, id: &Uuid, status: &SoftDeleteStatus) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let existing = self
+            .get_with_deleted(id)
+            .await?
+            .ok_or(SoftDeleteError::NotFound(*id))?;
+        if existing.deleted_at().is_none() {
+            return Ok(existing);
+        }
+        let restored = with_deletion_state(&existing, None, Some(&status.on_restore))?;
+        self.update(id, restored).await
+    }
+
+    /// Search entities by field values, excluding soft-deleted ones
     async fn search(&self, field: &str, value: &str) -> Result<Vec<T>>;
+
+    /// Free-text search over the entities' text fields, best matches first
+    ///
+    /// The default implementation keeps the entities where one of
+    /// [`Data::indexed_fields`] holds `query` as a case-insensitive
+    /// substring (`ILIKE '%query%'`), listing those where a field equals
+    /// `query` before the partial matches. The PostgreSQL backend ranks
+    /// with full-text search instead. Soft-deleted entities are excluded.
+    async fn search_text(&self, query: &str) -> Result<Vec<T>> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut ranked: Vec<(bool, T)> = self
+            .list()
+            .await?
+            .into_iter()
+            .filter_map(|entity| {
+                let texts: Vec<String> = T::indexed_fields()
+                    .iter()
+                    .filter_map(|field| entity.field_value(field))
+                    .filter_map(|value| value.as_string().map(str::to_lowercase))
+                    .collect();
+                if !texts.iter().any(|text| text.contains(&query)) {
+                    return None;
+                }
+                let exact = texts.contains(&query);
+                Some((exact, entity))
+            })
+            .collect();
+        // Stable, so entities of equal rank keep the listing order
+        ranked.sort_by_key(|(exact, _)| !exact);
+        Ok(ranked.into_iter().map(|(_, entity)| entity).collect())
+    }
+
+    /// Get an entity by ID if it belongs to `tenant`
+    ///
+    /// Entities of other tenants are reported as absent. The default
+    /// implementation filters [`get`](Self::get) on [`Entity::tenant_id`];
+    /// SQL backends add `AND tenant_id = ?` to the query.
+    ///
+    /// [`Entity::tenant_id`]: crate::core::Entity::tenant_id
+    async fn get_for_tenant(&self, id: &Uuid, tenant: &TenantContext) -> Result<Option<T>> {
+        Ok(self
+            .get(id)
+            .await?
+            .filter(|entity| tenant.owns(entity.tenant_id())))
+    }
+
+    /// List the entities of `tenant`, excluding soft-deleted ones
+    ///
+    /// See [`get_for_tenant`](Self::get_for_tenant).
+    async fn list_for_tenant(&self, tenant: &TenantContext) -> Result<Vec<T>> {
+        let mut entities = self.list().await?;
+        entities.retain(|entity| tenant.owns(entity.tenant_id()));
+        Ok(entities)
+    }
+
+    /// Search the entities of `tenant` by field value
+    ///
+    /// See [`get_for_tenant`](Self::get_for_tenant).
+    async fn search_for_tenant(
+        &self,
+        field: &str,
+        value: &str,
+        tenant: &TenantContext,
+    ) -> Result<Vec<T>> {
+        let mut entities = self.search(field, value).await?;
+        entities.retain(|entity| tenant.owns(entity.tenant_id()));
+        Ok(entities)
+    }
+
+    /// List entities matching every clause, excluding soft-deleted ones
+    ///
+    /// Clauses come from [`FilterClause::parse_all`]. The default
+    /// implementation evaluates them in memory against
+    /// [`FilterClause::matches_entity`]; SQL backends translate them into a
+    /// `WHERE` clause.
+    async fn list_where(&self, clauses: &[FilterClause]) -> Result<Vec<T>> {
+        let mut entities = self.list().await?;
+        entities.retain(|e| clauses.iter().all(|clause| clause.matches_entity(e)));
+        Ok(entities)
+    }
+
+    /// Get an entity only if it changed since the client's ETag
+    ///
+    /// `etag` is the raw `If-None-Match` value (see [`etag_matches`]),
+    /// compared against the ETag of the stored entity's id, `updated_at`
+    /// and version ([`etag_of`]). The REST exposure answers conditional
+    /// `GET`s on `/{entity_type}/{id}` with it, through
+    /// [`EntityFetcher::fetch_if_modified_as_json`](crate::core::EntityFetcher::fetch_if_modified_as_json).
+    /// SQL backends override this to read only those columns before loading
+    /// the full row. The default implementation fetches the entity and
+    /// compares in memory.
+    async fn get_if_modified(&self, id: &Uuid, etag: Option<&str>) -> Result<CacheResult<T>> {
+        let Some(entity) = self.get(id).await? else {
+            return Ok(CacheResult::NotFound);
+        };
+        match etag {
+            Some(tag) if etag_matches(tag, &etag_of(&entity)) => Ok(CacheResult::NotModified),
+            _ => Ok(CacheResult::Modified(entity)),
+        }
+    }
+
+    /// Whether mutations record their events in a transactional outbox
+    ///
+    /// See [`crate::core::outbox`]. Services returning `true` leave event
+    /// delivery to the outbox poller.
+    fn writes_outbox(&self) -> bool {
+        false
+    }
 }
 
 /// Service trait for managing links between entities
@@ -39,9 +374,106 @@ pub trait LinkService: Send + Sync {
     /// Create a new link between two entities
     async fn create(&self, link: LinkEntity) -> Result<LinkEntity>;
 
+    /// Create a link unless it would exceed `limit`
+    ///
+    /// Returns `Ok(None)`, inserting nothing, when the link's source or target
+    /// already has the maximum number of live links of the same type. The
+    /// check and the insert must be atomic so concurrent creates cannot
+    /// overshoot the cap: the in-memory backend holds its write lock across
+    /// both, and the SQL backends serialize bounded inserts per link type.
+    ///
+    /// The default implementation counts and then inserts without any
+    /// locking, so it is only safe for backends without concurrent writers.
+    async fn create_within_limit(
+        &self,
+        link: LinkEntity,
+        limit: LinkLimit,
+    ) -> Result<Option<LinkEntity>> {
+        let mut existing = Vec::new();
+        if limit.per_source.is_some() {
+            existing.extend(
+                self.find_by_source(&link.source_id, Some(&link.link_type), None)
+                    .await?,
+            );
+        }
+        if limit.per_target.is_some() {
+            let incoming = self
+                .find_by_target(&link.target_id, Some(&link.link_type), None)
+                .await?;
+            // A link sharing both endpoints is already in the list
+            let seen: Vec<Uuid> = existing.iter().map(|l| l.id).collect();
+            existing.extend(incoming.into_iter().filter(|l| !seen.contains(&l.id)));
+        }
+
+        let (source_count, target_count) = LinkLimit::count(&link, &existing);
+        if !limit.allows(source_count, target_count) {
+            return Ok(None);
+        }
+        self.create(link).await.map(Some)
+    }
+
+    /// Create a link unless an identical one already exists
+    ///
+    /// Returns `Ok(None)`, inserting nothing, when a live link with the same
+    /// `link_type`, `source_id` and `target_id` is already stored.
+    /// Soft-deleted links do not count. As with
+    /// [`create_within_limit`](Self::create_within_limit), backends with
+    /// concurrent writers should make the check and the insert atomic.
+    ///
+    /// The default implementation scans the source's links and then inserts
+    /// without any locking.
+    async fn create_unique(&self, link: LinkEntity) -> Result<Option<LinkEntity>> {
+        let existing = self
+            .find_by_source(&link.source_id, Some(&link.link_type), None)
+            .await?;
+        if existing.iter().any(|l| l.duplicates(&link)) {
+            return Ok(None);
+        }
+        self.create(link).await.map(Some)
+    }
+
+    /// Create a link within the cardinality of its definition
+    ///
+    /// Bounded cardinalities go through
+    /// [`create_within_limit`](Self::create_within_limit), many-to-many
+    /// links through [`create_unique`](Self::create_unique) unless
+    /// `allow_duplicates` is set. Returns `Ok(None)`, inserting nothing, when
+    /// the link is rejected. Every exposure creates links through this.
+    async fn create_with_cardinality(
+        &self,
+        link: LinkEntity,
+        cardinality: LinkCardinality,
+        allow_duplicates: bool,
+    ) -> Result<Option<LinkEntity>> {
+        match cardinality {
+            LinkCardinality::ManyToMany if allow_duplicates => self.create(link).await.map(Some),
+            LinkCardinality::ManyToMany => self.create_unique(link).await,
+            _ => self.create_within_limit(link, cardinality.limit()).await,
+        }
+    }
+
     /// Get a specific link by ID
     async fn get(&self, id: &Uuid) -> Result<Option<LinkEntity>>;
 
+    /// Get the live `link_type` link from `source_id` to `target_id`
+    ///
+    /// Soft-deleted links are ignored. The default implementation scans the
+    /// source's links; backends with an index on the pair should override
+    /// it with a direct lookup.
+    async fn get_between(
+        &self,
+        source_id: &Uuid,
+        target_id: &Uuid,
+        link_type: &str,
+    ) -> Result<Option<LinkEntity>> {
+        let links = self
+            .find_by_source(source_id, Some(link_type), None)
+            .await?;
+        Ok(links
+            .into_iter()
+            .find(|l| l.target_id == *target_id && !l.is_deleted()))
+    }
+
     /// List all links
     async fn list(&self) -> Result<Vec<LinkEntity>>;
 
@@ -65,6 +497,186 @@ pub trait LinkService: Send + Sync {
         source_type: Option<&str>,
     ) -> Result<Vec<LinkEntity>>;
 
+    /// Find one page of links by source entity, with the number of matches
+    ///
+    /// Filters as [`find_by_source`](Self::find_by_source), then skips
+    /// `offset` links and returns up to `limit`, newest first, along with
+    /// the total across all pages. The default implementation loads every
+    /// match and slices it; SQL backends override it to page in storage
+    /// (`LIMIT`/`OFFSET` plus a count).
+    async fn find_by_source_page(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<LinkEntity>, usize)> {
+        let links = self
+            .find_by_source(source_id, link_type, target_type)
+            .await?;
+        Ok(page_of_links(links, limit, offset))
+    }
+
+    /// Find one page of links by target entity, with the number of matches
+    ///
+    /// See [`find_by_source_page`](Self::find_by_source_page).
+    async fn find_by_target_page(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<LinkEntity>, usize)> {
+        let links = self
+            .find_by_target(target_id, link_type, source_type)
+            .await?;
+        Ok(page_of_links(links, limit, offset))
+    }
+
+    /// Find links by any of several source entities
+    ///
+    /// Equivalent to calling [`find_by_source`](Self::find_by_source) for each
+    /// ID and concatenating the results; the GraphQL executor uses it to
+    /// resolve a relation for a whole list of entities at once. Backends able
+    /// to match many IDs in one round-trip (`IN (...)`) should override it.
+    async fn find_by_sources(
+        &self,
+        source_ids: &[Uuid],
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = Vec::new();
+        for source_id in source_ids {
+            links.extend(
+                self.find_by_source(source_id, link_type, target_type)
+                    .await?,
+            );
+        }
+        Ok(links)
+    }
+
+    /// Find links by any of several target entities
+    ///
+    /// See [`find_by_sources`](Self::find_by_sources).
+    async fn find_by_targets(
+        &self,
+        target_ids: &[Uuid],
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = Vec::new();
+        for target_id in target_ids {
+            links.extend(
+                self.find_by_target(target_id, link_type, source_type)
+                    .await?,
+            );
+        }
+        Ok(links)
+    }
+
+    /// Find the links of `tenant` by source entity
+    ///
+    /// Links of other tenants, and links without a tenant, are left out. The
+    /// default implementation filters [`find_by_source`](Self::find_by_source);
+    /// SQL backends add `AND tenant_id = ?` to the query.
+    async fn find_by_source_for_tenant(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+        tenant: &TenantContext,
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = self
+            .find_by_source(source_id, link_type, target_type)
+            .await?;
+        links.retain(|link| tenant.owns(link.tenant_id));
+        Ok(links)
+    }
+
+    /// Find the links of `tenant` by target entity
+    ///
+    /// See [`find_by_source_for_tenant`](Self::find_by_source_for_tenant).
+    async fn find_by_target_for_tenant(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+        tenant: &TenantContext,
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = self
+            .find_by_target(target_id, link_type, source_type)
+            .await?;
+        links.retain(|link| tenant.owns(link.tenant_id));
+        Ok(links)
+    }
+
+    /// Find links by source entity, returning only the given metadata keys
+    ///
+    /// Backends able to extract JSON paths natively (PostgreSQL, MySQL) override
+    /// this to avoid transferring the full metadata blob. The default
+    /// implementation delegates to `find_by_source` and slices metadata in memory.
+    async fn find_by_source_projected(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+        metadata_fields: &[String],
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = self
+            .find_by_source(source_id, link_type, target_type)
+            .await?;
+        for link in &mut links {
+            link.project_metadata(metadata_fields);
+        }
+        Ok(links)
+    }
+
+    /// Find links by target entity, returning only the given metadata keys
+    ///
+    /// See [`find_by_source_projected`](Self::find_by_source_projected).
+    async fn find_by_target_projected(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+        metadata_fields: &[String],
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = self
+            .find_by_target(target_id, link_type, source_type)
+            .await?;
+        for link in &mut links {
+            link.project_metadata(metadata_fields);
+        }
+        Ok(links)
+    }
+
+    /// Find every link touching an entity, in the given direction
+    ///
+    /// `Out` is equivalent to `find_by_source` and `In` to `find_by_target`;
+    /// `Both` merges the two (a self-link is returned once). Results are
+    /// ordered newest first. SQL backends override `Both` with a single
+    /// `source_id = ? OR target_id = ?` query.
+    async fn find_relations(
+        &self,
+        entity_id: &Uuid,
+        direction: RelationDirection,
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = match direction {
+            RelationDirection::Out => self.find_by_source(entity_id, None, None).await?,
+            RelationDirection::In => self.find_by_target(entity_id, None, None).await?,
+            RelationDirection::Both => {
+                let mut links = self.find_by_source(entity_id, None, None).await?;
+                let incoming = self.find_by_target(entity_id, None, None).await?;
+                links.extend(incoming.into_iter().filter(|l| l.source_id != *entity_id));
+                links
+            }
+        };
+        links.sort_by_key(|l| std::cmp::Reverse(l.created_at));
+        Ok(links)
+    }
+
     /// Update a link's metadata
     ///
     /// This allows updating the metadata associated with a link without
@@ -79,6 +691,86 @@ pub trait LinkService: Send + Sync {
     ///
     /// Used when deleting an entity to maintain referential integrity
     async fn delete_by_entity(&self, entity_id: &Uuid) -> Result<()>;
+
+    /// Delete every link of `link_type` matching `filter`, returning how many
+    /// were removed
+    ///
+    /// `filter` is parsed with [`LinkFilterCondition::parse_all`]; an invalid
+    /// filter is an error and deletes nothing. SQL backends override this with
+    /// a single `DELETE ... WHERE`; the default implementation lists the links
+    /// and deletes the matches one by one.
+    async fn delete_where(&self, link_type: &str, filter: Option<&Value>) -> Result<u64> {
+        let conditions = match filter {
+            Some(filter) => LinkFilterCondition::parse_all(filter).map_err(|e| anyhow!(e))?,
+            None => Vec::new(),
+        };
+
+        let mut deleted = 0;
+        for link in self.list().await? {
+            if link.link_type == link_type && conditions.iter().all(|c| c.matches(&link)) {
+                self.delete(&link.id).await?;
+                deleted += 1;
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Delete every link of `tenant` of `link_type` matching `filter`
+    ///
+    /// Links of other tenants, and links without a tenant, are kept. See
+    /// [`delete_where`](Self::delete_where); the default implementation lists
+    /// the links and deletes the matches one by one, MySQL adds
+    /// `AND tenant_id = ?` to its `DELETE`.
+    async fn delete_where_for_tenant(
+        &self,
+        link_type: &str,
+        filter: Option<&Value>,
+        tenant: &TenantContext,
+    ) -> Result<u64> {
+        let conditions = match filter {
+            Some(filter) => LinkFilterCondition::parse_all(filter).map_err(|e| anyhow!(e))?,
+            None => Vec::new(),
+        };
+
+        let mut deleted = 0;
+        for link in self.list().await? {
+            if link.link_type == link_type
+                && tenant.owns(link.tenant_id)
+                && conditions.iter().all(|c| c.matches(&link))
+            {
+                self.delete(&link.id).await?;
+                deleted += 1;
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Whether link creates and deletes record their events in a
+    /// transactional outbox
+    ///
+    /// See [`crate::core::outbox`]. Services returning `true` leave link
+    /// event delivery to the outbox poller.
+    fn writes_outbox(&self) -> bool {
+        false
+    }
+}
+
+/// Order `links` as pages of links are: newest first, ties by id
+pub(crate) fn sort_links_for_paging(links: &mut [LinkEntity]) {
+    links.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(a.id.cmp(&b.id)));
+}
+
+/// Order `links` newest first (ties by id) and keep `limit` of them after
+/// `offset`, with the number of links before slicing
+fn page_of_links(
+    mut links: Vec<LinkEntity>,
+    limit: usize,
+    offset: usize,
+) -> (Vec<LinkEntity>, usize) {
+    let total = links.len();
+    sort_links_for_paging(&mut links);
+    let page = links.into_iter().skip(offset).take(limit).collect();
+    (page, total)
 }
 
 #[cfg(test)]
@@ -0,0 +1,65 @@
+//! Soft deletion and restoration of entities
+//!
+//! A soft-deleted entity keeps its row but carries a `deleted_at` timestamp,
+//! which hides it from `get`, `list` and searches until it is restored. See
+//! [`DataService::soft_delete`](crate::core::DataService::soft_delete) and
+//! [`DataService::restore`](crate::core::DataService::restore); over REST,
+//! `DELETE /{entity_type}/{id}?soft=true` and
+//! `POST /{entity_type}/{id}/restore`. A [`SoftDeleteStatus`] can also move
+//! the entity's `status` along with its `deleted_at`.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+
+/// Error returned when an entity cannot be soft-deleted or restored
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SoftDeleteError {
+    /// No entity exists with this ID (for soft deletion: no live one)
+    #[error("entity not found: {0}")]
+    NotFound(Uuid),
+    /// The entity type does not support soft deletion
+    #[error("soft delete is not supported for this entity type")]
+    Unsupported,
+}
+
+/// Status an entity takes when soft-deleted, and when restored
+///
+/// Set with [`ServerBuilder::with_soft_delete_status`](crate::server::ServerBuilder::with_soft_delete_status)
+/// and applied by
+/// [`DataService::soft_delete_with_status`](crate::core::DataService::soft_delete_with_status)
+/// and
+/// [`DataService::restore_with_status`](crate::core::DataService::restore_with_status).
+/// Restoring reverts to the status the entity had before its deletion when
+/// the backend recorded it, and falls back to `on_restore` otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoftDeleteStatus {
+    /// Status of soft-deleted entities (e.g. `"deleted"`)
+    pub on_delete: String,
+    /// Status of restored entities whose previous status is unknown
+    pub on_restore: String,
+}
+
+impl SoftDeleteStatus {
+    pub fn new(on_delete: impl Into<String>, on_restore: impl Into<String>) -> Self {
+        Self {
+            on_delete: on_delete.into(),
+            on_restore: on_restore.into(),
+        }
+    }
+}
+
+/// `entity` with its `deleted_at`, and its `status` when given, replaced
+pub(crate) fn with_deletion_state<T: Serialize + DeserializeOwned>(
+    entity: &T,
+    deleted_at: Option<DateTime<Utc>>,
+    status: Option<&str>,
+) -> anyhow::Result<T> {
+    let mut value = serde_json::to_value(entity)?;
+    value["deleted_at"] = serde_json::to_value(deleted_at)?;
+    if let Some(status) = status {
+        value["status"] = status.into();
+    }
+    Ok(serde_json::from_value(value)?)
+}
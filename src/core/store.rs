@@ -1,12 +1,178 @@
 //! Store traits for filtering and sorting
+//!
+//! [`QueryBuilder`] builds a backend-agnostic [`Query`] that any
+//! [`QueryableStore`] can run:
+//!
+//! ```rust,ignore
+//! use this::core::store::{QueryBuilder, QueryOp, SortDirection};
+//!
+//! let query = QueryBuilder::new()
+//!     .filter("status", QueryOp::Eq, "active")
+//!     .filter("amount", QueryOp::Gte, 100)
+//!     .sort("created_at", SortDirection::Desc)
+//!     .limit(20)
+//!     .build()?;
+//! let orders = store.query(&query).await?;
+//! ```
 
+use crate::core::entity::Data;
+use crate::core::field::FieldValue;
+use crate::core::query::{FilterClause, FilterOp, SortKey, sort_entities};
+use anyhow::Result;
+use async_trait::async_trait;
 use serde_json::Value;
 
+/// Comparison of a [`QueryBuilder::filter`] condition
+///
+/// Each operator behaves like its `$`-prefixed counterpart in list filters
+/// (see [`FilterClause`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    /// The value must be an array
+    In,
+    /// Substring match; the value must be a string
+    Contains,
+}
+
+impl QueryOp {
+    /// The list filter operator this comparison stands for
+    fn operator(&self) -> &'static str {
+        match self {
+            QueryOp::Eq => "$eq",
+            QueryOp::Ne => "$ne",
+            QueryOp::Gt => "$gt",
+            QueryOp::Gte => "$gte",
+            QueryOp::Lt => "$lt",
+            QueryOp::Lte => "$lte",
+            QueryOp::In => "$in",
+            QueryOp::Contains => "$contains",
+        }
+    }
+}
+
+/// Direction of a [`QueryBuilder::sort`] key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// A filtered, sorted and windowed query over one entity type
+///
+/// Built with [`QueryBuilder`] and run by [`QueryableStore::query`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    /// Conditions every result satisfies
+    pub filters: Vec<FilterClause>,
+    /// Sort keys, in priority order
+    pub sort: Vec<SortKey>,
+    /// Most results returned, after `offset`
+    pub limit: Option<usize>,
+    /// Results skipped after sorting
+    pub offset: usize,
+}
+
+impl Query {
+    /// Evaluate the query in memory over `entities`
+    ///
+    /// Filters match like [`FilterClause::matches_entity`] and keys sort
+    /// like [`sort_entities`].
+    pub fn apply<T: Data>(&self, mut entities: Vec<T>) -> Vec<T> {
+        entities.retain(|e| self.filters.iter().all(|clause| clause.matches_entity(e)));
+        sort_entities(&mut entities, &self.sort);
+        entities
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+/// Fluent builder of a [`Query`]
+///
+/// Conditions are checked when added; the first invalid one (a non-scalar
+/// value, `In` without an array, `Contains` without a string) is reported
+/// by [`build`](Self::build).
+#[derive(Debug, Clone, Default)]
+pub struct QueryBuilder {
+    query: Query,
+    error: Option<String>,
+}
+
+impl QueryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep only entities whose `field` compares to `value` by `op`
+    pub fn filter(mut self, field: &str, op: QueryOp, value: impl Into<Value>) -> Self {
+        let value = value.into();
+        let op = match op {
+            QueryOp::Eq => FieldValue::from_json(&value)
+                .map(FilterOp::Eq)
+                .ok_or_else(|| format!("filter value for '{}' must be a scalar", field)),
+            _ => FilterOp::parse(field, op.operator(), &value),
+        };
+        match op {
+            Ok(op) => self.query.filters.push(FilterClause {
+                field: field.to_string(),
+                op,
+            }),
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+        self
+    }
+
+    /// Sort by `field`, after any earlier sort keys
+    pub fn sort(mut self, field: &str, direction: SortDirection) -> Self {
+        self.query.sort.push(SortKey {
+            field: field.to_string(),
+            descending: direction == SortDirection::Desc,
+        });
+        self
+    }
+
+    /// Return at most `limit` entities
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.query.limit = Some(limit);
+        self
+    }
+
+    /// Skip the first `offset` entities
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.query.offset = offset;
+        self
+    }
+
+    /// The query, or the first invalid condition
+    pub fn build(self) -> Result<Query, String> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.query),
+        }
+    }
+}
+
 /// Trait for stores that support filtering and sorting
 ///
 /// Implement this trait for stores that support generic querying with
 /// filters and sorting capabilities.
-pub trait QueryableStore<T>: Send + Sync {
+#[async_trait]
+pub trait QueryableStore<T: Data>: Send + Sync {
+    /// Run a query, excluding soft-deleted entities
+    ///
+    /// In-memory stores can evaluate it with [`Query::apply`]; SQL
+    /// backends translate it into their own query language.
+    async fn query(&self, query: &Query) -> Result<Vec<T>>;
+
     /// Apply filters to a collection of entities
     ///
     /// # Parameters
@@ -14,8 +180,18 @@ pub trait QueryableStore<T>: Send + Sync {
     /// - `filter`: Filter criteria as JSON Value
     ///
     /// # Returns
-    /// Filtered collection
-    fn apply_filters(&self, data: Vec<T>, filter: &Value) -> Vec<T>;
+    /// Filtered collection. The default implementation parses `filter`
+    /// with [`FilterClause::parse_all`]; nothing matches an invalid filter.
+    fn apply_filters(&self, data: Vec<T>, filter: &Value) -> Vec<T> {
+        match FilterClause::parse_all(filter) {
+            Ok(filters) => Query {
+                filters,
+                ..Query::default()
+            }
+            .apply(data),
+            Err(_) => Vec::new(),
+        }
+    }
 
     /// Apply sorting to a collection of entities
     ///
@@ -24,9 +200,10 @@ pub trait QueryableStore<T>: Send + Sync {
     /// - `sort`: Sort expression (e.g., "field:asc" or "field:desc")
     ///
     /// # Returns
-    /// Sorted collection
-    fn apply_sort(&self, data: Vec<T>, sort: &str) -> Vec<T>;
-
-    /// Get all entities (unfiltered, unsorted)
-    fn list_all(&self) -> Vec<T>;
+    /// Sorted collection. The default implementation parses `sort` with
+    /// [`SortKey::parse_all`].
+    fn apply_sort(&self, mut data: Vec<T>, sort: &str) -> Vec<T> {
+        sort_entities(&mut data, &SortKey::parse_all(sort));
+        data
+    }
 }
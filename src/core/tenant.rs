@@ -0,0 +1,165 @@
+//! Tenant scoping of requests
+//!
+//! With [`ServerBuilder::with_tenancy`](crate::server::ServerBuilder::with_tenancy),
+//! every entity and link request must name its tenant in a header (by
+//! default [`DEFAULT_TENANT_HEADER`]). The tenant id is parsed into a
+//! [`TenantContext`] and stored in the request extensions, where handlers
+//! extract it as `TenantContext` (required) or `Option<TenantContext>`.
+//!
+//! Handlers pass the context to the `*_for_tenant` methods of
+//! [`DataService`](crate::core::DataService) and
+//! [`LinkService`](crate::core::LinkService), which only return records whose
+//! `tenant_id` is the context's. Records of other tenants, and records
+//! without a tenant, are invisible rather than forbidden, so a tenant cannot
+//! probe for ids it does not own.
+
+use axum::extract::{FromRequestParts, OptionalFromRequestParts};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderName, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+use std::convert::Infallible;
+use uuid::Uuid;
+
+use crate::core::extractors::error_response;
+
+/// Header carrying the tenant id when no other name is configured
+pub const DEFAULT_TENANT_HEADER: &str = "x-tenant-id";
+
+/// The tenant a request acts for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TenantContext {
+    pub tenant_id: Uuid,
+}
+
+impl TenantContext {
+    pub fn new(tenant_id: Uuid) -> Self {
+        Self { tenant_id }
+    }
+
+    /// Read the tenant id from `header`
+    pub fn from_headers(headers: &HeaderMap, header: &HeaderName) -> Result<Self, TenancyError> {
+        let value = headers
+            .get(header)
+            .ok_or_else(|| TenancyError::MissingHeader(header.to_string()))?;
+        value
+            .to_str()
+            .ok()
+            .and_then(|v| Uuid::parse_str(v.trim()).ok())
+            .map(Self::new)
+            .ok_or_else(|| TenancyError::InvalidTenantId(header.to_string()))
+    }
+
+    /// Whether a record carrying `tenant_id` belongs to this tenant
+    pub fn owns(&self, tenant_id: Option<Uuid>) -> bool {
+        tenant_id == Some(self.tenant_id)
+    }
+
+    /// Whether an entity serialized as JSON belongs to this tenant
+    pub fn owns_json(&self, entity: &Value) -> bool {
+        let tenant_id = entity
+            .get("tenant_id")
+            .and_then(Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok());
+        self.owns(tenant_id)
+    }
+
+    /// Bind an entity body to this tenant by setting its `tenant_id`
+    ///
+    /// Returns `false`, leaving the body untouched, when it names another
+    /// tenant. Bodies that are not JSON objects are left alone.
+    pub fn bind_json(&self, entity: &mut Value) -> bool {
+        let Value::Object(entity) = entity else {
+            return true;
+        };
+        match entity.get("tenant_id") {
+            None | Some(Value::Null) => {}
+            Some(given) if given.as_str() == Some(&self.tenant_id.to_string()) => {}
+            Some(_) => return false,
+        }
+        entity.insert(
+            "tenant_id".to_string(),
+            Value::String(self.tenant_id.to_string()),
+        );
+        true
+    }
+}
+
+/// A request that cannot be scoped to a tenant
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TenancyError {
+    #[error("missing tenant header '{0}'")]
+    MissingHeader(String),
+    #[error("header '{0}' must hold a tenant UUID")]
+    InvalidTenantId(String),
+}
+
+impl TenancyError {
+    /// Machine-readable kind of error, sent as the body's `code`
+    pub fn code(&self) -> &'static str {
+        match self {
+            TenancyError::MissingHeader(_) => "MISSING_TENANT",
+            TenancyError::InvalidTenantId(_) => "INVALID_TENANT_ID",
+        }
+    }
+}
+
+impl IntoResponse for TenancyError {
+    fn into_response(self) -> Response {
+        error_response(StatusCode::BAD_REQUEST, self.code(), self.to_string())
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for TenantContext {
+    type Rejection = TenancyError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<TenantContext>()
+            .copied()
+            .ok_or_else(|| TenancyError::MissingHeader(DEFAULT_TENANT_HEADER.to_string()))
+    }
+}
+
+impl<S: Send + Sync> OptionalFromRequestParts<S> for TenantContext {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        Ok(parts.extensions.get::<TenantContext>().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_tenant_is_read_from_the_header() {
+        let header = HeaderName::from_static(DEFAULT_TENANT_HEADER);
+        let tenant = Uuid::new_v4();
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(
+            TenantContext::from_headers(&headers, &header),
+            Err(TenancyError::MissingHeader("x-tenant-id".to_string()))
+        );
+
+        headers.insert(&header, HeaderValue::from_static("acme"));
+        assert_eq!(
+            TenantContext::from_headers(&headers, &header),
+            Err(TenancyError::InvalidTenantId("x-tenant-id".to_string()))
+        );
+
+        headers.insert(&header, tenant.to_string().parse().unwrap());
+        let context = TenantContext::from_headers(&headers, &header).unwrap();
+        assert_eq!(context.tenant_id, tenant);
+        assert!(context.owns(Some(tenant)));
+        assert!(!context.owns(Some(Uuid::new_v4())));
+        assert!(!context.owns(None));
+    }
+}
@@ -0,0 +1,157 @@
+//! Minimum interval between updates of the same entity
+//!
+//! Guards externally-synced entities against runaway update loops: with
+//! `min_update_interval` set on an entity, an update arriving sooner than
+//! that after the stored `updated_at` is refused with [`UpdateTooSoon`].
+
+use crate::core::module::{EntityCreator, EntityFetcher};
+use crate::core::soft_delete::SoftDeleteStatus;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// An update arrived before the entity's minimum update interval elapsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("entity was updated too recently, retry in {}s", self.retry_after_secs())]
+pub struct UpdateTooSoon {
+    /// Time left until the entity may be updated again
+    pub retry_after: Duration,
+}
+
+impl UpdateTooSoon {
+    /// `retry_after` rounded up to whole seconds, as sent in `Retry-After`
+    pub fn retry_after_secs(&self) -> u64 {
+        self.retry_after.as_secs() + u64::from(self.retry_after.subsec_nanos() > 0)
+    }
+}
+
+/// Check `stored` (an entity as JSON) against `interval` at `now`
+///
+/// Entities without a parseable `updated_at` are not restricted.
+pub fn check_update_interval(
+    stored: &Value,
+    interval: Duration,
+    now: DateTime<Utc>,
+) -> Result<(), UpdateTooSoon> {
+    let Some(updated_at) = stored
+        .get("updated_at")
+        .and_then(Value::as_str)
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+    else {
+        return Ok(());
+    };
+    let elapsed = (now - updated_at.with_timezone(&Utc))
+        .to_std()
+        .unwrap_or_default();
+    match interval.checked_sub(elapsed) {
+        Some(retry_after) if !retry_after.is_zero() => Err(UpdateTooSoon { retry_after }),
+        _ => Ok(()),
+    }
+}
+
+/// [`EntityCreator`] wrapper refusing updates within the minimum interval
+///
+/// Applied by the server builder to entity types with `min_update_interval`,
+/// so GraphQL and gRPC updates honor it like REST does.
+pub struct UpdateIntervalCreator {
+    inner: Arc<dyn EntityCreator>,
+    fetcher: Arc<dyn EntityFetcher>,
+    interval: Duration,
+}
+
+impl UpdateIntervalCreator {
+    pub fn new(
+        inner: Arc<dyn EntityCreator>,
+        fetcher: Arc<dyn EntityFetcher>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            inner,
+            fetcher,
+            interval,
+        }
+    }
+
+    async fn check(&self, entity_id: &Uuid) -> Result<()> {
+        // Missing entities are left for the inner creator to report
+        if let Ok(stored) = self.fetcher.fetch_as_json(entity_id).await {
+            check_update_interval(&stored, self.interval, Utc::now())?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EntityCreator for UpdateIntervalCreator {
+    async fn create_from_json(&self, entity_data: Value) -> Result<Value> {
+        self.inner.create_from_json(entity_data).await
+    }
+
+    async fn create_owned_from_json(&self, entity_data: Value, owner_id: Uuid) -> Result<Value> {
+        self.inner
+            .create_owned_from_json(entity_data, owner_id)
+            .await
+    }
+
+    async fn update_from_json(&self, entity_id: &Uuid, entity_data: Value) -> Result<Value> {
+        self.check(entity_id).await?;
+        self.inner.update_from_json(entity_id, entity_data).await
+    }
+
+    async fn patch_from_json(&self, entity_id: &Uuid, partial: Value) -> Result<Value> {
+        self.check(entity_id).await?;
+        self.inner.patch_from_json(entity_id, partial).await
+    }
+
+    async fn delete(&self, entity_id: &Uuid) -> Result<()> {
+        self.inner.delete(entity_id).await
+    }
+
+    async fn soft_delete(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.soft_delete(entity_id).await
+    }
+
+    async fn restore(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.restore(entity_id).await
+    }
+
+    async fn soft_delete_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.soft_delete_with_status(entity_id, status).await
+    }
+
+    async fn restore_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.restore_with_status(entity_id, status).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_update_within_interval_is_refused() {
+        let now = Utc::now();
+        let stored =
+            json!({ "updated_at": (now - chrono::Duration::milliseconds(1500)).to_rfc3339() });
+
+        let err = check_update_interval(&stored, Duration::from_secs(5), now).unwrap_err();
+        assert_eq!(err.retry_after, Duration::from_millis(3500));
+        assert_eq!(err.retry_after_secs(), 4);
+
+        assert!(check_update_interval(&stored, Duration::from_secs(1), now).is_ok());
+        assert!(check_update_interval(&json!({}), Duration::from_secs(5), now).is_ok());
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides the configuration structure that holds validators and filters
 //! for an entity. It's generated by the macro system.
 
+use super::error::{FieldError, ValidationError};
 use anyhow::Result;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -60,7 +61,40 @@ impl EntityValidationConfig {
     /// Validate and filter a complete payload
     ///
     /// Returns the filtered payload or a list of validation errors
-    pub fn validate_and_filter(&self, mut payload: Value) -> Result<Value, Vec<String>> {
+    pub fn validate_and_filter(&self, payload: Value) -> Result<Value, Vec<String>> {
+        self.run(payload).map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|(_, error)| match error {
+                    FieldFailure::Filter(e) => e,
+                    FieldFailure::Validator(e) => e,
+                })
+                .collect()
+        })
+    }
+
+    /// Validate and filter a complete payload, reporting every failure as a [`FieldError`]
+    ///
+    /// Each error points at its field (`/status`) and carries the code of the
+    /// failed validator when it has one (e.g. [`FieldError::PATTERN_MISMATCH`]).
+    pub fn validate(&self, payload: Value) -> Result<Value, ValidationError> {
+        self.run(payload).map_err(|errors| {
+            ValidationError::FieldErrors(
+                errors
+                    .into_iter()
+                    .map(|(field, error)| {
+                        let pointer = format!("/{}", field);
+                        match error {
+                            FieldFailure::Filter(e) => FieldError::new(pointer, e),
+                            FieldFailure::Validator(e) => FieldError::from_message(pointer, &e),
+                        }
+                    })
+                    .collect(),
+            )
+        })
+    }
+
+    fn run(&self, mut payload: Value) -> Result<Value, Vec<(String, FieldFailure)>> {
         let mut errors = Vec::new();
 
         // Step 1: Apply all filters
@@ -70,23 +104,29 @@ impl EntityValidationConfig {
                     for filter in field_filters {
                         match filter(field, value.clone()) {
                             Ok(filtered) => *value = filtered,
-                            Err(e) => {
-                                errors.push(format!("Erreur de filtrage sur '{}': {}", field, e));
-                            }
+                            Err(e) => errors.push((
+                                field.clone(),
+                                FieldFailure::Filter(format!(
+                                    "Erreur de filtrage sur '{}': {}",
+                                    field, e
+                                )),
+                            )),
                         }
                     }
                 }
             }
         }
 
-        // Step 2: Apply all validators
+        // Step 2: Apply all validators, absent fields being validated as null
+        // so that `required` catches them
         if let Some(obj) = payload.as_object() {
-            for (field, value) in obj.iter() {
-                if let Some(field_validators) = self.validators.get(field) {
-                    for validator in field_validators {
-                        if let Err(e) = validator(field, value) {
-                            errors.push(e);
-                        }
+            let mut fields: Vec<&String> = self.validators.keys().collect();
+            fields.sort();
+            for field in fields {
+                let value = obj.get(field.as_str()).unwrap_or(&Value::Null);
+                for validator in &self.validators[field] {
+                    if let Err(e) = validator(field, value) {
+                        errors.push((field.clone(), FieldFailure::Validator(e)));
                     }
                 }
             }
@@ -100,6 +140,12 @@ impl EntityValidationConfig {
     }
 }
 
+/// A failed filter or validator of one field
+enum FieldFailure {
+    Filter(String),
+    Validator(String),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,6 +246,19 @@ mod tests {
         assert!(errors[0].contains("too short"));
     }
 
+    #[test]
+    fn test_validators_run_on_absent_fields() {
+        let mut config = EntityValidationConfig::new("order");
+        config.add_validator("number", crate::core::validation::validators::required());
+        config.add_validator("notes", crate::core::validation::validators::max_length(10));
+
+        let err = config.validate(json!({"name": "Test"})).unwrap_err();
+        assert_eq!(err.field_errors().len(), 1);
+        assert_eq!(err.field_errors()[0].field, "/number");
+        assert_eq!(err.field_errors()[0].code, Some(FieldError::REQUIRED));
+        assert!(config.validate(json!({"number": "N-1"})).is_ok());
+    }
+
     // === validate_and_filter: filters only ===
 
     #[test]
@@ -0,0 +1,247 @@
+//! Declarative per-field constraints on entity payloads
+//!
+//! Declared per entity in the configuration:
+//!
+//! ```yaml
+//! entities:
+//!   - singular: user
+//!     plural: users
+//!     fields:
+//!       name: { min_length: 1, max_length: 255 }
+//! ```
+//!
+//! Lengths count characters, not bytes, like SQL `VARCHAR(n)`.
+
+use super::error::{FieldError, ValidationError};
+use crate::core::module::EntityCreator;
+use crate::core::soft_delete::SoftDeleteStatus;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Constraints on a single entity field
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldConstraints {
+    /// Fewest characters a string value may have
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+    /// Most characters a string value may have
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+}
+
+impl FieldConstraints {
+    /// Check `value` of `field`; non-string values are not constrained
+    pub fn check(&self, field: &str, value: &Value) -> Option<FieldError> {
+        let len = value.as_str()?.chars().count();
+        let pointer = format!("/{}", field);
+        if let Some(max) = self.max_length.filter(|max| len > *max) {
+            return Some(too_long(pointer, max, len));
+        }
+        if let Some(min) = self.min_length.filter(|min| len < *min) {
+            return Some(
+                FieldError::new(
+                    pointer,
+                    format!("must be at least {} characters (got {})", min, len),
+                )
+                .with_code(FieldError::TOO_SHORT),
+            );
+        }
+        None
+    }
+}
+
+fn too_long(pointer: String, max: usize, len: usize) -> FieldError {
+    FieldError::new(
+        pointer,
+        format!("must be at most {} characters (got {})", max, len),
+    )
+    .with_code(FieldError::TOO_LONG)
+}
+
+/// Check the top-level fields of `payload` against `constraints`
+///
+/// Absent fields pass, so the same check serves creates and partial updates.
+pub fn check_field_constraints(
+    constraints: &HashMap<String, FieldConstraints>,
+    payload: &Value,
+) -> Result<(), ValidationError> {
+    let Some(obj) = payload.as_object() else {
+        return Ok(());
+    };
+    let mut errors: Vec<FieldError> = constraints
+        .iter()
+        .filter_map(|(field, c)| obj.get(field).and_then(|value| c.check(field, value)))
+        .collect();
+    if errors.is_empty() {
+        return Ok(());
+    }
+    errors.sort_by(|a, b| a.field.cmp(&b.field));
+    Err(ValidationError::FieldErrors(errors))
+}
+
+/// Check values bound to fixed-width SQL columns, as `(column, value, width)`
+///
+/// Used by the SQL backends so an over-long value is reported as a
+/// `TOO_LONG` field error instead of a truncation error from the database.
+#[cfg(any(feature = "mysql", feature = "postgres", test))]
+pub(crate) fn check_column_widths(columns: &[(&str, &str, usize)]) -> Result<(), ValidationError> {
+    let errors: Vec<FieldError> = columns
+        .iter()
+        .filter_map(|(column, value, width)| {
+            let len = value.chars().count();
+            (len > *width).then(|| too_long(format!("/{}", column), *width, len))
+        })
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::FieldErrors(errors))
+    }
+}
+
+/// [`EntityCreator`] wrapper checking payloads against field constraints
+///
+/// Applied by the server builder to every entity type with configured
+/// constraints, so GraphQL, gRPC and link-with-entity creation honor them.
+pub struct ConstrainedCreator {
+    inner: Arc<dyn EntityCreator>,
+    constraints: Arc<HashMap<String, FieldConstraints>>,
+}
+
+impl ConstrainedCreator {
+    pub fn new(
+        inner: Arc<dyn EntityCreator>,
+        constraints: Arc<HashMap<String, FieldConstraints>>,
+    ) -> Self {
+        Self { inner, constraints }
+    }
+}
+
+#[async_trait]
+impl EntityCreator for ConstrainedCreator {
+    async fn create_from_json(&self, entity_data: Value) -> Result<Value> {
+        check_field_constraints(&self.constraints, &entity_data)?;
+        self.inner.create_from_json(entity_data).await
+    }
+
+    async fn create_owned_from_json(&self, entity_data: Value, owner_id: Uuid) -> Result<Value> {
+        check_field_constraints(&self.constraints, &entity_data)?;
+        self.inner
+            .create_owned_from_json(entity_data, owner_id)
+            .await
+    }
+
+    async fn update_from_json(&self, entity_id: &Uuid, entity_data: Value) -> Result<Value> {
+        check_field_constraints(&self.constraints, &entity_data)?;
+        self.inner.update_from_json(entity_id, entity_data).await
+    }
+
+    async fn patch_from_json(&self, entity_id: &Uuid, partial: Value) -> Result<Value> {
+        check_field_constraints(&self.constraints, &partial)?;
+        self.inner.patch_from_json(entity_id, partial).await
+    }
+
+    async fn delete(&self, entity_id: &Uuid) -> Result<()> {
+        self.inner.delete(entity_id).await
+    }
+
+    async fn soft_delete(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.soft_delete(entity_id).await
+    }
+
+    async fn restore(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.restore(entity_id).await
+    }
+
+    async fn soft_delete_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.soft_delete_with_status(entity_id, status).await
+    }
+
+    async fn restore_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.restore_with_status(entity_id, status).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn name_constraints() -> HashMap<String, FieldConstraints> {
+        HashMap::from([(
+            "name".to_string(),
+            FieldConstraints {
+                min_length: Some(2),
+                max_length: Some(5),
+            },
+        )])
+    }
+
+    #[test]
+    fn test_lengths_count_characters() {
+        let constraints = name_constraints();
+        assert!(check_field_constraints(&constraints, &json!({"name": "héhé"})).is_ok());
+        assert!(check_field_constraints(&constraints, &json!({"other": "x"})).is_ok());
+        assert!(check_field_constraints(&constraints, &json!({"name": 123456})).is_ok());
+
+        let err = check_field_constraints(&constraints, &json!({"name": "abcdef"})).unwrap_err();
+        assert_eq!(err.field_errors()[0].field, "/name");
+        assert_eq!(err.field_errors()[0].code, Some(FieldError::TOO_LONG));
+
+        let err = check_field_constraints(&constraints, &json!({"name": "a"})).unwrap_err();
+        assert_eq!(err.field_errors()[0].code, Some(FieldError::TOO_SHORT));
+    }
+
+    #[test]
+    fn test_column_widths() {
+        assert!(check_column_widths(&[("name", "abc", 3)]).is_ok());
+        let err = check_column_widths(&[("name", "abcd", 3), ("status", "ok", 3)]).unwrap_err();
+        assert_eq!(err.field_errors().len(), 1);
+        assert_eq!(err.field_errors()[0].field, "/name");
+        assert_eq!(err.field_errors()[0].code, Some(FieldError::TOO_LONG));
+    }
+
+    struct EchoCreator;
+
+    #[async_trait]
+    impl EntityCreator for EchoCreator {
+        async fn create_from_json(&self, entity_data: Value) -> Result<Value> {
+            Ok(entity_data)
+        }
+
+        async fn update_from_json(&self, _entity_id: &Uuid, entity_data: Value) -> Result<Value> {
+            Ok(entity_data)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_constrained_creator_rejects_before_inner() {
+        let creator = ConstrainedCreator::new(Arc::new(EchoCreator), Arc::new(name_constraints()));
+
+        assert!(
+            creator
+                .create_from_json(json!({"name": "ok"}))
+                .await
+                .is_ok()
+        );
+        let err = creator
+            .update_from_json(&Uuid::new_v4(), json!({"name": "far too long"}))
+            .await
+            .unwrap_err();
+        let err = err.downcast_ref::<ValidationError>().unwrap();
+        assert_eq!(err.field_errors()[0].code, Some(FieldError::TOO_LONG));
+    }
+}
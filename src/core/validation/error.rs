@@ -0,0 +1,158 @@
+//! Structured validation errors
+//!
+//! Unlike the string list returned by
+//! [`EntityValidationConfig::validate_and_filter`](super::EntityValidationConfig::validate_and_filter),
+//! these errors point at the offending value, which lets clients map them
+//! back onto form fields.
+
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+use crate::core::extractors::validation_body;
+
+/// A single failed constraint on an entity payload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    /// JSON pointer to the offending value (`""` for the whole document)
+    pub field: String,
+    /// Human-readable description of the failed constraint
+    pub message: String,
+    /// Machine-readable kind of failure (e.g. [`FieldError::TOO_LONG`])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'static str>,
+}
+
+impl FieldError {
+    /// Code of a string longer than its `max_length`
+    pub const TOO_LONG: &'static str = "TOO_LONG";
+    /// Code of a string shorter than its `min_length`
+    pub const TOO_SHORT: &'static str = "TOO_SHORT";
+    /// Code of a missing or null required field
+    pub const REQUIRED: &'static str = "REQUIRED";
+    /// Code of a number outside its `range`
+    pub const OUT_OF_RANGE: &'static str = "OUT_OF_RANGE";
+    /// Code of a string not matching its `pattern`
+    pub const PATTERN_MISMATCH: &'static str = "PATTERN_MISMATCH";
+    /// Code of a value not in its `one_of` list
+    pub const NOT_ALLOWED: &'static str = "NOT_ALLOWED";
+
+    /// Codes a validator message may start with, as in `"TOO_LONG: ..."`
+    const CODES: [&'static str; 6] = [
+        Self::TOO_LONG,
+        Self::TOO_SHORT,
+        Self::REQUIRED,
+        Self::OUT_OF_RANGE,
+        Self::PATTERN_MISMATCH,
+        Self::NOT_ALLOWED,
+    ];
+
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+            code: None,
+        }
+    }
+
+    /// Build an error from a validator message, lifting a known `CODE: ` prefix into the code
+    pub fn from_message(field: impl Into<String>, message: &str) -> Self {
+        for code in Self::CODES {
+            if let Some(rest) = message
+                .strip_prefix(code)
+                .and_then(|rest| rest.strip_prefix(": "))
+            {
+                return Self::new(field, rest).with_code(code);
+            }
+        }
+        Self::new(field, message)
+    }
+
+    /// Tag the error with a machine-readable code
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// Error returned when an entity payload fails validation
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationError {
+    /// One or more fields violate their constraints
+    #[error("validation failed: {}", format_field_errors(.0))]
+    FieldErrors(Vec<FieldError>),
+}
+
+impl ValidationError {
+    /// The individual field errors
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            Self::FieldErrors(errors) => errors,
+        }
+    }
+}
+
+fn format_field_errors(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|e| {
+            if e.field.is_empty() {
+                e.message.clone()
+            } else {
+                format!("{}: {}", e.field, e.message)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Rendered as `400 Bad Request`, in the same shape as the
+/// [`Validated`](super::Validated) extractor's rejection
+impl IntoResponse for ValidationError {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, Json(validation_body(&self))).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_lists_every_field() {
+        let error = ValidationError::FieldErrors(vec![
+            FieldError::new("/email", "\"x\" is not a \"email\""),
+            FieldError::new("", "\"name\" is a required property"),
+        ]);
+        assert_eq!(
+            error.to_string(),
+            "validation failed: /email: \"x\" is not a \"email\"; \"name\" is a required property"
+        );
+    }
+
+    #[test]
+    fn test_from_message_lifts_known_codes() {
+        let error = FieldError::from_message("/qty", "OUT_OF_RANGE: trop grand");
+        assert_eq!(error.code, Some(FieldError::OUT_OF_RANGE));
+        assert_eq!(error.message, "trop grand");
+
+        let error = FieldError::from_message("/qty", "UNKNOWN: trop grand");
+        assert_eq!(error.code, None);
+        assert_eq!(error.message, "UNKNOWN: trop grand");
+    }
+
+    #[tokio::test]
+    async fn test_into_response_is_bad_request() {
+        let error = ValidationError::FieldErrors(vec![FieldError::new("/age", "too small")]);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["errors"][0]["field"], "/age");
+        assert_eq!(body["errors"][0]["message"], "too small");
+    }
+}
@@ -4,13 +4,13 @@
 //! validates and filters request payloads before they reach handlers.
 
 use super::config::EntityValidationConfig;
+use crate::core::extractors::ExtractorError;
 use axum::{
     Json,
     extract::{FromRequest, Request},
-    http::StatusCode,
     response::{IntoResponse, Response},
 };
-use serde_json::{Value, json};
+use serde_json::Value;
 
 /// Trait for entities that support validation
 ///
@@ -22,6 +22,10 @@ pub trait ValidatableEntity {
 
 /// Axum extractor that validates and filters entity data
 ///
+/// Payloads failing validation are rejected with the
+/// [`ValidationError::FieldErrors`](super::ValidationError::FieldErrors) of
+/// every failed validator.
+///
 /// # Usage
 ///
 /// ```rust,ignore
@@ -68,16 +72,7 @@ where
         // Extract JSON payload
         let Json(payload): Json<Value> = match Json::from_request(req, state).await {
             Ok(json) => json,
-            Err(e) => {
-                return Err((
-                    StatusCode::BAD_REQUEST,
-                    Json(json!({
-                        "error": "Invalid JSON",
-                        "details": e.to_string()
-                    })),
-                )
-                    .into_response());
-            }
+            Err(e) => return Err(ExtractorError::JsonError(e.body_text()).into_response()),
         };
 
         // Determine operation from HTTP method
@@ -91,16 +86,9 @@ where
         let config = T::validation_config(operation);
 
         // Validate and filter
-        match config.validate_and_filter(payload) {
+        match config.validate(payload) {
             Ok(validated_payload) => Ok(Validated::new(validated_payload)),
-            Err(errors) => Err((
-                StatusCode::UNPROCESSABLE_ENTITY,
-                Json(json!({
-                    "error": "Validation failed",
-                    "errors": errors
-                })),
-            )
-                .into_response()),
+            Err(error) => Err(error.into_response()),
         }
     }
 }
@@ -110,7 +98,7 @@ mod tests {
     use super::*;
     use axum::body::Body;
     use axum::extract::FromRequest;
-    use axum::http::Request;
+    use axum::http::{Request, StatusCode};
     use serde_json::json;
 
     /// Dummy entity that implements ValidatableEntity for testing.
@@ -195,11 +183,26 @@ mod tests {
         let result = Validated::<TestEntity>::from_request(req, &()).await;
         assert!(result.is_err());
         match result {
-            Err(response) => assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY),
+            Err(response) => assert_eq!(response.status(), StatusCode::BAD_REQUEST),
             Ok(_) => panic!("expected error"),
         }
     }
 
+    #[tokio::test]
+    async fn test_from_request_missing_required_field_is_a_field_error() {
+        let req = json_request("POST", json!({}));
+        let Err(response) = Validated::<TestEntity>::from_request(req, &()).await else {
+            panic!("expected error");
+        };
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["errors"][0]["field"], "/name");
+        assert_eq!(body["errors"][0]["message"], "name is required");
+    }
+
     #[tokio::test]
     async fn test_from_request_post_too_short_after_trim() {
         // "  a  " → trim → "a" (length 1 < 2) → fails
@@ -207,7 +210,7 @@ mod tests {
         let result = Validated::<TestEntity>::from_request(req, &()).await;
         assert!(result.is_err());
         match result {
-            Err(response) => assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY),
+            Err(response) => assert_eq!(response.status(), StatusCode::BAD_REQUEST),
             Ok(_) => panic!("expected error"),
         }
     }
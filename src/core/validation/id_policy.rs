@@ -0,0 +1,127 @@
+//! Enforcement of per-entity [`IdPolicy`] on creation
+
+use crate::config::IdPolicy;
+use crate::core::module::EntityCreator;
+use crate::core::soft_delete::SoftDeleteStatus;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// [`EntityCreator`] wrapper applying an [`IdPolicy`] to create payloads
+///
+/// Applied by the server builder to every creator whose entity type is not
+/// `ClientOptional`, so GraphQL, gRPC and link-with-entity creation honor the
+/// policy too. Updates are passed through untouched.
+pub struct IdPolicyCreator {
+    inner: Arc<dyn EntityCreator>,
+    policy: IdPolicy,
+}
+
+impl IdPolicyCreator {
+    pub fn new(inner: Arc<dyn EntityCreator>, policy: IdPolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+impl EntityCreator for IdPolicyCreator {
+    async fn create_from_json(&self, mut entity_data: Value) -> Result<Value> {
+        self.policy.apply(&mut entity_data)?;
+        self.inner.create_from_json(entity_data).await
+    }
+
+    async fn create_owned_from_json(
+        &self,
+        mut entity_data: Value,
+        owner_id: Uuid,
+    ) -> Result<Value> {
+        self.policy.apply(&mut entity_data)?;
+        self.inner
+            .create_owned_from_json(entity_data, owner_id)
+            .await
+    }
+
+    async fn update_from_json(&self, entity_id: &Uuid, entity_data: Value) -> Result<Value> {
+        self.inner.update_from_json(entity_id, entity_data).await
+    }
+
+    async fn patch_from_json(&self, entity_id: &Uuid, partial: Value) -> Result<Value> {
+        self.inner.patch_from_json(entity_id, partial).await
+    }
+
+    async fn delete(&self, entity_id: &Uuid) -> Result<()> {
+        self.inner.delete(entity_id).await
+    }
+
+    async fn soft_delete(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.soft_delete(entity_id).await
+    }
+
+    async fn restore(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.restore(entity_id).await
+    }
+
+    async fn soft_delete_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.soft_delete_with_status(entity_id, status).await
+    }
+
+    async fn restore_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.restore_with_status(entity_id, status).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::validation::ValidationError;
+    use serde_json::json;
+
+    struct EchoCreator;
+
+    #[async_trait]
+    impl EntityCreator for EchoCreator {
+        async fn create_from_json(&self, mut entity_data: Value) -> Result<Value> {
+            if entity_data.get("id").is_none() {
+                entity_data["id"] = json!(Uuid::new_v4());
+            }
+            Ok(entity_data)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_generated_replaces_client_id() {
+        let creator = IdPolicyCreator::new(Arc::new(EchoCreator), IdPolicy::ServerGenerated);
+        let spoofed = Uuid::new_v4();
+
+        let created = creator
+            .create_from_json(json!({"id": spoofed, "name": "x"}))
+            .await
+            .unwrap();
+        assert_ne!(created["id"], json!(spoofed));
+    }
+
+    #[tokio::test]
+    async fn test_client_required_rejects_missing_id() {
+        let creator = IdPolicyCreator::new(Arc::new(EchoCreator), IdPolicy::ClientRequired);
+
+        let err = creator
+            .create_from_json(json!({"name": "x"}))
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<ValidationError>().is_some());
+
+        let id = Uuid::new_v4();
+        let created = creator.create_from_json(json!({"id": id})).await.unwrap();
+        assert_eq!(created["id"], json!(id));
+    }
+}
@@ -4,9 +4,19 @@
 //! before it reaches the handlers. It integrates seamlessly with the entity macro system.
 
 pub mod config;
+pub mod constraints;
+pub mod error;
 pub mod extractor;
 pub mod filters;
+pub mod id_policy;
+#[cfg(feature = "json-schema")]
+pub mod schema;
 pub mod validators;
 
 pub use config::EntityValidationConfig;
+pub use constraints::{ConstrainedCreator, FieldConstraints, check_field_constraints};
+pub use error::{FieldError, ValidationError};
 pub use extractor::Validated;
+pub use id_policy::IdPolicyCreator;
+#[cfg(feature = "json-schema")]
+pub use schema::{EntitySchemas, SchemaValidatedCreator, validate_link_metadata};
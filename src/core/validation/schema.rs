@@ -0,0 +1,357 @@
+//! JSON Schema validation of entity payloads
+//!
+//! Teams that need richer constraints than the built-in validators (string
+//! patterns, numeric ranges, nested object shapes) can register a JSON Schema
+//! per entity type with
+//! [`ServerBuilder::with_entity_schema`](crate::server::ServerBuilder::with_entity_schema).
+//! Create bodies are checked against the full schema. Update bodies are merged
+//! into the stored entity, so they may omit required top-level properties;
+//! every other constraint still applies to the fields they do carry.
+
+use super::error::{FieldError, ValidationError};
+use crate::config::ConfigError;
+use crate::core::link::LinkDefinition;
+use crate::core::module::EntityCreator;
+use crate::core::soft_delete::SoftDeleteStatus;
+use anyhow::Result;
+use async_trait::async_trait;
+use jsonschema::Validator;
+use jsonschema::error::ValidationErrorKind;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Compiled JSON Schemas keyed by entity type
+#[derive(Default)]
+pub struct EntitySchemas {
+    validators: HashMap<String, Validator>,
+}
+
+impl EntitySchemas {
+    /// Create an empty schema set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compile `schema` and register it for `entity_type`
+    ///
+    /// Replaces any schema previously registered for the same type.
+    pub fn insert(&mut self, entity_type: &str, schema: &Value) -> Result<(), ConfigError> {
+        let validator =
+            jsonschema::validator_for(schema).map_err(|e| ConfigError::InvalidEntitySchema {
+                entity_type: entity_type.to_string(),
+                reason: e.to_string(),
+            })?;
+        self.validators.insert(entity_type.to_string(), validator);
+        Ok(())
+    }
+
+    /// Whether a schema is registered for `entity_type`
+    pub fn contains(&self, entity_type: &str) -> bool {
+        self.validators.contains_key(entity_type)
+    }
+
+    /// Check a create payload against the full schema
+    ///
+    /// Entity types without a schema always pass.
+    pub fn validate(&self, entity_type: &str, payload: &Value) -> Result<(), ValidationError> {
+        self.check(entity_type, payload, false)
+    }
+
+    /// Check an update payload, ignoring top-level `required` properties
+    pub fn validate_partial(
+        &self,
+        entity_type: &str,
+        payload: &Value,
+    ) -> Result<(), ValidationError> {
+        self.check(entity_type, payload, true)
+    }
+
+    fn check(
+        &self,
+        entity_type: &str,
+        payload: &Value,
+        partial: bool,
+    ) -> Result<(), ValidationError> {
+        let Some(validator) = self.validators.get(entity_type) else {
+            return Ok(());
+        };
+        as_result(schema_errors(validator, payload, partial, ""))
+    }
+}
+
+/// Check link metadata against its definition's `metadata_schema`
+///
+/// Absent metadata is checked as an empty object, so required properties
+/// are still reported. Errors point into the request body (`/metadata/...`).
+/// Definitions without a schema always pass.
+pub fn validate_link_metadata(
+    definition: &LinkDefinition,
+    metadata: Option<&Value>,
+) -> Result<(), ValidationError> {
+    let Some(schema) = &definition.metadata_schema else {
+        return Ok(());
+    };
+    // Checked by `LinksConfig::validate`; compiled here since link
+    // definitions follow config reloads
+    let validator = jsonschema::validator_for(schema).map_err(|e| {
+        ValidationError::FieldErrors(vec![FieldError::new(
+            "/metadata",
+            format!(
+                "invalid metadata_schema for link '{}': {}",
+                definition.link_type, e
+            ),
+        )])
+    })?;
+    let empty = Value::Object(Default::default());
+    as_result(schema_errors(
+        &validator,
+        metadata.unwrap_or(&empty),
+        false,
+        "/metadata",
+    ))
+}
+
+/// Schema violations of `payload`, with paths under `prefix`
+///
+/// `partial` skips top-level `required` properties.
+fn schema_errors(
+    validator: &Validator,
+    payload: &Value,
+    partial: bool,
+    prefix: &str,
+) -> Vec<FieldError> {
+    validator
+        .iter_errors(payload)
+        .filter_map(|error| {
+            let path = format!("{}{}", prefix, error.instance_path().as_str());
+            match error.kind() {
+                ValidationErrorKind::Required { .. }
+                    if partial && error.instance_path().as_str().is_empty() =>
+                {
+                    None
+                }
+                // Point at the missing property rather than its parent object
+                ValidationErrorKind::Required { property } => Some(FieldError::new(
+                    format!("{}/{}", path, property.as_str().unwrap_or_default()),
+                    error.to_string(),
+                )),
+                _ => Some(FieldError::new(path, error.to_string())),
+            }
+        })
+        .collect()
+}
+
+fn as_result(errors: Vec<FieldError>) -> Result<(), ValidationError> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::FieldErrors(errors))
+    }
+}
+
+/// [`EntityCreator`] wrapper that validates payloads before delegating
+///
+/// Applied by the server builder to every creator whose entity type has a
+/// schema, so GraphQL, gRPC and link-with-entity creation are covered too.
+pub struct SchemaValidatedCreator {
+    inner: Arc<dyn EntityCreator>,
+    schemas: Arc<EntitySchemas>,
+    entity_type: String,
+}
+
+impl SchemaValidatedCreator {
+    pub fn new(
+        inner: Arc<dyn EntityCreator>,
+        schemas: Arc<EntitySchemas>,
+        entity_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            inner,
+            schemas,
+            entity_type: entity_type.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl EntityCreator for SchemaValidatedCreator {
+    async fn create_from_json(&self, entity_data: Value) -> Result<Value> {
+        self.schemas.validate(&self.entity_type, &entity_data)?;
+        self.inner.create_from_json(entity_data).await
+    }
+
+    async fn create_owned_from_json(&self, entity_data: Value, owner_id: Uuid) -> Result<Value> {
+        self.schemas.validate(&self.entity_type, &entity_data)?;
+        self.inner
+            .create_owned_from_json(entity_data, owner_id)
+            .await
+    }
+
+    async fn update_from_json(&self, entity_id: &Uuid, entity_data: Value) -> Result<Value> {
+        self.schemas
+            .validate_partial(&self.entity_type, &entity_data)?;
+        self.inner.update_from_json(entity_id, entity_data).await
+    }
+
+    async fn patch_from_json(&self, entity_id: &Uuid, partial: Value) -> Result<Value> {
+        self.schemas.validate_partial(&self.entity_type, &partial)?;
+        self.inner.patch_from_json(entity_id, partial).await
+    }
+
+    async fn delete(&self, entity_id: &Uuid) -> Result<()> {
+        self.inner.delete(entity_id).await
+    }
+
+    async fn soft_delete(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.soft_delete(entity_id).await
+    }
+
+    async fn restore(&self, entity_id: &Uuid) -> Result<Value> {
+        self.inner.restore(entity_id).await
+    }
+
+    async fn soft_delete_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.soft_delete_with_status(entity_id, status).await
+    }
+
+    async fn restore_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<Value> {
+        self.inner.restore_with_status(entity_id, status).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn order_schemas() -> EntitySchemas {
+        let mut schemas = EntitySchemas::new();
+        schemas
+            .insert(
+                "order",
+                &json!({
+                    "type": "object",
+                    "required": ["number", "total"],
+                    "properties": {
+                        "number": { "type": "string", "pattern": "^ORD-[0-9]+$" },
+                        "total": { "type": "number", "minimum": 0 },
+                        "shipping": {
+                            "type": "object",
+                            "required": ["city"],
+                            "properties": { "city": { "type": "string" } }
+                        }
+                    }
+                }),
+            )
+            .unwrap();
+        schemas
+    }
+
+    #[test]
+    fn test_valid_payload_passes() {
+        let schemas = order_schemas();
+        assert!(
+            schemas
+                .validate("order", &json!({"number": "ORD-1", "total": 12.5}))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_invalid_payload_reports_each_field() {
+        let schemas = order_schemas();
+        let err = schemas
+            .validate("order", &json!({"number": "1", "shipping": {}}))
+            .unwrap_err();
+        let mut fields: Vec<&str> = err
+            .field_errors()
+            .iter()
+            .map(|e| e.field.as_str())
+            .collect();
+        fields.sort();
+        assert_eq!(fields, vec!["/number", "/shipping/city", "/total"]);
+    }
+
+    #[test]
+    fn test_partial_ignores_top_level_required_only() {
+        let schemas = order_schemas();
+        assert!(
+            schemas
+                .validate_partial("order", &json!({"total": 3}))
+                .is_ok()
+        );
+
+        let err = schemas
+            .validate_partial("order", &json!({"total": -1, "shipping": {}}))
+            .unwrap_err();
+        let mut fields: Vec<&str> = err
+            .field_errors()
+            .iter()
+            .map(|e| e.field.as_str())
+            .collect();
+        fields.sort();
+        assert_eq!(fields, vec!["/shipping/city", "/total"]);
+    }
+
+    struct EchoCreator;
+
+    #[async_trait]
+    impl EntityCreator for EchoCreator {
+        async fn create_from_json(&self, entity_data: Value) -> Result<Value> {
+            Ok(entity_data)
+        }
+
+        async fn update_from_json(&self, _entity_id: &Uuid, entity_data: Value) -> Result<Value> {
+            Ok(entity_data)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validated_creator_rejects_before_delegating() {
+        let creator =
+            SchemaValidatedCreator::new(Arc::new(EchoCreator), Arc::new(order_schemas()), "order");
+
+        let err = creator
+            .create_from_json(json!({"number": "ORD-1"}))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ValidationError>(),
+            Some(ValidationError::FieldErrors(errors)) if errors[0].field == "/total"
+        ));
+
+        let updated = creator
+            .update_from_json(&Uuid::new_v4(), json!({"total": 1}))
+            .await
+            .unwrap();
+        assert_eq!(updated["total"], 1);
+    }
+
+    #[test]
+    fn test_unknown_entity_type_passes() {
+        let schemas = order_schemas();
+        assert!(schemas.validate("invoice", &json!({"anything": 1})).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_schema_is_config_error() {
+        let mut schemas = EntitySchemas::new();
+        let err = schemas
+            .insert("order", &json!({"type": "not-a-type"}))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidEntitySchema { ref entity_type, .. } if entity_type == "order"
+        ));
+    }
+}
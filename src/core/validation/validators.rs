@@ -2,13 +2,14 @@
 //!
 //! These validators are used by the macro system to validate entity fields
 
+use regex::Regex;
 use serde_json::Value;
 
 /// Validator: field is required (not null)
 pub fn required() -> impl Fn(&str, &Value) -> Result<(), String> + Send + Sync + Clone {
     |field: &str, value: &Value| {
         if value.is_null() {
-            Err(format!("Le champ '{}' est requis", field))
+            Err(format!("REQUIRED: Le champ '{}' est requis", field))
         } else {
             Ok(())
         }
@@ -65,6 +66,28 @@ pub fn string_length(
     }
 }
 
+/// Validator: string must not exceed `max` characters
+pub fn max_length(max: usize) -> impl Fn(&str, &Value) -> Result<(), String> + Send + Sync + Clone {
+    move |field: &str, value: &Value| match value.as_str().map(|s| s.chars().count()) {
+        Some(len) if len > max => Err(format!(
+            "TOO_LONG: '{}' ne doit pas dépasser {} caractères (actuellement: {})",
+            field, max, len
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Validator: string must have at least `min` characters
+pub fn min_length(min: usize) -> impl Fn(&str, &Value) -> Result<(), String> + Send + Sync + Clone {
+    move |field: &str, value: &Value| match value.as_str().map(|s| s.chars().count()) {
+        Some(len) if len < min => Err(format!(
+            "TOO_SHORT: '{}' doit avoir au moins {} caractères (actuellement: {})",
+            field, min, len
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Validator: number must not exceed maximum
 pub fn max_value(max: f64) -> impl Fn(&str, &Value) -> Result<(), String> + Send + Sync + Clone {
     move |field: &str, value: &Value| {
@@ -83,6 +106,62 @@ pub fn max_value(max: f64) -> impl Fn(&str, &Value) -> Result<(), String> + Send
     }
 }
 
+/// Validator: number must lie within `min..=max`
+pub fn range(
+    min: f64,
+    max: f64,
+) -> impl Fn(&str, &Value) -> Result<(), String> + Send + Sync + Clone {
+    move |field: &str, value: &Value| match value.as_f64() {
+        Some(num) if num < min || num > max => Err(format!(
+            "OUT_OF_RANGE: '{}' doit être compris entre {} et {} (valeur: {})",
+            field, min, max, num
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Validator: string must match the regular expression `pattern`
+///
+/// The expression is not anchored: use `^...$` to match the whole string.
+///
+/// # Panics
+///
+/// Panics if `pattern` is not a valid regular expression.
+pub fn pattern(pattern: &str) -> impl Fn(&str, &Value) -> Result<(), String> + Send + Sync + Clone {
+    let regex = Regex::new(pattern)
+        .unwrap_or_else(|e| panic!("invalid pattern validator '{}': {}", pattern, e));
+    move |field: &str, value: &Value| match value.as_str() {
+        Some(s) if !regex.is_match(s) => Err(format!(
+            "PATTERN_MISMATCH: '{}' ne respecte pas le format {} (valeur actuelle: {})",
+            field,
+            regex.as_str(),
+            s
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Validator: value must equal one of `allowed`
+///
+/// Unlike [`in_list`], values of any JSON type are compared, so
+/// `one_of(vec![json!(1), json!(2)])` works for numbers too.
+pub fn one_of(
+    allowed: Vec<Value>,
+) -> impl Fn(&str, &Value) -> Result<(), String> + Send + Sync + Clone {
+    move |field: &str, value: &Value| {
+        if value.is_null() || allowed.contains(value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "NOT_ALLOWED: '{}' doit être l'une des valeurs: {} (valeur actuelle: {})",
+                field,
+                Value::Array(allowed.clone()),
+                value
+            ))
+        }
+    }
+}
+
 /// Validator: value must be in allowed list
 pub fn in_list(
     allowed: Vec<String>,
@@ -173,6 +252,16 @@ mod tests {
         assert!(v("tags", &json!([1, 2, 3])).is_ok());
     }
 
+    #[test]
+    fn test_required_error_carries_its_code() {
+        let v = required();
+        assert!(
+            v("name", &json!(null))
+                .unwrap_err()
+                .starts_with("REQUIRED:")
+        );
+    }
+
     // === optional() ===
 
     #[test]
@@ -269,6 +358,24 @@ mod tests {
         assert!(v("age", &json!(42)).is_ok());
     }
 
+    // === max_length() / min_length() ===
+
+    #[test]
+    fn test_max_length_counts_characters() {
+        let v = max_length(3);
+        assert!(v("name", &json!("été")).is_ok());
+        let err = v("name", &json!("abcd")).unwrap_err();
+        assert!(err.starts_with("TOO_LONG"));
+        assert!(v("age", &json!(12345)).is_ok());
+    }
+
+    #[test]
+    fn test_min_length_too_short_returns_error() {
+        let v = min_length(2);
+        assert!(v("name", &json!("ab")).is_ok());
+        assert!(v("name", &json!("a")).unwrap_err().starts_with("TOO_SHORT"));
+    }
+
     // === max_value() ===
 
     #[test]
@@ -364,4 +471,53 @@ mod tests {
         let v = date_format("%d/%m/%Y");
         assert!(v("date", &json!("15/01/2024")).is_ok());
     }
+
+    // === range() ===
+
+    #[test]
+    fn test_range_bounds_are_inclusive() {
+        let v = range(1.0, 5.0);
+        assert!(v("qty", &json!(1)).is_ok());
+        assert!(v("qty", &json!(5.0)).is_ok());
+        assert!(v("qty", &json!("ten")).is_ok());
+        assert!(
+            v("qty", &json!(0))
+                .unwrap_err()
+                .starts_with("OUT_OF_RANGE:")
+        );
+        assert!(v("qty", &json!(5.5)).is_err());
+    }
+
+    // === pattern() ===
+
+    #[test]
+    fn test_pattern_matches_strings_only() {
+        let v = pattern(r"^[^@\s]+@[^@\s]+$");
+        assert!(v("email", &json!("a@example.com")).is_ok());
+        assert!(v("email", &json!(42)).is_ok());
+        let err = v("email", &json!("not-an-email")).unwrap_err();
+        assert!(err.starts_with("PATTERN_MISMATCH:"));
+        assert!(err.contains("not-an-email"));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid pattern validator")]
+    fn test_pattern_rejects_invalid_regex() {
+        let _ = pattern("(unclosed");
+    }
+
+    // === one_of() ===
+
+    #[test]
+    fn test_one_of_compares_json_values() {
+        let v = one_of(vec![json!("draft"), json!("sent"), json!(3)]);
+        assert!(v("status", &json!("sent")).is_ok());
+        assert!(v("status", &json!(3)).is_ok());
+        assert!(v("status", &json!(null)).is_ok());
+        assert!(
+            v("status", &json!("3"))
+                .unwrap_err()
+                .starts_with("NOT_ALLOWED:")
+        );
+    }
 }
@@ -0,0 +1,120 @@
+//! Warnings for partially successful responses
+//!
+//! Some operations mostly work: a batch where a few items are rejected, a
+//! list whose links could not all be enriched. Instead of failing the whole
+//! request, they answer with their data plus a `warnings` array, each entry
+//! a [`Warning`] naming the item it concerns:
+//!
+//! ```json
+//! {
+//!   "data": [...],
+//!   "warnings": [
+//!     {"code": "ENRICHMENT_FAILED", "message": "...", "item": {"link_id": "..."}}
+//!   ]
+//! }
+//! ```
+//!
+//! List endpoints add `warnings` next to `data` and `pagination` and keep
+//! `200 OK`. Batch-style handlers can use [`PartialResponse`], which answers
+//! `207 Multi-Status` when any item failed.
+
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use serde_json::Value;
+
+/// A problem with one item of an otherwise successful response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Warning {
+    /// Machine-readable kind of problem (e.g. [`Warning::ENRICHMENT_FAILED`])
+    pub code: &'static str,
+    /// Human-readable description
+    pub message: String,
+    /// The item concerned (an id, an index, or the rejected payload)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<Value>,
+}
+
+impl Warning {
+    /// Code of a linked entity that could not be embedded in its link
+    pub const ENRICHMENT_FAILED: &'static str = "ENRICHMENT_FAILED";
+    /// Code of a batch item that was rejected while the others were applied
+    pub const ITEM_REJECTED: &'static str = "ITEM_REJECTED";
+
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            item: None,
+        }
+    }
+
+    /// Attach the item the warning concerns
+    pub fn with_item(mut self, item: Value) -> Self {
+        self.item = Some(item);
+        self
+    }
+}
+
+/// Response carrying data along with warnings about the items that failed
+///
+/// Serialized as `{"data": ..., "warnings": [...]}` (`warnings` omitted when
+/// empty), with `207 Multi-Status` if there are warnings and `200 OK`
+/// otherwise.
+#[derive(Debug, Serialize)]
+pub struct PartialResponse<T> {
+    pub data: T,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+}
+
+impl<T> PartialResponse<T> {
+    pub fn new(data: T, warnings: Vec<Warning>) -> Self {
+        Self { data, warnings }
+    }
+}
+
+impl<T: Serialize> IntoResponse for PartialResponse<T> {
+    fn into_response(self) -> Response {
+        let status = if self.warnings.is_empty() {
+            StatusCode::OK
+        } else {
+            StatusCode::MULTI_STATUS
+        };
+        (status, Json(self)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_partial_response_is_multi_status_with_warnings() {
+        let warning =
+            Warning::new(Warning::ITEM_REJECTED, "name is required").with_item(json!({"index": 1}));
+        let response = PartialResponse::new(vec![json!({"id": 1})], vec![warning]).into_response();
+        assert_eq!(response.status(), StatusCode::MULTI_STATUS);
+
+        let body: Value =
+            serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap())
+                .unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "data": [{"id": 1}],
+                "warnings": [{
+                    "code": "ITEM_REJECTED",
+                    "message": "name is required",
+                    "item": {"index": 1}
+                }]
+            })
+        );
+
+        let response = PartialResponse::new(json!([]), Vec::new()).into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -150,6 +150,63 @@ macro_rules! link_fields {
 ///     vec!["admin".to_string()],
 /// );
 /// ```
+///
+/// Fields that must never leave the server can be declared after the field
+/// list; they become [`Data::redacted_fields`](crate::core::Data::redacted_fields)
+/// for every caller:
+///
+/// ```rust,ignore
+/// impl_data_entity!(
+///     User,
+///     "user",
+///     ["name", "email"],
+///     {
+///         email: String,
+///         password_hash: String,
+///     },
+///     redacted: ["password_hash"]
+/// );
+/// ```
+///
+/// Fields no two live entities may share follow, as
+/// [`Data::unique_fields`](crate::core::Data::unique_fields); their values
+/// are also served by `field_value`:
+///
+/// ```rust,ignore
+/// impl_data_entity!(
+///     User,
+///     "user",
+///     ["name", "email"],
+///     {
+///         email: String,
+///     },
+///     unique: ["email"]
+/// );
+/// ```
+///
+/// Methods of the type whose results are served on reads, but never
+/// stored, come last as
+/// [`Data::computed_fields`](crate::core::Data::computed_fields), each
+/// under the method's name:
+///
+/// ```rust,ignore
+/// impl_data_entity!(
+///     Person,
+///     "person",
+///     ["name"],
+///     {
+///         first_name: String,
+///         last_name: String,
+///     },
+///     computed: [full_name]
+/// );
+///
+/// impl Person {
+///     pub fn full_name(&self) -> String {
+///         format!("{} {}", self.first_name, self.last_name)
+///     }
+/// }
+/// ```
 #[macro_export]
 macro_rules! impl_data_entity {
     (
@@ -159,6 +216,47 @@ macro_rules! impl_data_entity {
         {
             $( $specific_field:ident : $specific_type:ty ),* $(,)?
         }
+        $(,)?
+    ) => {
+        $crate::impl_data_entity!(
+            @entity $type,
+            $type_name,
+            [ $( $indexed_field ),* ],
+            { $( $specific_field : $specific_type ),* },
+            [],
+            [],
+            []
+        );
+    };
+    (
+        $type:ident,
+        $type_name:expr,
+        [ $( $indexed_field:expr ),* $(,)? ],
+        {
+            $( $specific_field:ident : $specific_type:ty ),* $(,)?
+        },
+        $( redacted: [ $( $redacted_field:expr ),* $(,)? ] $(,)? )?
+        $( unique: [ $( $unique_field:expr ),* $(,)? ] $(,)? )?
+        $( computed: [ $( $computed_field:ident ),* $(,)? ] $(,)? )?
+    ) => {
+        $crate::impl_data_entity!(
+            @entity $type,
+            $type_name,
+            [ $( $indexed_field ),* ],
+            { $( $specific_field : $specific_type ),* },
+            [ $( $( $redacted_field ),* )? ],
+            [ $( $( $unique_field ),* )? ],
+            [ $( $( $computed_field ),* )? ]
+        );
+    };
+    (
+        @entity $type:ident,
+        $type_name:expr,
+        [ $( $indexed_field:expr ),* ],
+        { $( $specific_field:ident : $specific_type:ty ),* },
+        [ $( $redacted_field:expr ),* ],
+        [ $( $unique_field:expr ),* ],
+        [ $( $computed_field:ident ),* ]
     ) => {
         #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize)]
         pub struct $type {
@@ -183,6 +281,18 @@ macro_rules! impl_data_entity {
 
             /// Name of this data entity
             pub name: String,
+
+            /// User who created this entity (see `Entity::owner_id`)
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub owner_id: Option<::uuid::Uuid>,
+
+            /// Subject that created this entity (see `Entity::created_by`)
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub created_by: Option<String>,
+
+            /// Subject that last updated this entity
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub updated_by: Option<String>,
             $( pub $specific_field : $specific_type ),*
         }
 
@@ -195,7 +305,7 @@ macro_rules! impl_data_entity {
                 static PLURAL: OnceLock<&'static str> = OnceLock::new();
                 PLURAL.get_or_init(|| {
                     Box::leak(
-                        $crate::core::pluralize::Pluralizer::pluralize($type_name)
+                        $crate::core::pluralize::Pluralizer::standard().pluralize($type_name)
                             .into_boxed_str()
                     )
                 })
@@ -234,6 +344,26 @@ macro_rules! impl_data_entity {
             fn status(&self) -> &str {
                 &self.status
             }
+
+            fn owner_id(&self) -> Option<::uuid::Uuid> {
+                self.owner_id
+            }
+
+            fn created_by(&self) -> Option<&str> {
+                self.created_by.as_deref()
+            }
+
+            fn updated_by(&self) -> Option<&str> {
+                self.updated_by.as_deref()
+            }
+
+            fn set_created_by(&mut self, subject: Option<String>) {
+                self.created_by = subject;
+            }
+
+            fn set_updated_by(&mut self, subject: Option<String>) {
+                self.updated_by = subject;
+            }
         }
 
         // Implement Data trait
@@ -250,49 +380,92 @@ macro_rules! impl_data_entity {
                 match field {
                     "name" => Some($crate::core::field::FieldValue::String(self.name.clone())),
                     "status" => Some($crate::core::field::FieldValue::String(self.status.clone())),
+                    _ if <Self as $crate::core::entity::Data>::unique_fields().contains(&field) => {
+                        $crate::core::field::FieldValue::from_serialized(self, field)
+                    }
                     _ => None,
                 }
             }
+
+            fn redacted_fields(
+                _context: &$crate::core::auth::AuthContext,
+            ) -> &'static [&'static str] {
+                &[ $( $redacted_field ),* ]
+            }
+
+            fn unique_fields() -> &'static [&'static str] {
+                &[ $( $unique_field ),* ]
+            }
+
+            fn computed_fields(&self) -> $crate::core::entity::ComputedFields {
+                #[allow(unused_mut)]
+                let mut fields = $crate::core::entity::ComputedFields::new();
+                $(
+                    fields.insert(
+                        stringify!($computed_field).to_string(),
+                        $crate::core::entity::computed_value(&self.$computed_field()),
+                    );
+                )*
+                fields
+            }
         }
 
         // Utility methods
         impl $type {
             /// Create a new instance of this entity
+            ///
+            /// The id is a random UUIDv4 (see `core::ids::new_id`); a
+            /// server's `IdStrategy` only applies to entities created
+            /// through it.
             pub fn new(
                 name: String,
                 status: String,
                 $( $specific_field: $specific_type ),*
             ) -> Self {
                 Self {
-                    id: ::uuid::Uuid::new_v4(),
+                    id: $crate::core::ids::new_id(),
                     entity_type: $type_name.to_string(),
                     created_at: ::chrono::Utc::now(),
                     updated_at: ::chrono::Utc::now(),
                     deleted_at: None,
                     status,
                     name,
+                    owner_id: None,
+                    created_by: None,
+                    updated_by: None,
                     $( $specific_field ),*
                 }
             }
 
+            /// Record `owner_id` as the user owning this entity
+            #[allow(dead_code)]
+            pub fn with_owner(mut self, owner_id: ::uuid::Uuid) -> Self {
+                self.owner_id = Some(owner_id);
+                self
+            }
+
             /// Soft delete this entity (sets deleted_at timestamp)
+            #[allow(dead_code)]
             pub fn soft_delete(&mut self) {
                 self.deleted_at = Some(::chrono::Utc::now());
                 self.updated_at = ::chrono::Utc::now();
             }
 
             /// Restore a soft-deleted entity (clears deleted_at timestamp)
+            #[allow(dead_code)]
             pub fn restore(&mut self) {
                 self.deleted_at = None;
                 self.updated_at = ::chrono::Utc::now();
             }
 
             /// Update the updated_at timestamp to now
+            #[allow(dead_code)]
             pub fn touch(&mut self) {
                 self.updated_at = ::chrono::Utc::now();
             }
 
             /// Change the entity status
+            #[allow(dead_code)]
             pub fn set_status(&mut self, status: String) {
                 self.status = status;
                 self.touch();
@@ -377,7 +550,7 @@ macro_rules! impl_link_entity {
                 static PLURAL: OnceLock<&'static str> = OnceLock::new();
                 PLURAL.get_or_init(|| {
                     Box::leak(
-                        $crate::core::pluralize::Pluralizer::pluralize($type_name)
+                        $crate::core::pluralize::Pluralizer::standard().pluralize($type_name)
                             .into_boxed_str()
                     )
                 })
@@ -436,6 +609,10 @@ macro_rules! impl_link_entity {
         // Utility methods
         impl $type {
             /// Create a new link instance
+            ///
+            /// The id is a random UUIDv4 (see `core::ids::new_id`); a
+            /// server's `IdStrategy` replaces it when the link is created
+            /// through it.
             pub fn new(
                 link_type: String,
                 source_id: ::uuid::Uuid,
@@ -444,7 +621,7 @@ macro_rules! impl_link_entity {
                 $( $specific_field: $specific_type ),*
             ) -> Self {
                 Self {
-                    id: ::uuid::Uuid::new_v4(),
+                    id: $crate::core::ids::new_id(),
                     entity_type: $type_name.to_string(),
                     created_at: ::chrono::Utc::now(),
                     updated_at: ::chrono::Utc::now(),
@@ -511,6 +688,8 @@ macro_rules! impl_link_entity {
 ///         },
 ///         update: {
 ///             amount: [optional, positive],
+///             email: [optional, pattern(r"^[^@\s]+@[^@\s]+$")],
+///             status: [optional, one_of("draft", "sent", "paid")],
 ///         },
 ///     },
 ///     filters: {
@@ -625,6 +804,18 @@ macro_rules! add_validators_for_field {
         $crate::add_validators_for_field!($config, $field, $( $rest )*);
     };
 
+    // max_length with parameter
+    ($config:expr, $field:expr, max_length($max:expr) $( $rest:tt )*) => {
+        $config.add_validator($field, $crate::core::validation::validators::max_length($max));
+        $crate::add_validators_for_field!($config, $field, $( $rest )*);
+    };
+
+    // min_length with parameter
+    ($config:expr, $field:expr, min_length($min:expr) $( $rest:tt )*) => {
+        $config.add_validator($field, $crate::core::validation::validators::min_length($min));
+        $crate::add_validators_for_field!($config, $field, $( $rest )*);
+    };
+
     // max_value with parameter
     ($config:expr, $field:expr, max_value($max:expr) $( $rest:tt )*) => {
         $config.add_validator($field, $crate::core::validation::validators::max_value($max));
@@ -637,6 +828,24 @@ macro_rules! add_validators_for_field {
         $crate::add_validators_for_field!($config, $field, $( $rest )*);
     };
 
+    // range with bounds
+    ($config:expr, $field:expr, range($min:expr, $max:expr) $( $rest:tt )*) => {
+        $config.add_validator($field, $crate::core::validation::validators::range($min as f64, $max as f64));
+        $crate::add_validators_for_field!($config, $field, $( $rest )*);
+    };
+
+    // pattern with a regular expression
+    ($config:expr, $field:expr, pattern($regex:expr) $( $rest:tt )*) => {
+        $config.add_validator($field, $crate::core::validation::validators::pattern($regex));
+        $crate::add_validators_for_field!($config, $field, $( $rest )*);
+    };
+
+    // one_of with values of any JSON type
+    ($config:expr, $field:expr, one_of($( $value:expr ),* $(,)?) $( $rest:tt )*) => {
+        $config.add_validator($field, $crate::core::validation::validators::one_of(vec![$( ($value).into() ),*]));
+        $crate::add_validators_for_field!($config, $field, $( $rest )*);
+    };
+
     // date_format with format string
     ($config:expr, $field:expr, date_format($format:expr) $( $rest:tt )*) => {
         $config.add_validator($field, $crate::core::validation::validators::date_format($format));
@@ -689,6 +898,47 @@ mod tests {
         }
     );
 
+    // Test Data entity with a redacted field
+    impl_data_entity!(
+        TestAccount,
+        "test_account",
+        ["name"],
+        {
+            password_hash: String,
+        },
+        redacted: ["password_hash"]
+    );
+
+    // Test Data entity with declarative validators
+    impl_data_entity_validated!(
+        TestContact,
+        "test_contact",
+        ["name", "email"],
+        {
+            email: String,
+            age: Option<i64>,
+        },
+        validate: {
+            create: {
+                email: [required pattern(r"^[^@\s]+@[^@\s]+$")],
+                status: [required one_of("active", "archived")],
+                age: [range(0, 150)],
+            },
+        },
+        filters: {}
+    );
+
+    // Test Data entity with a unique field
+    impl_data_entity!(
+        TestMember,
+        "test_member",
+        ["name"],
+        {
+            email: String,
+        },
+        unique: ["email"]
+    );
+
     // Test Link entity
     impl_link_entity!(
         TestOwnerLink,
@@ -698,6 +948,36 @@ mod tests {
         }
     );
 
+    #[test]
+    fn test_redacted_fields_are_declared_in_the_macro() {
+        assert!(TestUser::redacted_fields(&AuthContext::Anonymous).is_empty());
+        assert_eq!(
+            TestAccount::redacted_fields(&AuthContext::Admin {
+                admin_id: Uuid::new_v4()
+            }),
+            &["password_hash"]
+        );
+        let account = TestAccount::new("a".to_string(), "active".to_string(), "x".to_string());
+        assert_eq!(account.password_hash, "x");
+    }
+
+    #[test]
+    fn test_unique_fields_are_declared_in_the_macro() {
+        assert!(TestUser::unique_fields().is_empty());
+        assert_eq!(TestMember::unique_fields(), &["email"]);
+        let member = TestMember::new(
+            "Ada".to_string(),
+            "active".to_string(),
+            "ada@example.com".to_string(),
+        );
+        assert_eq!(
+            member.field_value("email"),
+            Some(crate::core::field::FieldValue::String(
+                "ada@example.com".to_string()
+            ))
+        );
+    }
+
     #[test]
     fn test_data_entity_creation() {
         let user = TestUser::new(
@@ -864,4 +1144,54 @@ mod tests {
         assert!(fields.contains(&"email"));
         assert_eq!(fields.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_validated_entity_aggregates_field_errors() {
+        use axum::extract::FromRequest;
+
+        let request = axum::http::Request::post("/test_contacts")
+            .header("content-type", "application/json")
+            .body(axum::body::Body::from(
+                serde_json::json!({"name": "Ada", "email": "not-an-email", "status": "deleted"})
+                    .to_string(),
+            ))
+            .unwrap();
+        let Err(response) = Validated::<TestContact>::from_request(request, &()).await else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), 4096)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let errors: Vec<(&str, &str)> = body["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| (e["field"].as_str().unwrap(), e["code"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![("/email", "PATTERN_MISMATCH"), ("/status", "NOT_ALLOWED")]
+        );
+
+        let contact = TestContact::new(
+            "Ada".to_string(),
+            "active".to_string(),
+            "ada@example.com".to_string(),
+            Some(36),
+        );
+        let request = axum::http::Request::post("/test_contacts")
+            .header("content-type", "application/json")
+            .body(axum::body::Body::from(
+                serde_json::to_string(&contact).unwrap(),
+            ))
+            .unwrap();
+        assert!(
+            Validated::<TestContact>::from_request(request, &())
+                .await
+                .is_ok()
+        );
+    }
 }
@@ -131,6 +131,13 @@ impl FlowContext {
                         source_id,
                         target_id,
                         metadata,
+                    }
+                    | LinkEvent::Updated {
+                        link_type,
+                        link_id,
+                        source_id,
+                        target_id,
+                        metadata,
                     } => {
                         variables.insert("link_type".to_string(), Value::String(link_type.clone()));
                         variables.insert("link_id".to_string(), Value::String(link_id.to_string()));
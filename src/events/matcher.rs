@@ -7,6 +7,7 @@
 //! # Supported event kinds
 //!
 //! - `link.created` — matches `FrameworkEvent::Link(LinkEvent::Created { .. })`
+//! - `link.updated` — matches `FrameworkEvent::Link(LinkEvent::Updated { .. })`
 //! - `link.deleted` — matches `FrameworkEvent::Link(LinkEvent::Deleted { .. })`
 //! - `entity.created` — matches `FrameworkEvent::Entity(EntityEvent::Created { .. })`
 //! - `entity.updated` — matches `FrameworkEvent::Entity(EntityEvent::Updated { .. })`
@@ -26,6 +27,7 @@ use crate::core::events::{EntityEvent, FrameworkEvent, LinkEvent};
 #[derive(Debug, Clone, PartialEq)]
 enum EventKind {
     LinkCreated,
+    LinkUpdated,
     LinkDeleted,
     EntityCreated,
     EntityUpdated,
@@ -51,7 +53,7 @@ pub struct EventMatcher {
 /// Error returned when a TriggerConfig has an invalid `kind` string
 #[derive(Debug, thiserror::Error)]
 #[error(
-    "unknown event kind: '{kind}'. Expected one of: link.created, link.deleted, entity.created, entity.updated, entity.deleted"
+    "unknown event kind: '{kind}'. Expected one of: link.created, link.updated, link.deleted, entity.created, entity.updated, entity.deleted"
 )]
 pub struct UnknownEventKind {
     pub kind: String,
@@ -78,6 +80,7 @@ impl EventMatcher {
     pub fn compile(config: &TriggerConfig) -> Result<Self, UnknownEventKind> {
         let kind = match config.kind.as_str() {
             "link.created" => EventKind::LinkCreated,
+            "link.updated" => EventKind::LinkUpdated,
             "link.deleted" => EventKind::LinkDeleted,
             "entity.created" => EventKind::EntityCreated,
             "entity.updated" => EventKind::EntityUpdated,
@@ -116,6 +119,9 @@ impl EventMatcher {
             LinkEvent::Created { link_type, .. } => {
                 (self.kind == EventKind::LinkCreated, link_type)
             }
+            LinkEvent::Updated { link_type, .. } => {
+                (self.kind == EventKind::LinkUpdated, link_type)
+            }
             LinkEvent::Deleted { link_type, .. } => {
                 (self.kind == EventKind::LinkDeleted, link_type)
             }
@@ -176,6 +182,16 @@ mod tests {
         })
     }
 
+    fn link_updated(link_type: &str) -> FrameworkEvent {
+        FrameworkEvent::Link(LinkEvent::Updated {
+            link_type: link_type.to_string(),
+            link_id: Uuid::new_v4(),
+            source_id: Uuid::new_v4(),
+            target_id: Uuid::new_v4(),
+            metadata: Some(json!({"since": 2021})),
+        })
+    }
+
     fn link_deleted(link_type: &str) -> FrameworkEvent {
         FrameworkEvent::Link(LinkEvent::Deleted {
             link_type: link_type.to_string(),
@@ -237,6 +253,17 @@ mod tests {
         assert!(!m.matches(&link_created("blocks")));
     }
 
+    // ── link.updated tests ───────────────────────────────────────────
+
+    #[test]
+    fn test_link_updated_with_type_filter() {
+        let m = EventMatcher::compile(&trigger("link.updated", Some("follows"), None)).unwrap();
+        assert!(m.matches(&link_updated("follows")));
+        assert!(!m.matches(&link_updated("likes")));
+        assert!(!m.matches(&link_created("follows")));
+        assert!(!m.matches(&link_deleted("follows")));
+    }
+
     // ── link.deleted tests ───────────────────────────────────────────
 
     #[test]
@@ -314,10 +341,10 @@ mod tests {
 
     #[test]
     fn test_unknown_kind_returns_error() {
-        let result = EventMatcher::compile(&trigger("link.updated", None, None));
+        let result = EventMatcher::compile(&trigger("link.moved", None, None));
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert!(err.to_string().contains("link.updated"));
+        assert!(err.to_string().contains("link.moved"));
     }
 
     #[test]
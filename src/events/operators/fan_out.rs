@@ -240,6 +240,8 @@ mod tests {
             link_type: "follows".to_string(),
             source_id,
             target_id,
+            source_type: None,
+            target_type: None,
             metadata: None,
         }
     }
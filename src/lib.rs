@@ -58,6 +58,8 @@ pub mod events;
 pub mod links;
 pub mod server;
 pub mod storage;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing;
 
 /// Re-exports of commonly used types and traits
 pub mod prelude {
@@ -65,14 +67,16 @@ pub mod prelude {
     pub use crate::core::{
         auth::{AuthContext, AuthPolicy, AuthProvider, NoAuthProvider},
         entity::{Data, Entity, Link},
+        etag::CacheResult,
         field::{FieldFormat, FieldValue},
-        link::{LinkAuthConfig, LinkDefinition, LinkEntity},
+        ids::{DefaultIdNormalizer, IdNormalizer, IdStrategy},
+        link::{LinkAuthConfig, LinkCardinality, LinkDefinition, LinkEntity, LinkError},
         module::{EntityCreator, EntityFetcher, Module},
         pluralize::Pluralizer,
         query::{PaginatedResponse, PaginationMeta, QueryParams},
         service::{DataService, LinkService},
         store::QueryableStore,
-        validation::{EntityValidationConfig, Validated},
+        validation::{EntityValidationConfig, FieldConstraints, Validated},
     };
 
     // === Macros ===
@@ -84,13 +88,15 @@ pub mod prelude {
     // === Link Handlers ===
     pub use crate::links::{
         handlers::{
-            AppState, create_link, delete_link, get_link, list_available_links, list_links,
-            update_link,
+            AppState, DEFAULT_ENRICHMENT_CONCURRENCY, EnrichmentFallback, create_link, delete_link,
+            get_link, list_available_links, list_links, update_link,
         },
         registry::{LinkDirection, LinkRouteRegistry, RouteInfo},
     };
 
     // === Storage ===
+    #[cfg(feature = "redis")]
+    pub use crate::storage::RedisLinkService;
     #[cfg(feature = "dynamodb")]
     pub use crate::storage::{DynamoDBDataService, DynamoDBLinkService};
     pub use crate::storage::{InMemoryDataService, InMemoryLinkService};
@@ -109,12 +115,14 @@ pub mod prelude {
 
     // === Config ===
     pub use crate::config::{
-        EntityAuthConfig, EntityConfig, EventsConfig, LinksConfig, SinkConfig, SinkType,
+        EntityAuthConfig, EntityConfig, EventsConfig, IdPolicy, LinksConfig, SinkConfig, SinkType,
         ValidationRule,
     };
 
     // === Server ===
-    pub use crate::server::{EntityDescriptor, EntityRegistry, ServerBuilder};
+    pub use crate::server::{
+        CorsConfig, EntityDescriptor, EntityRegistry, RateKey, ServerBuilder, TimestampFormat,
+    };
 
     // === External dependencies ===
     pub use anyhow::Result;
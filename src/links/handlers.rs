@@ -5,11 +5,12 @@
 
 use axum::{
     Json,
-    extract::{Path, Query, State},
-    http::StatusCode,
+    extract::{FromRef, FromRequestParts, Path, Query, State, rejection::QueryRejection},
+    http::{StatusCode, request::Parts},
     response::{IntoResponse, Response},
 };
 use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -17,14 +18,21 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::config::LinksConfig;
+use crate::core::audit::{AuditEntry, AuditLogService, AuditOperation, LINK_ENTITY_TYPE};
 use crate::core::events::{EventBus, FrameworkEvent, LinkEvent};
 use crate::core::extractors::{
-    DirectLinkExtractor, ExtractorError, LinkExtractor, RecursiveLinkExtractor,
+    DirectLinkExtractor, ExtractorError, LinkExtractor, LinkPathSegment, RecursiveLinkExtractor,
 };
 use crate::core::{
-    EntityCreator, EntityFetcher, LinkDefinition, LinkService,
-    link::LinkEntity,
-    query::{PaginationMeta, QueryParams},
+    AuthContext, AuthPolicy, AuthProvider, EntityCreator, EntityFetcher, IdStrategy,
+    LinkDefinition, LinkService, TenantContext,
+    link::{LinkEntity, LinkError, LinkFilterCondition, RelationDirection},
+    ownership,
+    query::{FilterClause, PaginationMeta, QueryParams},
+    redaction::redact_entity,
+    service::sort_links_for_paging,
+    validation::{FieldError, ValidationError},
+    warning::Warning,
 };
 use crate::links::registry::{LinkDirection, LinkRouteRegistry};
 
@@ -40,6 +48,28 @@ pub struct AppState {
     pub entity_creators: Arc<HashMap<String, Arc<dyn EntityCreator>>>,
     /// Optional event bus for publishing real-time events
     pub event_bus: Option<Arc<EventBus>>,
+    /// How enriched links report entities that could not be loaded
+    pub enrichment_fallback: EnrichmentFallback,
+    /// How many entity types are fetched at once (see [`DEFAULT_ENRICHMENT_CONCURRENCY`])
+    pub enrichment_concurrency: usize,
+    /// Extracts the caller's [`AuthContext`] for link auth policies
+    ///
+    /// Link policies ([`LinkDefinition::auth`]) are only enforced when set.
+    pub auth_provider: Option<Arc<dyn AuthProvider>>,
+    /// Records link mutations (and entities created through link routes)
+    pub audit_log: Option<Arc<dyn AuditLogService>>,
+    /// Accept a link identical to a live one (same type, source and target)
+    ///
+    /// Off by default: such links are rejected with
+    /// [`LinkError::AlreadyExists`] (409).
+    pub allow_duplicate_links: bool,
+    /// How ids of new links are minted
+    pub id_strategy: IdStrategy,
+    /// Path the REST routes are nested under (e.g. `/api/v1`), empty at the root
+    ///
+    /// Handlers see paths with the prefix stripped; introspection adds it
+    /// back to the paths it advertises.
+    pub route_prefix: String,
 }
 
 impl AppState {
@@ -53,6 +83,41 @@ impl AppState {
         }
     }
 
+    /// Append an entry to the audit log (if configured)
+    ///
+    /// The mutation has already happened, so a failed write is logged
+    /// rather than failing the request.
+    pub async fn audit(&self, entry: AuditEntry) {
+        if let Some(audit_log) = &self.audit_log
+            && let Err(e) = audit_log.record(entry).await
+        {
+            tracing::warn!(error = %e, "failed to record audit entry");
+        }
+    }
+
+    /// Audit a link mutation, with the link as it was before and after it
+    async fn audit_link(
+        &self,
+        auth: &RequestAuth,
+        operation: AuditOperation,
+        before: Option<&LinkEntity>,
+        after: Option<&LinkEntity>,
+    ) {
+        let Some(link_id) = after.or(before).map(|link| link.id) else {
+            return;
+        };
+        let snapshot = |link: &LinkEntity| serde_json::to_value(link).ok();
+        self.audit(AuditEntry::new(
+            LINK_ENTITY_TYPE,
+            link_id,
+            operation,
+            auth.actor(),
+            before.and_then(snapshot),
+            after.and_then(snapshot),
+        ))
+        .await;
+    }
+
     /// Get the authorization policy for a link operation
     pub fn get_link_auth_policy(
         link_definition: &LinkDefinition,
@@ -69,6 +134,113 @@ impl AppState {
     }
 }
 
+/// Caller's auth context, extracted with [`AppState::auth_provider`]
+///
+/// Holds `None` when no provider is configured. Credentials the provider
+/// rejects fail the request with `401 Unauthorized`.
+#[derive(Debug, Clone, Default)]
+pub struct RequestAuth(pub Option<AuthContext>);
+
+impl RequestAuth {
+    /// The caller as named in audit entries, when a provider identified them
+    pub fn actor(&self) -> Option<String> {
+        self.0.as_ref().map(AuthContext::subject)
+    }
+
+    /// The user owning the entities the request creates, if any
+    pub fn owner_id(&self) -> Option<Uuid> {
+        self.0.as_ref().and_then(AuthContext::user_id)
+    }
+}
+
+impl<S> FromRequestParts<S> for RequestAuth
+where
+    AppState: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ExtractorError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let state = AppState::from_ref(state);
+        let Some(provider) = &state.auth_provider else {
+            return Ok(Self(None));
+        };
+        provider
+            .extract_context(parts)
+            .await
+            .map(|context| Self(Some(context)))
+            .map_err(|_| ExtractorError::Unauthorized)
+    }
+}
+
+/// Enforce the link's auth policy for `operation`
+///
+/// The policy comes from [`AppState::get_link_auth_policy`]; links without
+/// `auth` config and requests without an auth context are not checked.
+/// `owner` is checked against the entity the request addresses.
+async fn authorize_link(
+    state: &AppState,
+    auth: &RequestAuth,
+    link_definition: &LinkDefinition,
+    operation: &str,
+    entity_type: &str,
+    entity_id: &Uuid,
+) -> Result<(), ExtractorError> {
+    let (Some(provider), Some(context)) = (&state.auth_provider, &auth.0) else {
+        return Ok(());
+    };
+    let Some(policy) = AppState::get_link_auth_policy(link_definition, operation) else {
+        return Ok(());
+    };
+
+    let route = format!("{} link {}", operation, link_definition.link_type);
+    let allowed = AuthPolicy::parse_policy(&policy)
+        .evaluate_for(context, provider.as_ref(), entity_type, entity_id, &route)
+        .await
+        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+    if allowed {
+        return Ok(());
+    }
+
+    Err(match context {
+        AuthContext::Anonymous => ExtractorError::Unauthorized,
+        _ => ExtractorError::Forbidden(format!(
+            "{} on link '{}' requires '{}'",
+            operation, link_definition.link_type, policy
+        )),
+    })
+}
+
+/// Whether the request's tenant, if any, may see `link`
+///
+/// Links of other tenants are answered as missing, like the tenant-scoped
+/// service lookups do (see [`crate::core::tenant`]).
+fn visible_to(tenant: Option<&TenantContext>, link: &LinkEntity) -> bool {
+    tenant.is_none_or(|tenant| tenant.owns(link.tenant_id))
+}
+
+/// The link a direct link route addresses, if `tenant` may see it
+///
+/// Reverse routes name the stored target first, so their ids are swapped
+/// before the lookup.
+async fn find_routed_link(
+    state: &AppState,
+    extractor: &DirectLinkExtractor,
+    tenant: Option<&TenantContext>,
+) -> Result<LinkEntity, ExtractorError> {
+    let (source_id, target_id) = match extractor.direction {
+        LinkDirection::Forward => (extractor.source_id, extractor.target_id),
+        LinkDirection::Reverse => (extractor.target_id, extractor.source_id),
+    };
+    state
+        .link_service
+        .get_between(&source_id, &target_id, &extractor.link_definition.link_type)
+        .await
+        .map_err(|e| ExtractorError::JsonError(e.to_string()))?
+        .filter(|link| visible_to(tenant, link))
+        .ok_or(ExtractorError::LinkNotFound)
+}
+
 /// Response for list links endpoint
 #[derive(Debug, Serialize)]
 pub struct ListLinksResponse {
@@ -118,6 +290,40 @@ pub struct EnrichedLink {
 
     /// Status
     pub status: String,
+
+    /// Why `source`/`target` could not be loaded
+    /// (only set with [`EnrichmentFallback::ErrorMarker`])
+    #[serde(
+        rename = "_enrichment_error",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub enrichment_error: Option<String>,
+
+    /// Enrichment failures reported in the response's `warnings`
+    /// (only set with [`EnrichmentFallback::Warnings`])
+    #[serde(skip)]
+    pub warnings: Vec<Warning>,
+}
+
+impl EnrichedLink {
+    /// Report warnings through `_enrichment_error` instead, for responses
+    /// made of this link alone (which have no `warnings` array)
+    fn with_inline_warnings(mut self) -> Self {
+        if !self.warnings.is_empty() {
+            let messages: Vec<String> = self.warnings.drain(..).map(|w| w.message).collect();
+            self.enrichment_error = Some(messages.join("; "));
+        }
+        self
+    }
+}
+
+/// Move the enrichment warnings out of `links`
+fn take_warnings(links: &mut [EnrichedLink]) -> Vec<Warning> {
+    links
+        .iter_mut()
+        .flat_map(|link| std::mem::take(&mut link.warnings))
+        .collect()
 }
 
 /// Response for enriched list links endpoint (legacy, without pagination)
@@ -138,6 +344,17 @@ pub struct PaginatedEnrichedLinksResponse {
     pub link_type: String,
     pub direction: String,
     pub description: Option<String>,
+    /// Problems with individual links of this page (see [`EnrichmentFallback::Warnings`])
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+}
+
+/// Paginated response for the relations endpoint
+#[derive(Debug, Serialize)]
+pub struct PaginatedRelationsResponse {
+    pub data: Vec<LinkEntity>,
+    pub pagination: PaginationMeta,
+    pub direction: RelationDirection,
 }
 
 /// Request body for creating a link between existing entities
@@ -146,6 +363,32 @@ pub struct CreateLinkRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
+/// Request body for updating a link's metadata
+///
+/// With `merge`, `metadata` is merged recursively into the current metadata
+/// instead of replacing it (see
+/// [`LinkEntity::apply_metadata`]).
+#[derive(Debug, Deserialize)]
+pub struct UpdateLinkRequest {
+    pub metadata: Option<serde_json::Value>,
+    #[serde(default)]
+    pub merge: bool,
+}
+
+/// Request body for creating a link by type, without a route name
+///
+/// `source_type`/`target_type` are only needed when several link definitions
+/// share the `link_type`.
+#[derive(Debug, Deserialize)]
+pub struct CreateLinkByTypeRequest {
+    pub link_type: String,
+    pub source_id: Uuid,
+    pub target_id: Uuid,
+    pub source_type: Option<String>,
+    pub target_type: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+}
+
 /// Request body for creating a new linked entity
 #[derive(Debug, Deserialize)]
 pub struct CreateLinkedEntityRequest {
@@ -153,6 +396,68 @@ pub struct CreateLinkedEntityRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
+/// Number of entity types fetched concurrently during enrichment
+///
+/// Bounds the fan-out of a single list request against the entity backends.
+pub const DEFAULT_ENRICHMENT_CONCURRENCY: usize = 10;
+
+/// Entities loaded for enrichment: per entity type, the batch or why it failed
+type FetchedEntities = HashMap<String, Result<HashMap<Uuid, serde_json::Value>, String>>;
+
+/// How link enrichment reports an entity it could not load
+///
+/// A source or target entity cannot be embedded when no `EntityFetcher` is
+/// registered for its type or when the fetch fails (e.g. the entity was
+/// deleted).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnrichmentFallback {
+    /// Omit the entity, as if it had not been requested
+    #[default]
+    Silent,
+    /// Omit the entity and explain why in a link-level `_enrichment_error`
+    ErrorMarker,
+    /// Embed `{"id": ..., "type": ..., "_enriched": false}` in place of the entity
+    IdReference,
+    /// Omit the entity and report it in the response's `warnings` array
+    /// (`ENRICHMENT_FAILED`, with the link and entity ids as `item`)
+    ///
+    /// Responses made of a single link have no `warnings` array and get the
+    /// `_enrichment_error` marker instead.
+    Warnings,
+}
+
+impl EnrichmentFallback {
+    /// Resolve a fetch result into the embedded entity, recording failures
+    fn resolve(
+        self,
+        fetched: Result<serde_json::Value, String>,
+        link_id: &Uuid,
+        entity_type: &str,
+        entity_id: &Uuid,
+        errors: &mut Vec<Warning>,
+    ) -> Option<serde_json::Value> {
+        match (fetched, self) {
+            (Ok(entity), _) => Some(entity),
+            (Err(_), Self::Silent) => None,
+            (Err(reason), Self::ErrorMarker | Self::Warnings) => {
+                errors.push(Warning::new(Warning::ENRICHMENT_FAILED, reason).with_item(
+                    serde_json::json!({
+                        "link_id": link_id,
+                        "entity_type": entity_type,
+                        "entity_id": entity_id,
+                    }),
+                ));
+                None
+            }
+            (Err(_), Self::IdReference) => Some(serde_json::json!({
+                "id": entity_id,
+                "type": entity_type,
+                "_enriched": false,
+            })),
+        }
+    }
+}
+
 /// Context for link enrichment
 #[derive(Debug, Clone, Copy)]
 pub enum EnrichmentContext {
@@ -169,6 +474,8 @@ pub enum EnrichmentContext {
 /// GET /{entity_type}/{entity_id}/{route_name}
 pub async fn list_links(
     State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
     Path((entity_type_plural, entity_id, route_name)): Path<(String, Uuid, String)>,
     Query(params): Query<QueryParams>,
 ) -> Result<Json<PaginatedEnrichedLinksResponse>, ExtractorError> {
@@ -177,27 +484,133 @@ pub async fn list_links(
         &state.registry,
         &state.config,
     )?;
+    authorize_link(
+        &state,
+        &auth,
+        &extractor.link_definition,
+        "list",
+        &extractor.entity_type,
+        &extractor.entity_id,
+    )
+    .await?;
 
-    // Query links based on direction
-    let links = match extractor.direction {
-        LinkDirection::Forward => state
-            .link_service
-            .find_by_source(
-                &extractor.entity_id,
-                Some(&extractor.link_definition.link_type),
-                Some(&extractor.link_definition.target_type),
-            )
-            .await
+    let page = params.page();
+    let limit = params.limit();
+    let offset = (page - 1) * limit;
+    let metadata_fields = params.metadata_fields();
+    let filter_value = params.filter_value();
+
+    // Without filters, tenant scoping or projection, the storage layer pages
+    let stored_page = filter_value.is_none() && tenant.is_none() && metadata_fields.is_none();
+    let (links, stored_total) = if stored_page {
+        let definition = &extractor.link_definition;
+        let (links, total) = match extractor.direction {
+            LinkDirection::Forward => {
+                state
+                    .link_service
+                    .find_by_source_page(
+                        &extractor.entity_id,
+                        Some(&definition.link_type),
+                        Some(&definition.target_type),
+                        limit,
+                        offset,
+                    )
+                    .await
+            }
+            LinkDirection::Reverse => {
+                state
+                    .link_service
+                    .find_by_target_page(
+                        &extractor.entity_id,
+                        Some(&definition.link_type),
+                        Some(&definition.source_type),
+                        limit,
+                        offset,
+                    )
+                    .await
+            }
+        }
+        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+        (links, Some(total))
+    } else {
+        // Query links based on direction (projecting metadata when requested)
+        let mut links = match (extractor.direction, metadata_fields) {
+            (LinkDirection::Forward, None) => match &tenant {
+                Some(tenant) => {
+                    state
+                        .link_service
+                        .find_by_source_for_tenant(
+                            &extractor.entity_id,
+                            Some(&extractor.link_definition.link_type),
+                            Some(&extractor.link_definition.target_type),
+                            tenant,
+                        )
+                        .await
+                }
+                None => {
+                    state
+                        .link_service
+                        .find_by_source(
+                            &extractor.entity_id,
+                            Some(&extractor.link_definition.link_type),
+                            Some(&extractor.link_definition.target_type),
+                        )
+                        .await
+                }
+            }
             .map_err(|e| ExtractorError::JsonError(e.to_string()))?,
-        LinkDirection::Reverse => state
-            .link_service
-            .find_by_target(
-                &extractor.entity_id,
-                Some(&extractor.link_definition.link_type),
-                Some(&extractor.link_definition.source_type),
-            )
-            .await
+            (LinkDirection::Forward, Some(fields)) => state
+                .link_service
+                .find_by_source_projected(
+                    &extractor.entity_id,
+                    Some(&extractor.link_definition.link_type),
+                    Some(&extractor.link_definition.target_type),
+                    &fields,
+                )
+                .await
+                .map_err(|e| ExtractorError::JsonError(e.to_string()))?,
+            (LinkDirection::Reverse, None) => match &tenant {
+                Some(tenant) => {
+                    state
+                        .link_service
+                        .find_by_target_for_tenant(
+                            &extractor.entity_id,
+                            Some(&extractor.link_definition.link_type),
+                            Some(&extractor.link_definition.source_type),
+                            tenant,
+                        )
+                        .await
+                }
+                None => {
+                    state
+                        .link_service
+                        .find_by_target(
+                            &extractor.entity_id,
+                            Some(&extractor.link_definition.link_type),
+                            Some(&extractor.link_definition.source_type),
+                        )
+                        .await
+                }
+            }
             .map_err(|e| ExtractorError::JsonError(e.to_string()))?,
+            (LinkDirection::Reverse, Some(fields)) => state
+                .link_service
+                .find_by_target_projected(
+                    &extractor.entity_id,
+                    Some(&extractor.link_definition.link_type),
+                    Some(&extractor.link_definition.source_type),
+                    &fields,
+                )
+                .await
+                .map_err(|e| ExtractorError::JsonError(e.to_string()))?,
+        };
+        // Projected lookups have no tenant-scoped variant
+        if let Some(tenant) = &tenant {
+            links.retain(|link| tenant.owns(link.tenant_id));
+        }
+        // Same order as the pages storage returns
+        sort_links_for_paging(&mut links);
+        (links, None)
     };
 
     // Determine enrichment context based on direction
@@ -206,24 +619,31 @@ pub async fn list_links(
         LinkDirection::Reverse => EnrichmentContext::FromTarget,
     };
 
-    // Enrich ALL links with full entity data first
-    let mut all_enriched =
-        enrich_links_with_entities(&state, links, context, &extractor.link_definition).await?;
+    // Enrich the links with full entity data
+    let mut all_enriched = enrich_links_with_entities(
+        &state,
+        tenant.as_ref(),
+        links,
+        context,
+        &extractor.link_definition,
+    )
+    .await?;
+    redact_enriched_links(&state, &auth, &mut all_enriched, &extractor.link_definition);
 
     // Apply filters if provided
-    if let Some(filter_value) = params.filter_value() {
-        all_enriched = apply_link_filters(all_enriched, &filter_value);
+    if let Some(filter_value) = filter_value {
+        all_enriched = apply_link_filters(all_enriched, &filter_value)?;
     }
 
-    let total = all_enriched.len();
-
-    // Apply pagination (ALWAYS paginate for links)
-    let page = params.page();
-    let limit = params.limit();
-    let start = (page - 1) * limit;
-
-    let paginated_links: Vec<EnrichedLink> =
-        all_enriched.into_iter().skip(start).take(limit).collect();
+    // Apply pagination (ALWAYS paginate for links) unless storage did
+    let (total, mut paginated_links) = match stored_total {
+        Some(total) => (total, all_enriched),
+        None => (
+            all_enriched.len(),
+            all_enriched.into_iter().skip(offset).take(limit).collect(),
+        ),
+    };
+    let warnings = take_warnings(&mut paginated_links);
 
     Ok(Json(PaginatedEnrichedLinksResponse {
         data: paginated_links,
@@ -231,123 +651,222 @@ pub async fn list_links(
         link_type: extractor.link_definition.link_type,
         direction: format!("{:?}", extractor.direction),
         description: extractor.link_definition.description,
+        warnings,
     }))
 }
 
 /// Helper function to enrich links with full entity data
+///
+/// The entities of each type are loaded with a single
+/// [`EntityFetcher::fetch_many_as_json`] call, up to
+/// `state.enrichment_concurrency` types at once; the result keeps the order
+/// of `links`. Entities of another tenant than `tenant` are treated as
+/// missing.
 async fn enrich_links_with_entities(
     state: &AppState,
+    tenant: Option<&TenantContext>,
     links: Vec<LinkEntity>,
     context: EnrichmentContext,
     link_definition: &LinkDefinition,
 ) -> Result<Vec<EnrichedLink>, ExtractorError> {
-    let mut enriched = Vec::new();
+    if links.is_empty() {
+        return Ok(Vec::new());
+    }
 
-    for link in links {
-        // Fetch source entity only if needed
-        let source_entity = match context {
-            EnrichmentContext::FromSource => None,
-            EnrichmentContext::FromTarget | EnrichmentContext::DirectLink => {
-                // Fetch source entity using the type from link definition
-                fetch_entity_by_type(state, &link_definition.source_type, &link.source_id)
-                    .await
-                    .ok()
-            }
-        };
+    // Collect the ids to load per entity type (source and target may share one)
+    let mut wanted: HashMap<String, Vec<Uuid>> = HashMap::new();
+    if !matches!(context, EnrichmentContext::FromSource) {
+        wanted
+            .entry(link_definition.source_type.clone())
+            .or_default()
+            .extend(links.iter().map(|link| link.source_id));
+    }
+    if !matches!(context, EnrichmentContext::FromTarget) {
+        wanted
+            .entry(link_definition.target_type.clone())
+            .or_default()
+            .extend(links.iter().map(|link| link.target_id));
+    }
 
-        // Fetch target entity only if needed
-        let target_entity = match context {
-            EnrichmentContext::FromTarget => None,
-            EnrichmentContext::FromSource | EnrichmentContext::DirectLink => {
-                // Fetch target entity using the type from link definition
-                fetch_entity_by_type(state, &link_definition.target_type, &link.target_id)
-                    .await
-                    .ok()
+    let fetched: FetchedEntities = stream::iter(wanted)
+        .map(|(entity_type, mut ids)| async move {
+            ids.sort_unstable();
+            ids.dedup();
+            let mut entities = fetch_entities_by_type(state, &entity_type, &ids).await;
+            if let (Some(tenant), Ok(entities)) = (tenant, &mut entities) {
+                entities.retain(|_, entity| tenant.owns_json(entity));
             }
-        };
+            (entity_type, entities)
+        })
+        .buffer_unordered(state.enrichment_concurrency.max(1))
+        .collect()
+        .await;
 
-        enriched.push(EnrichedLink {
-            id: link.id,
-            entity_type: link.entity_type,
-            link_type: link.link_type,
-            source_id: link.source_id,
-            target_id: link.target_id,
-            source: source_entity,
-            target: target_entity,
-            metadata: link.metadata,
-            created_at: link.created_at,
-            updated_at: link.updated_at,
-            status: link.status,
-        });
+    Ok(links
+        .into_iter()
+        .map(|link| enrich_link(state, link, context, link_definition, &fetched))
+        .collect())
+}
+
+/// Strip the fields the caller may not see from the embedded entities
+///
+/// Uses the [`EntityFetcher::redacted_fields`] of the link's source and
+/// target types, as [`redaction`](crate::core::redaction) does for entity
+/// responses.
+fn redact_enriched_links(
+    state: &AppState,
+    auth: &RequestAuth,
+    links: &mut [EnrichedLink],
+    link_definition: &LinkDefinition,
+) {
+    let source_fields = redacted_fields_for(state, auth, &link_definition.source_type);
+    let target_fields = redacted_fields_for(state, auth, &link_definition.target_type);
+    for link in links {
+        if let Some(source) = &mut link.source {
+            redact_entity(source, source_fields);
+        }
+        if let Some(target) = &mut link.target {
+            redact_entity(target, target_fields);
+        }
     }
+}
 
-    Ok(enriched)
+/// The [`EntityFetcher::redacted_fields`] of `entity_type` for the caller
+fn redacted_fields_for(
+    state: &AppState,
+    auth: &RequestAuth,
+    entity_type: &str,
+) -> &'static [&'static str] {
+    let context = auth.0.clone().unwrap_or(AuthContext::Anonymous);
+    state
+        .entity_fetchers
+        .get(entity_type)
+        .map_or(&[][..], |fetcher| fetcher.redacted_fields(&context))
 }
 
-/// Fetch an entity dynamically by type
-async fn fetch_entity_by_type(
+/// Enrich a single link with the entities the context asks for
+fn enrich_link(
     state: &AppState,
+    link: LinkEntity,
+    context: EnrichmentContext,
+    link_definition: &LinkDefinition,
+    fetched: &FetchedEntities,
+) -> EnrichedLink {
+    let fallback = state.enrichment_fallback;
+    let mut errors = Vec::new();
+
+    // Embed source entity only if needed
+    let source_entity = match context {
+        EnrichmentContext::FromSource => None,
+        EnrichmentContext::FromTarget | EnrichmentContext::DirectLink => {
+            // Look up source entity using the type from link definition
+            let source_type = &link_definition.source_type;
+            let entity = fetched_entity(fetched, source_type, &link.source_id);
+            fallback.resolve(entity, &link.id, source_type, &link.source_id, &mut errors)
+        }
+    };
+
+    // Embed target entity only if needed
+    let target_entity = match context {
+        EnrichmentContext::FromTarget => None,
+        EnrichmentContext::FromSource | EnrichmentContext::DirectLink => {
+            // Look up target entity using the type from link definition
+            let target_type = &link_definition.target_type;
+            let entity = fetched_entity(fetched, target_type, &link.target_id);
+            fallback.resolve(entity, &link.id, target_type, &link.target_id, &mut errors)
+        }
+    };
+
+    let enriched = EnrichedLink {
+        id: link.id,
+        entity_type: link.entity_type,
+        link_type: link.link_type,
+        source_id: link.source_id,
+        target_id: link.target_id,
+        source: source_entity,
+        target: target_entity,
+        metadata: link.metadata,
+        created_at: link.created_at,
+        updated_at: link.updated_at,
+        status: link.status,
+        enrichment_error: None,
+        warnings: errors,
+    };
+    // The marker mode reports failures on the link itself
+    if fallback == EnrichmentFallback::ErrorMarker {
+        enriched.with_inline_warnings()
+    } else {
+        enriched
+    }
+}
+
+/// Look up a fetched entity, describing why it is unavailable on failure
+fn fetched_entity(
+    fetched: &FetchedEntities,
     entity_type: &str,
     entity_id: &Uuid,
-) -> Result<serde_json::Value, ExtractorError> {
-    let fetcher = state.entity_fetchers.get(entity_type).ok_or_else(|| {
-        ExtractorError::JsonError(format!(
+) -> Result<serde_json::Value, String> {
+    match fetched.get(entity_type) {
+        Some(Ok(entities)) => entities.get(entity_id).cloned().ok_or_else(|| {
+            format!(
+                "Failed to fetch entity: {} not found: {}",
+                entity_type, entity_id
+            )
+        }),
+        Some(Err(reason)) => Err(reason.clone()),
+        None => Err(format!(
             "No entity fetcher registered for type: {}",
             entity_type
-        ))
-    })?;
+        )),
+    }
+}
+
+/// Fetch entities of one type dynamically, in a single batch
+async fn fetch_entities_by_type(
+    state: &AppState,
+    entity_type: &str,
+    ids: &[Uuid],
+) -> Result<HashMap<Uuid, serde_json::Value>, String> {
+    let fetcher = state
+        .entity_fetchers
+        .get(entity_type)
+        .ok_or_else(|| format!("No entity fetcher registered for type: {}", entity_type))?;
 
     fetcher
-        .fetch_as_json(entity_id)
+        .fetch_many_as_json(ids)
         .await
-        .map_err(|e| ExtractorError::JsonError(format!("Failed to fetch entity: {}", e)))
+        .map_err(|e| format!("Failed to fetch entities: {}", e))
 }
 
-/// Apply filtering to enriched links based on query parameters
+/// Keep the links whose JSON form satisfies every clause of `filter`
 ///
 /// Supports filtering on:
 /// - link fields (id, link_type, source_id, target_id, status, metadata)
 /// - nested entity fields (source.*, target.*)
-fn apply_link_filters(enriched_links: Vec<EnrichedLink>, filter: &Value) -> Vec<EnrichedLink> {
-    if filter.is_null() || !filter.is_object() {
-        return enriched_links;
+///
+/// Field values may be plain values, IN sets or operator objects (see
+/// [`FilterClause`]). A filter that is not an object is ignored.
+fn apply_link_filters<L: Serialize>(
+    links: Vec<L>,
+    filter: &Value,
+) -> Result<Vec<L>, ExtractorError> {
+    if !filter.is_object() {
+        return Ok(links);
     }
+    let clauses = FilterClause::parse_all(filter).map_err(ExtractorError::JsonError)?;
 
-    let filter_obj = filter.as_object().unwrap();
-
-    enriched_links
+    Ok(links
         .into_iter()
         .filter(|link| {
-            let mut matches = true;
-
             // Convert link to JSON for easy filtering
-            let link_json = match serde_json::to_value(link) {
-                Ok(v) => v,
-                Err(_) => return false,
+            let Ok(link_json) = serde_json::to_value(link) else {
+                return false;
             };
-
-            for (key, value) in filter_obj.iter() {
-                // Check if the field exists in the link or in nested entities
-                let field_value = get_nested_value(&link_json, key);
-
-                match field_value {
-                    Some(field_val) => {
-                        // Simple equality match for now
-                        if field_val != *value {
-                            matches = false;
-                            break;
-                        }
-                    }
-                    None => {
-                        matches = false;
-                        break;
-                    }
-                }
-            }
-
-            matches
+            clauses.iter().all(|clause| {
+                clause.matches_json(get_nested_value(&link_json, &clause.field).as_ref())
+            })
         })
-        .collect()
+        .collect())
 }
 
 /// Get a nested value from JSON using dot notation
@@ -370,6 +889,8 @@ fn get_nested_value(json: &Value, key: &str) -> Option<Value> {
 /// GET /links/{link_id}
 pub async fn get_link(
     State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
     Path(link_id): Path<Uuid>,
 ) -> Result<Response, ExtractorError> {
     let link = state
@@ -377,6 +898,7 @@ pub async fn get_link(
         .get(&link_id)
         .await
         .map_err(|e| ExtractorError::JsonError(e.to_string()))?
+        .filter(|link| visible_to(tenant.as_ref(), link))
         .ok_or(ExtractorError::LinkNotFound)?;
 
     // Find the link definition from config
@@ -391,20 +913,32 @@ pub async fn get_link(
                 link.link_type
             ))
         })?;
+    authorize_link(
+        &state,
+        &auth,
+        link_definition,
+        "get",
+        &link_definition.source_type,
+        &link.source_id,
+    )
+    .await?;
 
     // Enrich with both source and target entities
-    let enriched_links = enrich_links_with_entities(
+    let mut enriched_links = enrich_links_with_entities(
         &state,
+        tenant.as_ref(),
         vec![link],
         EnrichmentContext::DirectLink,
         link_definition,
     )
     .await?;
+    redact_enriched_links(&state, &auth, &mut enriched_links, link_definition);
 
     let enriched_link = enriched_links
         .into_iter()
         .next()
-        .ok_or(ExtractorError::LinkNotFound)?;
+        .ok_or(ExtractorError::LinkNotFound)?
+        .with_inline_warnings();
 
     Ok(Json(enriched_link).into_response())
 }
@@ -414,6 +948,8 @@ pub async fn get_link(
 /// GET /{source_type}/{source_id}/{route_name}/{target_id}
 pub async fn get_link_by_route(
     State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
     Path((source_type_plural, source_id, route_name, target_id)): Path<(
         String,
         Uuid,
@@ -426,93 +962,225 @@ pub async fn get_link_by_route(
         &state.registry,
         &state.config,
     )?;
+    authorize_link(
+        &state,
+        &auth,
+        &extractor.link_definition,
+        "get",
+        &extractor.source_type,
+        &extractor.source_id,
+    )
+    .await?;
 
-    // Find the specific link based on direction
-    let existing_links = match extractor.direction {
-        LinkDirection::Forward => {
-            // Forward: search by source_id in URL
-            state
-                .link_service
-                .find_by_source(
-                    &extractor.source_id,
-                    Some(&extractor.link_definition.link_type),
-                    Some(&extractor.target_type),
-                )
-                .await
-                .map_err(|e| ExtractorError::JsonError(e.to_string()))?
-        }
-        LinkDirection::Reverse => {
-            // Reverse: search by target_id in URL (which is the actual source in DB)
-            state
-                .link_service
-                .find_by_source(
-                    &extractor.target_id,
-                    Some(&extractor.link_definition.link_type),
-                    Some(&extractor.source_type),
-                )
-                .await
-                .map_err(|e| ExtractorError::JsonError(e.to_string()))?
-        }
-    };
-
-    let link = existing_links
-        .into_iter()
-        .find(|link| match extractor.direction {
-            LinkDirection::Forward => link.target_id == extractor.target_id,
-            LinkDirection::Reverse => link.target_id == extractor.source_id,
-        })
-        .ok_or(ExtractorError::LinkNotFound)?;
+    let link = find_routed_link(&state, &extractor, tenant.as_ref()).await?;
 
     // Enrich with both source and target entities
-    let enriched_links = enrich_links_with_entities(
+    let mut enriched_links = enrich_links_with_entities(
         &state,
+        tenant.as_ref(),
         vec![link],
         EnrichmentContext::DirectLink,
         &extractor.link_definition,
     )
     .await?;
+    redact_enriched_links(
+        &state,
+        &auth,
+        &mut enriched_links,
+        &extractor.link_definition,
+    );
 
     let enriched_link = enriched_links
         .into_iter()
         .next()
-        .ok_or(ExtractorError::LinkNotFound)?;
+        .ok_or(ExtractorError::LinkNotFound)?
+        .with_inline_warnings();
 
     Ok(Json(enriched_link).into_response())
 }
 
-/// Create a link between two existing entities
+/// Insert a link, enforcing the cardinality of its definition
 ///
-/// POST /{source_type}/{source_id}/{route_name}/{target_id}
-/// Body: { "metadata": {...} }
-pub async fn create_link(
-    State(state): State<AppState>,
-    Path((source_type_plural, source_id, route_name, target_id)): Path<(
-        String,
-        Uuid,
-        String,
-        Uuid,
-    )>,
-    Json(payload): Json<CreateLinkRequest>,
-) -> Result<Response, ExtractorError> {
-    let extractor = DirectLinkExtractor::from_path(
-        (source_type_plural, source_id, route_name, target_id),
+/// The check and the insert are atomic (see
+/// [`LinkService::create_within_limit`]); a link that would break the
+/// cardinality fails with [`LinkError::AlreadyExists`] (409). So does a
+/// duplicate of a live link, unless [`AppState::allow_duplicate_links`] is
+/// set. Bounded cardinalities already rule duplicates out. The link gets a
+/// fresh id from [`AppState::id_strategy`].
+async fn insert_link(
+    state: &AppState,
+    link_definition: &LinkDefinition,
+    tenant: Option<&TenantContext>,
+    mut link: LinkEntity,
+) -> Result<LinkEntity, ExtractorError> {
+    link.id = state.id_strategy.generate();
+    if let Some(tenant) = tenant {
+        link.tenant_id = Some(tenant.tenant_id);
+    }
+    let cardinality = link_definition.cardinality.unwrap_or_default();
+    state
+        .link_service
+        .create_with_cardinality(link, cardinality, state.allow_duplicate_links)
+        .await
+        .map_err(|e| match e.downcast::<LinkError>() {
+            Ok(rejected) => rejected.into(),
+            Err(e) => ExtractorError::JsonError(e.to_string()),
+        })?
+        .ok_or_else(|| {
+            LinkError::AlreadyExists {
+                link_type: link_definition.link_type.clone(),
+                cardinality,
+            }
+            .into()
+        })
+}
+
+/// [`insert_link`] for a link to an entity created by the same request
+///
+/// If the link cannot be inserted, whether it is rejected (a concurrent
+/// request took the slot after [`ensure_link_slot`]) or the link service
+/// fails, the new entity is deleted again so it is not left orphaned.
+/// Deletion is best effort.
+async fn insert_link_for_new_entity(
+    state: &AppState,
+    link_definition: &LinkDefinition,
+    tenant: Option<&TenantContext>,
+    link: LinkEntity,
+    entity_creator: &dyn EntityCreator,
+    new_entity_id: &Uuid,
+) -> Result<LinkEntity, ExtractorError> {
+    let result = insert_link(state, link_definition, tenant, link).await;
+    if result.is_err() {
+        let _ = entity_creator.delete(new_entity_id).await;
+    }
+    result
+}
+
+/// Reject early if `entity_id` already has every link its side allows
+///
+/// `direction` says which end `entity_id` is: the source for
+/// [`LinkDirection::Forward`], the target for [`LinkDirection::Reverse`].
+async fn ensure_link_slot(
+    state: &AppState,
+    link_definition: &LinkDefinition,
+    entity_id: &Uuid,
+    direction: LinkDirection,
+) -> Result<(), ExtractorError> {
+    let cardinality = link_definition.cardinality.unwrap_or_default();
+    let limit = cardinality.limit();
+    let link_type = Some(link_definition.link_type.as_str());
+    let (cap, existing) = match (direction, limit.per_source, limit.per_target) {
+        (LinkDirection::Forward, Some(cap), _) => (
+            cap,
+            state
+                .link_service
+                .find_by_source(entity_id, link_type, None)
+                .await,
+        ),
+        (LinkDirection::Reverse, _, Some(cap)) => (
+            cap,
+            state
+                .link_service
+                .find_by_target(entity_id, link_type, None)
+                .await,
+        ),
+        _ => return Ok(()),
+    };
+
+    let existing = existing.map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+    if existing.iter().filter(|l| !l.is_deleted()).count() >= cap {
+        return Err(LinkError::AlreadyExists {
+            link_type: link_definition.link_type.clone(),
+            cardinality,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Fail with `404 Not Found` unless every `(entity_type, id)` belongs to `tenant`
+///
+/// Keeps a tenant from linking its entities to another tenant's. Without a
+/// tenant nothing is checked.
+async fn ensure_entities_in_tenant(
+    state: &AppState,
+    tenant: Option<&TenantContext>,
+    entities: [(&str, Uuid); 2],
+) -> Result<(), ExtractorError> {
+    let Some(tenant) = tenant else {
+        return Ok(());
+    };
+    for (entity_type, id) in entities {
+        let fetcher = state
+            .entity_fetchers
+            .get(entity_type)
+            .ok_or(ExtractorError::EntityNotFound(id))?;
+        fetcher
+            .fetch_for_tenant_as_json(&id, tenant)
+            .await
+            .map_err(|_| ExtractorError::EntityNotFound(id))?;
+    }
+    Ok(())
+}
+
+/// Create a link between two existing entities
+///
+/// POST /{source_type}/{source_id}/{route_name}/{target_id}
+/// Body: { "metadata": {...} }
+pub async fn create_link(
+    State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
+    Path((source_type_plural, source_id, route_name, target_id)): Path<(
+        String,
+        Uuid,
+        String,
+        Uuid,
+    )>,
+    Json(payload): Json<CreateLinkRequest>,
+) -> Result<Response, ExtractorError> {
+    let extractor = DirectLinkExtractor::from_path(
+        (source_type_plural, source_id, route_name, target_id),
         &state.registry,
         &state.config,
     )?;
+    authorize_link(
+        &state,
+        &auth,
+        &extractor.link_definition,
+        "create",
+        &extractor.source_type,
+        &extractor.source_id,
+    )
+    .await?;
+
+    extractor
+        .link_definition
+        .validate_metadata(extractor.direction, payload.metadata.as_ref())?;
+    ensure_entities_in_tenant(
+        &state,
+        tenant.as_ref(),
+        [
+            (&extractor.source_type, extractor.source_id),
+            (&extractor.target_type, extractor.target_id),
+        ],
+    )
+    .await?;
 
     // Create the link between existing entities
     let link = LinkEntity::new(
-        extractor.link_definition.link_type,
+        &extractor.link_definition.link_type,
         extractor.source_id,
         extractor.target_id,
         payload.metadata,
+    )
+    .with_entity_types(
+        &extractor.link_definition.source_type,
+        &extractor.link_definition.target_type,
     );
 
-    let created_link = state
-        .link_service
-        .create(link)
-        .await
-        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+    let created_link =
+        insert_link(&state, &extractor.link_definition, tenant.as_ref(), link).await?;
 
     // Emit link created event
     state.publish_event(FrameworkEvent::Link(LinkEvent::Created {
@@ -522,16 +1190,189 @@ pub async fn create_link(
         target_id: created_link.target_id,
         metadata: created_link.metadata.clone(),
     }));
+    state
+        .audit_link(&auth, AuditOperation::Create, None, Some(&created_link))
+        .await;
+
+    Ok((StatusCode::CREATED, Json(created_link)).into_response())
+}
+
+/// Resolve the link definition named by a [`CreateLinkByTypeRequest`]
+///
+/// The definition must be unique for the given link/entity types and
+/// allowed by the config's validation rules.
+fn resolve_link_definition(
+    config: &LinksConfig,
+    payload: &CreateLinkByTypeRequest,
+) -> Result<LinkDefinition, ExtractorError> {
+    let invalid = |message: String| -> ExtractorError {
+        ValidationError::FieldErrors(vec![FieldError::new("/link_type", message)]).into()
+    };
+    let candidates: Vec<&LinkDefinition> = config
+        .links
+        .iter()
+        .filter(|def| {
+            def.link_type == payload.link_type
+                && payload
+                    .source_type
+                    .as_ref()
+                    .is_none_or(|t| *t == def.source_type)
+                && payload
+                    .target_type
+                    .as_ref()
+                    .is_none_or(|t| *t == def.target_type)
+        })
+        .collect();
+
+    let definition = match candidates.as_slice() {
+        [definition] => *definition,
+        [] => {
+            return Err(invalid(format!(
+                "no link '{}' is defined between these entity types",
+                payload.link_type
+            )));
+        }
+        _ => {
+            return Err(invalid(format!(
+                "link '{}' is ambiguous, specify source_type and target_type",
+                payload.link_type
+            )));
+        }
+    };
+
+    if !config.is_valid_link(
+        &definition.link_type,
+        &definition.source_type,
+        &definition.target_type,
+    ) {
+        return Err(invalid(format!(
+            "link '{}' from '{}' to '{}' is not allowed",
+            definition.link_type, definition.source_type, definition.target_type
+        )));
+    }
+    Ok(definition.clone())
+}
+
+/// Create a link between two existing entities, addressed by link type
+///
+/// POST /links
+/// Body: { "link_type": "owner", "source_id": "...", "target_id": "...", "metadata": {...} }
+///
+/// For clients that know the link type rather than a route name. Runs the
+/// same checks as [`create_link`]: validation rules, required metadata,
+/// cardinality and the `create` auth policy (checked against the source).
+pub async fn create_link_by_type(
+    State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
+    Json(payload): Json<CreateLinkByTypeRequest>,
+) -> Result<Response, ExtractorError> {
+    let link_definition = resolve_link_definition(&state.config, &payload)?;
+    authorize_link(
+        &state,
+        &auth,
+        &link_definition,
+        "create",
+        &link_definition.source_type,
+        &payload.source_id,
+    )
+    .await?;
+
+    link_definition.validate_metadata(LinkDirection::Forward, payload.metadata.as_ref())?;
+    ensure_entities_in_tenant(
+        &state,
+        tenant.as_ref(),
+        [
+            (&link_definition.source_type, payload.source_id),
+            (&link_definition.target_type, payload.target_id),
+        ],
+    )
+    .await?;
+
+    let link = LinkEntity::new(
+        &link_definition.link_type,
+        payload.source_id,
+        payload.target_id,
+        payload.metadata,
+    )
+    .with_entity_types(&link_definition.source_type, &link_definition.target_type);
+
+    let created_link = insert_link(&state, &link_definition, tenant.as_ref(), link).await?;
+
+    state.publish_event(FrameworkEvent::Link(LinkEvent::Created {
+        link_type: created_link.link_type.clone(),
+        link_id: created_link.id,
+        source_id: created_link.source_id,
+        target_id: created_link.target_id,
+        metadata: created_link.metadata.clone(),
+    }));
+    state
+        .audit_link(&auth, AuditOperation::Create, None, Some(&created_link))
+        .await;
 
     Ok((StatusCode::CREATED, Json(created_link)).into_response())
 }
 
+/// Type and creator of the entity a link request creates
+///
+/// The new entity is the target of a [`LinkDirection::Forward`] link and
+/// the source of a [`LinkDirection::Reverse`] one.
+fn new_entity_creator<'a>(
+    state: &'a AppState,
+    link_definition: &'a LinkDefinition,
+    direction: LinkDirection,
+) -> Result<(&'a String, &'a Arc<dyn EntityCreator>), ExtractorError> {
+    let (entity_type, route, side) = match direction {
+        LinkDirection::Forward => (&link_definition.target_type, "forward", "target"),
+        LinkDirection::Reverse => (&link_definition.source_type, "reverse", "source"),
+    };
+    let creator =
+        state
+            .entity_creators
+            .get(entity_type)
+            .ok_or_else(|| LinkError::OperationFailed {
+                link_type: link_definition.link_type.clone(),
+                operation: format!("create an entity through the {} route of", route),
+                reason: format!(
+                    "no EntityCreator is registered for '{}' (the link's {} type)",
+                    entity_type, side
+                ),
+            })?;
+    Ok((entity_type, creator))
+}
+
+/// Create the entity a link request carries, owned by the caller
+///
+/// A client-supplied `owner_id` is dropped: ownership comes from the
+/// [`RequestAuth`] only. With a tenant, the entity gets its `tenant_id`, as
+/// on the entity routes; a body naming another tenant is refused with
+/// `403 Forbidden`.
+async fn create_new_entity(
+    entity_creator: &dyn EntityCreator,
+    auth: &RequestAuth,
+    tenant: Option<&TenantContext>,
+    mut entity_data: Value,
+) -> Result<Value, ExtractorError> {
+    if let Some(tenant) = tenant
+        && !tenant.bind_json(&mut entity_data)
+    {
+        return Err(ExtractorError::Forbidden(
+            "tenant_id does not match the request's tenant".to_string(),
+        ));
+    }
+    ownership::create_owned(entity_creator, entity_data, auth.owner_id())
+        .await
+        .map_err(|e| ExtractorError::JsonError(format!("Failed to create entity: {}", e)))
+}
+
 /// Create a new entity and link it to the source
 ///
 /// POST /{source_type}/{source_id}/{route_name}
 /// Body: { "entity": {...entity fields...}, "metadata": {...link metadata...} }
 pub async fn create_linked_entity(
     State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
     Path((source_type_plural, source_id, route_name)): Path<(String, Uuid, String)>,
     Json(payload): Json<CreateLinkedEntityRequest>,
 ) -> Result<Response, ExtractorError> {
@@ -540,79 +1381,105 @@ pub async fn create_linked_entity(
         &state.registry,
         &state.config,
     )?;
+    authorize_link(
+        &state,
+        &auth,
+        &extractor.link_definition,
+        "create",
+        &extractor.entity_type,
+        &extractor.entity_id,
+    )
+    .await?;
 
-    // Determine source and target based on direction
-    let (source_entity_id, target_entity_type) = match extractor.direction {
-        LinkDirection::Forward => {
-            // Forward: source is the entity in the URL, target is the new entity
-            (extractor.entity_id, &extractor.link_definition.target_type)
-        }
-        LinkDirection::Reverse => {
-            // Reverse: target is the entity in the URL, source is the new entity
-            (extractor.entity_id, &extractor.link_definition.source_type)
-        }
-    };
+    // Validate before creating the entity so a rejected link leaves no orphan
+    extractor
+        .link_definition
+        .validate_metadata(extractor.direction, payload.metadata.as_ref())?;
 
-    // Get the entity creator for the target type
-    let entity_creator = state
-        .entity_creators
-        .get(target_entity_type)
-        .ok_or_else(|| {
-            ExtractorError::JsonError(format!(
-                "No entity creator registered for type: {}",
-                target_entity_type
-            ))
-        })?;
+    // The entity in the URL already exists; the new one takes the other end
+    let existing_entity_id = extractor.entity_id;
+    let (new_entity_type, entity_creator) =
+        new_entity_creator(&state, &extractor.link_definition, extractor.direction)?;
+
+    // The new entity has no links yet, so only the existing side can be full
+    ensure_link_slot(
+        &state,
+        &extractor.link_definition,
+        &existing_entity_id,
+        extractor.direction,
+    )
+    .await?;
 
     // Create the new entity
-    let created_entity = entity_creator
-        .create_from_json(payload.entity)
-        .await
-        .map_err(|e| ExtractorError::JsonError(format!("Failed to create entity: {}", e)))?;
+    let created_entity = create_new_entity(
+        entity_creator.as_ref(),
+        &auth,
+        tenant.as_ref(),
+        payload.entity,
+    )
+    .await?;
 
     // Extract the ID from the created entity
-    let target_entity_id = created_entity["id"].as_str().ok_or_else(|| {
+    let new_entity_id = created_entity["id"].as_str().ok_or_else(|| {
         ExtractorError::JsonError("Created entity missing 'id' field".to_string())
     })?;
-    let target_entity_id = Uuid::parse_str(target_entity_id)
+    let new_entity_id = Uuid::parse_str(new_entity_id)
         .map_err(|e| ExtractorError::JsonError(format!("Invalid UUID in created entity: {}", e)))?;
 
     // Create the link based on direction
     let link = match extractor.direction {
         LinkDirection::Forward => {
-            // Forward: source -> target (new entity)
+            // Forward: existing entity -> new entity
             LinkEntity::new(
-                extractor.link_definition.link_type,
-                source_entity_id,
-                target_entity_id,
+                &extractor.link_definition.link_type,
+                existing_entity_id,
+                new_entity_id,
                 payload.metadata,
             )
         }
         LinkDirection::Reverse => {
-            // Reverse: source (new entity) -> target
+            // Reverse: new entity -> existing entity
             LinkEntity::new(
-                extractor.link_definition.link_type,
-                target_entity_id,
-                source_entity_id,
+                &extractor.link_definition.link_type,
+                new_entity_id,
+                existing_entity_id,
                 payload.metadata,
             )
         }
-    };
+    }
+    .with_entity_types(
+        &extractor.link_definition.source_type,
+        &extractor.link_definition.target_type,
+    );
 
-    let created_link = state
-        .link_service
-        .create(link)
-        .await
-        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+    let created_link = insert_link_for_new_entity(
+        &state,
+        &extractor.link_definition,
+        tenant.as_ref(),
+        link,
+        entity_creator.as_ref(),
+        &new_entity_id,
+    )
+    .await?;
 
     // Emit entity created event
     state.publish_event(FrameworkEvent::Entity(
         crate::core::events::EntityEvent::Created {
-            entity_type: target_entity_type.clone(),
-            entity_id: target_entity_id,
+            entity_type: new_entity_type.clone(),
+            entity_id: new_entity_id,
             data: created_entity.clone(),
         },
     ));
+    state
+        .audit(AuditEntry::new(
+            new_entity_type.clone(),
+            new_entity_id,
+            AuditOperation::Create,
+            auth.actor(),
+            None,
+            Some(created_entity.clone()),
+        ))
+        .await;
 
     // Emit link created event
     state.publish_event(FrameworkEvent::Link(LinkEvent::Created {
@@ -622,10 +1489,18 @@ pub async fn create_linked_entity(
         target_id: created_link.target_id,
         metadata: created_link.metadata.clone(),
     }));
+    state
+        .audit_link(&auth, AuditOperation::Create, None, Some(&created_link))
+        .await;
 
     // Return both the created entity and the link
+    let mut entity = created_entity;
+    redact_entity(
+        &mut entity,
+        redacted_fields_for(&state, &auth, new_entity_type),
+    );
     let response = serde_json::json!({
-        "entity": created_entity,
+        "entity": entity,
         "link": created_link,
     });
 
@@ -635,40 +1510,45 @@ pub async fn create_linked_entity(
 /// Update a link's metadata using route name
 ///
 /// PUT/PATCH /{source_type}/{source_id}/{route_name}/{target_id}
+///
+/// The body's `metadata` replaces the link's, or is merged into it with
+/// `"merge": true`.
 pub async fn update_link(
     State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
     Path((source_type_plural, source_id, route_name, target_id)): Path<(
         String,
         Uuid,
         String,
         Uuid,
     )>,
-    Json(payload): Json<CreateLinkRequest>,
+    Json(payload): Json<UpdateLinkRequest>,
 ) -> Result<Response, ExtractorError> {
     let extractor = DirectLinkExtractor::from_path(
         (source_type_plural, source_id, route_name, target_id),
         &state.registry,
         &state.config,
     )?;
+    authorize_link(
+        &state,
+        &auth,
+        &extractor.link_definition,
+        "update",
+        &extractor.source_type,
+        &extractor.source_id,
+    )
+    .await?;
 
     // Find the existing link
-    let existing_links = state
-        .link_service
-        .find_by_source(
-            &extractor.source_id,
-            Some(&extractor.link_definition.link_type),
-            Some(&extractor.target_type),
-        )
-        .await
-        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-
-    let mut existing_link = existing_links
-        .into_iter()
-        .find(|link| link.target_id == extractor.target_id)
-        .ok_or_else(|| ExtractorError::RouteNotFound("Link not found".to_string()))?;
-
-    // Update metadata
-    existing_link.metadata = payload.metadata;
+    let mut existing_link = find_routed_link(&state, &extractor, tenant.as_ref()).await?;
+
+    // Update metadata, validating the result of a merge as a whole
+    let before = existing_link.clone();
+    existing_link.apply_metadata(payload.metadata, payload.merge);
+    extractor
+        .link_definition
+        .validate_metadata(extractor.direction, existing_link.metadata.as_ref())?;
     existing_link.touch();
 
     // Save the updated link
@@ -679,6 +1559,22 @@ pub async fn update_link(
         .await
         .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
 
+    state.publish_event(FrameworkEvent::Link(LinkEvent::Updated {
+        link_type: updated_link.link_type.clone(),
+        link_id,
+        source_id: updated_link.source_id,
+        target_id: updated_link.target_id,
+        metadata: updated_link.metadata.clone(),
+    }));
+    state
+        .audit_link(
+            &auth,
+            AuditOperation::Update,
+            Some(&before),
+            Some(&updated_link),
+        )
+        .await;
+
     Ok(Json(updated_link).into_response())
 }
 
@@ -687,6 +1583,8 @@ pub async fn update_link(
 /// DELETE /{source_type}/{source_id}/{route_name}/{target_id}
 pub async fn delete_link(
     State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
     Path((source_type_plural, source_id, route_name, target_id)): Path<(
         String,
         Uuid,
@@ -699,22 +1597,18 @@ pub async fn delete_link(
         &state.registry,
         &state.config,
     )?;
+    authorize_link(
+        &state,
+        &auth,
+        &extractor.link_definition,
+        "delete",
+        &extractor.source_type,
+        &extractor.source_id,
+    )
+    .await?;
 
     // Find the existing link first
-    let existing_links = state
-        .link_service
-        .find_by_source(
-            &extractor.source_id,
-            Some(&extractor.link_definition.link_type),
-            Some(&extractor.target_type),
-        )
-        .await
-        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-
-    let existing_link = existing_links
-        .into_iter()
-        .find(|link| link.target_id == extractor.target_id)
-        .ok_or(ExtractorError::LinkNotFound)?;
+    let existing_link = find_routed_link(&state, &extractor, tenant.as_ref()).await?;
 
     // Delete the link by its ID
     state
@@ -730,10 +1624,93 @@ pub async fn delete_link(
         source_id: existing_link.source_id,
         target_id: existing_link.target_id,
     }));
+    state
+        .audit_link(&auth, AuditOperation::Delete, Some(&existing_link), None)
+        .await;
 
     Ok(StatusCode::NO_CONTENT.into_response())
 }
 
+/// Delete every link of a named route for one entity
+///
+/// DELETE /{entity_type}/{entity_id}/{route_name}
+///
+/// Removes the links the matching `GET` would list: for a forward route the
+/// links whose source is the entity, for a reverse route those whose target
+/// is. Links of the same type in the other direction are kept. Returns the
+/// number of deleted links; a `Deleted` event is published for each.
+pub async fn delete_links_by_route(
+    State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
+    Path((entity_type_plural, entity_id, route_name)): Path<(String, Uuid, String)>,
+) -> Result<Json<DeleteLinksResponse>, ExtractorError> {
+    let extractor = LinkExtractor::from_path_and_registry(
+        (entity_type_plural, entity_id, route_name),
+        &state.registry,
+        &state.config,
+    )?;
+    authorize_link(
+        &state,
+        &auth,
+        &extractor.link_definition,
+        "delete",
+        &extractor.entity_type,
+        &extractor.entity_id,
+    )
+    .await?;
+
+    let definition = &extractor.link_definition;
+    let mut links = match extractor.direction {
+        LinkDirection::Forward => {
+            state
+                .link_service
+                .find_by_source(
+                    &extractor.entity_id,
+                    Some(&definition.link_type),
+                    Some(&definition.target_type),
+                )
+                .await
+        }
+        LinkDirection::Reverse => {
+            state
+                .link_service
+                .find_by_target(
+                    &extractor.entity_id,
+                    Some(&definition.link_type),
+                    Some(&definition.source_type),
+                )
+                .await
+        }
+    }
+    .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+    if let Some(tenant) = &tenant {
+        links.retain(|link| tenant.owns(link.tenant_id));
+    }
+
+    for link in &links {
+        state
+            .link_service
+            .delete(&link.id)
+            .await
+            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+        state.publish_event(FrameworkEvent::Link(LinkEvent::Deleted {
+            link_type: link.link_type.clone(),
+            link_id: link.id,
+            source_id: link.source_id,
+            target_id: link.target_id,
+        }));
+        state
+            .audit_link(&auth, AuditOperation::Delete, Some(link), None)
+            .await;
+    }
+
+    Ok(Json(DeleteLinksResponse {
+        link_type: definition.link_type.clone(),
+        deleted: links.len() as u64,
+    }))
+}
+
 /// Response for introspection endpoint
 #[derive(Debug, Serialize)]
 pub struct IntrospectionResponse {
@@ -743,16 +1720,34 @@ pub struct IntrospectionResponse {
 }
 
 /// Description of an available route
+///
+/// Each link route has two paths: the collection of linked entities and one
+/// link to a given entity, each with the methods it accepts.
 #[derive(Debug, Serialize)]
 pub struct RouteDescription {
+    /// `/{entity_type}/{entity_id}/{route_name}`
     pub path: String,
-    pub method: String,
+    /// List links (GET), create an entity and link it (POST), unlink all (DELETE)
+    pub methods: Vec<String>,
+    /// `/{entity_type}/{entity_id}/{route_name}/{<connected_to>_id}`
+    pub item_path: String,
+    /// Get (GET), create (POST), update (PUT) or delete (DELETE) one link
+    pub item_methods: Vec<String>,
+    /// Template for following the linked entity's own routes, when it has any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nested_path: Option<String>,
     pub link_type: String,
     pub direction: String,
     pub connected_to: String,
     pub description: Option<String>,
 }
 
+/// Methods served on a link route's collection path
+const ROUTE_METHODS: [&str; 3] = ["GET", "POST", "DELETE"];
+
+/// Methods served on a link route's item path
+const ROUTE_ITEM_METHODS: [&str; 4] = ["GET", "POST", "PUT", "DELETE"];
+
 /// Introspection: List all available link routes for an entity
 ///
 /// GET /{entity_type}/{entity_id}/links
@@ -774,13 +1769,28 @@ pub async fn list_available_links(
 
     let available_routes = routes
         .iter()
-        .map(|r| RouteDescription {
-            path: format!("/{}/{}/{}", entity_type_plural, entity_id, r.route_name),
-            method: "GET".to_string(),
-            link_type: r.link_type.clone(),
-            direction: format!("{:?}", r.direction),
-            connected_to: r.connected_to.clone(),
-            description: r.description.clone(),
+        .map(|r| {
+            let path = format!(
+                "{}/{}/{}/{}",
+                state.route_prefix, entity_type_plural, entity_id, r.route_name
+            );
+            let item_path = format!("{}/{{{}_id}}", path, r.connected_to);
+            let has_nested_routes = !state
+                .registry
+                .list_routes_for_entity(&r.connected_to)
+                .is_empty();
+
+            RouteDescription {
+                nested_path: has_nested_routes.then(|| format!("{}/{{route_name}}", item_path)),
+                path,
+                methods: ROUTE_METHODS.iter().map(|m| m.to_string()).collect(),
+                item_path,
+                item_methods: ROUTE_ITEM_METHODS.iter().map(|m| m.to_string()).collect(),
+                link_type: r.link_type.clone(),
+                direction: format!("{:?}", r.direction),
+                connected_to: r.connected_to.clone(),
+                description: r.description.clone(),
+            }
         })
         .collect();
 
@@ -791,14 +1801,284 @@ pub async fn list_available_links(
     }))
 }
 
-/// Handler générique pour GET sur chemins imbriqués illimités
+/// Query parameters for `DELETE /links`
+#[derive(Debug, Deserialize)]
+pub struct DeleteLinksParams {
+    /// Link type to delete
+    #[serde(rename = "type")]
+    pub link_type: String,
+
+    /// Optional JSON filter object (see [`LinkFilterCondition::parse_all`])
+    pub filter: Option<String>,
+
+    /// Must be `true`: bulk deletion cannot be undone
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+/// Response for bulk link deletion
+#[derive(Debug, Serialize)]
+pub struct DeleteLinksResponse {
+    pub link_type: String,
+    pub deleted: u64,
+}
+
+/// Delete every link of a type matching a filter
 ///
-/// Supporte des chemins comme:
-/// - GET /users/123/invoices/456/orders (liste les orders)
-/// - GET /users/123/invoices/456/orders/789 (get un order spécifique)
-pub async fn handle_nested_path_get(
+/// DELETE /links?type=driver&filter={"metadata.expired":true}&confirm=true
+///
+/// Restricted to admins identified by [`AppState::auth_provider`]; without a
+/// provider the route is closed. With tenancy on, only the links of the
+/// request's tenant are deleted. Without `confirm=true` the request is
+/// rejected. No per-link events or audit entries are recorded.
+pub async fn delete_links_where(
     State(state): State<AppState>,
-    Path(path): Path<String>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
+    Query(params): Query<DeleteLinksParams>,
+) -> Result<Json<DeleteLinksResponse>, ExtractorError> {
+    let Some(context) = &auth.0 else {
+        return Err(ExtractorError::Unauthorized);
+    };
+    if !AuthPolicy::AdminOnly.evaluate(context, "DELETE /links") {
+        return Err(match context {
+            AuthContext::Anonymous => ExtractorError::Unauthorized,
+            _ => ExtractorError::Forbidden("bulk link deletion requires an admin".to_string()),
+        });
+    }
+
+    if !params.confirm {
+        return Err(ExtractorError::JsonError(
+            "bulk link deletion requires confirm=true".to_string(),
+        ));
+    }
+
+    if !state
+        .config
+        .links
+        .iter()
+        .any(|def| def.link_type == params.link_type)
+    {
+        return Err(ExtractorError::RouteNotFound(params.link_type));
+    }
+
+    let filter = params
+        .filter
+        .as_deref()
+        .map(serde_json::from_str::<Value>)
+        .transpose()
+        .map_err(|e| ExtractorError::JsonError(format!("invalid filter: {}", e)))?;
+    if let Some(filter) = &filter {
+        LinkFilterCondition::parse_all(filter).map_err(ExtractorError::JsonError)?;
+    }
+
+    let deleted = match &tenant {
+        Some(tenant) => {
+            state
+                .link_service
+                .delete_where_for_tenant(&params.link_type, filter.as_ref(), tenant)
+                .await
+        }
+        None => {
+            state
+                .link_service
+                .delete_where(&params.link_type, filter.as_ref())
+                .await
+        }
+    }
+    .map_err(|e| ExtractorError::Internal(e.to_string()))?;
+
+    Ok(Json(DeleteLinksResponse {
+        link_type: params.link_type,
+        deleted,
+    }))
+}
+
+/// Query parameters of `GET /{entity_type}/{entity_id}/relations`
+#[derive(Debug, Default, Deserialize)]
+pub struct RelationsParams {
+    /// Which links to keep, `both` when not given
+    #[serde(default)]
+    pub direction: RelationDirection,
+}
+
+/// List every link touching an entity - WITH PAGINATION
+///
+/// GET /{entity_type}/{entity_id}/relations?direction=in|out|both
+///
+/// `direction` defaults to `both`. Pagination, the max page size and filters
+/// behave as on the other link list endpoints.
+pub async fn list_relations(
+    State(state): State<AppState>,
+    tenant: Option<TenantContext>,
+    Path((entity_type_plural, entity_id)): Path<(String, Uuid)>,
+    relations: Result<Query<RelationsParams>, QueryRejection>,
+    Query(params): Query<QueryParams>,
+) -> Result<Json<PaginatedRelationsResponse>, ExtractorError> {
+    if !state
+        .config
+        .entities
+        .iter()
+        .any(|e| e.plural == entity_type_plural)
+    {
+        return Err(ExtractorError::RouteNotFound(entity_type_plural));
+    }
+
+    let Query(RelationsParams { direction }) =
+        relations.map_err(|e| ExtractorError::JsonError(e.body_text()))?;
+
+    let mut links = state
+        .link_service
+        .find_relations(&entity_id, direction)
+        .await
+        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+    links.retain(|link| visible_to(tenant.as_ref(), link));
+
+    if let Some(filter_value) = params.filter_value() {
+        links = apply_link_filters(links, &filter_value)?;
+    }
+
+    let total = links.len();
+    let page = params.page();
+    let limit = params.limit();
+    let start = (page - 1) * limit;
+
+    Ok(Json(PaginatedRelationsResponse {
+        data: links.into_iter().skip(start).take(limit).collect(),
+        pagination: PaginationMeta::new(page, limit, total),
+        direction,
+    }))
+}
+
+/// First broken link of a nested path, found by [`validate_link_chain`]
+#[derive(Debug)]
+struct BrokenChainLink {
+    /// Index in the chain of the segment whose link to the next one fails
+    segment: usize,
+    error: ExtractorError,
+}
+
+impl BrokenChainLink {
+    /// Response of a `?dry_run=true` request for this link
+    ///
+    /// Only missing links and links without a direction are reported; link
+    /// service errors still fail the request.
+    fn into_report(
+        self,
+        chain: &[LinkPathSegment],
+    ) -> Result<Json<serde_json::Value>, ExtractorError> {
+        if !matches!(
+            self.error,
+            ExtractorError::LinkNotFound | ExtractorError::InvalidPath
+        ) {
+            return Err(self.error);
+        }
+
+        let current = &chain[self.segment];
+        let next = &chain[self.segment + 1];
+        Ok(Json(serde_json::json!({
+            "valid": false,
+            "error": self.error.to_string(),
+            "failed_segment": {
+                "index": self.segment,
+                "entity_type": current.entity_type,
+                "entity_id": current.entity_id,
+                "route_name": current.route_name,
+                "target_type": next.entity_type,
+                "target_id": next.entity_id,
+            }
+        })))
+    }
+}
+
+/// Check that every link of the chain exists, first to last
+///
+/// A list path ends with a segment without an ID (`Uuid::nil()`); with
+/// `skip_trailing_nil`, links to such a segment are not checked. Stops at
+/// the first broken link.
+///
+/// Shared by [`handle_nested_path_get`] (list, item and `?dry_run=true`)
+/// and [`handle_nested_path_post`].
+async fn validate_link_chain(
+    state: &AppState,
+    tenant: Option<&TenantContext>,
+    chain: &[LinkPathSegment],
+    skip_trailing_nil: bool,
+) -> Result<(), BrokenChainLink> {
+    for (i, pair) in chain.windows(2).enumerate() {
+        let (current, next) = (&pair[0], &pair[1]);
+        let broken = |error| BrokenChainLink { segment: i, error };
+
+        // A nil next.entity_id ends a list path: there is no link to check
+        if skip_trailing_nil && next.entity_id.is_nil() {
+            continue;
+        }
+
+        // Case 1: the segment has a link_definition -> the link starts at current
+        // Case 2: first segment without a link_definition, but next has one
+        // -> start of the chain, current must be linked to next
+        let (link_def, direction) = match (&current.link_definition, &next.link_definition) {
+            (Some(link_def), _) => (link_def, current.link_direction),
+            (None, Some(next_link_def)) => (next_link_def, next.link_direction),
+            (None, None) => continue,
+        };
+
+        let link_exists = match direction {
+            Some(LinkDirection::Forward) => {
+                // Forward: current is the source, next the target
+                let links = state
+                    .link_service
+                    .find_by_source(
+                        &current.entity_id,
+                        Some(&link_def.link_type),
+                        Some(&link_def.target_type),
+                    )
+                    .await
+                    .map_err(|e| broken(ExtractorError::JsonError(e.to_string())))?;
+                links
+                    .iter()
+                    .any(|l| l.target_id == next.entity_id && visible_to(tenant, l))
+            }
+            Some(LinkDirection::Reverse) => {
+                // Reverse: current is the target, next the source
+                let links = state
+                    .link_service
+                    .find_by_target(
+                        &current.entity_id,
+                        Some(&link_def.link_type),
+                        Some(&link_def.source_type),
+                    )
+                    .await
+                    .map_err(|e| broken(ExtractorError::JsonError(e.to_string())))?;
+                links
+                    .iter()
+                    .any(|l| l.source_id == next.entity_id && visible_to(tenant, l))
+            }
+            None => return Err(broken(ExtractorError::InvalidPath)),
+        };
+
+        if !link_exists {
+            return Err(broken(ExtractorError::LinkNotFound));
+        }
+    }
+
+    Ok(())
+}
+
+/// Generic GET handler for nested paths of any depth
+///
+/// Supports paths such as:
+/// - GET /users/123/invoices/456/orders (lists the orders)
+/// - GET /users/123/invoices/456/orders/789 (gets one order)
+///
+/// With `?dry_run=true`, only the chain is validated: the response is
+/// `{"valid": true}`, or `{"valid": false, "error", "failed_segment"}` for
+/// the first missing link.
+pub async fn handle_nested_path_get(
+    State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
+    Path(path): Path<String>,
     Query(params): Query<QueryParams>,
 ) -> Result<Json<serde_json::Value>, ExtractorError> {
     // Parser le path en segments
@@ -818,131 +2098,97 @@ pub async fn handle_nested_path_get(
     let extractor =
         RecursiveLinkExtractor::from_segments(segments, &state.registry, &state.config)?;
 
-    // Si is_list, récupérer les liens depuis la dernière entité
-    if extractor.is_list {
-        // Valider toute la chaîne de liens avant de retourner les résultats
-        // Pour chaque segment avec un link_definition, vérifier que le lien existe
-        // SAUF pour le dernier segment si c'est une liste (ID = Uuid::nil())
+    // Same policy as list_links / get_link_by_route, on the last link
+    if let (Some(link_def), Some(penultimate)) =
+        (extractor.final_link_def(), extractor.penultimate_segment())
+    {
+        let operation = if extractor.is_list { "list" } else { "get" };
+        authorize_link(
+            &state,
+            &auth,
+            link_def,
+            operation,
+            &penultimate.entity_type,
+            &penultimate.entity_id,
+        )
+        .await?;
+    }
 
-        use crate::links::registry::LinkDirection;
+    // Validate the whole link chain before returning anything; with
+    // ?dry_run=true, only the result of this validation is returned
+    let validation =
+        validate_link_chain(&state, tenant.as_ref(), &extractor.chain, extractor.is_list).await;
+    if params.dry_run {
+        return match validation {
+            Ok(()) => Ok(Json(serde_json::json!({ "valid": true }))),
+            Err(broken) => broken.into_report(&extractor.chain),
+        };
+    }
+    validation.map_err(|broken| broken.error)?;
 
-        // VALIDATION COMPLÈTE DE LA CHAÎNE
-        for i in 0..extractor.chain.len() - 1 {
-            let current = &extractor.chain[i];
-            let next = &extractor.chain[i + 1];
+    // Si is_list, récupérer les liens depuis la dernière entité
+    if extractor.is_list {
+        // Toute la chaîne est valide, récupérer les liens finaux
+        if let Some(link_def) = extractor.final_link_def() {
+            // Pour une liste, on veut l'ID du segment pénultième (celui qui a le lien)
+            let penultimate = extractor
+                .penultimate_segment()
+                .ok_or(ExtractorError::InvalidPath)?;
+            let entity_id = penultimate.entity_id;
 
-            // Si next.entity_id est Uuid::nil(), c'est une liste finale, on ne valide pas ce lien
-            if next.entity_id.is_nil() {
-                continue;
-            }
+            use crate::links::registry::LinkDirection;
 
-            // Cas 1: Le segment a un link_definition → validation normale
-            if let Some(link_def) = &current.link_definition {
-                let link_exists = match current.link_direction {
-                    Some(LinkDirection::Forward) => {
-                        // Forward: current est la source, next est le target
-                        let links = state
+            // Récupérer les liens selon la direction
+            let metadata_fields = params.metadata_fields().unwrap_or_default();
+            let (links, enrichment_context) = match penultimate.link_direction {
+                Some(LinkDirection::Forward) => {
+                    // Forward: entity_id est la source
+                    let links = if metadata_fields.is_empty() {
+                        state
                             .link_service
                             .find_by_source(
-                                &current.entity_id,
+                                &entity_id,
                                 Some(&link_def.link_type),
                                 Some(&link_def.target_type),
                             )
                             .await
-                            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                        links.iter().any(|l| l.target_id == next.entity_id)
-                    }
-                    Some(LinkDirection::Reverse) => {
-                        // Reverse: current est le target, next est la source
-                        let links = state
+                    } else {
+                        state
                             .link_service
-                            .find_by_target(&current.entity_id, None, Some(&link_def.link_type))
+                            .find_by_source_projected(
+                                &entity_id,
+                                Some(&link_def.link_type),
+                                Some(&link_def.target_type),
+                                &metadata_fields,
+                            )
                             .await
-                            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                        links.iter().any(|l| l.source_id == next.entity_id)
                     }
-                    None => {
-                        return Err(ExtractorError::InvalidPath);
-                    }
-                };
-
-                if !link_exists {
-                    return Err(ExtractorError::LinkNotFound);
+                    .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+                    (links, EnrichmentContext::FromSource)
                 }
-            }
-            // Cas 2: Premier segment sans link_definition mais next a un link_definition
-            // → C'est le début d'une chaîne, on doit vérifier que current est lié à next
-            else if let Some(next_link_def) = &next.link_definition {
-                let link_exists = match next.link_direction {
-                    Some(LinkDirection::Forward) => {
-                        // Forward depuis current: current → next
-                        let links = state
+                Some(LinkDirection::Reverse) => {
+                    // Reverse: entity_id est le target, on cherche les sources
+                    let links = if metadata_fields.is_empty() {
+                        state
                             .link_service
-                            .find_by_source(
-                                &current.entity_id,
-                                Some(&next_link_def.link_type),
-                                Some(&next_link_def.target_type),
+                            .find_by_target(
+                                &entity_id,
+                                Some(&link_def.link_type),
+                                Some(&link_def.source_type),
                             )
                             .await
-                            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                        links.iter().any(|l| l.target_id == next.entity_id)
-                    }
-                    Some(LinkDirection::Reverse) => {
-                        // Reverse depuis current: current ← next (donc next est source)
-                        let links = state
+                    } else {
+                        state
                             .link_service
-                            .find_by_target(
-                                &current.entity_id,
-                                None,
-                                Some(&next_link_def.link_type),
+                            .find_by_target_projected(
+                                &entity_id,
+                                Some(&link_def.link_type),
+                                Some(&link_def.source_type),
+                                &metadata_fields,
                             )
                             .await
-                            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                        links.iter().any(|l| l.source_id == next.entity_id)
                     }
-                    None => {
-                        return Err(ExtractorError::InvalidPath);
-                    }
-                };
-
-                if !link_exists {
-                    return Err(ExtractorError::LinkNotFound);
-                }
-            }
-        }
-
-        // Toute la chaîne est valide, récupérer les liens finaux
-        if let Some(link_def) = extractor.final_link_def() {
-            // Pour une liste, on veut l'ID du segment pénultième (celui qui a le lien)
-            let penultimate = extractor
-                .penultimate_segment()
-                .ok_or(ExtractorError::InvalidPath)?;
-            let entity_id = penultimate.entity_id;
-
-            use crate::links::registry::LinkDirection;
-
-            // Récupérer les liens selon la direction
-            let (links, enrichment_context) = match penultimate.link_direction {
-                Some(LinkDirection::Forward) => {
-                    // Forward: entity_id est la source
-                    let links = state
-                        .link_service
-                        .find_by_source(
-                            &entity_id,
-                            Some(&link_def.link_type),
-                            Some(&link_def.target_type),
-                        )
-                        .await
-                        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                    (links, EnrichmentContext::FromSource)
-                }
-                Some(LinkDirection::Reverse) => {
-                    // Reverse: entity_id est le target, on cherche les sources
-                    let links = state
-                        .link_service
-                        .find_by_target(&entity_id, None, Some(&link_def.link_type))
-                        .await
-                        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+                    .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
                     (links, EnrichmentContext::FromTarget)
                 }
                 None => {
@@ -950,13 +2196,23 @@ pub async fn handle_nested_path_get(
                 }
             };
 
+            let mut links = links;
+            links.retain(|link| visible_to(tenant.as_ref(), link));
+
             // Enrichir TOUS les liens
-            let mut all_enriched =
-                enrich_links_with_entities(&state, links, enrichment_context, link_def).await?;
+            let mut all_enriched = enrich_links_with_entities(
+                &state,
+                tenant.as_ref(),
+                links,
+                enrichment_context,
+                link_def,
+            )
+            .await?;
+            redact_enriched_links(&state, &auth, &mut all_enriched, link_def);
 
             // Apply filters if provided
             if let Some(filter_value) = params.filter_value() {
-                all_enriched = apply_link_filters(all_enriched, &filter_value);
+                all_enriched = apply_link_filters(all_enriched, &filter_value)?;
             }
 
             let total = all_enriched.len();
@@ -966,10 +2222,11 @@ pub async fn handle_nested_path_get(
             let limit = params.limit();
             let start = (page - 1) * limit;
 
-            let paginated_links: Vec<EnrichedLink> =
+            let mut paginated_links: Vec<EnrichedLink> =
                 all_enriched.into_iter().skip(start).take(limit).collect();
+            let warnings = take_warnings(&mut paginated_links);
 
-            Ok(Json(serde_json::json!({
+            let mut body = serde_json::json!({
                 "data": paginated_links,
                 "pagination": {
                     "page": page,
@@ -982,7 +2239,11 @@ pub async fn handle_nested_path_get(
                 "link_type": link_def.link_type,
                 "direction": format!("{:?}", penultimate.link_direction),
                 "description": link_def.description
-            })))
+            });
+            if !warnings.is_empty() {
+                body["warnings"] = serde_json::json!(warnings);
+            }
+            Ok(Json(body))
         } else {
             Err(ExtractorError::InvalidPath)
         }
@@ -991,81 +2252,6 @@ pub async fn handle_nested_path_get(
 
         use crate::links::registry::LinkDirection;
 
-        // VALIDATION COMPLÈTE DE LA CHAÎNE (aussi pour items spécifiques)
-        for i in 0..extractor.chain.len() - 1 {
-            let current = &extractor.chain[i];
-            let next = &extractor.chain[i + 1];
-
-            // Cas 1: Le segment a un link_definition → validation normale
-            if let Some(link_def) = &current.link_definition {
-                let link_exists = match current.link_direction {
-                    Some(LinkDirection::Forward) => {
-                        let links = state
-                            .link_service
-                            .find_by_source(
-                                &current.entity_id,
-                                Some(&link_def.link_type),
-                                Some(&link_def.target_type),
-                            )
-                            .await
-                            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                        links.iter().any(|l| l.target_id == next.entity_id)
-                    }
-                    Some(LinkDirection::Reverse) => {
-                        let links = state
-                            .link_service
-                            .find_by_target(&current.entity_id, None, Some(&link_def.link_type))
-                            .await
-                            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                        links.iter().any(|l| l.source_id == next.entity_id)
-                    }
-                    None => {
-                        return Err(ExtractorError::InvalidPath);
-                    }
-                };
-
-                if !link_exists {
-                    return Err(ExtractorError::LinkNotFound);
-                }
-            }
-            // Cas 2: Premier segment sans link_definition mais next a un link_definition
-            else if let Some(next_link_def) = &next.link_definition {
-                let link_exists = match next.link_direction {
-                    Some(LinkDirection::Forward) => {
-                        let links = state
-                            .link_service
-                            .find_by_source(
-                                &current.entity_id,
-                                Some(&next_link_def.link_type),
-                                Some(&next_link_def.target_type),
-                            )
-                            .await
-                            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                        links.iter().any(|l| l.target_id == next.entity_id)
-                    }
-                    Some(LinkDirection::Reverse) => {
-                        let links = state
-                            .link_service
-                            .find_by_target(
-                                &current.entity_id,
-                                None,
-                                Some(&next_link_def.link_type),
-                            )
-                            .await
-                            .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
-                        links.iter().any(|l| l.source_id == next.entity_id)
-                    }
-                    None => {
-                        return Err(ExtractorError::InvalidPath);
-                    }
-                };
-
-                if !link_exists {
-                    return Err(ExtractorError::LinkNotFound);
-                }
-            }
-        }
-
         // Toute la chaîne est validée, récupérer le lien final
         if let Some(link_def) = extractor.final_link_def() {
             let (target_id, _) = extractor.final_target();
@@ -1087,20 +2273,24 @@ pub async fn handle_nested_path_get(
 
                     links
                         .into_iter()
-                        .find(|l| l.target_id == target_id)
+                        .find(|l| l.target_id == target_id && visible_to(tenant.as_ref(), l))
                         .ok_or(ExtractorError::LinkNotFound)?
                 }
                 Some(LinkDirection::Reverse) => {
                     // Reverse: penultimate est le target, target_id est la source
                     let links = state
                         .link_service
-                        .find_by_target(&penultimate.entity_id, None, Some(&link_def.link_type))
+                        .find_by_target(
+                            &penultimate.entity_id,
+                            Some(&link_def.link_type),
+                            Some(&link_def.source_type),
+                        )
                         .await
                         .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
 
                     links
                         .into_iter()
-                        .find(|l| l.source_id == target_id)
+                        .find(|l| l.source_id == target_id && visible_to(tenant.as_ref(), l))
                         .ok_or(ExtractorError::LinkNotFound)?
                 }
                 None => {
@@ -1109,17 +2299,21 @@ pub async fn handle_nested_path_get(
             };
 
             // Enrichir le lien
-            let enriched = enrich_links_with_entities(
+            let mut enriched = enrich_links_with_entities(
                 &state,
+                tenant.as_ref(),
                 vec![link],
                 EnrichmentContext::DirectLink,
                 link_def,
             )
             .await?;
+            redact_enriched_links(&state, &auth, &mut enriched, link_def);
 
-            Ok(Json(serde_json::json!({
-                "link": enriched.first()
-            })))
+            let link = enriched
+                .into_iter()
+                .next()
+                .map(EnrichedLink::with_inline_warnings);
+            Ok(Json(serde_json::json!({ "link": link })))
         } else {
             Err(ExtractorError::InvalidPath)
         }
@@ -1132,6 +2326,8 @@ pub async fn handle_nested_path_get(
 /// - POST /users/123/invoices/456/orders (crée un nouvel order + link)
 pub async fn handle_nested_path_post(
     State(state): State<AppState>,
+    auth: RequestAuth,
+    tenant: Option<TenantContext>,
     Path(path): Path<String>,
     Json(payload): Json<CreateLinkedEntityRequest>,
 ) -> Result<Response, ExtractorError> {
@@ -1150,30 +2346,47 @@ pub async fn handle_nested_path_post(
     let extractor =
         RecursiveLinkExtractor::from_segments(segments, &state.registry, &state.config)?;
 
-    // Récupérer le dernier lien
+    // Last link and its parent (the source of the new link)
     let link_def = extractor
         .final_link_def()
         .ok_or(ExtractorError::InvalidPath)?;
+    let parent = extractor
+        .penultimate_segment()
+        .ok_or(ExtractorError::InvalidPath)?;
+    authorize_link(
+        &state,
+        &auth,
+        link_def,
+        "create",
+        &parent.entity_type,
+        &parent.entity_id,
+    )
+    .await?;
 
-    let (source_id, _) = extractor.final_target();
-    let target_entity_type = &link_def.target_type;
+    // The chain leading to the new entity's parent must exist
+    validate_link_chain(&state, tenant.as_ref(), &extractor.chain, true)
+        .await
+        .map_err(|broken| broken.error)?;
+
+    // Nested creation always links the new entity as the target
+    link_def.validate_metadata(LinkDirection::Forward, payload.metadata.as_ref())?;
+
+    let source_id = parent.entity_id;
 
     // Récupérer le creator pour l'entité target
-    let entity_creator = state
-        .entity_creators
-        .get(target_entity_type)
-        .ok_or_else(|| {
-            ExtractorError::JsonError(format!(
-                "No entity creator registered for type: {}",
-                target_entity_type
-            ))
-        })?;
+    let (target_entity_type, entity_creator) =
+        new_entity_creator(&state, link_def, LinkDirection::Forward)?;
+
+    ensure_link_slot(&state, link_def, &source_id, LinkDirection::Forward).await?;
 
     // Créer la nouvelle entité
-    let created_entity = entity_creator
-        .create_from_json(payload.entity)
-        .await
-        .map_err(|e| ExtractorError::JsonError(format!("Failed to create entity: {}", e)))?;
+    let created_entity = create_new_entity(
+        entity_creator.as_ref(),
+        &auth,
+        tenant.as_ref(),
+        payload.entity,
+    )
+    .await?;
 
     // Extraire l'ID de l'entité créée
     let target_entity_id = created_entity["id"].as_str().ok_or_else(|| {
@@ -1188,13 +2401,18 @@ pub async fn handle_nested_path_post(
         source_id,
         target_entity_id,
         payload.metadata,
-    );
+    )
+    .with_entity_types(&link_def.source_type, &link_def.target_type);
 
-    let created_link = state
-        .link_service
-        .create(link)
-        .await
-        .map_err(|e| ExtractorError::JsonError(e.to_string()))?;
+    let created_link = insert_link_for_new_entity(
+        &state,
+        link_def,
+        tenant.as_ref(),
+        link,
+        entity_creator.as_ref(),
+        &target_entity_id,
+    )
+    .await?;
 
     // Emit entity created event
     state.publish_event(FrameworkEvent::Entity(
@@ -1204,6 +2422,16 @@ pub async fn handle_nested_path_post(
             data: created_entity.clone(),
         },
     ));
+    state
+        .audit(AuditEntry::new(
+            target_entity_type.clone(),
+            target_entity_id,
+            AuditOperation::Create,
+            auth.actor(),
+            None,
+            Some(created_entity.clone()),
+        ))
+        .await;
 
     // Emit link created event
     state.publish_event(FrameworkEvent::Link(LinkEvent::Created {
@@ -1213,9 +2441,17 @@ pub async fn handle_nested_path_post(
         target_id: created_link.target_id,
         metadata: created_link.metadata.clone(),
     }));
+    state
+        .audit_link(&auth, AuditOperation::Create, None, Some(&created_link))
+        .await;
 
+    let mut entity = created_entity;
+    redact_entity(
+        &mut entity,
+        redacted_fields_for(&state, &auth, target_entity_type),
+    );
     let response = serde_json::json!({
-        "entity": created_entity,
+        "entity": entity,
         "link": created_link,
     });
 
@@ -1236,11 +2472,17 @@ mod tests {
                     singular: "user".to_string(),
                     plural: "users".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "car".to_string(),
                     plural: "cars".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -1251,11 +2493,17 @@ mod tests {
                 reverse_route_name: "users-owners".to_string(),
                 description: Some("User owns a car".to_string()),
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         });
 
         let registry = Arc::new(LinkRouteRegistry::new(config.clone()));
@@ -1268,6 +2516,13 @@ mod tests {
             entity_fetchers: Arc::new(HashMap::new()),
             entity_creators: Arc::new(HashMap::new()),
             event_bus: None,
+            enrichment_fallback: Default::default(),
+            enrichment_concurrency: DEFAULT_ENRICHMENT_CONCURRENCY,
+            auth_provider: None,
+            audit_log: None,
+            allow_duplicate_links: false,
+            id_strategy: IdStrategy::default(),
+            route_prefix: String::new(),
         }
     }
 
@@ -1417,6 +2672,8 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             status: status.to_string(),
+            enrichment_error: None,
+            warnings: Vec::new(),
         }
     }
 
@@ -1426,14 +2683,14 @@ mod tests {
             make_enriched_link("owner", "active", None, None, None),
             make_enriched_link("driver", "active", None, None, None),
         ];
-        let result = apply_link_filters(links, &serde_json::Value::Null);
+        let result = apply_link_filters(links, &serde_json::Value::Null).unwrap();
         assert_eq!(result.len(), 2, "null filter should return all links");
     }
 
     #[test]
     fn test_apply_link_filters_non_object_filter_returns_all() {
         let links = vec![make_enriched_link("owner", "active", None, None, None)];
-        let result = apply_link_filters(links, &serde_json::json!("not an object"));
+        let result = apply_link_filters(links, &serde_json::json!("not an object")).unwrap();
         assert_eq!(result.len(), 1, "non-object filter should return all links");
     }
 
@@ -1443,7 +2700,7 @@ mod tests {
             make_enriched_link("owner", "active", None, None, None),
             make_enriched_link("driver", "inactive", None, None, None),
         ];
-        let result = apply_link_filters(links, &serde_json::json!({}));
+        let result = apply_link_filters(links, &serde_json::json!({})).unwrap();
         assert_eq!(
             result.len(),
             2,
@@ -1459,7 +2716,7 @@ mod tests {
             make_enriched_link("owner", "active", None, None, None),
         ];
         let filter = serde_json::json!({ "status": "active" });
-        let result = apply_link_filters(links, &filter);
+        let result = apply_link_filters(links, &filter).unwrap();
         assert_eq!(result.len(), 2, "should filter to only active links");
         for link in &result {
             assert_eq!(link.status, "active");
@@ -1474,7 +2731,7 @@ mod tests {
             make_enriched_link("owner", "active", None, None, None),
         ];
         let filter = serde_json::json!({ "link_type": "owner" });
-        let result = apply_link_filters(links, &filter);
+        let result = apply_link_filters(links, &filter).unwrap();
         assert_eq!(result.len(), 2, "should filter to only 'owner' links");
     }
 
@@ -1497,7 +2754,7 @@ mod tests {
             ),
         ];
         let filter = serde_json::json!({ "target.name": "Car A" });
-        let result = apply_link_filters(links, &filter);
+        let result = apply_link_filters(links, &filter).unwrap();
         assert_eq!(result.len(), 1, "should filter by nested target.name");
     }
 
@@ -1520,7 +2777,7 @@ mod tests {
             ),
         ];
         let filter = serde_json::json!({ "source.email": "bob@test.com" });
-        let result = apply_link_filters(links, &filter);
+        let result = apply_link_filters(links, &filter).unwrap();
         assert_eq!(result.len(), 1, "should filter by nested source.email");
     }
 
@@ -1532,7 +2789,7 @@ mod tests {
             make_enriched_link("driver", "active", None, None, None),
         ];
         let filter = serde_json::json!({ "link_type": "owner", "status": "active" });
-        let result = apply_link_filters(links, &filter);
+        let result = apply_link_filters(links, &filter).unwrap();
         assert_eq!(
             result.len(),
             1,
@@ -1546,7 +2803,7 @@ mod tests {
     fn test_apply_link_filters_no_match_returns_empty() {
         let links = vec![make_enriched_link("owner", "active", None, None, None)];
         let filter = serde_json::json!({ "status": "deleted" });
-        let result = apply_link_filters(links, &filter);
+        let result = apply_link_filters(links, &filter).unwrap();
         assert!(
             result.is_empty(),
             "non-matching filter should return empty vec"
@@ -1558,13 +2815,35 @@ mod tests {
         let links = vec![make_enriched_link("owner", "active", None, None, None)];
         // "nonexistent_field" does not exist on EnrichedLink serialization
         let filter = serde_json::json!({ "nonexistent_field": "value" });
-        let result = apply_link_filters(links, &filter);
+        let result = apply_link_filters(links, &filter).unwrap();
         assert!(
             result.is_empty(),
             "filtering by a missing field should exclude the link"
         );
     }
 
+    #[test]
+    fn test_apply_link_filters_operators_on_nested_field() {
+        let links: Vec<EnrichedLink> = [50, 150, 300]
+            .into_iter()
+            .map(|amount| {
+                make_enriched_link(
+                    "owner",
+                    "active",
+                    Some(serde_json::json!({ "amount": amount })),
+                    None,
+                    None,
+                )
+            })
+            .collect();
+        let filter = serde_json::json!({ "target.amount": { "$gt": 100, "$lte": 300 } });
+        let result = apply_link_filters(links, &filter).unwrap();
+        assert_eq!(result.len(), 2);
+
+        let filter = serde_json::json!({ "status": { "$between": [1, 2] } });
+        assert!(apply_link_filters(result, &filter).is_err());
+    }
+
     // ------------------------------------------------------------------
     // get_link_auth_policy
     // ------------------------------------------------------------------
@@ -1579,6 +2858,9 @@ mod tests {
             reverse_route_name: "as".to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: Some(LinkAuthConfig {
                 list: "public".to_string(),
                 get: "authenticated".to_string(),
@@ -1586,6 +2868,8 @@ mod tests {
                 update: "owner".to_string(),
                 delete: "service_only".to_string(),
             }),
+            cardinality: None,
+            metadata_schema: None,
         }
     }
 
@@ -1649,7 +2933,12 @@ mod tests {
             reverse_route_name: "as".to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: None,
+            cardinality: None,
+            metadata_schema: None,
         };
         let result = AppState::get_link_auth_policy(&def, "list");
         assert_eq!(
@@ -1715,16 +3004,25 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "payment".to_string(),
                     plural: "payments".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![
@@ -1736,7 +3034,12 @@ mod tests {
                     reverse_route_name: "order".to_string(),
                     description: Some("Order has invoices".to_string()),
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
                 LinkDefinition {
                     link_type: "payment".to_string(),
@@ -1746,12 +3049,18 @@ mod tests {
                     reverse_route_name: "invoice".to_string(),
                     description: None,
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
             ],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         });
 
         let registry = Arc::new(LinkRouteRegistry::new(config.clone()));
@@ -1764,6 +3073,13 @@ mod tests {
             entity_fetchers: Arc::new(HashMap::new()),
             entity_creators: Arc::new(HashMap::new()),
             event_bus: None,
+            enrichment_fallback: Default::default(),
+            enrichment_concurrency: DEFAULT_ENRICHMENT_CONCURRENCY,
+            auth_provider: None,
+            audit_log: None,
+            allow_duplicate_links: false,
+            id_strategy: IdStrategy::default(),
+            route_prefix: String::new(),
         }
     }
 
@@ -1830,10 +3146,15 @@ mod tests {
         let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
 
         let link_def = &state.config.links[0];
-        let enriched =
-            enrich_links_with_entities(&state, vec![link], EnrichmentContext::FromSource, link_def)
-                .await
-                .expect("enrichment should succeed");
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![link],
+            EnrichmentContext::FromSource,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
 
         assert_eq!(enriched.len(), 1);
         assert!(
@@ -1852,10 +3173,15 @@ mod tests {
         let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
 
         let link_def = &state.config.links[0];
-        let enriched =
-            enrich_links_with_entities(&state, vec![link], EnrichmentContext::FromTarget, link_def)
-                .await
-                .expect("enrichment should succeed");
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![link],
+            EnrichmentContext::FromTarget,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
 
         assert_eq!(enriched.len(), 1);
         assert!(
@@ -1884,10 +3210,15 @@ mod tests {
         let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
         let link_def = &state.config.links[0];
 
-        let enriched =
-            enrich_links_with_entities(&state, vec![link], EnrichmentContext::FromSource, link_def)
-                .await
-                .expect("enrichment should succeed");
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![link],
+            EnrichmentContext::FromSource,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
 
         assert_eq!(enriched.len(), 1);
         let target = enriched[0]
@@ -1924,10 +3255,15 @@ mod tests {
         let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
         let link_def = &state.config.links[0];
 
-        let enriched =
-            enrich_links_with_entities(&state, vec![link], EnrichmentContext::DirectLink, link_def)
-                .await
-                .expect("enrichment should succeed");
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![link],
+            EnrichmentContext::DirectLink,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
 
         assert_eq!(enriched.len(), 1);
         assert!(
@@ -1957,41 +3293,280 @@ mod tests {
         );
 
         let link_def = &state.config.links[0];
-        let enriched =
-            enrich_links_with_entities(&state, vec![link], EnrichmentContext::FromSource, link_def)
-                .await
-                .expect("enrichment should succeed");
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![link],
+            EnrichmentContext::FromSource,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
 
         assert_eq!(enriched[0].metadata, Some(metadata));
     }
 
     #[tokio::test]
-    async fn test_enrich_links_empty_input() {
-        let state = create_test_state();
+    async fn test_enrich_links_error_marker_names_missing_fetcher() {
+        let mut state = create_test_state();
+        state.enrichment_fallback = EnrichmentFallback::ErrorMarker;
+        let link =
+            crate::core::link::LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None);
+
         let link_def = &state.config.links[0];
-        let enriched =
-            enrich_links_with_entities(&state, vec![], EnrichmentContext::FromSource, link_def)
-                .await
-                .expect("enrichment should succeed");
-        assert!(
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![link],
+            EnrichmentContext::DirectLink,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
+
+        assert!(enriched[0].source.is_none() && enriched[0].target.is_none());
+        let json = serde_json::to_value(&enriched[0]).unwrap();
+        let marker = json["_enrichment_error"].as_str().expect("marker present");
+        assert!(marker.contains("No entity fetcher registered for type: user"));
+        assert!(marker.contains("No entity fetcher registered for type: car"));
+    }
+
+    #[tokio::test]
+    async fn test_list_links_reports_enrichment_gaps_as_warnings() {
+        let mut state = create_test_state();
+        state.enrichment_fallback = EnrichmentFallback::Warnings;
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
+        let link_id = link.id;
+        state.link_service.create(link).await.unwrap();
+
+        let resp = list_links(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(("users".to_string(), user_id, "cars-owned".to_string())),
+            Query(crate::core::query::QueryParams::default()),
+        )
+        .await
+        .expect("a missing fetcher should not fail the list")
+        .0;
+
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(json["data"].as_array().unwrap().len(), 1);
+        assert!(json["data"][0].get("_enrichment_error").is_none());
+        assert_eq!(
+            json["warnings"],
+            serde_json::json!([{
+                "code": "ENRICHMENT_FAILED",
+                "message": "No entity fetcher registered for type: car",
+                "item": {"link_id": link_id, "entity_type": "car", "entity_id": car_id}
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_enrich_links_id_reference_fallback() {
+        let mut state = create_test_state();
+        state.enrichment_fallback = EnrichmentFallback::IdReference;
+        let car_id = Uuid::new_v4();
+        let link = crate::core::link::LinkEntity::new("owner", Uuid::new_v4(), car_id, None);
+
+        let link_def = &state.config.links[0];
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![link],
+            EnrichmentContext::FromSource,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
+
+        assert_eq!(
+            enriched[0].target,
+            Some(serde_json::json!({"id": car_id, "type": "car", "_enriched": false}))
+        );
+        assert!(enriched[0].enrichment_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_enrich_links_silent_fallback_has_no_marker() {
+        let state = create_test_state();
+        let link =
+            crate::core::link::LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None);
+
+        let link_def = &state.config.links[0];
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![link],
+            EnrichmentContext::FromSource,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
+
+        let json = serde_json::to_value(&enriched[0]).unwrap();
+        assert!(json.get("target").is_none());
+        assert!(json.get("_enrichment_error").is_none());
+    }
+
+    /// Fetcher that records how many fetches are in flight at once
+    struct SlowFetcher {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::core::EntityFetcher for SlowFetcher {
+        async fn fetch_as_json(&self, entity_id: &Uuid) -> anyhow::Result<serde_json::Value> {
+            use std::sync::atomic::Ordering;
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            // Later links finish first, so completion order differs from input order
+            let delay = 1 + (entity_id.as_bytes()[0] % 8) as u64;
+            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(serde_json::json!({ "id": entity_id }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enrich_links_default_batch_is_bounded_and_preserves_order() {
+        let fetcher = Arc::new(SlowFetcher {
+            in_flight: Default::default(),
+            peak: Default::default(),
+        });
+        let mut fetchers: HashMap<String, Arc<dyn crate::core::EntityFetcher>> = HashMap::new();
+        fetchers.insert("car".to_string(), fetcher.clone());
+
+        let mut state = create_test_state();
+        state.entity_fetchers = Arc::new(fetchers);
+
+        let links: Vec<_> = (0..20)
+            .map(|_| {
+                crate::core::link::LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None)
+            })
+            .collect();
+        let expected: Vec<Uuid> = links.iter().map(|l| l.target_id).collect();
+
+        let link_def = &state.config.links[0];
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            links,
+            EnrichmentContext::FromSource,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
+
+        let targets: Vec<Uuid> = enriched.iter().map(|l| l.target_id).collect();
+        assert_eq!(targets, expected, "links must keep their original order");
+        assert!(enriched.iter().all(|l| l.target.is_some()));
+
+        let peak = fetcher.peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(peak > 1, "fetches should overlap (peak {})", peak);
+        assert!(peak <= 10, "at most 10 fetches in flight (peak {})", peak);
+    }
+
+    /// Fetcher counting its batched fetches
+    #[derive(Default)]
+    struct BatchCountingFetcher {
+        batches: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::core::EntityFetcher for BatchCountingFetcher {
+        async fn fetch_as_json(&self, _entity_id: &Uuid) -> anyhow::Result<serde_json::Value> {
+            unreachable!("enrichment should only use fetch_many_as_json")
+        }
+
+        async fn fetch_many_as_json(
+            &self,
+            ids: &[Uuid],
+        ) -> anyhow::Result<HashMap<Uuid, serde_json::Value>> {
+            self.batches
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(ids
+                .iter()
+                .map(|id| (*id, serde_json::json!({ "id": id })))
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enrich_links_fetches_once_per_entity_type() {
+        let users = Arc::new(BatchCountingFetcher::default());
+        let cars = Arc::new(BatchCountingFetcher::default());
+        let mut fetchers: HashMap<String, Arc<dyn crate::core::EntityFetcher>> = HashMap::new();
+        fetchers.insert("user".to_string(), users.clone());
+        fetchers.insert("car".to_string(), cars.clone());
+
+        let mut state = create_test_state();
+        state.entity_fetchers = Arc::new(fetchers);
+
+        // 30 links between 3 owners and 30 cars
+        let owners: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let links: Vec<_> = (0..30)
+            .map(|i| {
+                crate::core::link::LinkEntity::new("owner", owners[i % 3], Uuid::new_v4(), None)
+            })
+            .collect();
+
+        let link_def = &state.config.links[0];
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            links,
+            EnrichmentContext::DirectLink,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
+
+        assert!(
+            enriched
+                .iter()
+                .all(|l| l.source.is_some() && l.target.is_some())
+        );
+        assert_eq!(users.batches.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(cars.batches.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_enrich_links_empty_input() {
+        let state = create_test_state();
+        let link_def = &state.config.links[0];
+        let enriched = enrich_links_with_entities(
+            &state,
+            None,
+            vec![],
+            EnrichmentContext::FromSource,
+            link_def,
+        )
+        .await
+        .expect("enrichment should succeed");
+        assert!(
             enriched.is_empty(),
             "enriching empty vec should return empty vec"
         );
     }
 
     // ------------------------------------------------------------------
-    // fetch_entity_by_type
+    // fetch_entities_by_type
     // ------------------------------------------------------------------
 
     #[tokio::test]
-    async fn test_fetch_entity_by_type_no_fetcher_registered() {
+    async fn test_fetch_entities_by_type_no_fetcher_registered() {
         let state = create_test_state();
-        let result = fetch_entity_by_type(&state, "unknown_type", &Uuid::new_v4()).await;
+        let result = fetch_entities_by_type(&state, "unknown_type", &[Uuid::new_v4()]).await;
         assert!(
             result.is_err(),
             "should error when no fetcher is registered"
         );
-        let err_msg = result.unwrap_err().to_string();
+        let err_msg = result.unwrap_err();
         assert!(
             err_msg.contains("No entity fetcher registered"),
             "error should mention missing fetcher, got: {}",
@@ -2000,7 +3575,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_fetch_entity_by_type_entity_not_found() {
+    async fn test_fetch_entities_by_type_entity_not_found() {
         let fetcher = Arc::new(MockEntityFetcher::new());
         let mut fetchers: HashMap<String, Arc<dyn crate::core::EntityFetcher>> = HashMap::new();
         fetchers.insert("car".to_string(), fetcher);
@@ -2008,12 +3583,18 @@ mod tests {
         let mut state = create_test_state();
         state.entity_fetchers = Arc::new(fetchers);
 
-        let result = fetch_entity_by_type(&state, "car", &Uuid::new_v4()).await;
-        assert!(result.is_err(), "should error when entity is not found");
+        let missing = Uuid::new_v4();
+        let result = fetch_entities_by_type(&state, "car", &[missing])
+            .await
+            .expect("should succeed");
+        assert!(
+            !result.contains_key(&missing),
+            "missing entities are left out"
+        );
     }
 
     #[tokio::test]
-    async fn test_fetch_entity_by_type_success() {
+    async fn test_fetch_entities_by_type_success() {
         let car_id = Uuid::new_v4();
         let fetcher = Arc::new(MockEntityFetcher::new());
         fetcher.insert(
@@ -2027,10 +3608,10 @@ mod tests {
         let mut state = create_test_state();
         state.entity_fetchers = Arc::new(fetchers);
 
-        let result = fetch_entity_by_type(&state, "car", &car_id)
+        let result = fetch_entities_by_type(&state, "car", &[car_id])
             .await
             .expect("should succeed");
-        assert_eq!(result["model"], "Audi");
+        assert_eq!(result[&car_id]["model"], "Audi");
     }
 
     // ------------------------------------------------------------------
@@ -2044,6 +3625,8 @@ mod tests {
 
         let result = list_links(
             State(state),
+            RequestAuth::default(),
+            None,
             Path(("users".to_string(), user_id, "cars-owned".to_string())),
             Query(crate::core::query::QueryParams::default()),
         )
@@ -2080,6 +3663,8 @@ mod tests {
 
         let result = list_links(
             State(state),
+            RequestAuth::default(),
+            None,
             Path(("users".to_string(), user_id, "cars-owned".to_string())),
             Query(crate::core::query::QueryParams::default()),
         )
@@ -2092,244 +3677,1827 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_list_links_reverse() {
+    async fn test_list_links_scoped_to_request_tenant() {
         let state = create_test_state();
+        let (tenant_a, tenant_b) = (Uuid::new_v4(), Uuid::new_v4());
         let user_id = Uuid::new_v4();
-        let car_id = Uuid::new_v4();
+        let car_a = Uuid::new_v4();
 
-        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
-        state
-            .link_service
-            .create(link)
+        for (tenant, car) in [(tenant_a, car_a), (tenant_b, Uuid::new_v4())] {
+            state
+                .link_service
+                .create(crate::core::link::LinkEntity::new_with_tenant(
+                    tenant, "owner", user_id, car, None,
+                ))
+                .await
+                .expect("create should succeed");
+        }
+
+        let list = |tenant| {
+            list_links(
+                State(state.clone()),
+                RequestAuth::default(),
+                Some(TenantContext::new(tenant)),
+                Path(("users".to_string(), user_id, "cars-owned".to_string())),
+                Query(crate::core::query::QueryParams::default()),
+            )
+        };
+
+        let resp = list(tenant_a).await.expect("handler should succeed").0;
+        assert_eq!(resp.data.len(), 1);
+        assert_eq!(resp.data[0].target_id, car_a);
+        assert_eq!(resp.pagination.total, 1);
+
+        let resp = list(Uuid::new_v4())
             .await
-            .expect("create should succeed");
+            .expect("handler should succeed")
+            .0;
+        assert!(resp.data.is_empty());
+    }
 
-        let result = list_links(
-            State(state),
-            Path(("cars".to_string(), car_id, "users-owners".to_string())),
-            Query(crate::core::query::QueryParams::default()),
+    /// Test state whose `user` and `car` fetchers hold `entities`, each
+    /// given as `(type, id, tenant)`
+    fn create_tenant_state(entities: &[(&str, Uuid, &TenantContext)]) -> AppState {
+        let mut fetchers: HashMap<String, Arc<dyn crate::core::EntityFetcher>> = HashMap::new();
+        for entity_type in ["user", "car"] {
+            let fetcher = MockEntityFetcher::new();
+            for (_, id, tenant) in entities.iter().filter(|(t, ..)| *t == entity_type) {
+                fetcher.insert(
+                    *id,
+                    serde_json::json!({ "id": id, "tenant_id": tenant.tenant_id }),
+                );
+            }
+            fetchers.insert(entity_type.to_string(), Arc::new(fetcher));
+        }
+        let mut state = create_test_state();
+        state.entity_fetchers = Arc::new(fetchers);
+        state
+    }
+
+    #[tokio::test]
+    async fn test_links_created_over_rest_belong_to_the_request_tenant() {
+        let (tenant_a, tenant_b) = (
+            TenantContext::new(Uuid::new_v4()),
+            TenantContext::new(Uuid::new_v4()),
+        );
+        let (user_id, car_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let state =
+            create_tenant_state(&[("user", user_id, &tenant_a), ("car", car_id, &tenant_a)]);
+        let route = || {
+            Path((
+                "users".to_string(),
+                user_id,
+                "cars-owned".to_string(),
+                car_id,
+            ))
+        };
+
+        let response = create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            Some(tenant_a),
+            route(),
+            Json(CreateLinkRequest { metadata: None }),
         )
         .await
-        .expect("handler should succeed");
+        .expect("create should succeed");
+        assert_eq!(response.status(), StatusCode::CREATED);
 
-        let resp = result.0;
+        let list = |tenant| {
+            list_links(
+                State(state.clone()),
+                RequestAuth::default(),
+                Some(tenant),
+                Path(("users".to_string(), user_id, "cars-owned".to_string())),
+                Query(crate::core::query::QueryParams::default()),
+            )
+        };
+        let resp = list(tenant_a).await.expect("handler should succeed").0;
         assert_eq!(resp.data.len(), 1);
-        assert_eq!(resp.direction, "Reverse");
+        assert_eq!(resp.data[0].target_id, car_id);
+        assert!(list(tenant_b).await.unwrap().0.data.is_empty());
+
+        // Other tenants cannot read or change the link by route either
+        let err = get_link_by_route(
+            State(state.clone()),
+            RequestAuth::default(),
+            Some(tenant_b),
+            route(),
+        )
+        .await
+        .expect_err("hidden from other tenants");
+        assert!(matches!(err, ExtractorError::LinkNotFound));
+        let err = delete_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            Some(tenant_b),
+            route(),
+        )
+        .await
+        .expect_err("hidden from other tenants");
+        assert!(matches!(err, ExtractorError::LinkNotFound));
+        get_link_by_route(
+            State(state),
+            RequestAuth::default(),
+            Some(tenant_a),
+            route(),
+        )
+        .await
+        .expect("visible to its tenant");
     }
 
     #[tokio::test]
-    async fn test_list_links_invalid_route() {
-        let state = create_test_state();
-        let result = list_links(
-            State(state),
+    async fn test_create_link_rejects_entities_of_another_tenant() {
+        let (tenant_a, tenant_b) = (
+            TenantContext::new(Uuid::new_v4()),
+            TenantContext::new(Uuid::new_v4()),
+        );
+        let (user_id, car_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let state =
+            create_tenant_state(&[("user", user_id, &tenant_a), ("car", car_id, &tenant_b)]);
+
+        let err = create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            Some(tenant_a),
             Path((
                 "users".to_string(),
-                Uuid::new_v4(),
-                "nonexistent".to_string(),
+                user_id,
+                "cars-owned".to_string(),
+                car_id,
             )),
-            Query(crate::core::query::QueryParams::default()),
+            Json(CreateLinkRequest { metadata: None }),
         )
-        .await;
+        .await
+        .expect_err("the car belongs to another tenant");
+        assert!(matches!(err, ExtractorError::EntityNotFound(id) if id == car_id));
 
-        assert!(result.is_err(), "should fail with invalid route");
+        let err = create_link_by_type(
+            State(state.clone()),
+            RequestAuth::default(),
+            Some(tenant_b),
+            Json(CreateLinkByTypeRequest {
+                link_type: "owner".to_string(),
+                source_id: user_id,
+                target_id: car_id,
+                source_type: None,
+                target_type: None,
+                metadata: None,
+            }),
+        )
+        .await
+        .expect_err("the user belongs to another tenant");
+        assert!(matches!(err, ExtractorError::EntityNotFound(id) if id == user_id));
+        assert_eq!(err.status(), StatusCode::NOT_FOUND);
+
+        let links = state
+            .link_service
+            .find_by_source(&user_id, None, None)
+            .await
+            .expect("find_by_source should succeed");
+        assert!(links.is_empty());
     }
 
     #[tokio::test]
-    async fn test_list_links_pagination() {
-        let state = create_test_state();
-        let user_id = Uuid::new_v4();
-
-        // Create 5 links
-        for _ in 0..5 {
-            let car_id = Uuid::new_v4();
-            let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
-            state
-                .link_service
-                .create(link)
-                .await
-                .expect("create should succeed");
-        }
-
-        let params = crate::core::query::QueryParams {
-            page: 1,
-            limit: 2,
-            filter: None,
-            sort: None,
-        };
+    async fn test_enrich_links_hides_entities_of_another_tenant() {
+        let (tenant_a, tenant_b) = (
+            TenantContext::new(Uuid::new_v4()),
+            TenantContext::new(Uuid::new_v4()),
+        );
+        let (user_id, car_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let state =
+            create_tenant_state(&[("user", user_id, &tenant_a), ("car", car_id, &tenant_b)]);
+        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
 
-        let result = list_links(
-            State(state),
-            Path(("users".to_string(), user_id, "cars-owned".to_string())),
-            Query(params),
+        let enriched = enrich_links_with_entities(
+            &state,
+            Some(&tenant_a),
+            vec![link],
+            EnrichmentContext::DirectLink,
+            &state.config.links[0],
         )
         .await
-        .expect("handler should succeed");
+        .expect("enrichment should succeed");
 
-        let resp = result.0;
-        assert_eq!(resp.data.len(), 2, "page 1 should have 2 items");
-        assert_eq!(resp.pagination.total, 5);
-        assert_eq!(resp.pagination.total_pages, 3);
-        assert!(resp.pagination.has_next);
-        assert!(!resp.pagination.has_prev);
+        assert_eq!(
+            enriched[0].source.as_ref().unwrap()["id"],
+            user_id.to_string()
+        );
+        assert!(enriched[0].target.is_none());
     }
 
     #[tokio::test]
-    async fn test_list_links_with_filter() {
+    async fn test_list_links_reverse() {
         let state = create_test_state();
         let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
 
-        // Create links: two "owner" links from the same user
-        let car1_id = Uuid::new_v4();
-        let car2_id = Uuid::new_v4();
-        let link1 = crate::core::link::LinkEntity::new("owner", user_id, car1_id, None);
-        let mut link2 = crate::core::link::LinkEntity::new("owner", user_id, car2_id, None);
-        link2.status = "inactive".to_string();
-
-        state
-            .link_service
-            .create(link1)
-            .await
-            .expect("create should succeed");
+        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
         state
             .link_service
-            .create(link2)
+            .create(link)
             .await
             .expect("create should succeed");
 
-        let params = crate::core::query::QueryParams {
-            page: 1,
-            limit: 20,
-            filter: Some(r#"{"status": "active"}"#.to_string()),
-            sort: None,
-        };
-
         let result = list_links(
             State(state),
-            Path(("users".to_string(), user_id, "cars-owned".to_string())),
-            Query(params),
+            RequestAuth::default(),
+            None,
+            Path(("cars".to_string(), car_id, "users-owners".to_string())),
+            Query(crate::core::query::QueryParams::default()),
         )
         .await
         .expect("handler should succeed");
 
         let resp = result.0;
-        assert_eq!(resp.data.len(), 1, "filter should return only active links");
-        assert_eq!(resp.data[0].status, "active");
-    }
+        assert_eq!(resp.data.len(), 1);
+        assert_eq!(resp.direction, "Reverse");
+    }
+
+    #[tokio::test]
+    async fn test_list_links_pages_in_the_same_order_with_projection() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let now = chrono::Utc::now();
+        // Two links share a timestamp, so the id decides between them
+        for age in [3, 1, 1, 2, 0] {
+            let mut link = crate::core::link::LinkEntity::new(
+                "owner",
+                user_id,
+                Uuid::new_v4(),
+                Some(serde_json::json!({ "rank": age })),
+            );
+            link.created_at = now - chrono::Duration::minutes(age);
+            state.link_service.create(link).await.unwrap();
+        }
+
+        let page_ids = |link_fields: Option<&str>, page| {
+            let state = state.clone();
+            let link_fields = link_fields.map(str::to_string);
+            async move {
+                let response = list_links(
+                    State(state),
+                    RequestAuth::default(),
+                    None,
+                    Path(("users".to_string(), user_id, "cars-owned".to_string())),
+                    Query(crate::core::query::QueryParams {
+                        page,
+                        limit: 2,
+                        link_fields,
+                        ..Default::default()
+                    }),
+                )
+                .await
+                .expect("handler should succeed")
+                .0;
+                response.data.iter().map(|l| l.id).collect::<Vec<_>>()
+            }
+        };
+
+        for page in 1..=3 {
+            assert_eq!(
+                page_ids(None, page).await,
+                page_ids(Some("metadata.rank"), page).await,
+                "page {} differs between the storage and in-memory paths",
+                page
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_links_self_link_in_both_directions() {
+        let config = Arc::new(
+            LinksConfig::from_yaml_str(
+                r#"
+entities:
+  - singular: employee
+    plural: employees
+links:
+  - link_type: manages
+    source_type: employee
+    target_type: employee
+    forward_route_name: reports
+    reverse_route_name: manager
+"#,
+            )
+            .unwrap(),
+        );
+        let state = AppState {
+            registry: Arc::new(LinkRouteRegistry::new(config.clone())),
+            config,
+            ..create_test_state()
+        };
+        let (boss, report) = (Uuid::new_v4(), Uuid::new_v4());
+        state
+            .link_service
+            .create(crate::core::link::LinkEntity::new(
+                "manages", boss, report, None,
+            ))
+            .await
+            .expect("create should succeed");
+
+        let list = |id, route: &str| {
+            list_links(
+                State(state.clone()),
+                RequestAuth::default(),
+                None,
+                Path(("employees".to_string(), id, route.to_string())),
+                Query(crate::core::query::QueryParams::default()),
+            )
+        };
+
+        let reports = list(boss, "reports").await.expect("reports").0;
+        assert_eq!(reports.direction, "Forward");
+        assert_eq!(reports.data.len(), 1);
+        assert_eq!(reports.data[0].target_id, report);
+
+        let manager = list(report, "manager").await.expect("manager").0;
+        assert_eq!(manager.direction, "Reverse");
+        assert_eq!(manager.data.len(), 1);
+        assert_eq!(manager.data[0].source_id, boss);
+
+        assert!(
+            list(boss, "manager")
+                .await
+                .expect("manager")
+                .0
+                .data
+                .is_empty()
+        );
+        assert!(
+            list(report, "reports")
+                .await
+                .expect("reports")
+                .0
+                .data
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_links_by_route_keeps_other_direction() {
+        let config = Arc::new(
+            LinksConfig::from_yaml_str(
+                r#"
+entities:
+  - singular: employee
+    plural: employees
+links:
+  - link_type: manages
+    source_type: employee
+    target_type: employee
+    forward_route_name: reports
+    reverse_route_name: manager
+"#,
+            )
+            .unwrap(),
+        );
+        let state = AppState {
+            registry: Arc::new(LinkRouteRegistry::new(config.clone())),
+            config,
+            ..create_test_state()
+        };
+        let (boss, director) = (Uuid::new_v4(), Uuid::new_v4());
+        for (source, target) in [
+            (boss, Uuid::new_v4()),
+            (boss, Uuid::new_v4()),
+            (director, boss),
+        ] {
+            state
+                .link_service
+                .create(crate::core::link::LinkEntity::new(
+                    "manages", source, target, None,
+                ))
+                .await
+                .expect("create should succeed");
+        }
+
+        let resp = delete_links_by_route(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path(("employees".to_string(), boss, "reports".to_string())),
+        )
+        .await
+        .expect("handler should succeed")
+        .0;
+        assert_eq!(resp.link_type, "manages");
+        assert_eq!(resp.deleted, 2);
+
+        let remaining = state.link_service.list().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(
+            (remaining[0].source_id, remaining[0].target_id),
+            (director, boss)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_links_invalid_route() {
+        let state = create_test_state();
+        let result = list_links(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                Uuid::new_v4(),
+                "nonexistent".to_string(),
+            )),
+            Query(crate::core::query::QueryParams::default()),
+        )
+        .await;
+
+        assert!(result.is_err(), "should fail with invalid route");
+    }
+
+    #[tokio::test]
+    async fn test_list_links_pagination() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+
+        // Create 5 links
+        for _ in 0..5 {
+            let car_id = Uuid::new_v4();
+            let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
+            state
+                .link_service
+                .create(link)
+                .await
+                .expect("create should succeed");
+        }
+
+        let params = crate::core::query::QueryParams {
+            page: 1,
+            limit: 2,
+            ..Default::default()
+        };
+
+        let result = list_links(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(("users".to_string(), user_id, "cars-owned".to_string())),
+            Query(params),
+        )
+        .await
+        .expect("handler should succeed");
+
+        let resp = result.0;
+        assert_eq!(resp.data.len(), 2, "page 1 should have 2 items");
+        assert_eq!(resp.pagination.total, 5);
+        assert_eq!(resp.pagination.total_pages, 3);
+        assert!(resp.pagination.has_next);
+        assert!(!resp.pagination.has_prev);
+    }
+
+    #[tokio::test]
+    async fn test_list_links_pages_in_storage() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        for _ in 0..25 {
+            let link = crate::core::link::LinkEntity::new("owner", user_id, Uuid::new_v4(), None);
+            state.link_service.create(link).await.unwrap();
+        }
+        let (expected, total) = state
+            .link_service
+            .find_by_source_page(&user_id, Some("owner"), None, 10, 10)
+            .await
+            .unwrap();
+        assert_eq!(total, 25);
+        assert_eq!(expected.len(), 10);
+
+        let params = crate::core::query::QueryParams {
+            page: 2,
+            limit: 10,
+            ..Default::default()
+        };
+        let resp = list_links(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(("users".to_string(), user_id, "cars-owned".to_string())),
+            Query(params),
+        )
+        .await
+        .expect("handler should succeed")
+        .0;
+
+        let ids: Vec<Uuid> = resp.data.iter().map(|l| l.id).collect();
+        assert_eq!(ids, expected.iter().map(|l| l.id).collect::<Vec<_>>());
+        assert_eq!(resp.pagination.total, 25);
+        assert_eq!(resp.pagination.total_pages, 3);
+        assert!(resp.pagination.has_next);
+        assert!(resp.pagination.has_prev);
+    }
+
+    #[tokio::test]
+    async fn test_list_links_with_filter() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+
+        // Create links: two "owner" links from the same user
+        let car1_id = Uuid::new_v4();
+        let car2_id = Uuid::new_v4();
+        let link1 = crate::core::link::LinkEntity::new("owner", user_id, car1_id, None);
+        let mut link2 = crate::core::link::LinkEntity::new("owner", user_id, car2_id, None);
+        link2.status = "inactive".to_string();
+
+        state
+            .link_service
+            .create(link1)
+            .await
+            .expect("create should succeed");
+        state
+            .link_service
+            .create(link2)
+            .await
+            .expect("create should succeed");
+
+        let params = crate::core::query::QueryParams {
+            page: 1,
+            limit: 20,
+            filter: Some(r#"{"status": "active"}"#.to_string()),
+            ..Default::default()
+        };
+
+        let result = list_links(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(("users".to_string(), user_id, "cars-owned".to_string())),
+            Query(params),
+        )
+        .await
+        .expect("handler should succeed");
+
+        let resp = result.0;
+        assert_eq!(resp.data.len(), 1, "filter should return only active links");
+        assert_eq!(resp.data[0].status, "active");
+    }
+
+    #[tokio::test]
+    async fn test_list_links_with_repeated_field_params() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+
+        for status in ["active", "pending", "archived"] {
+            let mut link =
+                crate::core::link::LinkEntity::new("owner", user_id, Uuid::new_v4(), None);
+            link.status = status.to_string();
+            state
+                .link_service
+                .create(link)
+                .await
+                .expect("create should succeed");
+        }
+
+        let mut params = crate::core::query::QueryParams::default();
+        params.field_filters.insert(
+            "status".to_string(),
+            vec!["active".to_string(), "pending".to_string()],
+        );
+
+        let result = list_links(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(("users".to_string(), user_id, "cars-owned".to_string())),
+            Query(params),
+        )
+        .await
+        .expect("handler should succeed");
+
+        let resp = result.0;
+        assert_eq!(resp.pagination.total, 2);
+        assert!(resp.data.iter().all(|l| l.status != "archived"));
+    }
+
+    #[tokio::test]
+    async fn test_list_links_with_metadata_projection() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+        let link = crate::core::link::LinkEntity::new(
+            "owner",
+            user_id,
+            car_id,
+            Some(serde_json::json!({"priority": 1, "notes": "a very long blob"})),
+        );
+        state
+            .link_service
+            .create(link)
+            .await
+            .expect("create should succeed");
+
+        let params = crate::core::query::QueryParams {
+            link_fields: Some("metadata.priority".to_string()),
+            ..Default::default()
+        };
+
+        let result = list_links(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(("users".to_string(), user_id, "cars-owned".to_string())),
+            Query(params),
+        )
+        .await
+        .expect("handler should succeed");
+
+        let resp = result.0;
+        assert_eq!(resp.data.len(), 1);
+        assert_eq!(
+            resp.data[0].metadata,
+            Some(serde_json::json!({"priority": 1}))
+        );
+        assert_eq!(resp.data[0].entity_type, "link");
+        assert_eq!(resp.data[0].target_id, car_id);
+    }
+
+    // ------------------------------------------------------------------
+    // Handler: delete_links_where
+    // ------------------------------------------------------------------
+
+    fn admin() -> RequestAuth {
+        RequestAuth(Some(AuthContext::Admin {
+            admin_id: Uuid::new_v4(),
+        }))
+    }
+
+    fn delete_params(filter: Option<&str>, confirm: bool) -> DeleteLinksParams {
+        DeleteLinksParams {
+            link_type: "owner".to_string(),
+            filter: filter.map(str::to_string),
+            confirm,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_links_where_deletes_matching_links() {
+        let state = create_test_state();
+        for expired in [true, true, false] {
+            let link = crate::core::link::LinkEntity::new(
+                "owner",
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Some(serde_json::json!({ "expired": expired })),
+            );
+            state.link_service.create(link).await.unwrap();
+        }
+
+        let resp = delete_links_where(
+            State(state.clone()),
+            admin(),
+            None,
+            Query(delete_params(Some(r#"{"metadata.expired":true}"#), true)),
+        )
+        .await
+        .expect("handler should succeed")
+        .0;
+
+        assert_eq!(resp.deleted, 2);
+        assert_eq!(state.link_service.list().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_links_where_keeps_other_tenants_links() {
+        let state = create_test_state();
+        let (tenant_a, tenant_b) = (Uuid::new_v4(), Uuid::new_v4());
+        for tenant_id in [tenant_a, tenant_a, tenant_b] {
+            let link = LinkEntity::new_with_tenant(
+                tenant_id,
+                "owner",
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                None,
+            );
+            state.link_service.create(link).await.unwrap();
+        }
+
+        let resp = delete_links_where(
+            State(state.clone()),
+            admin(),
+            Some(TenantContext::new(tenant_a)),
+            Query(delete_params(None, true)),
+        )
+        .await
+        .expect("handler should succeed")
+        .0;
+
+        assert_eq!(resp.deleted, 2);
+        let remaining = state.link_service.list().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].tenant_id, Some(tenant_b));
+    }
+
+    #[tokio::test]
+    async fn test_delete_links_where_requires_admin_and_confirmation() {
+        let state = create_test_state();
+        let user = RequestAuth(Some(AuthContext::User {
+            user_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            roles: vec![],
+        }));
+        let delete =
+            |auth, params| delete_links_where(State(state.clone()), auth, None, Query(params));
+
+        let err = delete(RequestAuth::default(), delete_params(None, true))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExtractorError::Unauthorized));
+
+        let err = delete(user, delete_params(None, true)).await.unwrap_err();
+        assert!(matches!(err, ExtractorError::Forbidden(_)));
+
+        let err = delete(admin(), delete_params(None, false))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExtractorError::JsonError(_)));
+
+        let err = delete(admin(), delete_params(Some(r#"{"created_at":"x"}"#), true))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExtractorError::JsonError(msg) if msg.contains("created_at")));
+    }
+
+    // ------------------------------------------------------------------
+    // Handler: list_relations
+    // ------------------------------------------------------------------
+
+    async fn seed_relations(state: &AppState, user_id: Uuid) {
+        // Two outgoing links and one incoming link
+        for _ in 0..2 {
+            let link = crate::core::link::LinkEntity::new("owner", user_id, Uuid::new_v4(), None);
+            state
+                .link_service
+                .create(link)
+                .await
+                .expect("create should succeed");
+        }
+        let link = crate::core::link::LinkEntity::new("driver", Uuid::new_v4(), user_id, None);
+        state
+            .link_service
+            .create(link)
+            .await
+            .expect("create should succeed");
+    }
+
+    fn relations_params(direction: Option<&str>) -> Result<Query<RelationsParams>, QueryRejection> {
+        let uri = match direction {
+            Some(direction) => format!("/relations?direction={}", direction),
+            None => "/relations".to_string(),
+        };
+        Query::try_from_uri(&uri.parse().unwrap())
+    }
+
+    fn page_params(limit: usize) -> Query<QueryParams> {
+        Query(QueryParams {
+            limit,
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_relations_defaults_to_both() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        seed_relations(&state, user_id).await;
+
+        let resp = list_relations(
+            State(state),
+            None,
+            Path(("users".to_string(), user_id)),
+            relations_params(None),
+            page_params(20),
+        )
+        .await
+        .expect("handler should succeed")
+        .0;
+
+        assert_eq!(resp.direction, RelationDirection::Both);
+        assert_eq!(resp.pagination.total, 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_relations_by_direction_and_paginated() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        seed_relations(&state, user_id).await;
+
+        let outgoing = list_relations(
+            State(state.clone()),
+            None,
+            Path(("users".to_string(), user_id)),
+            relations_params(Some("out")),
+            page_params(1),
+        )
+        .await
+        .expect("handler should succeed")
+        .0;
+        assert_eq!(outgoing.pagination.total, 2);
+        assert_eq!(outgoing.data.len(), 1);
+        assert!(outgoing.pagination.has_next);
+        assert_eq!(outgoing.data[0].source_id, user_id);
+
+        let incoming = list_relations(
+            State(state),
+            None,
+            Path(("users".to_string(), user_id)),
+            relations_params(Some("in")),
+            page_params(20),
+        )
+        .await
+        .expect("handler should succeed")
+        .0;
+        assert_eq!(incoming.pagination.total, 1);
+        assert_eq!(incoming.data[0].link_type, "driver");
+    }
+
+    #[tokio::test]
+    async fn test_list_relations_rejects_bad_direction_and_unknown_entity() {
+        let state = create_test_state();
+
+        let bad_direction = list_relations(
+            State(state.clone()),
+            None,
+            Path(("users".to_string(), Uuid::new_v4())),
+            relations_params(Some("sideways")),
+            page_params(20),
+        )
+        .await;
+        assert!(matches!(bad_direction, Err(ExtractorError::JsonError(_))));
+
+        let unknown = list_relations(
+            State(state),
+            None,
+            Path(("widgets".to_string(), Uuid::new_v4())),
+            relations_params(None),
+            page_params(20),
+        )
+        .await;
+        assert!(matches!(unknown, Err(ExtractorError::RouteNotFound(_))));
+    }
+
+    // ------------------------------------------------------------------
+    // Handler: get_link
+    // ------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_get_link_not_found() {
+        let state = create_test_state();
+        let result = get_link(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(Uuid::new_v4()),
+        )
+        .await;
+        assert!(result.is_err(), "should fail for nonexistent link");
+    }
+
+    #[tokio::test]
+    async fn test_get_link_success() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
+        let link_id = link.id;
+
+        state
+            .link_service
+            .create(link)
+            .await
+            .expect("create should succeed");
+
+        let result = get_link(State(state), RequestAuth::default(), None, Path(link_id)).await;
+        assert!(result.is_ok(), "should succeed for existing link");
+    }
+
+    /// Fetcher withholding `password_hash` from every caller
+    struct RedactingFetcher(MockEntityFetcher);
+
+    #[async_trait::async_trait]
+    impl crate::core::EntityFetcher for RedactingFetcher {
+        async fn fetch_as_json(&self, entity_id: &Uuid) -> anyhow::Result<serde_json::Value> {
+            self.0.fetch_as_json(entity_id).await
+        }
+
+        fn redacted_fields(&self, _context: &AuthContext) -> &'static [&'static str] {
+            &["password_hash"]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_link_redacts_embedded_entities() {
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+        let user_fetcher = RedactingFetcher(MockEntityFetcher::new());
+        user_fetcher.0.insert(
+            user_id,
+            serde_json::json!({ "id": user_id.to_string(), "name": "Alice", "password_hash": "x" }),
+        );
+        let car_fetcher = MockEntityFetcher::new();
+        car_fetcher.insert(car_id, serde_json::json!({ "id": car_id.to_string() }));
+
+        let mut fetchers: HashMap<String, Arc<dyn crate::core::EntityFetcher>> = HashMap::new();
+        fetchers.insert("user".to_string(), Arc::new(user_fetcher));
+        fetchers.insert("car".to_string(), Arc::new(car_fetcher));
+        let mut state = create_test_state();
+        state.entity_fetchers = Arc::new(fetchers);
+
+        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
+        let link_id = link.id;
+        state.link_service.create(link).await.unwrap();
+
+        let response = get_link(State(state), RequestAuth::default(), None, Path(link_id))
+            .await
+            .expect("should succeed for existing link");
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["source"]["name"], "Alice");
+        assert!(body["source"].get("password_hash").is_none());
+        assert!(body["target"].is_object());
+    }
+
+    // ------------------------------------------------------------------
+    // Handler: create_link
+    // ------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_create_link_success() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+
+        let result = create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                user_id,
+                "cars-owned".to_string(),
+                car_id,
+            )),
+            Json(CreateLinkRequest { metadata: None }),
+        )
+        .await;
+
+        assert!(result.is_ok(), "create_link should succeed");
+        let response = result.expect("should be ok");
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        // Verify the link exists in the service
+        let links = state
+            .link_service
+            .find_by_source(&user_id, Some("owner"), None)
+            .await
+            .expect("find_by_source should succeed");
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].source_id, user_id);
+        assert_eq!(links[0].target_id, car_id);
+    }
+
+    #[tokio::test]
+    async fn test_create_link_records_entity_types() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+
+        create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                user_id,
+                "cars-owned".to_string(),
+                car_id,
+            )),
+            Json(CreateLinkRequest { metadata: None }),
+        )
+        .await
+        .expect("create_link should succeed");
+
+        let links = state
+            .link_service
+            .find_by_source(&user_id, Some("owner"), Some("car"))
+            .await
+            .expect("find_by_source should succeed");
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].source_type.as_deref(), Some("user"));
+        assert_eq!(links[0].target_type.as_deref(), Some("car"));
+
+        let mismatched = state
+            .link_service
+            .find_by_source(&user_id, Some("owner"), Some("house"))
+            .await
+            .expect("find_by_source should succeed");
+        assert!(mismatched.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_link_with_metadata() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+
+        let metadata = serde_json::json!({ "primary_owner": true });
+
+        let result = create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                user_id,
+                "cars-owned".to_string(),
+                car_id,
+            )),
+            Json(CreateLinkRequest {
+                metadata: Some(metadata.clone()),
+            }),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let links = state
+            .link_service
+            .find_by_source(&user_id, Some("owner"), None)
+            .await
+            .expect("find_by_source should succeed");
+        assert_eq!(links[0].metadata, Some(metadata));
+    }
+
+    #[tokio::test]
+    async fn test_create_link_enforces_direction_specific_required_fields() {
+        let mut state = create_test_state();
+        let mut config = (*state.config).clone();
+        config.links[0].required_fields_forward = Some(vec!["since".to_string()]);
+        config.links[0].required_fields_reverse = Some(vec![]);
+        state.config = Arc::new(config);
+        state.registry = Arc::new(LinkRouteRegistry::new(state.config.clone()));
+
+        // Forward: users/{id}/cars-owned/{id} requires "since"
+        let missing = create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                Uuid::new_v4(),
+                "cars-owned".to_string(),
+                Uuid::new_v4(),
+            )),
+            Json(CreateLinkRequest { metadata: None }),
+        )
+        .await;
+        match missing {
+            Err(ExtractorError::Validation(err)) => {
+                assert_eq!(err.field_errors()[0].field, "/metadata/since")
+            }
+            other => panic!("expected missing metadata error, got {:?}", other.is_ok()),
+        }
+
+        let present = create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                Uuid::new_v4(),
+                "cars-owned".to_string(),
+                Uuid::new_v4(),
+            )),
+            Json(CreateLinkRequest {
+                metadata: Some(serde_json::json!({ "since": "2024" })),
+            }),
+        )
+        .await;
+        assert!(present.is_ok());
+
+        // Reverse: cars/{id}/users-owners/{id} has no required fields
+        let reverse = create_link(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path((
+                "cars".to_string(),
+                Uuid::new_v4(),
+                "users-owners".to_string(),
+                Uuid::new_v4(),
+            )),
+            Json(CreateLinkRequest { metadata: None }),
+        )
+        .await;
+        assert!(reverse.is_ok());
+    }
+
+    /// Test state with a user -> company "worker" link that requires a role
+    fn create_worker_test_state() -> AppState {
+        let mut state = create_test_state();
+        let mut config = (*state.config).clone();
+        config.entities.push(EntityConfig {
+            singular: "company".to_string(),
+            plural: "companies".to_string(),
+            auth: crate::config::EntityAuthConfig::default(),
+            id_policy: Default::default(),
+            fields: Default::default(),
+            min_update_interval: None,
+        });
+        let mut worker = config.links[0].clone();
+        worker.link_type = "worker".to_string();
+        worker.target_type = "company".to_string();
+        worker.forward_route_name = "companies-work".to_string();
+        worker.reverse_route_name = "users-workers".to_string();
+        worker.required_fields = Some(vec!["role".to_string()]);
+        config.links.push(worker);
+        state.config = Arc::new(config);
+        state.registry = Arc::new(LinkRouteRegistry::new(state.config.clone()));
+        state
+    }
+
+    async fn create_worker_link(
+        state: &AppState,
+        metadata: Option<Value>,
+    ) -> Result<Response, ExtractorError> {
+        create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                Uuid::new_v4(),
+                "companies-work".to_string(),
+                Uuid::new_v4(),
+            )),
+            Json(CreateLinkRequest { metadata }),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_create_worker_link_requires_role() {
+        let state = create_worker_test_state();
+
+        for metadata in [
+            None,
+            Some(serde_json::json!({ "since": "2024" })),
+            Some(serde_json::json!({ "role": "" })),
+        ] {
+            let err = create_worker_link(&state, metadata)
+                .await
+                .expect_err("a worker link without a role should be rejected");
+            match &err {
+                ExtractorError::Validation(e) => {
+                    assert_eq!(e.field_errors().len(), 1);
+                    assert_eq!(e.field_errors()[0].field, "/metadata/role");
+                }
+                other => panic!("expected a validation error, got {other}"),
+            }
+
+            let response = err.into_response();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            let body = axum::body::to_bytes(response.into_body(), 1024)
+                .await
+                .unwrap();
+            let body: Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["errors"][0]["field"], "/metadata/role");
+        }
+
+        let response = create_worker_link(&state, Some(serde_json::json!({ "role": "CTO" })))
+            .await
+            .expect("a worker link with a role should be created");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    /// Test state with a user -> invoice "payment" link whose metadata needs a numeric amount
+    #[cfg(feature = "json-schema")]
+    fn create_payment_test_state() -> AppState {
+        let mut state = create_test_state();
+        let mut config = (*state.config).clone();
+        config.entities.push(EntityConfig {
+            singular: "invoice".to_string(),
+            plural: "invoices".to_string(),
+            auth: crate::config::EntityAuthConfig::default(),
+            id_policy: Default::default(),
+            fields: Default::default(),
+            min_update_interval: None,
+        });
+        let mut payment = config.links[0].clone();
+        payment.link_type = "payment".to_string();
+        payment.target_type = "invoice".to_string();
+        payment.forward_route_name = "invoices-paid".to_string();
+        payment.reverse_route_name = "users-payers".to_string();
+        payment.metadata_schema = Some(serde_json::json!({
+            "type": "object",
+            "required": ["amount"],
+            "properties": { "amount": { "type": "number" } }
+        }));
+        config.links.push(payment);
+        config.validate().expect("payment schema should compile");
+        state.config = Arc::new(config);
+        state.registry = Arc::new(LinkRouteRegistry::new(state.config.clone()));
+        state
+    }
 
-    // ------------------------------------------------------------------
-    // Handler: get_link
-    // ------------------------------------------------------------------
+    #[cfg(feature = "json-schema")]
+    #[tokio::test]
+    async fn test_payment_link_metadata_is_checked_against_its_schema() {
+        let state = create_payment_test_state();
+        let (user_id, invoice_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let path = || {
+            Path((
+                "users".to_string(),
+                user_id,
+                "invoices-paid".to_string(),
+                invoice_id,
+            ))
+        };
+
+        for (metadata, message) in [
+            (None, "required"),
+            (
+                Some(serde_json::json!({ "amount": "12.50" })),
+                "not of type",
+            ),
+        ] {
+            let err = create_link(
+                State(state.clone()),
+                RequestAuth::default(),
+                None,
+                path(),
+                Json(CreateLinkRequest { metadata }),
+            )
+            .await
+            .expect_err("invalid payment metadata should be rejected");
+            match &err {
+                ExtractorError::Validation(e) => {
+                    assert_eq!(e.field_errors().len(), 1);
+                    assert_eq!(e.field_errors()[0].field, "/metadata/amount");
+                    assert!(e.field_errors()[0].message.contains(message));
+                }
+                other => panic!("expected a validation error, got {other}"),
+            }
+            assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+        }
+
+        let response = create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            path(),
+            Json(CreateLinkRequest {
+                metadata: Some(serde_json::json!({ "amount": 12.5 })),
+            }),
+        )
+        .await
+        .expect("a payment with a numeric amount should be created");
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        // Updates replace the metadata, so they are checked too
+        let err = update_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            path(),
+            Json(UpdateLinkRequest {
+                metadata: Some(serde_json::json!({ "amount": true })),
+                merge: false,
+            }),
+        )
+        .await
+        .expect_err("an update with a boolean amount should be rejected");
+        assert!(matches!(err, ExtractorError::Validation(_)));
+        let response = update_link(
+            State(state),
+            RequestAuth::default(),
+            None,
+            path(),
+            Json(UpdateLinkRequest {
+                metadata: Some(serde_json::json!({ "amount": 20 })),
+                merge: false,
+            }),
+        )
+        .await
+        .expect("an update with a numeric amount should succeed");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 
     #[tokio::test]
-    async fn test_get_link_not_found() {
-        let state = create_test_state();
-        let result = get_link(State(state), Path(Uuid::new_v4())).await;
-        assert!(result.is_err(), "should fail for nonexistent link");
+    async fn test_create_linked_entity_requires_role() {
+        let mut state = create_worker_test_state();
+        let creator = Arc::new(CountingEntityCreator::default());
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("company".to_string(), creator.clone());
+        state.entity_creators = Arc::new(creators);
+
+        let create = |metadata| {
+            create_linked_entity(
+                State(state.clone()),
+                RequestAuth::default(),
+                None,
+                Path((
+                    "users".to_string(),
+                    Uuid::new_v4(),
+                    "companies-work".to_string(),
+                )),
+                Json(CreateLinkedEntityRequest {
+                    entity: serde_json::json!({ "name": "Acme" }),
+                    metadata,
+                }),
+            )
+        };
+
+        let missing = create(None).await;
+        assert!(matches!(missing, Err(ExtractorError::Validation(_))));
+        assert_eq!(creator.created.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        let response = create(Some(serde_json::json!({ "role": "CTO" })))
+            .await
+            .expect("a worker with a role should be created");
+        assert_eq!(response.status(), StatusCode::CREATED);
     }
 
     #[tokio::test]
-    async fn test_get_link_success() {
+    async fn test_create_link_invalid_route() {
         let state = create_test_state();
-        let user_id = Uuid::new_v4();
-        let car_id = Uuid::new_v4();
-        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
-        let link_id = link.id;
+        let result = create_link(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                Uuid::new_v4(),
+                "nonexistent".to_string(),
+                Uuid::new_v4(),
+            )),
+            Json(CreateLinkRequest { metadata: None }),
+        )
+        .await;
 
+        assert!(result.is_err(), "should fail with invalid route");
+    }
+
+    /// Test state where a car has a single owner but any number of drivers
+    fn create_cardinality_test_state() -> AppState {
+        let mut state = create_test_state();
+        let mut config = (*state.config).clone();
+        config.links[0].cardinality = Some(crate::core::link::LinkCardinality::OneToMany);
+        let mut driver = config.links[0].clone();
+        driver.link_type = "driver".to_string();
+        driver.forward_route_name = "cars-driven".to_string();
+        driver.reverse_route_name = "users-drivers".to_string();
+        driver.cardinality = None;
+        config.links.push(driver);
+        state.config = Arc::new(config);
+        state.registry = Arc::new(LinkRouteRegistry::new(state.config.clone()));
         state
+    }
+
+    async fn link_user_to_car(
+        state: &AppState,
+        user_id: Uuid,
+        route: &str,
+        car_id: Uuid,
+    ) -> Result<Response, ExtractorError> {
+        create_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path(("users".to_string(), user_id, route.to_string(), car_id)),
+            Json(CreateLinkRequest { metadata: None }),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_create_link_rejects_second_owner_of_car() {
+        let state = create_cardinality_test_state();
+        let car_id = Uuid::new_v4();
+
+        link_user_to_car(&state, Uuid::new_v4(), "cars-owned", car_id)
+            .await
+            .expect("first owner should be accepted");
+
+        let err = link_user_to_car(&state, Uuid::new_v4(), "cars-owned", car_id)
+            .await
+            .expect_err("second owner should be rejected");
+        assert!(matches!(err, ExtractorError::Conflict(_)));
+        assert_eq!(err.into_response().status(), StatusCode::CONFLICT);
+
+        // One user may still own several cars
+        let owner = state
             .link_service
-            .create(link)
+            .find_by_target(&car_id, Some("owner"), None)
             .await
-            .expect("create should succeed");
+            .unwrap()[0]
+            .source_id;
+        link_user_to_car(&state, owner, "cars-owned", Uuid::new_v4())
+            .await
+            .expect("one_to_many leaves the source side unbounded");
+    }
 
-        let result = get_link(State(state), Path(link_id)).await;
-        assert!(result.is_ok(), "should succeed for existing link");
+    #[tokio::test]
+    async fn test_create_link_without_cardinality_is_unbounded() {
+        let state = create_cardinality_test_state();
+        let car_id = Uuid::new_v4();
+
+        for _ in 0..2 {
+            let response = link_user_to_car(&state, Uuid::new_v4(), "cars-driven", car_id)
+                .await
+                .expect("drivers are many_to_many");
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let drivers = state
+            .link_service
+            .find_by_target(&car_id, Some("driver"), None)
+            .await
+            .unwrap();
+        assert_eq!(drivers.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_link_rejects_duplicate_link() {
+        let state = create_cardinality_test_state();
+        let (user_id, car_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        link_user_to_car(&state, user_id, "cars-driven", car_id)
+            .await
+            .expect("first link should be accepted");
+        let err = link_user_to_car(&state, user_id, "cars-driven", car_id)
+            .await
+            .expect_err("an identical link should be rejected");
+        assert_eq!(err.into_response().status(), StatusCode::CONFLICT);
+
+        let drivers = state
+            .link_service
+            .find_by_target(&car_id, Some("driver"), None)
+            .await
+            .unwrap();
+        assert_eq!(drivers.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_link_allows_duplicates_when_opted_out() {
+        let mut state = create_cardinality_test_state();
+        state.allow_duplicate_links = true;
+        let (user_id, car_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        for _ in 0..2 {
+            let response = link_user_to_car(&state, user_id, "cars-driven", car_id)
+                .await
+                .expect("duplicates are allowed");
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let drivers = state
+            .link_service
+            .find_by_target(&car_id, Some("driver"), None)
+            .await
+            .unwrap();
+        assert_eq!(drivers.len(), 2);
     }
 
     // ------------------------------------------------------------------
-    // Handler: create_link
+    // Handler: create_link_by_type
     // ------------------------------------------------------------------
 
+    fn link_by_type(link_type: &str, metadata: Option<Value>) -> Json<CreateLinkByTypeRequest> {
+        Json(CreateLinkByTypeRequest {
+            link_type: link_type.to_string(),
+            source_id: Uuid::new_v4(),
+            target_id: Uuid::new_v4(),
+            source_type: None,
+            target_type: None,
+            metadata,
+        })
+    }
+
+    fn field_error_of(result: Result<Response, ExtractorError>) -> String {
+        match result {
+            Err(ExtractorError::Validation(err)) => err.field_errors()[0].field.clone(),
+            other => panic!("expected a validation error, got {:?}", other.is_ok()),
+        }
+    }
+
     #[tokio::test]
-    async fn test_create_link_success() {
+    async fn test_create_link_by_type_success() {
         let state = create_test_state();
-        let user_id = Uuid::new_v4();
-        let car_id = Uuid::new_v4();
+        let Json(payload) = link_by_type("owner", None);
+        let (user_id, car_id) = (payload.source_id, payload.target_id);
 
-        let result = create_link(
+        let response = create_link_by_type(
             State(state.clone()),
-            Path((
-                "users".to_string(),
-                user_id,
-                "cars-owned".to_string(),
-                car_id,
-            )),
-            Json(CreateLinkRequest { metadata: None }),
+            RequestAuth::default(),
+            None,
+            Json(payload),
         )
-        .await;
-
-        assert!(result.is_ok(), "create_link should succeed");
-        let response = result.expect("should be ok");
+        .await
+        .expect("create_link_by_type should succeed");
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        // Verify the link exists in the service
         let links = state
             .link_service
-            .find_by_source(&user_id, Some("owner"), None)
+            .find_by_source(&user_id, Some("owner"), Some("car"))
             .await
-            .expect("find_by_source should succeed");
+            .unwrap();
         assert_eq!(links.len(), 1);
-        assert_eq!(links[0].source_id, user_id);
         assert_eq!(links[0].target_id, car_id);
+        assert_eq!(links[0].source_type.as_deref(), Some("user"));
     }
 
     #[tokio::test]
-    async fn test_create_link_with_metadata() {
-        let state = create_test_state();
-        let user_id = Uuid::new_v4();
+    async fn test_create_link_by_type_runs_route_checks() {
+        let mut state = create_cardinality_test_state();
+        let mut config = (*state.config).clone();
+        config.links[1].required_fields = Some(vec!["since".to_string()]);
+        config.validation_rules = Some(HashMap::from([(
+            "owner".to_string(),
+            vec![crate::config::ValidationRule {
+                source: "user".to_string(),
+                targets: vec!["truck".to_string()],
+            }],
+        )]));
+        state.config = Arc::new(config);
+
+        // Unknown link type
+        let unknown = create_link_by_type(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            link_by_type("rider", None),
+        )
+        .await;
+        assert_eq!(field_error_of(unknown), "/link_type");
+
+        // Rejected by validation rules
+        let denied = create_link_by_type(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            link_by_type("owner", None),
+        )
+        .await;
+        assert_eq!(field_error_of(denied), "/link_type");
+
+        // Required metadata
+        let missing = create_link_by_type(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            link_by_type("driver", None),
+        )
+        .await;
+        assert_eq!(field_error_of(missing), "/metadata/since");
+
+        let created = create_link_by_type(
+            State(state),
+            RequestAuth::default(),
+            None,
+            link_by_type("driver", Some(serde_json::json!({ "since": "2024" }))),
+        )
+        .await
+        .expect("driver link with metadata should be created");
+        assert_eq!(created.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_create_link_by_type_enforces_cardinality() {
+        let state = create_cardinality_test_state();
         let car_id = Uuid::new_v4();
+        let owner_of_car = || {
+            let Json(mut payload) = link_by_type("owner", None);
+            payload.target_id = car_id;
+            Json(payload)
+        };
 
-        let metadata = serde_json::json!({ "primary_owner": true });
+        create_link_by_type(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            owner_of_car(),
+        )
+        .await
+        .expect("first owner should be accepted");
+        let err = create_link_by_type(State(state), RequestAuth::default(), None, owner_of_car())
+            .await
+            .expect_err("second owner should be rejected");
+        assert!(matches!(err, ExtractorError::Conflict(_)));
+    }
 
-        let result = create_link(
+    // ------------------------------------------------------------------
+    // Link auth policies
+    // ------------------------------------------------------------------
+
+    /// Reads an admin from `x-admin-id`, else the user from `x-user-id`/`x-roles`;
+    /// users own what `owner_id` owns
+    struct HeaderAuthProvider {
+        owner_id: Uuid,
+    }
+
+    #[async_trait::async_trait]
+    impl AuthProvider for HeaderAuthProvider {
+        async fn extract_context(&self, parts: &Parts) -> anyhow::Result<AuthContext> {
+            let header = |name| parts.headers.get(name).and_then(|v| v.to_str().ok());
+            if let Some(admin_id) = header("x-admin-id") {
+                return Ok(AuthContext::Admin {
+                    admin_id: admin_id.parse()?,
+                });
+            }
+            let Some(user_id) = header("x-user-id") else {
+                return Ok(AuthContext::Anonymous);
+            };
+            Ok(AuthContext::User {
+                user_id: user_id.parse()?,
+                tenant_id: Uuid::nil(),
+                roles: header("x-roles")
+                    .unwrap_or_default()
+                    .split(',')
+                    .map(str::to_string)
+                    .collect(),
+            })
+        }
+
+        async fn is_owner(&self, user_id: &Uuid, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(*user_id == self.owner_id)
+        }
+
+        async fn has_role(&self, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+    }
+
+    /// Test state where creating an owner link needs `role:admin` and deleting it `owner`
+    fn create_auth_test_state(owner_id: Uuid) -> AppState {
+        use crate::core::link::LinkAuthConfig;
+
+        let mut state = create_test_state();
+        let mut config = (*state.config).clone();
+        config.links[0].auth = Some(LinkAuthConfig {
+            create: "role:admin".to_string(),
+            delete: "owner".to_string(),
+            ..Default::default()
+        });
+        state.config = Arc::new(config);
+        state.registry = Arc::new(LinkRouteRegistry::new(state.config.clone()));
+        state.auth_provider = Some(Arc::new(HeaderAuthProvider { owner_id }));
+        state
+    }
+
+    fn user_auth(user_id: Uuid, roles: &[&str]) -> RequestAuth {
+        RequestAuth(Some(AuthContext::User {
+            user_id,
+            tenant_id: Uuid::nil(),
+            roles: roles.iter().map(|r| r.to_string()).collect(),
+        }))
+    }
+
+    async fn create_owner_link(
+        state: &AppState,
+        auth: RequestAuth,
+        user_id: Uuid,
+        car_id: Uuid,
+    ) -> Result<Response, ExtractorError> {
+        create_link(
             State(state.clone()),
+            auth,
+            None,
             Path((
                 "users".to_string(),
                 user_id,
                 "cars-owned".to_string(),
                 car_id,
             )),
-            Json(CreateLinkRequest {
-                metadata: Some(metadata.clone()),
-            }),
+            Json(CreateLinkRequest { metadata: None }),
         )
-        .await;
+        .await
+    }
 
-        assert!(result.is_ok());
+    #[tokio::test]
+    async fn test_create_link_requires_admin_role() {
+        let state = create_auth_test_state(Uuid::new_v4());
+        let (user_id, car_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let err = create_owner_link(&state, user_auth(user_id, &["user"]), user_id, car_id)
+            .await
+            .expect_err("non-admins may not create owner links");
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+
+        let err = create_owner_link(
+            &state,
+            RequestAuth(Some(AuthContext::Anonymous)),
+            user_id,
+            car_id,
+        )
+        .await
+        .expect_err("anonymous callers may not create owner links");
+        assert_eq!(err.into_response().status(), StatusCode::UNAUTHORIZED);
 
         let links = state
             .link_service
-            .find_by_source(&user_id, Some("owner"), None)
+            .find_by_source(&user_id, None, None)
             .await
-            .expect("find_by_source should succeed");
-        assert_eq!(links[0].metadata, Some(metadata));
+            .unwrap();
+        assert!(
+            links.is_empty(),
+            "rejected before reaching the link service"
+        );
+
+        let response = create_owner_link(&state, user_auth(user_id, &["admin"]), user_id, car_id)
+            .await
+            .expect("admins may create owner links");
+        assert_eq!(response.status(), StatusCode::CREATED);
     }
 
     #[tokio::test]
-    async fn test_create_link_invalid_route() {
-        let state = create_test_state();
-        let result = create_link(
-            State(state),
-            Path((
-                "users".to_string(),
-                Uuid::new_v4(),
-                "nonexistent".to_string(),
-                Uuid::new_v4(),
-            )),
-            Json(CreateLinkRequest { metadata: None }),
-        )
-        .await;
+    async fn test_delete_link_requires_ownership() {
+        let owner_id = Uuid::new_v4();
+        let state = create_auth_test_state(owner_id);
+        let (user_id, car_id) = (Uuid::new_v4(), Uuid::new_v4());
+        create_owner_link(&state, user_auth(user_id, &["admin"]), user_id, car_id)
+            .await
+            .unwrap();
+
+        let delete = |auth| {
+            delete_link(
+                State(state.clone()),
+                auth,
+                None,
+                Path((
+                    "users".to_string(),
+                    user_id,
+                    "cars-owned".to_string(),
+                    car_id,
+                )),
+            )
+        };
+
+        let err = delete(user_auth(Uuid::new_v4(), &["admin"]))
+            .await
+            .expect_err("only the owner may delete");
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+
+        let response = delete(user_auth(owner_id, &[])).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_link_routes_extract_auth_from_headers() {
+        use crate::server::router::build_link_routes;
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let state = create_auth_test_state(Uuid::new_v4());
+        let uri = format!("/users/{}/cars-owned/{}", Uuid::new_v4(), Uuid::new_v4());
+        let create = |roles: &'static str| {
+            Request::post(&uri)
+                .header("content-type", "application/json")
+                .header("x-user-id", Uuid::new_v4().to_string())
+                .header("x-roles", roles)
+                .body(Body::from("{}"))
+                .unwrap()
+        };
+
+        let app = build_link_routes(state);
+        let response = app.clone().oneshot(create("user")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
 
-        assert!(result.is_err(), "should fail with invalid route");
+        let response = app.oneshot(create("user,admin")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_delete_links_route_extracts_admin_from_headers() {
+        use crate::server::router::build_link_routes;
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let state = create_auth_test_state(Uuid::new_v4());
+        let link = LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None);
+        state.link_service.create(link).await.unwrap();
+        let delete = |header: &'static str| {
+            Request::delete("/links?type=owner&confirm=true")
+                .header(header, Uuid::new_v4().to_string())
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let app = build_link_routes(state.clone());
+        let response = app.clone().oneshot(delete("x-user-id")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = app.oneshot(delete("x-admin-id")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(state.link_service.list().await.unwrap().is_empty());
     }
 
     // ------------------------------------------------------------------
@@ -2353,6 +5521,8 @@ mod tests {
         // Delete it
         let result = delete_link(
             State(state.clone()),
+            RequestAuth::default(),
+            None,
             Path((
                 "users".to_string(),
                 user_id,
@@ -2375,11 +5545,48 @@ mod tests {
         assert!(links.is_empty(), "link should be deleted");
     }
 
+    #[tokio::test]
+    async fn test_delete_link_reverse_route() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
+        state
+            .link_service
+            .create(link)
+            .await
+            .expect("create should succeed");
+
+        let response = delete_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path((
+                "cars".to_string(),
+                car_id,
+                "users-owners".to_string(),
+                user_id,
+            )),
+        )
+        .await
+        .expect("delete through the reverse route should succeed");
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let links = state
+            .link_service
+            .find_by_source(&user_id, Some("owner"), None)
+            .await
+            .expect("find_by_source should succeed");
+        assert!(links.is_empty(), "link should be deleted");
+    }
+
     #[tokio::test]
     async fn test_delete_link_not_found() {
         let state = create_test_state();
         let result = delete_link(
             State(state),
+            RequestAuth::default(),
+            None,
             Path((
                 "users".to_string(),
                 Uuid::new_v4(),
@@ -2410,6 +5617,8 @@ mod tests {
 
         let result = create_linked_entity(
             State(state.clone()),
+            RequestAuth::default(),
+            None,
             Path(("users".to_string(), user_id, "cars-owned".to_string())),
             Json(CreateLinkedEntityRequest {
                 entity: entity_data,
@@ -2431,6 +5640,77 @@ mod tests {
         assert_eq!(links.len(), 1, "a link should have been created");
     }
 
+    #[tokio::test]
+    async fn test_create_linked_entity_redacts_the_entity() {
+        let mut state = create_test_state();
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("car".to_string(), Arc::new(MockEntityCreator));
+        state.entity_creators = Arc::new(creators);
+        let mut fetchers: HashMap<String, Arc<dyn crate::core::EntityFetcher>> = HashMap::new();
+        fetchers.insert(
+            "car".to_string(),
+            Arc::new(RedactingFetcher(MockEntityFetcher::new())),
+        );
+        state.entity_fetchers = Arc::new(fetchers);
+
+        let response = create_linked_entity(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                Uuid::new_v4(),
+                "cars-owned".to_string(),
+            )),
+            Json(CreateLinkedEntityRequest {
+                entity: serde_json::json!({ "model": "Zoe", "password_hash": "x" }),
+                metadata: None,
+            }),
+        )
+        .await
+        .expect("create_linked_entity should succeed");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["entity"]["model"], "Zoe");
+        assert!(body["entity"].get("password_hash").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_create_linked_entity_is_owned_by_the_caller() {
+        let mut state = create_test_state();
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("car".to_string(), Arc::new(MockEntityCreator));
+        state.entity_creators = Arc::new(creators);
+        let user_id = Uuid::new_v4();
+        let auth = RequestAuth(Some(AuthContext::User {
+            user_id,
+            tenant_id: Uuid::nil(),
+            roles: vec![],
+        }));
+
+        let response = create_linked_entity(
+            State(state),
+            auth,
+            None,
+            Path(("users".to_string(), user_id, "cars-owned".to_string())),
+            Json(CreateLinkedEntityRequest {
+                entity: serde_json::json!({ "model": "Zoe", "owner_id": Uuid::new_v4() }),
+                metadata: None,
+            }),
+        )
+        .await
+        .expect("create_linked_entity should succeed");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["entity"]["owner_id"], serde_json::json!(user_id));
+    }
+
     #[tokio::test]
     async fn test_create_linked_entity_no_creator_registered() {
         let state = create_test_state();
@@ -2438,6 +5718,8 @@ mod tests {
 
         let result = create_linked_entity(
             State(state),
+            RequestAuth::default(),
+            None,
             Path(("users".to_string(), user_id, "cars-owned".to_string())),
             Json(CreateLinkedEntityRequest {
                 entity: serde_json::json!({}),
@@ -2452,6 +5734,189 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_create_linked_entity_reverse_names_the_missing_source_creator() {
+        // Only the target type ("car") can be created
+        let mut state = create_test_state();
+        let creator = Arc::new(CountingEntityCreator::default());
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("car".to_string(), creator.clone());
+        state.entity_creators = Arc::new(creators);
+
+        // POST /cars/{id}/users-owners creates a user
+        let err = create_linked_entity(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path((
+                "cars".to_string(),
+                Uuid::new_v4(),
+                "users-owners".to_string(),
+            )),
+            Json(CreateLinkedEntityRequest {
+                entity: serde_json::json!({ "name": "Bob" }),
+                metadata: None,
+            }),
+        )
+        .await
+        .expect_err("a user cannot be created without its creator");
+
+        let ExtractorError::Internal(message) = &err else {
+            panic!("expected an internal error, got {err}");
+        };
+        assert!(
+            message.contains("reverse route of 'owner' link"),
+            "{message}"
+        );
+        assert!(
+            message.contains("no EntityCreator is registered for 'user'"),
+            "{message}"
+        );
+        assert_eq!(
+            creator.created.load(std::sync::atomic::Ordering::SeqCst),
+            0,
+            "the car creator must not be used"
+        );
+        assert_eq!(
+            err.into_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    /// Entity creator that counts how many entities it created and deleted
+    #[derive(Default)]
+    struct CountingEntityCreator {
+        created: std::sync::atomic::AtomicUsize,
+        deleted: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::core::EntityCreator for CountingEntityCreator {
+        async fn create_from_json(
+            &self,
+            entity_data: serde_json::Value,
+        ) -> anyhow::Result<serde_json::Value> {
+            self.created
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            MockEntityCreator.create_from_json(entity_data).await
+        }
+
+        async fn delete(&self, _entity_id: &Uuid) -> anyhow::Result<()> {
+            self.deleted
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    /// Link service whose writes always fail
+    struct FailingLinkService;
+
+    #[async_trait::async_trait]
+    impl crate::core::service::LinkService for FailingLinkService {
+        async fn create(&self, _link: LinkEntity) -> anyhow::Result<LinkEntity> {
+            anyhow::bail!("storage unavailable")
+        }
+        async fn get(&self, _id: &Uuid) -> anyhow::Result<Option<LinkEntity>> {
+            Ok(None)
+        }
+        async fn list(&self) -> anyhow::Result<Vec<LinkEntity>> {
+            Ok(vec![])
+        }
+        async fn find_by_source(
+            &self,
+            _source_id: &Uuid,
+            _link_type: Option<&str>,
+            _target_type: Option<&str>,
+        ) -> anyhow::Result<Vec<LinkEntity>> {
+            Ok(vec![])
+        }
+        async fn find_by_target(
+            &self,
+            _target_id: &Uuid,
+            _link_type: Option<&str>,
+            _source_type: Option<&str>,
+        ) -> anyhow::Result<Vec<LinkEntity>> {
+            Ok(vec![])
+        }
+        async fn update(&self, _id: &Uuid, _link: LinkEntity) -> anyhow::Result<LinkEntity> {
+            anyhow::bail!("storage unavailable")
+        }
+        async fn delete(&self, _id: &Uuid) -> anyhow::Result<()> {
+            anyhow::bail!("storage unavailable")
+        }
+        async fn delete_by_entity(&self, _entity_id: &Uuid) -> anyhow::Result<()> {
+            anyhow::bail!("storage unavailable")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_linked_entity_deletes_the_entity_when_the_link_fails() {
+        let mut state = create_test_state();
+        state.link_service = Arc::new(FailingLinkService);
+        let creator = Arc::new(CountingEntityCreator::default());
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("car".to_string(), creator.clone());
+        state.entity_creators = Arc::new(creators);
+
+        let result = create_linked_entity(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                Uuid::new_v4(),
+                "cars-owned".to_string(),
+            )),
+            Json(CreateLinkedEntityRequest {
+                entity: serde_json::json!({ "model": "Zoe" }),
+                metadata: None,
+            }),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(creator.created.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(
+            creator.deleted.load(std::sync::atomic::Ordering::SeqCst),
+            1,
+            "the orphaned entity should be deleted"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_linked_entity_rejected_before_creating_entity() {
+        let mut state = create_cardinality_test_state();
+        let creator = Arc::new(CountingEntityCreator::default());
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("user".to_string(), creator.clone());
+        state.entity_creators = Arc::new(creators);
+
+        let car_id = Uuid::new_v4();
+        link_user_to_car(&state, Uuid::new_v4(), "cars-owned", car_id)
+            .await
+            .expect("first owner should be accepted");
+
+        // POST /cars/{id}/users-owners would give the car a second owner
+        let result = create_linked_entity(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path(("cars".to_string(), car_id, "users-owners".to_string())),
+            Json(CreateLinkedEntityRequest {
+                entity: serde_json::json!({ "name": "Bob" }),
+                metadata: None,
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ExtractorError::Conflict(_))));
+        assert_eq!(
+            creator.created.load(std::sync::atomic::Ordering::SeqCst),
+            0,
+            "no entity should be created for a rejected link"
+        );
+    }
+
     // ------------------------------------------------------------------
     // Handler: update_link
     // ------------------------------------------------------------------
@@ -2473,21 +5938,147 @@ mod tests {
 
         let result = update_link(
             State(state.clone()),
+            RequestAuth::default(),
+            None,
             Path((
                 "users".to_string(),
                 user_id,
                 "cars-owned".to_string(),
                 car_id,
             )),
-            Json(CreateLinkRequest {
+            Json(UpdateLinkRequest {
                 metadata: Some(new_metadata.clone()),
+                merge: false,
             }),
         )
         .await;
 
         assert!(result.is_ok(), "update_link should succeed");
 
-        // Verify metadata updated
+        // Verify metadata updated
+        let links = state
+            .link_service
+            .find_by_source(&user_id, Some("owner"), None)
+            .await
+            .expect("find_by_source should succeed");
+        assert_eq!(links[0].metadata, Some(new_metadata));
+    }
+
+    #[tokio::test]
+    async fn test_update_link_merges_metadata_when_asked() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+        let metadata = serde_json::json!({ "insured": true, "since": 2020 });
+        state
+            .link_service
+            .create(crate::core::link::LinkEntity::new(
+                "owner",
+                user_id,
+                car_id,
+                Some(metadata),
+            ))
+            .await
+            .expect("create should succeed");
+
+        let update = |metadata, merge| {
+            update_link(
+                State(state.clone()),
+                RequestAuth::default(),
+                None,
+                Path((
+                    "users".to_string(),
+                    user_id,
+                    "cars-owned".to_string(),
+                    car_id,
+                )),
+                Json(UpdateLinkRequest { metadata, merge }),
+            )
+        };
+        let stored = || async {
+            state
+                .link_service
+                .find_by_source(&user_id, Some("owner"), None)
+                .await
+                .expect("find_by_source should succeed")[0]
+                .metadata
+                .clone()
+        };
+
+        update(
+            Some(serde_json::json!({ "since": 2021, "plate": "AB-123" })),
+            true,
+        )
+        .await
+        .expect("merge should succeed");
+        assert_eq!(
+            stored().await,
+            Some(serde_json::json!({ "insured": true, "since": 2021, "plate": "AB-123" }))
+        );
+
+        update(Some(serde_json::json!({ "since": 2022 })), false)
+            .await
+            .expect("replace should succeed");
+        assert_eq!(stored().await, Some(serde_json::json!({ "since": 2022 })));
+    }
+
+    #[tokio::test]
+    async fn test_update_link_not_found() {
+        let state = create_test_state();
+        let result = update_link(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                Uuid::new_v4(),
+                "cars-owned".to_string(),
+                Uuid::new_v4(),
+            )),
+            Json(UpdateLinkRequest {
+                metadata: None,
+                merge: false,
+            }),
+        )
+        .await;
+
+        assert!(
+            matches!(result, Err(ExtractorError::LinkNotFound)),
+            "should fail when link does not exist"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_link_reverse_route() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
+        state
+            .link_service
+            .create(link)
+            .await
+            .expect("create should succeed");
+
+        let new_metadata = serde_json::json!({ "insured": true });
+        update_link(
+            State(state.clone()),
+            RequestAuth::default(),
+            None,
+            Path((
+                "cars".to_string(),
+                car_id,
+                "users-owners".to_string(),
+                user_id,
+            )),
+            Json(UpdateLinkRequest {
+                metadata: Some(new_metadata.clone()),
+                merge: false,
+            }),
+        )
+        .await
+        .expect("update through the reverse route should succeed");
+
         let links = state
             .link_service
             .find_by_source(&user_id, Some("owner"), None)
@@ -2496,24 +6087,6 @@ mod tests {
         assert_eq!(links[0].metadata, Some(new_metadata));
     }
 
-    #[tokio::test]
-    async fn test_update_link_not_found() {
-        let state = create_test_state();
-        let result = update_link(
-            State(state),
-            Path((
-                "users".to_string(),
-                Uuid::new_v4(),
-                "cars-owned".to_string(),
-                Uuid::new_v4(),
-            )),
-            Json(CreateLinkRequest { metadata: None }),
-        )
-        .await;
-
-        assert!(result.is_err(), "should fail when link does not exist");
-    }
-
     // ------------------------------------------------------------------
     // Handler: get_link_by_route
     // ------------------------------------------------------------------
@@ -2533,6 +6106,8 @@ mod tests {
 
         let result = get_link_by_route(
             State(state),
+            RequestAuth::default(),
+            None,
             Path((
                 "users".to_string(),
                 user_id,
@@ -2550,6 +6125,8 @@ mod tests {
         let state = create_test_state();
         let result = get_link_by_route(
             State(state),
+            RequestAuth::default(),
+            None,
             Path((
                 "users".to_string(),
                 Uuid::new_v4(),
@@ -2610,6 +6187,35 @@ mod tests {
         assert!(has_owners, "car should have users-owners route");
     }
 
+    #[tokio::test]
+    async fn test_list_available_links_advertises_every_method_of_a_route() {
+        let state = create_test_state();
+        let user_id = Uuid::new_v4();
+
+        let resp = list_available_links(State(state), Path(("users".to_string(), user_id)))
+            .await
+            .expect("handler should succeed")
+            .0;
+
+        let owner = resp
+            .available_routes
+            .iter()
+            .find(|r| r.link_type == "owner")
+            .expect("user should have the owner route");
+        assert_eq!(owner.path, format!("/users/{}/cars-owned", user_id));
+        assert_eq!(owner.methods, ["GET", "POST", "DELETE"]);
+        assert_eq!(
+            owner.item_path,
+            format!("/users/{}/cars-owned/{{car_id}}", user_id)
+        );
+        assert_eq!(owner.item_methods, ["GET", "POST", "PUT", "DELETE"]);
+        // car has routes of its own (users-owners), so the path can go deeper
+        assert_eq!(
+            owner.nested_path.as_deref(),
+            Some(format!("/users/{}/cars-owned/{{car_id}}/{{route_name}}", user_id).as_str())
+        );
+    }
+
     // ======================================================================
     // Phase 3: Nested path handler tests
     // ======================================================================
@@ -2620,6 +6226,8 @@ mod tests {
         // Only 3 segments: orders/{id}/invoices — less than 5 segments
         let result = handle_nested_path_get(
             State(state),
+            RequestAuth::default(),
+            None,
             Path("orders/abc/invoices".to_string()),
             Query(crate::core::query::QueryParams::default()),
         )
@@ -2655,6 +6263,8 @@ mod tests {
         let path = format!("orders/{}/invoices/{}/payments", order_id, invoice_id);
         let result = handle_nested_path_get(
             State(state),
+            RequestAuth::default(),
+            None,
             Path(path),
             Query(crate::core::query::QueryParams::default()),
         )
@@ -2703,6 +6313,8 @@ mod tests {
         );
         let result = handle_nested_path_get(
             State(state),
+            RequestAuth::default(),
+            None,
             Path(path),
             Query(crate::core::query::QueryParams::default()),
         )
@@ -2740,6 +6352,8 @@ mod tests {
         );
         let result = handle_nested_path_get(
             State(state),
+            RequestAuth::default(),
+            None,
             Path(path),
             Query(crate::core::query::QueryParams::default()),
         )
@@ -2782,6 +6396,8 @@ mod tests {
         let path = format!("orders/{}/invoices/{}/payments", order_id, wrong_invoice_id);
         let result = handle_nested_path_get(
             State(state),
+            RequestAuth::default(),
+            None,
             Path(path),
             Query(crate::core::query::QueryParams::default()),
         )
@@ -2793,6 +6409,206 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_handle_nested_path_get_dry_run_valid_chain() {
+        let state = create_chain_test_state();
+
+        let order_id = Uuid::new_v4();
+        let invoice_id = Uuid::new_v4();
+        let link = crate::core::link::LinkEntity::new("billing", order_id, invoice_id, None);
+        state
+            .link_service
+            .create(link)
+            .await
+            .expect("create should succeed");
+
+        let path = format!("orders/{}/invoices/{}/payments", order_id, invoice_id);
+        let result = handle_nested_path_get(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(path),
+            Query(crate::core::query::QueryParams {
+                dry_run: true,
+                ..Default::default()
+            }),
+        )
+        .await
+        .expect("dry run of a valid chain should succeed");
+
+        assert_eq!(result.0, serde_json::json!({ "valid": true }));
+    }
+
+    #[tokio::test]
+    async fn test_handle_nested_path_get_dry_run_reports_broken_middle_link() {
+        let state = create_chain_test_state();
+
+        let order_id = Uuid::new_v4();
+        let invoice_id = Uuid::new_v4();
+        let payment_id = Uuid::new_v4();
+
+        // order -> invoice exists, invoice -> payment does not
+        let link = crate::core::link::LinkEntity::new("billing", order_id, invoice_id, None);
+        state
+            .link_service
+            .create(link)
+            .await
+            .expect("create should succeed");
+
+        // Three hops: order -> invoice -> payment -> back to the invoice
+        let path = format!(
+            "orders/{}/invoices/{}/payments/{}/invoice/{}",
+            order_id, invoice_id, payment_id, invoice_id
+        );
+        let result = handle_nested_path_get(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(path),
+            Query(crate::core::query::QueryParams {
+                dry_run: true,
+                ..Default::default()
+            }),
+        )
+        .await
+        .expect("dry run should report the broken link, not fail");
+
+        let body = result.0;
+        assert_eq!(body["valid"], false);
+        assert_eq!(body["error"], "Link not found");
+        assert_eq!(body["failed_segment"]["index"], 1);
+        assert_eq!(body["failed_segment"]["entity_type"], "invoice");
+        assert_eq!(
+            body["failed_segment"]["entity_id"],
+            serde_json::json!(invoice_id)
+        );
+        assert_eq!(body["failed_segment"]["route_name"], "payments");
+        assert_eq!(body["failed_segment"]["target_type"], "payment");
+        assert_eq!(
+            body["failed_segment"]["target_id"],
+            serde_json::json!(payment_id)
+        );
+    }
+
+    // ------------------------------------------------------------------
+    // validate_link_chain
+    // ------------------------------------------------------------------
+
+    /// Parse a nested path into its chain, as the nested handlers do
+    fn parse_chain(state: &AppState, path: &str) -> Vec<LinkPathSegment> {
+        let segments = path.split('/').map(|s| s.to_string()).collect();
+        RecursiveLinkExtractor::from_segments(segments, &state.registry, &state.config)
+            .expect("path should parse")
+            .chain
+    }
+
+    async fn insert_chain_link(
+        state: &AppState,
+        link_type: &str,
+        source_id: Uuid,
+        target_id: Uuid,
+    ) {
+        state
+            .link_service
+            .create(crate::core::link::LinkEntity::new(
+                link_type, source_id, target_id, None,
+            ))
+            .await
+            .expect("create should succeed");
+    }
+
+    #[tokio::test]
+    async fn test_validate_link_chain_accepts_valid_three_level_chain() {
+        let state = create_chain_test_state();
+        let (order_id, invoice_id, payment_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        insert_chain_link(&state, "billing", order_id, invoice_id).await;
+        insert_chain_link(&state, "payment", invoice_id, payment_id).await;
+
+        let chain = parse_chain(
+            &state,
+            &format!(
+                "orders/{}/invoices/{}/payments/{}",
+                order_id, invoice_id, payment_id
+            ),
+        );
+
+        for skip_trailing_nil in [false, true] {
+            assert!(
+                validate_link_chain(&state, None, &chain, skip_trailing_nil)
+                    .await
+                    .is_ok()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_link_chain_stops_at_broken_first_link() {
+        let state = create_chain_test_state();
+        let (order_id, invoice_id, payment_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        // invoice -> payment exists, but the invoice belongs to another order
+        insert_chain_link(&state, "billing", Uuid::new_v4(), invoice_id).await;
+        insert_chain_link(&state, "payment", invoice_id, payment_id).await;
+
+        let chain = parse_chain(
+            &state,
+            &format!(
+                "orders/{}/invoices/{}/payments/{}",
+                order_id, invoice_id, payment_id
+            ),
+        );
+        let broken = validate_link_chain(&state, None, &chain, false)
+            .await
+            .expect_err("first link is missing");
+
+        assert_eq!(broken.segment, 0);
+        assert!(matches!(broken.error, ExtractorError::LinkNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_validate_link_chain_stops_at_broken_middle_link() {
+        let state = create_chain_test_state();
+        let (order_id, invoice_id, payment_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        insert_chain_link(&state, "billing", order_id, invoice_id).await;
+
+        // order -> invoice -> payment -> back to the invoice
+        let chain = parse_chain(
+            &state,
+            &format!(
+                "orders/{}/invoices/{}/payments/{}/invoice/{}",
+                order_id, invoice_id, payment_id, invoice_id
+            ),
+        );
+        let broken = validate_link_chain(&state, None, &chain, false)
+            .await
+            .expect_err("invoice -> payment is missing");
+
+        assert_eq!(broken.segment, 1);
+        assert!(matches!(broken.error, ExtractorError::LinkNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_validate_link_chain_skips_trailing_nil_target_of_a_list() {
+        let state = create_chain_test_state();
+        let (order_id, invoice_id) = (Uuid::new_v4(), Uuid::new_v4());
+        insert_chain_link(&state, "billing", order_id, invoice_id).await;
+
+        let chain = parse_chain(
+            &state,
+            &format!("orders/{}/invoices/{}/payments", order_id, invoice_id),
+        );
+        assert!(chain.last().expect("chain is not empty").entity_id.is_nil());
+
+        assert!(
+            validate_link_chain(&state, None, &chain, true)
+                .await
+                .is_ok()
+        );
+        let broken = validate_link_chain(&state, None, &chain, false)
+            .await
+            .expect_err("no invoice -> nil link exists");
+        assert_eq!(broken.segment, 1);
+    }
+
     // ------------------------------------------------------------------
     // Handler: handle_nested_path_post
     // ------------------------------------------------------------------
@@ -2802,6 +6618,8 @@ mod tests {
         let state = create_chain_test_state();
         let result = handle_nested_path_post(
             State(state),
+            RequestAuth::default(),
+            None,
             Path("orders/abc/invoices".to_string()),
             Json(CreateLinkedEntityRequest {
                 entity: serde_json::json!({}),
@@ -2813,6 +6631,77 @@ mod tests {
         assert!(result.is_err(), "should fail with fewer than 5 segments");
     }
 
+    #[tokio::test]
+    async fn test_nested_paths_enforce_link_policies() {
+        use crate::core::link::LinkAuthConfig;
+
+        let mut state = create_chain_test_state();
+        let mut config = (*state.config).clone();
+        config.links[1].auth = Some(LinkAuthConfig {
+            list: "role:admin".to_string(),
+            get: "role:admin".to_string(),
+            create: "role:admin".to_string(),
+            ..Default::default()
+        });
+        state.config = Arc::new(config);
+        state.registry = Arc::new(LinkRouteRegistry::new(state.config.clone()));
+        state.auth_provider = Some(Arc::new(HeaderAuthProvider {
+            owner_id: Uuid::nil(),
+        }));
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("payment".to_string(), Arc::new(MockEntityCreator));
+        state.entity_creators = Arc::new(creators);
+
+        let (order_id, invoice_id, payment_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        for link in [
+            LinkEntity::new("billing", order_id, invoice_id, None),
+            LinkEntity::new("payment", invoice_id, payment_id, None),
+        ] {
+            state.link_service.create(link).await.unwrap();
+        }
+        let list = format!("orders/{}/invoices/{}/payments", order_id, invoice_id);
+        let item = format!("{}/{}", list, payment_id);
+        let get = |path: &String, roles: &[&str]| {
+            handle_nested_path_get(
+                State(state.clone()),
+                user_auth(Uuid::new_v4(), roles),
+                None,
+                Path(path.clone()),
+                Query(crate::core::query::QueryParams::default()),
+            )
+        };
+        let post = |roles: &[&str]| {
+            handle_nested_path_post(
+                State(state.clone()),
+                user_auth(Uuid::new_v4(), roles),
+                None,
+                Path(list.clone()),
+                Json(CreateLinkedEntityRequest {
+                    entity: serde_json::json!({ "amount": 10.0 }),
+                    metadata: None,
+                }),
+            )
+        };
+
+        for path in [&list, &item] {
+            let err = get(path, &["user"]).await.expect_err("users are denied");
+            assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+            let body = get(path, &["admin"]).await.expect("admins may read").0;
+            assert!(body.get("data").or(body.get("link")).is_some());
+        }
+        let err = post(&["user"]).await.expect_err("users are denied");
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+
+        let response = post(&["admin"]).await.expect("admins may create");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let links = state
+            .link_service
+            .find_by_source(&invoice_id, Some("payment"), None)
+            .await
+            .unwrap();
+        assert_eq!(links.len(), 2, "the new payment is linked to the invoice");
+    }
+
     #[tokio::test]
     async fn test_handle_nested_path_post_success() {
         let mut state = create_chain_test_state();
@@ -2837,6 +6726,8 @@ mod tests {
         let path = format!("orders/{}/invoices/{}/payments", order_id, invoice_id);
         let result = handle_nested_path_post(
             State(state.clone()),
+            RequestAuth::default(),
+            None,
             Path(path),
             Json(CreateLinkedEntityRequest {
                 entity: serde_json::json!({ "amount": 100.0 }),
@@ -2850,6 +6741,92 @@ mod tests {
         assert_eq!(response.status(), StatusCode::CREATED);
     }
 
+    #[tokio::test]
+    async fn test_handle_nested_path_post_binds_the_entity_to_the_tenant() {
+        let mut state = create_chain_test_state();
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("payment".to_string(), Arc::new(MockEntityCreator));
+        state.entity_creators = Arc::new(creators);
+
+        let tenant = TenantContext::new(Uuid::new_v4());
+        let (order_id, invoice_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let link =
+            LinkEntity::new_with_tenant(tenant.tenant_id, "billing", order_id, invoice_id, None);
+        state.link_service.create(link).await.unwrap();
+
+        let path = format!("orders/{}/invoices/{}/payments", order_id, invoice_id);
+        let post = |entity| {
+            handle_nested_path_post(
+                State(state.clone()),
+                RequestAuth::default(),
+                Some(tenant),
+                Path(path.clone()),
+                Json(CreateLinkedEntityRequest {
+                    entity,
+                    metadata: None,
+                }),
+            )
+        };
+
+        let err = post(serde_json::json!({ "amount": 1.0, "tenant_id": Uuid::new_v4() }))
+            .await
+            .expect_err("another tenant's id is refused");
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+        assert_eq!(state.link_service.list().await.unwrap().len(), 1);
+
+        let response = post(serde_json::json!({ "amount": 1.0 })).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body["entity"]["tenant_id"],
+            serde_json::json!(tenant.tenant_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_nested_path_post_redacts_the_entity() {
+        let mut state = create_chain_test_state();
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("payment".to_string(), Arc::new(MockEntityCreator));
+        state.entity_creators = Arc::new(creators);
+        let mut fetchers: HashMap<String, Arc<dyn crate::core::EntityFetcher>> = HashMap::new();
+        fetchers.insert(
+            "payment".to_string(),
+            Arc::new(RedactingFetcher(MockEntityFetcher::new())),
+        );
+        state.entity_fetchers = Arc::new(fetchers);
+
+        let (order_id, invoice_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let link = LinkEntity::new("billing", order_id, invoice_id, None);
+        state.link_service.create(link).await.unwrap();
+
+        let response = handle_nested_path_post(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(format!(
+                "orders/{}/invoices/{}/payments",
+                order_id, invoice_id
+            )),
+            Json(CreateLinkedEntityRequest {
+                entity: serde_json::json!({ "amount": 1.0, "password_hash": "x" }),
+                metadata: None,
+            }),
+        )
+        .await
+        .expect("nested post should succeed");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["entity"]["amount"], 1.0);
+        assert!(body["entity"].get("password_hash").is_none());
+    }
+
     #[tokio::test]
     async fn test_handle_nested_path_post_no_creator() {
         let state = create_chain_test_state();
@@ -2867,6 +6844,8 @@ mod tests {
         let path = format!("orders/{}/invoices/{}/payments", order_id, invoice_id);
         let result = handle_nested_path_post(
             State(state),
+            RequestAuth::default(),
+            None,
             Path(path),
             Json(CreateLinkedEntityRequest {
                 entity: serde_json::json!({}),
@@ -2881,6 +6860,34 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_handle_nested_path_post_rejects_broken_chain() {
+        let mut state = create_chain_test_state();
+        let mut creators: HashMap<String, Arc<dyn crate::core::EntityCreator>> = HashMap::new();
+        creators.insert("payment".to_string(), Arc::new(MockEntityCreator));
+        state.entity_creators = Arc::new(creators);
+
+        // The invoice is not linked to the order
+        let path = format!(
+            "orders/{}/invoices/{}/payments",
+            Uuid::new_v4(),
+            Uuid::new_v4()
+        );
+        let result = handle_nested_path_post(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path(path),
+            Json(CreateLinkedEntityRequest {
+                entity: serde_json::json!({ "amount": 100.0 }),
+                metadata: None,
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ExtractorError::LinkNotFound)));
+    }
+
     // ------------------------------------------------------------------
     // EnrichedLink serialization
     // ------------------------------------------------------------------
@@ -2936,6 +6943,8 @@ mod tests {
 
         let _result = create_link(
             State(state),
+            RequestAuth::default(),
+            None,
             Path((
                 "users".to_string(),
                 user_id,
@@ -2982,6 +6991,8 @@ mod tests {
 
         delete_link(
             State(state),
+            RequestAuth::default(),
+            None,
             Path((
                 "users".to_string(),
                 user_id,
@@ -3007,4 +7018,55 @@ mod tests {
             other => panic!("expected Link::Deleted event, got: {:?}", other),
         }
     }
+
+    #[tokio::test]
+    async fn test_update_link_emits_event() {
+        let bus = Arc::new(EventBus::new(16));
+        let mut state = create_test_state();
+        state.event_bus = Some(bus.clone());
+
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+        let link = crate::core::link::LinkEntity::new("owner", user_id, car_id, None);
+        state
+            .link_service
+            .create(link)
+            .await
+            .expect("create should succeed");
+
+        let mut rx = bus.subscribe();
+
+        update_link(
+            State(state),
+            RequestAuth::default(),
+            None,
+            Path((
+                "users".to_string(),
+                user_id,
+                "cars-owned".to_string(),
+                car_id,
+            )),
+            Json(UpdateLinkRequest {
+                metadata: Some(serde_json::json!({ "since": 2021 })),
+                merge: false,
+            }),
+        )
+        .await
+        .expect("update should succeed");
+
+        let envelope = rx.try_recv().expect("should receive link updated event");
+        match envelope.event {
+            FrameworkEvent::Link(LinkEvent::Updated {
+                link_type,
+                source_id,
+                metadata,
+                ..
+            }) => {
+                assert_eq!(link_type, "owner");
+                assert_eq!(source_id, user_id);
+                assert_eq!(metadata, Some(serde_json::json!({ "since": 2021 })));
+            }
+            other => panic!("expected Link::Updated event, got: {:?}", other),
+        }
+    }
 }
@@ -42,12 +42,18 @@ impl LinkRouteRegistry {
             );
             routes.insert(forward_key, (link_def.clone(), LinkDirection::Forward));
 
-            // Reverse route: target -> source
+            // Reverse route: target -> source. For a link between two
+            // entities of the same type both routes live on that type and
+            // only their names tell the directions apart; identical names
+            // are rejected by `LinksConfig::validate`, and should they get
+            // here anyway the forward route is kept.
             let reverse_key = (
                 link_def.target_type.clone(),
                 link_def.reverse_route_name.clone(),
             );
-            routes.insert(reverse_key, (link_def.clone(), LinkDirection::Reverse));
+            routes
+                .entry(reverse_key)
+                .or_insert_with(|| (link_def.clone(), LinkDirection::Reverse));
         }
 
         Self { config, routes }
@@ -73,8 +79,12 @@ impl LinkRouteRegistry {
     }
 
     /// List all available routes for a given entity type
+    ///
+    /// Forward routes come first, each group sorted by route name. A link
+    /// from the type to itself appears twice, once per direction.
     pub fn list_routes_for_entity(&self, entity_type: &str) -> Vec<RouteInfo> {
-        self.routes
+        let mut routes: Vec<RouteInfo> = self
+            .routes
             .iter()
             .filter(|((etype, _), _)| etype == entity_type)
             .map(|((_, route_name), (link_def, direction))| {
@@ -91,7 +101,12 @@ impl LinkRouteRegistry {
                     description: link_def.description.clone(),
                 }
             })
-            .collect()
+            .collect();
+        routes.sort_by(|a, b| {
+            (a.direction == LinkDirection::Reverse, &a.route_name)
+                .cmp(&(b.direction == LinkDirection::Reverse, &b.route_name))
+        });
+        routes
     }
 
     /// Get the underlying configuration
@@ -363,11 +378,17 @@ mod tests {
                     singular: "user".to_string(),
                     plural: "users".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "car".to_string(),
                     plural: "cars".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![
@@ -379,7 +400,12 @@ mod tests {
                     reverse_route_name: "users-owners".to_string(),
                     description: Some("User owns a car".to_string()),
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
                 LinkDefinition {
                     link_type: "driver".to_string(),
@@ -389,12 +415,18 @@ mod tests {
                     reverse_route_name: "users-drivers".to_string(),
                     description: Some("User drives a car".to_string()),
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
             ],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         }
     }
 
@@ -463,16 +495,25 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "payment".to_string(),
                     plural: "payments".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![
@@ -484,7 +525,12 @@ mod tests {
                     reverse_route_name: "order".to_string(),
                     description: None,
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
                 LinkDefinition {
                     link_type: "payment".to_string(),
@@ -494,12 +540,18 @@ mod tests {
                     reverse_route_name: "invoice".to_string(),
                     description: None,
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
             ],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         }
     }
 
@@ -512,11 +564,17 @@ mod tests {
                     singular: "a".to_string(),
                     plural: "as".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "b".to_string(),
                     plural: "bs".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![
@@ -528,7 +586,12 @@ mod tests {
                     reverse_route_name: "as-from-b".to_string(),
                     description: None,
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
                 LinkDefinition {
                     link_type: "ba".to_string(),
@@ -538,12 +601,18 @@ mod tests {
                     reverse_route_name: "bs-from-a".to_string(),
                     description: None,
                     required_fields: None,
+                    required_fields_forward: None,
+                    required_fields_reverse: None,
+                    symmetric: false,
                     auth: None,
+                    cardinality: None,
+                    metadata_schema: None,
                 },
             ],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         }
     }
 
@@ -556,6 +625,7 @@ mod tests {
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         }
     }
 
@@ -779,6 +849,9 @@ mod tests {
                     singular: "widget".to_string(),
                     plural: "widgets".to_string(),
                     auth: crate::config::EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 // "gadget" entity is deliberately missing from entities list
             ],
@@ -790,11 +863,17 @@ mod tests {
                 reverse_route_name: "widget".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         });
 
         // Manually build a chain with an unknown entity to exercise fallback
@@ -966,6 +1045,73 @@ mod tests {
         );
     }
 
+    fn create_self_link_config() -> LinksConfig {
+        LinksConfig::from_yaml_str(
+            r#"
+entities:
+  - singular: employee
+    plural: employees
+links:
+  - link_type: manages
+    source_type: employee
+    target_type: employee
+    forward_route_name: reports
+    reverse_route_name: manager
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_self_link_routes_by_name() {
+        let registry = LinkRouteRegistry::new(Arc::new(create_self_link_config()));
+
+        let (def, direction) = registry.resolve_route("employee", "reports").unwrap();
+        assert_eq!(def.link_type, "manages");
+        assert_eq!(direction, LinkDirection::Forward);
+
+        let (def, direction) = registry.resolve_route("employee", "manager").unwrap();
+        assert_eq!(def.link_type, "manages");
+        assert_eq!(direction, LinkDirection::Reverse);
+    }
+
+    #[test]
+    fn test_list_routes_for_self_link_has_both_directions() {
+        let registry = LinkRouteRegistry::new(Arc::new(create_self_link_config()));
+
+        let routes = registry.list_routes_for_entity("employee");
+        let listed: Vec<_> = routes
+            .iter()
+            .map(|r| (r.route_name.as_str(), r.direction, r.connected_to.as_str()))
+            .collect();
+        assert_eq!(
+            listed,
+            [
+                ("reports", LinkDirection::Forward, "employee"),
+                ("manager", LinkDirection::Reverse, "employee"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_self_link_with_colliding_names_keeps_forward_route() {
+        let mut config = create_self_link_config();
+        config.links[0].reverse_route_name = "reports".to_string();
+        let registry = LinkRouteRegistry::new(Arc::new(config));
+
+        let (_, direction) = registry.resolve_route("employee", "reports").unwrap();
+        assert_eq!(direction, LinkDirection::Forward);
+        assert_eq!(registry.list_routes_for_entity("employee").len(), 1);
+    }
+
+    #[test]
+    fn test_detect_link_chains_terminates_on_self_link() {
+        let registry = LinkRouteRegistry::new(Arc::new(create_self_link_config()));
+        let chains = registry.detect_link_chains(5);
+        assert!(!chains.is_empty());
+        assert!(chains.iter().all(|c| c.steps.len() == 2));
+    }
+
     #[test]
     fn test_list_routes_for_entity_reverse_direction() {
         let config = Arc::new(create_test_config());
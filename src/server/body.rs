@@ -0,0 +1,262 @@
+//! Bodies buffered by the REST layers
+//!
+//! Several REST layers read or rewrite JSON bodies: timestamps, ids,
+//! ownership, hooks and validation on the way in; computed fields,
+//! redaction, ETags, events and the audit log on the way out. They buffer
+//! them through [`JsonRequest`] and [`JsonResponse`], which share:
+//!
+//! - one size limit, the [`BodyLimit`] set with
+//!   [`ServerBuilder::with_max_body_size`](crate::server::ServerBuilder::with_max_body_size)
+//!   and carried in the request extensions; handlers extracting `Json`
+//!   get the same limit;
+//! - the parsed JSON, kept in the message extensions with the bytes it was
+//!   parsed from, so a body is parsed once however many layers read it.
+
+use crate::core::extractors::ExtractorError;
+use axum::body::{Body, Bytes, to_bytes};
+use axum::extract::{Request, State};
+use axum::http::header::CONTENT_LENGTH;
+use axum::http::{Extensions, HeaderMap, request, response};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Body size limit when none is configured (axum's own default)
+pub const DEFAULT_MAX_BODY_SIZE: usize = 2 * 1024 * 1024;
+
+/// Largest request or response body the REST layers buffer, in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyLimit(pub usize);
+
+impl Default for BodyLimit {
+    fn default() -> Self {
+        Self(DEFAULT_MAX_BODY_SIZE)
+    }
+}
+
+impl BodyLimit {
+    /// The limit recorded in a request's extensions, or the default
+    pub fn of(extensions: &Extensions) -> Self {
+        extensions.get::<BodyLimit>().copied().unwrap_or_default()
+    }
+}
+
+/// Middleware recording the router's [`BodyLimit`] in each request
+pub async fn body_limit_middleware(
+    State(limit): State<BodyLimit>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    request.extensions_mut().insert(limit);
+    next.run(request).await
+}
+
+/// JSON parsed from a body, kept with the bytes it came from
+///
+/// Only reused while the body still holds these bytes, so a layer replacing
+/// the body some other way cannot leave a stale parse behind.
+#[derive(Clone)]
+struct ParsedJson {
+    bytes: Bytes,
+    json: Option<Arc<Value>>,
+}
+
+/// Request or response head of a buffered body
+pub trait Head {
+    fn headers_mut(&mut self) -> &mut HeaderMap;
+    fn extensions_mut(&mut self) -> &mut Extensions;
+}
+
+impl Head for request::Parts {
+    fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+    fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+}
+
+impl Head for response::Parts {
+    fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+    fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+}
+
+/// A buffered body, parsed as JSON, with the head of its message
+pub struct Buffered<P> {
+    /// Head of the message
+    pub parts: P,
+    bytes: Bytes,
+    json: Option<Arc<Value>>,
+    changed: bool,
+}
+
+/// A buffered request body; see [`Buffered`]
+pub type JsonRequest = Buffered<request::Parts>;
+
+/// A buffered response body; see [`Buffered`]
+pub type JsonResponse = Buffered<response::Parts>;
+
+impl<P: Head> Buffered<P> {
+    fn new(mut parts: P, bytes: Bytes) -> Self {
+        // Taking the cached parse out keeps its JSON uniquely owned
+        let json = match parts.extensions_mut().remove::<ParsedJson>() {
+            Some(parsed) if parsed.bytes == bytes => parsed.json,
+            _ => serde_json::from_slice(&bytes).ok().map(Arc::new),
+        };
+        Self {
+            parts,
+            bytes,
+            json,
+            changed: false,
+        }
+    }
+
+    /// The body as received
+    pub fn bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// The parsed body, `None` when it is not JSON
+    pub fn json(&self) -> Option<&Value> {
+        self.json.as_deref()
+    }
+
+    /// The parsed body for rewriting, `None` when it is not JSON
+    ///
+    /// The body is serialized again when passed on.
+    pub fn json_mut(&mut self) -> Option<&mut Value> {
+        let json = self.json.as_mut()?;
+        self.changed = true;
+        Some(Arc::make_mut(json))
+    }
+
+    /// Rewrite the parsed body with `f`, which returns whether it changed it
+    ///
+    /// Returns `false` without calling `f` when the body is not JSON.
+    pub fn rewrite(&mut self, f: impl FnOnce(&mut Value) -> bool) -> bool {
+        let Some(json) = self.json.as_mut() else {
+            return false;
+        };
+        let changed = f(Arc::make_mut(json));
+        self.changed |= changed;
+        changed
+    }
+
+    fn into_body(mut self) -> (P, Body) {
+        if self.changed
+            && let Some(json) = &self.json
+            && let Ok(bytes) = serde_json::to_vec(&**json)
+        {
+            self.bytes = Bytes::from(bytes);
+            self.parts.headers_mut().remove(CONTENT_LENGTH);
+        }
+        self.parts.extensions_mut().insert(ParsedJson {
+            bytes: self.bytes.clone(),
+            json: self.json,
+        });
+        (self.parts, Body::from(self.bytes))
+    }
+}
+
+impl JsonRequest {
+    /// Buffer a request body up to the request's [`BodyLimit`]
+    ///
+    /// Larger bodies are answered with `413 Payload Too Large`.
+    pub async fn read(request: Request) -> Result<Self, Response> {
+        let limit = BodyLimit::of(request.extensions());
+        let (parts, body) = request.into_parts();
+        let bytes = to_bytes(body, limit.0)
+            .await
+            .map_err(|_| ExtractorError::PayloadTooLarge.into_response())?;
+        Ok(Self::new(parts, bytes))
+    }
+
+    /// Rebuild the request, with the rewritten body if it changed
+    pub fn into_request(self) -> Request {
+        let (parts, body) = self.into_body();
+        Request::from_parts(parts, body)
+    }
+}
+
+impl JsonResponse {
+    /// Buffer a response body up to `limit`
+    ///
+    /// Larger bodies are replaced with a `500 Internal Server Error`.
+    pub async fn read(response: Response, limit: BodyLimit) -> Result<Self, Response> {
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, limit.0).await.map_err(|_| {
+            ExtractorError::Internal("response body too large".to_string()).into_response()
+        })?;
+        Ok(Self::new(parts, bytes))
+    }
+
+    /// Rebuild the response, with the rewritten body if it changed
+    pub fn into_response(self) -> Response {
+        let (parts, body) = self.into_body();
+        Response::from_parts(parts, body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(body: &Value) -> Request {
+        Request::builder()
+            .header(CONTENT_LENGTH, body.to_string().len())
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rewritten_bodies_reach_the_next_reader() {
+        let mut first = JsonRequest::read(request(&json!({ "a": 1 })))
+            .await
+            .unwrap();
+        first.json_mut().unwrap()["b"] = json!(2);
+        let request = first.into_request();
+        assert!(request.headers().get(CONTENT_LENGTH).is_none());
+
+        let second = JsonRequest::read(request).await.unwrap();
+        assert_eq!(second.json(), Some(&json!({ "a": 1, "b": 2 })));
+        let bytes = to_bytes(second.into_request().into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&bytes).unwrap(),
+            json!({ "a": 1, "b": 2 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_a_replaced_body_is_parsed_again() {
+        let (parts, _) = JsonRequest::read(request(&json!({ "a": 1 })))
+            .await
+            .unwrap()
+            .into_body();
+        let replaced = Request::from_parts(parts, Body::from(json!({ "c": 3 }).to_string()));
+
+        let read = JsonRequest::read(replaced).await.unwrap();
+        assert_eq!(read.json(), Some(&json!({ "c": 3 })));
+    }
+
+    #[tokio::test]
+    async fn test_bodies_over_the_limit_are_refused() {
+        let mut request = request(&json!({ "text": "x".repeat(64) }));
+        request.extensions_mut().insert(BodyLimit(16));
+        let response = JsonRequest::read(request).await.err().unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::PAYLOAD_TOO_LARGE);
+
+        let not_json = JsonRequest::read(Request::new(Body::from("plain")))
+            .await
+            .unwrap();
+        assert_eq!(not_json.json(), None);
+        assert_eq!(not_json.bytes().as_ref(), b"plain");
+    }
+}
@@ -1,24 +1,55 @@
 //! ServerBuilder for fluent API to build HTTP servers
 
+use super::body::{BodyLimit, body_limit_middleware};
+use super::config_watch::{ConfigWatcher, load_merged};
+use super::cors::CorsConfig;
 use super::entity_registry::EntityRegistry;
 use super::exposure::RestExposure;
 use super::host::ServerHost;
-use crate::config::LinksConfig;
-use crate::core::events::EventBus;
-use crate::core::module::Module;
+use super::rate_limit::{RateKey, RateLimiter, rate_limit_middleware};
+use super::timestamps::{TimestampFormat, timestamp_middleware};
+use crate::config::{EntityCapability, IdPolicy, LinksConfig};
+use crate::core::audit::AuditLogService;
+use crate::core::auth::AuthContext;
+use crate::core::events::{EventBus, OutboxSources};
+use crate::core::history::HistoryService;
+use crate::core::module::{HookedCreator, Module};
+use crate::core::outbox::{DEFAULT_OUTBOX_POLL_INTERVAL, OutboxService, spawn_outbox_poller};
+use crate::core::query::PaginationConfig;
 use crate::core::service::LinkService;
-use crate::core::{EntityCreator, EntityFetcher};
+use crate::core::soft_delete::SoftDeleteStatus;
+use crate::core::update_interval::UpdateIntervalCreator;
+use crate::core::validation::{ConstrainedCreator, IdPolicyCreator};
+#[cfg(feature = "json-schema")]
+use crate::core::validation::{EntitySchemas, SchemaValidatedCreator};
+use crate::core::{
+    AuthProvider, EntityCreator, EntityFetcher, HealthCheck, IdNormalizer, IdStrategy,
+    IdStrategyCreator,
+};
 use crate::events::SinkFactory;
 use crate::events::sinks::SinkRegistry;
 use crate::events::sinks::device_tokens::DeviceTokenStore;
 use crate::events::sinks::in_app::NotificationStore;
 use crate::events::sinks::preferences::NotificationPreferencesStore;
+use crate::links::{DEFAULT_ENRICHMENT_CONCURRENCY, EnrichmentFallback};
 use anyhow::Result;
 use axum::Router;
+use axum::http::HeaderName;
+use futures::future::BoxFuture;
 use std::collections::HashMap;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
 
+/// How long shutdown hooks may run before the server exits anyway
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// An async closure run once the server stopped serving
+type ShutdownHook = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
 /// Builder for creating HTTP servers with auto-registered routes
 ///
 /// # Example
@@ -36,6 +67,33 @@ pub struct ServerBuilder {
     modules: Vec<Arc<dyn Module>>,
     custom_routes: Vec<Router>,
     event_bus: Option<EventBus>,
+    replay_buffer: Option<usize>,
+    timestamp_format: Option<TimestampFormat>,
+    cors: Option<CorsConfig>,
+    rate_limit: Option<RateLimiter>,
+    history_service: Option<Arc<dyn HistoryService>>,
+    audit_log: Option<Arc<dyn AuditLogService>>,
+    outbox: Option<Arc<dyn OutboxService>>,
+    enrichment_fallback: EnrichmentFallback,
+    enrichment_concurrency: usize,
+    id_normalizer: Option<Arc<dyn IdNormalizer>>,
+    id_strategy: Option<IdStrategy>,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
+    tenant_header: Option<String>,
+    request_logging: bool,
+    idempotency_cache: Option<(usize, Duration)>,
+    pagination: Option<PaginationConfig>,
+    max_body_size: Option<usize>,
+    soft_delete_status: Option<SoftDeleteStatus>,
+    allow_duplicate_links: bool,
+    route_prefix: Option<String>,
+    health_checks: Vec<Arc<dyn HealthCheck>>,
+    validate_registrations: bool,
+    config_watch: Option<PathBuf>,
+    shutdown_hooks: Vec<ShutdownHook>,
+    shutdown_timeout: Duration,
+    #[cfg(feature = "json-schema")]
+    entity_schemas: Vec<(String, serde_json::Value)>,
 
     // Manual overrides for event system stores
     sink_registry: Option<SinkRegistry>,
@@ -54,6 +112,33 @@ impl ServerBuilder {
             modules: Vec::new(),
             custom_routes: Vec::new(),
             event_bus: None,
+            replay_buffer: None,
+            timestamp_format: None,
+            cors: None,
+            rate_limit: None,
+            history_service: None,
+            audit_log: None,
+            outbox: None,
+            enrichment_fallback: EnrichmentFallback::default(),
+            enrichment_concurrency: DEFAULT_ENRICHMENT_CONCURRENCY,
+            id_normalizer: None,
+            id_strategy: None,
+            auth_provider: None,
+            tenant_header: None,
+            request_logging: true,
+            idempotency_cache: None,
+            pagination: None,
+            max_body_size: None,
+            soft_delete_status: None,
+            allow_duplicate_links: false,
+            route_prefix: None,
+            health_checks: Vec::new(),
+            validate_registrations: false,
+            config_watch: None,
+            shutdown_hooks: Vec::new(),
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            #[cfg(feature = "json-schema")]
+            entity_schemas: Vec::new(),
             sink_registry: None,
             notification_store: None,
             device_token_store: None,
@@ -120,6 +205,423 @@ impl ServerBuilder {
         self
     }
 
+    /// Retain the last `capacity` events for WebSocket clients reconnecting
+    ///
+    /// Events get monotonic sequence numbers (`seq_no`), and a client can
+    /// subscribe with `since_seq` to receive the buffered events it missed
+    /// before live ones resume. Has no effect without `with_event_bus()`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// ServerBuilder::new()
+    ///     .with_link_service(service)
+    ///     .with_event_bus(1024)
+    ///     .with_replay_buffer(500)
+    ///     .register_module(module)?
+    ///     .build_host()?;
+    /// ```
+    pub fn with_replay_buffer(mut self, capacity: usize) -> Self {
+        self.replay_buffer = Some(capacity);
+        self
+    }
+
+    /// Deliver events through a transactional outbox
+    ///
+    /// The data and link services write each mutation's event to `service`
+    /// in the same transaction as the change (e.g.
+    /// `MysqlDataService::with_outbox`), and a background task publishes
+    /// unsent rows to the event bus every
+    /// [`DEFAULT_OUTBOX_POLL_INTERVAL`](crate::core::outbox::DEFAULT_OUTBOX_POLL_INTERVAL),
+    /// so events survive a crash and are delivered at least once. Direct
+    /// publishes of the events those services record are dropped to avoid
+    /// duplicates; services without an outbox keep publishing directly.
+    ///
+    /// Requires `with_event_bus()`, at least one service whose
+    /// `writes_outbox()` is true, and `build_host()` must run inside a Tokio
+    /// runtime.
+    pub fn with_outbox(mut self, service: impl OutboxService + 'static) -> Self {
+        self.outbox = Some(Arc::new(service));
+        self
+    }
+
+    /// Set how timestamp fields are rendered in REST responses
+    ///
+    /// The `created_at`, `updated_at` and `deleted_at` fields of JSON
+    /// responses are rewritten to the chosen format. Once set, request bodies
+    /// accept either RFC3339 strings or epoch milliseconds for them. Storage
+    /// keeps its native format.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// ServerBuilder::new()
+    ///     .with_link_service(service)
+    ///     .with_timestamp_format(TimestampFormat::EpochMillis)
+    ///     .register_module(module)?
+    ///     .build()?;
+    /// ```
+    pub fn with_timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.timestamp_format = Some(format);
+        self
+    }
+
+    /// Answer CORS preflights and tag responses for browser clients
+    ///
+    /// The layer wraps every route, so `OPTIONS` preflights are answered
+    /// before reaching any handler. Use [`CorsConfig::permissive`] during
+    /// development.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let app = ServerBuilder::new()
+    ///     .with_link_service(InMemoryLinkService::new())
+    ///     .with_cors(CorsConfig {
+    ///         allowed_origins: vec!["https://app.example.com".to_string()],
+    ///         allow_credentials: true,
+    ///         ..CorsConfig::default()
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn with_cors(mut self, config: CorsConfig) -> Self {
+        self.cors = Some(config);
+        self
+    }
+
+    /// Allow each client `requests` requests per `per`
+    ///
+    /// Clients over their limit get `429 Too Many Requests` with a
+    /// `RATE_LIMITED` error body and a `Retry-After` header. Tokens refill continuously (a token bucket), so
+    /// a client may burst up to `requests` requests after a quiet period.
+    /// See [`RateKey`] for how clients are told apart.
+    pub fn with_rate_limit(mut self, requests: u32, per: Duration, key: RateKey) -> Self {
+        self.rate_limit = Some(RateLimiter::new(requests, per, key));
+        self
+    }
+
+    /// Expose entity version history over REST
+    ///
+    /// Mounts `GET /{entity}/{id}/history` and `GET /{entity}/{id}/versions/{n}`.
+    /// Versions are written by the data services (e.g.
+    /// `PostgresDataService::with_history`), not by the server.
+    pub fn with_history_service(mut self, service: impl HistoryService + 'static) -> Self {
+        self.history_service = Some(Arc::new(service));
+        self
+    }
+
+    /// Record every REST entity and link mutation in an audit log
+    ///
+    /// Each create, update and delete appends an entry with the caller's
+    /// [`AuthContext::subject`](crate::core::AuthContext::subject) as actor
+    /// (when an auth provider is set) and JSON snapshots of the record
+    /// before and after the change.
+    pub fn with_audit_log(mut self, service: impl AuditLogService + 'static) -> Self {
+        self.audit_log = Some(Arc::new(service));
+        self
+    }
+
+    /// Choose how enriched links report entities that could not be loaded
+    ///
+    /// By default a source/target whose type has no registered
+    /// `EntityFetcher` (or whose fetch fails) is silently omitted.
+    /// [`EnrichmentFallback::ErrorMarker`] adds an `_enrichment_error` field
+    /// to the link explaining why, and [`EnrichmentFallback::IdReference`]
+    /// embeds `{"id", "type", "_enriched": false}` instead, so clients can
+    /// tell skipped enrichment apart from an entity with null fields.
+    /// [`EnrichmentFallback::Warnings`] lists the failures in a `warnings`
+    /// array next to `data` in link list responses.
+    pub fn with_enrichment_fallback(mut self, fallback: EnrichmentFallback) -> Self {
+        self.enrichment_fallback = fallback;
+        self
+    }
+
+    /// Fail `build_host` when link definitions use unregistered entity types
+    ///
+    /// Off by default. When enabled, every source and target type of a link
+    /// must have an `EntityFetcher` and an `EntityCreator` registered by some
+    /// module; otherwise startup fails with
+    /// [`ConfigError::MissingRegistrations`](crate::config::ConfigError::MissingRegistrations)
+    /// naming each type and the capability it lacks, instead of link requests
+    /// failing later with "No entity fetcher registered".
+    pub fn with_registration_validation(mut self, enabled: bool) -> Self {
+        self.validate_registrations = enabled;
+        self
+    }
+
+    /// Bound how many entity types are fetched concurrently during enrichment
+    ///
+    /// Link list responses embed the source or target entity of every link.
+    /// The entities of each type are loaded with one batched
+    /// `EntityFetcher::fetch_many_as_json` call; those batches run
+    /// concurrently, at most `concurrency` at a time (default
+    /// [`DEFAULT_ENRICHMENT_CONCURRENCY`]). `1` fetches one type after the
+    /// other. Response order is unaffected.
+    pub fn with_enrichment_concurrency(mut self, concurrency: usize) -> Self {
+        self.enrichment_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Replace how entity ids received from clients are canonicalized
+    ///
+    /// REST rewrites ids in paths and in create/update bodies to one
+    /// canonical form before handlers run, so `{AAAAAAAA-...}` and
+    /// `aaaaaaaa-...` address the same entity. The default,
+    /// [`DefaultIdNormalizer`](crate::core::DefaultIdNormalizer), accepts
+    /// braces, any case and the unhyphenated form; ids it rejects fail with
+    /// `400 Invalid entity ID format`.
+    pub fn with_id_normalizer(mut self, normalizer: impl IdNormalizer + 'static) -> Self {
+        self.id_normalizer = Some(Arc::new(normalizer));
+        self
+    }
+
+    /// Mint new entity and link ids with `strategy` instead of random UUIDv4
+    ///
+    /// [`IdStrategy::UuidV7`] makes ids sort by creation time. The strategy
+    /// is kept by the host: links created through any exposure get their id
+    /// from it, and create payloads without an `id` are given one, which
+    /// creators store like a client-supplied id. Ids supplied by clients are
+    /// kept as they are. Entities and links built directly with their
+    /// `new()` constructors keep a random UUIDv4 (see
+    /// [`new_id`](crate::core::ids::new_id)).
+    pub fn with_id_strategy(mut self, strategy: IdStrategy) -> Self {
+        self.id_strategy = Some(strategy);
+        self
+    }
+
+    /// Enforce link auth policies with this provider
+    ///
+    /// Link handlers extract the caller's [`AuthContext`](crate::core::AuthContext)
+    /// with the provider and check it against the link's `auth` policies
+    /// (`authenticated`, `role:<name>`, `owner`, ...), answering `401`/`403`
+    /// before touching the link service. Without a provider, link auth
+    /// policies are not enforced.
+    pub fn with_auth_provider(mut self, provider: impl AuthProvider + 'static) -> Self {
+        self.auth_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Scope every entity and link request to the tenant named in `header_name`
+    ///
+    /// REST requests without the header, or whose value is not a UUID, are
+    /// answered with `400 Bad Request`. The others carry a
+    /// [`TenantContext`](crate::core::TenantContext) that link listings pass
+    /// to the `*_for_tenant` service methods, so links of other tenants are
+    /// invisible; entity handlers extract it to do the same. `build_host`
+    /// fails if `header_name` is not a valid header name. See
+    /// [`DEFAULT_TENANT_HEADER`](crate::core::tenant::DEFAULT_TENANT_HEADER)
+    /// for the conventional name. The gRPC exposure is not tenant-scoped, so
+    /// `build_with_grpc` fails when tenancy is enabled.
+    pub fn with_tenancy(mut self, header_name: impl Into<String>) -> Self {
+        self.tenant_header = Some(header_name.into());
+        self
+    }
+
+    /// Log every REST request (on by default)
+    ///
+    /// Each request produces one `info` event with its method, path, status
+    /// and duration, plus the matched route and link route name when routing
+    /// resolved them. See
+    /// [`request_logging_middleware`](super::router::request_logging_middleware).
+    pub fn with_request_logging(mut self, enabled: bool) -> Self {
+        self.request_logging = enabled;
+        self
+    }
+
+    /// Size and entry lifetime of the `Idempotency-Key` cache
+    ///
+    /// POSTs to entity and link routes carrying an `Idempotency-Key` header
+    /// run once per key, path and caller; repeats within `ttl` get the first
+    /// response back instead of creating again, and a key reused with
+    /// another body is refused with `422`. The cache keeps the `size`
+    /// most recently used keys. Defaults to
+    /// [`DEFAULT_IDEMPOTENCY_CACHE_SIZE`](super::exposure::rest::idempotency::DEFAULT_IDEMPOTENCY_CACHE_SIZE)
+    /// keys for
+    /// [`DEFAULT_IDEMPOTENCY_TTL`](super::exposure::rest::idempotency::DEFAULT_IDEMPOTENCY_TTL).
+    pub fn with_idempotency_cache(mut self, size: usize, ttl: Duration) -> Self {
+        self.idempotency_cache = Some((size, ttl));
+        self
+    }
+
+    /// Bound the page size of REST list endpoints
+    ///
+    /// Requests without a `limit` (or with `limit=0`) get `default_limit`
+    /// items per page, and larger limits are capped at `max_limit`; the
+    /// response's pagination metadata reports the limit actually used.
+    /// Defaults to 20 and 100.
+    pub fn with_pagination_limits(mut self, default_limit: usize, max_limit: usize) -> Self {
+        self.pagination = Some(PaginationConfig::new(default_limit, max_limit));
+        self
+    }
+
+    /// Bound the size of request and response bodies, in bytes
+    ///
+    /// Requests with a larger body are refused with `413 Payload Too Large`,
+    /// both by the `Json` extractor of handlers and by the REST layers that
+    /// read bodies (timestamps, hooks, validation, ...). Defaults to
+    /// [`DEFAULT_MAX_BODY_SIZE`](super::body::DEFAULT_MAX_BODY_SIZE), 2 MiB.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let app = ServerBuilder::new()
+    ///     .with_link_service(InMemoryLinkService::new())
+    ///     .with_max_body_size(512 * 1024)
+    ///     .build()?;
+    /// ```
+    pub fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = Some(bytes);
+        self
+    }
+
+    /// Move the `status` of entities along with their soft deletion
+    ///
+    /// `DELETE /{entity_type}/{id}?soft=true` then sets the status to
+    /// `on_delete`, and `POST /{entity_type}/{id}/restore` reverts it to the
+    /// status the entity had before. Backends that do not keep that status
+    /// (see [`DataService::restore_with_status`](crate::core::DataService::restore_with_status))
+    /// set `on_restore` instead. Entity creators must implement
+    /// [`EntityCreator::soft_delete_with_status`](crate::core::EntityCreator::soft_delete_with_status)
+    /// and [`EntityCreator::restore_with_status`](crate::core::EntityCreator::restore_with_status).
+    ///
+    /// ```ignore
+    /// ServerBuilder::new().with_soft_delete_status("deleted", "active")
+    /// ```
+    pub fn with_soft_delete_status(
+        mut self,
+        on_delete: impl Into<String>,
+        on_restore: impl Into<String>,
+    ) -> Self {
+        self.soft_delete_status = Some(SoftDeleteStatus::new(on_delete, on_restore));
+        self
+    }
+
+    /// Let REST link routes create a link identical to a live one
+    ///
+    /// By default, creating a link whose type, source and target match a
+    /// live link fails with 409 Conflict, whatever its cardinality. Pass
+    /// `true` to store such duplicates, e.g. for links that record repeated
+    /// events. Cardinality limits still apply.
+    pub fn with_allow_duplicate_links(mut self, allow: bool) -> Self {
+        self.allow_duplicate_links = allow;
+        self
+    }
+
+    /// Serve the REST API under a path prefix, e.g. for versioning
+    ///
+    /// Every REST route moves under the prefix: health checks, the OpenAPI
+    /// document, entity, link and nested link routes, event streams and
+    /// custom routes. Introspection reports the prefixed paths. Routes
+    /// outside the prefix answer 404. gRPC services keep their paths.
+    ///
+    /// ```ignore
+    /// ServerBuilder::new().with_route_prefix("/api/v1")
+    /// ```
+    pub fn with_route_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.route_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Register backend checks for the `GET /health` readiness probe
+    ///
+    /// Every check runs on each request to `/health`, concurrently and with
+    /// a timeout of [`DEFAULT_HEALTH_CHECK_TIMEOUT`](crate::core::health::DEFAULT_HEALTH_CHECK_TIMEOUT).
+    /// If any fails, the probe answers `503 Service Unavailable` with the
+    /// status of each dependency. `GET /healthz` stays a liveness probe and
+    /// never runs them. Storage services implement
+    /// [`HealthCheck`](crate::core::HealthCheck), so they can be passed
+    /// directly:
+    ///
+    /// ```ignore
+    /// let links = Arc::new(MysqlLinkService::new(pool.clone()));
+    /// ServerBuilder::new()
+    ///     .with_health_checks(vec![links.clone(), Arc::new(redis_links)])
+    /// ```
+    pub fn with_health_checks(mut self, checks: Vec<Arc<dyn HealthCheck>>) -> Self {
+        self.health_checks.extend(checks);
+        self
+    }
+
+    /// Load link configuration from a file and reload it when it changes
+    ///
+    /// The file (YAML or JSON, by extension) is merged after the modules'
+    /// configs. `build_host` fails if it is missing or invalid, and must then
+    /// run inside a Tokio runtime: a background task checks the file every
+    /// [`DEFAULT_POLL_INTERVAL`](super::config_watch::DEFAULT_POLL_INTERVAL)
+    /// and swaps in the new config and route registry. Link routes and link
+    /// auth policies follow reloads; entity settings need a restart. See
+    /// [`config_watch`](super::config_watch).
+    pub fn with_config_watch(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config_watch = Some(path.into());
+        self
+    }
+
+    /// Run `hook` when the server shuts down
+    ///
+    /// Hooks run after [`serve`](Self::serve) stopped accepting connections
+    /// and finished the in-flight requests, one at a time in registration
+    /// order. Use them to flush state: close pools, drain the event bus. All
+    /// hooks together get [`with_shutdown_timeout`](Self::with_shutdown_timeout)
+    /// to complete; past it the remaining ones are abandoned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// ServerBuilder::new()
+    ///     .with_link_service(service)
+    ///     .on_shutdown(move || async move { pool.close().await })
+    ///     .serve("127.0.0.1:3000")
+    ///     .await?;
+    /// ```
+    pub fn on_shutdown<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.shutdown_hooks
+            .push(Box::new(move || Box::pin(hook()) as BoxFuture<'static, ()>));
+        self
+    }
+
+    /// Give the shutdown hooks `timeout` instead of [`DEFAULT_SHUTDOWN_TIMEOUT`]
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Validate entity payloads against a JSON Schema
+    ///
+    /// Create bodies for `entity_type` must satisfy the whole schema; update
+    /// bodies are checked with top-level `required` relaxed, since they are
+    /// merged into the stored entity. Violations are rejected with
+    /// `400 Bad Request` and one entry per failing field. The schema
+    /// is compiled in [`build_host`](Self::build_host), which fails with
+    /// [`ConfigError::InvalidEntitySchema`](crate::config::ConfigError::InvalidEntitySchema)
+    /// if it is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let app = ServerBuilder::new()
+    ///     .with_link_service(service)
+    ///     .with_entity_schema("order", json!({
+    ///         "type": "object",
+    ///         "required": ["number"],
+    ///         "properties": { "number": { "type": "string", "pattern": "^ORD-[0-9]+$" } }
+    ///     }))
+    ///     .register_module(module)?
+    ///     .build()?;
+    /// ```
+    #[cfg(feature = "json-schema")]
+    pub fn with_entity_schema(
+        mut self,
+        entity_type: impl Into<String>,
+        schema: serde_json::Value,
+    ) -> Self {
+        self.entity_schemas.push((entity_type.into(), schema));
+        self
+    }
+
     /// Provide a pre-built sink registry (overrides auto-wiring from config)
     ///
     /// Use this when you need full control over which sinks are registered.
@@ -184,8 +686,24 @@ impl ServerBuilder {
     ///
     /// Returns a `ServerHost` containing all framework state.
     pub fn build_host(mut self) -> Result<ServerHost> {
-        // Merge all configs
-        let merged_config = self.merge_configs()?;
+        // Merge all configs, the watched file last
+        let merged_config = match &self.config_watch {
+            Some(path) => {
+                tokio::runtime::Handle::try_current()
+                    .map_err(|_| anyhow::anyhow!("with_config_watch requires a Tokio runtime"))?;
+                load_merged(path, &self.configs)?
+            }
+            None => self.merge_configs()?,
+        };
+        merged_config.validate()?;
+
+        if self.outbox.is_some() {
+            if self.event_bus.is_none() {
+                anyhow::bail!("with_outbox requires an event bus. Call .with_event_bus()");
+            }
+            tokio::runtime::Handle::try_current()
+                .map_err(|_| anyhow::anyhow!("with_outbox requires a Tokio runtime"))?;
+        }
 
         // Extract link service
         let link_service = self
@@ -213,6 +731,112 @@ impl ServerBuilder {
             }
         }
 
+        // Events recorded by the services themselves reach the bus through
+        // the outbox poller only
+        let outbox_sources = OutboxSources {
+            entity_types: creators_map
+                .iter()
+                .filter(|(_, creator)| creator.writes_outbox())
+                .map(|(entity_type, _)| entity_type.clone())
+                .collect(),
+            links: link_service.writes_outbox(),
+        };
+        if self.outbox.is_some() && outbox_sources.is_empty() {
+            anyhow::bail!(
+                "with_outbox requires a data or link service that writes the outbox \
+                 (e.g. MysqlDataService::with_outbox)"
+            );
+        }
+
+        // Owning module of each entity type, for lifecycle hooks
+        let mut modules_map: HashMap<String, Arc<dyn Module>> = HashMap::new();
+        for module in &self.modules {
+            for entity_type in module.entity_types() {
+                modules_map.insert(entity_type.to_string(), module.clone());
+            }
+        }
+
+        if self.validate_registrations {
+            merged_config.check_registrations(|entity_type, capability| match capability {
+                EntityCapability::Fetcher => fetchers_map.contains_key(entity_type),
+                EntityCapability::Creator => creators_map.contains_key(entity_type),
+            })?;
+        }
+
+        // Mint the ids of created entities with the configured strategy;
+        // innermost, so validation sees the payload as the client sent it
+        if let Some(strategy) = self.id_strategy {
+            for creator in creators_map.values_mut() {
+                *creator = Arc::new(IdStrategyCreator::new(creator.clone(), strategy));
+            }
+        }
+
+        // Compile entity schemas and validate payloads at the creator boundary
+        #[cfg(feature = "json-schema")]
+        let entity_schemas = if self.entity_schemas.is_empty() {
+            None
+        } else {
+            let mut schemas = EntitySchemas::new();
+            for (entity_type, schema) in &self.entity_schemas {
+                schemas.insert(entity_type, schema)?;
+            }
+            let schemas = Arc::new(schemas);
+            for (entity_type, creator) in creators_map.iter_mut() {
+                if schemas.contains(entity_type) {
+                    *creator = Arc::new(SchemaValidatedCreator::new(
+                        creator.clone(),
+                        schemas.clone(),
+                        entity_type.clone(),
+                    ));
+                }
+            }
+            Some(schemas)
+        };
+
+        // Enforce field constraints at the creator boundary
+        for (entity_type, creator) in creators_map.iter_mut() {
+            let constraints = merged_config.field_constraints(entity_type);
+            if !constraints.is_empty() {
+                *creator = Arc::new(ConstrainedCreator::new(
+                    creator.clone(),
+                    Arc::new(constraints),
+                ));
+            }
+        }
+
+        // Run lifecycle hooks around creator mutations; validation above sees
+        // the payload as rewritten by `before_*` hooks
+        for (entity_type, creator) in creators_map.iter_mut() {
+            if let Some(module) = modules_map.get(entity_type) {
+                *creator = Arc::new(HookedCreator::new(
+                    creator.clone(),
+                    module.clone(),
+                    entity_type.clone(),
+                ));
+            }
+        }
+
+        // Enforce id policies at the creator boundary
+        for (entity_type, creator) in creators_map.iter_mut() {
+            let policy = merged_config.id_policy(entity_type);
+            if policy != IdPolicy::ClientOptional {
+                *creator = Arc::new(IdPolicyCreator::new(creator.clone(), policy));
+            }
+        }
+
+        // Refuse updates arriving within an entity's minimum update interval
+        for (entity_type, creator) in creators_map.iter_mut() {
+            if let Some(interval) = merged_config.min_update_interval(entity_type)
+                && let Some(fetcher) = fetchers_map.get(entity_type)
+            {
+                *creator = Arc::new(UpdateIntervalCreator::new(
+                    creator.clone(),
+                    fetcher.clone(),
+                    interval,
+                ));
+            }
+        }
+
         // Build the host
         let mut host = ServerHost::from_builder_components(
             link_service,
@@ -222,13 +846,113 @@ impl ServerBuilder {
             creators_map,
         )?;
 
+        if let Some(strategy) = self.id_strategy.take() {
+            host = host.with_id_strategy(strategy);
+        }
+
         // Attach event bus if configured
-        if let Some(event_bus) = self.event_bus.take() {
+        if let Some(mut event_bus) = self.event_bus.take() {
+            if let Some(capacity) = self.replay_buffer {
+                event_bus = event_bus.with_replay_buffer(capacity);
+            }
+            if self.outbox.is_some() {
+                event_bus = event_bus.with_outbox_sources(outbox_sources);
+            }
+            let redacted: HashMap<_, _> = host
+                .entity_fetchers
+                .iter()
+                .map(|(entity_type, fetcher)| {
+                    let fields = fetcher.redacted_fields(&AuthContext::Anonymous);
+                    (entity_type.clone(), fields)
+                })
+                .filter(|(_, fields)| !fields.is_empty())
+                .collect();
+            if !redacted.is_empty() {
+                event_bus = event_bus.with_redacted_fields(redacted);
+            }
             host = host.with_event_bus(event_bus);
         }
 
+        if let (Some(outbox), Some(event_bus)) = (self.outbox.take(), host.event_bus().cloned()) {
+            spawn_outbox_poller(outbox.clone(), event_bus, DEFAULT_OUTBOX_POLL_INTERVAL);
+            host = host.with_outbox(outbox);
+        }
+
+        host = host
+            .with_entity_modules(modules_map)
+            .with_enrichment_fallback(self.enrichment_fallback)
+            .with_enrichment_concurrency(self.enrichment_concurrency)
+            .with_request_logging(self.request_logging)
+            .with_allow_duplicate_links(self.allow_duplicate_links)
+            .with_health_checks(std::mem::take(&mut self.health_checks));
+
+        if let Some(id_normalizer) = self.id_normalizer.take() {
+            host = host.with_id_normalizer(id_normalizer);
+        }
+
+        if let Some((size, ttl)) = self.idempotency_cache.take() {
+            host = host.with_idempotency_cache(size, ttl);
+        }
+
+        if let Some(pagination) = self.pagination.take() {
+            if pagination.max_limit == 0
+                || pagination.default_limit == 0
+                || pagination.default_limit > pagination.max_limit
+            {
+                anyhow::bail!(
+                    "invalid pagination limits: default {} must be between 1 and the maximum {}",
+                    pagination.default_limit,
+                    pagination.max_limit
+                );
+            }
+            host = host.with_pagination(pagination);
+        }
+
+        if let Some(bytes) = self.max_body_size.take() {
+            host = host.with_max_body_size(bytes);
+        }
+
+        if let Some(status) = self.soft_delete_status.take() {
+            host = host.with_soft_delete_status(status);
+        }
+
+        if let Some(prefix) = self.route_prefix.take() {
+            let normalized = format!("/{}", prefix.trim_matches('/'));
+            if normalized == "/" || normalized.contains(['{', '}', '*']) {
+                anyhow::bail!(
+                    "invalid route prefix '{}': expected literal path segments such as /api/v1",
+                    prefix
+                );
+            }
+            host = host.with_route_prefix(normalized);
+        }
+
+        if let Some(auth_provider) = self.auth_provider.take() {
+            host = host.with_auth_provider(auth_provider);
+        }
+
+        if let Some(header) = self.tenant_header.take() {
+            let header = HeaderName::from_bytes(header.as_bytes())
+                .map_err(|_| anyhow::anyhow!("invalid tenant header name '{}'", header))?;
+            host = host.with_tenant_header(header);
+        }
+
+        // Attach history store if configured
+        if let Some(history_service) = self.history_service.take() {
+            host = host.with_history_service(history_service);
+        }
+
+        if let Some(audit_log) = self.audit_log.take() {
+            host = host.with_audit_log(audit_log);
+        }
+
+        #[cfg(feature = "json-schema")]
+        if let Some(schemas) = entity_schemas {
+            host = host.with_entity_schemas(schemas);
+        }
+
         // Auto-wire event pipeline from config (sinks section)
-        let has_sinks = host.config.sinks.as_ref().is_some_and(|s| !s.is_empty());
+        let has_sinks = host.config().sinks.as_ref().is_some_and(|s| !s.is_empty());
 
         if has_sinks || self.sink_registry.is_some() {
             // Build or use provided stores
@@ -248,7 +972,7 @@ impl ServerBuilder {
             // Build or use provided sink registry
             let sink_registry = if let Some(registry) = self.sink_registry.take() {
                 registry
-            } else if let Some(ref sink_configs) = host.config.sinks {
+            } else if let Some(ref sink_configs) = host.config().sinks {
                 let factory = SinkFactory::with_stores(
                     notification_store.clone(),
                     preferences_store.clone(),
@@ -270,12 +994,16 @@ impl ServerBuilder {
         }
 
         // Auto-wire event log if events section is present
-        if host.config.events.is_some() {
+        if host.config().events.is_some() {
             let event_log = Arc::new(crate::events::InMemoryEventLog::new());
             host = host.with_event_log(event_log);
             tracing::info!("event log auto-wired (InMemoryEventLog)");
         }
 
+        if let Some(path) = self.config_watch.take() {
+            ConfigWatcher::new(path, self.configs, host.link_tables().clone()).spawn();
+        }
+
         Ok(host)
     }
 
@@ -290,8 +1018,17 @@ impl ServerBuilder {
     /// exposes it via REST. For other exposure types, use `build_host_arc()`.
     pub fn build(mut self) -> Result<Router> {
         let custom_routes = std::mem::take(&mut self.custom_routes);
+        let timestamp_format = self.timestamp_format;
+        let cors = self.cors.take();
+        let rate_limit = self.rate_limit.take();
         let host = Arc::new(self.build_host()?);
-        RestExposure::build_router(host, custom_routes)
+        let body_limit = BodyLimit(host.max_body_size);
+        let router = RestExposure::build_router(host, custom_routes)?;
+        let router = with_rate_limit_layer(
+            with_timestamp_layer(router, timestamp_format, body_limit),
+            rate_limit,
+        );
+        with_cors_layer(router, cors)
     }
 
     /// Merge all configurations from registered modules
@@ -326,12 +1063,21 @@ impl ServerBuilder {
         use super::router::combine_rest_and_grpc;
 
         let custom_routes = std::mem::take(&mut self.custom_routes);
+        let timestamp_format = self.timestamp_format;
+        let cors = self.cors.take();
+        let rate_limit = self.rate_limit.take();
         let host = Arc::new(self.build_host()?);
 
-        let rest_router = RestExposure::build_router(host.clone(), custom_routes)?;
+        let rest_router = with_timestamp_layer(
+            RestExposure::build_router(host.clone(), custom_routes)?,
+            timestamp_format,
+            BodyLimit(host.max_body_size),
+        );
         let grpc_router = GrpcExposure::build_router_no_fallback(host)?;
 
-        Ok(combine_rest_and_grpc(rest_router, grpc_router))
+        let router =
+            with_rate_limit_layer(combine_rest_and_grpc(rest_router, grpc_router), rate_limit);
+        with_cors_layer(router, cors)
     }
 
     /// Serve the application with graceful shutdown
@@ -350,15 +1096,34 @@ impl ServerBuilder {
     ///     .serve("127.0.0.1:3000").await?;
     /// ```
     pub async fn serve(self, addr: &str) -> Result<()> {
-        let app = self.build()?;
         let listener = TcpListener::bind(addr).await?;
 
         tracing::info!("Server listening on {}", addr);
 
-        axum::serve(listener, app)
-            .with_graceful_shutdown(shutdown_signal())
-            .await?;
+        self.serve_with_shutdown(listener, shutdown_signal()).await
+    }
+
+    /// Serve on `listener` until `signal` completes, then run the shutdown hooks
+    ///
+    /// [`serve`](Self::serve) with a caller-chosen trigger instead of
+    /// SIGTERM/Ctrl+C, e.g. a cancellation token.
+    pub async fn serve_with_shutdown(
+        mut self,
+        listener: TcpListener,
+        signal: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<()> {
+        let hooks = std::mem::take(&mut self.shutdown_hooks);
+        let timeout = self.shutdown_timeout;
+        let app = self.build()?;
+
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(signal)
+        .await?;
 
+        run_shutdown_hooks(hooks, timeout).await;
         tracing::info!("Server shutdown complete");
         Ok(())
     }
@@ -377,16 +1142,22 @@ impl ServerBuilder {
     ///     .serve_with_grpc("127.0.0.1:3000").await?;
     /// ```
     #[cfg(feature = "grpc")]
-    pub async fn serve_with_grpc(self, addr: &str) -> Result<()> {
+    pub async fn serve_with_grpc(mut self, addr: &str) -> Result<()> {
+        let hooks = std::mem::take(&mut self.shutdown_hooks);
+        let timeout = self.shutdown_timeout;
         let app = self.build_with_grpc()?;
         let listener = TcpListener::bind(addr).await?;
 
         tracing::info!("Server listening on {} (REST + gRPC)", addr);
 
-        axum::serve(listener, app)
-            .with_graceful_shutdown(shutdown_signal())
-            .await?;
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
 
+        run_shutdown_hooks(hooks, timeout).await;
         tracing::info!("Server shutdown complete");
         Ok(())
     }
@@ -398,6 +1169,67 @@ impl Default for ServerBuilder {
     }
 }
 
+/// Install the timestamp rewriting layer when a format was configured
+///
+/// It sits outside the REST exposure, so it gets the body limit on its own.
+fn with_timestamp_layer(
+    router: Router,
+    format: Option<TimestampFormat>,
+    limit: BodyLimit,
+) -> Router {
+    match format {
+        Some(format) => router
+            .layer(axum::middleware::from_fn_with_state(
+                format,
+                timestamp_middleware,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                limit,
+                body_limit_middleware,
+            )),
+        None => router,
+    }
+}
+
+/// Install the rate limiter when one was configured
+fn with_rate_limit_layer(router: Router, limiter: Option<RateLimiter>) -> Router {
+    match limiter {
+        Some(limiter) => router.layer(axum::middleware::from_fn_with_state(
+            Arc::new(limiter),
+            rate_limit_middleware,
+        )),
+        None => router,
+    }
+}
+
+/// Wrap the whole router in the CORS layer when one was configured
+fn with_cors_layer(router: Router, config: Option<CorsConfig>) -> Result<Router> {
+    Ok(match config {
+        Some(config) => router.layer(config.into_layer()?),
+        None => router,
+    })
+}
+
+/// Run `hooks` in order, giving up on the rest once `timeout` elapsed
+async fn run_shutdown_hooks(hooks: Vec<ShutdownHook>, timeout: Duration) {
+    if hooks.is_empty() {
+        return;
+    }
+    let count = hooks.len();
+    let run_all = async {
+        for hook in hooks {
+            hook().await;
+        }
+    };
+    if tokio::time::timeout(timeout, run_all).await.is_err() {
+        tracing::warn!(
+            hooks = count,
+            timeout_secs = timeout.as_secs_f64(),
+            "shutdown hooks did not finish in time, exiting anyway"
+        );
+    }
+}
+
 /// Wait for shutdown signal (SIGTERM or Ctrl+C)
 async fn shutdown_signal() {
     use tokio::signal;
@@ -435,6 +1267,7 @@ mod tests {
     use crate::config::{EntityAuthConfig, EntityConfig, LinksConfig};
     use crate::core::LinkDefinition;
     use crate::core::module::Module;
+    use crate::server::body::DEFAULT_MAX_BODY_SIZE;
     use crate::server::entity_registry::EntityRegistry;
     use crate::storage::InMemoryLinkService;
     use std::sync::Arc;
@@ -458,11 +1291,15 @@ mod tests {
                         singular: "order".to_string(),
                         plural: "orders".to_string(),
                         auth: EntityAuthConfig::default(),
+                        id_policy: Default::default(),
+                        fields: Default::default(),
+                        min_update_interval: None,
                     }],
                     links: vec![],
                     validation_rules: None,
                     events: None,
                     sinks: None,
+                    pluralization: None,
                 },
             }
         }
@@ -477,11 +1314,17 @@ mod tests {
                             singular: "user".to_string(),
                             plural: "users".to_string(),
                             auth: EntityAuthConfig::default(),
+                            id_policy: Default::default(),
+                            fields: Default::default(),
+                            min_update_interval: None,
                         },
                         EntityConfig {
                             singular: "car".to_string(),
                             plural: "cars".to_string(),
                             auth: EntityAuthConfig::default(),
+                            id_policy: Default::default(),
+                            fields: Default::default(),
+                            min_update_interval: None,
                         },
                     ],
                     links: vec![LinkDefinition {
@@ -492,62 +1335,215 @@ mod tests {
                         reverse_route_name: "users-owners".to_string(),
                         description: Some("User owns a car".to_string()),
                         required_fields: None,
+                        required_fields_forward: None,
+                        required_fields_reverse: None,
+                        symmetric: false,
                         auth: None,
+                        cardinality: None,
+                        metadata_schema: None,
                     }],
                     validation_rules: None,
                     events: None,
                     sinks: None,
+                    pluralization: None,
                 },
             }
         }
     }
 
-    impl Module for StubModule {
-        fn name(&self) -> &str {
-            self.name
-        }
+    impl Module for StubModule {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn entity_types(&self) -> Vec<&str> {
+            self.entity_types.clone()
+        }
+
+        fn links_config(&self) -> anyhow::Result<LinksConfig> {
+            Ok(self.config.clone())
+        }
+
+        fn register_entities(&self, _registry: &mut EntityRegistry) {
+            // No entity descriptors in stub
+        }
+
+        fn get_entity_fetcher(
+            &self,
+            _entity_type: &str,
+        ) -> Option<Arc<dyn crate::core::EntityFetcher>> {
+            None
+        }
+
+        fn get_entity_creator(
+            &self,
+            _entity_type: &str,
+        ) -> Option<Arc<dyn crate::core::EntityCreator>> {
+            None
+        }
+    }
+
+    /// A module whose links_config() returns an error
+    struct FailingModule;
+
+    impl Module for FailingModule {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        fn entity_types(&self) -> Vec<&str> {
+            vec![]
+        }
+
+        fn links_config(&self) -> anyhow::Result<LinksConfig> {
+            Err(anyhow::anyhow!("config load failed"))
+        }
+
+        fn register_entities(&self, _registry: &mut EntityRegistry) {}
+
+        fn get_entity_fetcher(
+            &self,
+            _entity_type: &str,
+        ) -> Option<Arc<dyn crate::core::EntityFetcher>> {
+            None
+        }
+
+        fn get_entity_creator(
+            &self,
+            _entity_type: &str,
+        ) -> Option<Arc<dyn crate::core::EntityCreator>> {
+            None
+        }
+    }
+
+    // ── Constructor tests ────────────────────────────────────────────────
+
+    #[test]
+    fn test_new_creates_empty_builder() {
+        let builder = ServerBuilder::new();
+        assert!(builder.link_service.is_none());
+        assert!(builder.configs.is_empty());
+        assert!(builder.modules.is_empty());
+        assert!(builder.custom_routes.is_empty());
+        assert!(builder.event_bus.is_none());
+    }
+
+    #[test]
+    fn test_default_is_same_as_new() {
+        let builder = ServerBuilder::default();
+        assert!(builder.link_service.is_none());
+        assert!(builder.configs.is_empty());
+        assert!(builder.modules.is_empty());
+        assert!(builder.custom_routes.is_empty());
+        assert!(builder.event_bus.is_none());
+    }
+
+    // ── with_link_service ────────────────────────────────────────────────
+
+    #[test]
+    fn test_with_link_service_sets_service() {
+        let builder = ServerBuilder::new().with_link_service(InMemoryLinkService::new());
+        assert!(builder.link_service.is_some());
+    }
+
+    // ── with_event_bus ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_with_event_bus_sets_bus() {
+        let builder = ServerBuilder::new().with_event_bus(1024);
+        assert!(builder.event_bus.is_some());
+    }
+
+    // ── with_pagination_limits ───────────────────────────────────────────
+
+    #[test]
+    fn test_with_pagination_limits_attaches_to_host() {
+        let host = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_pagination_limits(10, 50)
+            .build_host()
+            .unwrap();
+        assert_eq!(host.pagination, PaginationConfig::new(10, 50));
+    }
+
+    #[test]
+    fn test_with_pagination_limits_rejects_default_above_max() {
+        let result = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_pagination_limits(100, 50)
+            .build_host();
+        assert!(result.is_err());
+    }
+
+    // ── with_max_body_size ───────────────────────────────────────────────
 
-        fn entity_types(&self) -> Vec<&str> {
-            self.entity_types.clone()
-        }
+    #[test]
+    fn test_with_max_body_size_attaches_to_host() {
+        let host = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .build_host()
+            .unwrap();
+        assert_eq!(host.max_body_size, DEFAULT_MAX_BODY_SIZE);
 
-        fn links_config(&self) -> anyhow::Result<LinksConfig> {
-            Ok(self.config.clone())
-        }
+        let host = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_max_body_size(1024)
+            .build_host()
+            .unwrap();
+        assert_eq!(host.max_body_size, 1024);
+    }
 
-        fn register_entities(&self, _registry: &mut EntityRegistry) {
-            // No entity descriptors in stub
-        }
+    // ── with_outbox ──────────────────────────────────────────────────────
 
-        fn get_entity_fetcher(
+    #[test]
+    fn test_with_outbox_requires_event_bus() {
+        let result = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_outbox(crate::storage::InMemoryOutboxService::new())
+            .build_host();
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_with_outbox_requires_a_service_writing_it() {
+        let result = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_event_bus(16)
+            .with_outbox(crate::storage::InMemoryOutboxService::new())
+            .build_host();
+        assert!(result.is_err());
+    }
+
+    /// Registers `order` with a creator recording its events in the outbox
+    struct OutboxModule(StubModule);
+
+    struct OutboxCreator;
+
+    #[async_trait::async_trait]
+    impl crate::core::EntityCreator for OutboxCreator {
+        async fn create_from_json(
             &self,
-            _entity_type: &str,
-        ) -> Option<Arc<dyn crate::core::EntityFetcher>> {
-            None
+            entity_data: serde_json::Value,
+        ) -> anyhow::Result<serde_json::Value> {
+            Ok(entity_data)
         }
 
-        fn get_entity_creator(
-            &self,
-            _entity_type: &str,
-        ) -> Option<Arc<dyn crate::core::EntityCreator>> {
-            None
+        fn writes_outbox(&self) -> bool {
+            true
         }
     }
 
-    /// A module whose links_config() returns an error
-    struct FailingModule;
-
-    impl Module for FailingModule {
+    impl Module for OutboxModule {
         fn name(&self) -> &str {
-            "failing"
+            "outbox"
         }
 
         fn entity_types(&self) -> Vec<&str> {
-            vec![]
+            self.0.entity_types()
         }
 
         fn links_config(&self) -> anyhow::Result<LinksConfig> {
-            Err(anyhow::anyhow!("config load failed"))
+            self.0.links_config()
         }
 
         fn register_entities(&self, _registry: &mut EntityRegistry) {}
@@ -563,46 +1559,162 @@ mod tests {
             &self,
             _entity_type: &str,
         ) -> Option<Arc<dyn crate::core::EntityCreator>> {
-            None
+            Some(Arc::new(OutboxCreator))
         }
     }
 
-    // ── Constructor tests ────────────────────────────────────────────────
+    #[tokio::test]
+    async fn test_with_outbox_only_drops_events_the_services_record() {
+        use crate::core::events::{EntityEvent, FrameworkEvent};
 
-    #[test]
-    fn test_new_creates_empty_builder() {
-        let builder = ServerBuilder::new();
-        assert!(builder.link_service.is_none());
-        assert!(builder.configs.is_empty());
-        assert!(builder.modules.is_empty());
-        assert!(builder.custom_routes.is_empty());
-        assert!(builder.event_bus.is_none());
+        let host = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .register_module(OutboxModule(StubModule::single_entity()))
+            .unwrap()
+            .with_event_bus(16)
+            .with_outbox(crate::storage::InMemoryOutboxService::new())
+            .build_host()
+            .unwrap();
+        assert!(host.outbox().is_some());
+
+        let bus = host.event_bus().unwrap();
+        let sources = bus.outbox_sources().unwrap();
+        assert!(sources.entity_types.contains("order"));
+        assert!(!sources.links);
+
+        let _rx = bus.subscribe();
+        let deleted = |entity_type: &str| {
+            FrameworkEvent::Entity(EntityEvent::Deleted {
+                entity_type: entity_type.to_string(),
+                entity_id: uuid::Uuid::new_v4(),
+            })
+        };
+        assert_eq!(bus.publish(deleted("order")), 0);
+        assert_eq!(bus.publish(deleted("invoice")), 1);
     }
 
+    // ── with_history_service ─────────────────────────────────────────────
+
     #[test]
-    fn test_default_is_same_as_new() {
-        let builder = ServerBuilder::default();
-        assert!(builder.link_service.is_none());
-        assert!(builder.configs.is_empty());
-        assert!(builder.modules.is_empty());
-        assert!(builder.custom_routes.is_empty());
-        assert!(builder.event_bus.is_none());
+    fn test_with_history_service_attaches_to_host() {
+        let host = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_history_service(crate::storage::InMemoryHistoryService::new())
+            .build_host()
+            .expect("build_host should succeed");
+        assert!(host.history_service().is_some());
+
+        // History routes must merge cleanly with the generic link routes
+        let router = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_history_service(crate::storage::InMemoryHistoryService::new())
+            .build();
+        assert!(router.is_ok());
     }
 
-    // ── with_link_service ────────────────────────────────────────────────
+    // ── with_timestamp_format ────────────────────────────────────────────
 
     #[test]
-    fn test_with_link_service_sets_service() {
-        let builder = ServerBuilder::new().with_link_service(InMemoryLinkService::new());
-        assert!(builder.link_service.is_some());
+    fn test_with_timestamp_format_sets_format() {
+        let builder = ServerBuilder::new();
+        assert!(builder.timestamp_format.is_none());
+
+        let builder = builder.with_timestamp_format(TimestampFormat::EpochMillis);
+        assert_eq!(builder.timestamp_format, Some(TimestampFormat::EpochMillis));
     }
 
-    // ── with_event_bus ───────────────────────────────────────────────────
+    // ── with_cors ────────────────────────────────────────────────────────
 
-    #[test]
-    fn test_with_event_bus_sets_bus() {
-        let builder = ServerBuilder::new().with_event_bus(1024);
-        assert!(builder.event_bus.is_some());
+    #[tokio::test]
+    async fn test_cors_preflight_is_answered_before_the_handlers() {
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tower::ServiceExt;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let router = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_custom_routes(Router::new().route(
+                "/ping",
+                axum::routing::any(move || async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    "pong"
+                }),
+            ))
+            .with_cors(CorsConfig {
+                allowed_origins: vec!["https://app.example.com".to_string()],
+                ..CorsConfig::default()
+            })
+            .build()
+            .expect("build should succeed");
+
+        let preflight = Request::options("/ping")
+            .header("origin", "https://app.example.com")
+            .header("access-control-request-method", "POST")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(preflight).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["access-control-allow-origin"],
+            "https://app.example.com"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        // Other origins get no grant
+        let request = Request::get("/ping")
+            .header("origin", "https://evil.example.com")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert!(
+            !response
+                .headers()
+                .contains_key("access-control-allow-origin")
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    // ── with_rate_limit ──────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_rate_limit_rejects_the_request_over_the_limit() {
+        use axum::body::{Body, to_bytes};
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let router = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_rate_limit(
+                3,
+                Duration::from_secs(60),
+                RateKey::Header("x-api-key".to_string()),
+            )
+            .build()
+            .expect("build should succeed");
+        let send = |key: &'static str| {
+            let request = Request::get("/healthz")
+                .header("x-api-key", key)
+                .body(Body::empty())
+                .unwrap();
+            router.clone().oneshot(request)
+        };
+
+        for _ in 0..3 {
+            assert_eq!(send("alice").await.unwrap().status(), StatusCode::OK);
+        }
+        let response = send("alice").await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()["retry-after"], "20");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "RATE_LIMITED");
+        assert_eq!(body["message"], "Too many requests");
+
+        // Another key has its own budget
+        assert_eq!(send("bob").await.unwrap().status(), StatusCode::OK);
     }
 
     // ── with_custom_routes ───────────────────────────────────────────────
@@ -675,9 +1787,188 @@ mod tests {
             .build_host()
             .expect("build_host should succeed");
 
-        assert_eq!(host.config.entities.len(), 1);
-        assert_eq!(host.config.entities[0].singular, "order");
+        assert_eq!(host.config().entities.len(), 1);
+        assert_eq!(host.config().entities[0].singular, "order");
         assert!(host.event_bus.is_none());
+        assert!(host.request_logging);
+    }
+
+    #[test]
+    fn test_with_request_logging_can_turn_the_log_off() {
+        let host = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_request_logging(false)
+            .build_host()
+            .expect("build_host should succeed");
+        assert!(!host.request_logging);
+    }
+
+    #[test]
+    fn test_with_allow_duplicate_links_reaches_the_host() {
+        let builder = || ServerBuilder::new().with_link_service(InMemoryLinkService::new());
+        assert!(!builder().build_host().unwrap().allow_duplicate_links);
+
+        let host = builder()
+            .with_allow_duplicate_links(true)
+            .build_host()
+            .expect("build_host should succeed");
+        assert!(host.allow_duplicate_links);
+    }
+
+    #[tokio::test]
+    async fn test_route_prefix_moves_every_route_under_it() {
+        use crate::core::link::LinkEntity;
+        use axum::body::{Body, to_bytes};
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let links = InMemoryLinkService::new();
+        let (user, car) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        links
+            .create(LinkEntity::new("owner", user, car, None))
+            .await
+            .unwrap();
+        let router = ServerBuilder::new()
+            .with_link_service(links)
+            .register_module(StubModule::with_link())
+            .expect("register should succeed")
+            .with_route_prefix("/api/v1/")
+            .build()
+            .expect("build should succeed");
+        let get = |uri: String| async {
+            let response = router
+                .clone()
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, serde_json::from_slice(&body).unwrap_or_default())
+        };
+
+        let (status, _) = get("/api/v1/health".to_string()).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = get("/health".to_string()).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body): (_, serde_json::Value) =
+            get(format!("/api/v1/users/{user}/links")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body["available_routes"][0]["path"],
+            format!("/api/v1/users/{user}/cars-owned")
+        );
+
+        // Deeper paths reach the nested link handler, which parses them unprefixed
+        let (status, _) = get(format!(
+            "/api/v1/users/{user}/cars-owned/{car}/users-owners"
+        ))
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[test]
+    fn test_route_prefix_must_name_a_path() {
+        for prefix in ["/", "", "/api/{version}"] {
+            let result = ServerBuilder::new()
+                .with_link_service(InMemoryLinkService::new())
+                .with_route_prefix(prefix)
+                .build_host();
+            assert!(result.is_err(), "prefix {prefix:?} should be rejected");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_module_layers_wrap_the_module_entity_routes() {
+        use crate::core::BoxedLayer;
+        use crate::server::entity_registry::EntityDescriptor;
+        use axum::body::Body;
+        use axum::extract::Request;
+        use axum::http::{HeaderValue, StatusCode};
+        use axum::middleware::Next;
+        use tower::ServiceExt;
+
+        struct OrderDescriptor;
+
+        impl EntityDescriptor for OrderDescriptor {
+            fn entity_type(&self) -> &str {
+                "order"
+            }
+
+            fn plural(&self) -> &str {
+                "orders"
+            }
+
+            fn build_routes(&self) -> Router {
+                Router::new().route("/orders", axum::routing::get(|| async { "[]" }))
+            }
+        }
+
+        /// Registers `order` and tags its responses with `x-module`
+        struct BillingModule(StubModule);
+
+        impl Module for BillingModule {
+            fn name(&self) -> &str {
+                "billing"
+            }
+
+            fn entity_types(&self) -> Vec<&str> {
+                self.0.entity_types()
+            }
+
+            fn links_config(&self) -> anyhow::Result<LinksConfig> {
+                self.0.links_config()
+            }
+
+            fn register_entities(&self, registry: &mut EntityRegistry) {
+                registry.register(Box::new(OrderDescriptor));
+            }
+
+            fn get_entity_fetcher(
+                &self,
+                _entity_type: &str,
+            ) -> Option<Arc<dyn crate::core::EntityFetcher>> {
+                None
+            }
+
+            fn get_entity_creator(
+                &self,
+                _entity_type: &str,
+            ) -> Option<Arc<dyn crate::core::EntityCreator>> {
+                None
+            }
+
+            fn layers(&self) -> Vec<BoxedLayer> {
+                vec![BoxedLayer::new(axum::middleware::from_fn(
+                    |request: Request, next: Next| async move {
+                        let mut response = next.run(request).await;
+                        response
+                            .headers_mut()
+                            .insert("x-module", HeaderValue::from_static("billing"));
+                        response
+                    },
+                ))]
+            }
+        }
+
+        let router = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .register_module(BillingModule(StubModule::single_entity()))
+            .expect("register should succeed")
+            .build()
+            .expect("build should succeed");
+        let get = |uri: &str| {
+            router
+                .clone()
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        };
+
+        let response = get("/orders").await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-module"], "billing");
+
+        let response = get("/health").await.unwrap();
+        assert!(!response.headers().contains_key("x-module"));
     }
 
     #[test]
@@ -692,8 +1983,8 @@ mod tests {
             .expect("build_host should succeed");
 
         // Merged config should contain entities from both modules
-        let entity_names: Vec<&str> = host
-            .config
+        let config = host.config();
+        let entity_names: Vec<&str> = config
             .entities
             .iter()
             .map(|e| e.singular.as_str())
@@ -703,6 +1994,70 @@ mod tests {
         assert!(entity_names.contains(&"car"), "should contain car");
     }
 
+    #[test]
+    fn test_build_host_rejects_invalid_link_definition() {
+        let mut module = StubModule::with_link();
+        module.config.links[0].symmetric = true;
+
+        let err = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .register_module(module)
+            .expect("register should succeed")
+            .build_host()
+            .err()
+            .expect("build_host should fail");
+        assert!(matches!(
+            err.downcast_ref::<crate::config::ConfigError>(),
+            Some(crate::config::ConfigError::InvalidLinkDefinition { .. })
+        ));
+    }
+
+    #[test]
+    fn test_build_host_validates_registrations_when_enabled() {
+        let build = |enabled| {
+            ServerBuilder::new()
+                .with_link_service(InMemoryLinkService::new())
+                .with_registration_validation(enabled)
+                .register_module(StubModule::with_link())
+                .expect("register should succeed")
+                .build_host()
+        };
+        assert!(build(false).is_ok(), "validation is opt-in");
+
+        let err = build(true).err().expect("build_host should fail");
+        let Some(crate::config::ConfigError::MissingRegistrations(missing)) =
+            err.downcast_ref::<crate::config::ConfigError>()
+        else {
+            panic!("unexpected error: {err}");
+        };
+        assert_eq!(missing.len(), 4, "user and car each lack both capabilities");
+        assert!(missing.iter().all(|m| m.link_type == "owner"));
+    }
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn test_build_host_compiles_entity_schemas() {
+        let host = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_entity_schema("order", serde_json::json!({"type": "object"}))
+            .register_module(StubModule::single_entity())
+            .expect("register should succeed")
+            .build_host()
+            .expect("build_host should succeed");
+        assert!(host.entity_schemas.as_ref().unwrap().contains("order"));
+
+        let err = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_entity_schema("order", serde_json::json!({"type": 42}))
+            .build_host()
+            .err()
+            .expect("build_host should fail");
+        assert!(matches!(
+            err.downcast_ref::<crate::config::ConfigError>(),
+            Some(crate::config::ConfigError::InvalidEntitySchema { .. })
+        ));
+    }
+
     #[test]
     fn test_build_host_with_event_bus_attaches_bus() {
         let host = ServerBuilder::new()
@@ -716,6 +2071,27 @@ mod tests {
         assert!(host.event_bus().is_some());
     }
 
+    #[test]
+    fn test_build_host_with_replay_buffer_numbers_events() {
+        let host = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_replay_buffer(4)
+            .with_event_bus(16)
+            .register_module(StubModule::single_entity())
+            .expect("register should succeed")
+            .build_host()
+            .expect("build_host should succeed");
+
+        let bus = host.event_bus().unwrap();
+        bus.publish(crate::core::events::FrameworkEvent::Entity(
+            crate::core::events::EntityEvent::Deleted {
+                entity_type: "order".to_string(),
+                entity_id: uuid::Uuid::new_v4(),
+            },
+        ));
+        assert_eq!(bus.replay_since(0)[0].seq_no, Some(1));
+    }
+
     #[test]
     fn test_build_host_no_modules_empty_config() {
         let host = ServerBuilder::new()
@@ -723,8 +2099,8 @@ mod tests {
             .build_host()
             .expect("build_host with no modules should succeed");
 
-        assert!(host.config.entities.is_empty());
-        assert!(host.config.links.is_empty());
+        assert!(host.config().entities.is_empty());
+        assert!(host.config().links.is_empty());
     }
 
     // ── build (REST router) ──────────────────────────────────────────────
@@ -804,6 +2180,9 @@ mod tests {
                     singular: "user".to_string(),
                     plural: "users".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 }],
                 links: vec![],
                 validation_rules: None,
@@ -813,6 +2192,7 @@ mod tests {
                     sink_type: SinkType::InApp,
                     config: Default::default(),
                 }]),
+                pluralization: None,
             })
         }
 
@@ -928,6 +2308,174 @@ mod tests {
         assert!(host.event_bus().is_some());
         assert!(host.sink_registry().is_none());
         assert!(host.event_log().is_none());
-        assert_eq!(host.config.entities.len(), 1);
+        assert_eq!(host.config().entities.len(), 1);
+    }
+
+    // ── Config watch ─────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_config_watch_serves_routes_added_to_the_file() {
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("links.yaml");
+        std::fs::write(&path, "entities: []\nlinks: []\n").unwrap();
+
+        let router = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .register_module(StubModule::with_link())
+            .expect("register should succeed")
+            .with_config_watch(&path)
+            .build()
+            .expect("build should succeed");
+        let get_driven = || {
+            router.clone().oneshot(
+                Request::get(format!("/users/{}/cars-driven", uuid::Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+        };
+        assert_eq!(get_driven().await.unwrap().status(), StatusCode::NOT_FOUND);
+
+        std::fs::write(
+            &path,
+            r#"
+entities: []
+links:
+  - link_type: driver
+    source_type: user
+    target_type: car
+    forward_route_name: cars-driven
+    reverse_route_name: users-drivers
+"#,
+        )
+        .unwrap();
+        tokio::time::sleep(crate::server::config_watch::DEFAULT_POLL_INTERVAL * 2).await;
+
+        assert_eq!(get_driven().await.unwrap().status(), StatusCode::OK);
+    }
+
+    // ── Shutdown hooks ───────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_shutdown_hooks_run_in_order_after_cancellation() {
+        use std::sync::Mutex;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use tokio_util::sync::CancellationToken;
+
+        let flushed = Arc::new(AtomicBool::new(false));
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let token = CancellationToken::new();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+        let builder = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .on_shutdown({
+                let (flushed, order) = (flushed.clone(), order.clone());
+                move || async move {
+                    tokio::task::yield_now().await;
+                    flushed.store(true, Ordering::SeqCst);
+                    order.lock().unwrap().push("flush");
+                }
+            })
+            .on_shutdown({
+                let order = order.clone();
+                move || async move { order.lock().unwrap().push("close") }
+            });
+        let server =
+            tokio::spawn(builder.serve_with_shutdown(listener, token.clone().cancelled_owned()));
+
+        assert!(!flushed.load(Ordering::SeqCst));
+        token.cancel();
+        server.await.unwrap().expect("serve should succeed");
+
+        assert!(flushed.load(Ordering::SeqCst));
+        assert_eq!(*order.lock().unwrap(), ["flush", "close"]);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_hooks_are_abandoned_after_the_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let serve = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .on_shutdown(std::future::pending)
+            .with_shutdown_timeout(Duration::from_millis(20))
+            .serve_with_shutdown(listener, async {});
+
+        tokio::time::timeout(Duration::from_secs(5), serve)
+            .await
+            .expect("a stuck hook must not block the exit")
+            .expect("serve should succeed");
+    }
+
+    // ── Tenancy ──────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_tenancy_hides_links_of_other_tenants() {
+        use crate::core::link::LinkEntity;
+        use axum::body::{Body, to_bytes};
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let links = InMemoryLinkService::new();
+        let (tenant_a, tenant_b) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let (user, car_a) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        for (tenant, car) in [(tenant_a, car_a), (tenant_b, uuid::Uuid::new_v4())] {
+            links
+                .create(LinkEntity::new_with_tenant(
+                    tenant, "owner", user, car, None,
+                ))
+                .await
+                .unwrap();
+        }
+
+        let router = ServerBuilder::new()
+            .with_link_service(links)
+            .register_module(StubModule::with_link())
+            .expect("register should succeed")
+            .with_tenancy("x-tenant-id")
+            .build()
+            .expect("build should succeed");
+        let list = |tenant: Option<uuid::Uuid>| {
+            let mut request = Request::get(format!("/users/{user}/cars-owned"));
+            if let Some(tenant) = tenant {
+                request = request.header("x-tenant-id", tenant.to_string());
+            }
+            router.clone().oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        assert_eq!(list(None).await.unwrap().status(), StatusCode::BAD_REQUEST);
+
+        let response = list(Some(tenant_a)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let data = body["data"].as_array().unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0]["target_id"], car_a.to_string());
+    }
+
+    #[test]
+    fn test_tenancy_with_invalid_header_name_fails() {
+        let result = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_tenancy("tenant id")
+            .build_host();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_config_watch_outside_runtime_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("links.yaml");
+        std::fs::write(&path, "links: []\n").unwrap();
+
+        let result = ServerBuilder::new()
+            .with_link_service(InMemoryLinkService::new())
+            .with_config_watch(&path)
+            .build_host();
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,175 @@
+//! Hot reload of the link configuration
+//!
+//! With [`ServerBuilder::with_config_watch`](crate::server::ServerBuilder::with_config_watch),
+//! a config file is merged after the module configs at startup and then
+//! watched: whenever its content changes it is reparsed (format picked from
+//! the extension, see [`LinksConfig::from_file`]), merged again, validated,
+//! and swapped into the host together with a fresh [`LinkRouteRegistry`].
+//! Readers always see a matching config/registry pair.
+//!
+//! A config that fails to parse or validate is logged and ignored; the last
+//! good one stays live.
+//!
+//! Only what is read per request follows a reload: link routes, link
+//! definitions and link auth policies. Entity-level settings (auth, field
+//! constraints, id policies, update intervals) are applied when the router is
+//! built and need a restart.
+
+use crate::config::LinksConfig;
+use crate::links::registry::LinkRouteRegistry;
+use anyhow::Result;
+use arc_swap::ArcSwap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often a watched config file is checked for changes
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A link configuration and the route registry built from it
+pub struct LinkTables {
+    pub config: Arc<LinksConfig>,
+    pub registry: Arc<LinkRouteRegistry>,
+}
+
+impl LinkTables {
+    pub fn new(config: LinksConfig) -> Self {
+        let config = Arc::new(config);
+        let registry = Arc::new(LinkRouteRegistry::new(config.clone()));
+        Self { config, registry }
+    }
+}
+
+/// Watches a config file and swaps reloaded configs into [`LinkTables`]
+pub struct ConfigWatcher {
+    path: PathBuf,
+    /// Configs merged before the watched file (the modules' own)
+    base: Vec<LinksConfig>,
+    tables: Arc<ArcSwap<LinkTables>>,
+    poll_interval: Duration,
+}
+
+impl ConfigWatcher {
+    pub fn new(
+        path: impl Into<PathBuf>,
+        base: Vec<LinksConfig>,
+        tables: Arc<ArcSwap<LinkTables>>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            base,
+            tables,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Check the file every `interval` instead of [`DEFAULT_POLL_INTERVAL`]
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Read the watched file and merge it after the base configs
+    pub fn load(&self) -> Result<LinksConfig> {
+        load_merged(&self.path, &self.base)
+    }
+
+    /// Start watching on the current Tokio runtime
+    ///
+    /// The file content at this point counts as already loaded. The task ends
+    /// once nothing but the watcher holds the tables (the host was dropped).
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        // Read now rather than in the task, which may first run after a change
+        let mut last = std::fs::read(&self.path).ok();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.poll_interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                if Arc::strong_count(&self.tables) == 1 {
+                    break;
+                }
+                let current = tokio::fs::read(&self.path).await.ok();
+                if current.is_none() || current == last {
+                    continue;
+                }
+                last = current;
+
+                match self.load() {
+                    Ok(config) => {
+                        self.tables.store(Arc::new(LinkTables::new(config)));
+                        tracing::info!(path = %self.path.display(), "link configuration reloaded");
+                    }
+                    Err(e) => {
+                        tracing::error!(
+                            path = %self.path.display(),
+                            error = %e,
+                            "invalid link configuration, keeping the previous one"
+                        );
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Merge the config file at `path` after `base` and validate the result
+pub(crate) fn load_merged(path: &Path, base: &[LinksConfig]) -> Result<LinksConfig> {
+    let mut configs = base.to_vec();
+    configs.push(LinksConfig::from_file(&path.to_string_lossy())?);
+    let merged = LinksConfig::merge(configs);
+    merged.validate()?;
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDERS: &str = r#"
+entities: []
+links:
+  - link_type: has_invoice
+    source_type: order
+    target_type: invoice
+    forward_route_name: invoices
+    reverse_route_name: order
+"#;
+
+    #[tokio::test]
+    async fn test_watcher_swaps_valid_configs_and_ignores_invalid_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("links.yaml");
+        std::fs::write(&path, ORDERS).unwrap();
+
+        let initial = LinksConfig::from_yaml_str(ORDERS).unwrap();
+        let tables = Arc::new(ArcSwap::from_pointee(LinkTables::new(initial)));
+        let handle = ConfigWatcher::new(&path, vec![], tables.clone())
+            .with_poll_interval(Duration::from_millis(10))
+            .spawn();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        let resolves = |route: &str| tables.load().registry.resolve_route("order", route).is_ok();
+        assert!(resolves("invoices"));
+        assert!(!resolves("payments"));
+
+        let added = format!(
+            "{ORDERS}
+  - link_type: has_payment
+    source_type: order
+    target_type: payment
+    forward_route_name: payments
+    reverse_route_name: order
+"
+        );
+        std::fs::write(&path, added).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(resolves("payments"), "the new route should resolve");
+
+        std::fs::write(&path, "links: [not, a, link]").unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(resolves("payments"), "the last good config stays live");
+
+        handle.abort();
+    }
+}
@@ -0,0 +1,126 @@
+//! Cross-origin resource sharing for browser clients
+//!
+//! Browsers only let a page call the API from another origin when the
+//! responses carry the `Access-Control-Allow-*` headers, and they send an
+//! `OPTIONS` preflight before most requests. A [`CorsConfig`] installed with
+//! [`ServerBuilder::with_cors`](crate::server::ServerBuilder::with_cors) wraps
+//! the whole router in a [`CorsLayer`], which adds the headers and answers
+//! preflights itself, before routing.
+
+use anyhow::{Result, anyhow};
+use axum::http::{HeaderName, HeaderValue, Method};
+use std::time::Duration;
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer};
+
+/// Which cross-origin requests browsers may send
+#[derive(Debug, Clone, Default)]
+pub struct CorsConfig {
+    /// Origins allowed to call the API, e.g. `https://app.example.com`;
+    /// `"*"` allows any origin
+    pub allowed_origins: Vec<String>,
+
+    /// Methods allowed in cross-origin requests; empty allows those the
+    /// preflight asks for
+    pub allowed_methods: Vec<Method>,
+
+    /// Request headers allowed in cross-origin requests; empty allows those
+    /// the preflight asks for
+    pub allowed_headers: Vec<HeaderName>,
+
+    /// Whether browsers may send cookies and `Authorization` headers;
+    /// requires explicit `allowed_origins`
+    pub allow_credentials: bool,
+
+    /// How long browsers may cache a preflight response
+    pub max_age: Option<Duration>,
+}
+
+impl CorsConfig {
+    /// Allow every origin, method and header, without credentials
+    ///
+    /// Meant for development; list the origins explicitly in production.
+    pub fn permissive() -> Self {
+        Self {
+            allowed_origins: vec!["*".to_string()],
+            ..Self::default()
+        }
+    }
+
+    /// Build the layer, rejecting origins that are not valid header values
+    ///
+    /// Credentials with the `"*"` origin are rejected too: that would let any
+    /// site make authenticated calls with the visitor's cookies.
+    pub fn into_layer(self) -> Result<CorsLayer> {
+        let any_origin = self.allowed_origins.iter().any(|origin| origin == "*");
+        if any_origin && self.allow_credentials {
+            return Err(anyhow!(
+                "CORS credentials cannot be allowed for any origin ('*'); list the origins"
+            ));
+        }
+        let origins = if any_origin {
+            AllowOrigin::any()
+        } else {
+            let origins = self
+                .allowed_origins
+                .iter()
+                .map(|origin| {
+                    HeaderValue::from_str(origin)
+                        .map_err(|_| anyhow!("invalid CORS origin '{}'", origin))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            AllowOrigin::list(origins)
+        };
+        let methods = if self.allowed_methods.is_empty() {
+            AllowMethods::mirror_request()
+        } else {
+            AllowMethods::list(self.allowed_methods)
+        };
+        let headers = if self.allowed_headers.is_empty() {
+            AllowHeaders::mirror_request()
+        } else {
+            AllowHeaders::list(self.allowed_headers)
+        };
+
+        let layer = CorsLayer::new()
+            .allow_origin(origins)
+            .allow_methods(methods)
+            .allow_headers(headers)
+            .allow_credentials(self.allow_credentials);
+        Ok(match self.max_age {
+            Some(max_age) => layer.max_age(max_age),
+            None => layer,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_origin_is_rejected() {
+        let config = CorsConfig {
+            allowed_origins: vec!["https://app.example.com\n".to_string()],
+            ..CorsConfig::default()
+        };
+        let err = config.into_layer().unwrap_err();
+        assert!(err.to_string().contains("invalid CORS origin"));
+    }
+
+    #[test]
+    fn test_wildcard_origin_with_credentials_is_rejected() {
+        let config = CorsConfig {
+            allow_credentials: true,
+            ..CorsConfig::permissive()
+        };
+        let err = config.into_layer().unwrap_err();
+        assert!(err.to_string().contains("credentials"));
+
+        let config = CorsConfig {
+            allowed_origins: vec!["https://app.example.com".to_string()],
+            allow_credentials: true,
+            ..CorsConfig::default()
+        };
+        assert!(config.into_layer().is_ok());
+    }
+}
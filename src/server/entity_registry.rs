@@ -1,6 +1,7 @@
 //! Entity registry for managing entity descriptors and auto-generating CRUD routes
 
 use axum::Router;
+use serde_json::Value;
 use std::collections::HashMap;
 
 /// Trait that describes how to build routes for an entity
@@ -21,6 +22,14 @@ pub trait EntityDescriptor: Send + Sync {
     /// - POST /{plural}
     /// - GET /{plural}/:id
     fn build_routes(&self) -> Router;
+
+    /// JSON Schema of the create/update request body
+    ///
+    /// Published in the REST exposure's OpenAPI document; `None` (the
+    /// default) describes the body as any JSON object.
+    fn body_schema(&self) -> Option<Value> {
+        None
+    }
 }
 
 /// Registry for all entities in the application
@@ -65,6 +74,11 @@ impl EntityRegistry {
     pub fn entity_types(&self) -> Vec<&str> {
         self.descriptors.keys().map(|s| s.as_str()).collect()
     }
+
+    /// Get all registered descriptors
+    pub fn descriptors(&self) -> Vec<&dyn EntityDescriptor> {
+        self.descriptors.values().map(|d| d.as_ref()).collect()
+    }
 }
 
 #[cfg(test)]
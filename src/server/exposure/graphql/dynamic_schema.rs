@@ -13,6 +13,8 @@ use std::sync::Arc;
 #[cfg(feature = "graphql")]
 use uuid::Uuid;
 
+#[cfg(feature = "graphql")]
+use crate::core::auth::AuthContext;
 #[cfg(feature = "graphql")]
 use crate::core::link::LinkEntity;
 #[cfg(feature = "graphql")]
@@ -186,6 +188,14 @@ impl DynamicQueryRoot {
     }
 }
 
+/// Caller of a request, from the [`AuthContext`] in the schema data
+#[cfg(feature = "graphql")]
+fn caller(ctx: &Context<'_>) -> AuthContext {
+    ctx.data_opt::<AuthContext>()
+        .cloned()
+        .unwrap_or(AuthContext::Anonymous)
+}
+
 /// Dynamic Mutation Root for CRUD operations
 #[cfg(feature = "graphql")]
 #[allow(dead_code)]
@@ -199,12 +209,13 @@ impl DynamicMutationRoot {
     /// Create a new entity of the specified type
     async fn create_entity(
         &self,
+        ctx: &Context<'_>,
         entity_type: String,
         data: JsonValue,
     ) -> async_graphql::Result<JsonValue> {
-        if let Some(creator) = self.host.entity_creators.get(&entity_type) {
-            creator
-                .create_from_json(data.0)
+        if self.host.entity_creators.contains_key(&entity_type) {
+            self.host
+                .create_entity(&entity_type, data.0, &caller(ctx))
                 .await
                 .map(JsonValue)
                 .map_err(|e| Error::new(format!("Failed to create entity: {}", e)))
@@ -216,15 +227,16 @@ impl DynamicMutationRoot {
     /// Update an existing entity
     async fn update_entity(
         &self,
+        ctx: &Context<'_>,
         id: ID,
         entity_type: String,
         data: JsonValue,
     ) -> async_graphql::Result<JsonValue> {
         let uuid = Uuid::parse_str(&id).map_err(|e| Error::new(format!("Invalid UUID: {}", e)))?;
 
-        if let Some(creator) = self.host.entity_creators.get(&entity_type) {
-            creator
-                .update_from_json(&uuid, data.0)
+        if self.host.entity_creators.contains_key(&entity_type) {
+            self.host
+                .update_entity(&entity_type, &uuid, data.0, &caller(ctx))
                 .await
                 .map(JsonValue)
                 .map_err(|e| Error::new(format!("Failed to update entity: {}", e)))
@@ -234,12 +246,17 @@ impl DynamicMutationRoot {
     }
 
     /// Delete an entity
-    async fn delete_entity(&self, id: ID, entity_type: String) -> async_graphql::Result<bool> {
+    async fn delete_entity(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        entity_type: String,
+    ) -> async_graphql::Result<bool> {
         let uuid = Uuid::parse_str(&id).map_err(|e| Error::new(format!("Invalid UUID: {}", e)))?;
 
-        if let Some(creator) = self.host.entity_creators.get(&entity_type) {
-            creator
-                .delete(&uuid)
+        if self.host.entity_creators.contains_key(&entity_type) {
+            self.host
+                .delete_entity(&entity_type, &uuid, &caller(ctx))
                 .await
                 .map(|_| true)
                 .map_err(|e| Error::new(format!("Failed to delete entity: {}", e)))
@@ -267,8 +284,7 @@ impl DynamicMutationRoot {
 
         let link = self
             .host
-            .link_service
-            .create(link_entity)
+            .create_link(link_entity)
             .await
             .map_err(|e| Error::new(format!("Failed to create link: {}", e)))?;
 
@@ -442,11 +458,15 @@ mod tests {
                 singular: "order".to_string(),
                 plural: "orders".to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
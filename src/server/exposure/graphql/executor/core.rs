@@ -1,19 +1,27 @@
 //! Core GraphQL executor orchestration
 
 use anyhow::{Result, bail};
+use futures::channel::mpsc;
+use futures::{SinkExt, Stream};
 use graphql_parser::query::{Document, OperationDefinition, Selection, parse_query};
 use serde_json::{Value, json};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use super::data_loader::DataLoader;
+use super::incremental::Deferrals;
 use super::mutation_executor;
 use super::query_executor;
+use crate::core::auth::AuthContext;
 use crate::server::exposure::graphql::schema_generator::SchemaGenerator;
 use crate::server::host::ServerHost;
 
 /// GraphQL executor that executes queries against the dynamic schema
+#[derive(Clone)]
 pub struct GraphQLExecutor {
     host: Arc<ServerHost>,
+    /// Caller of the operations, checked against `owner` policies
+    context: AuthContext,
     #[allow(dead_code)]
     schema_sdl: String,
 }
@@ -24,10 +32,22 @@ impl GraphQLExecutor {
         let generator = SchemaGenerator::new(host.clone());
         let schema_sdl = generator.generate_sdl().await;
 
-        Self { host, schema_sdl }
+        Self {
+            host,
+            context: AuthContext::Anonymous,
+            schema_sdl,
+        }
+    }
+
+    /// Execute operations on behalf of `context` instead of an anonymous caller
+    pub fn with_context(mut self, context: AuthContext) -> Self {
+        self.context = context;
+        self
     }
 
     /// Execute a GraphQL query and return the result as JSON
+    ///
+    /// `@defer` and `@stream` are ignored: everything is resolved inline.
     pub async fn execute(
         &self,
         query: &str,
@@ -37,9 +57,10 @@ impl GraphQLExecutor {
         let doc = parse_query::<String>(query)
             .map_err(|e| anyhow::anyhow!("Failed to parse query: {:?}", e))?;
 
-        // Execute the query
+        // Execute the query, with relation lookups batched across the operation
+        let loader = DataLoader::new(self.host.clone()).with_context(self.context.clone());
         let result = self
-            .execute_document(&doc, variables.unwrap_or_default())
+            .execute_document(&doc, variables.unwrap_or_default(), &loader, None)
             .await?;
 
         Ok(json!({
@@ -47,11 +68,55 @@ impl GraphQLExecutor {
         }))
     }
 
+    /// Execute a GraphQL query with incremental delivery of `@defer`/`@stream` results
+    ///
+    /// Yields the initial payload (`data`, `hasNext`) followed by one
+    /// subsequent payload (`incremental`, `hasNext`) per deferred fragment
+    /// or streamed item. Execution runs on its own task and stops once the
+    /// returned stream is dropped.
+    pub fn execute_incremental(
+        &self,
+        query: String,
+        variables: Option<HashMap<String, Value>>,
+    ) -> impl Stream<Item = Value> + Send + 'static {
+        let executor = self.clone();
+        let (mut tx, rx) = mpsc::channel(1);
+
+        tokio::spawn(async move {
+            let defer = Deferrals::default();
+            let loader =
+                DataLoader::new(executor.host.clone()).with_context(executor.context.clone());
+            let initial = match parse_query::<String>(&query) {
+                Ok(doc) => executor
+                    .execute_document(&doc, variables.unwrap_or_default(), &loader, Some(&defer))
+                    .await
+                    .map(|data| json!({ "data": data, "hasNext": defer.has_next() })),
+                Err(e) => Err(anyhow::anyhow!("Failed to parse query: {:?}", e)),
+            };
+            let initial = initial.unwrap_or_else(
+                |e| json!({ "errors": [{ "message": e.to_string() }], "hasNext": false }),
+            );
+            if tx.send(initial).await.is_err() {
+                return;
+            }
+
+            while let Some(payload) = defer.next_payload(&loader).await {
+                if tx.send(payload).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        rx
+    }
+
     /// Execute a parsed GraphQL document
-    async fn execute_document(
+    async fn execute_document<'s>(
         &self,
-        doc: &Document<'_, String>,
+        doc: &Document<'s, String>,
         variables: HashMap<String, Value>,
+        loader: &DataLoader,
+        defer: Option<&Deferrals<'s>>,
     ) -> Result<Value> {
         // Find the operation to execute (default to first query)
         let operation = doc
@@ -68,7 +133,7 @@ impl GraphQLExecutor {
 
         match operation {
             OperationDefinition::Query(query) => {
-                self.execute_query(&query.selection_set.items, &variables)
+                self.execute_query(&query.selection_set.items, &variables, loader, defer)
                     .await
             }
             OperationDefinition::Mutation(mutation) => {
@@ -76,29 +141,22 @@ impl GraphQLExecutor {
                     .await
             }
             OperationDefinition::SelectionSet(selection_set) => {
-                self.execute_query(&selection_set.items, &variables).await
+                self.execute_query(&selection_set.items, &variables, loader, defer)
+                    .await
             }
             _ => bail!("Subscriptions are not supported"),
         }
     }
 
     /// Execute a query operation
-    async fn execute_query(
+    async fn execute_query<'s>(
         &self,
-        selections: &[Selection<'_, String>],
+        selections: &[Selection<'s, String>],
         _variables: &HashMap<String, Value>,
+        loader: &DataLoader,
+        defer: Option<&Deferrals<'s>>,
     ) -> Result<Value> {
-        let mut result = serde_json::Map::new();
-
-        for selection in selections {
-            if let Selection::Field(field) = selection {
-                let field_name = field.name.as_str();
-                let field_value = query_executor::resolve_query_field(&self.host, field).await?;
-                result.insert(field_name.to_string(), field_value);
-            }
-        }
-
-        Ok(Value::Object(result))
+        query_executor::resolve_selection_set(loader, selections, defer).await
     }
 
     /// Execute a mutation operation
@@ -113,7 +171,8 @@ impl GraphQLExecutor {
             if let Selection::Field(field) = selection {
                 let field_name = field.name.as_str();
                 let field_value =
-                    mutation_executor::resolve_mutation_field(&self.host, field).await?;
+                    mutation_executor::resolve_mutation_field(&self.host, &self.context, field)
+                        .await?;
                 result.insert(field_name.to_string(), field_value);
             }
         }
@@ -252,11 +311,17 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -267,11 +332,17 @@ mod tests {
                 reverse_route_name: "order".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
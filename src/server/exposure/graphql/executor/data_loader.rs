@@ -0,0 +1,280 @@
+//! Per-request batching of relation resolution
+//!
+//! Resolving `orders { invoices { ... } }` one order at a time costs a link
+//! query and a fetch per order (N+1). Before the items of an entity list are
+//! resolved, [`DataLoader::prime`] walks the selection set level by level and
+//! loads each relation for the whole list at once: one
+//! [`find_by_sources`](crate::core::LinkService::find_by_sources) or
+//! [`find_by_targets`](crate::core::LinkService::find_by_targets) call per
+//! relation, then one
+//! [`fetch_many_as_json`](crate::core::EntityFetcher::fetch_many_as_json)
+//! call for the linked entities. The field resolver reads relations back
+//! from the loader, which only goes to the backend for what was not primed.
+//!
+//! A loader lives for a single operation, so nothing is served from an
+//! earlier request.
+
+use anyhow::Result;
+use futures::future::{BoxFuture, FutureExt};
+use graphql_parser::query::Selection;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use super::incremental;
+use crate::core::auth::AuthContext;
+use crate::core::link::{LinkDefinition, LinkEntity};
+use crate::server::host::ServerHost;
+
+/// Which end of a link definition a relation field starts from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From the source to its targets (e.g., order -> invoices)
+    Forward,
+    /// From the target to its source (e.g., invoice -> order)
+    Reverse,
+}
+
+/// Direction, link type, linked entity type and entity ID of a link lookup
+type LinkKey = (Direction, String, String, Uuid);
+
+/// Relation cache of one GraphQL operation
+pub struct DataLoader {
+    host: Arc<ServerHost>,
+    /// Caller of the operation, checked against `owner` policies
+    context: AuthContext,
+    links: Mutex<HashMap<LinkKey, Vec<LinkEntity>>>,
+    /// Fetched entities by type and ID, `None` when missing
+    entities: Mutex<HashMap<(String, Uuid), Option<Value>>>,
+}
+
+impl DataLoader {
+    pub fn new(host: Arc<ServerHost>) -> Self {
+        Self {
+            host,
+            context: AuthContext::Anonymous,
+            links: Mutex::new(HashMap::new()),
+            entities: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_context(mut self, context: AuthContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    pub fn host(&self) -> &Arc<ServerHost> {
+        &self.host
+    }
+
+    pub fn context(&self) -> &AuthContext {
+        &self.context
+    }
+
+    /// The link definition behind the relation field `field_name` of `entity_type`
+    pub fn relation(
+        &self,
+        entity_type: &str,
+        field_name: &str,
+    ) -> Option<(Direction, LinkDefinition)> {
+        self.host.config().links.iter().find_map(|link| {
+            if link.source_type == entity_type && link.forward_route_name == field_name {
+                Some((Direction::Forward, link.clone()))
+            } else if link.target_type == entity_type && link.reverse_route_name == field_name {
+                Some((Direction::Reverse, link.clone()))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The links of entity `id` through `link`, in `direction`
+    pub async fn links(
+        &self,
+        direction: Direction,
+        link: &LinkDefinition,
+        id: Uuid,
+    ) -> Result<Vec<LinkEntity>> {
+        self.load_links(direction, link, &[id]).await?;
+        let links = self.links.lock().expect("lock poisoned");
+        Ok(links
+            .get(&link_key(direction, link, id))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// The entity `id` of `entity_type`, or `None` if it cannot be fetched
+    pub async fn entity(&self, entity_type: &str, id: Uuid) -> Option<Value> {
+        self.load_entities(entity_type, &[id]).await;
+        self.cached_entity(entity_type, id)
+    }
+
+    /// Load the relations selected by `selections` for all of `entities`
+    ///
+    /// Recurses into the selections of each relation with the entities it
+    /// links to, so every nesting level costs a bounded number of calls
+    /// whatever the number of entities.
+    pub fn prime<'a>(
+        &'a self,
+        entities: &'a [Value],
+        selections: &'a [Selection<'_, String>],
+        entity_type: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        async move {
+            let ids: Vec<Uuid> = entities.iter().filter_map(entity_id).collect();
+            if ids.is_empty() {
+                return Ok(());
+            }
+
+            for field in incremental::collect_fields(selections, false).fields {
+                let Some((direction, link)) = self.relation(entity_type, &field.name) else {
+                    continue;
+                };
+                self.load_links(direction, &link, &ids).await?;
+
+                let linked_ids: Vec<Uuid> = {
+                    let links = self.links.lock().expect("lock poisoned");
+                    ids.iter()
+                        .filter_map(|id| links.get(&link_key(direction, &link, *id)))
+                        .flat_map(|links| match direction {
+                            Direction::Forward => links.iter().map(|l| l.target_id).collect(),
+                            // A reverse relation resolves to the first source only
+                            Direction::Reverse => links
+                                .first()
+                                .map(|l| l.source_id)
+                                .into_iter()
+                                .collect::<Vec<_>>(),
+                        })
+                        .collect()
+                };
+                let linked_type = linked_type(direction, &link);
+                self.load_entities(linked_type, &linked_ids).await;
+
+                let linked: Vec<Value> = linked_ids
+                    .iter()
+                    .filter_map(|id| self.cached_entity(linked_type, *id))
+                    .collect();
+                self.prime(&linked, &field.selection_set.items, linked_type)
+                    .await?;
+            }
+            Ok(())
+        }
+        .boxed()
+    }
+
+    /// Fetch the links of the `ids` not looked up yet, in one call
+    async fn load_links(
+        &self,
+        direction: Direction,
+        link: &LinkDefinition,
+        ids: &[Uuid],
+    ) -> Result<()> {
+        let missing: Vec<Uuid> = {
+            let links = self.links.lock().expect("lock poisoned");
+            let mut seen = HashSet::new();
+            ids.iter()
+                .copied()
+                .filter(|id| {
+                    !links.contains_key(&link_key(direction, link, *id)) && seen.insert(*id)
+                })
+                .collect()
+        };
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        let link_service = &self.host.link_service;
+        let found = match direction {
+            Direction::Forward => {
+                link_service
+                    .find_by_sources(&missing, Some(&link.link_type), Some(&link.target_type))
+                    .await?
+            }
+            Direction::Reverse => {
+                link_service
+                    .find_by_targets(&missing, Some(&link.link_type), Some(&link.source_type))
+                    .await?
+            }
+        };
+
+        let mut grouped: HashMap<Uuid, Vec<LinkEntity>> =
+            missing.iter().map(|id| (*id, Vec::new())).collect();
+        for found in found {
+            let id = match direction {
+                Direction::Forward => found.source_id,
+                Direction::Reverse => found.target_id,
+            };
+            if let Some(group) = grouped.get_mut(&id) {
+                group.push(found);
+            }
+        }
+
+        let mut links = self.links.lock().expect("lock poisoned");
+        for (id, group) in grouped {
+            links.insert(link_key(direction, link, id), group);
+        }
+        Ok(())
+    }
+
+    /// Fetch the entities among `ids` not fetched yet, in one call
+    ///
+    /// Entities that fail to fetch are treated as missing, like links to
+    /// deleted entities.
+    async fn load_entities(&self, entity_type: &str, ids: &[Uuid]) {
+        let Some(fetcher) = self.host.entity_fetchers.get(entity_type) else {
+            return;
+        };
+        let missing: Vec<Uuid> = {
+            let entities = self.entities.lock().expect("lock poisoned");
+            let mut seen = HashSet::new();
+            ids.iter()
+                .copied()
+                .filter(|id| {
+                    !entities.contains_key(&(entity_type.to_string(), *id)) && seen.insert(*id)
+                })
+                .collect()
+        };
+        if missing.is_empty() {
+            return;
+        }
+
+        let mut found = fetcher
+            .fetch_many_as_json(&missing)
+            .await
+            .unwrap_or_default();
+        let mut entities = self.entities.lock().expect("lock poisoned");
+        for id in missing {
+            entities.insert((entity_type.to_string(), id), found.remove(&id));
+        }
+    }
+
+    fn cached_entity(&self, entity_type: &str, id: Uuid) -> Option<Value> {
+        let entities = self.entities.lock().expect("lock poisoned");
+        entities
+            .get(&(entity_type.to_string(), id))
+            .cloned()
+            .flatten()
+    }
+}
+
+fn link_key(direction: Direction, link: &LinkDefinition, id: Uuid) -> LinkKey {
+    (
+        direction,
+        link.link_type.clone(),
+        linked_type(direction, link).to_string(),
+        id,
+    )
+}
+
+/// The entity type at the other end of the relation
+fn linked_type(direction: Direction, link: &LinkDefinition) -> &str {
+    match direction {
+        Direction::Forward => &link.target_type,
+        Direction::Reverse => &link.source_type,
+    }
+}
+
+fn entity_id(entity: &Value) -> Option<Uuid> {
+    entity.get("id")?.as_str()?.parse().ok()
+}
@@ -1,48 +1,139 @@
 //! Field and relation resolution for GraphQL entities
+//!
+//! Fields the entity type's `redacted_fields` withhold from the loader's
+//! caller resolve to `null`, as the REST exposure leaves them out.
 
 use anyhow::Result;
 use futures::future::{BoxFuture, FutureExt};
 use graphql_parser::query::{Field, Selection};
-use serde_json::Value;
+use serde_json::{Value, json};
 use std::sync::Arc;
 use uuid::Uuid;
 
+use super::data_loader::{DataLoader, Direction};
+use super::incremental::{self, Deferrals, Deferred};
 use super::utils;
+use crate::core::auth::AuthContext;
 use crate::server::host::ServerHost;
 
 /// Resolve fields for a list of entities
+///
+/// The relations selected on the entities are loaded for the whole list up
+/// front, see [`DataLoader::prime`].
 pub async fn resolve_entity_list(
-    host: &Arc<ServerHost>,
+    loader: &DataLoader,
     entities: Vec<Value>,
     selections: &[Selection<'_, String>],
     entity_type: &str,
 ) -> Result<Vec<Value>> {
+    loader.prime(&entities, selections, entity_type).await?;
+
     let mut resolved = Vec::new();
 
     for entity in entities {
-        let resolved_entity = resolve_entity_fields(host, entity, selections, entity_type).await?;
+        let resolved_entity =
+            resolve_entity_fields_impl(loader, entity, selections, entity_type, None, &[]).await?;
         resolved.push(resolved_entity);
     }
 
     Ok(resolved)
 }
 
-/// Resolve fields for a single entity
+/// Resolve fields for a single entity sent to `context`
 pub fn resolve_entity_fields<'a>(
     host: &'a Arc<ServerHost>,
+    context: &'a AuthContext,
     entity: Value,
     selections: &'a [Selection<'_, String>],
     entity_type: &'a str,
 ) -> BoxFuture<'a, Result<Value>> {
-    async move { resolve_entity_fields_impl(host, entity, selections, entity_type).await }.boxed()
+    async move {
+        let loader = DataLoader::new(host.clone()).with_context(context.clone());
+        resolve_entity_fields_deferring(&loader, entity, selections, entity_type, None, Vec::new())
+            .await
+    }
+    .boxed()
+}
+
+/// Resolve fields for the entity at `path`, queueing `@defer`/`@stream` work on `defer`
+pub fn resolve_entity_fields_deferring<'a, 's>(
+    loader: &'a DataLoader,
+    entity: Value,
+    selections: &'a [Selection<'s, String>],
+    entity_type: &'a str,
+    defer: Option<&'a Deferrals<'s>>,
+    path: Vec<Value>,
+) -> BoxFuture<'a, Result<Value>> {
+    async move {
+        loader
+            .prime(std::slice::from_ref(&entity), selections, entity_type)
+            .await?;
+        resolve_entity_fields_impl(loader, entity, selections, entity_type, defer, &path).await
+    }
+    .boxed()
+}
+
+/// Resolve the entities of the list field at `path`
+///
+/// Under `@stream` only the first `initialCount` items are resolved, the
+/// others are queued on `defer`.
+pub async fn resolve_list_field<'s>(
+    loader: &DataLoader,
+    entities: Vec<Value>,
+    field: &Field<'s, String>,
+    entity_type: &str,
+    defer: Option<&Deferrals<'s>>,
+    path: &[Value],
+) -> Result<Vec<Value>> {
+    let selections = &field.selection_set.items;
+    if defer.is_none() {
+        return resolve_entity_list(loader, entities, selections, entity_type).await;
+    }
+    loader.prime(&entities, selections, entity_type).await?;
+
+    let streamed = defer.zip(incremental::streamed(&field.directives));
+    let mut resolved = Vec::new();
+
+    for (index, entity) in entities.into_iter().enumerate() {
+        let mut item_path = path.to_vec();
+        item_path.push(json!(index));
+
+        match &streamed {
+            Some((defer, (label, initial_count))) if index >= *initial_count => {
+                defer.push(Deferred::Item {
+                    label: label.clone(),
+                    path: item_path,
+                    entity,
+                    entity_type: entity_type.to_string(),
+                    selections: selections.clone(),
+                });
+            }
+            _ => {
+                let item = resolve_entity_fields_impl(
+                    loader,
+                    entity,
+                    selections,
+                    entity_type,
+                    defer,
+                    &item_path,
+                )
+                .await?;
+                resolved.push(item);
+            }
+        }
+    }
+
+    Ok(resolved)
 }
 
 /// Implementation of resolve_entity_fields
-async fn resolve_entity_fields_impl(
-    host: &Arc<ServerHost>,
+async fn resolve_entity_fields_impl<'s>(
+    loader: &DataLoader,
     entity: Value,
-    selections: &[Selection<'_, String>],
+    selections: &[Selection<'s, String>],
     entity_type: &str,
+    defer: Option<&Deferrals<'s>>,
+    path: &[Value],
 ) -> Result<Value> {
     let mut result = serde_json::Map::new();
 
@@ -50,33 +141,56 @@ async fn resolve_entity_fields_impl(
         .as_object()
         .ok_or_else(|| anyhow::anyhow!("Entity is not an object"))?;
 
-    for selection in selections {
-        if let Selection::Field(field) = selection {
-            let field_name = field.name.as_str();
+    let collected = incremental::collect_fields(selections, defer.is_some());
+    let redacted = loader
+        .host()
+        .entity_fetchers
+        .get(entity_type)
+        .map_or(&[][..], |fetcher| fetcher.redacted_fields(loader.context()));
 
-            // Check if this is a regular field (exists in the entity data)
-            if let Some(value) = entity_obj.get(field_name) {
-                result.insert(field_name.to_string(), value.clone());
-                continue;
-            }
+    for field in collected.fields {
+        let field_name = field.name.as_str();
 
-            // Check if this is a snake_case vs camelCase mismatch
-            let snake_case_name = utils::camel_to_snake(field_name);
-            if let Some(value) = entity_obj.get(&snake_case_name) {
-                result.insert(field_name.to_string(), value.clone());
-                continue;
-            }
+        let snake_case_name = utils::camel_to_snake(field_name);
+        if redacted.contains(&field_name) || redacted.contains(&snake_case_name.as_str()) {
+            result.insert(field_name.to_string(), Value::Null);
+            continue;
+        }
 
-            // Check if this is a relation field
-            if let Some(relation_value) =
-                resolve_relation_field_impl(host, entity_obj, field, entity_type).await?
-            {
-                result.insert(field_name.to_string(), relation_value);
-                continue;
-            }
+        // Check if this is a regular field (exists in the entity data)
+        if let Some(value) = entity_obj.get(field_name) {
+            result.insert(field_name.to_string(), value.clone());
+            continue;
+        }
 
-            // Field not found - return null
-            result.insert(field_name.to_string(), Value::Null);
+        // Check if this is a snake_case vs camelCase mismatch
+        if let Some(value) = entity_obj.get(&snake_case_name) {
+            result.insert(field_name.to_string(), value.clone());
+            continue;
+        }
+
+        // Check if this is a relation field
+        if let Some(relation_value) =
+            resolve_relation_field_impl(loader, entity_obj, field, entity_type, defer, path).await?
+        {
+            result.insert(field_name.to_string(), relation_value);
+            continue;
+        }
+
+        // Field not found - return null
+        result.insert(field_name.to_string(), Value::Null);
+    }
+
+    // Deferred fragments are resolved later against the same entity
+    if let Some(defer) = defer {
+        for (label, selections) in collected.deferred {
+            defer.push(Deferred::Fragment {
+                label,
+                path: path.to_vec(),
+                entity: entity.clone(),
+                entity_type: entity_type.to_string(),
+                selections: selections.to_vec(),
+            });
         }
     }
 
@@ -84,21 +198,29 @@ async fn resolve_entity_fields_impl(
 }
 
 /// Resolve a relation field (e.g., "invoices" for an order)
-fn resolve_relation_field_impl<'a>(
-    host: &'a Arc<ServerHost>,
+fn resolve_relation_field_impl<'a, 's>(
+    loader: &'a DataLoader,
     entity: &'a serde_json::Map<String, Value>,
-    field: &'a Field<'_, String>,
+    field: &'a Field<'s, String>,
     entity_type: &'a str,
+    defer: Option<&'a Deferrals<'s>>,
+    path: &'a [Value],
 ) -> BoxFuture<'a, Result<Option<Value>>> {
-    async move { resolve_relation_field_inner(host, entity, field, entity_type).await }.boxed()
+    async move { resolve_relation_field_inner(loader, entity, field, entity_type, defer, path).await }
+        .boxed()
 }
 
 /// Inner implementation of resolve_relation_field
-async fn resolve_relation_field_inner(
-    host: &Arc<ServerHost>,
+///
+/// Links and linked entities come from the [`DataLoader`], usually already
+/// primed for the whole enclosing list.
+async fn resolve_relation_field_inner<'s>(
+    loader: &DataLoader,
     entity: &serde_json::Map<String, Value>,
-    field: &Field<'_, String>,
+    field: &Field<'s, String>,
     entity_type: &str,
+    defer: Option<&Deferrals<'s>>,
+    path: &[Value],
 ) -> Result<Option<Value>> {
     let field_name = field.name.as_str();
     let entity_id = entity
@@ -107,72 +229,71 @@ async fn resolve_relation_field_inner(
         .ok_or_else(|| anyhow::anyhow!("Entity missing id field"))?;
     let source_uuid = Uuid::parse_str(entity_id)?;
 
-    // Get links configuration for this entity type
-    let links_config = &host.config;
+    let mut field_path = path.to_vec();
+    field_path.push(json!(field_name));
 
     // Find the link configuration for this relation
-    for link_config in &links_config.links {
-        if link_config.source_type == entity_type && link_config.forward_route_name == field_name {
-            // This is a forward relation (e.g., order -> invoices)
-            let links = host
-                .link_service
-                .find_by_source(
-                    &source_uuid,
-                    Some(&link_config.link_type),
-                    Some(&link_config.target_type),
-                )
-                .await?;
+    let Some((direction, link_config)) = loader.relation(entity_type, field_name) else {
+        return Ok(None);
+    };
+    let links = loader.links(direction, &link_config, source_uuid).await?;
+
+    match direction {
+        // This is a forward relation (e.g., order -> invoices)
+        Direction::Forward => {
+            if !loader
+                .host()
+                .entity_fetchers
+                .contains_key(&link_config.target_type)
+            {
+                return Ok(None);
+            }
 
             // Fetch the target entities
-            if let Some(fetcher) = host.entity_fetchers.get(&link_config.target_type) {
-                let mut targets = Vec::new();
-
-                for link in links {
-                    if let Ok(target_entity) = fetcher.fetch_as_json(&link.target_id).await {
-                        let resolved = resolve_entity_fields_impl(
-                            host,
-                            target_entity,
-                            &field.selection_set.items,
-                            &link_config.target_type,
-                        )
-                        .await?;
-                        targets.push(resolved);
-                    }
+            let mut targets = Vec::new();
+            for link in links {
+                if let Some(target_entity) = loader
+                    .entity(&link_config.target_type, link.target_id)
+                    .await
+                {
+                    targets.push(target_entity);
                 }
-
-                return Ok(Some(Value::Array(targets)));
             }
-        } else if link_config.target_type == entity_type
-            && link_config.reverse_route_name == field_name
-        {
-            // This is a reverse relation (e.g., invoice -> order)
-            let links = host
-                .link_service
-                .find_by_target(
-                    &source_uuid,
-                    Some(&link_config.link_type),
-                    Some(&link_config.source_type),
-                )
-                .await?;
 
+            let resolved = resolve_list_field(
+                loader,
+                targets,
+                field,
+                &link_config.target_type,
+                defer,
+                &field_path,
+            )
+            .await?;
+
+            Ok(Some(Value::Array(resolved)))
+        }
+        // This is a reverse relation (e.g., invoice -> order)
+        Direction::Reverse => {
             // Fetch the source entity (should be only one for singular relations)
             if let Some(link) = links.first()
-                && let Some(fetcher) = host.entity_fetchers.get(&link_config.source_type)
-                && let Ok(source_entity) = fetcher.fetch_as_json(&link.source_id).await
+                && let Some(source_entity) = loader
+                    .entity(&link_config.source_type, link.source_id)
+                    .await
             {
                 let resolved = resolve_entity_fields_impl(
-                    host,
+                    loader,
                     source_entity,
                     &field.selection_set.items,
                     &link_config.source_type,
+                    defer,
+                    &field_path,
                 )
                 .await?;
                 return Ok(Some(resolved));
             }
+            Ok(None)
         }
     }
-
-    Ok(None)
 }
 
 #[cfg(test)]
@@ -193,6 +314,7 @@ mod tests {
     use graphql_parser::query::SelectionSet;
     use serde_json::json;
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     // -----------------------------------------------------------------------
     // Mock infrastructure
@@ -200,15 +322,22 @@ mod tests {
 
     struct MockFetcher {
         entities: std::sync::Mutex<HashMap<Uuid, Value>>,
+        redacted: &'static [&'static str],
     }
 
     impl MockFetcher {
         fn new() -> Self {
             Self {
                 entities: std::sync::Mutex::new(HashMap::new()),
+                redacted: &[],
             }
         }
 
+        fn redacting(mut self, fields: &'static [&'static str]) -> Self {
+            self.redacted = fields;
+            self
+        }
+
         fn with_entity(self, id: Uuid, entity: Value) -> Self {
             self.entities
                 .lock()
@@ -236,6 +365,87 @@ mod tests {
             let entities = self.entities.lock().expect("lock poisoned");
             Ok(entities.values().cloned().collect())
         }
+
+        fn redacted_fields(&self, context: &AuthContext) -> &'static [&'static str] {
+            match context {
+                AuthContext::Admin { .. } => &[],
+                _ => self.redacted,
+            }
+        }
+    }
+
+    /// Link service counting single and batched lookups
+    #[derive(Default)]
+    struct CountingLinkService {
+        inner: InMemoryLinkService,
+        single_lookups: AtomicUsize,
+        batched_lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LinkService for CountingLinkService {
+        async fn create(&self, link: LinkEntity) -> anyhow::Result<LinkEntity> {
+            self.inner.create(link).await
+        }
+        async fn get(&self, id: &Uuid) -> anyhow::Result<Option<LinkEntity>> {
+            self.inner.get(id).await
+        }
+        async fn list(&self) -> anyhow::Result<Vec<LinkEntity>> {
+            self.inner.list().await
+        }
+        async fn find_by_source(
+            &self,
+            source_id: &Uuid,
+            link_type: Option<&str>,
+            target_type: Option<&str>,
+        ) -> anyhow::Result<Vec<LinkEntity>> {
+            self.single_lookups.fetch_add(1, Ordering::SeqCst);
+            self.inner
+                .find_by_source(source_id, link_type, target_type)
+                .await
+        }
+        async fn find_by_target(
+            &self,
+            target_id: &Uuid,
+            link_type: Option<&str>,
+            source_type: Option<&str>,
+        ) -> anyhow::Result<Vec<LinkEntity>> {
+            self.single_lookups.fetch_add(1, Ordering::SeqCst);
+            self.inner
+                .find_by_target(target_id, link_type, source_type)
+                .await
+        }
+        async fn find_by_sources(
+            &self,
+            source_ids: &[Uuid],
+            link_type: Option<&str>,
+            target_type: Option<&str>,
+        ) -> anyhow::Result<Vec<LinkEntity>> {
+            self.batched_lookups.fetch_add(1, Ordering::SeqCst);
+            self.inner
+                .find_by_sources(source_ids, link_type, target_type)
+                .await
+        }
+        async fn find_by_targets(
+            &self,
+            target_ids: &[Uuid],
+            link_type: Option<&str>,
+            source_type: Option<&str>,
+        ) -> anyhow::Result<Vec<LinkEntity>> {
+            self.batched_lookups.fetch_add(1, Ordering::SeqCst);
+            self.inner
+                .find_by_targets(target_ids, link_type, source_type)
+                .await
+        }
+        async fn update(&self, id: &Uuid, link: LinkEntity) -> anyhow::Result<LinkEntity> {
+            self.inner.update(id, link).await
+        }
+        async fn delete(&self, id: &Uuid) -> anyhow::Result<()> {
+            self.inner.delete(id).await
+        }
+        async fn delete_by_entity(&self, entity_id: &Uuid) -> anyhow::Result<()> {
+            self.inner.delete_by_entity(entity_id).await
+        }
     }
 
     struct StubDescriptor {
@@ -266,7 +476,7 @@ mod tests {
 
     fn build_test_host_with_link_service(
         fetchers: HashMap<String, Arc<dyn EntityFetcher>>,
-        link_service: Arc<InMemoryLinkService>,
+        link_service: Arc<dyn LinkService>,
     ) -> Arc<ServerHost> {
         let config = LinksConfig {
             entities: vec![
@@ -274,11 +484,17 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -289,11 +505,17 @@ mod tests {
                 reverse_route_name: "order".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
@@ -360,15 +582,58 @@ mod tests {
         let entity = json!({"id": "abc-123", "name": "Order 1", "total": 99.9});
         let field = make_field_with_selections("order", &["id", "name", "total"]);
 
-        let result = resolve_entity_fields(&host, entity, &field.selection_set.items, "order")
-            .await
-            .expect("should resolve fields");
+        let result = resolve_entity_fields(
+            &host,
+            &AuthContext::Anonymous,
+            entity,
+            &field.selection_set.items,
+            "order",
+        )
+        .await
+        .expect("should resolve fields");
 
         assert_eq!(result.get("id").and_then(|v| v.as_str()), Some("abc-123"));
         assert_eq!(result.get("name").and_then(|v| v.as_str()), Some("Order 1"));
         assert_eq!(result.get("total").and_then(|v| v.as_f64()), Some(99.9));
     }
 
+    #[tokio::test]
+    async fn test_redacted_fields_resolve_to_null() {
+        let link_service = Arc::new(InMemoryLinkService::new());
+        let mut fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::new();
+        fetchers.insert(
+            "order".to_string(),
+            Arc::new(MockFetcher::new().redacting(&["card_number"])),
+        );
+        fetchers.insert("invoice".to_string(), Arc::new(MockFetcher::new()));
+        let host = build_test_host_with_link_service(fetchers, link_service);
+
+        let entity = json!({"id": "abc", "card_number": "4242"});
+        let field = make_field_with_selections("order", &["id", "cardNumber", "card_number"]);
+
+        let result = resolve_entity_fields(
+            &host,
+            &AuthContext::Anonymous,
+            entity.clone(),
+            &field.selection_set.items,
+            "order",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result["id"], "abc");
+        assert_eq!(result["cardNumber"], Value::Null);
+        assert_eq!(result["card_number"], Value::Null);
+
+        let admin = AuthContext::Admin {
+            admin_id: Uuid::new_v4(),
+        };
+        let result =
+            resolve_entity_fields(&host, &admin, entity, &field.selection_set.items, "order")
+                .await
+                .unwrap();
+        assert_eq!(result["cardNumber"], "4242");
+    }
+
     #[tokio::test]
     async fn test_resolve_camel_to_snake_case_field() {
         let link_service = Arc::new(InMemoryLinkService::new());
@@ -381,9 +646,15 @@ mod tests {
         let entity = json!({"id": "abc", "created_at": "2024-01-01T00:00:00Z"});
         let field = make_field_with_selections("order", &["id", "createdAt"]);
 
-        let result = resolve_entity_fields(&host, entity, &field.selection_set.items, "order")
-            .await
-            .expect("should resolve camelCase -> snake_case");
+        let result = resolve_entity_fields(
+            &host,
+            &AuthContext::Anonymous,
+            entity,
+            &field.selection_set.items,
+            "order",
+        )
+        .await
+        .expect("should resolve camelCase -> snake_case");
 
         assert_eq!(
             result.get("createdAt").and_then(|v| v.as_str()),
@@ -403,9 +674,15 @@ mod tests {
         let entity = json!({"id": order_id.to_string()});
         let field = make_field_with_selections("order", &["id", "nonExistentField"]);
 
-        let result = resolve_entity_fields(&host, entity, &field.selection_set.items, "order")
-            .await
-            .expect("should resolve with null for unknown");
+        let result = resolve_entity_fields(
+            &host,
+            &AuthContext::Anonymous,
+            entity,
+            &field.selection_set.items,
+            "order",
+        )
+        .await
+        .expect("should resolve with null for unknown");
 
         assert_eq!(result.get("nonExistentField"), Some(&Value::Null));
     }
@@ -421,8 +698,14 @@ mod tests {
         let entity = json!("not an object");
         let field = make_field_with_selections("order", &["id"]);
 
-        let result =
-            resolve_entity_fields(&host, entity, &field.selection_set.items, "order").await;
+        let result = resolve_entity_fields(
+            &host,
+            &AuthContext::Anonymous,
+            entity,
+            &field.selection_set.items,
+            "order",
+        )
+        .await;
         assert!(result.is_err(), "non-object entity should error");
         let err_msg = result.expect_err("error").to_string();
         assert!(
@@ -450,9 +733,14 @@ mod tests {
         ];
         let field = make_field_with_selections("orders", &["id", "name"]);
 
-        let result = resolve_entity_list(&host, entities, &field.selection_set.items, "order")
-            .await
-            .expect("should resolve list");
+        let result = resolve_entity_list(
+            &DataLoader::new(host.clone()),
+            entities,
+            &field.selection_set.items,
+            "order",
+        )
+        .await
+        .expect("should resolve list");
 
         assert_eq!(result.len(), 2, "should have two resolved entities");
         assert_eq!(
@@ -476,9 +764,14 @@ mod tests {
         let entities: Vec<Value> = vec![];
         let field = make_field_with_selections("orders", &["id"]);
 
-        let result = resolve_entity_list(&host, entities, &field.selection_set.items, "order")
-            .await
-            .expect("should resolve empty list");
+        let result = resolve_entity_list(
+            &DataLoader::new(host.clone()),
+            entities,
+            &field.selection_set.items,
+            "order",
+        )
+        .await
+        .expect("should resolve empty list");
 
         assert!(result.is_empty(), "empty input should produce empty output");
     }
@@ -625,4 +918,51 @@ mod tests {
             "should be empty when no links"
         );
     }
+
+    #[tokio::test]
+    async fn test_relations_of_a_list_are_loaded_in_batches() {
+        let link_service = Arc::new(CountingLinkService::default());
+        let mut orders = MockFetcher::new();
+        let mut invoices = MockFetcher::new();
+        for i in 0..10 {
+            let order_id = Uuid::new_v4();
+            orders = orders.with_entity(order_id, json!({"id": order_id.to_string(), "n": i}));
+            for amount in [i, i + 100] {
+                let invoice_id = Uuid::new_v4();
+                invoices = invoices.with_entity(
+                    invoice_id,
+                    json!({"id": invoice_id.to_string(), "amount": amount}),
+                );
+                link_service
+                    .create(LinkEntity::new("has_invoice", order_id, invoice_id, None))
+                    .await
+                    .expect("should create link");
+            }
+        }
+
+        let mut fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::new();
+        fetchers.insert("order".to_string(), Arc::new(orders));
+        fetchers.insert("invoice".to_string(), Arc::new(invoices));
+        let host = build_test_host_with_link_service(fetchers, link_service.clone());
+        let executor = GraphQLExecutor::new(host).await;
+
+        let result = executor
+            .execute("{ orders { id n invoices { amount order { n } } } }", None)
+            .await
+            .expect("should resolve nested relations");
+
+        let orders = result["data"]["orders"].as_array().expect("orders");
+        assert_eq!(orders.len(), 10);
+        for order in orders {
+            let invoices = order["invoices"].as_array().expect("invoices");
+            assert_eq!(invoices.len(), 2);
+            for invoice in invoices {
+                assert_eq!(invoice["order"]["n"], order["n"]);
+            }
+        }
+
+        // One batched lookup per relation level, none per order or invoice
+        assert_eq!(link_service.batched_lookups.load(Ordering::SeqCst), 2);
+        assert_eq!(link_service.single_lookups.load(Ordering::SeqCst), 0);
+    }
 }
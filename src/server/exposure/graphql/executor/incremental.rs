@@ -0,0 +1,470 @@
+//! Incremental delivery (`@defer` / `@stream`) for GraphQL queries
+//!
+//! The executor understands the two incremental delivery directives:
+//!
+//! - `@defer(label: String, if: Boolean = true)` on inline fragments: the
+//!   fragment's fields are left out of the enclosing payload and delivered
+//!   in a later one, e.g. relation fields after the entity data.
+//! - `@stream(label: String, initialCount: Int = 0, if: Boolean = true)` on
+//!   entity list fields (root lists and forward relations): the first
+//!   `initialCount` items are part of the enclosing payload, the others
+//!   follow one per payload.
+//!
+//! Payloads follow the incremental delivery RFC: the initial payload is
+//! `{"data": ..., "hasNext": true}`, each subsequent one is
+//! `{"incremental": [{"data" | "items": ..., "path": [...]}], "hasNext": ...}`.
+//!
+//! Without an incremental transport (see [`GraphQLExecutor::execute`]) the
+//! directives are ignored and every fragment and item is resolved inline.
+//!
+//! [`GraphQLExecutor::execute`]: super::GraphQLExecutor::execute
+
+use anyhow::Result;
+use graphql_parser::query::{Directive, Field, Selection, Value as GqlValue};
+use serde_json::{Value, json};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use super::data_loader::DataLoader;
+use super::field_resolver;
+use super::query_executor;
+
+/// Work left out of a payload, to be delivered in a later one
+pub enum Deferred<'s> {
+    /// A deferred fragment of the root selection set
+    Root {
+        label: Option<String>,
+        selections: Vec<Selection<'s, String>>,
+    },
+    /// A deferred fragment of the entity at `path`
+    Fragment {
+        label: Option<String>,
+        path: Vec<Value>,
+        entity: Value,
+        entity_type: String,
+        selections: Vec<Selection<'s, String>>,
+    },
+    /// A streamed list item, `path` ending with its index
+    Item {
+        label: Option<String>,
+        path: Vec<Value>,
+        entity: Value,
+        entity_type: String,
+        selections: Vec<Selection<'s, String>>,
+    },
+}
+
+/// Queue of deferred work for one operation, in delivery order
+#[derive(Default)]
+pub struct Deferrals<'s> {
+    pending: Mutex<VecDeque<Deferred<'s>>>,
+}
+
+impl<'s> Deferrals<'s> {
+    pub fn push(&self, deferred: Deferred<'s>) {
+        self.pending
+            .lock()
+            .expect("lock poisoned")
+            .push_back(deferred);
+    }
+
+    /// Whether more payloads will follow
+    pub fn has_next(&self) -> bool {
+        !self.pending.lock().expect("lock poisoned").is_empty()
+    }
+
+    fn pop(&self) -> Option<Deferred<'s>> {
+        self.pending.lock().expect("lock poisoned").pop_front()
+    }
+
+    /// Resolve the next piece of deferred work into a subsequent payload
+    ///
+    /// Resolving may queue more work (nested `@defer`/`@stream`). Returns
+    /// `None` once everything has been delivered.
+    pub async fn next_payload(&self, loader: &DataLoader) -> Option<Value> {
+        let incremental = match self.pop()? {
+            Deferred::Root { label, selections } => {
+                let data = query_executor::resolve_selection_set(loader, &selections, Some(self))
+                    .await
+                    .map(|data| json!({ "data": data }));
+                incremental_result(label, Vec::new(), data)
+            }
+            Deferred::Fragment {
+                label,
+                path,
+                entity,
+                entity_type,
+                selections,
+            } => {
+                let data = field_resolver::resolve_entity_fields_deferring(
+                    loader,
+                    entity,
+                    &selections,
+                    &entity_type,
+                    Some(self),
+                    path.clone(),
+                )
+                .await
+                .map(|data| json!({ "data": data }));
+                incremental_result(label, path, data)
+            }
+            Deferred::Item {
+                label,
+                path,
+                entity,
+                entity_type,
+                selections,
+            } => {
+                let items = field_resolver::resolve_entity_fields_deferring(
+                    loader,
+                    entity,
+                    &selections,
+                    &entity_type,
+                    Some(self),
+                    path.clone(),
+                )
+                .await
+                .map(|item| json!({ "items": [item] }));
+                incremental_result(label, path, items)
+            }
+        };
+
+        Some(json!({
+            "incremental": [incremental],
+            "hasNext": self.has_next(),
+        }))
+    }
+}
+
+fn incremental_result(label: Option<String>, path: Vec<Value>, result: Result<Value>) -> Value {
+    let mut entry = result.unwrap_or_else(|e| json!({ "errors": [{ "message": e.to_string() }] }));
+    entry["path"] = Value::Array(path);
+    if let Some(label) = label {
+        entry["label"] = Value::String(label);
+    }
+    entry
+}
+
+/// Fields of a selection set, with inline fragments flattened
+pub struct CollectedFields<'a, 's> {
+    pub fields: Vec<&'a Field<'s, String>>,
+    /// `@defer` fragments as `(label, selections)`, when deferring
+    pub deferred: Vec<(Option<String>, &'a [Selection<'s, String>])>,
+}
+
+/// Flatten inline fragments into the fields they select
+///
+/// When `deferring`, fragments marked `@defer` are set aside instead of
+/// being flattened. Fragment spreads are not supported and are skipped.
+pub fn collect_fields<'a, 's>(
+    selections: &'a [Selection<'s, String>],
+    deferring: bool,
+) -> CollectedFields<'a, 's> {
+    let mut collected = CollectedFields {
+        fields: Vec::new(),
+        deferred: Vec::new(),
+    };
+    collect_into(selections, deferring, &mut collected);
+    collected
+}
+
+fn collect_into<'a, 's>(
+    selections: &'a [Selection<'s, String>],
+    deferring: bool,
+    collected: &mut CollectedFields<'a, 's>,
+) {
+    for selection in selections {
+        match selection {
+            Selection::Field(field) => collected.fields.push(field),
+            Selection::InlineFragment(fragment) => {
+                match deferred(&fragment.directives).filter(|_| deferring) {
+                    Some(label) => collected
+                        .deferred
+                        .push((label, &fragment.selection_set.items)),
+                    None => collect_into(&fragment.selection_set.items, deferring, collected),
+                }
+            }
+            Selection::FragmentSpread(_) => {}
+        }
+    }
+}
+
+/// Label of an active `@defer` directive, `None` when not deferred
+pub fn deferred(directives: &[Directive<'_, String>]) -> Option<Option<String>> {
+    active(directives, "defer").map(label)
+}
+
+/// Label and `initialCount` of an active `@stream` directive
+pub fn streamed(directives: &[Directive<'_, String>]) -> Option<(Option<String>, usize)> {
+    let directive = active(directives, "stream")?;
+    let initial_count = match argument(directive, "initialCount") {
+        Some(GqlValue::Int(n)) => n.as_i64().unwrap_or(0).max(0) as usize,
+        _ => 0,
+    };
+    Some((label(directive), initial_count))
+}
+
+fn active<'a, 's>(
+    directives: &'a [Directive<'s, String>],
+    name: &str,
+) -> Option<&'a Directive<'s, String>> {
+    directives
+        .iter()
+        .find(|d| d.name == name)
+        .filter(|d| !matches!(argument(d, "if"), Some(GqlValue::Boolean(false))))
+}
+
+fn argument<'a, 's>(
+    directive: &'a Directive<'s, String>,
+    name: &str,
+) -> Option<&'a GqlValue<'s, String>> {
+    directive
+        .arguments
+        .iter()
+        .find(|(arg, _)| arg == name)
+        .map(|(_, value)| value)
+}
+
+fn label(directive: &Directive<'_, String>) -> Option<String> {
+    match argument(directive, "label") {
+        Some(GqlValue::String(label)) => Some(label.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::core::GraphQLExecutor;
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig, LinksConfig};
+    use crate::core::EntityFetcher;
+    use crate::core::link::{LinkDefinition, LinkEntity};
+    use crate::core::service::LinkService;
+    use crate::server::entity_registry::{EntityDescriptor, EntityRegistry};
+    use crate::server::host::ServerHost;
+    use crate::storage::in_memory::InMemoryLinkService;
+    use async_trait::async_trait;
+    use axum::Router;
+    use futures::StreamExt;
+    use graphql_parser::query::{Definition, OperationDefinition, parse_query};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    struct MockFetcher {
+        entities: Vec<Value>,
+    }
+
+    #[async_trait]
+    impl EntityFetcher for MockFetcher {
+        async fn fetch_as_json(&self, entity_id: &Uuid) -> anyhow::Result<Value> {
+            self.entities
+                .iter()
+                .find(|e| e["id"] == entity_id.to_string())
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Entity not found: {}", entity_id))
+        }
+
+        async fn list_as_json(
+            &self,
+            _limit: Option<i32>,
+            _offset: Option<i32>,
+        ) -> anyhow::Result<Vec<Value>> {
+            Ok(self.entities.clone())
+        }
+    }
+
+    struct StubDescriptor(&'static str, &'static str);
+
+    impl EntityDescriptor for StubDescriptor {
+        fn entity_type(&self) -> &str {
+            self.0
+        }
+        fn plural(&self) -> &str {
+            self.1
+        }
+        fn build_routes(&self) -> Router {
+            Router::new()
+        }
+    }
+
+    /// Two orders, the first one with two invoices
+    async fn build_test_executor() -> (GraphQLExecutor, Uuid) {
+        let order_ids = [Uuid::new_v4(), Uuid::new_v4()];
+        let invoice_ids = [Uuid::new_v4(), Uuid::new_v4()];
+
+        let link_service = Arc::new(InMemoryLinkService::new());
+        for invoice_id in invoice_ids {
+            link_service
+                .create(LinkEntity::new(
+                    "has_invoice",
+                    order_ids[0],
+                    invoice_id,
+                    None,
+                ))
+                .await
+                .expect("should create link");
+        }
+
+        let entities = |ids: &[Uuid]| MockFetcher {
+            entities: ids
+                .iter()
+                .map(|id| json!({ "id": id.to_string(), "number": "N" }))
+                .collect(),
+        };
+        let mut fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::new();
+        fetchers.insert("order".to_string(), Arc::new(entities(&order_ids)));
+        fetchers.insert("invoice".to_string(), Arc::new(entities(&invoice_ids)));
+
+        let entity = |singular: &str, plural: &str| EntityConfig {
+            singular: singular.to_string(),
+            plural: plural.to_string(),
+            auth: EntityAuthConfig::default(),
+            id_policy: Default::default(),
+            fields: Default::default(),
+            min_update_interval: None,
+        };
+        let config = LinksConfig {
+            entities: vec![entity("order", "orders"), entity("invoice", "invoices")],
+            links: vec![LinkDefinition {
+                link_type: "has_invoice".to_string(),
+                source_type: "order".to_string(),
+                target_type: "invoice".to_string(),
+                forward_route_name: "invoices".to_string(),
+                reverse_route_name: "order".to_string(),
+                description: None,
+                required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
+                auth: None,
+                cardinality: None,
+                metadata_schema: None,
+            }],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+
+        let mut registry = EntityRegistry::new();
+        registry.register(Box::new(StubDescriptor("order", "orders")));
+        registry.register(Box::new(StubDescriptor("invoice", "invoices")));
+
+        let host = ServerHost::from_builder_components(
+            link_service,
+            config,
+            registry,
+            fetchers,
+            HashMap::new(),
+        )
+        .expect("should build test host");
+
+        (GraphQLExecutor::new(Arc::new(host)).await, order_ids[0])
+    }
+
+    async fn payloads(executor: &GraphQLExecutor, query: String) -> Vec<Value> {
+        executor.execute_incremental(query, None).collect().await
+    }
+
+    #[tokio::test]
+    async fn test_deferred_relation_follows_entity_data() {
+        let (executor, order_id) = build_test_executor().await;
+        let query = format!(
+            r#"{{ order(id: "{}") {{ id ... @defer(label: "invoices") {{ invoices {{ id }} }} }} }}"#,
+            order_id
+        );
+
+        let payloads = payloads(&executor, query).await;
+        assert_eq!(payloads.len(), 2);
+
+        assert_eq!(payloads[0]["hasNext"], true);
+        assert_eq!(payloads[0]["data"]["order"]["id"], order_id.to_string());
+        assert!(payloads[0]["data"]["order"].get("invoices").is_none());
+
+        let deferred = &payloads[1]["incremental"][0];
+        assert_eq!(payloads[1]["hasNext"], false);
+        assert_eq!(deferred["path"], json!(["order"]));
+        assert_eq!(deferred["label"], "invoices");
+        assert_eq!(deferred["data"]["invoices"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_streamed_list_delivers_items_after_initial_count() {
+        let (executor, _) = build_test_executor().await;
+
+        let payloads = payloads(
+            &executor,
+            "{ orders @stream(initialCount: 1) { id } }".to_string(),
+        )
+        .await;
+        assert_eq!(payloads.len(), 2);
+
+        assert_eq!(payloads[0]["data"]["orders"].as_array().unwrap().len(), 1);
+        let streamed = &payloads[1]["incremental"][0];
+        assert_eq!(streamed["path"], json!(["orders", 1]));
+        assert_eq!(streamed["items"].as_array().unwrap().len(), 1);
+        assert_eq!(payloads[1]["hasNext"], false);
+    }
+
+    #[tokio::test]
+    async fn test_nested_stream_inside_deferred_fragment() {
+        let (executor, order_id) = build_test_executor().await;
+        let query = format!(
+            r#"{{ order(id: "{}") {{ ... @defer {{ invoices @stream {{ id }} }} }} }}"#,
+            order_id
+        );
+
+        let payloads = payloads(&executor, query).await;
+        assert_eq!(payloads.len(), 4);
+        assert_eq!(payloads[1]["incremental"][0]["data"]["invoices"], json!([]));
+        assert_eq!(
+            payloads[3]["incremental"][0]["path"],
+            json!(["order", "invoices", 1])
+        );
+        assert_eq!(payloads[3]["hasNext"], false);
+    }
+
+    #[tokio::test]
+    async fn test_execute_resolves_deferred_fragments_inline() {
+        let (executor, order_id) = build_test_executor().await;
+        let query = format!(
+            r#"{{ order(id: "{}") {{ id ... @defer {{ invoices @stream {{ id }} }} }} }}"#,
+            order_id
+        );
+
+        let result = executor.execute(&query, None).await.unwrap();
+        let invoices = result["data"]["order"]["invoices"].as_array().unwrap();
+        assert_eq!(invoices.len(), 2);
+
+        // Nothing deferred: a single payload
+        let payloads = payloads(&executor, "{ orders { id } }".to_string()).await;
+        assert_eq!(payloads.len(), 1);
+        assert_eq!(payloads[0]["hasNext"], false);
+    }
+
+    #[test]
+    fn test_directive_arguments() {
+        let doc = parse_query::<String>(
+            r#"{ a @defer(if: false) b @defer(label: "l") c @stream(initialCount: 3, label: "s") }"#,
+        )
+        .unwrap();
+        let Definition::Operation(OperationDefinition::SelectionSet(set)) = &doc.definitions[0]
+        else {
+            panic!("expected a selection set");
+        };
+        let directives: Vec<_> = set
+            .items
+            .iter()
+            .map(|selection| match selection {
+                Selection::Field(field) => &field.directives,
+                _ => panic!("expected fields"),
+            })
+            .collect();
+
+        assert_eq!(deferred(directives[0]), None);
+        assert_eq!(deferred(directives[1]), Some(Some("l".to_string())));
+        assert_eq!(streamed(directives[2]), Some((Some("s".to_string()), 3)));
+        assert_eq!(streamed(directives[1]), None);
+    }
+}
@@ -11,8 +11,9 @@ use uuid::Uuid;
 
 use super::field_resolver;
 use super::utils;
+use crate::core::auth::AuthContext;
 use crate::core::events::{FrameworkEvent, LinkEvent};
-use crate::core::link::LinkEntity;
+use crate::core::link::{LinkEntity, LinkError};
 use crate::server::host::ServerHost;
 
 /// Create a link between two existing entities
@@ -36,7 +37,7 @@ pub async fn create_link_mutation(
 
     // Create the link
     let link_entity = LinkEntity::new(link_type, source_uuid, target_uuid, metadata);
-    let created_link = host.link_service.create(link_entity).await?;
+    let created_link = host.create_link(link_entity).await?;
 
     // Publish event to EventBus
     if let Some(event_bus) = host.event_bus() {
@@ -90,6 +91,7 @@ pub async fn delete_link_mutation(
 /// Create an entity and link it to another entity (e.g., createInvoiceForOrder)
 pub async fn create_and_link_mutation(
     host: &Arc<ServerHost>,
+    context: &AuthContext,
     field: &Field<'_, String>,
 ) -> Result<Value> {
     let field_name = field.name.as_str();
@@ -119,7 +121,7 @@ pub async fn create_and_link_mutation(
 
     // Create the entity
     if let Some(creator) = host.entity_creators.get(&entity_type) {
-        let created = creator.create_from_json(data).await?;
+        let created = host.create_entity(&entity_type, data, context).await?;
 
         // Extract the new entity's ID
         let entity_id = created
@@ -144,12 +146,21 @@ pub async fn create_and_link_mutation(
             lt
         } else {
             // Try to find link type from config
-            utils::find_link_type(&host.config.links, &parent_type, &entity_type)?
+            utils::find_link_type(&host.config().links, &parent_type, &entity_type)?
         };
 
-        // Create the link
+        // Create the link; if its cardinality rejects it, drop the new entity
+        // again so it is not left orphaned (best effort, as over REST)
         let link_entity = LinkEntity::new(actual_link_type, parent_uuid, entity_uuid, None);
-        let created_link = host.link_service.create(link_entity).await?;
+        let created_link = match host.create_link(link_entity).await {
+            Ok(link) => link,
+            Err(e) => {
+                if e.downcast_ref::<LinkError>().is_some() {
+                    let _ = creator.delete(&entity_uuid).await;
+                }
+                return Err(e);
+            }
+        };
 
         // Publish link creation event
         if let Some(event_bus) = host.event_bus() {
@@ -165,6 +176,7 @@ pub async fn create_and_link_mutation(
         // Resolve sub-fields for the created entity
         let resolved = field_resolver::resolve_entity_fields(
             host,
+            context,
             created,
             &field.selection_set.items,
             &entity_type,
@@ -212,7 +224,7 @@ pub async fn link_entities_mutation(
     let actual_link_type = if let Some(lt) = link_type {
         lt
     } else {
-        utils::find_link_type(&host.config.links, &source_type, &target_type)?
+        utils::find_link_type(&host.config().links, &source_type, &target_type)?
     };
 
     // Get optional metadata
@@ -220,7 +232,7 @@ pub async fn link_entities_mutation(
 
     // Create the link
     let link_entity = LinkEntity::new(actual_link_type, source_uuid, target_uuid, metadata);
-    let created_link = host.link_service.create(link_entity).await?;
+    let created_link = host.create_link(link_entity).await?;
 
     // Publish event to EventBus
     if let Some(event_bus) = host.event_bus() {
@@ -279,7 +291,7 @@ pub async fn unlink_entities_mutation(
     let actual_link_type = if let Some(lt) = link_type {
         Some(lt)
     } else {
-        utils::find_link_type(&host.config.links, &source_type, &target_type).ok()
+        utils::find_link_type(&host.config().links, &source_type, &target_type).ok()
     };
 
     // Find and delete the link
@@ -407,11 +419,17 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -422,11 +440,17 @@ mod tests {
                 reverse_route_name: "order".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
@@ -480,6 +504,31 @@ mod tests {
         assert!(link_result.get("id").is_some(), "should have id");
     }
 
+    #[tokio::test]
+    async fn test_create_link_mutation_rejects_a_duplicate_link() {
+        let (host, link_service) = default_host();
+        let executor = GraphQLExecutor::new(host).await;
+        let source_id = Uuid::new_v4();
+        let target_id = Uuid::new_v4();
+
+        let query = format!(
+            r#"mutation {{ createLink(sourceId: "{}", targetId: "{}", linkType: "has_invoice") {{ id }} }}"#,
+            source_id, target_id
+        );
+        executor
+            .execute(&query, None)
+            .await
+            .expect("should create link");
+        let result = executor.execute(&query, None).await;
+        assert!(result.is_err(), "a duplicate link should be rejected");
+
+        let links = link_service
+            .find_by_source(&source_id, Some("has_invoice"), None)
+            .await
+            .expect("should list links");
+        assert_eq!(links.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_create_link_mutation_missing_source_id() {
         let (host, _) = default_host();
@@ -871,11 +920,17 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -886,11 +941,17 @@ mod tests {
                 reverse_route_name: "order".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
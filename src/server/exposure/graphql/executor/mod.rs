@@ -5,17 +5,23 @@
 //!
 //! The executor is split into several sub-modules for better maintainability:
 //! - `core`: Main executor orchestration
+//! - `data_loader`: Per-request batching of relation lookups
 //! - `query_executor`: Query resolution logic
 //! - `mutation_executor`: Mutation resolution logic
 //! - `link_mutations`: Link-specific mutations
+//! - `incremental`: `@defer`/`@stream` incremental delivery
 //! - `field_resolver`: Field and relation resolution
 //! - `utils`: Utility functions
 
 #[cfg(feature = "graphql")]
 mod core;
 #[cfg(feature = "graphql")]
+mod data_loader;
+#[cfg(feature = "graphql")]
 mod field_resolver;
 #[cfg(feature = "graphql")]
+mod incremental;
+#[cfg(feature = "graphql")]
 mod link_mutations;
 #[cfg(feature = "graphql")]
 mod mutation_executor;
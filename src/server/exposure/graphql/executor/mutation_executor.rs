@@ -13,12 +13,18 @@ use uuid::Uuid;
 use super::field_resolver;
 use super::link_mutations;
 use super::utils;
+use crate::core::auth::AuthContext;
 use crate::core::events::{EntityEvent, FrameworkEvent};
 use crate::server::host::ServerHost;
 
 /// Resolve a mutation field (e.g., "createOrder", "updateInvoice", etc.)
+///
+/// Entity mutations run on behalf of `context`: see
+/// [`ServerHost::create_entity`] and the `owner` checks of
+/// [`ServerHost::update_entity`] and [`ServerHost::delete_entity`].
 pub async fn resolve_mutation_field(
     host: &Arc<ServerHost>,
+    context: &AuthContext,
     field: &Field<'_, String>,
 ) -> Result<Value> {
     let field_name = field.name.as_str();
@@ -30,7 +36,7 @@ pub async fn resolve_mutation_field(
 
     // Check for createAndLink mutation (e.g., "createInvoiceForOrder") - must be before create check
     if field_name.starts_with("create") && field_name.contains("For") {
-        return link_mutations::create_and_link_mutation(host, field).await;
+        return link_mutations::create_and_link_mutation(host, context, field).await;
     }
 
     // Check for link mutation (e.g., "linkInvoiceToOrder")
@@ -45,17 +51,17 @@ pub async fn resolve_mutation_field(
 
     // Check for create mutation (e.g., "createOrder")
     if field_name.starts_with("create") {
-        return create_entity_mutation(host, field).await;
+        return create_entity_mutation(host, context, field).await;
     }
 
     // Check for update mutation (e.g., "updateOrder")
     if field_name.starts_with("update") {
-        return update_entity_mutation(host, field).await;
+        return update_entity_mutation(host, context, field).await;
     }
 
     // Check for delete mutation (e.g., "deleteOrder")
     if field_name.starts_with("delete") {
-        return delete_entity_mutation(host, field).await;
+        return delete_entity_mutation(host, context, field).await;
     }
 
     // Check for deleteLink mutation
@@ -95,6 +101,7 @@ pub async fn resolve_mutation_field(
 /// Create an entity
 async fn create_entity_mutation(
     host: &Arc<ServerHost>,
+    context: &AuthContext,
     field: &Field<'_, String>,
 ) -> Result<Value> {
     let field_name = field.name.as_str();
@@ -105,8 +112,8 @@ async fn create_entity_mutation(
         .ok_or_else(|| anyhow::anyhow!("Missing required argument 'data'"))?;
 
     // Create the entity
-    if let Some(creator) = host.entity_creators.get(&entity_type) {
-        let created = creator.create_from_json(data).await?;
+    if host.entity_creators.contains_key(&entity_type) {
+        let created = host.create_entity(&entity_type, data, context).await?;
 
         // Publish event to EventBus
         if let Some(event_bus) = host.event_bus() {
@@ -121,6 +128,7 @@ async fn create_entity_mutation(
         // Resolve sub-fields
         let resolved = field_resolver::resolve_entity_fields(
             host,
+            context,
             created,
             &field.selection_set.items,
             &entity_type,
@@ -136,6 +144,7 @@ async fn create_entity_mutation(
 /// Update an entity
 async fn update_entity_mutation(
     host: &Arc<ServerHost>,
+    context: &AuthContext,
     field: &Field<'_, String>,
 ) -> Result<Value> {
     let field_name = field.name.as_str();
@@ -149,8 +158,10 @@ async fn update_entity_mutation(
         .ok_or_else(|| anyhow::anyhow!("Missing required argument 'data'"))?;
 
     // Update the entity
-    if let Some(creator) = host.entity_creators.get(&entity_type) {
-        let updated = creator.update_from_json(&uuid, data).await?;
+    if host.entity_creators.contains_key(&entity_type) {
+        let updated = host
+            .update_entity(&entity_type, &uuid, data, context)
+            .await?;
 
         // Publish event to EventBus
         if let Some(event_bus) = host.event_bus() {
@@ -164,6 +175,7 @@ async fn update_entity_mutation(
         // Resolve sub-fields
         let resolved = field_resolver::resolve_entity_fields(
             host,
+            context,
             updated,
             &field.selection_set.items,
             &entity_type,
@@ -179,6 +191,7 @@ async fn update_entity_mutation(
 /// Delete an entity
 async fn delete_entity_mutation(
     host: &Arc<ServerHost>,
+    context: &AuthContext,
     field: &Field<'_, String>,
 ) -> Result<Value> {
     let field_name = field.name.as_str();
@@ -190,8 +203,8 @@ async fn delete_entity_mutation(
     let uuid = Uuid::parse_str(&id)?;
 
     // Delete the entity
-    if let Some(creator) = host.entity_creators.get(&entity_type) {
-        creator.delete(&uuid).await?;
+    if host.entity_creators.contains_key(&entity_type) {
+        host.delete_entity(&entity_type, &uuid, context).await?;
 
         // Publish event to EventBus
         if let Some(event_bus) = host.event_bus() {
@@ -299,11 +312,17 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -314,11 +333,17 @@ mod tests {
                 reverse_route_name: "order".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
@@ -550,11 +575,17 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -565,11 +596,17 @@ mod tests {
                 reverse_route_name: "order".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
@@ -670,11 +707,17 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -685,11 +728,17 @@ mod tests {
                 reverse_route_name: "order".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
@@ -1,21 +1,67 @@
 //! Query execution for GraphQL
 
-use anyhow::{Result, bail};
-use graphql_parser::query::Field;
-use serde_json::Value;
+use anyhow::{Result, anyhow, bail};
+use graphql_parser::query::{Field, Selection};
+use serde_json::{Value, json};
 use std::sync::Arc;
 use uuid::Uuid;
 
+use super::data_loader::DataLoader;
 use super::field_resolver;
+use super::incremental::{self, Deferrals, Deferred};
 use super::utils;
+use crate::core::Cursor;
 use crate::server::host::ServerHost;
 
+/// Page size of a connection query without `first`
+const DEFAULT_CONNECTION_SIZE: usize = 20;
+
+/// Resolve the root selection set of a query
+///
+/// With `defer` set, `@defer` fragments and `@stream`ed list items are
+/// queued on it instead of being resolved.
+pub async fn resolve_selection_set<'s>(
+    loader: &DataLoader,
+    selections: &[Selection<'s, String>],
+    defer: Option<&Deferrals<'s>>,
+) -> Result<Value> {
+    let collected = incremental::collect_fields(selections, defer.is_some());
+    let mut result = serde_json::Map::new();
+
+    for field in collected.fields {
+        let field_value = resolve_query_field(loader, field, defer).await?;
+        result.insert(field.name.clone(), field_value);
+    }
+
+    if let Some(defer) = defer {
+        for (label, selections) in collected.deferred {
+            defer.push(Deferred::Root {
+                label,
+                selections: selections.to_vec(),
+            });
+        }
+    }
+
+    Ok(Value::Object(result))
+}
+
 /// Resolve a query field (e.g., "orders", "order", "invoice", etc.)
-pub async fn resolve_query_field(
-    host: &Arc<ServerHost>,
-    field: &Field<'_, String>,
+pub async fn resolve_query_field<'s>(
+    loader: &DataLoader,
+    field: &Field<'s, String>,
+    defer: Option<&Deferrals<'s>>,
 ) -> Result<Value> {
+    let host = loader.host();
     let field_name = field.name.as_str();
+    let path = vec![json!(field_name)];
+
+    // Check if this is a connection query (e.g., "ordersConnection")
+    if let Some(entity_type) = field_name
+        .strip_suffix("Connection")
+        .and_then(|plural| get_entity_type_from_plural(host, plural))
+    {
+        return resolve_connection_field(loader, field, entity_type).await;
+    }
 
     // Check if this is a plural query (e.g., "orders", "invoices")
     if let Some(entity_type) = get_entity_type_from_plural(host, field_name) {
@@ -28,11 +74,13 @@ pub async fn resolve_query_field(
             let entities = fetcher.list_as_json(limit, offset).await?;
 
             // Resolve sub-fields for each entity
-            let resolved_entities = field_resolver::resolve_entity_list(
-                host,
+            let resolved_entities = field_resolver::resolve_list_field(
+                loader,
                 entities,
-                &field.selection_set.items,
+                field,
                 entity_type,
+                defer,
+                &path,
             )
             .await?;
 
@@ -49,16 +97,20 @@ pub async fn resolve_query_field(
             .ok_or_else(|| anyhow::anyhow!("Missing required argument 'id'"))?;
         let uuid = Uuid::parse_str(&id)?;
 
-        // Fetch the entity
+        // Fetch the entity, if its `owner` policy lets the caller read it
         if let Some(fetcher) = host.entity_fetchers.get(entity_type) {
+            host.authorize_owner(entity_type, "get", &uuid, loader.context())
+                .await?;
             let entity = fetcher.fetch_as_json(&uuid).await?;
 
             // Resolve sub-fields
-            let resolved = field_resolver::resolve_entity_fields(
-                host,
+            let resolved = field_resolver::resolve_entity_fields_deferring(
+                loader,
                 entity,
                 &field.selection_set.items,
                 entity_type,
+                defer,
+                path,
             )
             .await?;
 
@@ -105,6 +157,101 @@ pub async fn resolve_query_field(
     bail!("Unknown query field: {}", field_name);
 }
 
+/// Resolve a Relay connection query (e.g., `ordersConnection(first: 10, after: "...")`)
+///
+/// Pages walk the newest-first keyset order of
+/// [`EntityFetcher::list_after_as_json`](crate::core::EntityFetcher::list_after_as_json);
+/// edge cursors are opaque [`Cursor`] tokens, so paging with `after` neither
+/// skips nor repeats entities when others are created meanwhile.
+async fn resolve_connection_field(
+    loader: &DataLoader,
+    field: &Field<'_, String>,
+    entity_type: &str,
+) -> Result<Value> {
+    let first = match utils::get_int_arg(field, "first") {
+        Some(first) => {
+            usize::try_from(first).map_err(|_| anyhow!("'first' must not be negative"))?
+        }
+        None => DEFAULT_CONNECTION_SIZE,
+    };
+    let after = utils::get_string_arg(field, "after")
+        .map(|token| Cursor::decode(&token))
+        .transpose()
+        .map_err(|e| anyhow!(e))?;
+    let fetcher = loader
+        .host()
+        .entity_fetchers
+        .get(entity_type)
+        .ok_or_else(|| anyhow!("Unknown entity type: {}", entity_type))?;
+
+    // One extra entity tells whether another page follows
+    let mut nodes = fetcher.list_after_as_json(after, first + 1).await?;
+    let has_next_page = nodes.len() > first;
+    nodes.truncate(first);
+    let cursors: Vec<Option<String>> = nodes
+        .iter()
+        .map(|node| Cursor::of_json(node).map(|c| c.encode()))
+        .collect();
+
+    let mut result = serde_json::Map::new();
+    for connection_field in incremental::collect_fields(&field.selection_set.items, false).fields {
+        let value = match connection_field.name.as_str() {
+            "edges" => {
+                let edge_fields =
+                    incremental::collect_fields(&connection_field.selection_set.items, false)
+                        .fields;
+                let resolved = match edge_fields.iter().find(|f| f.name == "node") {
+                    Some(node) => {
+                        field_resolver::resolve_entity_list(
+                            loader,
+                            nodes.clone(),
+                            &node.selection_set.items,
+                            entity_type,
+                        )
+                        .await?
+                    }
+                    None => Vec::new(),
+                };
+                let edges = cursors
+                    .iter()
+                    .enumerate()
+                    .map(|(index, cursor)| {
+                        let edge = edge_fields.iter().map(|edge_field| {
+                            let value = match edge_field.name.as_str() {
+                                "node" => resolved.get(index).cloned().unwrap_or(Value::Null),
+                                "cursor" => json!(cursor),
+                                _ => Value::Null,
+                            };
+                            (edge_field.name.clone(), value)
+                        });
+                        Value::Object(edge.collect())
+                    })
+                    .collect();
+                Value::Array(edges)
+            }
+            "pageInfo" => {
+                let page_info =
+                    incremental::collect_fields(&connection_field.selection_set.items, false)
+                        .fields
+                        .into_iter()
+                        .map(|info_field| {
+                            let value = match info_field.name.as_str() {
+                                "hasNextPage" => json!(has_next_page),
+                                "endCursor" => json!(cursors.last().cloned().flatten()),
+                                _ => Value::Null,
+                            };
+                            (info_field.name.clone(), value)
+                        });
+                Value::Object(page_info.collect())
+            }
+            _ => Value::Null,
+        };
+        result.insert(connection_field.name.clone(), value);
+    }
+
+    Ok(Value::Object(result))
+}
+
 /// Get entity type from plural field name (e.g., "orders" -> "order")
 fn get_entity_type_from_plural<'a>(host: &'a Arc<ServerHost>, field_name: &str) -> Option<&'a str> {
     for entity_type in host.entity_types() {
@@ -230,11 +377,17 @@ mod tests {
                     singular: "order".to_string(),
                     plural: "orders".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
                 EntityConfig {
                     singular: "invoice".to_string(),
                     plural: "invoices".to_string(),
                     auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
                 },
             ],
             links: vec![LinkDefinition {
@@ -245,11 +398,17 @@ mod tests {
                 reverse_route_name: "order".to_string(),
                 description: None,
                 required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
                 auth: None,
+                cardinality: None,
+                metadata_schema: None,
             }],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let mut registry = EntityRegistry::new();
@@ -457,4 +616,73 @@ mod tests {
 
         assert_eq!(get_entity_type_from_singular(&host, "widget"), None);
     }
+
+    #[tokio::test]
+    async fn test_connection_pages_through_entities_with_after_cursors() {
+        let mut orders = MockFetcher::new();
+        for i in 0..5 {
+            let order_id = Uuid::new_v4();
+            orders = orders.with_entity(
+                order_id,
+                json!({
+                    "id": order_id.to_string(),
+                    "created_at": format!("2024-01-0{}T00:00:00Z", i + 1),
+                }),
+            );
+        }
+        let mut fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::new();
+        fetchers.insert("order".to_string(), Arc::new(orders));
+        fetchers.insert("invoice".to_string(), Arc::new(MockFetcher::new()));
+        let executor = GraphQLExecutor::new(build_test_host(fetchers)).await;
+
+        let mut seen = Vec::new();
+        let mut after = String::new();
+        let mut pages = 0;
+        loop {
+            let query = format!(
+                "{{ ordersConnection(first: 2{}) {{ edges {{ cursor node {{ id created_at }} }} pageInfo {{ hasNextPage endCursor }} }} }}",
+                after
+            );
+            let result = executor.execute(&query, None).await.expect("should page");
+            let connection = &result["data"]["ordersConnection"];
+            let edges = connection["edges"].as_array().expect("edges");
+            assert!(edges.len() <= 2);
+            for edge in edges {
+                assert!(edge["cursor"].is_string());
+                seen.push(edge["node"]["created_at"].as_str().unwrap().to_string());
+            }
+            pages += 1;
+            if connection["pageInfo"]["hasNextPage"] != true {
+                break;
+            }
+            after = format!(", after: {}", connection["pageInfo"]["endCursor"]);
+        }
+
+        assert_eq!(pages, 3);
+        // Newest first, every entity exactly once
+        assert_eq!(
+            seen,
+            (1..=5)
+                .rev()
+                .map(|day| format!("2024-01-0{}T00:00:00Z", day))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connection_rejects_an_invalid_cursor() {
+        let mut fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::new();
+        fetchers.insert("order".to_string(), Arc::new(MockFetcher::new()));
+        fetchers.insert("invoice".to_string(), Arc::new(MockFetcher::new()));
+        let executor = GraphQLExecutor::new(build_test_host(fetchers)).await;
+
+        let err = executor
+            .execute(
+                r#"{ ordersConnection(after: "garbage") { pageInfo { hasNextPage } } }"#,
+                None,
+            )
+            .await
+            .expect_err("should reject the cursor");
+        assert!(err.to_string().contains("invalid cursor"));
+    }
 }
@@ -343,7 +343,12 @@ mod tests {
             reverse_route_name: source.to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: None,
+            cardinality: None,
+            metadata_schema: None,
         }
     }
 
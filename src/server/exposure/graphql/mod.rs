@@ -8,6 +8,8 @@ mod executor;
 mod schema;
 mod schema_generator; // Now a directory with sub-modules
 mod subscription_handler;
+#[cfg(feature = "graphql")]
+mod transport;
 
 #[cfg(feature = "graphql")]
 use crate::server::host::ServerHost;
@@ -19,15 +21,20 @@ use async_graphql::http::{GraphQLPlaygroundConfig, playground_source};
 use axum::{
     Router,
     extract::{Extension, Json as AxumJson},
-    response::{Html, IntoResponse},
+    http::request::Parts,
+    response::{Html, IntoResponse, Response},
     routing::{get, post},
 };
 #[cfg(feature = "graphql")]
 use executor::GraphQLExecutor;
 #[cfg(feature = "graphql")]
+use futures::StreamExt;
+#[cfg(feature = "graphql")]
 use serde::Deserialize;
 #[cfg(feature = "graphql")]
 use std::sync::Arc;
+#[cfg(feature = "graphql")]
+use transport::Transport;
 
 #[cfg(feature = "graphql")]
 #[derive(Debug, Deserialize)]
@@ -91,12 +98,35 @@ impl GraphQLExposure {
 
 #[cfg(feature = "graphql")]
 /// Handler for GraphQL queries and mutations using custom executor
+///
+/// Clients accepting `multipart/mixed` or `text/event-stream` get
+/// `@defer`/`@stream` results incrementally (see [`transport`]). Operations
+/// run as the caller the host's auth provider identifies.
 async fn graphql_handler_custom(
     Extension(host): Extension<Arc<ServerHost>>,
+    parts: Parts,
     AxumJson(request): AxumJson<GraphQLRequestBody>,
-) -> impl IntoResponse {
+) -> Response {
     // Create executor on each request (or we could cache it)
-    let executor = GraphQLExecutor::new(host).await;
+    let context = host.auth_context(&parts).await;
+    let executor = GraphQLExecutor::new(host).await.with_context(context);
+
+    if let Some(transport) = Transport::negotiate(&parts.headers) {
+        let mut payloads = executor.execute_incremental(request.query, request.variables);
+        let Some(mut initial) = payloads.next().await else {
+            return AxumJson(serde_json::json!({
+                "errors": [{ "message": "Execution aborted" }]
+            }))
+            .into_response();
+        };
+        if initial["hasNext"] == true {
+            return transport.respond(futures::stream::once(async { initial }).chain(payloads));
+        }
+        if let Some(obj) = initial.as_object_mut() {
+            obj.remove("hasNext");
+        }
+        return AxumJson(initial).into_response();
+    }
 
     match executor.execute(&request.query, request.variables).await {
         Ok(response) => AxumJson(response),
@@ -106,6 +136,7 @@ async fn graphql_handler_custom(
             }]
         })),
     }
+    .into_response()
 }
 
 #[cfg(feature = "graphql")]
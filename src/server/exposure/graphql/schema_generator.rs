@@ -56,6 +56,10 @@ impl SchemaGenerator {
             }
         }
 
+        // Generate Relay connection types for the list queries
+        sdl.push_str(&self.generate_connection_types());
+        sdl.push_str("\n\n");
+
         // Generate Query root
         sdl.push_str(&self.generate_query_root());
         sdl.push_str("\n\n");
@@ -145,6 +149,12 @@ impl SchemaGenerator {
                 "  {}(limit: Int, offset: Int): [{}!]!\n",
                 plural, type_name
             ));
+
+            // Connection query: ordersConnection(first: Int, after: String): OrderConnection!
+            query.push_str(&format!(
+                "  {}Connection(first: Int, after: String): {}Connection!\n",
+                plural, type_name
+            ));
         }
 
         // Add notification queries if NotificationStore is configured
@@ -160,6 +170,29 @@ impl SchemaGenerator {
         query
     }
 
+    /// Generate the `Connection` and `Edge` types of each entity, and `PageInfo`
+    fn generate_connection_types(&self) -> String {
+        let mut types = String::new();
+
+        for entity_type in self.host.entity_types() {
+            let type_name = Self::to_pascal_case(entity_type);
+            types.push_str(&format!(
+                "type {0}Connection {{\n  edges: [{0}Edge!]!\n  pageInfo: PageInfo!\n}}\n\n",
+                type_name
+            ));
+            types.push_str(&format!(
+                "type {0}Edge {{\n  node: {0}!\n  cursor: String!\n}}\n\n",
+                type_name
+            ));
+        }
+
+        types.push_str("type PageInfo {\n");
+        types.push_str("  hasNextPage: Boolean!\n");
+        types.push_str("  endCursor: String\n");
+        types.push('}');
+        types
+    }
+
     /// Generate the Mutation root type
     fn generate_mutation_root(&self) -> String {
         let mut mutation = String::from("type Mutation {\n");
@@ -190,7 +223,7 @@ impl SchemaGenerator {
 
         // Add typed link mutations for each entity combination
         mutation.push_str("\n  # Typed link mutations\n");
-        for link_config in &self.host.config.links {
+        for link_config in &self.host.config().links {
             let source_type = Self::to_pascal_case(&link_config.source_type);
             let target_type = Self::to_pascal_case(&link_config.target_type);
 
@@ -229,6 +262,7 @@ impl SchemaGenerator {
     ///
     /// Only generated when the host has an EventBus configured.
     /// Provides `onEvent` for streaming entity/link events with optional filters,
+    /// `entityChanged`/`linkChanged` for the events of one entity or link type,
     /// and `onNotification` for streaming notifications (when NotificationStore is configured).
     fn generate_subscription_root(&self) -> String {
         let mut sub = String::from("type Subscription {\n");
@@ -237,6 +271,12 @@ impl SchemaGenerator {
         sub.push_str("  \"\"\"Stream real-time events. All filter arguments are optional.\"\"\"\n");
         sub.push_str("  onEvent(kind: String, entityType: String, eventType: String, entityId: ID): EventEnvelope!\n");
 
+        // Per-type shorthands (all types when the argument is omitted)
+        sub.push_str("\n  \"\"\"Stream entity created/updated/deleted events.\"\"\"\n");
+        sub.push_str("  entityChanged(entityType: String): EventEnvelope!\n");
+        sub.push_str("\n  \"\"\"Stream link created/deleted events.\"\"\"\n");
+        sub.push_str("  linkChanged(linkType: String): EventEnvelope!\n");
+
         // Notification subscription (if NotificationStore is configured)
         if self.host.notification_store().is_some() {
             sub.push_str("\n  \"\"\"Stream real-time notifications. Filter by userId to receive only that user's notifications.\"\"\"\n");
@@ -327,7 +367,7 @@ impl SchemaGenerator {
     fn get_relations_for(&self, entity_type: &str) -> Vec<RelationInfo> {
         let mut relations = Vec::new();
 
-        for link_def in &self.host.config.links {
+        for link_def in &self.host.config().links {
             // Forward relation: order -> invoices
             if link_def.source_type == entity_type {
                 relations.push(RelationInfo {
@@ -355,7 +395,7 @@ impl SchemaGenerator {
     /// Get plural form of entity type
     fn get_plural(&self, entity_type: &str) -> String {
         self.host
-            .config
+            .config()
             .entities
             .iter()
             .find(|e| e.singular == entity_type)
@@ -644,6 +684,9 @@ mod tests {
                 singular: singular.to_string(),
                 plural: plural.to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             })
             .collect();
 
@@ -663,6 +706,7 @@ mod tests {
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         Arc::new(
@@ -692,7 +736,12 @@ mod tests {
             reverse_route_name: "order".to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: None,
+            cardinality: None,
+            metadata_schema: None,
         };
 
         let host = build_host_with_links(
@@ -719,7 +768,12 @@ mod tests {
             reverse_route_name: "order".to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: None,
+            cardinality: None,
+            metadata_schema: None,
         };
 
         let host = build_host_with_links(
@@ -747,7 +801,12 @@ mod tests {
             reverse_route_name: "parent_order".to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: None,
+            cardinality: None,
+            metadata_schema: None,
         };
         let link2 = LinkDefinition {
             link_type: "has_payment".to_string(),
@@ -757,7 +816,12 @@ mod tests {
             reverse_route_name: "parent_invoice".to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: None,
+            cardinality: None,
+            metadata_schema: None,
         };
 
         let host = build_host_with_links(
@@ -849,6 +913,22 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "graphql")]
+    #[tokio::test]
+    async fn test_generate_sdl_contains_connection_types() {
+        let host = build_host_with_links(vec![("order", "orders", None)], vec![]);
+        let sdl = SchemaGenerator::new(host).generate_sdl().await;
+
+        assert!(sdl.contains("ordersConnection(first: Int, after: String): OrderConnection!"));
+        assert!(
+            sdl.contains(
+                "type OrderConnection {\n  edges: [OrderEdge!]!\n  pageInfo: PageInfo!\n}"
+            )
+        );
+        assert!(sdl.contains("type OrderEdge {\n  node: Order!\n  cursor: String!\n}"));
+        assert!(sdl.contains("type PageInfo {\n  hasNextPage: Boolean!\n  endCursor: String\n}"));
+    }
+
     // -----------------------------------------------------------------------
     // generate_mutation_root tests
     // -----------------------------------------------------------------------
@@ -864,7 +944,12 @@ mod tests {
             reverse_route_name: "order".to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: None,
+            cardinality: None,
+            metadata_schema: None,
         };
 
         let host = build_host_with_links(
@@ -937,7 +1022,12 @@ mod tests {
             reverse_route_name: "parent_order".to_string(),
             description: None,
             required_fields: None,
+            required_fields_forward: None,
+            required_fields_reverse: None,
+            symmetric: false,
             auth: None,
+            cardinality: None,
+            metadata_schema: None,
         };
 
         let host = build_host_with_links(
@@ -1058,6 +1148,9 @@ mod tests {
                 singular: singular.to_string(),
                 plural: plural.to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             })
             .collect();
 
@@ -1077,6 +1170,7 @@ mod tests {
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
 
         let notification_store = Arc::new(NotificationStore::new());
@@ -1150,6 +1244,8 @@ mod tests {
             "should have Subscription type"
         );
         assert!(sub.contains("onEvent("), "should have onEvent subscription");
+        assert!(sub.contains("entityChanged(entityType: String): EventEnvelope!"));
+        assert!(sub.contains("linkChanged(linkType: String): EventEnvelope!"));
         assert!(
             sub.contains("EventEnvelope!"),
             "should reference EventEnvelope"
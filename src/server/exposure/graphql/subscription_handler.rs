@@ -5,6 +5,13 @@
 //!
 //! # Protocol Messages
 //!
+//! # Subscription fields
+//!
+//! - `onEvent(kind, entityType, eventType, entityId)` — any framework event
+//! - `entityChanged(entityType)` — entity events, optionally of one type
+//! - `linkChanged(linkType)` — link events, optionally of one link type
+//! - `onNotification(userId)` — in-app notifications
+//!
 //! Client → Server:
 //! - `connection_init` → Server responds with `connection_ack`
 //! - `subscribe { id, payload: { query, variables } }` → Server streams `next` messages
@@ -159,7 +166,7 @@ async fn handle_graphql_ws(socket: WebSocket, host: Arc<ServerHost>) {
                 let sub_type = detect_subscription_type(&payload.query);
 
                 match sub_type {
-                    SubscriptionType::OnEvent(filter) => {
+                    SubscriptionType::OnEvent { field, filter } => {
                         let event_bus = match host.event_bus() {
                             Some(bus) => bus.clone(),
                             None => {
@@ -175,7 +182,7 @@ async fn handle_graphql_ws(socket: WebSocket, host: Arc<ServerHost>) {
                         let sub_tx = out_tx.clone();
                         let sub_id = id.clone();
                         let handle = tokio::spawn(async move {
-                            run_subscription(event_bus, sub_id, filter, sub_tx).await;
+                            run_subscription(event_bus, sub_id, field, filter, sub_tx).await;
                         });
                         active_subscriptions.insert(id, handle);
                     }
@@ -224,9 +231,12 @@ async fn handle_graphql_ws(socket: WebSocket, host: Arc<ServerHost>) {
 }
 
 /// Run a single subscription, streaming filtered events from the EventBus
+///
+/// Each event is sent as `{"data": {<field>: <event>}}`.
 async fn run_subscription(
     event_bus: Arc<EventBus>,
     subscription_id: String,
+    field: &'static str,
     filter: SubscriptionFilter,
     tx: tokio::sync::mpsc::UnboundedSender<ServerMsg>,
 ) {
@@ -240,7 +250,7 @@ async fn run_subscription(
                     let payload = envelope_to_graphql_value(&envelope);
                     let msg = ServerMsg::Next {
                         id: subscription_id.clone(),
-                        payload: json!({"data": {"onEvent": payload}}),
+                        payload: json!({"data": {field: payload}}),
                     };
                     if tx.send(msg).is_err() {
                         break; // Receiver dropped
@@ -288,6 +298,7 @@ fn matches_filter(envelope: &EventEnvelope, filter: &SubscriptionFilter) -> bool
             },
             FrameworkEvent::Link(l) => match l {
                 LinkEvent::Created { link_type: lt, .. }
+                | LinkEvent::Updated { link_type: lt, .. }
                 | LinkEvent::Deleted { link_type: lt, .. } => lt == entity_type,
             },
         };
@@ -358,11 +369,18 @@ fn envelope_to_graphql_value(envelope: &EventEnvelope) -> Value {
                 source_id,
                 target_id,
                 metadata,
+            }
+            | LinkEvent::Updated {
+                link_type,
+                link_id,
+                source_id,
+                target_id,
+                metadata,
             } => json!({
                 "id": envelope.id.to_string(),
                 "timestamp": envelope.timestamp.to_rfc3339(),
                 "kind": "link",
-                "action": "created",
+                "action": envelope.event.action(),
                 "linkType": link_type,
                 "linkId": link_id.to_string(),
                 "sourceId": source_id.to_string(),
@@ -390,8 +408,12 @@ fn envelope_to_graphql_value(envelope: &EventEnvelope) -> Value {
 
 /// Detected subscription type from the query
 enum SubscriptionType {
-    /// `subscription { onEvent(...) { ... } }`
-    OnEvent(SubscriptionFilter),
+    /// `subscription { onEvent(...) { ... } }`, `entityChanged(...)` or
+    /// `linkChanged(...)`, with the field the events are reported under
+    OnEvent {
+        field: &'static str,
+        filter: SubscriptionFilter,
+    },
     /// `subscription { onNotification(userId: "...") { ... } }`
     OnNotification(Option<String>),
     /// Unknown subscription field
@@ -416,21 +438,36 @@ fn detect_subscription_type(query: &str) -> SubscriptionType {
                 if let graphql_parser::query::Selection::Field(field) = sel {
                     match field.name.as_str() {
                         "onEvent" => {
-                            return SubscriptionType::OnEvent(parse_subscription_filter(query));
+                            return SubscriptionType::OnEvent {
+                                field: "onEvent",
+                                filter: parse_subscription_filter(query),
+                            };
+                        }
+                        "entityChanged" => {
+                            return SubscriptionType::OnEvent {
+                                field: "entityChanged",
+                                filter: SubscriptionFilter {
+                                    kind: Some("entity".to_string()),
+                                    entity_type: string_argument(field, "entityType"),
+                                    ..Default::default()
+                                },
+                            };
+                        }
+                        "linkChanged" => {
+                            // Link events are matched on their link type by `entity_type`
+                            return SubscriptionType::OnEvent {
+                                field: "linkChanged",
+                                filter: SubscriptionFilter {
+                                    kind: Some("link".to_string()),
+                                    entity_type: string_argument(field, "linkType"),
+                                    ..Default::default()
+                                },
+                            };
                         }
                         "onNotification" => {
-                            let user_id = field
-                                .arguments
-                                .iter()
-                                .find(|(name, _)| name == "userId")
-                                .and_then(|(_, value)| {
-                                    if let graphql_parser::query::Value::String(s) = value {
-                                        Some(s.clone())
-                                    } else {
-                                        None
-                                    }
-                                });
-                            return SubscriptionType::OnNotification(user_id);
+                            return SubscriptionType::OnNotification(string_argument(
+                                field, "userId",
+                            ));
                         }
                         other => return SubscriptionType::Unknown(other.to_string()),
                     }
@@ -442,6 +479,21 @@ fn detect_subscription_type(query: &str) -> SubscriptionType {
     SubscriptionType::Unknown("(no subscription field)".to_string())
 }
 
+/// Value of a string literal argument of a subscription field
+fn string_argument(field: &graphql_parser::query::Field<'_, String>, name: &str) -> Option<String> {
+    field
+        .arguments
+        .iter()
+        .find(|(arg_name, _)| arg_name == name)
+        .and_then(|(_, value)| {
+            if let graphql_parser::query::Value::String(s) = value {
+                Some(s.clone())
+            } else {
+                None
+            }
+        })
+}
+
 /// Run a notification subscription, streaming from the NotificationStore's broadcast channel
 async fn run_notification_subscription(
     store: Arc<NotificationStore>,
@@ -974,6 +1026,7 @@ mod tests {
             run_subscription(
                 bus_clone,
                 "sub-1".to_string(),
+                "onEvent",
                 SubscriptionFilter {
                     entity_type: Some("order".to_string()),
                     ..Default::default()
@@ -1035,6 +1088,7 @@ mod tests {
             run_subscription(
                 bus_clone,
                 "sub-all".to_string(),
+                "onEvent",
                 SubscriptionFilter::default(),
                 tx,
             )
@@ -1072,6 +1126,130 @@ mod tests {
         handle.abort();
     }
 
+    // -----------------------------------------------------------------------
+    // entityChanged through the executor
+    // -----------------------------------------------------------------------
+
+    struct OrderCreator;
+
+    #[async_trait::async_trait]
+    impl crate::core::EntityCreator for OrderCreator {
+        async fn create_from_json(&self, mut data: Value) -> anyhow::Result<Value> {
+            data["id"] = json!(Uuid::new_v4());
+            Ok(data)
+        }
+
+        async fn update_from_json(&self, _entity_id: &Uuid, data: Value) -> anyhow::Result<Value> {
+            Ok(data)
+        }
+
+        async fn delete(&self, _entity_id: &Uuid) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct OrderFetcher;
+
+    #[async_trait::async_trait]
+    impl crate::core::EntityFetcher for OrderFetcher {
+        async fn fetch_as_json(&self, _entity_id: &Uuid) -> anyhow::Result<Value> {
+            Ok(json!({}))
+        }
+    }
+
+    struct OrderDescriptor;
+
+    impl crate::server::entity_registry::EntityDescriptor for OrderDescriptor {
+        fn entity_type(&self) -> &str {
+            "order"
+        }
+        fn plural(&self) -> &str {
+            "orders"
+        }
+        fn build_routes(&self) -> axum::Router {
+            axum::Router::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entity_changed_yields_executor_mutations() {
+        use crate::config::{EntityAuthConfig, EntityConfig, LinksConfig};
+        use crate::server::entity_registry::EntityRegistry;
+        use crate::server::exposure::graphql::executor::GraphQLExecutor;
+        use crate::storage::InMemoryLinkService;
+
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "order".to_string(),
+                plural: "orders".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        let mut registry = EntityRegistry::new();
+        registry.register(Box::new(OrderDescriptor));
+        let host = Arc::new(
+            ServerHost::from_builder_components(
+                Arc::new(InMemoryLinkService::new()),
+                config,
+                registry,
+                HashMap::from([("order".to_string(), Arc::new(OrderFetcher) as _)]),
+                HashMap::from([("order".to_string(), Arc::new(OrderCreator) as _)]),
+            )
+            .unwrap()
+            .with_event_bus(EventBus::new(16)),
+        );
+
+        let SubscriptionType::OnEvent { field, filter } = detect_subscription_type(
+            r#"subscription { entityChanged(entityType: "order") { entityId data } }"#,
+        ) else {
+            panic!("entityChanged should be an event subscription");
+        };
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let handle = tokio::spawn(run_subscription(
+            host.event_bus().unwrap().clone(),
+            "sub-orders".to_string(),
+            field,
+            filter,
+            tx,
+        ));
+        tokio::task::yield_now().await;
+
+        let executor = GraphQLExecutor::new(host).await;
+        let result = executor
+            .execute(
+                r#"mutation { createOrder(data: {name: "Order 1"}) { id } }"#,
+                None,
+            )
+            .await
+            .unwrap();
+        let order_id = result["data"]["createOrder"]["id"].clone();
+
+        let msg = tokio::time::timeout(std::time::Duration::from_millis(100), rx.recv())
+            .await
+            .expect("should receive within timeout")
+            .expect("should have message");
+        match msg {
+            ServerMsg::Next { id, payload } => {
+                assert_eq!(id, "sub-orders");
+                let change = &payload["data"]["entityChanged"];
+                assert_eq!(change["action"], "created");
+                assert_eq!(change["entityId"], order_id);
+                assert_eq!(change["data"]["name"], "Order 1");
+            }
+            other => panic!("expected Next, got {:?}", other),
+        }
+
+        handle.abort();
+    }
+
     // -----------------------------------------------------------------------
     // detect_subscription_type tests
     // -----------------------------------------------------------------------
@@ -1081,11 +1259,40 @@ mod tests {
         let query = r#"subscription { onEvent(kind: "entity") { id kind } }"#;
         let sub_type = detect_subscription_type(query);
         assert!(
-            matches!(sub_type, SubscriptionType::OnEvent(_)),
+            matches!(
+                sub_type,
+                SubscriptionType::OnEvent {
+                    field: "onEvent",
+                    ..
+                }
+            ),
             "should detect onEvent"
         );
     }
 
+    #[test]
+    fn test_detect_subscription_type_entity_and_link_changed() {
+        let query = r#"subscription { entityChanged(entityType: "order") { entityId } }"#;
+        match detect_subscription_type(query) {
+            SubscriptionType::OnEvent { field, filter } => {
+                assert_eq!(field, "entityChanged");
+                assert_eq!(filter.kind.as_deref(), Some("entity"));
+                assert_eq!(filter.entity_type.as_deref(), Some("order"));
+            }
+            _ => panic!("expected entityChanged to be an event subscription"),
+        }
+
+        let query = r#"subscription { linkChanged { linkId } }"#;
+        match detect_subscription_type(query) {
+            SubscriptionType::OnEvent { field, filter } => {
+                assert_eq!(field, "linkChanged");
+                assert_eq!(filter.kind.as_deref(), Some("link"));
+                assert_eq!(filter.entity_type, None);
+            }
+            _ => panic!("expected linkChanged to be an event subscription"),
+        }
+    }
+
     #[test]
     fn test_detect_subscription_type_on_notification() {
         let query = r#"subscription { onNotification(userId: "user-A") { id title } }"#;
@@ -0,0 +1,141 @@
+//! HTTP transports for incremental delivery (`@defer` / `@stream`)
+//!
+//! Clients opt in through the `Accept` header of `POST /graphql`:
+//!
+//! - `multipart/mixed` — one `application/json` part per payload, separated
+//!   by the `-` boundary. This is what Apollo Client sends
+//!   (`Accept: multipart/mixed;deferSpec=20220824,application/json`), as do
+//!   urql and Relay with the `meros` multipart parser.
+//! - `text/event-stream` — one `next` event per payload, then a `complete`
+//!   event, as in the "distinct connections" mode of `graphql-sse`.
+//!
+//! Other clients get a single `application/json` response with every
+//! deferred fragment and streamed item resolved inline. Requests that end up
+//! deferring nothing are also answered with plain JSON.
+//!
+//! Proxies in front of the server must not buffer these responses (e.g.
+//! `proxy_buffering off` for nginx), or payloads arrive all at once.
+
+use axum::body::Body;
+use axum::http::HeaderMap;
+use axum::http::header::{ACCEPT, CONTENT_TYPE};
+use axum::response::sse::{Event, Sse};
+use axum::response::{IntoResponse, Response};
+use futures::stream::{self, Stream, StreamExt};
+use serde_json::Value;
+use std::convert::Infallible;
+
+/// Content type of multipart responses, with the `-` boundary
+const MULTIPART_CONTENT_TYPE: &str = "multipart/mixed; boundary=\"-\"; deferSpec=20220824";
+
+/// Incremental delivery transport accepted by the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    Multipart,
+    EventStream,
+}
+
+impl Transport {
+    /// Pick the first incremental transport listed in `Accept`, if any
+    pub fn negotiate(headers: &HeaderMap) -> Option<Self> {
+        let accept = headers.get(ACCEPT)?.to_str().ok()?;
+        accept
+            .split(',')
+            .find_map(|media_type| match media_type.split(';').next()?.trim() {
+                "multipart/mixed" => Some(Self::Multipart),
+                "text/event-stream" => Some(Self::EventStream),
+                _ => None,
+            })
+    }
+
+    /// Stream `payloads` to the client
+    pub fn respond(self, payloads: impl Stream<Item = Value> + Send + 'static) -> Response {
+        match self {
+            Self::Multipart => (
+                [(CONTENT_TYPE, MULTIPART_CONTENT_TYPE)],
+                Body::from_stream(multipart_body(payloads).map(Ok::<_, Infallible>)),
+            )
+                .into_response(),
+            Self::EventStream => {
+                let events = payloads
+                    .map(|payload| Event::default().event("next").data(payload.to_string()))
+                    .chain(stream::once(async {
+                        Event::default().event("complete").data("")
+                    }))
+                    .map(Ok::<_, Infallible>);
+                Sse::new(events).into_response()
+            }
+        }
+    }
+}
+
+/// Frame payloads as `multipart/mixed` body chunks
+fn multipart_body(payloads: impl Stream<Item = Value>) -> impl Stream<Item = String> {
+    stream::once(async { "\r\n---".to_string() })
+        .chain(payloads.map(|payload| {
+            format!(
+                "\r\nContent-Type: application/json; charset=utf-8\r\n\r\n{}\r\n---",
+                payload
+            )
+        }))
+        .chain(stream::once(async { "--\r\n".to_string() }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use serde_json::json;
+
+    fn accept(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn test_negotiate_transport_from_accept() {
+        assert_eq!(
+            Transport::negotiate(&accept(
+                "multipart/mixed;deferSpec=20220824,application/json"
+            )),
+            Some(Transport::Multipart)
+        );
+        assert_eq!(
+            Transport::negotiate(&accept("application/json, text/event-stream")),
+            Some(Transport::EventStream)
+        );
+        assert_eq!(Transport::negotiate(&accept("application/json")), None);
+        assert_eq!(Transport::negotiate(&HeaderMap::new()), None);
+    }
+
+    #[tokio::test]
+    async fn test_multipart_body_framing() {
+        let payloads = vec![
+            json!({"data": {}, "hasNext": true}),
+            json!({"incremental": [], "hasNext": false}),
+        ];
+        let body: String = multipart_body(stream::iter(payloads.clone()))
+            .collect::<Vec<_>>()
+            .await
+            .concat();
+
+        // Key order inside a part depends on serde_json's `preserve_order`
+        // feature, so the parts are compared as JSON values
+        let part = "\r\nContent-Type: application/json; charset=utf-8\r\n\r\n";
+        let inner = body
+            .strip_prefix("\r\n---")
+            .and_then(|rest| rest.strip_suffix("\r\n-----\r\n"))
+            .expect("body should open and close with a boundary");
+        let parts: Vec<Value> = inner
+            .split("\r\n---")
+            .map(|chunk| {
+                let json = chunk
+                    .strip_prefix(part)
+                    .expect("part should have its headers");
+                serde_json::from_str(json).expect("part should be JSON")
+            })
+            .collect();
+        assert_eq!(parts, payloads);
+    }
+}
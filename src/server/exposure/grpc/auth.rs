@@ -0,0 +1,59 @@
+//! Bearer token authentication for the gRPC services
+//!
+//! gRPC metadata travels as HTTP/2 headers, so the interceptor runs in front
+//! of the tonic services as an axum middleware: it takes the
+//! `authorization: Bearer <token>` metadata, lets the [`AuthProvider`] turn
+//! the request head into an [`AuthContext`], and rejects the call with
+//! `UNAUTHENTICATED` when the token is missing, rejected by the provider, or
+//! resolves to [`AuthContext::Anonymous`]. Accepted calls carry the context
+//! in their extensions, where service implementations read it with
+//! `request.extensions().get::<AuthContext>()`.
+
+use crate::core::auth::{AuthContext, AuthProvider};
+use axum::extract::{Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::sync::Arc;
+use tonic::Status;
+
+/// A gRPC `UNAUTHENTICATED` response
+fn unauthenticated(message: &str) -> Response {
+    Status::unauthenticated(message).into_http::<axum::body::Body>()
+}
+
+/// Caller of a request, as authenticated by [`grpc_auth_middleware`]
+///
+/// Anonymous when the services run without authentication.
+pub(crate) fn caller<T>(request: &tonic::Request<T>) -> AuthContext {
+    request
+        .extensions()
+        .get::<AuthContext>()
+        .cloned()
+        .unwrap_or(AuthContext::Anonymous)
+}
+
+/// Middleware authenticating gRPC calls with the provider's bearer tokens
+pub async fn grpc_auth_middleware(
+    State(provider): State<Arc<dyn AuthProvider>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let has_bearer = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("Bearer ") && value.len() > "Bearer ".len());
+    if !has_bearer {
+        return unauthenticated("missing bearer token");
+    }
+
+    let (mut parts, body) = request.into_parts();
+    match provider.extract_context(&parts).await {
+        Ok(AuthContext::Anonymous) | Err(_) => unauthenticated("invalid bearer token"),
+        Ok(context) => {
+            parts.extensions.insert(context);
+            next.run(Request::from_parts(parts, body)).await
+        }
+    }
+}
@@ -2,19 +2,34 @@
 //!
 //! Implements generic CRUD operations for any registered entity type.
 //! Uses `EntityFetcher` and `EntityCreator` from the `ServerHost` to
-//! resolve operations dynamically.
+//! resolve operations dynamically. Entities are sent without the fields
+//! their `redacted_fields` withhold from the caller, as on the REST routes.
 
+use super::auth::caller;
 use super::convert::{json_to_struct, struct_to_json};
 use super::proto::{
     CreateEntityRequest, DeleteEntityRequest, DeleteEntityResponse, EntityResponse,
-    GetEntityRequest, ListEntitiesRequest, ListEntitiesResponse, UpdateEntityRequest,
-    entity_service_server::EntityService,
+    GetEntityRequest, ListEntitiesRequest, ListEntitiesResponse, StreamEntitiesRequest,
+    UpdateEntityRequest, entity_service_server::EntityService,
 };
+use crate::core::EntityFetcher;
+use crate::core::auth::AuthContext;
+use crate::core::ownership::OwnershipError;
+use crate::core::redaction::redact_entity;
 use crate::server::host::ServerHost;
+use std::pin::Pin;
 use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 use uuid::Uuid;
 
+/// Page size of `StreamEntities` when the request leaves it unset
+const DEFAULT_STREAM_PAGE_SIZE: i32 = 100;
+
+type StreamEntitiesStream =
+    Pin<Box<dyn tokio_stream::Stream<Item = Result<EntityResponse, Status>> + Send>>;
+
 /// gRPC Entity Service implementation
 ///
 /// Delegates all operations to the `ServerHost`'s entity fetchers and creators.
@@ -56,35 +71,114 @@ impl EntityServiceImpl {
                 Status::not_found(format!("Entity type '{}' not registered", entity_type))
             })
     }
+
+    /// `entity` as sent to `context`, without the fields redacted from it
+    fn entity_response(
+        &self,
+        entity_type: &str,
+        mut entity: serde_json::Value,
+        context: &AuthContext,
+    ) -> EntityResponse {
+        if let Some(fetcher) = self.host.entity_fetchers.get(entity_type) {
+            redact_entity(&mut entity, fetcher.redacted_fields(context));
+        }
+        EntityResponse {
+            data: Some(json_to_struct(&entity)),
+        }
+    }
+}
+
+/// Status of a failed entity operation: `owner` policy rejections keep their meaning
+fn entity_error(e: anyhow::Error, action: &str) -> Status {
+    match e.downcast_ref::<OwnershipError>() {
+        Some(OwnershipError::Unauthenticated) => Status::unauthenticated(e.to_string()),
+        Some(OwnershipError::Forbidden(_)) => Status::permission_denied(e.to_string()),
+        None => Status::internal(format!("Failed to {} entity: {}", action, e)),
+    }
+}
+
+/// Send every entity of `fetchers` to `tx`, reading `page_size` at a time
+///
+/// Entities lose the fields redacted from `context`. Stops at the first
+/// short page of each fetcher, on a backend error (sent to the client), or
+/// when the client goes away.
+async fn stream_pages(
+    fetchers: Vec<Arc<dyn EntityFetcher>>,
+    page_size: i32,
+    context: AuthContext,
+    tx: mpsc::Sender<Result<EntityResponse, Status>>,
+) {
+    for fetcher in fetchers {
+        let redacted = fetcher.redacted_fields(&context);
+        let mut offset = 0;
+        loop {
+            let page = match fetcher.list_as_json(Some(page_size), Some(offset)).await {
+                Ok(page) => page,
+                Err(e) => {
+                    let _ = tx
+                        .send(Err(Status::internal(format!(
+                            "Failed to list entities: {}",
+                            e
+                        ))))
+                        .await;
+                    return;
+                }
+            };
+            let last = page.len() < page_size as usize;
+            for mut entity in page {
+                redact_entity(&mut entity, redacted);
+                let response = EntityResponse {
+                    data: Some(json_to_struct(&entity)),
+                };
+                if tx.send(Ok(response)).await.is_err() {
+                    tracing::debug!("gRPC entity stream: client disconnected, closing");
+                    return;
+                }
+            }
+            if last {
+                break;
+            }
+            offset += page_size;
+        }
+    }
 }
 
 #[tonic::async_trait]
 impl EntityService for EntityServiceImpl {
+    type StreamEntitiesStream = StreamEntitiesStream;
     async fn get_entity(
         &self,
         request: Request<GetEntityRequest>,
     ) -> Result<Response<EntityResponse>, Status> {
+        let context = caller(&request);
         let req = request.into_inner();
 
         let entity_id = Uuid::parse_str(&req.entity_id)
             .map_err(|e| Status::invalid_argument(format!("Invalid entity_id: {}", e)))?;
 
         let fetcher = self.get_fetcher(&req.entity_type)?;
+        self.host
+            .authorize_owner(&req.entity_type, "get", &entity_id, &context)
+            .await
+            .map_err(|e| entity_error(e, "fetch"))?;
 
         let json = fetcher
             .fetch_as_json(&entity_id)
             .await
             .map_err(|e| Status::internal(format!("Failed to fetch entity: {}", e)))?;
 
-        Ok(Response::new(EntityResponse {
-            data: Some(json_to_struct(&json)),
-        }))
+        Ok(Response::new(self.entity_response(
+            &req.entity_type,
+            json,
+            &context,
+        )))
     }
 
     async fn list_entities(
         &self,
         request: Request<ListEntitiesRequest>,
     ) -> Result<Response<ListEntitiesResponse>, Status> {
+        let context = caller(&request);
         let req = request.into_inner();
 
         let fetcher = self.get_fetcher(&req.entity_type)?;
@@ -96,12 +190,16 @@ impl EntityService for EntityServiceImpl {
             None
         };
 
-        let entities = fetcher
+        let mut entities = fetcher
             .list_as_json(limit, offset)
             .await
             .map_err(|e| Status::internal(format!("Failed to list entities: {}", e)))?;
 
         let total = entities.len() as i32;
+        let redacted = fetcher.redacted_fields(&context);
+        for entity in &mut entities {
+            redact_entity(entity, redacted);
+        }
         let proto_entities = entities.iter().map(json_to_struct).collect();
 
         Ok(Response::new(ListEntitiesResponse {
@@ -110,13 +208,51 @@ impl EntityService for EntityServiceImpl {
         }))
     }
 
+    async fn stream_entities(
+        &self,
+        request: Request<StreamEntitiesRequest>,
+    ) -> Result<Response<Self::StreamEntitiesStream>, Status> {
+        let context = caller(&request);
+        let req = request.into_inner();
+
+        let fetchers = if req.entity_type.is_empty() {
+            let mut types: Vec<&String> = self.host.entity_fetchers.keys().collect();
+            types.sort();
+            types
+                .into_iter()
+                .map(|entity_type| self.host.entity_fetchers[entity_type].clone())
+                .collect()
+        } else {
+            vec![self.get_fetcher(&req.entity_type)?]
+        };
+        // Capped at the host's maximum page size, like REST list limits
+        let max_page_size = i32::try_from(self.host.pagination.max_limit)
+            .unwrap_or(i32::MAX)
+            .max(1);
+        let page_size = if req.page_size > 0 {
+            req.page_size
+        } else {
+            DEFAULT_STREAM_PAGE_SIZE
+        }
+        .min(max_page_size);
+
+        // At most one page is buffered ahead of the client
+        let (tx, rx) = mpsc::channel(page_size as usize);
+        tokio::spawn(stream_pages(fetchers, page_size, context, tx));
+
+        Ok(Response::new(
+            Box::pin(ReceiverStream::new(rx)) as Self::StreamEntitiesStream
+        ))
+    }
+
     async fn create_entity(
         &self,
         request: Request<CreateEntityRequest>,
     ) -> Result<Response<EntityResponse>, Status> {
+        let context = caller(&request);
         let req = request.into_inner();
 
-        let creator = self.get_creator(&req.entity_type)?;
+        self.get_creator(&req.entity_type)?;
 
         let data = req
             .data
@@ -124,10 +260,11 @@ impl EntityService for EntityServiceImpl {
             .map(struct_to_json)
             .unwrap_or(serde_json::Value::Object(serde_json::Map::new()));
 
-        let result = creator
-            .create_from_json(data)
+        let result = self
+            .host
+            .create_entity(&req.entity_type, data, &context)
             .await
-            .map_err(|e| Status::internal(format!("Failed to create entity: {}", e)))?;
+            .map_err(|e| entity_error(e, "create"))?;
 
         // Publish event if event bus is configured
         if let Some(ref bus) = self.host.event_bus
@@ -143,21 +280,24 @@ impl EntityService for EntityServiceImpl {
             ));
         }
 
-        Ok(Response::new(EntityResponse {
-            data: Some(json_to_struct(&result)),
-        }))
+        Ok(Response::new(self.entity_response(
+            &req.entity_type,
+            result,
+            &context,
+        )))
     }
 
     async fn update_entity(
         &self,
         request: Request<UpdateEntityRequest>,
     ) -> Result<Response<EntityResponse>, Status> {
+        let context = caller(&request);
         let req = request.into_inner();
 
         let entity_id = Uuid::parse_str(&req.entity_id)
             .map_err(|e| Status::invalid_argument(format!("Invalid entity_id: {}", e)))?;
 
-        let creator = self.get_creator(&req.entity_type)?;
+        self.get_creator(&req.entity_type)?;
 
         let data = req
             .data
@@ -165,10 +305,11 @@ impl EntityService for EntityServiceImpl {
             .map(struct_to_json)
             .unwrap_or(serde_json::Value::Object(serde_json::Map::new()));
 
-        let result = creator
-            .update_from_json(&entity_id, data)
+        let result = self
+            .host
+            .update_entity(&req.entity_type, &entity_id, data, &context)
             .await
-            .map_err(|e| Status::internal(format!("Failed to update entity: {}", e)))?;
+            .map_err(|e| entity_error(e, "update"))?;
 
         // Publish event if event bus is configured
         if let Some(ref bus) = self.host.event_bus {
@@ -181,26 +322,29 @@ impl EntityService for EntityServiceImpl {
             ));
         }
 
-        Ok(Response::new(EntityResponse {
-            data: Some(json_to_struct(&result)),
-        }))
+        Ok(Response::new(self.entity_response(
+            &req.entity_type,
+            result,
+            &context,
+        )))
     }
 
     async fn delete_entity(
         &self,
         request: Request<DeleteEntityRequest>,
     ) -> Result<Response<DeleteEntityResponse>, Status> {
+        let context = caller(&request);
         let req = request.into_inner();
 
         let entity_id = Uuid::parse_str(&req.entity_id)
             .map_err(|e| Status::invalid_argument(format!("Invalid entity_id: {}", e)))?;
 
-        let creator = self.get_creator(&req.entity_type)?;
+        self.get_creator(&req.entity_type)?;
 
-        creator
-            .delete(&entity_id)
+        self.host
+            .delete_entity(&req.entity_type, &entity_id, &context)
             .await
-            .map_err(|e| Status::internal(format!("Failed to delete entity: {}", e)))?;
+            .map_err(|e| entity_error(e, "delete"))?;
 
         // Publish event if event bus is configured
         if let Some(ref bus) = self.host.event_bus {
@@ -235,12 +379,15 @@ mod tests {
 
     struct MockEntityFetcher {
         entities: Arc<RwLock<HashMap<Uuid, serde_json::Value>>>,
+        /// The `limit` of every `list_as_json` call
+        limits: RwLock<Vec<Option<i32>>>,
     }
 
     impl MockEntityFetcher {
         fn new() -> Self {
             Self {
                 entities: Arc::new(RwLock::new(HashMap::new())),
+                limits: RwLock::new(Vec::new()),
             }
         }
 
@@ -267,6 +414,7 @@ mod tests {
             limit: Option<i32>,
             offset: Option<i32>,
         ) -> anyhow::Result<Vec<serde_json::Value>> {
+            self.limits.write().expect("lock poisoned").push(limit);
             let store = self.entities.read().expect("lock poisoned");
             let mut items: Vec<_> = store.values().cloned().collect();
             if let Some(off) = offset {
@@ -277,6 +425,10 @@ mod tests {
             }
             Ok(items)
         }
+
+        fn redacted_fields(&self, _context: &AuthContext) -> &'static [&'static str] {
+            &["card_number"]
+        }
     }
 
     // -----------------------------------------------------------------------
@@ -346,11 +498,15 @@ mod tests {
                 singular: "order".to_string(),
                 plural: "orders".to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         }
     }
 
@@ -502,6 +658,44 @@ mod tests {
         assert_eq!(inner.entities.len(), 2);
     }
 
+    #[tokio::test]
+    async fn get_and_list_entities_leave_out_redacted_fields() {
+        let fetcher = Arc::new(MockEntityFetcher::new());
+        let id = Uuid::new_v4();
+        fetcher.insert(
+            id,
+            json!({"id": id.to_string(), "name": "Order #1", "card_number": "4242"}),
+        );
+        let svc = EntityServiceImpl::new(make_host_with_mocks(
+            fetcher,
+            Arc::new(MockEntityCreator::new()),
+        ));
+
+        let got = svc
+            .get_entity(Request::new(GetEntityRequest {
+                entity_type: "order".to_string(),
+                entity_id: id.to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .data
+            .unwrap();
+        assert!(got.fields.contains_key("name"));
+        assert!(!got.fields.contains_key("card_number"));
+
+        let listed = svc
+            .list_entities(Request::new(ListEntitiesRequest {
+                entity_type: "order".to_string(),
+                limit: 0,
+                offset: 0,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!listed.entities[0].fields.contains_key("card_number"));
+    }
+
     #[tokio::test]
     async fn list_entities_with_limit_and_offset() {
         let fetcher = Arc::new(MockEntityFetcher::new());
@@ -550,6 +744,84 @@ mod tests {
         assert_eq!(err.code(), Code::NotFound);
     }
 
+    // -----------------------------------------------------------------------
+    // stream_entities tests
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn stream_entities_reads_every_page() {
+        use tokio_stream::StreamExt;
+
+        let fetcher = Arc::new(MockEntityFetcher::new());
+        for i in 0..7 {
+            fetcher.insert(Uuid::new_v4(), json!({"name": format!("Order #{}", i)}));
+        }
+
+        let svc = EntityServiceImpl::new(make_host_with_mocks(
+            fetcher,
+            Arc::new(MockEntityCreator::new()),
+        ));
+
+        let stream = svc
+            .stream_entities(Request::new(StreamEntitiesRequest {
+                entity_type: "order".to_string(),
+                page_size: 3,
+            }))
+            .await
+            .expect("stream_entities should succeed")
+            .into_inner();
+
+        let items: Vec<_> = stream.collect().await;
+        assert_eq!(items.len(), 7);
+        assert!(items.iter().all(|item| item.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn stream_entities_caps_the_page_size() {
+        use crate::core::query::PaginationConfig;
+        use tokio_stream::StreamExt;
+
+        let fetcher = Arc::new(MockEntityFetcher::new());
+        for i in 0..5 {
+            fetcher.insert(Uuid::new_v4(), json!({"name": format!("Order #{}", i)}));
+        }
+        let host = make_host_with_mocks(fetcher.clone(), Arc::new(MockEntityCreator::new()));
+        let host = Arc::into_inner(host)
+            .expect("host is not shared yet")
+            .with_pagination(PaginationConfig::new(10, 2));
+        let svc = EntityServiceImpl::new(Arc::new(host));
+
+        let stream = svc
+            .stream_entities(Request::new(StreamEntitiesRequest {
+                entity_type: "order".to_string(),
+                page_size: i32::MAX,
+            }))
+            .await
+            .expect("stream_entities should succeed")
+            .into_inner();
+
+        let items: Vec<_> = stream.collect().await;
+        assert_eq!(items.len(), 5);
+        let limits = fetcher.limits.read().expect("lock poisoned").clone();
+        assert_eq!(limits, vec![Some(2); 3]);
+    }
+
+    #[tokio::test]
+    async fn stream_entities_unknown_type_returns_not_found() {
+        let svc = EntityServiceImpl::new(make_host_with_mocks(
+            Arc::new(MockEntityFetcher::new()),
+            Arc::new(MockEntityCreator::new()),
+        ));
+
+        let result = svc
+            .stream_entities(Request::new(StreamEntitiesRequest {
+                entity_type: "unknown".to_string(),
+                page_size: 0,
+            }))
+            .await;
+        assert_eq!(result.err().map(|s| s.code()), Some(Code::NotFound));
+    }
+
     // -----------------------------------------------------------------------
     // create_entity tests
     // -----------------------------------------------------------------------
@@ -108,9 +108,9 @@ fn matches_filter(event: &FrameworkEvent, filter: &SubscribeRequest) -> bool {
 fn extract_link_type(event: &FrameworkEvent) -> Option<&str> {
     match event {
         FrameworkEvent::Link(link) => match link {
-            LinkEvent::Created { link_type, .. } | LinkEvent::Deleted { link_type, .. } => {
-                Some(link_type)
-            }
+            LinkEvent::Created { link_type, .. }
+            | LinkEvent::Updated { link_type, .. }
+            | LinkEvent::Deleted { link_type, .. } => Some(link_type),
         },
         FrameworkEvent::Entity(_) => None,
     }
@@ -172,6 +172,13 @@ fn envelope_to_response(envelope: &EventEnvelope) -> EventResponse {
                 source_id,
                 target_id,
                 metadata,
+            }
+            | LinkEvent::Updated {
+                link_type,
+                link_id,
+                source_id,
+                target_id,
+                metadata,
             } => (
                 String::new(),
                 link_id.to_string(),
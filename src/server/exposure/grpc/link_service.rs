@@ -4,12 +4,16 @@
 //! Uses the `LinkService` trait from the `ServerHost` for all operations
 //! and enriches link responses with entity data via `EntityFetcher`.
 
+use super::auth::caller;
 use super::convert::{json_to_struct, struct_to_json};
 use super::proto::{
     CreateLinkRequest, DeleteLinkRequest, DeleteLinkResponse, FindLinksRequest, GetLinkRequest,
-    LinkListResponse, LinkResponse, link_service_server::LinkService as LinkServiceTrait,
+    LinkListResponse, LinkResponse, UpdateLinkRequest,
+    link_service_server::LinkService as LinkServiceTrait,
 };
-use crate::core::link::LinkEntity;
+use crate::core::link::{LinkEntity, LinkError};
+use crate::core::ownership::OwnershipError;
+use crate::core::validation::ValidationError;
 use crate::server::host::ServerHost;
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -61,6 +65,20 @@ impl LinkServiceImpl {
     }
 }
 
+/// Status of a failed link update: policy and metadata rejections keep their meaning
+fn update_error(e: anyhow::Error) -> Status {
+    if let Some(rejection) = e.downcast_ref::<OwnershipError>() {
+        return match rejection {
+            OwnershipError::Unauthenticated => Status::unauthenticated(e.to_string()),
+            OwnershipError::Forbidden(_) => Status::permission_denied(e.to_string()),
+        };
+    }
+    match e.downcast::<ValidationError>() {
+        Ok(invalid) => Status::invalid_argument(invalid.to_string()),
+        Err(e) => Status::internal(format!("Failed to update link: {}", e)),
+    }
+}
+
 #[tonic::async_trait]
 impl LinkServiceTrait for LinkServiceImpl {
     async fn create_link(
@@ -79,12 +97,13 @@ impl LinkServiceTrait for LinkServiceImpl {
 
         let link = LinkEntity::new(&req.link_type, source_id, target_id, metadata);
 
-        let created = self
-            .host
-            .link_service
-            .create(link)
-            .await
-            .map_err(|e| Status::internal(format!("Failed to create link: {}", e)))?;
+        let created = self.host.create_link(link).await.map_err(|e| {
+            if let Some(e @ LinkError::AlreadyExists { .. }) = e.downcast_ref::<LinkError>() {
+                Status::already_exists(e.to_string())
+            } else {
+                Status::internal(format!("Failed to create link: {}", e))
+            }
+        })?;
 
         // Publish event if event bus is configured
         if let Some(ref bus) = self.host.event_bus {
@@ -196,6 +215,36 @@ impl LinkServiceTrait for LinkServiceImpl {
         Ok(Response::new(LinkListResponse { links: responses }))
     }
 
+    async fn update_link(
+        &self,
+        request: Request<UpdateLinkRequest>,
+    ) -> Result<Response<LinkResponse>, Status> {
+        let context = caller(&request);
+        let req = request.into_inner();
+
+        let link_id = Uuid::parse_str(&req.link_id)
+            .map_err(|e| Status::invalid_argument(format!("Invalid link_id: {}", e)))?;
+
+        // Same checks and semantics as REST PUT: metadata is replaced (or
+        // deep-merged with `merge`), created_at kept
+        let updated = self
+            .host
+            .update_link(
+                &link_id,
+                req.metadata.as_ref().map(struct_to_json),
+                req.merge,
+                &context,
+                // Hosts with tenancy never serve gRPC (see `GrpcExposure`)
+                None,
+            )
+            .await
+            .map_err(update_error)?
+            .ok_or_else(|| Status::not_found(format!("Link '{}' not found", req.link_id)))?;
+
+        let response = self.link_to_response(&updated).await;
+        Ok(Response::new(response))
+    }
+
     async fn delete_link(
         &self,
         request: Request<DeleteLinkRequest>,
@@ -308,11 +357,15 @@ mod tests {
                 singular: "order".to_string(),
                 plural: "orders".to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         }
     }
 
@@ -514,6 +567,208 @@ mod tests {
         );
     }
 
+    // -----------------------------------------------------------------------
+    // update_link tests
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn update_link_replaces_metadata_and_bumps_updated_at() {
+        let link_svc = Arc::new(InMemoryLinkService::new());
+        let link = LinkEntity::new(
+            "has_invoice",
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Some(json!({"priority": "low", "notes": "n/a"})),
+        );
+        let (link_id, created_at) = (link.id, link.created_at);
+        link_svc.create(link).await.expect("should create link");
+        let svc = LinkServiceImpl::new(make_host(link_svc.clone()));
+
+        let inner = svc
+            .update_link(Request::new(UpdateLinkRequest {
+                link_id: link_id.to_string(),
+                metadata: Some(json_to_struct(&json!({"priority": "high"}))),
+                merge: false,
+            }))
+            .await
+            .expect("update_link should succeed")
+            .into_inner();
+
+        assert_eq!(
+            inner.metadata.as_ref().map(struct_to_json),
+            Some(json!({"priority": "high"}))
+        );
+        let stored = link_svc.get(&link_id).await.unwrap().unwrap();
+        assert_eq!(stored.metadata, Some(json!({"priority": "high"})));
+        assert_eq!(stored.created_at, created_at);
+        assert!(stored.updated_at > created_at);
+    }
+
+    #[tokio::test]
+    async fn update_link_merges_metadata_when_asked() {
+        let link_svc = Arc::new(InMemoryLinkService::new());
+        let link = LinkEntity::new(
+            "has_invoice",
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Some(json!({"priority": "low", "notes": "n/a"})),
+        );
+        let link_id = link.id;
+        link_svc.create(link).await.expect("should create link");
+        let svc = LinkServiceImpl::new(make_host(link_svc.clone()));
+
+        svc.update_link(Request::new(UpdateLinkRequest {
+            link_id: link_id.to_string(),
+            metadata: Some(json_to_struct(&json!({"priority": "high"}))),
+            merge: true,
+        }))
+        .await
+        .expect("update_link should succeed");
+
+        let stored = link_svc.get(&link_id).await.unwrap().unwrap();
+        assert_eq!(
+            stored.metadata,
+            Some(json!({"priority": "high", "notes": "n/a"}))
+        );
+    }
+
+    #[tokio::test]
+    async fn update_link_merge_keeps_nested_metadata() {
+        let link_svc = Arc::new(InMemoryLinkService::new());
+        let link = LinkEntity::new(
+            "has_invoice",
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Some(json!({
+                "priority": "low",
+                "billing": {"address": {"city": "Paris", "zip": "75001"}, "terms": "net-30"}
+            })),
+        );
+        let link_id = link.id;
+        link_svc.create(link).await.expect("should create link");
+        let svc = LinkServiceImpl::new(make_host(link_svc.clone()));
+
+        let inner = svc
+            .update_link(Request::new(UpdateLinkRequest {
+                link_id: link_id.to_string(),
+                metadata: Some(json_to_struct(&json!({
+                    "priority": "high",
+                    "shipping": {"carrier": {"name": "DHL"}}
+                }))),
+                merge: true,
+            }))
+            .await
+            .expect("update_link should succeed")
+            .into_inner();
+
+        let expected = json!({
+            "priority": "high",
+            "billing": {"address": {"city": "Paris", "zip": "75001"}, "terms": "net-30"},
+            "shipping": {"carrier": {"name": "DHL"}}
+        });
+        assert_eq!(
+            inner.metadata.as_ref().map(struct_to_json),
+            Some(expected.clone())
+        );
+        let stored = link_svc.get(&link_id).await.unwrap().unwrap();
+        assert_eq!(stored.metadata, Some(expected));
+    }
+
+    #[tokio::test]
+    async fn update_link_publishes_event() {
+        let link_svc = Arc::new(InMemoryLinkService::new());
+        let link = LinkEntity::new("has_invoice", Uuid::new_v4(), Uuid::new_v4(), None);
+        let link_id = link.id;
+        link_svc.create(link).await.expect("should create link");
+        let host = make_host_with_event_bus(link_svc);
+        let mut rx = host.event_bus().expect("event bus").subscribe();
+        let svc = LinkServiceImpl::new(host);
+
+        svc.update_link(Request::new(UpdateLinkRequest {
+            link_id: link_id.to_string(),
+            metadata: Some(json_to_struct(&json!({"priority": "high"}))),
+            merge: false,
+        }))
+        .await
+        .expect("update_link should succeed");
+
+        let envelope = rx.try_recv().expect("should have received a link event");
+        assert_eq!(envelope.event.event_kind(), "link");
+        assert_eq!(envelope.event.action(), "updated");
+        assert_eq!(envelope.event.entity_id(), Some(link_id));
+    }
+
+    #[tokio::test]
+    async fn update_link_validates_the_merged_metadata() {
+        let link_svc = Arc::new(InMemoryLinkService::new());
+        let link = LinkEntity::new(
+            "has_invoice",
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Some(json!({"priority": "low"})),
+        );
+        let link_id = link.id;
+        link_svc.create(link).await.expect("should create link");
+        let mut config = test_config();
+        config.links = vec![
+            serde_json::from_value(json!({
+                "link_type": "has_invoice",
+                "source_type": "order",
+                "target_type": "invoice",
+                "forward_route_name": "invoices",
+                "reverse_route_name": "orders",
+                "required_fields": ["priority"]
+            }))
+            .expect("valid link definition"),
+        ];
+        let host = ServerHost::from_builder_components(
+            link_svc.clone(),
+            config,
+            EntityRegistry::new(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .expect("should build host");
+        let svc = LinkServiceImpl::new(Arc::new(host));
+
+        let err = svc
+            .update_link(Request::new(UpdateLinkRequest {
+                link_id: link_id.to_string(),
+                metadata: Some(json_to_struct(&json!({"notes": "n/a"}))),
+                merge: false,
+            }))
+            .await
+            .expect_err("replacing the metadata drops `priority`");
+        assert_eq!(err.code(), Code::InvalidArgument);
+        let stored = link_svc.get(&link_id).await.unwrap().unwrap();
+        assert_eq!(stored.metadata, Some(json!({"priority": "low"})));
+    }
+
+    #[tokio::test]
+    async fn update_link_not_found_returns_not_found() {
+        let svc = LinkServiceImpl::new(make_host(Arc::new(InMemoryLinkService::new())));
+
+        let err = svc
+            .update_link(Request::new(UpdateLinkRequest {
+                link_id: Uuid::new_v4().to_string(),
+                metadata: None,
+                merge: false,
+            }))
+            .await
+            .expect_err("should fail when link does not exist");
+        assert_eq!(err.code(), Code::NotFound);
+
+        let err = svc
+            .update_link(Request::new(UpdateLinkRequest {
+                link_id: "not-a-uuid".to_string(),
+                metadata: None,
+                merge: false,
+            }))
+            .await
+            .expect_err("should reject an invalid id");
+        assert_eq!(err.code(), Code::InvalidArgument);
+    }
+
     // -----------------------------------------------------------------------
     // find_links_by_source tests
     // -----------------------------------------------------------------------
@@ -11,10 +11,19 @@
 //! - **EventService**: Real-time event streaming via server-streaming RPC
 //! - **NotificationService**: In-app notification CRUD and streaming (when NotificationStore is configured)
 //! - **ProtoGenerator**: Generates typed `.proto` files for client code generation
+//! - **Reflection** and **Health**: the standard `grpc.reflection` and
+//!   `grpc.health.v1` services used by `grpcurl`, load balancers and probes
 //!
 //! The gRPC services consume a `ServerHost` (same as REST, GraphQL, WebSocket)
 //! and are mounted alongside other exposures on the same port via axum interop.
 //!
+//! ## Tenancy
+//!
+//! The gRPC services do not scope calls to a tenant. Building a gRPC router
+//! for a host with a tenant header (see
+//! [`ServerBuilder::with_tenancy`](crate::server::ServerBuilder::with_tenancy))
+//! fails rather than expose every tenant's entities and links.
+//!
 //! ## Dual mode: standalone vs cohabitation
 //!
 //! Two builder methods are available:
@@ -29,6 +38,7 @@
 //!   or [`ServerBuilder::build_with_grpc`](crate::server::ServerBuilder::build_with_grpc)
 //!   for convenience.
 
+pub mod auth;
 pub mod entity_service;
 pub mod event_service;
 pub mod link_service;
@@ -40,12 +50,20 @@ mod convert;
 // Include the generated protobuf code
 pub mod proto {
     tonic::include_proto!("this_grpc");
+
+    /// Encoded `FileDescriptorSet` of `this_grpc.proto`, served by reflection
+    pub const FILE_DESCRIPTOR_SET: &[u8] =
+        tonic::include_file_descriptor_set!("this_grpc_descriptor");
 }
 
+use crate::core::AuthProvider;
 use crate::server::host::ServerHost;
 use anyhow::Result;
 use axum::Router;
+use std::convert::Infallible;
 use std::sync::Arc;
+use tonic::server::NamedService;
+use tower::Service;
 
 /// gRPC API exposure implementation
 ///
@@ -86,6 +104,7 @@ impl GrpcExposure {
     /// The router includes:
     /// - `EntityService` for CRUD operations on any entity type
     /// - `LinkService` for relationship management
+    /// - The gRPC reflection and health services
     /// - `GET /grpc/proto` endpoint for exporting the typed `.proto` definition
     /// - A tonic `UNIMPLEMENTED` fallback for unknown gRPC services
     ///
@@ -104,39 +123,53 @@ impl GrpcExposure {
     ///
     /// Returns a fully configured Axum router with gRPC services and tonic fallback.
     pub fn build_router(host: Arc<ServerHost>) -> Result<Router> {
-        use axum::routing::get;
-        use proto::entity_service_server::EntityServiceServer;
-        use proto::event_service_server::EventServiceServer;
-        use proto::link_service_server::LinkServiceServer;
-        use proto::notification_service_server::NotificationServiceServer;
-        use tonic::service::Routes;
-
-        // Create gRPC service implementations
-        let entity_svc = entity_service::EntityServiceImpl::new(host.clone());
-        let link_svc = link_service::LinkServiceImpl::new(host.clone());
-        let event_svc = event_service::EventServiceImpl::new(host.clone());
+        Ok(Self::build_router_no_fallback(host)?.merge(Self::unimplemented_fallback()))
+    }
 
-        // Build tonic Routes and convert to axum Router
-        // NOTE: Routes::default() installs a fallback(UNIMPLEMENTED) handler.
-        let mut builder = Routes::builder();
-        builder.add_service(EntityServiceServer::new(entity_svc));
-        builder.add_service(LinkServiceServer::new(link_svc));
-        builder.add_service(EventServiceServer::new(event_svc));
+    /// Build the standalone gRPC router, authenticating every call
+    ///
+    /// Like [`build_router`](Self::build_router), but calls must carry an
+    /// `authorization: Bearer <token>` metadata entry that `provider` turns
+    /// into an [`AuthContext`](crate::core::AuthContext); others fail with
+    /// `UNAUTHENTICATED`. Services find the context in the request
+    /// extensions. `GET /grpc/proto` stays public, as do the reflection and
+    /// health services, which probes call without credentials. See [`auth`].
+    pub fn build_router_with_auth(
+        host: Arc<ServerHost>,
+        provider: Arc<dyn AuthProvider>,
+    ) -> Result<Router> {
+        Self::ensure_untenanted(&host)?;
+        let services = Self::framework_services(host.clone()).layer(
+            axum::middleware::from_fn_with_state(provider, auth::grpc_auth_middleware),
+        );
+        Ok(services
+            .merge(Self::standard_services(&host)?)
+            .merge(Self::proto_route(host))
+            .merge(Self::unimplemented_fallback()))
+    }
 
-        // Conditionally add NotificationService when NotificationStore is configured
-        if host.notification_store().is_some() {
-            let notification_svc = notification_service::NotificationServiceImpl::new(host.clone());
-            builder.add_service(NotificationServiceServer::new(notification_svc));
+    /// Refuse hosts with tenancy enabled: the gRPC services are not tenant-scoped
+    fn ensure_untenanted(host: &ServerHost) -> Result<()> {
+        if let Some(header) = &host.tenant_header {
+            anyhow::bail!(
+                "gRPC exposure does not support tenancy (tenant header '{}' is configured)",
+                header
+            );
         }
+        Ok(())
+    }
 
-        let grpc_router = builder.routes().into_axum_router();
+    /// tonic's `UNIMPLEMENTED` fallback for unknown gRPC services
+    fn unimplemented_fallback() -> Router {
+        // Routes::default() is an empty router with the fallback installed
+        tonic::service::Routes::default().into_axum_router()
+    }
 
-        // Add the proto export endpoint
-        let proto_host = host.clone();
-        let proto_route =
-            Router::new().route("/grpc/proto", get(move || proto_export_handler(proto_host)));
+    /// `GET /grpc/proto`, exporting the typed `.proto` definition
+    fn proto_route(host: Arc<ServerHost>) -> Router {
+        use axum::routing::get;
 
-        Ok(grpc_router.merge(proto_route))
+        Router::new().route("/grpc/proto", get(move || proto_export_handler(host)))
     }
 
     /// Build a gRPC router **without** a fallback handler (**cohabitation mode**)
@@ -149,6 +182,7 @@ impl GrpcExposure {
     /// The router includes:
     /// - `EntityService` for CRUD operations on any entity type
     /// - `LinkService` for relationship management
+    /// - The gRPC reflection and health services
     /// - `GET /grpc/proto` endpoint for exporting the typed `.proto` definition
     ///
     /// # When to use
@@ -183,81 +217,116 @@ impl GrpcExposure {
     /// a bare `axum::Router` using `route_service()`, replicating the path format
     /// `/{package.ServiceName}/{*rest}` that tonic uses internally.
     pub fn build_router_no_fallback(host: Arc<ServerHost>) -> Result<Router> {
-        use axum::routing::get;
+        Self::ensure_untenanted(&host)?;
+        Ok(Self::framework_services(host.clone())
+            .merge(Self::standard_services(&host)?)
+            .merge(Self::proto_route(host)))
+    }
+
+    /// The entity, link, event and notification services
+    fn framework_services(host: Arc<ServerHost>) -> Router {
         use proto::entity_service_server::EntityServiceServer;
         use proto::event_service_server::EventServiceServer;
         use proto::link_service_server::LinkServiceServer;
         use proto::notification_service_server::NotificationServiceServer;
-        use tonic::server::NamedService;
-        use tower::ServiceExt;
 
         // Create gRPC service implementations
         let entity_svc = entity_service::EntityServiceImpl::new(host.clone());
         let link_svc = link_service::LinkServiceImpl::new(host.clone());
         let event_svc = event_service::EventServiceImpl::new(host.clone());
 
-        let entity_server = EntityServiceServer::new(entity_svc);
-        let link_server = LinkServiceServer::new(link_svc);
-        let event_server = EventServiceServer::new(event_svc);
-
-        // Build axum Router directly, bypassing tonic's Routes which installs
-        // a fallback via Routes::default() → axum::Router::new().fallback(unimplemented).
-        //
-        // This replicates what tonic::service::Routes::add_service() does internally:
-        //   router.route_service("/{ServiceName}/{*rest}", svc.map_request(body_convert))
-        let mut grpc_router = Router::new()
-            .route_service(
-                &format!(
-                    "/{}/{{*rest}}",
-                    EntityServiceServer::<entity_service::EntityServiceImpl>::NAME
-                ),
-                entity_server.map_request(|req: axum::http::Request<axum::body::Body>| {
-                    req.map(tonic::body::Body::new)
-                }),
-            )
-            .route_service(
-                &format!(
-                    "/{}/{{*rest}}",
-                    LinkServiceServer::<link_service::LinkServiceImpl>::NAME
-                ),
-                link_server.map_request(|req: axum::http::Request<axum::body::Body>| {
-                    req.map(tonic::body::Body::new)
-                }),
-            )
-            .route_service(
-                &format!(
-                    "/{}/{{*rest}}",
-                    EventServiceServer::<event_service::EventServiceImpl>::NAME
-                ),
-                event_server.map_request(|req: axum::http::Request<axum::body::Body>| {
-                    req.map(tonic::body::Body::new)
-                }),
-            );
+        let mut grpc_router = Router::new();
+        grpc_router = route_grpc_service(grpc_router, EntityServiceServer::new(entity_svc));
+        grpc_router = route_grpc_service(grpc_router, LinkServiceServer::new(link_svc));
+        grpc_router = route_grpc_service(grpc_router, EventServiceServer::new(event_svc));
 
         // Conditionally add NotificationService when NotificationStore is configured
         if host.notification_store().is_some() {
             let notification_svc = notification_service::NotificationServiceImpl::new(host.clone());
-            let notification_server = NotificationServiceServer::new(notification_svc);
-            grpc_router = grpc_router.route_service(
-                &format!(
-                    "/{}/{{*rest}}",
-                    NotificationServiceServer::<notification_service::NotificationServiceImpl>::NAME
-                ),
-                notification_server.map_request(|req: axum::http::Request<axum::body::Body>| {
-                    req.map(tonic::body::Body::new)
-                }),
+            grpc_router = route_grpc_service(
+                grpc_router,
+                NotificationServiceServer::new(notification_svc),
             );
         }
 
-        // Add the proto export endpoint
-        let proto_host = host.clone();
-        let proto_route =
-            Router::new().route("/grpc/proto", get(move || proto_export_handler(proto_host)));
+        grpc_router
+    }
+
+    /// The standard `grpc.reflection` and `grpc.health.v1` services
+    ///
+    /// Reflection describes `this_grpc.proto` (and the health service) so
+    /// tools like `grpcurl` work without the `.proto` file. Health reports
+    /// `SERVING` for the whole server (service `""`) and for each framework
+    /// service mounted.
+    fn standard_services(host: &ServerHost) -> Result<Router> {
+        use proto::entity_service_server::EntityServiceServer;
+        use proto::event_service_server::EventServiceServer;
+        use proto::link_service_server::LinkServiceServer;
+        use proto::notification_service_server::NotificationServiceServer;
+        use tonic_health::ServingStatus;
+
+        let reflection = || {
+            tonic_reflection::server::Builder::configure()
+                .register_encoded_file_descriptor_set(proto::FILE_DESCRIPTOR_SET)
+                .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        };
+        let reflection_v1 = reflection().build_v1()?;
+        let reflection_v1alpha = reflection().build_v1alpha()?;
+
+        let mut services = vec![
+            EntityServiceServer::<entity_service::EntityServiceImpl>::NAME,
+            LinkServiceServer::<link_service::LinkServiceImpl>::NAME,
+            EventServiceServer::<event_service::EventServiceImpl>::NAME,
+        ];
+        if host.notification_store().is_some() {
+            services.push(
+                NotificationServiceServer::<notification_service::NotificationServiceImpl>::NAME,
+            );
+        }
+        let (reporter, health) = tonic_health::server::health_reporter();
+        // The reporter's lock is fresh and uncontended, so this completes at
+        // once and keeps the router builders synchronous
+        futures::executor::block_on(async {
+            for service in services {
+                reporter
+                    .set_service_status(service, ServingStatus::Serving)
+                    .await;
+            }
+        });
 
-        Ok(grpc_router.merge(proto_route))
+        let mut grpc_router = Router::new();
+        grpc_router = route_grpc_service(grpc_router, reflection_v1);
+        grpc_router = route_grpc_service(grpc_router, reflection_v1alpha);
+        grpc_router = route_grpc_service(grpc_router, health);
+        Ok(grpc_router)
     }
 }
 
+/// Mount a tonic service on `router` at `/{package.ServiceName}/{*rest}`
+///
+/// This replicates what `tonic::service::Routes::add_service()` does
+/// internally, without the fallback `Routes::default()` installs.
+fn route_grpc_service<S>(router: Router, service: S) -> Router
+where
+    S: Service<axum::http::Request<tonic::body::Body>, Error = Infallible>
+        + NamedService
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    S::Response: axum::response::IntoResponse,
+    S::Future: Send + 'static,
+{
+    use tower::ServiceExt;
+
+    router.route_service(
+        &format!("/{}/{{*rest}}", S::NAME),
+        service.map_request(|req: axum::http::Request<axum::body::Body>| {
+            req.map(tonic::body::Body::new)
+        }),
+    )
+}
+
 /// Handler for GET /grpc/proto — exports a typed `.proto` definition
 ///
 /// This generates a `.proto` file dynamically based on the registered
@@ -6,7 +6,8 @@
 //!
 //! The generated `.proto` file includes:
 //! - A typed message per entity (e.g., `Order`, `Invoice`)
-//! - A typed CRUD service per entity (e.g., `OrderService`, `InvoiceService`)
+//! - A typed CRUD service per entity (e.g., `OrderService`, `InvoiceService`),
+//!   with a server-streaming `Stream<Entity>` RPC
 //! - A generic `LinkService` for relationship management
 
 use crate::server::host::ServerHost;
@@ -86,6 +87,10 @@ impl ProtoGenerator {
         proto.push_str("  int32 total = 2;\n");
         proto.push_str("}\n\n");
 
+        proto.push_str(&format!("message Stream{}Request {{\n", pascal));
+        proto.push_str("  int32 page_size = 1;\n");
+        proto.push_str("}\n\n");
+
         proto.push_str(&format!("message Create{}Request {{\n", pascal));
         // Exclude auto-generated fields
         for (i, (name, proto_type)) in fields.iter().enumerate() {
@@ -126,6 +131,10 @@ impl ProtoGenerator {
             "  rpc List{}(List{}Request) returns (List{}Response);\n",
             pascal, pascal, pascal
         ));
+        proto.push_str(&format!(
+            "  rpc Stream{}(Stream{}Request) returns (stream {});\n",
+            pascal, pascal, pascal
+        ));
         proto.push_str(&format!(
             "  rpc Create{}(Create{}Request) returns ({});\n",
             pascal, pascal, pascal
@@ -151,6 +160,7 @@ impl ProtoGenerator {
         proto.push_str("  string target_id = 4;\n");
         proto.push_str("  string created_at = 5;\n");
         proto.push_str("  string updated_at = 6;\n");
+        proto.push_str("  string metadata = 7;\n");
         proto.push_str("}\n\n");
 
         proto.push_str("message CreateLinkRequest {\n");
@@ -172,6 +182,12 @@ impl ProtoGenerator {
         proto.push_str("  repeated Link links = 1;\n");
         proto.push_str("}\n\n");
 
+        proto.push_str("message UpdateLinkRequest {\n");
+        proto.push_str("  string id = 1;\n");
+        proto.push_str("  string metadata = 2;\n");
+        proto.push_str("  bool merge = 3;\n");
+        proto.push_str("}\n\n");
+
         proto.push_str("message DeleteLinkRequest {\n");
         proto.push_str("  string id = 1;\n");
         proto.push_str("}\n\n");
@@ -188,6 +204,7 @@ impl ProtoGenerator {
         proto.push_str("  rpc GetLink(GetLinkRequest) returns (Link);\n");
         proto.push_str("  rpc FindLinksBySource(FindLinksRequest) returns (LinkListResponse);\n");
         proto.push_str("  rpc FindLinksByTarget(FindLinksRequest) returns (LinkListResponse);\n");
+        proto.push_str("  rpc UpdateLink(UpdateLinkRequest) returns (Link);\n");
         proto.push_str("  rpc DeleteLink(DeleteLinkRequest) returns (DeleteLinkResponse);\n");
         proto.push_str("}\n");
     }
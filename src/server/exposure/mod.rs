@@ -25,6 +25,3 @@ pub mod grpc;
 
 #[cfg(feature = "grpc")]
 pub use grpc::GrpcExposure;
-
-// Future expositions
-// pub mod openapi;
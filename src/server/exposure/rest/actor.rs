@@ -0,0 +1,131 @@
+//! The caller as `created_by`/`updated_by` actor
+//!
+//! Runs each request inside an [`actor::scope`] holding the caller's
+//! subject, so `DataService::create` and `update` stamp the entities they
+//! write (see [`crate::core::actor`]). Anonymous callers leave both fields
+//! null. Only mounted when the host has an auth provider.
+
+use super::redaction::redaction_context;
+use crate::core::actor;
+use crate::core::auth::AuthProvider;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::sync::Arc;
+
+/// Middleware running the request with the caller as current actor
+pub async fn actor_middleware(
+    State(auth_provider): State<Arc<dyn AuthProvider>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (parts, body) = request.into_parts();
+    let context = redaction_context(Some(&auth_provider), &parts).await;
+    let request = Request::from_parts(parts, body);
+    actor::scope(&context, next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DataService;
+    use crate::core::auth::AuthContext;
+    use crate::storage::InMemoryDataService;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Path;
+    use axum::http::request::Parts;
+    use axum::routing::{post, put};
+    use axum::{Json, Router, middleware};
+    use serde_json::Value;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    crate::impl_data_entity!(Note, "note", ["name"], {
+        text: String,
+    });
+
+    /// Reads the user from `x-user-id`
+    struct HeaderAuthProvider;
+
+    #[async_trait::async_trait]
+    impl AuthProvider for HeaderAuthProvider {
+        async fn extract_context(&self, parts: &Parts) -> anyhow::Result<AuthContext> {
+            let Some(user_id) = parts.headers.get("x-user-id") else {
+                return Ok(AuthContext::Anonymous);
+            };
+            Ok(AuthContext::User {
+                user_id: user_id.to_str()?.parse()?,
+                tenant_id: Uuid::nil(),
+                roles: vec![],
+            })
+        }
+
+        async fn is_owner(&self, _: &Uuid, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+
+        async fn has_role(&self, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+    }
+
+    fn app() -> Router {
+        let service = Arc::new(InMemoryDataService::<Note>::new());
+        let create = {
+            let service = service.clone();
+            move || async move {
+                let note = Note::new("note".to_string(), "active".to_string(), "a".to_string());
+                Json(service.create(note).await.unwrap())
+            }
+        };
+        let update = move |Path(id): Path<Uuid>| async move {
+            let mut note = service.get(&id).await.unwrap().unwrap();
+            note.text = "b".to_string();
+            Json(service.update(&id, note).await.unwrap())
+        };
+        Router::new()
+            .route("/notes", post(create))
+            .route("/notes/{id}", put(update))
+            .layer(middleware::from_fn_with_state(
+                Arc::new(HeaderAuthProvider) as Arc<dyn AuthProvider>,
+                actor_middleware,
+            ))
+    }
+
+    async fn send(app: &Router, method: &str, uri: &str, user: Option<Uuid>) -> Value {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(user) = user {
+            request = request.header("x-user-id", user.to_string());
+        }
+        let response = app
+            .clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_writes_are_stamped_with_the_caller() {
+        let app = app();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let note = send(&app, "POST", "/notes", Some(alice)).await;
+        assert_eq!(note["created_by"], format!("user:{}", alice));
+        assert_eq!(note["updated_by"], format!("user:{}", alice));
+
+        let uri = format!("/notes/{}", note["id"].as_str().unwrap());
+        let note = send(&app, "PUT", &uri, Some(bob)).await;
+        assert_eq!(note["created_by"], format!("user:{}", alice));
+        assert_eq!(note["updated_by"], format!("user:{}", bob));
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_writes_leave_the_stamps_null() {
+        let app = app();
+        let note = send(&app, "POST", "/notes", None).await;
+        assert!(note.get("created_by").is_none());
+        assert!(note.get("updated_by").is_none());
+    }
+}
@@ -0,0 +1,260 @@
+//! Audit log entries for REST entity routes
+//!
+//! Entity routes come from each module's `EntityDescriptor`, so a layer
+//! around them records an [`AuditEntry`] for every successful
+//! `POST /{entity_type}` (`create`), `PUT`/`PATCH /{entity_type}/{id}`
+//! (`update`) and `DELETE /{entity_type}/{id}` (`delete`).
+//!
+//! `before` is the entity as the type's fetcher returned it just before the
+//! request ran; `after` is the response body. The actor is the caller's
+//! [`AuthContext::subject`](crate::core::AuthContext::subject), known only
+//! when the host has an auth provider. Only mounted when the host has an
+//! audit log.
+
+use super::redaction::redaction_context;
+use crate::config::LinksConfig;
+use crate::core::audit::{AuditEntry, AuditLogService, AuditOperation};
+use crate::core::{AuthProvider, EntityFetcher};
+use crate::server::body::{BodyLimit, JsonResponse};
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::Response;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Shared state for the audit middleware
+#[derive(Clone)]
+pub struct AuditState {
+    audit_log: Arc<dyn AuditLogService>,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
+    /// Plural route segment -> singular entity type
+    entity_types: Arc<HashMap<String, String>>,
+    /// Singular entity type -> fetcher reading the `before` snapshot
+    fetchers: Arc<HashMap<String, Arc<dyn EntityFetcher>>>,
+}
+
+impl AuditState {
+    pub fn new(
+        audit_log: Arc<dyn AuditLogService>,
+        fetchers: Arc<HashMap<String, Arc<dyn EntityFetcher>>>,
+        config: &LinksConfig,
+        auth_provider: Option<Arc<dyn AuthProvider>>,
+    ) -> Self {
+        let entity_types = config
+            .entities
+            .iter()
+            .map(|e| (e.plural.clone(), e.singular.clone()))
+            .collect();
+        Self {
+            audit_log,
+            auth_provider,
+            entity_types: Arc::new(entity_types),
+            fetchers,
+        }
+    }
+
+    /// Resolve a mutating entity route to its entity type, operation and ID
+    fn target(&self, method: &Method, path: &str) -> Option<(&str, AuditOperation, Option<Uuid>)> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let (operation, id) = match (method, segments.as_slice()) {
+            (&Method::POST, [_]) => (AuditOperation::Create, None),
+            (&Method::PUT | &Method::PATCH, [_, id]) => (AuditOperation::Update, Some(*id)),
+            (&Method::DELETE, [_, id]) => (AuditOperation::Delete, Some(*id)),
+            _ => return None,
+        };
+        let entity_type = self.entity_types.get(segments[0])?;
+        // Malformed ids are answered by the handler and never mutate anything
+        let id = match id {
+            Some(id) => Some(Uuid::parse_str(id).ok()?),
+            None => None,
+        };
+        Some((entity_type.as_str(), operation, id))
+    }
+
+    /// Append an entry; the mutation already happened, so failures are only logged
+    async fn record(&self, entry: AuditEntry) {
+        if let Err(e) = self.audit_log.record(entry).await {
+            tracing::warn!(error = %e, "failed to record audit entry");
+        }
+    }
+}
+
+/// Middleware recording each successful entity mutation in the audit log
+pub async fn audit_middleware(
+    State(state): State<AuditState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((entity_type, operation, path_id)) =
+        state.target(request.method(), request.uri().path())
+    else {
+        return next.run(request).await;
+    };
+    let entity_type = entity_type.to_string();
+
+    let (parts, body) = request.into_parts();
+    let actor = match &state.auth_provider {
+        Some(provider) => Some(redaction_context(Some(provider), &parts).await.subject()),
+        None => None,
+    };
+    let before = match (path_id, state.fetchers.get(&entity_type)) {
+        (Some(id), Some(fetcher)) => fetcher.fetch_as_json(&id).await.ok(),
+        _ => None,
+    };
+
+    let limit = BodyLimit::of(&parts.extensions);
+    let response = next.run(Request::from_parts(parts, body)).await;
+    if !response.status().is_success() {
+        return response;
+    }
+
+    if operation == AuditOperation::Delete {
+        if let Some(entity_id) = path_id {
+            let entry = AuditEntry::new(entity_type, entity_id, operation, actor, before, None);
+            state.record(entry).await;
+        }
+        return response;
+    }
+
+    let body = match JsonResponse::read(response, limit).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    let after = body.json().cloned();
+    // Handlers answering without the entity (or its id) leave nothing to key the entry on
+    let entity_id = after
+        .as_ref()
+        .and_then(|data| data.get("id"))
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .or(path_id);
+    if let Some(entity_id) = entity_id {
+        let entry = AuditEntry::new(entity_type, entity_id, operation, actor, before, after);
+        state.record(entry).await;
+    }
+
+    body.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::storage::{InMemoryAuditLogService, InMemoryDataService};
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Path;
+    use axum::http::StatusCode;
+    use axum::routing::{post, put};
+    use axum::{Json, Router, middleware};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    crate::impl_data_entity!(Order, "order", ["name"], {
+        amount: f64,
+    });
+
+    fn config() -> LinksConfig {
+        LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "order".to_string(),
+                plural: "orders".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        }
+    }
+
+    fn request(method: &str, uri: &str, body: Value) -> Request {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_then_update_records_two_entries() {
+        let service = Arc::new(InMemoryDataService::<Order>::new());
+        let audit_log = Arc::new(InMemoryAuditLogService::new());
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::from([(
+            "order".to_string(),
+            service.clone() as Arc<dyn EntityFetcher>,
+        )]);
+
+        let create = {
+            let service = service.clone();
+            move |Json(body): Json<Value>| async move {
+                let order = Order::new(
+                    body["name"].as_str().unwrap().to_string(),
+                    "active".to_string(),
+                    body["amount"].as_f64().unwrap(),
+                );
+                (
+                    StatusCode::CREATED,
+                    Json(service.create(order).await.unwrap()),
+                )
+            }
+        };
+        let update = {
+            let service = service.clone();
+            move |Path(id): Path<Uuid>, Json(body): Json<Value>| async move {
+                let mut order = service.get(&id).await.unwrap().unwrap();
+                order.amount = body["amount"].as_f64().unwrap();
+                Json(service.update(&id, order).await.unwrap())
+            }
+        };
+        let app = Router::new()
+            .route("/orders", post(create))
+            .route("/orders/{id}", put(update))
+            .layer(middleware::from_fn_with_state(
+                AuditState::new(audit_log.clone(), Arc::new(fetchers), &config(), None),
+                audit_middleware,
+            ));
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                "/orders",
+                json!({"name": "A-1", "amount": 10.0}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let created: Value = serde_json::from_slice(&body).unwrap();
+        let id = Uuid::parse_str(created["id"].as_str().unwrap()).unwrap();
+
+        let response = app
+            .oneshot(request(
+                "PUT",
+                &format!("/orders/{}", id),
+                json!({"amount": 25.0}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let entries = audit_log.list("order", &id).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].operation, AuditOperation::Create);
+        assert_eq!(entries[0].before, None);
+        assert_eq!(entries[0].after.as_ref().unwrap()["amount"], 10.0);
+        assert_eq!(entries[1].operation, AuditOperation::Update);
+        assert_eq!(entries[1].before.as_ref().unwrap()["amount"], 10.0);
+        assert_eq!(entries[1].after.as_ref().unwrap()["amount"], 25.0);
+        assert!(entries.iter().all(|e| e.actor.is_none()));
+    }
+}
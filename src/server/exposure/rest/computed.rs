@@ -0,0 +1,196 @@
+//! Computed fields in REST entity responses
+//!
+//! Successful JSON responses to `GET /{entity_type}` and
+//! `GET /{entity_type}/{id}` get the [`EntityFetcher::computed_fields`] of
+//! each entity they carry merged in. The body may be one entity, an array of
+//! entities, or a paginated response whose `data` holds the entities.
+//! Computed fields are never stored, so the handlers do not see them.
+
+use crate::config::LinksConfig;
+use crate::core::module::EntityFetcher;
+use crate::server::body::{BodyLimit, JsonResponse};
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::Response;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Shared state for the computed fields middleware
+#[derive(Clone)]
+pub struct ComputedState {
+    /// Plural route segment -> fetcher computing the fields
+    fetchers: Arc<HashMap<String, Arc<dyn EntityFetcher>>>,
+}
+
+impl ComputedState {
+    pub fn new(fetchers: &HashMap<String, Arc<dyn EntityFetcher>>, config: &LinksConfig) -> Self {
+        let fetchers = config
+            .entities
+            .iter()
+            .filter_map(|e| Some((e.plural.clone(), fetchers.get(&e.singular)?.clone())))
+            .collect();
+        Self {
+            fetchers: Arc::new(fetchers),
+        }
+    }
+
+    /// Whether no entity type can compute anything
+    pub fn is_empty(&self) -> bool {
+        self.fetchers.is_empty()
+    }
+
+    fn target(&self, method: &Method, path: &str) -> Option<&Arc<dyn EntityFetcher>> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match (method, segments.len()) {
+            (&Method::GET, 1 | 2) => self.fetchers.get(segments[0]),
+            _ => None,
+        }
+    }
+}
+
+/// Merge the computed fields of `entity`, if it is an entity object
+pub(super) fn merge_entity(entity: &mut Value, fetcher: &dyn EntityFetcher) -> bool {
+    let computed = fetcher.computed_fields(entity);
+    match entity.as_object_mut() {
+        Some(object) if !computed.is_empty() => {
+            object.extend(computed);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Merge the computed fields into every entity of a response body
+///
+/// Returns whether anything was added.
+fn merge_body(body: &mut Value, fetcher: &dyn EntityFetcher) -> bool {
+    let entities = match body {
+        Value::Array(entities) => entities,
+        Value::Object(object) => match object.get_mut("data") {
+            Some(Value::Array(entities)) => entities,
+            _ => return merge_entity(body, fetcher),
+        },
+        _ => return false,
+    };
+    let mut merged = false;
+    for entity in entities {
+        merged |= merge_entity(entity, fetcher);
+    }
+    merged
+}
+
+/// Middleware adding computed fields to entity reads
+pub async fn computed_middleware(
+    State(state): State<ComputedState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(fetcher) = state
+        .target(request.method(), request.uri().path())
+        .cloned()
+    else {
+        return next.run(request).await;
+    };
+
+    let limit = BodyLimit::of(request.extensions());
+    let response = next.run(request).await;
+    if !response.status().is_success() {
+        return response;
+    }
+    let mut body = match JsonResponse::read(response, limit).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    body.rewrite(|json| merge_body(json, &*fetcher));
+    body.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::storage::InMemoryDataService;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Path;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{Json, Router, middleware};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    crate::impl_data_entity!(Person, "person", ["name"], {
+        first_name: String,
+        last_name: String,
+    }, computed: [full_name]);
+
+    impl Person {
+        fn full_name(&self) -> String {
+            format!("{} {}", self.first_name, self.last_name)
+        }
+    }
+
+    fn config() -> LinksConfig {
+        LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "person".to_string(),
+                plural: "people".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_full_name_is_served_on_get_and_list() {
+        let service = Arc::new(InMemoryDataService::<Person>::new());
+        let person = service
+            .create(Person::new(
+                "ada".to_string(),
+                "active".to_string(),
+                "Ada".to_string(),
+                "Lovelace".to_string(),
+            ))
+            .await
+            .unwrap();
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::from([(
+            "person".to_string(),
+            service.clone() as Arc<dyn EntityFetcher>,
+        )]);
+
+        let get_person = {
+            let service = service.clone();
+            move |Path(id): Path<Uuid>| async move { Json(service.get(&id).await.unwrap().unwrap()) }
+        };
+        let list_people = {
+            let service = service.clone();
+            move || async move { Json(service.list().await.unwrap()) }
+        };
+        let app = Router::new()
+            .route("/people", get(list_people))
+            .route("/people/{id}", get(get_person))
+            .layer(middleware::from_fn_with_state(
+                ComputedState::new(&fetchers, &config()),
+                computed_middleware,
+            ));
+
+        for uri in [format!("/people/{}", person.id), "/people".to_string()] {
+            let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: Value = serde_json::from_slice(&body).unwrap();
+            let entity = if body.is_array() { &body[0] } else { &body };
+            assert_eq!(entity["full_name"], "Ada Lovelace");
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! ETags and conditional requests on `/{entity_type}/{id}`
+//!
+//! Successful `GET`, `PUT` and `PATCH` responses carrying an entity get an
+//! `ETag` header (see [`etag_of_json`]). A `GET` whose `If-None-Match` still
+//! matches the stored entity is answered with `304 Not Modified`, and a
+//! `PUT`/`PATCH` whose `If-Match` no longer does with
+//! `412 Precondition Failed`, both without reaching the entity handlers.
+//!
+//! The stored entity is checked through
+//! [`EntityFetcher::fetch_if_modified_as_json`] (backed by
+//! `DataService::get_if_modified`) only when a conditional header is
+//! present; requests for entities that cannot be fetched pass through.
+//! `If-Match` uses the weak comparison, like `If-None-Match`.
+//!
+//! A write whose `If-Match` matched runs inside an [`etag::if_match`] scope
+//! holding the checked state, so the backend only applies it while the
+//! entity is unchanged. A concurrent write landing between the check and
+//! the update also gets `412 Precondition Failed`.
+
+use crate::config::LinksConfig;
+use crate::core::etag::{self, CacheResult, Precondition, etag_matches, etag_of_json};
+use crate::core::extractors::error_body;
+use crate::core::module::EntityFetcher;
+use crate::server::body::{BodyLimit, JsonResponse};
+use axum::Json;
+use axum::body::HttpBody;
+use axum::extract::{Request, State};
+use axum::http::header::{ETAG, IF_MATCH, IF_NONE_MATCH};
+use axum::http::{HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Shared state for the conditional request middleware
+#[derive(Clone)]
+pub struct ConditionalState {
+    /// Plural route segment -> fetcher of the stored entities
+    fetchers: Arc<HashMap<String, Arc<dyn EntityFetcher>>>,
+}
+
+impl ConditionalState {
+    pub fn new(fetchers: &HashMap<String, Arc<dyn EntityFetcher>>, config: &LinksConfig) -> Self {
+        let fetchers = config
+            .entities
+            .iter()
+            .filter_map(|e| Some((e.plural.clone(), fetchers.get(&e.singular)?.clone())))
+            .collect();
+        Self {
+            fetchers: Arc::new(fetchers),
+        }
+    }
+
+    /// Whether no entity type can be served
+    pub fn is_empty(&self) -> bool {
+        self.fetchers.is_empty()
+    }
+
+    fn target(&self, path: &str) -> Option<(&Arc<dyn EntityFetcher>, Uuid)> {
+        let (plural, id) = path.trim_matches('/').split_once('/')?;
+        Some((self.fetchers.get(plural)?, Uuid::parse_str(id).ok()?))
+    }
+}
+
+/// Middleware adding ETags and answering `If-None-Match` / `If-Match`
+pub async fn conditional_middleware(
+    State(state): State<ConditionalState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().clone();
+    let reads = method == Method::GET || method == Method::HEAD;
+    if !reads && method != Method::PUT && method != Method::PATCH {
+        return next.run(request).await;
+    }
+    let Some((fetcher, id)) = state.target(request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let condition = request
+        .headers()
+        .get(if reads { IF_NONE_MATCH } else { IF_MATCH })
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let limit = BodyLimit::of(request.extensions());
+    let Some(condition) = condition else {
+        return with_etag(next.run(request).await, limit).await;
+    };
+
+    if reads {
+        if let Ok(CacheResult::NotModified) = fetcher
+            .fetch_if_modified_as_json(&id, Some(&condition))
+            .await
+        {
+            let mut response = StatusCode::NOT_MODIFIED.into_response();
+            // The matched validator is the current tag unless a list or `*` was sent
+            if !condition.contains(',')
+                && condition.trim() != "*"
+                && let Ok(etag) = HeaderValue::from_str(condition.trim())
+            {
+                response.headers_mut().insert(ETAG, etag);
+            }
+            return response;
+        }
+        return with_etag(next.run(request).await, limit).await;
+    }
+
+    // The write is held to the state checked here, not just compared once
+    let Ok(CacheResult::Modified(stored)) = fetcher.fetch_if_modified_as_json(&id, None).await
+    else {
+        return with_etag(next.run(request).await, limit).await;
+    };
+    let (Some(current), Some(precondition)) =
+        (etag_of_json(&stored), Precondition::of_json(&stored))
+    else {
+        return with_etag(next.run(request).await, limit).await;
+    };
+    if !etag_matches(&condition, &current) {
+        return precondition_failed(Some(current));
+    }
+    if condition.trim() == "*" {
+        return with_etag(next.run(request).await, limit).await;
+    }
+
+    let (response, refused) = etag::if_match(precondition, next.run(request)).await;
+    if refused {
+        let current = match fetcher.fetch_if_modified_as_json(&id, None).await {
+            Ok(CacheResult::Modified(stored)) => etag_of_json(&stored),
+            _ => None,
+        };
+        return precondition_failed(current);
+    }
+    with_etag(response, limit).await
+}
+
+/// `412 Precondition Failed`, with the entity's current tag when known
+fn precondition_failed(current: Option<String>) -> Response {
+    let mut response = (
+        StatusCode::PRECONDITION_FAILED,
+        Json(error_body(
+            "PRECONDITION_FAILED",
+            "entity was modified since the given ETag",
+            None,
+        )),
+    )
+        .into_response();
+    if let Some(etag) = current.and_then(|tag| tag.parse().ok()) {
+        response.headers_mut().insert(ETAG, etag);
+    }
+    response
+}
+
+/// Tag a successful response whose body is an entity
+///
+/// Bodies larger than `limit` (or of unknown size) are passed through
+/// untagged.
+async fn with_etag(response: Response, limit: BodyLimit) -> Response {
+    if response.status() != StatusCode::OK
+        || response.headers().contains_key(ETAG)
+        || response
+            .body()
+            .size_hint()
+            .upper()
+            .is_none_or(|size| size > limit.0 as u64)
+    {
+        return response;
+    }
+    let mut body = match JsonResponse::read(response, limit).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    let etag = body
+        .json()
+        .and_then(etag_of_json)
+        .and_then(|etag| HeaderValue::from_str(&etag).ok());
+    if let Some(etag) = etag {
+        body.parts.headers.insert(ETAG, etag);
+    }
+    body.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::core::etag::etag_of;
+    use crate::storage::InMemoryDataService;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Path;
+    use axum::routing::get;
+    use axum::{Router, middleware};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    crate::impl_data_entity!(Invoice, "invoice", ["name"], {
+        amount: i64,
+    });
+
+    type Service = Arc<InMemoryDataService<Invoice>>;
+
+    async fn get_invoice(State(service): State<Service>, Path(id): Path<Uuid>) -> Response {
+        match service.get(&id).await.unwrap() {
+            Some(invoice) => Json(invoice).into_response(),
+            None => StatusCode::NOT_FOUND.into_response(),
+        }
+    }
+
+    async fn put_invoice(
+        State(service): State<Service>,
+        Path(id): Path<Uuid>,
+        Json(body): Json<Value>,
+    ) -> Json<Invoice> {
+        let mut invoice = service.get(&id).await.unwrap().unwrap();
+        invoice.amount = body["amount"].as_i64().unwrap();
+        invoice.updated_at = chrono::Utc::now();
+        Json(service.update(&id, invoice).await.unwrap())
+    }
+
+    /// Loses a race: another request updates the invoice first
+    async fn race_invoice(State(service): State<Service>, Path(id): Path<Uuid>) -> Response {
+        let rival = service.clone();
+        tokio::spawn(async move {
+            let mut invoice = rival.get(&id).await.unwrap().unwrap();
+            invoice.amount = 999;
+            invoice.updated_at = chrono::Utc::now();
+            rival.update(&id, invoice).await.unwrap();
+        })
+        .await
+        .unwrap();
+
+        let mut invoice = service.get(&id).await.unwrap().unwrap();
+        invoice.amount = 250;
+        invoice.updated_at = chrono::Utc::now();
+        match service.update(&id, invoice).await {
+            Ok(invoice) => Json(invoice).into_response(),
+            Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+        }
+    }
+
+    async fn setup() -> (Router, Service, Invoice) {
+        let service = Arc::new(InMemoryDataService::<Invoice>::new());
+        let invoice = service
+            .create(Invoice::new("INV-1".to_string(), "active".to_string(), 100))
+            .await
+            .unwrap();
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "invoice".to_string(),
+                plural: "invoices".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> =
+            HashMap::from([("invoice".to_string(), service.clone() as _)]);
+        let router = Router::new()
+            .route(
+                "/invoices/{id}",
+                get(get_invoice).put(put_invoice).patch(race_invoice),
+            )
+            .with_state(service.clone())
+            .layer(middleware::from_fn_with_state(
+                ConditionalState::new(&fetchers, &config),
+                conditional_middleware,
+            ));
+        (router, service, invoice)
+    }
+
+    fn request(method: Method, id: Uuid, header: Option<(&str, &str)>) -> Request {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(format!("/invoices/{id}"))
+            .header("content-type", "application/json");
+        if let Some((name, value)) = header {
+            builder = builder.header(name, value);
+        }
+        builder.body(Body::from(r#"{"amount": 250}"#)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_returns_etag_and_honors_if_none_match() {
+        let (router, _, invoice) = setup().await;
+
+        let response = router
+            .clone()
+            .oneshot(request(Method::GET, invoice.id, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let etag = response.headers()[ETAG].to_str().unwrap().to_string();
+        assert_eq!(etag, etag_of(&invoice));
+
+        let response = router
+            .oneshot(request(
+                Method::GET,
+                invoice.id,
+                Some(("if-none-match", &etag)),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[ETAG], etag.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_put_with_stale_if_match_is_rejected() {
+        let (router, _, invoice) = setup().await;
+        let etag = etag_of(&invoice);
+
+        let response = router
+            .clone()
+            .oneshot(request(Method::PUT, invoice.id, Some(("if-match", &etag))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let fresh = response.headers()[ETAG].to_str().unwrap().to_string();
+        assert_ne!(fresh, etag);
+
+        // A second writer still holding the original tag loses
+        let response = router
+            .clone()
+            .oneshot(request(Method::PUT, invoice.id, Some(("if-match", &etag))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+        assert_eq!(response.headers()[ETAG], fresh.as_str());
+
+        let response = router
+            .oneshot(request(Method::GET, invoice.id, None))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let stored: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(stored["amount"], 250);
+    }
+
+    #[tokio::test]
+    async fn test_write_racing_the_if_match_check_is_rejected() {
+        let (router, service, invoice) = setup().await;
+        let etag = etag_of(&invoice);
+
+        let response = router
+            .oneshot(request(
+                Method::PATCH,
+                invoice.id,
+                Some(("if-match", &etag)),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        // The rival's write stands and its tag is reported
+        let stored = service.get(&invoice.id).await.unwrap().unwrap();
+        assert_eq!(stored.amount, 999);
+        assert_eq!(response.headers()[ETAG], etag_of(&stored).as_str());
+    }
+}
@@ -0,0 +1,167 @@
+//! Enforcement of per-field constraints on REST entity payloads
+//!
+//! Checks `POST /{entity_type}` and `PUT`/`PATCH /{entity_type}/{id}` bodies
+//! against the entity's configured [`FieldConstraints`]. Failing requests
+//! never reach the entity handlers and are answered with `400 Bad Request`
+//! listing a `TOO_LONG`/`TOO_SHORT` error per field.
+//!
+//! Only mounted when some entity declares constraints.
+
+use crate::config::LinksConfig;
+use crate::core::extractors::ExtractorError;
+use crate::core::validation::{FieldConstraints, check_field_constraints};
+use crate::server::body::JsonRequest;
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Shared state for the field constraints middleware
+#[derive(Clone)]
+pub struct ConstraintsState {
+    /// Plural route segment -> constraints, for entities that declare some
+    constraints: Arc<HashMap<String, HashMap<String, FieldConstraints>>>,
+}
+
+impl ConstraintsState {
+    pub fn new(config: &LinksConfig) -> Self {
+        let constraints = config
+            .entities
+            .iter()
+            .filter(|e| !e.fields.is_empty())
+            .map(|e| (e.plural.clone(), e.fields.clone()))
+            .collect();
+        Self {
+            constraints: Arc::new(constraints),
+        }
+    }
+
+    /// Whether any entity needs enforcement
+    pub fn is_empty(&self) -> bool {
+        self.constraints.is_empty()
+    }
+
+    fn target(&self, method: &Method, path: &str) -> Option<&HashMap<String, FieldConstraints>> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match (method, segments.len()) {
+            (&Method::POST, 1) | (&Method::PUT | &Method::PATCH, 2) => {
+                self.constraints.get(segments[0])
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Middleware rejecting entity payloads that violate their field constraints
+pub async fn constraints_middleware(
+    State(state): State<ConstraintsState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(constraints) = state.target(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let body = match JsonRequest::read(request).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+
+    // Malformed JSON is left for the handler to report as usual
+    if let Some(payload) = body.json()
+        && let Err(e) = check_field_constraints(constraints, payload)
+    {
+        return ExtractorError::from(e).into_response();
+    }
+
+    next.run(body.into_request()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use axum::body::{Body, to_bytes};
+    use axum::http::StatusCode;
+    use axum::routing::{post, put};
+    use axum::{Json, Router, middleware};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "user".to_string(),
+                plural: "users".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: HashMap::from([(
+                    "name".to_string(),
+                    FieldConstraints {
+                        min_length: Some(1),
+                        max_length: Some(255),
+                    },
+                )]),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+
+        let echo = |Json(body): Json<Value>| async move { Json(body) };
+        Router::new()
+            .route("/users", post(echo))
+            .route("/users/{id}", put(echo))
+            .layer(middleware::from_fn_with_state(
+                ConstraintsState::new(&config),
+                constraints_middleware,
+            ))
+    }
+
+    async fn send(method: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024 * 64).await.unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_over_long_name_is_rejected_on_create_and_update() {
+        let long = "x".repeat(256);
+
+        let (status, body) = send("POST", "/users", json!({ "name": long })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["errors"][0]["field"], "/name");
+        assert_eq!(body["errors"][0]["code"], "TOO_LONG");
+
+        let (status, body) = send("PUT", "/users/1", json!({ "name": "" })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["errors"][0]["code"], "TOO_SHORT");
+    }
+
+    #[tokio::test]
+    async fn test_valid_name_passes_through() {
+        let name = "x".repeat(255);
+        let (status, body) = send("POST", "/users", json!({ "name": name })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["name"], name);
+    }
+}
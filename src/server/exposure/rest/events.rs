@@ -0,0 +1,217 @@
+//! Entity events for REST entity routes
+//!
+//! Entity routes come from each module's `EntityDescriptor` and know nothing
+//! of the [`EventBus`], so a layer around them publishes an [`EntityEvent`]
+//! for every successful `POST /{entity_type}` (`created`),
+//! `PUT /{entity_type}/{id}` (`updated`) and `DELETE /{entity_type}/{id}`
+//! (`deleted`). Created/updated events carry the response body as `data`.
+//!
+//! `PATCH` is not handled here: the patch layer publishes its own event.
+//! Only mounted when the host has an event bus.
+
+use crate::config::LinksConfig;
+use crate::core::events::{EntityEvent, EventBus, FrameworkEvent};
+use crate::server::body::{BodyLimit, JsonResponse};
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::Response;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Shared state for the entity events middleware
+#[derive(Clone)]
+pub struct EntityEventsState {
+    event_bus: Arc<EventBus>,
+    /// Plural route segment -> singular entity type
+    entity_types: Arc<HashMap<String, String>>,
+}
+
+impl EntityEventsState {
+    pub fn new(event_bus: Arc<EventBus>, config: &LinksConfig) -> Self {
+        let entity_types = config
+            .entities
+            .iter()
+            .map(|e| (e.plural.clone(), e.singular.clone()))
+            .collect();
+        Self {
+            event_bus,
+            entity_types: Arc::new(entity_types),
+        }
+    }
+
+    /// Resolve a mutating entity route to its entity type and raw ID segment
+    fn target<'a>(&self, method: &Method, path: &'a str) -> Option<(&str, Option<&'a str>)> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let id = match (method, segments.as_slice()) {
+            (&Method::POST, [_]) => None,
+            (&Method::PUT | &Method::DELETE, [_, id]) => Some(*id),
+            _ => return None,
+        };
+        let entity_type = self.entity_types.get(segments[0])?;
+        Some((entity_type.as_str(), id))
+    }
+}
+
+/// Middleware publishing an entity event for each successful entity mutation
+pub async fn entity_events_middleware(
+    State(state): State<EntityEventsState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().clone();
+    let Some((entity_type, raw_id)) = state.target(&method, request.uri().path()) else {
+        return next.run(request).await;
+    };
+    let entity_type = entity_type.to_string();
+    let path_id = raw_id.and_then(|id| Uuid::parse_str(id).ok());
+
+    let limit = BodyLimit::of(request.extensions());
+    let response = next.run(request).await;
+    if !response.status().is_success() {
+        return response;
+    }
+
+    if method == Method::DELETE {
+        if let Some(entity_id) = path_id {
+            state
+                .event_bus
+                .publish(FrameworkEvent::Entity(EntityEvent::Deleted {
+                    entity_type,
+                    entity_id,
+                }));
+        }
+        return response;
+    }
+
+    let body = match JsonResponse::read(response, limit).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    // Handlers answering without the entity (or its id) are not reported
+    if let Some(data) = body.json().cloned()
+        && let Some(entity_id) = data
+            .get("id")
+            .and_then(Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .or(path_id)
+    {
+        let event = if method == Method::POST {
+            EntityEvent::Created {
+                entity_type,
+                entity_id,
+                data,
+            }
+        } else {
+            EntityEvent::Updated {
+                entity_type,
+                entity_id,
+                data,
+            }
+        };
+        state.event_bus.publish(FrameworkEvent::Entity(event));
+    }
+
+    body.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use axum::body::{Body, to_bytes};
+    use axum::http::StatusCode;
+    use axum::routing::{post, put};
+    use axum::{Json, Router, middleware};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    fn app(bus: Arc<EventBus>) -> Router {
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "order".to_string(),
+                plural: "orders".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+
+        let create = |Json(mut body): Json<Value>| async move {
+            body["id"] = json!(Uuid::new_v4());
+            (StatusCode::CREATED, Json(body))
+        };
+        Router::new()
+            .route("/orders", post(create))
+            .route(
+                "/orders/{id}",
+                put(|| async { StatusCode::BAD_REQUEST })
+                    .delete(|| async { StatusCode::NO_CONTENT }),
+            )
+            .layer(middleware::from_fn_with_state(
+                EntityEventsState::new(bus, &config),
+                entity_events_middleware,
+            ))
+    }
+
+    fn request(method: &str, uri: &str, body: Value) -> Request {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_publishes_entity_created() {
+        let bus = Arc::new(EventBus::new(16));
+        let mut rx = bus.subscribe();
+
+        let response = app(bus)
+            .oneshot(request("POST", "/orders", json!({"name": "Order 1"})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let created: Value = serde_json::from_slice(&bytes).unwrap();
+
+        let envelope = rx.try_recv().expect("an event should be published");
+        assert_eq!(envelope.event.action(), "created");
+        assert_eq!(envelope.event.entity_type(), Some("order"));
+        assert_eq!(
+            envelope.event.entity_id().map(|id| id.to_string()),
+            created["id"].as_str().map(str::to_string)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_publishes_and_failures_do_not() {
+        let bus = Arc::new(EventBus::new(16));
+        let mut rx = bus.subscribe();
+        let id = Uuid::new_v4();
+
+        let uri = format!("/orders/{}", id);
+        app(bus.clone())
+            .oneshot(request("PUT", &uri, json!({})))
+            .await
+            .unwrap();
+        app(bus)
+            .oneshot(request("DELETE", &uri, Value::Null))
+            .await
+            .unwrap();
+
+        let envelope = rx.try_recv().expect("the delete should be published");
+        assert_eq!(envelope.event.action(), "deleted");
+        assert_eq!(envelope.event.entity_id(), Some(id));
+        assert!(rx.try_recv().is_err(), "the failed update is not published");
+    }
+}
@@ -0,0 +1,655 @@
+//! CSV and NDJSON export of REST entity lists
+//!
+//! `GET /{entity_type}` with `?format=csv` (or `Accept: text/csv`) answers
+//! with every entity of the type as CSV instead of a JSON page. The columns
+//! are `id`, the type's indexed fields (`Data::indexed_fields()`), its
+//! computed fields and the `created_at`/`updated_at` timestamps; strings are
+//! written as-is, and objects and arrays JSON-encoded in their cell.
+//!
+//! With `?format=ndjson` (or `Accept: application/x-ndjson`) each entity is
+//! written as one JSON object per line. Pagination parameters are ignored,
+//! except `limit`, which caps the number of lines. Filters (`filter` and
+//! `filter[field]=value` parameters) apply as on the JSON list; `sort` is
+//! refused with `400`, exports being streamed newest first.
+//!
+//! The rows are streamed from [`EntityFetcher::list_after_as_json`] one page
+//! at a time, so the export never holds the whole table; fetchers without a
+//! keyset query are scanned by its default implementation. When the first
+//! page cannot be listed the export answers `500`. Entity list handlers are
+//! bypassed, so this layer applies the entity's `list` auth policy (when the
+//! host has an auth provider), its redacted fields and the caller's tenant
+//! itself, and merges the [`EntityFetcher::computed_fields`] into each row.
+//! The computed columns of a CSV export are those of the first entity
+//! listed.
+
+use super::computed::merge_entity;
+use super::redaction::redaction_context;
+use crate::config::LinksConfig;
+use crate::core::auth::{AuthContext, AuthPolicy, AuthProvider};
+use crate::core::extractors::ExtractorError;
+use crate::core::module::EntityFetcher;
+use crate::core::query::{Cursor, FilterClause, QueryParams};
+use crate::core::redaction::redact_entity;
+use crate::core::tenant::TenantContext;
+use anyhow::Result;
+use axum::body::Body;
+use axum::extract::{Query, Request, State};
+use axum::http::header::{ACCEPT, CONTENT_TYPE};
+use axum::http::{HeaderValue, Method, Uri};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use futures::{Stream, StreamExt, stream};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Entities fetched per backend call while exporting
+pub const EXPORT_PAGE_SIZE: usize = 500;
+
+/// Columns every export starts or ends with
+const LEADING_COLUMNS: &[&str] = &["id"];
+const TRAILING_COLUMNS: &[&str] = &["created_at", "updated_at"];
+
+/// Response format requested for an entity list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Ndjson,
+}
+
+impl ExportFormat {
+    /// The format asked for by `?format=` or, failing that, the `Accept` header
+    fn of(request: &Request) -> Option<Self> {
+        if let Some(format) = query_param(request.uri().query(), "format") {
+            return match format {
+                "csv" => Some(ExportFormat::Csv),
+                "ndjson" => Some(ExportFormat::Ndjson),
+                _ => None,
+            };
+        }
+        let accept = request.headers().get(ACCEPT)?.to_str().ok()?;
+        accept
+            .split(',')
+            .map(|range| range.split(';').next().unwrap_or("").trim())
+            .find_map(|media_type| match media_type {
+                "text/csv" => Some(ExportFormat::Csv),
+                "application/x-ndjson" => Some(ExportFormat::Ndjson),
+                _ => None,
+            })
+    }
+
+    fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+}
+
+/// One entity type's export settings
+#[derive(Clone)]
+struct ExportTarget {
+    entity_type: String,
+    list_policy: String,
+    fetcher: Arc<dyn EntityFetcher>,
+}
+
+/// Shared state for the export middleware
+#[derive(Clone)]
+pub struct ExportState {
+    /// Plural route segment -> entity type exported there
+    targets: Arc<HashMap<String, ExportTarget>>,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
+}
+
+impl ExportState {
+    pub fn new(
+        fetchers: &HashMap<String, Arc<dyn EntityFetcher>>,
+        config: &LinksConfig,
+        auth_provider: Option<Arc<dyn AuthProvider>>,
+    ) -> Self {
+        let targets = config
+            .entities
+            .iter()
+            .filter_map(|e| {
+                let target = ExportTarget {
+                    entity_type: e.singular.clone(),
+                    list_policy: e.auth.list.clone(),
+                    fetcher: fetchers.get(&e.singular)?.clone(),
+                };
+                Some((e.plural.clone(), target))
+            })
+            .collect();
+        Self {
+            targets: Arc::new(targets),
+            auth_provider,
+        }
+    }
+
+    fn target(&self, method: &Method, path: &str) -> Option<&ExportTarget> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match (method, segments.as_slice()) {
+            (&Method::GET, [plural]) => self.targets.get(*plural),
+            _ => None,
+        }
+    }
+}
+
+/// Middleware serving entity lists as CSV or NDJSON when asked to
+pub async fn export_middleware(
+    State(state): State<ExportState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(format) = ExportFormat::of(&request) else {
+        return next.run(request).await;
+    };
+    let Some(target) = state
+        .target(request.method(), request.uri().path())
+        .cloned()
+    else {
+        return next.run(request).await;
+    };
+
+    let (parts, _) = request.into_parts();
+    let clauses = match export_filter(&parts.uri) {
+        Ok(clauses) => clauses,
+        Err(e) => return e.into_response(),
+    };
+    let context = redaction_context(state.auth_provider.as_ref(), &parts).await;
+    if state.auth_provider.is_some() {
+        let route = format!("export {}", target.entity_type);
+        if !AuthPolicy::parse_policy(&target.list_policy).evaluate(&context, &route) {
+            return match context {
+                AuthContext::Anonymous => ExtractorError::Unauthorized,
+                _ => ExtractorError::Forbidden(format!(
+                    "{} requires '{}'",
+                    route, target.list_policy
+                )),
+            }
+            .into_response();
+        }
+    }
+    let redacted = target.fetcher.redacted_fields(&context);
+    let tenant = parts.extensions.get::<TenantContext>().copied();
+
+    // Fail before the headers are sent if the entities cannot be listed at all
+    let first_page = match target
+        .fetcher
+        .list_after_as_json(None, EXPORT_PAGE_SIZE)
+        .await
+    {
+        Ok(page) => page,
+        Err(e) => {
+            tracing::warn!(error = %e, "entity export failed");
+            return ExtractorError::Internal(e.to_string()).into_response();
+        }
+    };
+    let computed: Vec<String> = first_page
+        .first()
+        .map(|entity| {
+            target
+                .fetcher
+                .computed_fields(entity)
+                .keys()
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+    let fetcher = target.fetcher.clone();
+    let entities = entity_pages(target.fetcher.clone(), first_page).map(move |page| {
+        page.map(|page| {
+            page.into_iter()
+                .filter(|entity| tenant.is_none_or(|tenant| tenant.owns_json(entity)))
+                .filter(|entity| {
+                    clauses
+                        .iter()
+                        .all(|clause| clause.matches_json(entity.get(&clause.field)))
+                })
+                .map(|mut entity| {
+                    merge_entity(&mut entity, &*fetcher);
+                    entity
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let body = match format {
+        ExportFormat::Csv => {
+            let columns = csv_columns(target.fetcher.searchable_fields(), &computed, redacted);
+            let header = csv_row(columns.iter().cloned());
+            let rows = entities.map(move |page| {
+                page.map(|page| {
+                    page.iter()
+                        .map(|entity| {
+                            csv_row(
+                                columns
+                                    .iter()
+                                    .map(|column| csv_cell(entity.get(column.as_str()))),
+                            )
+                        })
+                        .collect::<String>()
+                })
+            });
+            Body::from_stream(stream::once(async move { Ok(header) }).chain(rows))
+        }
+        ExportFormat::Ndjson => {
+            let limit = query_param(parts.uri.query(), "limit")
+                .and_then(|limit| limit.parse::<usize>().ok())
+                .filter(|limit| *limit > 0);
+            let lines = entities
+                .flat_map(|page| {
+                    stream::iter(match page {
+                        Ok(page) => page.into_iter().map(Ok).collect(),
+                        Err(e) => vec![Err(e)],
+                    })
+                })
+                .take(limit.unwrap_or(usize::MAX))
+                .map(move |entity| {
+                    entity.map(|mut entity| {
+                        redact_entity(&mut entity, redacted);
+                        format!("{}\n", entity)
+                    })
+                });
+            Body::from_stream(lines)
+        }
+    };
+
+    let mut response = Response::new(body);
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static(format.content_type()),
+    );
+    response
+}
+
+/// The filter clauses of an export request, as entity lists read them
+///
+/// `filter` and `filter[field]=value` parameters are combined by
+/// [`QueryParams::filter_value`]. `sort` is refused, since exports always
+/// stream newest first.
+fn export_filter(uri: &Uri) -> Result<Vec<FilterClause>, ExtractorError> {
+    let Query(params) = Query::<QueryParams>::try_from_uri(uri)
+        .map_err(|e| ExtractorError::JsonError(e.body_text()))?;
+    if params.sort.is_some() {
+        return Err(ExtractorError::JsonError(
+            "sort is not supported by exports, which list newest first".to_string(),
+        ));
+    }
+    if let Some(filter) = &params.filter {
+        serde_json::from_str::<Value>(filter)
+            .map_err(|e| ExtractorError::JsonError(format!("invalid filter: {}", e)))?;
+    }
+    match params.filter_value() {
+        Some(filter) => FilterClause::parse_all(&filter).map_err(ExtractorError::JsonError),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// The raw value of a query parameter, if present
+fn query_param<'a>(query: Option<&'a str>, name: &str) -> Option<&'a str> {
+    query?.split('&').find_map(|pair| {
+        pair.strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix('='))
+    })
+}
+
+/// Every entity of `fetcher`, newest first, one page at a time, starting
+/// with the already fetched `first` page
+fn entity_pages(
+    fetcher: Arc<dyn EntityFetcher>,
+    first: Vec<Value>,
+) -> impl Stream<Item = Result<Vec<Value>>> {
+    let after_first = next_cursor(&first);
+    // `None` once the last page has been sent
+    let rest = stream::unfold(after_first, move |cursor| {
+        let fetcher = fetcher.clone();
+        async move {
+            let cursor = cursor?;
+            match fetcher
+                .list_after_as_json(Some(cursor), EXPORT_PAGE_SIZE)
+                .await
+            {
+                Ok(page) if page.is_empty() => None,
+                Ok(page) => {
+                    let next = next_cursor(&page);
+                    Some((Ok(page), next))
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "entity export failed");
+                    Some((Err(e), None))
+                }
+            }
+        }
+    });
+    stream::once(async move { Ok(first) }).chain(rest)
+}
+
+/// The cursor of the page after `page`, `None` if `page` is the last one
+fn next_cursor(page: &[Value]) -> Option<Cursor> {
+    match page.len() {
+        EXPORT_PAGE_SIZE => page.last().and_then(Cursor::of_json),
+        _ => None,
+    }
+}
+
+/// The export columns: id, the indexed and computed fields, then the timestamps
+fn csv_columns(indexed: &[&str], computed: &[String], redacted: &[&str]) -> Vec<String> {
+    let mut columns: Vec<String> = LEADING_COLUMNS.iter().map(|c| c.to_string()).collect();
+    for field in indexed
+        .iter()
+        .copied()
+        .chain(computed.iter().map(String::as_str))
+    {
+        if !LEADING_COLUMNS.contains(&field)
+            && !TRAILING_COLUMNS.contains(&field)
+            && !columns.iter().any(|column| column == field)
+        {
+            columns.push(field.to_string());
+        }
+    }
+    columns.extend(TRAILING_COLUMNS.iter().map(|c| c.to_string()));
+    columns.retain(|column| !redacted.contains(&column.as_str()));
+    columns
+}
+
+/// Render one field as a CSV cell, JSON-encoding objects and arrays
+fn csv_cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Join cells into one CSV line, quoting the ones that need it (RFC 4180)
+fn csv_row(cells: impl Iterator<Item = String>) -> String {
+    let mut line = cells
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::core::entity::Data;
+    use crate::storage::InMemoryDataService;
+    use axum::body::to_bytes;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{Router, middleware};
+    use tower::ServiceExt;
+
+    crate::impl_data_entity!(Order, "order", ["name", "number"], {
+        number: String,
+        amount: f64,
+    }, computed: [label]);
+
+    impl Order {
+        fn label(&self) -> String {
+            format!("{} ({})", self.number, self.status)
+        }
+    }
+
+    fn config() -> LinksConfig {
+        LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "order".to_string(),
+                plural: "orders".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        }
+    }
+
+    /// Fetcher relying on the default `list_after_as_json`
+    struct OffsetOnlyFetcher(Vec<Value>);
+
+    #[async_trait::async_trait]
+    impl EntityFetcher for OffsetOnlyFetcher {
+        async fn fetch_as_json(&self, _entity_id: &uuid::Uuid) -> Result<Value> {
+            anyhow::bail!("not found")
+        }
+
+        async fn list_as_json(
+            &self,
+            limit: Option<i32>,
+            offset: Option<i32>,
+        ) -> Result<Vec<Value>> {
+            Ok(self
+                .0
+                .iter()
+                .skip(offset.unwrap_or(0) as usize)
+                .take(limit.unwrap_or(i32::MAX) as usize)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_without_keyset_listing_scans_list_as_json() {
+        let base = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap();
+        let entities: Vec<Value> = (0..(EXPORT_PAGE_SIZE + 3))
+            .map(|i| {
+                serde_json::json!({
+                    "id": uuid::Uuid::new_v4(),
+                    "created_at": (base + chrono::Duration::seconds(i as i64)).to_rfc3339(),
+                    "number": i,
+                })
+            })
+            .collect();
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::from([(
+            "order".to_string(),
+            Arc::new(OffsetOnlyFetcher(entities)) as _,
+        )]);
+        let app = Router::new()
+            .route("/orders", get(|| async { "json list" }))
+            .layer(middleware::from_fn_with_state(
+                ExportState::new(&fetchers, &config(), None),
+                export_middleware,
+            ));
+
+        let request = Request::builder()
+            .uri("/orders?format=ndjson")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let numbers: Vec<u64> = String::from_utf8(body.to_vec())
+            .unwrap()
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<Value>(line).unwrap()["number"]
+                    .as_u64()
+                    .unwrap()
+            })
+            .collect();
+        let expected: Vec<u64> = (0..(EXPORT_PAGE_SIZE as u64 + 3)).rev().collect();
+        assert_eq!(numbers, expected);
+    }
+
+    #[tokio::test]
+    async fn test_format_csv_streams_a_header_and_one_row_per_entity() {
+        let service = Arc::new(InMemoryDataService::<Order>::new());
+        for (name, number) in [("First", "A-1"), ("Second, with comma", "A-2")] {
+            service
+                .create(Order::new(
+                    name.to_string(),
+                    "active".to_string(),
+                    number.to_string(),
+                    10.0,
+                ))
+                .await
+                .unwrap();
+        }
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::from([(
+            "order".to_string(),
+            service.clone() as Arc<dyn EntityFetcher>,
+        )]);
+        let app = Router::new()
+            .route("/orders", get(|| async { "json list" }))
+            .layer(middleware::from_fn_with_state(
+                ExportState::new(&fetchers, &config(), None),
+                export_middleware,
+            ));
+
+        let request = Request::builder()
+            .uri("/orders?format=csv")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/csv; charset=utf-8");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        let mut expected = vec!["id"];
+        expected.extend(Order::indexed_fields());
+        expected.extend(["label", "created_at", "updated_at"]);
+        assert_eq!(lines[0], expected.join(","));
+        assert_eq!(lines.len(), 3);
+        assert!(body.contains("\"Second, with comma\",A-2,A-2 (active),"));
+
+        // Without the parameter the handler answers as usual
+        let request = Request::builder()
+            .uri("/orders")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&body[..], b"json list");
+    }
+
+    #[tokio::test]
+    async fn test_accept_ndjson_streams_one_object_per_line() {
+        let service = Arc::new(InMemoryDataService::<Order>::new());
+        for i in 0..(EXPORT_PAGE_SIZE + 3) {
+            service
+                .create(Order::new(
+                    format!("Order {}", i),
+                    "active".to_string(),
+                    format!("A-{}", i),
+                    i as f64,
+                ))
+                .await
+                .unwrap();
+        }
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::from([(
+            "order".to_string(),
+            service.clone() as Arc<dyn EntityFetcher>,
+        )]);
+        let app = Router::new()
+            .route("/orders", get(|| async { "json list" }))
+            .layer(middleware::from_fn_with_state(
+                ExportState::new(&fetchers, &config(), None),
+                export_middleware,
+            ));
+
+        let request = Request::builder()
+            .uri("/orders")
+            .header(ACCEPT, "application/x-ndjson")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/x-ndjson");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        let entities: Vec<Value> = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(entities.len(), EXPORT_PAGE_SIZE + 3);
+        assert!(entities.iter().all(|entity| entity["number"].is_string()));
+        // Computed fields are merged in, as on JSON reads
+        assert!(
+            entities.iter().all(|entity| entity["label"]
+                == format!("{} (active)", entity["number"].as_str().unwrap()))
+        );
+
+        // `limit` caps the stream
+        let request = Request::builder()
+            .uri("/orders?format=ndjson&limit=7")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(String::from_utf8(body.to_vec()).unwrap().lines().count(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_export_applies_filters_and_refuses_sort() {
+        let service = Arc::new(InMemoryDataService::<Order>::new());
+        for (i, status) in ["active", "archived", "active"].iter().enumerate() {
+            service
+                .create(Order::new(
+                    format!("Order {}", i),
+                    status.to_string(),
+                    format!("A-{}", i),
+                    i as f64,
+                ))
+                .await
+                .unwrap();
+        }
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::from([(
+            "order".to_string(),
+            service.clone() as Arc<dyn EntityFetcher>,
+        )]);
+        let app = Router::new()
+            .route("/orders", get(|| async { "json list" }))
+            .layer(middleware::from_fn_with_state(
+                ExportState::new(&fetchers, &config(), None),
+                export_middleware,
+            ));
+        let export = |uri: &'static str| {
+            let app = app.clone();
+            async move {
+                let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+                app.oneshot(request).await.unwrap()
+            }
+        };
+        let lines = |body: axum::body::Bytes| -> Vec<Value> {
+            String::from_utf8(body.to_vec())
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        };
+
+        let response = export("/orders?format=ndjson&filter[status]=active").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let entities = lines(to_bytes(response.into_body(), usize::MAX).await.unwrap());
+        assert_eq!(entities.len(), 2);
+        assert!(entities.iter().all(|entity| entity["status"] == "active"));
+
+        let response =
+            export("/orders?format=ndjson&filter=%7B%22amount%22%3A%7B%22%24gt%22%3A1%7D%7D").await;
+        let entities = lines(to_bytes(response.into_body(), usize::MAX).await.unwrap());
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0]["amount"], 2.0);
+
+        let response = export("/orders?format=ndjson&filter=not-json").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = export("/orders?format=csv&sort=amount:asc").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,384 @@
+//! REST endpoints for entity version history
+//!
+//! - `GET /{entity_type}/{entity_id}/history`         — List all stored versions
+//! - `GET /{entity_type}/{entity_id}/versions/{n}`    — Get the snapshot of version `n`
+//! - `GET /{entity_type}/{entity_id}?version=n`       — Get the entity as it was at version `n`
+//!
+//! The `?version` form answers with the entity itself rather than the stored
+//! [`EntityVersion`](crate::core::EntityVersion), and also serves the
+//! current state as the version after the last snapshot (see
+//! [`state_at`]). It is a layer on the descriptor routes; requests without
+//! `version` reach the descriptor's handler.
+//!
+//! Only mounted when a [`HistoryService`] is configured on the server.
+//! Under tenancy, versions whose snapshot belongs to another tenant are
+//! answered with `404`, also once the entity itself is gone.
+
+use crate::config::LinksConfig;
+use crate::core::extractors::error_response;
+use crate::core::history::{HistoryService, state_at};
+use crate::core::module::EntityFetcher;
+use crate::core::tenant::TenantContext;
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::{Json, Router, routing::get};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+// ── Shared state ──────────────────────────────────────────────────────
+
+/// Shared state for history endpoints
+#[derive(Clone)]
+pub struct HistoryState {
+    pub history_service: Arc<dyn HistoryService>,
+    pub config: Arc<LinksConfig>,
+}
+
+impl HistoryState {
+    /// Resolve a plural path segment to its singular entity type
+    fn singular(&self, plural: &str) -> Option<&str> {
+        self.config
+            .entities
+            .iter()
+            .find(|e| e.plural == plural)
+            .map(|e| e.singular.as_str())
+    }
+}
+
+/// Build the history routes
+pub fn history_routes(state: HistoryState) -> Router {
+    Router::new()
+        .route("/{entity_type}/{entity_id}/history", get(list_history))
+        .route(
+            "/{entity_type}/{entity_id}/versions/{version}",
+            get(get_version),
+        )
+        .with_state(state)
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────
+
+/// Whether `tenant`, if any, owns the entity `snapshot`
+fn visible_to(tenant: Option<TenantContext>, snapshot: &Value) -> bool {
+    tenant.is_none_or(|tenant| tenant.owns_json(snapshot))
+}
+
+fn entity_not_found(entity_id: &Uuid) -> Response {
+    error_response(
+        StatusCode::NOT_FOUND,
+        "ENTITY_NOT_FOUND",
+        format!("Entity not found: {}", entity_id),
+    )
+}
+
+/// List every stored version of an entity, oldest first
+async fn list_history(
+    State(state): State<HistoryState>,
+    Path((entity_type_plural, entity_id)): Path<(String, Uuid)>,
+    tenant: Option<TenantContext>,
+) -> Response {
+    let Some(entity_type) = state.singular(&entity_type_plural) else {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            "ENTITY_TYPE_NOT_FOUND",
+            format!("unknown entity type: {}", entity_type_plural),
+        );
+    };
+
+    match state
+        .history_service
+        .list_versions(entity_type, &entity_id)
+        .await
+    {
+        Ok(versions) if !versions.iter().all(|v| visible_to(tenant, &v.snapshot)) => {
+            entity_not_found(&entity_id)
+        }
+        Ok(versions) => Json(json!({
+            "entity_type": entity_type,
+            "entity_id": entity_id,
+            "total": versions.len(),
+            "versions": versions,
+        }))
+        .into_response(),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "INTERNAL_ERROR",
+            e.to_string(),
+        ),
+    }
+}
+
+/// Get one version of an entity
+async fn get_version(
+    State(state): State<HistoryState>,
+    Path((entity_type_plural, entity_id, version)): Path<(String, Uuid, i64)>,
+    tenant: Option<TenantContext>,
+) -> Response {
+    let Some(entity_type) = state.singular(&entity_type_plural) else {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            "ENTITY_TYPE_NOT_FOUND",
+            format!("unknown entity type: {}", entity_type_plural),
+        );
+    };
+
+    match state
+        .history_service
+        .get_version(entity_type, &entity_id, version)
+        .await
+    {
+        Ok(Some(version)) if !visible_to(tenant, &version.snapshot) => entity_not_found(&entity_id),
+        Ok(Some(version)) => Json(version).into_response(),
+        Ok(None) => error_response(
+            StatusCode::NOT_FOUND,
+            "VERSION_NOT_FOUND",
+            format!("version {} not found", version),
+        ),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "INTERNAL_ERROR",
+            e.to_string(),
+        ),
+    }
+}
+
+#[derive(Deserialize)]
+struct VersionParam {
+    version: Option<String>,
+}
+
+/// Shared state for the `?version=n` middleware
+#[derive(Clone)]
+pub struct VersionState {
+    history_service: Arc<dyn HistoryService>,
+    /// Singular entity type -> fetcher reading the current state
+    fetchers: Arc<HashMap<String, Arc<dyn EntityFetcher>>>,
+    /// Plural route segment -> singular entity type
+    entity_types: Arc<HashMap<String, String>>,
+}
+
+impl VersionState {
+    pub fn new(
+        history_service: Arc<dyn HistoryService>,
+        fetchers: Arc<HashMap<String, Arc<dyn EntityFetcher>>>,
+        config: &LinksConfig,
+    ) -> Self {
+        let entity_types = config
+            .entities
+            .iter()
+            .map(|e| (e.plural.clone(), e.singular.clone()))
+            .collect();
+        Self {
+            history_service,
+            fetchers,
+            entity_types: Arc::new(entity_types),
+        }
+    }
+
+    /// Resolve `GET /{plural}/{id}?version=n` to its entity type, raw ID and raw version
+    fn target<'a>(&self, request: &'a Request) -> Option<(&str, &'a str, String)> {
+        if request.method() != Method::GET {
+            return None;
+        }
+        let Query(VersionParam { version }) = Query::try_from_uri(request.uri()).ok()?;
+        let (plural, id) = request.uri().path().trim_matches('/').split_once('/')?;
+        if id.contains('/') {
+            return None;
+        }
+        Some((self.entity_types.get(plural)?.as_str(), id, version?))
+    }
+}
+
+/// Middleware serving `GET /{plural}/{id}?version=n` from the entity's history
+pub async fn version_middleware(
+    State(state): State<VersionState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((entity_type, raw_id, raw_version)) = state.target(&request) else {
+        return next.run(request).await;
+    };
+    let Ok(entity_id) = Uuid::parse_str(raw_id) else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "INVALID_ENTITY_ID",
+            format!("Invalid UUID: {}", raw_id),
+        );
+    };
+    let Ok(version) = raw_version.parse::<i64>() else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "INVALID_VERSION",
+            format!("invalid version: {}", raw_version),
+        );
+    };
+
+    let versions = match state
+        .history_service
+        .list_versions(entity_type, &entity_id)
+        .await
+    {
+        Ok(versions) => versions,
+        Err(e) => {
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "INTERNAL_ERROR",
+                e.to_string(),
+            );
+        }
+    };
+    // A missing entity has no current state; its recorded versions still count
+    let current = match state.fetchers.get(entity_type) {
+        Some(fetcher) => fetcher.fetch_as_json(&entity_id).await.ok(),
+        None => None,
+    };
+    let snapshots = versions.into_iter().map(|v| v.snapshot).collect();
+    let tenant = request.extensions().get::<TenantContext>().copied();
+    match state_at(snapshots, current, version) {
+        Some(entity) if !visible_to(tenant, &entity) => entity_not_found(&entity_id),
+        Some(entity) => Json(entity).into_response(),
+        None => error_response(
+            StatusCode::NOT_FOUND,
+            "VERSION_NOT_FOUND",
+            format!("version {} not found", version),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::storage::InMemoryHistoryService;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn test_state() -> HistoryState {
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "order".to_string(),
+                plural: "orders".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        HistoryState {
+            history_service: Arc::new(InMemoryHistoryService::new()),
+            config: Arc::new(config),
+        }
+    }
+
+    async fn get_json(router: Router, uri: &str) -> (StatusCode, Value) {
+        let response = router
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), 1024 * 64)
+            .await
+            .expect("body should read");
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_history_lists_versions_and_fetches_one() {
+        let state = test_state();
+        let order_id = Uuid::new_v4();
+        for total in [10, 12] {
+            state
+                .history_service
+                .record("order", &order_id, json!({ "total": total }), Some("alice"))
+                .await
+                .unwrap();
+        }
+        let router = history_routes(state);
+
+        let (status, body) =
+            get_json(router.clone(), &format!("/orders/{}/history", order_id)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total"], 2);
+        assert_eq!(body["versions"][1]["version"], 2);
+        assert_eq!(body["versions"][1]["actor"], "alice");
+
+        let (status, body) =
+            get_json(router.clone(), &format!("/orders/{}/versions/1", order_id)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["snapshot"]["total"], 10);
+
+        let (status, _) = get_json(router, &format!("/orders/{}/versions/3", order_id)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_history_unknown_entity_type_is_not_found() {
+        let router = history_routes(test_state());
+        let (status, body) =
+            get_json(router, &format!("/widgets/{}/history", Uuid::new_v4())).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "ENTITY_TYPE_NOT_FOUND");
+        assert!(body["error"].as_str().unwrap().contains("widgets"));
+    }
+
+    #[tokio::test]
+    async fn test_version_query_serves_past_and_current_states() {
+        use crate::core::DataService;
+        use crate::storage::InMemoryDataService;
+
+        crate::impl_data_entity!(Order, "order", ["name"], {
+            total: f64,
+        });
+
+        let service = Arc::new(InMemoryDataService::<Order>::new());
+        let order = service
+            .create(Order::new("A-1".to_string(), "active".to_string(), 12.0))
+            .await
+            .unwrap();
+        let state = test_state();
+        state
+            .history_service
+            .record("order", &order.id, json!({ "total": 10.0 }), None)
+            .await
+            .unwrap();
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> =
+            HashMap::from([("order".to_string(), service as Arc<dyn EntityFetcher>)]);
+
+        let router = Router::new()
+            .route(
+                "/orders/{id}",
+                get(|| async { Json(json!({ "from": "descriptor" })) }),
+            )
+            .layer(axum::middleware::from_fn_with_state(
+                VersionState::new(state.history_service, Arc::new(fetchers), &state.config),
+                version_middleware,
+            ));
+
+        let uri = |query: &str| format!("/orders/{}{}", order.id, query);
+        let (status, body) = get_json(router.clone(), &uri("?version=1")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total"], 10.0);
+
+        let (_, body) = get_json(router.clone(), &uri("?version=2")).await;
+        assert_eq!(body["total"], 12.0);
+
+        let (status, _) = get_json(router.clone(), &uri("?version=3")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = get_json(router.clone(), &uri("?version=latest")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (_, body) = get_json(router, &uri("")).await;
+        assert_eq!(body["from"], "descriptor");
+    }
+}
@@ -0,0 +1,330 @@
+//! Module lifecycle hooks around REST entity routes
+//!
+//! Entity routes come from each module's `EntityDescriptor`, so the hooks run
+//! in a layer around them: `before_*` hooks see (and may rewrite) the request
+//! body of `POST /{entity_type}` and `PUT /{entity_type}/{id}`, `after_*`
+//! hooks the body of successful responses. `DELETE /{entity_type}/{id}` hooks
+//! receive `{"id": ...}`.
+//!
+//! `PATCH` is not handled here: the patch layer serves it through the entity
+//! creator, which already runs the hooks.
+
+use crate::config::LinksConfig;
+use crate::core::extractors::ExtractorError;
+use crate::core::module::Module;
+use crate::core::validation::ValidationError;
+use crate::server::body::{BodyLimit, JsonRequest, JsonResponse};
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mutation {
+    Create,
+    Update,
+    Delete,
+}
+
+/// Entity type behind a route and the module owning it
+struct Owner {
+    entity_type: String,
+    module: Arc<dyn Module>,
+}
+
+/// Shared state for the lifecycle hooks middleware
+#[derive(Clone)]
+pub struct HooksState {
+    /// Plural route segment -> owning module
+    owners: Arc<HashMap<String, Owner>>,
+}
+
+impl HooksState {
+    pub fn new(modules: &HashMap<String, Arc<dyn Module>>, config: &LinksConfig) -> Self {
+        let owners = config
+            .entities
+            .iter()
+            .filter_map(|e| {
+                let owner = Owner {
+                    entity_type: e.singular.clone(),
+                    module: modules.get(&e.singular)?.clone(),
+                };
+                Some((e.plural.clone(), owner))
+            })
+            .collect();
+        Self {
+            owners: Arc::new(owners),
+        }
+    }
+
+    /// Whether no entity route has an owning module
+    pub fn is_empty(&self) -> bool {
+        self.owners.is_empty()
+    }
+
+    fn target<'a>(
+        &self,
+        method: &Method,
+        path: &'a str,
+    ) -> Option<(&Owner, Mutation, Option<&'a str>)> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let (mutation, id) = match (method, segments.as_slice()) {
+            (&Method::POST, [_]) => (Mutation::Create, None),
+            (&Method::PUT, [_, id]) => (Mutation::Update, Some(*id)),
+            (&Method::DELETE, [_, id]) => (Mutation::Delete, Some(*id)),
+            _ => return None,
+        };
+        Some((self.owners.get(segments[0])?, mutation, id))
+    }
+}
+
+async fn before(
+    module: &dyn Module,
+    mutation: Mutation,
+    entity_type: &str,
+    entity: &mut Value,
+) -> anyhow::Result<()> {
+    match mutation {
+        Mutation::Create => module.before_create(entity_type, entity).await,
+        Mutation::Update => module.before_update(entity_type, entity).await,
+        Mutation::Delete => module.before_delete(entity_type, entity).await,
+    }
+}
+
+async fn after(
+    module: &dyn Module,
+    mutation: Mutation,
+    entity_type: &str,
+    entity: &mut Value,
+) -> anyhow::Result<()> {
+    match mutation {
+        Mutation::Create => module.after_create(entity_type, entity).await,
+        Mutation::Update => module.after_update(entity_type, entity).await,
+        Mutation::Delete => module.after_delete(entity_type, entity).await,
+    }
+}
+
+/// Answer a failed hook: validation errors are the client's, anything else is ours
+fn hook_error(e: anyhow::Error) -> Response {
+    match e.downcast::<ValidationError>() {
+        Ok(validation) => validation.into_response(),
+        Err(e) => ExtractorError::Internal(e.to_string()).into_response(),
+    }
+}
+
+/// Middleware running the owning module's lifecycle hooks around entity mutations
+pub async fn hooks_middleware(
+    State(state): State<HooksState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((owner, mutation, id)) = state.target(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+    let entity_type = owner.entity_type.clone();
+    let module = owner.module.clone();
+
+    if mutation == Mutation::Delete {
+        let mut entity = json!({ "id": id });
+        if let Err(e) = before(&*module, mutation, &entity_type, &mut entity).await {
+            return hook_error(e);
+        }
+        let response = next.run(request).await;
+        if response.status().is_success()
+            && let Err(e) = after(&*module, mutation, &entity_type, &mut entity).await
+        {
+            return hook_error(e);
+        }
+        return response;
+    }
+
+    let limit = BodyLimit::of(request.extensions());
+    let mut body = match JsonRequest::read(request).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    // Malformed JSON is left for the handler to report as usual
+    if let Some(payload) = body.json_mut()
+        && let Err(e) = before(&*module, mutation, &entity_type, payload).await
+    {
+        return hook_error(e);
+    }
+
+    let response = next.run(body.into_request()).await;
+    if !response.status().is_success() {
+        return response;
+    }
+    let mut body = match JsonResponse::read(response, limit).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    if let Some(entity) = body.json_mut()
+        && let Err(e) = after(&*module, mutation, &entity_type, entity).await
+    {
+        return hook_error(e);
+    }
+    body.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::EntityCreator;
+    use crate::core::module::EntityFetcher;
+    use crate::core::validation::FieldError;
+    use crate::server::entity_registry::EntityRegistry;
+    use async_trait::async_trait;
+    use axum::body::{Body, to_bytes};
+    use axum::http::StatusCode;
+    use axum::routing::{delete, post};
+    use axum::{Json, Router, middleware};
+    use std::sync::Mutex;
+    use tower::ServiceExt;
+
+    /// Uppercases names on create, stamps responses, and records deletes
+    #[derive(Default)]
+    struct ShoutingModule {
+        deleted: Mutex<Vec<Value>>,
+    }
+
+    #[async_trait]
+    impl Module for ShoutingModule {
+        fn name(&self) -> &str {
+            "shouting"
+        }
+
+        fn entity_types(&self) -> Vec<&str> {
+            vec!["user"]
+        }
+
+        fn links_config(&self) -> anyhow::Result<LinksConfig> {
+            unimplemented!()
+        }
+
+        fn register_entities(&self, _registry: &mut EntityRegistry) {}
+
+        fn get_entity_fetcher(&self, _entity_type: &str) -> Option<Arc<dyn EntityFetcher>> {
+            None
+        }
+
+        fn get_entity_creator(&self, _entity_type: &str) -> Option<Arc<dyn EntityCreator>> {
+            None
+        }
+
+        async fn before_create(
+            &self,
+            _entity_type: &str,
+            entity: &mut Value,
+        ) -> anyhow::Result<()> {
+            let name = entity["name"].as_str().unwrap_or_default().to_uppercase();
+            entity["name"] = json!(name);
+            Ok(())
+        }
+
+        async fn after_create(&self, entity_type: &str, entity: &mut Value) -> anyhow::Result<()> {
+            entity["_hooked"] = json!(entity_type);
+            Ok(())
+        }
+
+        async fn before_delete(
+            &self,
+            _entity_type: &str,
+            entity: &mut Value,
+        ) -> anyhow::Result<()> {
+            if entity["id"] == "protected" {
+                return Err(ValidationError::FieldErrors(vec![FieldError::new(
+                    "/id",
+                    "is protected",
+                )])
+                .into());
+            }
+            Ok(())
+        }
+
+        async fn after_delete(&self, _entity_type: &str, entity: &mut Value) -> anyhow::Result<()> {
+            self.deleted.lock().unwrap().push(entity.clone());
+            Ok(())
+        }
+    }
+
+    fn app(module: Arc<ShoutingModule>) -> Router {
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "user".to_string(),
+                plural: "users".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        let modules = HashMap::from([("user".to_string(), module as Arc<dyn Module>)]);
+
+        Router::new()
+            .route(
+                "/users",
+                post(|Json(body): Json<Value>| async move { Json(body) }),
+            )
+            .route("/users/{id}", delete(|| async { StatusCode::NO_CONTENT }))
+            .layer(middleware::from_fn_with_state(
+                HooksState::new(&modules, &config),
+                hooks_middleware,
+            ))
+    }
+
+    async fn send(router: Router, method: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_hooks_rewrite_request_and_response() {
+        let app = app(Arc::default());
+        let (status, body) = send(app, "POST", "/users", json!({"name": "alice"})).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({"name": "ALICE", "_hooked": "user"}));
+    }
+
+    #[tokio::test]
+    async fn test_delete_hooks_receive_id_and_can_abort() {
+        let module = Arc::new(ShoutingModule::default());
+
+        let (status, _) = send(
+            app(module.clone()),
+            "DELETE",
+            "/users/protected",
+            Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(module.deleted.lock().unwrap().is_empty());
+
+        let (status, _) = send(app(module.clone()), "DELETE", "/users/42", Value::Null).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert_eq!(*module.deleted.lock().unwrap(), vec![json!({"id": "42"})]);
+    }
+}
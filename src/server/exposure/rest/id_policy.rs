@@ -0,0 +1,157 @@
+//! Enforcement of per-entity [`IdPolicy`] on `POST /{entity_type}`
+//!
+//! Create bodies are rewritten (client `id` dropped under `ServerGenerated`)
+//! or rejected with `400 Bad Request` before reaching the entity
+//! handlers. Only mounted when some entity is not `ClientOptional`.
+
+use crate::config::{IdPolicy, LinksConfig};
+use crate::server::body::JsonRequest;
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Shared state for the id policy middleware
+#[derive(Clone)]
+pub struct IdPolicyState {
+    /// Plural route segment -> policy, for entities that are not `ClientOptional`
+    policies: Arc<HashMap<String, IdPolicy>>,
+}
+
+impl IdPolicyState {
+    pub fn new(config: &LinksConfig) -> Self {
+        let policies = config
+            .entities
+            .iter()
+            .filter(|e| e.id_policy != IdPolicy::ClientOptional)
+            .map(|e| (e.plural.clone(), e.id_policy))
+            .collect();
+        Self {
+            policies: Arc::new(policies),
+        }
+    }
+
+    /// Whether any entity needs enforcement
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    fn target(&self, method: &Method, path: &str) -> Option<IdPolicy> {
+        let plural = path.trim_matches('/');
+        if method != Method::POST || plural.contains('/') {
+            return None;
+        }
+        self.policies.get(plural).copied()
+    }
+}
+
+/// Middleware applying the entity's id policy to create payloads
+pub async fn id_policy_middleware(
+    State(state): State<IdPolicyState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(policy) = state.target(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let mut body = match JsonRequest::read(request).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+
+    // Malformed JSON is left for the handler to report as usual
+    if let Some(payload) = body.json_mut()
+        && let Err(e) = policy.apply(payload)
+    {
+        return e.into_response();
+    }
+
+    next.run(body.into_request()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use axum::body::{Body, to_bytes};
+    use axum::http::StatusCode;
+    use axum::routing::post;
+    use axum::{Json, Router, middleware};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    fn app() -> Router {
+        let entity = |singular: &str, plural: &str, id_policy| EntityConfig {
+            singular: singular.to_string(),
+            plural: plural.to_string(),
+            auth: EntityAuthConfig::default(),
+            id_policy,
+            fields: Default::default(),
+            min_update_interval: None,
+        };
+        let config = LinksConfig {
+            entities: vec![
+                entity("order", "orders", IdPolicy::ServerGenerated),
+                entity("import", "imports", IdPolicy::ClientRequired),
+            ],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+
+        let echo = post(|Json(body): Json<Value>| async move { Json(body) });
+        Router::new()
+            .route("/orders", echo.clone())
+            .route("/imports", echo)
+            .layer(middleware::from_fn_with_state(
+                IdPolicyState::new(&config),
+                id_policy_middleware,
+            ))
+    }
+
+    async fn create(uri: &str, body: Value) -> (StatusCode, Value) {
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024 * 64).await.unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_server_generated_drops_client_id() {
+        let (status, body) = create("/orders", json!({"id": Uuid::new_v4(), "n": 1})).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.get("id").is_none());
+        assert_eq!(body["n"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_client_required_rejects_missing_id() {
+        let (status, body) = create("/imports", json!({"n": 1})).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["errors"][0]["field"], "/id");
+
+        let id = Uuid::new_v4();
+        let (status, body) = create("/imports", json!({"id": id})).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["id"], json!(id));
+    }
+}
@@ -0,0 +1,361 @@
+//! `Idempotency-Key` support for create requests
+//!
+//! A POST to an entity or link route carrying an `Idempotency-Key` header
+//! runs once per key, path and caller (and tenant, with tenancy enabled).
+//! Its first successful response is kept in an in-process LRU cache and
+//! replayed, with an `Idempotent-Replayed: true` header, to requests
+//! repeating the key until the entry expires. Requests arriving while the
+//! first one still runs wait for its response instead of creating again.
+//! Failed responses are not kept, so a retry after an error runs again.
+//!
+//! The caller is the subject of the host's auth provider
+//! ([`AuthContext::subject`]), so one caller cannot replay another's
+//! response. A key is bound to the body it was first sent with: reusing it
+//! with a different body is answered with `422 Unprocessable Entity`, as the
+//! IETF Idempotency-Key draft specifies.
+//!
+//! [`AuthContext::subject`]: crate::core::auth::AuthContext::subject
+
+use super::redaction::redaction_context;
+use crate::core::TenantContext;
+use crate::core::auth::AuthProvider;
+use crate::core::extractors::{ExtractorError, error_response};
+use crate::server::body::{BodyLimit, JsonRequest};
+use axum::body::{Bytes, to_bytes};
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use indexmap::IndexMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+/// Header carrying the client's idempotency key
+pub const IDEMPOTENCY_KEY_HEADER: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Header set on replayed responses
+pub const IDEMPOTENT_REPLAYED_HEADER: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// How many keys the cache holds before evicting the least recently used
+pub const DEFAULT_IDEMPOTENCY_CACHE_SIZE: usize = 10_000;
+
+/// How long a key's response is replayed
+pub const DEFAULT_IDEMPOTENCY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Tenant, caller, path and idempotency key of a request
+type CacheKey = (Option<Uuid>, String, String, String);
+
+/// A response kept for replay
+struct StoredResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+struct Entry {
+    created_at: Instant,
+    /// Hash of the body the key was first sent with
+    fingerprint: u64,
+    response: Arc<OnceCell<StoredResponse>>,
+}
+
+/// LRU cache of the responses to idempotent requests
+pub struct IdempotencyCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<IndexMap<CacheKey, Entry>>,
+}
+
+impl IdempotencyCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            entries: Mutex::new(IndexMap::new()),
+        }
+    }
+
+    /// The response slot of `key`, created (and the oldest evicted) if missing or expired
+    ///
+    /// `None` if the key was first sent with a body of another `fingerprint`.
+    fn slot(&self, key: CacheKey, fingerprint: u64) -> Option<Arc<OnceCell<StoredResponse>>> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if let Some(index) = entries.get_index_of(&key) {
+            if now.duration_since(entries[index].created_at) < self.ttl {
+                if entries[index].fingerprint != fingerprint {
+                    return None;
+                }
+                let last = entries.len() - 1;
+                entries.move_index(index, last);
+                return Some(entries[last].response.clone());
+            }
+            entries.shift_remove_index(index);
+        }
+        while entries.len() >= self.capacity {
+            entries.shift_remove_index(0);
+        }
+        let response = Arc::new(OnceCell::new());
+        entries.insert(
+            key,
+            Entry {
+                created_at: now,
+                fingerprint,
+                response: response.clone(),
+            },
+        );
+        Some(response)
+    }
+}
+
+impl Default for IdempotencyCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_IDEMPOTENCY_CACHE_SIZE, DEFAULT_IDEMPOTENCY_TTL)
+    }
+}
+
+/// Shared state for the idempotency middleware
+#[derive(Clone)]
+pub struct IdempotencyState {
+    cache: Arc<IdempotencyCache>,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
+}
+
+impl IdempotencyState {
+    pub fn new(cache: IdempotencyCache, auth_provider: Option<Arc<dyn AuthProvider>>) -> Self {
+        Self {
+            cache: Arc::new(cache),
+            auth_provider,
+        }
+    }
+}
+
+/// Hash of a request body
+fn fingerprint(body: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Middleware running each keyed POST once and replaying its response
+pub async fn idempotency_middleware(
+    State(state): State<IdempotencyState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let key = match request.headers().get(IDEMPOTENCY_KEY_HEADER) {
+        Some(key) if request.method() == Method::POST => key,
+        _ => return next.run(request).await,
+    };
+    let Ok(key) = key.to_str().map(str::to_string) else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "INVALID_IDEMPOTENCY_KEY",
+            "Invalid Idempotency-Key header",
+        );
+    };
+    let tenant = request
+        .extensions()
+        .get::<TenantContext>()
+        .map(|t| t.tenant_id);
+    let (parts, body) = request.into_parts();
+    let caller = redaction_context(state.auth_provider.as_ref(), &parts)
+        .await
+        .subject();
+    let path = parts.uri.path().to_string();
+    let limit = BodyLimit::of(&parts.extensions);
+    let body = match JsonRequest::read(Request::from_parts(parts, body)).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    let Some(slot) = state
+        .cache
+        .slot((tenant, caller, path, key), fingerprint(body.bytes()))
+    else {
+        return error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "IDEMPOTENCY_KEY_REUSED",
+            "Idempotency-Key was already used with a different request body",
+        );
+    };
+    let request = body.into_request();
+
+    // Concurrent requests with the same key wait here for the first one
+    let mut replayed = true;
+    let stored = slot
+        .get_or_try_init(|| async {
+            replayed = false;
+            let response = next.run(request).await;
+            if !response.status().is_success() {
+                return Err(response);
+            }
+            let (parts, body) = response.into_parts();
+            let body = to_bytes(body, limit.0).await.map_err(|_| {
+                ExtractorError::Internal("response body too large".to_string()).into_response()
+            })?;
+            Ok(StoredResponse {
+                status: parts.status,
+                headers: parts.headers,
+                body,
+            })
+        })
+        .await;
+
+    match stored {
+        Ok(stored) => {
+            let mut response =
+                (stored.status, stored.headers.clone(), stored.body.clone()).into_response();
+            if replayed {
+                response
+                    .headers_mut()
+                    .insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+            }
+            response
+        }
+        Err(response) => response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::auth::AuthContext;
+    use axum::body::Body;
+    use axum::http::request::Parts;
+    use axum::routing::post;
+    use axum::{Router, middleware};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tower::ServiceExt;
+
+    /// Reads the user from `x-user-id`
+    struct HeaderAuthProvider;
+
+    #[async_trait::async_trait]
+    impl AuthProvider for HeaderAuthProvider {
+        async fn extract_context(&self, parts: &Parts) -> anyhow::Result<AuthContext> {
+            let Some(user_id) = parts.headers.get("x-user-id") else {
+                return Ok(AuthContext::Anonymous);
+            };
+            Ok(AuthContext::User {
+                user_id: user_id.to_str()?.parse()?,
+                tenant_id: Uuid::nil(),
+                roles: vec![],
+            })
+        }
+
+        async fn is_owner(&self, _: &Uuid, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+
+        async fn has_role(&self, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+    }
+
+    fn app(cache: IdempotencyCache, calls: Arc<AtomicUsize>) -> Router {
+        Router::new()
+            .route(
+                "/orders",
+                post(move || async move {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    let n = calls.fetch_add(1, Ordering::SeqCst);
+                    (StatusCode::CREATED, format!("order {n}"))
+                }),
+            )
+            .layer(middleware::from_fn_with_state(
+                IdempotencyState::new(cache, Some(Arc::new(HeaderAuthProvider))),
+                idempotency_middleware,
+            ))
+    }
+
+    async fn post_order(app: &Router, key: &str) -> (StatusCode, bool, String) {
+        post_order_as(app, key, None, "{}").await
+    }
+
+    async fn post_order_as(
+        app: &Router,
+        key: &str,
+        user: Option<Uuid>,
+        body: &'static str,
+    ) -> (StatusCode, bool, String) {
+        let mut request = Request::post("/orders").header(IDEMPOTENCY_KEY_HEADER, key);
+        if let Some(user) = user {
+            request = request.header("x-user-id", user.to_string());
+        }
+        let request = request.body(Body::from(body)).unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let replayed = response.headers().contains_key(IDEMPOTENT_REPLAYED_HEADER);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, replayed, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_with_one_key_run_once() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(IdempotencyCache::default(), calls.clone());
+
+        let (a, b) = tokio::join!(post_order(&app, "k1"), post_order(&app, "k1"));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!((a.0, b.0), (StatusCode::CREATED, StatusCode::CREATED));
+        assert_eq!(a.2, b.2);
+        assert!(a.1 != b.1, "exactly one response is a replay");
+
+        let (_, replayed, body) = post_order(&app, "k2").await;
+        assert!(!replayed);
+        assert_eq!(body, "order 1");
+    }
+
+    #[tokio::test]
+    async fn test_evicted_and_expired_keys_run_again() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let router = app(
+            IdempotencyCache::new(1, Duration::from_secs(60)),
+            calls.clone(),
+        );
+        post_order(&router, "k1").await;
+        post_order(&router, "k2").await;
+        let (_, replayed, _) = post_order(&router, "k1").await;
+        assert!(!replayed, "k1 was evicted by k2");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let router = app(IdempotencyCache::new(10, Duration::ZERO), calls.clone());
+        post_order(&router, "k1").await;
+        post_order(&router, "k1").await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_keys_are_scoped_to_the_caller() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(IdempotencyCache::default(), calls.clone());
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let (_, _, first) = post_order_as(&app, "k1", Some(alice), "{}").await;
+        let (_, replayed, other) = post_order_as(&app, "k1", Some(bob), "{}").await;
+        assert!(!replayed, "bob must not get alice's response");
+        assert_ne!(first, other);
+        let (_, replayed, again) = post_order_as(&app, "k1", Some(alice), "{}").await;
+        assert!(replayed);
+        assert_eq!(first, again);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_reusing_a_key_with_another_body_is_rejected() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(IdempotencyCache::default(), calls.clone());
+
+        post_order_as(&app, "k1", None, r#"{"total":1}"#).await;
+        let (status, replayed, body) = post_order_as(&app, "k1", None, r#"{"total":2}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(!replayed);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["code"], "IDEMPOTENCY_KEY_REUSED");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
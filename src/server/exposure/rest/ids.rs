@@ -0,0 +1,343 @@
+//! Canonicalization of entity ids in REST paths and bodies
+//!
+//! Ids are rewritten to their canonical form (see [`IdNormalizer`]) before
+//! routing, so `/users/{AAAAAAAA-...}` and `/users/aaaaaaaa-...` hit the same
+//! entity. Only paths under a configured entity plural (or `/links`) are
+//! touched, and only segments that actually parse as ids.
+//!
+//! Two checks run after routing, where the shape of the path is known: link
+//! routes hold an entity id at every odd segment, so an invalid one is
+//! rejected with [`ExtractorError::InvalidEntityId`]; entity create/update
+//! bodies get their `id` canonicalized or rejected the same way.
+
+use crate::config::LinksConfig;
+use crate::core::extractors::ExtractorError;
+use crate::core::ids::IdNormalizer;
+use crate::server::body::JsonRequest;
+use axum::Router;
+use axum::extract::{Request, State};
+use axum::http::{Method, Uri};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// What the middleware does with the ids of a request
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Rewrite every id-like odd segment of the path (before routing)
+    Paths,
+    /// Canonicalize or reject the `id` of entity create/update bodies
+    EntityBodies,
+    /// Reject link routes whose odd segments are not ids
+    LinkIds,
+}
+
+/// Shared state for the id normalization middleware
+#[derive(Clone)]
+pub struct IdNormalizationState {
+    normalizer: Arc<dyn IdNormalizer>,
+    /// First path segments under which ids are normalized
+    roots: Arc<HashSet<String>>,
+    mode: Mode,
+}
+
+impl IdNormalizationState {
+    /// State rewriting path ids, see [`canonicalize_paths`]
+    fn for_paths(normalizer: Arc<dyn IdNormalizer>, config: &LinksConfig) -> Self {
+        Self::new(normalizer, config, Mode::Paths)
+    }
+
+    /// State for the entity CRUD routes
+    pub fn for_entities(normalizer: Arc<dyn IdNormalizer>, config: &LinksConfig) -> Self {
+        Self::new(normalizer, config, Mode::EntityBodies)
+    }
+
+    /// State for the link routes
+    pub fn for_links(normalizer: Arc<dyn IdNormalizer>, config: &LinksConfig) -> Self {
+        Self::new(normalizer, config, Mode::LinkIds)
+    }
+
+    fn new(normalizer: Arc<dyn IdNormalizer>, config: &LinksConfig, mode: Mode) -> Self {
+        let mut roots: HashSet<String> = config.entities.iter().map(|e| e.plural.clone()).collect();
+        if mode != Mode::EntityBodies {
+            roots.insert("links".to_string());
+        }
+        Self {
+            normalizer,
+            roots: Arc::new(roots),
+            mode,
+        }
+    }
+
+    /// Canonical form of `path`, or `None` if a link route holds an invalid id
+    fn normalize_path(&self, path: &str) -> Option<String> {
+        let mut segments: Vec<String> = path.split('/').map(str::to_string).collect();
+        // segments[0] is the empty string before the leading '/'
+        if !segments
+            .get(1)
+            .is_some_and(|root| self.roots.contains(root))
+        {
+            return Some(path.to_string());
+        }
+
+        for index in (2..segments.len()).step_by(2) {
+            let raw = decode_braces(&segments[index]);
+            if raw.is_empty() {
+                continue;
+            }
+            match self.normalizer.canonicalize(&raw) {
+                Some(id) => segments[index] = id,
+                None if self.mode == Mode::LinkIds => return None,
+                None => {}
+            }
+        }
+        Some(segments.join("/"))
+    }
+
+    /// Whether the request body carries an entity `id` to normalize
+    fn normalizes_body(&self, method: &Method, path: &str) -> bool {
+        let mut segments = path.trim_matches('/').split('/');
+        let in_roots = segments
+            .next()
+            .is_some_and(|root| self.roots.contains(root));
+        match segments.count() {
+            0 => in_roots && method == Method::POST,
+            1 => in_roots && (method == Method::PUT || method == Method::PATCH),
+            _ => false,
+        }
+    }
+}
+
+/// Clients usually percent-encode braces; ids contain no other escapes
+fn decode_braces(segment: &str) -> String {
+    segment
+        .replace("%7B", "{")
+        .replace("%7b", "{")
+        .replace("%7D", "}")
+        .replace("%7d", "}")
+}
+
+/// Wrap `app` so path ids are canonical by the time it routes the request
+///
+/// Layers added with `Router::layer` run after routing, when path parameters
+/// are already extracted, so the rewrite has to happen in front of `app`.
+pub fn canonicalize_paths(
+    app: Router,
+    normalizer: Arc<dyn IdNormalizer>,
+    config: &LinksConfig,
+) -> Router {
+    Router::new()
+        .fallback_service(app)
+        .layer(axum::middleware::from_fn_with_state(
+            IdNormalizationState::for_paths(normalizer, config),
+            id_normalization_middleware,
+        ))
+}
+
+/// Middleware canonicalizing (or rejecting) the entity ids of a request
+pub async fn id_normalization_middleware(
+    State(state): State<IdNormalizationState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match state.mode {
+        Mode::Paths => next.run(rewrite_path(&state, request)).await,
+        Mode::LinkIds => match state.normalize_path(request.uri().path()) {
+            Some(_) => next.run(request).await,
+            None => ExtractorError::InvalidEntityId.into_response(),
+        },
+        Mode::EntityBodies => {
+            if !state.normalizes_body(request.method(), request.uri().path()) {
+                return next.run(request).await;
+            }
+            match normalize_body(&state, request).await {
+                Ok(request) => next.run(request).await,
+                Err(response) => response,
+            }
+        }
+    }
+}
+
+fn rewrite_path(state: &IdNormalizationState, mut request: Request) -> Request {
+    let path = request.uri().path();
+    let Some(normalized) = state.normalize_path(path).filter(|n| n != path) else {
+        return request;
+    };
+
+    let path_and_query = match request.uri().query() {
+        Some(query) => format!("{}?{}", normalized, query),
+        None => normalized,
+    };
+    let mut parts = request.uri().clone().into_parts();
+    parts.path_and_query = path_and_query.parse().ok();
+    if let Ok(uri) = Uri::from_parts(parts) {
+        *request.uri_mut() = uri;
+    }
+    request
+}
+
+async fn normalize_body(
+    state: &IdNormalizationState,
+    request: Request,
+) -> Result<Request, Response> {
+    let mut body = JsonRequest::read(request).await?;
+
+    // Malformed JSON and non-string ids are left for the handler to report
+    let raw = body
+        .json()
+        .and_then(|payload| payload.get("id"))
+        .and_then(Value::as_str);
+    if let Some(raw) = raw {
+        let id = state
+            .normalizer
+            .canonicalize(raw)
+            .ok_or_else(|| ExtractorError::InvalidEntityId.into_response())?;
+        if let Some(payload) = body.json_mut() {
+            payload["id"] = Value::String(id);
+        }
+    }
+
+    Ok(body.into_request())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::ids::DefaultIdNormalizer;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Path;
+    use axum::http::StatusCode;
+    use axum::routing::{get, post};
+    use axum::{Json, middleware};
+    use serde_json::json;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    const CANONICAL: &str = "a1b2c3d4-e5f6-4789-abcd-ef0123456789";
+    const BRACED: &str = "%7BA1B2C3D4-E5F6-4789-ABCD-EF0123456789%7D";
+
+    fn config() -> LinksConfig {
+        LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "user".to_string(),
+                plural: "users".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        }
+    }
+
+    fn entity_app() -> Router {
+        let echo = |Json(body): Json<Value>| async move { Json(body) };
+        let app = Router::new()
+            .route("/users", post(echo))
+            .route(
+                "/users/{id}",
+                get(|Path(id): Path<String>| async move { id }).put(echo),
+            )
+            .route("/users/{id}/{action}", get(|| async { "custom" }))
+            .layer(middleware::from_fn_with_state(
+                IdNormalizationState::for_entities(Arc::new(DefaultIdNormalizer), &config()),
+                id_normalization_middleware,
+            ));
+        canonicalize_paths(app, Arc::new(DefaultIdNormalizer), &config())
+    }
+
+    fn link_app() -> Router {
+        let app = Router::new()
+            .route(
+                "/{t}/{id}/{route}/{target}",
+                get(
+                    |Path((_, id, _, target)): Path<(String, Uuid, String, Uuid)>| async move {
+                        format!("{id} {target}")
+                    },
+                ),
+            )
+            .fallback(|| async { "fallback" })
+            .layer(middleware::from_fn_with_state(
+                IdNormalizationState::for_links(Arc::new(DefaultIdNormalizer), &config()),
+                id_normalization_middleware,
+            ));
+        canonicalize_paths(app, Arc::new(DefaultIdNormalizer), &config())
+    }
+
+    async fn send(
+        app: Router,
+        method: &str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, String) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(body.map_or_else(Body::empty, |b| Body::from(b.to_string())))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024 * 64).await.unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_entity_path_id_is_canonicalized() {
+        let (status, body) = send(entity_app(), "GET", &format!("/users/{BRACED}"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, CANONICAL);
+
+        // Module-defined segments are not ids and pass through
+        let (status, body) = send(entity_app(), "GET", "/users/search", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "search");
+    }
+
+    #[tokio::test]
+    async fn test_entity_body_id_is_canonicalized() {
+        let raw = "{A1B2C3D4-E5F6-4789-ABCD-EF0123456789}";
+        let (status, body) = send(entity_app(), "POST", "/users", Some(json!({"id": raw}))).await;
+        assert_eq!(status, StatusCode::OK);
+        let body: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["id"], CANONICAL);
+
+        let (status, _) = send(
+            entity_app(),
+            "PUT",
+            &format!("/users/{CANONICAL}"),
+            Some(json!({"id": "not-a-uuid"})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_link_path_ids_are_canonicalized_or_rejected() {
+        let target = Uuid::new_v4();
+        let uri = format!(
+            "/users/{BRACED}/cars-owned/{}",
+            target.to_string().to_uppercase()
+        );
+        let (status, body) = send(link_app(), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{CANONICAL} {target}"));
+
+        let (status, body) = send(link_app(), "GET", "/users/nope/cars-owned", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let body: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["error"], ExtractorError::InvalidEntityId.to_string());
+
+        // Paths outside configured entities are left alone
+        let (status, body) = send(link_app(), "GET", "/health/nope", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "fallback");
+    }
+}
@@ -6,15 +6,44 @@
 //!
 //! The REST exposure consumes a `ServerHost` and produces an Axum `Router`.
 
+pub mod actor;
+pub mod audit;
+pub mod computed;
+pub mod conditional;
+pub mod constraints;
+pub mod events;
+pub mod export;
+pub mod history;
+pub mod hooks;
+pub mod id_policy;
+pub mod idempotency;
+pub mod ids;
 pub mod notifications;
+pub mod openapi;
+pub mod ownership;
+pub mod pagination;
+pub mod patch;
+pub mod redaction;
+#[cfg(feature = "json-schema")]
+pub mod schema;
+pub mod search;
+pub mod soft_delete;
 pub mod sse;
+pub mod tenancy;
+pub mod update_interval;
 
 use super::super::host::ServerHost;
+use crate::core::health::{DEFAULT_HEALTH_CHECK_TIMEOUT, HealthReport};
+use crate::core::{BoxedLayer, HealthCheck};
 use crate::links::handlers::AppState;
-use crate::server::router::build_link_routes;
+use crate::server::body::{BodyLimit, body_limit_middleware};
+use crate::server::router::build_live_link_routes;
 use anyhow::Result;
+use axum::extract::{DefaultBodyLimit, State};
+use axum::http::StatusCode;
 use axum::{Json, Router, routing::get};
 use serde_json::{Value, json};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// REST API exposure implementation
@@ -42,24 +71,275 @@ impl RestExposure {
     /// - Entity CRUD routes
     /// - Link routes
     /// - Custom routes
+    /// - The OpenAPI document at `/openapi.json`
+    ///
+    /// All nested under the host's `route_prefix`, when it has one.
     pub fn build_router(host: Arc<ServerHost>, custom_routes: Vec<Router>) -> Result<Router> {
+        // Entity routes use the configuration as of now; link routes follow reloads
+        let config = host.config();
+
         // Create link app state from host
         let link_state = AppState {
             link_service: host.link_service.clone(),
-            config: host.config.clone(),
-            registry: host.registry.clone(),
+            config: config.clone(),
+            registry: host.registry(),
             entity_fetchers: host.entity_fetchers.clone(),
             entity_creators: host.entity_creators.clone(),
             event_bus: host.event_bus.clone(),
+            enrichment_fallback: host.enrichment_fallback,
+            enrichment_concurrency: host.enrichment_concurrency,
+            auth_provider: host.auth_provider.clone(),
+            audit_log: host.audit_log.clone(),
+            allow_duplicate_links: host.allow_duplicate_links,
+            id_strategy: host.id_strategy,
+            route_prefix: host.route_prefix.clone().unwrap_or_default(),
         };
 
         // Build all routes
-        let health_routes = Self::health_routes();
-        let entity_routes = host.entity_registry.build_routes();
-        let link_routes = build_link_routes(link_state.clone());
+        let health_routes = Self::health_routes(host.health_checks.clone());
+        let entity_routes = Self::entity_routes(&host);
+
+        // GET /{plural}/search for entity types with indexed fields
+        let entity_routes =
+            entity_routes.merge(search::search_routes(&host.entity_fetchers, &config));
+
+        // POST /{plural}/{id}/restore for entity types with a creator
+        let entity_routes = entity_routes.merge(soft_delete::restore_routes(
+            &host.entity_creators,
+            &config,
+            host.event_bus.clone(),
+            host.soft_delete_status.clone(),
+        ));
+
+        // GET /{plural}/{id}/history and /versions/{n}, behind the entity layers
+        let entity_routes = match &host.history_service {
+            Some(history_service) => {
+                entity_routes.merge(history::history_routes(history::HistoryState {
+                    history_service: history_service.clone(),
+                    config: config.clone(),
+                }))
+            }
+            None => entity_routes,
+        };
+
+        // Serve PATCH /{plural}/{id} through the entity creators
+        let entity_routes = entity_routes.layer(axum::middleware::from_fn_with_state(
+            patch::PatchState::new(
+                host.entity_creators.clone(),
+                &config,
+                host.event_bus.clone(),
+            ),
+            patch::patch_middleware,
+        ));
+
+        // Serve DELETE ?soft=true the same way
+        let entity_routes = entity_routes.layer(axum::middleware::from_fn_with_state(
+            soft_delete::SoftDeleteState::new(
+                host.entity_creators.clone(),
+                &config,
+                host.soft_delete_status.clone(),
+            ),
+            soft_delete::soft_delete_middleware,
+        ));
+
+        // Reject entity payloads that violate their JSON Schema
+        #[cfg(feature = "json-schema")]
+        let entity_routes = match &host.entity_schemas {
+            Some(schemas) => entity_routes.layer(axum::middleware::from_fn_with_state(
+                schema::SchemaState::new(schemas.clone(), &config),
+                schema::schema_middleware,
+            )),
+            None => entity_routes,
+        };
+
+        // Reject over-long/short fields before they reach storage
+        let constraints_state = constraints::ConstraintsState::new(&config);
+        let entity_routes = if constraints_state.is_empty() {
+            entity_routes
+        } else {
+            entity_routes.layer(axum::middleware::from_fn_with_state(
+                constraints_state,
+                constraints::constraints_middleware,
+            ))
+        };
+
+        // Publish entity events for successful mutations
+        let entity_routes = match &host.event_bus {
+            Some(event_bus) => entity_routes.layer(axum::middleware::from_fn_with_state(
+                events::EntityEventsState::new(event_bus.clone(), &config),
+                events::entity_events_middleware,
+            )),
+            None => entity_routes,
+        };
+
+        // Refuse updates arriving within the entity's minimum update interval
+        let update_interval_state =
+            update_interval::UpdateIntervalState::new(&host.entity_fetchers, &config);
+        let entity_routes = if update_interval_state.is_empty() {
+            entity_routes
+        } else {
+            entity_routes.layer(axum::middleware::from_fn_with_state(
+                update_interval_state,
+                update_interval::update_interval_middleware,
+            ))
+        };
+
+        // Serve computed fields on reads, inside the ETag and redaction layers
+        let computed_state = computed::ComputedState::new(&host.entity_fetchers, &config);
+        let entity_routes = if computed_state.is_empty() {
+            entity_routes
+        } else {
+            entity_routes.layer(axum::middleware::from_fn_with_state(
+                computed_state,
+                computed::computed_middleware,
+            ))
+        };
+
+        // Tag entities with ETags and answer If-None-Match / If-Match
+        let conditional_state = conditional::ConditionalState::new(&host.entity_fetchers, &config);
+        let entity_routes = if conditional_state.is_empty() {
+            entity_routes
+        } else {
+            entity_routes.layer(axum::middleware::from_fn_with_state(
+                conditional_state,
+                conditional::conditional_middleware,
+            ))
+        };
+
+        // Answer GET ?version=n from the history, outside the current-state layers
+        let entity_routes = match &host.history_service {
+            Some(history_service) => entity_routes.layer(axum::middleware::from_fn_with_state(
+                history::VersionState::new(
+                    history_service.clone(),
+                    host.entity_fetchers.clone(),
+                    &config,
+                ),
+                history::version_middleware,
+            )),
+            None => entity_routes,
+        };
+
+        // Run module lifecycle hooks; validation above sees rewritten payloads
+        let hooks_state = hooks::HooksState::new(&host.entity_modules, &config);
+        let entity_routes = if hooks_state.is_empty() {
+            entity_routes
+        } else {
+            entity_routes.layer(axum::middleware::from_fn_with_state(
+                hooks_state,
+                hooks::hooks_middleware,
+            ))
+        };
+
+        // Record successful mutations, as the hooks left them, in the audit log
+        let entity_routes = match &host.audit_log {
+            Some(audit_log) => entity_routes.layer(axum::middleware::from_fn_with_state(
+                audit::AuditState::new(
+                    audit_log.clone(),
+                    host.entity_fetchers.clone(),
+                    &config,
+                    host.auth_provider.clone(),
+                ),
+                audit::audit_middleware,
+            )),
+            None => entity_routes,
+        };
+
+        // Apply id policies to create payloads before anything else sees them
+        let id_policy_state = id_policy::IdPolicyState::new(&config);
+        let entity_routes = if id_policy_state.is_empty() {
+            entity_routes
+        } else {
+            entity_routes.layer(axum::middleware::from_fn_with_state(
+                id_policy_state,
+                id_policy::id_policy_middleware,
+            ))
+        };
+
+        // Stamp the caller as owner of new entities and enforce `owner` policies
+        let entity_routes = match &host.auth_provider {
+            Some(auth_provider) => entity_routes.layer(axum::middleware::from_fn_with_state(
+                ownership::OwnershipState::new(
+                    auth_provider.clone(),
+                    &host.entity_fetchers,
+                    &config,
+                ),
+                ownership::ownership_middleware,
+            )),
+            None => entity_routes,
+        };
+
+        // Strip the fields each entity type withholds from the caller
+        let redaction_state = redaction::RedactionState::new(
+            &host.entity_fetchers,
+            &config,
+            host.auth_provider.clone(),
+        );
+        let entity_routes = if redaction_state.is_empty() {
+            entity_routes
+        } else {
+            entity_routes.layer(axum::middleware::from_fn_with_state(
+                redaction_state,
+                redaction::redaction_middleware,
+            ))
+        };
+
+        // Serve CSV and NDJSON exports of entity lists, outside the layers buffering bodies
+        let entity_routes = entity_routes.layer(axum::middleware::from_fn_with_state(
+            export::ExportState::new(&host.entity_fetchers, &config, host.auth_provider.clone()),
+            export::export_middleware,
+        ));
+
+        // Canonicalize body ids and reject malformed ids in link paths
+        let entity_routes = entity_routes.layer(axum::middleware::from_fn_with_state(
+            ids::IdNormalizationState::for_entities(host.id_normalizer.clone(), &config),
+            ids::id_normalization_middleware,
+        ));
+        let link_routes = build_live_link_routes(link_state.clone(), host.link_tables().clone())
+            .layer(axum::middleware::from_fn_with_state(
+                ids::IdNormalizationState::for_links(host.id_normalizer.clone(), &config),
+                ids::id_normalization_middleware,
+            ));
+
+        // Run each POST carrying an Idempotency-Key once, replaying its response
+        let idempotency = axum::middleware::from_fn_with_state(
+            idempotency::IdempotencyState::new(
+                idempotency::IdempotencyCache::new(
+                    host.idempotency_cache_size,
+                    host.idempotency_ttl,
+                ),
+                host.auth_provider.clone(),
+            ),
+            idempotency::idempotency_middleware,
+        );
+        let entity_routes = entity_routes.layer(idempotency.clone());
+        let link_routes = link_routes.layer(idempotency);
+
+        // Scope entity and link requests to the tenant named in their header,
+        // hiding other tenants' entities from the per-entity layers
+        let (entity_routes, link_routes) = match &host.tenant_header {
+            Some(header) => {
+                let tenancy = axum::middleware::from_fn_with_state(
+                    tenancy::TenancyState::new(header.clone()),
+                    tenancy::tenancy_middleware,
+                );
+                let entity_routes = entity_routes.layer(axum::middleware::from_fn_with_state(
+                    tenancy::TenantEntityState::new(&host.entity_fetchers, &config),
+                    tenancy::tenant_entity_middleware,
+                ));
+                (
+                    entity_routes.layer(tenancy.clone()),
+                    link_routes.layer(tenancy),
+                )
+            }
+            None => (entity_routes, link_routes),
+        };
+
+        // Describe the entity and link routes as they are now
+        let openapi_routes =
+            openapi::openapi_routes(openapi::generate(&host.entity_registry, &config));
 
         // Merge everything
-        let mut app = health_routes.merge(entity_routes);
+        let mut app = health_routes.merge(openapi_routes).merge(entity_routes);
 
         for custom_router in custom_routes {
             app = app.merge(custom_router);
@@ -90,23 +370,105 @@ impl RestExposure {
             app = app.merge(notifications::notification_routes(notif_state));
         }
 
-        Ok(app)
+        // Apply the host's page size bounds to `QueryParams::limit()`
+        app = app.layer(axum::middleware::from_fn_with_state(
+            host.pagination,
+            pagination::pagination_middleware,
+        ));
+
+        // Stamp entities written by the request with the caller
+        if let Some(auth_provider) = &host.auth_provider {
+            app = app.layer(axum::middleware::from_fn_with_state(
+                auth_provider.clone(),
+                actor::actor_middleware,
+            ));
+        }
+
+        // Log every request with its matched route
+        if host.request_logging {
+            app = app.layer(axum::middleware::from_fn(
+                crate::server::router::request_logging_middleware,
+            ));
+        }
+
+        // Bound the bodies of handlers (`Json`) and of the layers above
+        app = app.layer(DefaultBodyLimit::max(host.max_body_size)).layer(
+            axum::middleware::from_fn_with_state(
+                BodyLimit(host.max_body_size),
+                body_limit_middleware,
+            ),
+        );
+
+        // Canonicalize path ids (braces, case) before routing
+        let app = ids::canonicalize_paths(app, host.id_normalizer.clone(), &config);
+
+        // Serve everything under the prefix; nesting strips it from the URI,
+        // so handlers parsing the path (nested link routes) see it unprefixed
+        Ok(match &host.route_prefix {
+            Some(prefix) => Router::new().nest(prefix, app),
+            None => app,
+        })
+    }
+
+    /// Build the entity CRUD routes, each wrapped in its module's layers
+    ///
+    /// See [`Module::layers`](crate::core::Module::layers).
+    fn entity_routes(host: &ServerHost) -> Router {
+        let mut module_layers: HashMap<&str, Vec<BoxedLayer>> = HashMap::new();
+        let mut router = Router::new();
+        for descriptor in host.entity_registry.descriptors() {
+            let mut routes = descriptor.build_routes();
+            if let Some(module) = host.entity_modules.get(descriptor.entity_type()) {
+                let layers = module_layers
+                    .entry(module.name())
+                    .or_insert_with(|| module.layers());
+                for layer in layers.iter() {
+                    routes = routes.layer(layer.clone());
+                }
+            }
+            router = router.merge(routes);
+        }
+        router
     }
 
     /// Build health check routes
-    fn health_routes() -> Router {
+    ///
+    /// `/healthz` is a liveness probe and always answers ok; `/health` is a
+    /// readiness probe running `checks`.
+    fn health_routes(checks: Vec<Arc<dyn HealthCheck>>) -> Router {
         Router::new()
-            .route("/health", get(Self::health_check))
+            .route("/health", get(Self::readiness_check))
+            .with_state(Arc::new(checks))
             .route("/healthz", get(Self::health_check))
     }
 
-    /// Health check endpoint handler
+    /// Liveness endpoint handler
     async fn health_check() -> Json<Value> {
         Json(json!({
             "status": "ok",
             "service": "this-rs"
         }))
     }
+
+    /// Readiness endpoint handler: 503 when any backend check fails
+    async fn readiness_check(
+        State(checks): State<Arc<Vec<Arc<dyn HealthCheck>>>>,
+    ) -> (StatusCode, Json<Value>) {
+        let report = HealthReport::collect(&checks, DEFAULT_HEALTH_CHECK_TIMEOUT).await;
+        let (status, label) = if report.is_healthy() {
+            (StatusCode::OK, "ok")
+        } else {
+            (StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+        };
+        (
+            status,
+            Json(json!({
+                "status": label,
+                "service": "this-rs",
+                "checks": report.checks
+            })),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -136,13 +498,13 @@ mod tests {
 
     #[test]
     fn test_health_routes_builds_router() {
-        let router = RestExposure::health_routes();
+        let router = RestExposure::health_routes(vec![]);
         let _ = router;
     }
 
     #[tokio::test]
     async fn test_health_endpoint_returns_ok() {
-        let router = RestExposure::health_routes();
+        let router = RestExposure::health_routes(vec![]);
         let response = router
             .oneshot(
                 Request::builder()
@@ -165,7 +527,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_healthz_endpoint_returns_ok() {
-        let router = RestExposure::health_routes();
+        let router = RestExposure::health_routes(vec![]);
         let response = router
             .oneshot(
                 Request::builder()
@@ -185,6 +547,276 @@ mod tests {
         assert_eq!(json["status"], "ok");
     }
 
+    struct Unreachable;
+
+    #[async_trait::async_trait]
+    impl HealthCheck for Unreachable {
+        fn name(&self) -> &str {
+            "mysql"
+        }
+
+        async fn check(&self) -> Result<()> {
+            anyhow::bail!("connection refused")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_health_returns_503_when_a_check_fails() {
+        let host = ServerHost::minimal_for_test().with_health_checks(vec![
+            Arc::new(crate::storage::InMemoryLinkService::new()),
+            Arc::new(Unreachable),
+        ]);
+        let router =
+            RestExposure::build_router(Arc::new(host), vec![]).expect("build should succeed");
+
+        let response = router
+            .clone()
+            .oneshot(Request::get("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["status"], "unavailable");
+        assert_eq!(json["checks"]["mysql"]["status"], "error");
+        assert_eq!(json["checks"]["mysql"]["error"], "connection refused");
+        assert_eq!(json["checks"]["in_memory"]["status"], "ok");
+
+        // Liveness does not depend on the backends
+        let response = router
+            .oneshot(Request::get("/healthz").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_restore_route_is_mounted_for_entities_with_a_creator() {
+        use crate::config::{EntityAuthConfig, EntityConfig};
+        use crate::core::EntityCreator;
+
+        struct TicketCreator;
+
+        #[async_trait::async_trait]
+        impl EntityCreator for TicketCreator {
+            async fn create_from_json(&self, data: Value) -> Result<Value> {
+                Ok(data)
+            }
+        }
+
+        let mut config = LinksConfig::default_config();
+        config.entities = vec![EntityConfig {
+            singular: "ticket".to_string(),
+            plural: "tickets".to_string(),
+            auth: EntityAuthConfig::default(),
+            id_policy: Default::default(),
+            fields: Default::default(),
+            min_update_interval: None,
+        }];
+        let creators: HashMap<String, Arc<dyn EntityCreator>> =
+            HashMap::from([("ticket".to_string(), Arc::new(TicketCreator) as _)]);
+        let host = ServerHost::from_builder_components(
+            Arc::new(InMemoryLinkService::new()),
+            config,
+            EntityRegistry::new(),
+            HashMap::new(),
+            creators,
+        )
+        .expect("should build host");
+        let router = RestExposure::build_router(Arc::new(host), vec![]).expect("should build");
+
+        // Reaches the restore handler rather than the link routes
+        let uri = format!("/tickets/{}/restore", uuid::Uuid::new_v4());
+        let response = router
+            .oneshot(Request::post(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[tokio::test]
+    async fn test_repeated_idempotency_key_creates_one_entity() {
+        use crate::config::{EntityAuthConfig, EntityConfig};
+        use crate::server::entity_registry::EntityDescriptor;
+        use axum::routing::post;
+        use std::sync::Mutex;
+
+        /// `POST /orders` storing the body under a fresh id
+        struct OrderDescriptor(Arc<Mutex<Vec<Value>>>);
+
+        impl EntityDescriptor for OrderDescriptor {
+            fn entity_type(&self) -> &str {
+                "order"
+            }
+
+            fn plural(&self) -> &str {
+                "orders"
+            }
+
+            fn build_routes(&self) -> Router {
+                let orders = self.0.clone();
+                Router::new().route(
+                    "/orders",
+                    post(move |Json(mut order): Json<Value>| async move {
+                        order["id"] = json!(uuid::Uuid::new_v4());
+                        orders.lock().unwrap().push(order.clone());
+                        (StatusCode::CREATED, Json(order))
+                    }),
+                )
+            }
+        }
+
+        let mut config = LinksConfig::default_config();
+        config.entities = vec![EntityConfig {
+            singular: "order".to_string(),
+            plural: "orders".to_string(),
+            auth: EntityAuthConfig::default(),
+            id_policy: Default::default(),
+            fields: Default::default(),
+            min_update_interval: None,
+        }];
+        let orders = Arc::new(Mutex::new(Vec::new()));
+        let mut registry = EntityRegistry::new();
+        registry.register(Box::new(OrderDescriptor(orders.clone())));
+        let host = ServerHost::from_builder_components(
+            Arc::new(InMemoryLinkService::new()),
+            config,
+            registry,
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .expect("should build host");
+        let router = RestExposure::build_router(Arc::new(host), vec![]).expect("should build");
+
+        let create = || async {
+            let request = Request::post("/orders")
+                .header("content-type", "application/json")
+                .header("idempotency-key", "order-42")
+                .body(Body::from(r#"{"amount": 42}"#))
+                .unwrap();
+            let response = router.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+            axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap()
+        };
+        let first = create().await;
+        let second = create().await;
+
+        assert_eq!(first, second);
+        assert_eq!(orders.lock().unwrap().len(), 1);
+    }
+
+    crate::impl_data_entity!(Invoice, "invoice", ["name"], {
+        amount: i64,
+    });
+
+    #[tokio::test]
+    async fn test_entity_get_answers_304_for_the_current_etag() {
+        use crate::config::{EntityAuthConfig, EntityConfig};
+        use crate::core::etag::etag_of;
+        use crate::core::{DataService, EntityFetcher};
+        use crate::server::entity_registry::EntityDescriptor;
+        use crate::storage::InMemoryDataService;
+        use axum::extract::Path;
+        use axum::http::header::{ETAG, IF_NONE_MATCH};
+        use axum::routing::get;
+
+        type Service = Arc<InMemoryDataService<Invoice>>;
+
+        /// `GET /invoices/{id}` reading from the in-memory service
+        struct InvoiceDescriptor(Service);
+
+        impl EntityDescriptor for InvoiceDescriptor {
+            fn entity_type(&self) -> &str {
+                "invoice"
+            }
+
+            fn plural(&self) -> &str {
+                "invoices"
+            }
+
+            fn build_routes(&self) -> Router {
+                Router::new()
+                    .route(
+                        "/invoices/{id}",
+                        get(
+                            |State(service): State<Service>, Path(id): Path<uuid::Uuid>| async move {
+                                Json(service.get(&id).await.unwrap())
+                            },
+                        ),
+                    )
+                    .with_state(self.0.clone())
+            }
+        }
+
+        let service = Arc::new(InMemoryDataService::<Invoice>::new());
+        let invoice = service
+            .create(Invoice::new("INV-1".to_string(), "active".to_string(), 100))
+            .await
+            .unwrap();
+
+        let mut config = LinksConfig::default_config();
+        config.entities = vec![EntityConfig {
+            singular: "invoice".to_string(),
+            plural: "invoices".to_string(),
+            auth: EntityAuthConfig::default(),
+            id_policy: Default::default(),
+            fields: Default::default(),
+            min_update_interval: None,
+        }];
+        let mut registry = EntityRegistry::new();
+        registry.register(Box::new(InvoiceDescriptor(service.clone())));
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> =
+            HashMap::from([("invoice".to_string(), service.clone() as _)]);
+        let host = ServerHost::from_builder_components(
+            Arc::new(InMemoryLinkService::new()),
+            config,
+            registry,
+            fetchers,
+            HashMap::new(),
+        )
+        .expect("should build host");
+        let router = RestExposure::build_router(Arc::new(host), vec![]).expect("should build");
+
+        let uri = format!("/invoices/{}", invoice.id);
+        let response = router
+            .clone()
+            .oneshot(Request::get(&uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let etag = etag_of(&invoice);
+        assert_eq!(response.headers()[ETAG], etag.as_str());
+
+        let response = router
+            .clone()
+            .oneshot(
+                Request::get(&uri)
+                    .header(IF_NONE_MATCH, &etag)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[ETAG], etag.as_str());
+
+        // A stale tag gets the entity again
+        let response = router
+            .oneshot(
+                Request::get(&uri)
+                    .header(IF_NONE_MATCH, "\"0\"")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
     #[test]
     fn test_build_router_succeeds_with_host() {
         let host = test_host();
@@ -202,6 +834,40 @@ mod tests {
         assert!(router.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_bodies_over_the_configured_limit_are_refused() {
+        use axum::routing::post;
+
+        let host = ServerHost::from_builder_components(
+            Arc::new(InMemoryLinkService::new()),
+            LinksConfig::default_config(),
+            EntityRegistry::new(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .expect("should build host")
+        .with_max_body_size(16);
+        let echo = Router::new().route(
+            "/echo",
+            post(|Json(body): Json<Value>| async { Json(body) }),
+        );
+        let router =
+            RestExposure::build_router(Arc::new(host), vec![echo]).expect("build should succeed");
+
+        let post_echo = |body: Value| {
+            router.clone().oneshot(
+                Request::post("/echo")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+        };
+        let response = post_echo(json!({ "a": 1 })).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = post_echo(json!({ "text": "x".repeat(64) })).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
     #[tokio::test]
     async fn test_build_router_health_endpoint_reachable() {
         let host = test_host();
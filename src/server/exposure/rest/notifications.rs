@@ -21,6 +21,7 @@
 //! - `POST   /device-tokens/:user_id`              — Register a device token
 //! - `DELETE /device-tokens/:user_id/:token`       — Unregister a device token
 
+use crate::core::extractors::error_response;
 use crate::events::sinks::device_tokens::{DeviceTokenStore, Platform};
 use crate::events::sinks::in_app::NotificationStore;
 use crate::events::sinks::preferences::{NotificationPreferencesStore, UserPreferences};
@@ -166,11 +167,12 @@ async fn delete_notification(
     let deleted = state.notification_store.delete(&notification_id).await;
 
     if deleted {
-        (StatusCode::OK, Json(json!({ "deleted": true })))
+        (StatusCode::OK, Json(json!({ "deleted": true }))).into_response()
     } else {
-        (
+        error_response(
             StatusCode::NOT_FOUND,
-            Json(json!({ "error": "notification not found" })),
+            "NOTIFICATION_NOT_FOUND",
+            "notification not found",
         )
     }
 }
@@ -259,12 +261,9 @@ async fn unregister_device_token(
     let removed = state.device_token_store.unregister(&user_id, &token).await;
 
     if removed {
-        (StatusCode::OK, Json(json!({ "unregistered": true })))
+        (StatusCode::OK, Json(json!({ "unregistered": true }))).into_response()
     } else {
-        (
-            StatusCode::NOT_FOUND,
-            Json(json!({ "error": "token not found" })),
-        )
+        error_response(StatusCode::NOT_FOUND, "TOKEN_NOT_FOUND", "token not found")
     }
 }
 
@@ -0,0 +1,555 @@
+//! OpenAPI 3.1 description of the REST exposure
+//!
+//! Served at `GET /openapi.json`, next to the `.proto` export of the gRPC
+//! exposure and the SDL of the GraphQL one. The document is generated once,
+//! when the router is built, from the [`EntityRegistry`] and the
+//! [`LinksConfig`]:
+//!
+//! - `/{plural}` and `/{plural}/{id}` for every registered entity, with the
+//!   request body schema given by its [`EntityDescriptor::body_schema`]
+//!   (any JSON object when the descriptor has none)
+//! - the generic `/links` routes and the forward/reverse routes of every
+//!   link definition
+//! - the pagination, filter and sort query parameters, and the `{"error"}`
+//!   bodies of failed requests
+//!
+//! [`EntityDescriptor::body_schema`]: crate::server::entity_registry::EntityDescriptor::body_schema
+
+use crate::config::LinksConfig;
+use crate::server::entity_registry::EntityRegistry;
+use axum::{Json, Router, routing::get};
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Route serving the document
+pub const OPENAPI_PATH: &str = "/openapi.json";
+
+/// Router serving `document` at [`OPENAPI_PATH`]
+pub fn openapi_routes(document: Value) -> Router {
+    let document = Arc::new(document);
+    Router::new().route(
+        OPENAPI_PATH,
+        get(move || {
+            let document = document.clone();
+            async move { Json(document.as_ref().clone()) }
+        }),
+    )
+}
+
+/// Generate the OpenAPI document for the entities of `registry` and the links of `config`
+pub fn generate(registry: &EntityRegistry, config: &LinksConfig) -> Value {
+    let mut paths = Map::new();
+    let mut schemas = Map::new();
+
+    // Plural route segment of each entity type, registry first
+    let mut plurals: HashMap<String, String> = config
+        .entities
+        .iter()
+        .map(|e| (e.singular.clone(), e.plural.clone()))
+        .collect();
+    let mut descriptors = registry.descriptors();
+    descriptors.sort_by_key(|d| d.entity_type().to_string());
+    for descriptor in &descriptors {
+        plurals.insert(
+            descriptor.entity_type().to_string(),
+            descriptor.plural().to_string(),
+        );
+    }
+    let pluralizer = config.pluralizer();
+    let plural_of = |entity_type: &str| {
+        plurals
+            .get(entity_type)
+            .cloned()
+            .unwrap_or_else(|| pluralizer.pluralize(entity_type))
+    };
+
+    for descriptor in &descriptors {
+        let entity_type = descriptor.entity_type();
+        let plural = descriptor.plural();
+        let body = descriptor
+            .body_schema()
+            .unwrap_or_else(|| json!({ "type": "object" }));
+        schemas.insert(schema_name(entity_type), body);
+        let entity = schema_ref(&schema_name(entity_type));
+
+        paths.insert(
+            format!("/{plural}"),
+            json!({
+                "get": {
+                    "tags": [entity_type],
+                    "operationId": format!("list_{plural}"),
+                    "parameters": list_parameters(),
+                    "responses": {
+                        "200": json_response("Page of entities", paginated(entity.clone())),
+                        "400": error_ref("BadRequest"),
+                    }
+                },
+                "post": {
+                    "tags": [entity_type],
+                    "operationId": format!("create_{entity_type}"),
+                    "requestBody": json_body(entity.clone()),
+                    "responses": {
+                        "201": json_response("Created entity", entity.clone()),
+                        "400": error_ref("BadRequest"),
+                        "422": error_ref("UnprocessableEntity"),
+                    }
+                }
+            }),
+        );
+
+        paths.insert(
+            format!("/{plural}/{{id}}"),
+            json!({
+                "parameters": [path_id("id")],
+                "get": {
+                    "tags": [entity_type],
+                    "operationId": format!("get_{entity_type}"),
+                    "responses": {
+                        "200": json_response("The entity", entity.clone()),
+                        "404": error_ref("NotFound"),
+                    }
+                },
+                "put": {
+                    "tags": [entity_type],
+                    "operationId": format!("update_{entity_type}"),
+                    "requestBody": json_body(entity.clone()),
+                    "responses": {
+                        "200": json_response("Updated entity", entity.clone()),
+                        "400": error_ref("BadRequest"),
+                        "404": error_ref("NotFound"),
+                        "409": error_ref("Conflict"),
+                    }
+                },
+                "patch": {
+                    "tags": [entity_type],
+                    "operationId": format!("patch_{entity_type}"),
+                    "description": "JSON Merge Patch: listed keys change, `null` clears a field",
+                    "requestBody": json_body(json!({ "type": "object" })),
+                    "responses": {
+                        "200": json_response("Patched entity", entity.clone()),
+                        "400": error_ref("BadRequest"),
+                        "404": error_ref("NotFound"),
+                        "409": error_ref("Conflict"),
+                    }
+                },
+                "delete": {
+                    "tags": [entity_type],
+                    "operationId": format!("delete_{entity_type}"),
+                    "responses": {
+                        "204": { "description": "Deleted" },
+                        "404": error_ref("NotFound"),
+                    }
+                }
+            }),
+        );
+    }
+
+    paths.insert(
+        "/links".to_string(),
+        json!({
+            "post": {
+                "tags": ["links"],
+                "operationId": "create_link_by_type",
+                "requestBody": json_body(schema_ref("CreateLink")),
+                "responses": {
+                    "201": json_response("Created link", schema_ref("Link")),
+                    "400": error_ref("BadRequest"),
+                    "404": error_ref("NotFound"),
+                }
+            }
+        }),
+    );
+    paths.insert(
+        "/links/{link_id}".to_string(),
+        json!({
+            "parameters": [path_id("link_id")],
+            "get": {
+                "tags": ["links"],
+                "operationId": "get_link",
+                "responses": {
+                    "200": json_response("The link", schema_ref("Link")),
+                    "404": error_ref("NotFound"),
+                }
+            }
+        }),
+    );
+
+    for link in &config.links {
+        let source = plural_of(&link.source_type);
+        let target = plural_of(&link.target_type);
+        let tags = json!([link.link_type]);
+        let summary = link
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("{} links", link.link_type));
+        let delete_all = json!({
+            "tags": tags,
+            "description": "Delete every link of this route for the entity",
+            "responses": {
+                "200": json_response("Number of deleted links", json!({
+                    "type": "object",
+                    "properties": {
+                        "link_type": { "type": "string" },
+                        "deleted": { "type": "integer", "minimum": 0 }
+                    },
+                    "required": ["link_type", "deleted"]
+                })),
+                "404": error_ref("NotFound"),
+            }
+        });
+
+        paths.insert(
+            format!("/{source}/{{id}}/{}", link.forward_route_name),
+            json!({
+                "parameters": [path_id("id")],
+                "get": {
+                    "tags": tags,
+                    "operationId": format!("list_{}_{}", link.source_type, link.forward_route_name),
+                    "summary": summary,
+                    "parameters": list_parameters(),
+                    "responses": {
+                        "200": json_response("Page of links", paginated(schema_ref("Link"))),
+                        "404": error_ref("NotFound"),
+                    }
+                },
+                "post": {
+                    "tags": tags,
+                    "operationId": format!("create_{}_{}", link.source_type, link.forward_route_name),
+                    "description": format!("Create a {} and link it", link.target_type),
+                    "requestBody": json_body(json!({
+                        "type": "object",
+                        "properties": {
+                            "entity": { "type": "object" },
+                            "metadata": { "type": "object" }
+                        },
+                        "required": ["entity"]
+                    })),
+                    "responses": {
+                        "201": json_response("Created entity and link", json!({ "type": "object" })),
+                        "400": error_ref("BadRequest"),
+                        "404": error_ref("NotFound"),
+                    }
+                },
+                "delete": delete_all,
+            }),
+        );
+        paths.insert(
+            format!("/{source}/{{id}}/{}/{{target_id}}", link.forward_route_name),
+            json!({
+                "parameters": [path_id("id"), path_id("target_id")],
+                "get": {
+                    "tags": tags,
+                    "responses": {
+                        "200": json_response("The link", schema_ref("Link")),
+                        "404": error_ref("NotFound"),
+                    }
+                },
+                "post": {
+                    "tags": tags,
+                    "requestBody": json_body(metadata_body()),
+                    "responses": {
+                        "201": json_response("Created link", schema_ref("Link")),
+                        "400": error_ref("BadRequest"),
+                        "404": error_ref("NotFound"),
+                        "409": error_ref("Conflict"),
+                    }
+                },
+                "put": {
+                    "tags": tags,
+                    "requestBody": json_body(metadata_body()),
+                    "responses": {
+                        "200": json_response("Updated link", schema_ref("Link")),
+                        "404": error_ref("NotFound"),
+                    }
+                },
+                "delete": {
+                    "tags": tags,
+                    "responses": {
+                        "204": { "description": "Deleted" },
+                        "404": error_ref("NotFound"),
+                    }
+                }
+            }),
+        );
+        paths.insert(
+            format!("/{target}/{{id}}/{}", link.reverse_route_name),
+            json!({
+                "parameters": [path_id("id")],
+                "get": {
+                    "tags": tags,
+                    "operationId": format!("list_{}_{}", link.target_type, link.reverse_route_name),
+                    "summary": summary,
+                    "parameters": list_parameters(),
+                    "responses": {
+                        "200": json_response("Page of links", paginated(schema_ref("Link"))),
+                        "404": error_ref("NotFound"),
+                    }
+                },
+                "delete": delete_all,
+            }),
+        );
+    }
+
+    schemas.extend(common_schemas());
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "this-rs REST API",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+            "parameters": {
+                "page": query_parameter("page", "Page number, starting at 1", json!({ "type": "integer", "minimum": 1, "default": 1 })),
+                "limit": query_parameter("limit", "Items per page", json!({ "type": "integer", "minimum": 1, "default": 20 })),
+                "filter": query_parameter("filter", "JSON filter object, e.g. {\"status\": \"active\", \"amount\": {\"$gt\": 100}}", json!({ "type": "string" })),
+                "sort": query_parameter("sort", "`field`, `field:asc` or `field:desc`", json!({ "type": "string" })),
+            },
+            "responses": {
+                "BadRequest": error_response("Malformed request, or a body that failed validation"),
+                "NotFound": error_response("No such entity, link or route"),
+                "Conflict": error_response("Conflicting state, e.g. a stale version"),
+                "UnprocessableEntity": error_response("An Idempotency-Key reused with a different body"),
+            }
+        }
+    })
+}
+
+/// Component name of an entity type's schema (`order_line` -> `OrderLine`)
+fn schema_name(entity_type: &str) -> String {
+    entity_type
+        .split(['_', '-'])
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{name}") })
+}
+
+fn error_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/responses/{name}") })
+}
+
+fn path_id(name: &str) -> Value {
+    json!({
+        "name": name,
+        "in": "path",
+        "required": true,
+        "schema": { "type": "string", "format": "uuid" }
+    })
+}
+
+fn list_parameters() -> Value {
+    json!(
+        ["page", "limit", "filter", "sort"]
+            .map(|name| json!({ "$ref": format!("#/components/parameters/{name}") }))
+    )
+}
+
+fn query_parameter(name: &str, description: &str, schema: Value) -> Value {
+    json!({ "name": name, "in": "query", "description": description, "schema": schema })
+}
+
+fn json_body(schema: Value) -> Value {
+    json!({ "required": true, "content": { "application/json": { "schema": schema } } })
+}
+
+fn json_response(description: &str, schema: Value) -> Value {
+    json!({ "description": description, "content": { "application/json": { "schema": schema } } })
+}
+
+fn error_response(description: &str) -> Value {
+    json_response(description, schema_ref("Error"))
+}
+
+fn paginated(item: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "data": { "type": "array", "items": item },
+            "pagination": schema_ref("PaginationMeta")
+        },
+        "required": ["data", "pagination"]
+    })
+}
+
+fn metadata_body() -> Value {
+    json!({ "type": "object", "properties": { "metadata": { "type": "object" } } })
+}
+
+/// Schemas shared by every document
+fn common_schemas() -> Map<String, Value> {
+    let uuid = json!({ "type": "string", "format": "uuid" });
+    let time = json!({ "type": "string", "format": "date-time" });
+    let schemas = json!({
+        "Error": {
+            "type": "object",
+            "properties": {
+                "code": { "type": "string" },
+                "message": { "type": "string" },
+                "details": {},
+                "error": { "type": "string" }
+            },
+            "required": ["code", "message", "error"]
+        },
+        "PaginationMeta": {
+            "type": "object",
+            "properties": {
+                "page": { "type": "integer" },
+                "limit": { "type": "integer" },
+                "total": { "type": "integer" },
+                "total_pages": { "type": "integer" },
+                "has_next": { "type": "boolean" },
+                "has_prev": { "type": "boolean" }
+            }
+        },
+        "Link": {
+            "type": "object",
+            "properties": {
+                "id": uuid,
+                "type": { "type": "string" },
+                "link_type": { "type": "string" },
+                "source_id": uuid,
+                "target_id": uuid,
+                "status": { "type": "string" },
+                "metadata": { "type": ["object", "null"] },
+                "created_at": time,
+                "updated_at": time,
+                "deleted_at": { "type": ["string", "null"], "format": "date-time" },
+                "source": { "type": "object" },
+                "target": { "type": "object" }
+            }
+        },
+        "CreateLink": {
+            "type": "object",
+            "properties": {
+                "link_type": { "type": "string" },
+                "source_id": uuid,
+                "target_id": uuid,
+                "metadata": { "type": "object" }
+            },
+            "required": ["link_type", "source_id", "target_id"]
+        }
+    });
+    match schemas {
+        Value::Object(map) => map,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::LinkDefinition;
+    use crate::server::entity_registry::EntityDescriptor;
+    use crate::server::exposure::RestExposure;
+    use crate::server::host::ServerHost;
+    use crate::storage::InMemoryLinkService;
+    use axum::body::{Body, to_bytes};
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    struct OrderDescriptor;
+
+    impl EntityDescriptor for OrderDescriptor {
+        fn entity_type(&self) -> &str {
+            "order"
+        }
+
+        fn plural(&self) -> &str {
+            "orders"
+        }
+
+        fn build_routes(&self) -> Router {
+            Router::new()
+        }
+
+        fn body_schema(&self) -> Option<Value> {
+            Some(json!({
+                "type": "object",
+                "properties": { "amount": { "type": "number" } },
+                "required": ["amount"]
+            }))
+        }
+    }
+
+    fn entity(singular: &str, plural: &str) -> EntityConfig {
+        EntityConfig {
+            singular: singular.to_string(),
+            plural: plural.to_string(),
+            auth: EntityAuthConfig::default(),
+            id_policy: Default::default(),
+            fields: Default::default(),
+            min_update_interval: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_openapi_json_describes_entity_and_link_routes() {
+        let config = LinksConfig {
+            entities: vec![entity("order", "orders"), entity("invoice", "invoices")],
+            links: vec![LinkDefinition {
+                link_type: "has_invoice".to_string(),
+                source_type: "order".to_string(),
+                target_type: "invoice".to_string(),
+                forward_route_name: "invoices".to_string(),
+                reverse_route_name: "order".to_string(),
+                description: None,
+                required_fields: None,
+                required_fields_forward: None,
+                required_fields_reverse: None,
+                symmetric: false,
+                auth: None,
+                cardinality: None,
+                metadata_schema: None,
+            }],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        let mut registry = EntityRegistry::new();
+        registry.register(Box::new(OrderDescriptor));
+        let host = ServerHost::from_builder_components(
+            Arc::new(InMemoryLinkService::new()),
+            config,
+            registry,
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        let response = RestExposure::build_router(Arc::new(host), vec![])
+            .unwrap()
+            .oneshot(Request::get(OPENAPI_PATH).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let document: Value =
+            serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap())
+                .expect("the document should be valid JSON");
+
+        assert_eq!(document["openapi"], "3.1.0");
+        let paths = &document["paths"];
+        assert!(paths["/orders"]["post"].is_object());
+        assert!(paths["/orders/{id}"]["put"].is_object());
+        assert!(paths["/orders/{id}/invoices"]["get"].is_object());
+        assert!(paths["/invoices/{id}/order"]["get"].is_object());
+        assert!(paths["/orders/{id}/invoices"]["delete"].is_object());
+        assert_eq!(
+            document["components"]["schemas"]["Order"]["required"],
+            json!(["amount"])
+        );
+    }
+}
@@ -0,0 +1,385 @@
+//! Entity ownership on REST entity routes
+//!
+//! `POST /{entity_type}` bodies get the caller's user id as `owner_id` (or
+//! lose a client-supplied one), and `PUT`/`PATCH /{entity_type}/{id}`
+//! bodies keep the stored owner, so `owner_id` is never client-writable.
+//! See [`Entity::owner_id`](crate::core::entity::Entity::owner_id).
+//!
+//! `GET`, `PUT`/`PATCH` and `DELETE /{entity_type}/{id}` are then checked
+//! against the entity's `get`, `update` and `delete` policies when these
+//! involve `owner`: the caller must be the recorded owner (see
+//! [`ownership::check_owner`]). Its history (`/history`, `/versions/{n}`)
+//! is read under the `get` policy, and `POST /{entity_type}/{id}/restore`,
+//! which undoes a soft delete, is checked against the `delete` policy;
+//! soft-deleted entities keep their owner. Other policies are left to the
+//! entity handlers. Only mounted when the host has an auth provider; GraphQL and
+//! gRPC apply the same rules through
+//! [`ServerHost`](crate::server::host::ServerHost).
+
+use super::redaction::redaction_context;
+use crate::config::LinksConfig;
+use crate::core::auth::{AuthPolicy, AuthProvider};
+use crate::core::extractors::ExtractorError;
+use crate::core::module::EntityFetcher;
+use crate::core::ownership::{self, OwnershipError};
+use crate::server::body::JsonRequest;
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// What the layer knows about one entity type
+struct OwnedEntity {
+    /// Reads the stored owner of an entity
+    fetcher: Option<Arc<dyn EntityFetcher>>,
+    /// `get`, `update` and `delete` policies, where they involve `owner`
+    get: Option<AuthPolicy>,
+    update: Option<AuthPolicy>,
+    delete: Option<AuthPolicy>,
+}
+
+/// Shared state for the ownership middleware
+#[derive(Clone)]
+pub struct OwnershipState {
+    auth_provider: Arc<dyn AuthProvider>,
+    /// Plural route segment -> entity type
+    entities: Arc<HashMap<String, OwnedEntity>>,
+}
+
+impl OwnershipState {
+    pub fn new(
+        auth_provider: Arc<dyn AuthProvider>,
+        fetchers: &HashMap<String, Arc<dyn EntityFetcher>>,
+        config: &LinksConfig,
+    ) -> Self {
+        let entities = config
+            .entities
+            .iter()
+            .map(|e| {
+                let entity = OwnedEntity {
+                    fetcher: fetchers.get(&e.singular).cloned(),
+                    get: ownership::owner_policy(&e.auth.get),
+                    update: ownership::owner_policy(&e.auth.update),
+                    delete: ownership::owner_policy(&e.auth.delete),
+                };
+                (e.plural.clone(), entity)
+            })
+            .collect();
+        Self {
+            auth_provider,
+            entities: Arc::new(entities),
+        }
+    }
+}
+
+/// Rebuild `request` with its `owner_id` set to `owner_id`
+///
+/// Bodies that are not JSON objects are passed on unchanged.
+async fn with_owner(request: Request, owner_id: Option<Uuid>) -> Result<Request, Response> {
+    let mut body = JsonRequest::read(request).await?;
+    if body.json().is_some_and(Value::is_object)
+        && let Some(json) = body.json_mut()
+    {
+        ownership::set_owner(json, owner_id);
+    }
+    Ok(body.into_request())
+}
+
+/// Middleware stamping entity owners and enforcing `owner` policies
+pub async fn ownership_middleware(
+    State(state): State<OwnershipState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path().trim_matches('/').to_string();
+    let segments: Vec<&str> = path.split('/').collect();
+    let Some(entity) = state.entities.get(segments[0]) else {
+        return next.run(request).await;
+    };
+
+    let (parts, body) = request.into_parts();
+    let context = redaction_context(Some(&state.auth_provider), &parts).await;
+    let request = Request::from_parts(parts, body);
+
+    let method = request.method().clone();
+    let (policy, rewrites_body) = match (&method, segments.as_slice()) {
+        (&Method::POST, [_]) => {
+            return match with_owner(request, context.user_id()).await {
+                Ok(request) => next.run(request).await,
+                Err(response) => response,
+            };
+        }
+        (&Method::GET, [_, _]) => (&entity.get, false),
+        (&Method::PUT | &Method::PATCH, [_, _]) => (&entity.update, true),
+        (&Method::DELETE, [_, _]) => (&entity.delete, false),
+        (&Method::GET, [_, _, "history"] | [_, _, "versions", _]) => (&entity.get, false),
+        (&Method::POST, [_, _, "restore"]) => (&entity.delete, false),
+        _ => return next.run(request).await,
+    };
+    if policy.is_none() && !rewrites_body {
+        return next.run(request).await;
+    }
+
+    // Malformed ids and missing entities are answered by the handler
+    let stored = match (Uuid::parse_str(segments[1]), &entity.fetcher) {
+        (Ok(id), Some(fetcher)) => fetcher.fetch_with_deleted_as_json(&id).await.ok(),
+        _ => None,
+    };
+    let Some(stored) = stored else {
+        return next.run(request).await;
+    };
+    let owner_id = ownership::stored_owner(&stored);
+
+    if let Some(policy) = policy {
+        let route = format!("{} /{}", method, path);
+        match ownership::check_owner(policy, &context, owner_id, &route) {
+            Ok(()) => {}
+            Err(OwnershipError::Unauthenticated) => {
+                return ExtractorError::Unauthorized.into_response();
+            }
+            Err(e @ OwnershipError::Forbidden(_)) => {
+                return ExtractorError::Forbidden(e.to_string()).into_response();
+            }
+        }
+    }
+
+    if !rewrites_body {
+        return next.run(request).await;
+    }
+    match with_owner(request, owner_id).await {
+        Ok(request) => next.run(request).await,
+        Err(response) => response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::core::auth::AuthContext;
+    use crate::storage::InMemoryDataService;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Path;
+    use axum::http::StatusCode;
+    use axum::http::request::Parts;
+    use axum::routing::{get, post, put};
+    use axum::{Json, Router, middleware};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    crate::impl_data_entity!(Note, "note", ["name"], {
+        text: String,
+    });
+
+    /// Reads the user from `x-user-id`
+    struct HeaderAuthProvider;
+
+    #[async_trait::async_trait]
+    impl AuthProvider for HeaderAuthProvider {
+        async fn extract_context(&self, parts: &Parts) -> anyhow::Result<AuthContext> {
+            let Some(user_id) = parts.headers.get("x-user-id") else {
+                return Ok(AuthContext::Anonymous);
+            };
+            Ok(AuthContext::User {
+                user_id: user_id.to_str()?.parse()?,
+                tenant_id: Uuid::nil(),
+                roles: vec![],
+            })
+        }
+
+        async fn is_owner(&self, _: &Uuid, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+
+        async fn has_role(&self, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+    }
+
+    fn app() -> Router {
+        let service = Arc::new(InMemoryDataService::<Note>::new());
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::from([(
+            "note".to_string(),
+            service.clone() as Arc<dyn EntityFetcher>,
+        )]);
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "note".to_string(),
+                plural: "notes".to_string(),
+                auth: EntityAuthConfig {
+                    get: "owner".to_string(),
+                    update: "owner".to_string(),
+                    delete: "owner".to_string(),
+                    ..EntityAuthConfig::default()
+                },
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+
+        let create = {
+            let service = service.clone();
+            move |Json(body): Json<Value>| async move {
+                let mut note = Note::new(
+                    "note".to_string(),
+                    "active".to_string(),
+                    body["text"].as_str().unwrap_or_default().to_string(),
+                );
+                if let Some(owner_id) = body["owner_id"].as_str() {
+                    note = note.with_owner(owner_id.parse().unwrap());
+                }
+                Json(service.create(note).await.unwrap())
+            }
+        };
+        let update = {
+            let service = service.clone();
+            move |Path(id): Path<Uuid>, Json(body): Json<Value>| async move {
+                let mut note = service.get(&id).await.unwrap().unwrap();
+                note.text = body["text"].as_str().unwrap_or_default().to_string();
+                Json(service.update(&id, note).await.unwrap())
+            }
+        };
+        let delete = {
+            let service = service.clone();
+            move |Path(id): Path<Uuid>| async move {
+                service.soft_delete(&id).await.unwrap();
+                StatusCode::NO_CONTENT
+            }
+        };
+        let restore =
+            move |Path(id): Path<Uuid>| async move { Json(service.restore(&id).await.unwrap()) };
+        Router::new()
+            .route("/notes", post(create))
+            .route("/notes/{id}", put(update).delete(delete))
+            .route("/notes/{id}/restore", post(restore))
+            .route("/notes/{id}/history", get(|| async { Json(json!([])) }))
+            .layer(middleware::from_fn_with_state(
+                OwnershipState::new(Arc::new(HeaderAuthProvider), &fetchers, &config),
+                ownership_middleware,
+            ))
+    }
+
+    async fn send(
+        app: &Router,
+        method: Method,
+        uri: &str,
+        user: Option<Uuid>,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json");
+        if let Some(user) = user {
+            request = request.header("x-user-id", user.to_string());
+        }
+        let request = request.body(Body::from(body.to_string())).unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn test_create_records_the_caller_as_owner() {
+        let app = app();
+        let (alice, mallory) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let (status, note) = send(
+            &app,
+            Method::POST,
+            "/notes",
+            Some(alice),
+            json!({"text": "hi", "owner_id": mallory}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(note["owner_id"], json!(alice));
+
+        // Anonymous callers cannot claim an owner either
+        let (_, note) = send(
+            &app,
+            Method::POST,
+            "/notes",
+            None,
+            json!({"text": "hi", "owner_id": mallory}),
+        )
+        .await;
+        assert!(note.get("owner_id").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_only_the_owner_may_update() {
+        let app = app();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let (_, note) = send(
+            &app,
+            Method::POST,
+            "/notes",
+            Some(alice),
+            json!({"text": "a"}),
+        )
+        .await;
+        let uri = format!("/notes/{}", note["id"].as_str().unwrap());
+
+        let (status, _) = send(&app, Method::PUT, &uri, Some(bob), json!({"text": "b"})).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = send(&app, Method::PUT, &uri, None, json!({"text": "b"})).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, updated) = send(
+            &app,
+            Method::PUT,
+            &uri,
+            Some(alice),
+            json!({"text": "c", "owner_id": bob}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["text"], "c");
+        assert_eq!(updated["owner_id"], json!(alice));
+    }
+
+    #[tokio::test]
+    async fn test_only_the_owner_may_restore_or_read_the_history() {
+        let app = app();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let (_, note) = send(
+            &app,
+            Method::POST,
+            "/notes",
+            Some(alice),
+            json!({"text": "a"}),
+        )
+        .await;
+        let uri = format!("/notes/{}", note["id"].as_str().unwrap());
+        let (status, _) = send(&app, Method::DELETE, &uri, Some(alice), Value::Null).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let restore = format!("{}/restore", uri);
+        let (status, _) = send(&app, Method::POST, &restore, Some(bob), Value::Null).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, restored) = send(&app, Method::POST, &restore, Some(alice), Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(restored["deleted_at"].is_null());
+
+        let history = format!("{}/history", uri);
+        let (status, _) = send(&app, Method::GET, &history, Some(bob), Value::Null).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = send(&app, Method::GET, &history, None, Value::Null).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, _) = send(&app, Method::GET, &history, Some(alice), Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
@@ -0,0 +1,61 @@
+//! Page size bounds for REST list endpoints
+//!
+//! Handlers read their page size with [`QueryParams::limit`], which has no
+//! access to the host. This layer runs each request inside a
+//! [`PaginationConfig::scope`] holding the host's bounds, so every list
+//! endpoint (built-in or custom) falls back to the configured default and
+//! is capped at the configured maximum.
+//!
+//! [`QueryParams::limit`]: crate::core::query::QueryParams::limit
+
+use crate::core::query::PaginationConfig;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+
+/// Middleware running the request under the host's [`PaginationConfig`]
+pub async fn pagination_middleware(
+    State(config): State<PaginationConfig>,
+    request: Request,
+    next: Next,
+) -> Response {
+    config.scope(next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::query::{PaginationMeta, QueryParams};
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Query;
+    use axum::routing::get;
+    use axum::{Json, Router, middleware};
+    use tower::ServiceExt;
+
+    async fn limit_of(uri: &str) -> usize {
+        let app = Router::new()
+            .route(
+                "/orders",
+                get(|Query(params): Query<QueryParams>| async move {
+                    Json(PaginationMeta::new(params.page(), params.limit(), 0))
+                }),
+            )
+            .layer(middleware::from_fn_with_state(
+                PaginationConfig::new(15, 200),
+                pagination_middleware,
+            ));
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let meta: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        meta["limit"].as_u64().unwrap() as usize
+    }
+
+    #[tokio::test]
+    async fn test_limits_default_and_clamp_to_the_configured_bounds() {
+        assert_eq!(limit_of("/orders").await, 15);
+        assert_eq!(limit_of("/orders?limit=0").await, 15);
+        assert_eq!(limit_of("/orders?limit=10000").await, 200);
+        assert_eq!(limit_of("/orders?limit=150").await, 150);
+    }
+}
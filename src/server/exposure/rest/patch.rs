@@ -0,0 +1,352 @@
+//! `PATCH /{entity_type}/{id}` — partial entity updates
+//!
+//! Entity routes come from each module's [`EntityDescriptor`], so PATCH is
+//! served by a layer on those routes rather than a route of its own: it
+//! hands the body to the entity's [`EntityCreator::patch_from_json`] and
+//! answers directly. Requests for entity types whose creator does not
+//! support patching continue to the descriptor's routes untouched.
+//!
+//! [`EntityDescriptor`]: crate::server::entity_registry::EntityDescriptor
+
+use crate::config::LinksConfig;
+use crate::core::events::{EntityEvent, EventBus, FrameworkEvent};
+use crate::core::extractors::{ExtractorError, error_response};
+use crate::core::module::EntityCreator;
+use crate::core::patch::PatchError;
+use crate::core::validation::ValidationError;
+use crate::server::body::JsonRequest;
+use crate::storage::StorageError;
+use axum::Json;
+use axum::extract::{Request, State};
+use axum::http::{Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Shared state for the patch middleware
+#[derive(Clone)]
+pub struct PatchState {
+    creators: Arc<HashMap<String, Arc<dyn EntityCreator>>>,
+    /// Plural route segment -> singular entity type
+    entity_types: Arc<HashMap<String, String>>,
+    event_bus: Option<Arc<EventBus>>,
+}
+
+impl PatchState {
+    pub fn new(
+        creators: Arc<HashMap<String, Arc<dyn EntityCreator>>>,
+        config: &LinksConfig,
+        event_bus: Option<Arc<EventBus>>,
+    ) -> Self {
+        let entity_types = config
+            .entities
+            .iter()
+            .map(|e| (e.plural.clone(), e.singular.clone()))
+            .collect();
+        Self {
+            creators,
+            entity_types: Arc::new(entity_types),
+            event_bus,
+        }
+    }
+
+    /// Resolve `PATCH /{plural}/{id}` to its entity type and raw ID segment
+    fn target<'a>(&self, method: &Method, path: &'a str) -> Option<(&str, &'a str)> {
+        if method != Method::PATCH {
+            return None;
+        }
+        let (plural, id) = path.trim_matches('/').split_once('/')?;
+        if id.contains('/') {
+            return None;
+        }
+        let entity_type = self.entity_types.get(plural)?;
+        self.creators
+            .contains_key(entity_type)
+            .then_some((entity_type.as_str(), id))
+    }
+}
+
+/// Middleware serving `PATCH /{plural}/{id}` through the entity's creator
+pub async fn patch_middleware(
+    State(state): State<PatchState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((entity_type, raw_id)) = state.target(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+    let entity_type = entity_type.to_string();
+    let Ok(entity_id) = Uuid::parse_str(raw_id) else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "INVALID_ENTITY_ID",
+            format!("Invalid UUID: {}", raw_id),
+        );
+    };
+
+    let body = match JsonRequest::read(request).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    let partial = match body.json() {
+        Some(partial) => partial.clone(),
+        None => {
+            let message = serde_json::from_slice::<Value>(body.bytes())
+                .err()
+                .map_or_else(|| "invalid JSON".to_string(), |e| e.to_string());
+            return ExtractorError::JsonError(message).into_response();
+        }
+    };
+
+    let creator = &state.creators[&entity_type];
+    match creator.patch_from_json(&entity_id, partial).await {
+        Ok(data) => {
+            if let Some(bus) = &state.event_bus {
+                bus.publish(FrameworkEvent::Entity(EntityEvent::Updated {
+                    entity_type,
+                    entity_id,
+                    data: data.clone(),
+                }));
+            }
+            Json(data).into_response()
+        }
+        Err(e) => match e.downcast_ref::<PatchError>() {
+            Some(PatchError::Unsupported) => next.run(body.into_request()).await,
+            Some(PatchError::NotFound(_)) => {
+                error_response(StatusCode::NOT_FOUND, "ENTITY_NOT_FOUND", e.to_string())
+            }
+            Some(PatchError::NotAnObject | PatchError::Invalid(_)) => {
+                error_response(StatusCode::BAD_REQUEST, "INVALID_PATCH", e.to_string())
+            }
+            None => match e.downcast::<ValidationError>() {
+                Ok(validation) => validation.into_response(),
+                Err(e) => match e.downcast::<StorageError>() {
+                    Ok(storage) => storage.into_response(),
+                    Err(e) => error_response(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "INTERNAL_ERROR",
+                        e.to_string(),
+                    ),
+                },
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::storage::InMemoryDataService;
+    use async_trait::async_trait;
+    use axum::body::{Body, to_bytes};
+    use axum::routing::get;
+    use axum::{Router, middleware};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    crate::impl_data_entity!(Contact, "contact", ["name"], {
+        email: Option<String>,
+        phone: Option<String>,
+    });
+
+    /// Creator backed by an in-memory store, patching through `DataService::patch`
+    struct ContactCreator(Arc<InMemoryDataService<Contact>>);
+
+    #[async_trait]
+    impl EntityCreator for ContactCreator {
+        async fn create_from_json(&self, _entity_data: Value) -> anyhow::Result<Value> {
+            unimplemented!()
+        }
+
+        async fn patch_from_json(&self, entity_id: &Uuid, partial: Value) -> anyhow::Result<Value> {
+            let patched = self.0.patch(entity_id, partial).await?;
+            Ok(serde_json::to_value(patched)?)
+        }
+    }
+
+    struct NoPatchCreator;
+
+    #[async_trait]
+    impl EntityCreator for NoPatchCreator {
+        async fn create_from_json(&self, _entity_data: Value) -> anyhow::Result<Value> {
+            unimplemented!()
+        }
+    }
+
+    fn entity(singular: &str, plural: &str) -> EntityConfig {
+        EntityConfig {
+            singular: singular.to_string(),
+            plural: plural.to_string(),
+            auth: EntityAuthConfig::default(),
+            id_policy: Default::default(),
+            fields: Default::default(),
+            min_update_interval: None,
+        }
+    }
+
+    fn app(store: Arc<InMemoryDataService<Contact>>) -> Router {
+        let config = LinksConfig {
+            entities: vec![entity("contact", "contacts"), entity("note", "notes")],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        let mut creators: HashMap<String, Arc<dyn EntityCreator>> = HashMap::new();
+        creators.insert("contact".to_string(), Arc::new(ContactCreator(store)));
+        creators.insert("note".to_string(), Arc::new(NoPatchCreator));
+        let state = PatchState::new(Arc::new(creators), &config, None);
+
+        Router::new()
+            .route("/contacts/{id}", get(|| async { "contact" }))
+            .route("/notes/{id}", get(|| async { "note" }))
+            .layer(middleware::from_fn_with_state(state, patch_middleware))
+    }
+
+    async fn send_patch(router: Router, uri: &str, body: Value) -> (StatusCode, Value) {
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("PATCH")
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024 * 64).await.unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    async fn stored_contact(store: &InMemoryDataService<Contact>) -> Contact {
+        let contact = Contact::new(
+            "Ada".to_string(),
+            "active".to_string(),
+            Some("ada@example.com".to_string()),
+            Some("555-0100".to_string()),
+        );
+        store.create(contact).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_patch_leaves_absent_fields_unchanged() {
+        let store = Arc::new(InMemoryDataService::new());
+        let contact = stored_contact(&store).await;
+
+        let (status, body) = send_patch(
+            app(store.clone()),
+            &format!("/contacts/{}", contact.id),
+            json!({"phone": "555-0199"}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["phone"], "555-0199");
+        assert_eq!(body["email"], "ada@example.com");
+        assert_eq!(body["name"], "Ada");
+
+        let stored = store.get(&contact.id).await.unwrap().unwrap();
+        assert_eq!(stored.phone.as_deref(), Some("555-0199"));
+        assert_eq!(stored.email.as_deref(), Some("ada@example.com"));
+        assert!(stored.updated_at > contact.updated_at);
+    }
+
+    #[tokio::test]
+    async fn test_patch_null_clears_field() {
+        let store = Arc::new(InMemoryDataService::new());
+        let contact = stored_contact(&store).await;
+
+        let (status, body) = send_patch(
+            app(store.clone()),
+            &format!("/contacts/{}", contact.id),
+            json!({"email": null}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["email"].is_null());
+        let stored = store.get(&contact.id).await.unwrap().unwrap();
+        assert_eq!(stored.email, None);
+        assert_eq!(stored.phone.as_deref(), Some("555-0100"));
+    }
+
+    #[tokio::test]
+    async fn test_patch_ignores_server_managed_fields() {
+        let store = Arc::new(InMemoryDataService::new());
+        let owner = Uuid::new_v4();
+        let mut contact = Contact::new("Ada".to_string(), "active".to_string(), None, None);
+        contact.owner_id = Some(owner);
+        contact.created_by = Some("user:alice".to_string());
+        contact.updated_by = Some("user:alice".to_string());
+        let contact = store.create(contact).await.unwrap();
+
+        let (status, body) = send_patch(
+            app(store.clone()),
+            &format!("/contacts/{}", contact.id),
+            json!({
+                "phone": "555-0199",
+                "owner_id": Uuid::new_v4(),
+                "created_by": "user:mallory",
+                "updated_by": "user:mallory",
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["phone"], "555-0199");
+        let stored = store.get(&contact.id).await.unwrap().unwrap();
+        assert_eq!(stored.owner_id, Some(owner));
+        assert_eq!(stored.created_by.as_deref(), Some("user:alice"));
+        assert_eq!(stored.updated_by.as_deref(), Some("user:alice"));
+    }
+
+    #[tokio::test]
+    async fn test_patch_errors() {
+        let store = Arc::new(InMemoryDataService::new());
+        let contact = stored_contact(&store).await;
+        let uri = format!("/contacts/{}", contact.id);
+
+        let (status, body) = send_patch(
+            app(store.clone()),
+            &format!("/contacts/{}", Uuid::new_v4()),
+            json!({"phone": "1"}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "ENTITY_NOT_FOUND");
+        assert_eq!(body["error"], body["message"]);
+
+        // `name` is required, so clearing it is rejected and nothing is written
+        let (status, _) = send_patch(app(store.clone()), &uri, json!({"name": null})).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(store.get(&contact.id).await.unwrap().unwrap().name, "Ada");
+
+        let (status, body) =
+            send_patch(app(store.clone()), "/contacts/not-a-uuid", json!({})).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_ENTITY_ID");
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_creator_falls_through() {
+        let store = Arc::new(InMemoryDataService::new());
+        let (status, _) = send_patch(
+            app(store),
+            &format!("/notes/{}", Uuid::new_v4()),
+            json!({"body": "x"}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+    }
+}
@@ -0,0 +1,174 @@
+//! Redaction of entity fields in REST entity responses
+//!
+//! Successful JSON responses of `/{entity_type}` routes lose the fields the
+//! entity's [`EntityFetcher::redacted_fields`] withholds from the caller,
+//! whose context comes from the host's auth provider (`Anonymous` without
+//! one). See [`redaction`](crate::core::redaction).
+
+use crate::config::LinksConfig;
+use crate::core::auth::{AuthContext, AuthProvider};
+use crate::core::module::EntityFetcher;
+use crate::core::redaction::redact_body;
+use crate::server::body::{BodyLimit, JsonResponse};
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Shared state for the redaction middleware
+#[derive(Clone)]
+pub struct RedactionState {
+    /// Plural route segment -> fetcher declaring the redacted fields
+    fetchers: Arc<HashMap<String, Arc<dyn EntityFetcher>>>,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
+}
+
+impl RedactionState {
+    pub fn new(
+        fetchers: &HashMap<String, Arc<dyn EntityFetcher>>,
+        config: &LinksConfig,
+        auth_provider: Option<Arc<dyn AuthProvider>>,
+    ) -> Self {
+        let fetchers = config
+            .entities
+            .iter()
+            .filter_map(|e| Some((e.plural.clone(), fetchers.get(&e.singular)?.clone())))
+            .collect();
+        Self {
+            fetchers: Arc::new(fetchers),
+            auth_provider,
+        }
+    }
+
+    /// Whether no entity type can redact anything
+    pub fn is_empty(&self) -> bool {
+        self.fetchers.is_empty()
+    }
+}
+
+/// The caller's auth context; unreadable credentials count as anonymous
+pub(crate) async fn redaction_context(
+    provider: Option<&Arc<dyn AuthProvider>>,
+    parts: &axum::http::request::Parts,
+) -> AuthContext {
+    match provider {
+        Some(provider) => provider
+            .extract_context(parts)
+            .await
+            .unwrap_or(AuthContext::Anonymous),
+        None => AuthContext::Anonymous,
+    }
+}
+
+/// Middleware removing redacted fields from entity responses
+pub async fn redaction_middleware(
+    State(state): State<RedactionState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let plural = request.uri().path().trim_matches('/').split('/').next();
+    let Some(fetcher) = plural
+        .and_then(|plural| state.fetchers.get(plural))
+        .cloned()
+    else {
+        return next.run(request).await;
+    };
+    let (parts, body) = request.into_parts();
+    let context = redaction_context(state.auth_provider.as_ref(), &parts).await;
+    let fields = fetcher.redacted_fields(&context);
+    let limit = BodyLimit::of(&parts.extensions);
+
+    let response = next.run(Request::from_parts(parts, body)).await;
+    if fields.is_empty() || !response.status().is_success() {
+        return response;
+    }
+    let mut body = match JsonResponse::read(response, limit).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    if let Some(json) = body.json_mut() {
+        redact_body(json, fields);
+    }
+    body.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::storage::InMemoryDataService;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Path;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{Json, Router, middleware};
+    use serde_json::Value;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    crate::impl_data_entity!(User, "user", ["name"], {
+        email: String,
+        password_hash: String,
+    }, redacted: ["password_hash"]);
+
+    fn config() -> LinksConfig {
+        LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "user".to_string(),
+                plural: "users".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_password_hash_is_absent_from_user_get() {
+        let service = Arc::new(InMemoryDataService::<User>::new());
+        let user = service
+            .create(User::new(
+                "alice".to_string(),
+                "active".to_string(),
+                "alice@example.com".to_string(),
+                "$argon2id$v=19$...".to_string(),
+            ))
+            .await
+            .unwrap();
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> = HashMap::from([(
+            "user".to_string(),
+            service.clone() as Arc<dyn EntityFetcher>,
+        )]);
+
+        let get_user = {
+            let service = service.clone();
+            move |Path(id): Path<Uuid>| async move { Json(service.get(&id).await.unwrap().unwrap()) }
+        };
+        let app = Router::new().route("/users/{id}", get(get_user)).layer(
+            middleware::from_fn_with_state(
+                RedactionState::new(&fetchers, &config(), None),
+                redaction_middleware,
+            ),
+        );
+
+        let request = Request::builder()
+            .uri(format!("/users/{}", user.id))
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["email"], "alice@example.com");
+        assert!(body.get("password_hash").is_none());
+    }
+}
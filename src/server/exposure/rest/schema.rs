@@ -0,0 +1,194 @@
+//! JSON Schema validation of REST entity payloads
+//!
+//! Checks `POST /{entity_type}` bodies against the full schema and
+//! `PUT`/`PATCH /{entity_type}/{id}` bodies with top-level `required`
+//! relaxed (see [`EntitySchemas`]). Failing requests never reach the entity
+//! handlers and are answered with `400 Bad Request`.
+//!
+//! Only mounted on the entity CRUD routes, and only when schemas are
+//! configured on the server.
+
+use crate::config::LinksConfig;
+use crate::core::validation::EntitySchemas;
+use crate::server::body::JsonRequest;
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Shared state for the schema validation middleware
+#[derive(Clone)]
+pub struct SchemaState {
+    schemas: Arc<EntitySchemas>,
+    /// Plural route segment -> singular entity type
+    entity_types: Arc<HashMap<String, String>>,
+}
+
+impl SchemaState {
+    pub fn new(schemas: Arc<EntitySchemas>, config: &LinksConfig) -> Self {
+        let entity_types = config
+            .entities
+            .iter()
+            .map(|e| (e.plural.clone(), e.singular.clone()))
+            .collect();
+        Self {
+            schemas,
+            entity_types: Arc::new(entity_types),
+        }
+    }
+
+    /// Resolve the entity type and whether the body is a partial update
+    fn target(&self, method: &Method, path: &str) -> Option<(&str, bool)> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let partial = match (method, segments.len()) {
+            (&Method::POST, 1) => false,
+            (&Method::PUT | &Method::PATCH, 2) => true,
+            _ => return None,
+        };
+        let entity_type = self.entity_types.get(segments[0])?;
+        self.schemas
+            .contains(entity_type)
+            .then_some((entity_type.as_str(), partial))
+    }
+}
+
+/// Middleware rejecting entity payloads that violate their JSON Schema
+pub async fn schema_middleware(
+    State(state): State<SchemaState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((entity_type, partial)) = state.target(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let body = match JsonRequest::read(request).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+
+    // Malformed JSON is left for the handler to report as usual
+    if let Some(payload) = body.json() {
+        let result = if partial {
+            state.schemas.validate_partial(entity_type, payload)
+        } else {
+            state.schemas.validate(entity_type, payload)
+        };
+        if let Err(e) = result {
+            return e.into_response();
+        }
+    }
+
+    next.run(body.into_request()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use axum::body::{Body, to_bytes};
+    use axum::http::StatusCode;
+    use axum::routing::{post, put};
+    use axum::{Json, Router, middleware};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "order".to_string(),
+                plural: "orders".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        let mut schemas = EntitySchemas::new();
+        schemas
+            .insert(
+                "order",
+                &json!({
+                    "type": "object",
+                    "required": ["number"],
+                    "properties": {
+                        "number": { "type": "string", "pattern": "^ORD-[0-9]+$" },
+                        "total": { "type": "number", "minimum": 0 }
+                    }
+                }),
+            )
+            .unwrap();
+        let state = SchemaState::new(Arc::new(schemas), &config);
+
+        Router::new()
+            .route(
+                "/orders",
+                post(|Json(body): Json<Value>| async move { Json(body) }),
+            )
+            .route(
+                "/orders/{id}",
+                put(|Json(body): Json<Value>| async move { Json(body) }),
+            )
+            .layer(middleware::from_fn_with_state(state, schema_middleware))
+    }
+
+    async fn send(method: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), 1024 * 64).await.unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_valid_create_reaches_handler() {
+        let (status, body) = send("POST", "/orders", json!({"number": "ORD-7"})).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["number"], "ORD-7");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_create_is_unprocessable() {
+        let (status, body) = send("POST", "/orders", json!({"total": -5})).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "Validation failed");
+        let mut fields: Vec<&str> = body["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["field"].as_str().unwrap())
+            .collect();
+        fields.sort();
+        assert_eq!(fields, vec!["/number", "/total"]);
+    }
+
+    #[tokio::test]
+    async fn test_update_relaxes_required_but_checks_fields() {
+        let uri = "/orders/7f1d2e1c-9c55-4c2d-9b4e-1f0c4bc0b0a1";
+        let (status, _) = send("PUT", uri, json!({"total": 3})).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send("PUT", uri, json!({"number": "nope"})).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["errors"][0]["field"], "/number");
+    }
+}
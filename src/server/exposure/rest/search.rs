@@ -0,0 +1,173 @@
+//! `GET /{entity_type}/search?field=...&value=...` — exact-match entity search
+//!
+//! Mounted for every entity type whose [`EntityFetcher`] declares searchable
+//! fields (its `Data::indexed_fields()`), and answered through
+//! [`EntityFetcher::search_as_json`] with the usual `page`/`limit`
+//! pagination. Any other field is refused with `400 Bad Request`, so the
+//! route cannot be used to probe arbitrary columns.
+
+use crate::config::LinksConfig;
+use crate::core::extractors::{ExtractorError, error_response};
+use crate::core::module::EntityFetcher;
+use crate::core::query::{PaginatedResponse, PaginationMeta, QueryParams};
+use crate::core::tenant::TenantContext;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Shared state for one entity type's search route
+#[derive(Clone)]
+struct SearchState {
+    fetcher: Arc<dyn EntityFetcher>,
+}
+
+/// The `field` and `value` query parameters
+#[derive(Debug, Deserialize)]
+struct SearchTerm {
+    field: String,
+    value: String,
+}
+
+/// Search routes for the entity types of `config` that support search
+pub fn search_routes(
+    fetchers: &HashMap<String, Arc<dyn EntityFetcher>>,
+    config: &LinksConfig,
+) -> Router {
+    config
+        .entities
+        .iter()
+        .filter_map(|entity| {
+            let fetcher = fetchers.get(&entity.singular)?;
+            if fetcher.searchable_fields().is_empty() {
+                return None;
+            }
+            let state = SearchState {
+                fetcher: fetcher.clone(),
+            };
+            Some(
+                Router::new()
+                    .route(&format!("/{}/search", entity.plural), get(search))
+                    .with_state(state),
+            )
+        })
+        .fold(Router::new(), Router::merge)
+}
+
+async fn search(
+    State(state): State<SearchState>,
+    tenant: Option<TenantContext>,
+    Query(term): Query<SearchTerm>,
+    Query(params): Query<QueryParams>,
+) -> Response {
+    let searchable = state.fetcher.searchable_fields();
+    if !searchable.contains(&term.field.as_str()) {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "FIELD_NOT_SEARCHABLE",
+            format!(
+                "Field '{}' is not searchable (searchable fields: {})",
+                term.field,
+                searchable.join(", ")
+            ),
+        );
+    }
+
+    match state.fetcher.search_as_json(&term.field, &term.value).await {
+        Ok(mut entities) => {
+            // Entities of other tenants are left out, as in tenant-scoped lists
+            if let Some(tenant) = tenant {
+                entities.retain(|entity| tenant.owns_json(entity));
+            }
+            let (page, limit) = (params.page(), params.limit());
+            let pagination = PaginationMeta::new(page, limit, entities.len());
+            let data = entities
+                .into_iter()
+                .skip((page - 1) * limit)
+                .take(limit)
+                .collect();
+            Json(PaginatedResponse { data, pagination }).into_response()
+        }
+        Err(e) => ExtractorError::Internal(e.to_string()).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::storage::InMemoryDataService;
+    use axum::body::{Body, to_bytes};
+    use axum::http::Request;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    crate::impl_data_entity!(Customer, "customer", ["name"], {
+        email: String,
+    });
+
+    async fn app() -> Router {
+        let service = InMemoryDataService::<Customer>::new();
+        for (name, email) in [("Alice", "alice@example.com"), ("Bob", "bob@example.com")] {
+            service
+                .create(Customer::new(
+                    name.to_string(),
+                    "active".to_string(),
+                    email.to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "customer".to_string(),
+                plural: "customers".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> =
+            HashMap::from([("customer".to_string(), Arc::new(service) as _)]);
+        search_routes(&fetchers, &config)
+    }
+
+    async fn get(uri: &str) -> (StatusCode, Value) {
+        let response = app()
+            .await
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_search_on_indexed_field_returns_matches() {
+        let (status, body) = get("/customers/search?field=name&value=Alice").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"][0]["email"], "alice@example.com");
+        assert_eq!(body["pagination"]["total"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_on_other_field_is_rejected() {
+        let (status, body) = get("/customers/search?field=email&value=alice@example.com").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "FIELD_NOT_SEARCHABLE");
+        assert!(body["error"].as_str().unwrap().contains("email"));
+    }
+}
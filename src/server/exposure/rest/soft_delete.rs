@@ -0,0 +1,402 @@
+//! `DELETE /{entity_type}/{id}?soft=true` and `POST /{entity_type}/{id}/restore`
+//!
+//! Both go through the entity's [`EntityCreator::soft_delete`] and
+//! [`EntityCreator::restore`]. The soft delete is served by a layer on the
+//! descriptor routes, like PATCH, and answers `204 No Content`; deletes
+//! without `soft=true` reach the descriptor's handler. The restore route is
+//! mounted for every entity type with a creator and answers `200` with the
+//! entity, also when it was not deleted. Entity types whose creator does not
+//! support soft deletion get `501 Not Implemented` from both, so a soft
+//! delete never falls through to a hard one. With a [`SoftDeleteStatus`],
+//! both go through [`EntityCreator::soft_delete_with_status`] and
+//! [`EntityCreator::restore_with_status`] instead.
+//!
+//! [`EntityCreator::soft_delete`]: crate::core::module::EntityCreator::soft_delete
+//! [`EntityCreator::restore`]: crate::core::module::EntityCreator::restore
+//! [`EntityCreator::soft_delete_with_status`]: crate::core::module::EntityCreator::soft_delete_with_status
+//! [`EntityCreator::restore_with_status`]: crate::core::module::EntityCreator::restore_with_status
+
+use crate::config::LinksConfig;
+use crate::core::events::{EntityEvent, EventBus, FrameworkEvent};
+use crate::core::extractors::error_response;
+use crate::core::module::EntityCreator;
+use crate::core::soft_delete::{SoftDeleteError, SoftDeleteStatus};
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+struct SoftParam {
+    #[serde(default)]
+    soft: bool,
+}
+
+/// Shared state for the soft delete middleware
+#[derive(Clone)]
+pub struct SoftDeleteState {
+    creators: Arc<HashMap<String, Arc<dyn EntityCreator>>>,
+    /// Plural route segment -> singular entity type
+    entity_types: Arc<HashMap<String, String>>,
+    status: Option<SoftDeleteStatus>,
+}
+
+impl SoftDeleteState {
+    pub fn new(
+        creators: Arc<HashMap<String, Arc<dyn EntityCreator>>>,
+        config: &LinksConfig,
+        status: Option<SoftDeleteStatus>,
+    ) -> Self {
+        let entity_types = config
+            .entities
+            .iter()
+            .map(|e| (e.plural.clone(), e.singular.clone()))
+            .collect();
+        Self {
+            creators,
+            entity_types: Arc::new(entity_types),
+            status,
+        }
+    }
+
+    /// Resolve `DELETE /{plural}/{id}?soft=true` to its creator and raw ID segment
+    fn target<'a>(&self, request: &'a Request) -> Option<(&Arc<dyn EntityCreator>, &'a str)> {
+        if request.method() != Method::DELETE
+            || !Query::<SoftParam>::try_from_uri(request.uri()).is_ok_and(|Query(p)| p.soft)
+        {
+            return None;
+        }
+        let (plural, id) = request.uri().path().trim_matches('/').split_once('/')?;
+        if id.contains('/') {
+            return None;
+        }
+        let creator = self.creators.get(self.entity_types.get(plural)?)?;
+        Some((creator, id))
+    }
+}
+
+fn invalid_id(raw_id: &str) -> Response {
+    error_response(
+        StatusCode::BAD_REQUEST,
+        "INVALID_ENTITY_ID",
+        format!("Invalid UUID: {}", raw_id),
+    )
+}
+
+fn soft_delete_error(e: anyhow::Error) -> Response {
+    let (status, code) = match e.downcast_ref::<SoftDeleteError>() {
+        Some(SoftDeleteError::Unsupported) => (StatusCode::NOT_IMPLEMENTED, "NOT_IMPLEMENTED"),
+        Some(SoftDeleteError::NotFound(_)) => (StatusCode::NOT_FOUND, "ENTITY_NOT_FOUND"),
+        None => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR"),
+    };
+    error_response(status, code, e.to_string())
+}
+
+/// Middleware serving `DELETE /{plural}/{id}?soft=true` through the entity's creator
+pub async fn soft_delete_middleware(
+    State(state): State<SoftDeleteState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((creator, raw_id)) = state.target(&request) else {
+        return next.run(request).await;
+    };
+    let Ok(entity_id) = Uuid::parse_str(raw_id) else {
+        return invalid_id(raw_id);
+    };
+    let deleted = match &state.status {
+        Some(status) => creator.soft_delete_with_status(&entity_id, status).await,
+        None => creator.soft_delete(&entity_id).await,
+    };
+    match deleted {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => soft_delete_error(e),
+    }
+}
+
+/// Shared state for one entity type's restore route
+#[derive(Clone)]
+struct RestoreState {
+    entity_type: String,
+    creator: Arc<dyn EntityCreator>,
+    event_bus: Option<Arc<EventBus>>,
+    status: Option<SoftDeleteStatus>,
+}
+
+/// Restore routes for the entity types of `config` that have a creator
+pub fn restore_routes(
+    creators: &HashMap<String, Arc<dyn EntityCreator>>,
+    config: &LinksConfig,
+    event_bus: Option<Arc<EventBus>>,
+    status: Option<SoftDeleteStatus>,
+) -> Router {
+    config
+        .entities
+        .iter()
+        .filter_map(|entity| {
+            let state = RestoreState {
+                entity_type: entity.singular.clone(),
+                creator: creators.get(&entity.singular)?.clone(),
+                event_bus: event_bus.clone(),
+                status: status.clone(),
+            };
+            Some(
+                Router::new()
+                    .route(&format!("/{}/{{id}}/restore", entity.plural), post(restore))
+                    .with_state(state),
+            )
+        })
+        .fold(Router::new(), Router::merge)
+}
+
+async fn restore(State(state): State<RestoreState>, Path(raw_id): Path<String>) -> Response {
+    let Ok(entity_id) = Uuid::parse_str(&raw_id) else {
+        return invalid_id(&raw_id);
+    };
+    let restored = match &state.status {
+        Some(status) => state.creator.restore_with_status(&entity_id, status).await,
+        None => state.creator.restore(&entity_id).await,
+    };
+    match restored {
+        Ok(data) => {
+            if let Some(bus) = &state.event_bus {
+                bus.publish(FrameworkEvent::Entity(EntityEvent::Updated {
+                    entity_type: state.entity_type,
+                    entity_id,
+                    data: data.clone(),
+                }));
+            }
+            Json(data).into_response()
+        }
+        Err(e) => soft_delete_error(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use crate::core::DataService;
+    use crate::storage::InMemoryDataService;
+    use async_trait::async_trait;
+    use axum::body::{Body, to_bytes};
+    use axum::routing::get;
+    use axum::{Router, middleware};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    crate::impl_data_entity!(Ticket, "ticket", ["name"], {});
+
+    /// Creator soft-deleting through `DataService`
+    struct TicketCreator(Arc<InMemoryDataService<Ticket>>);
+
+    #[async_trait]
+    impl EntityCreator for TicketCreator {
+        async fn create_from_json(&self, _data: Value) -> anyhow::Result<Value> {
+            unimplemented!()
+        }
+
+        async fn soft_delete(&self, entity_id: &Uuid) -> anyhow::Result<Value> {
+            Ok(serde_json::to_value(self.0.soft_delete(entity_id).await?)?)
+        }
+
+        async fn restore(&self, entity_id: &Uuid) -> anyhow::Result<Value> {
+            Ok(serde_json::to_value(self.0.restore(entity_id).await?)?)
+        }
+
+        async fn soft_delete_with_status(
+            &self,
+            entity_id: &Uuid,
+            status: &SoftDeleteStatus,
+        ) -> anyhow::Result<Value> {
+            let deleted = self.0.soft_delete_with_status(entity_id, status).await?;
+            Ok(serde_json::to_value(deleted)?)
+        }
+
+        async fn restore_with_status(
+            &self,
+            entity_id: &Uuid,
+            status: &SoftDeleteStatus,
+        ) -> anyhow::Result<Value> {
+            let restored = self.0.restore_with_status(entity_id, status).await?;
+            Ok(serde_json::to_value(restored)?)
+        }
+    }
+
+    /// Creator without soft delete support
+    struct PlainCreator;
+
+    #[async_trait]
+    impl EntityCreator for PlainCreator {
+        async fn create_from_json(&self, _data: Value) -> anyhow::Result<Value> {
+            unimplemented!()
+        }
+    }
+
+    fn config() -> LinksConfig {
+        LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "ticket".to_string(),
+                plural: "tickets".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        }
+    }
+
+    fn app(creator: Arc<dyn EntityCreator>) -> Router {
+        app_with_status(creator, None)
+    }
+
+    fn app_with_status(
+        creator: Arc<dyn EntityCreator>,
+        status: Option<SoftDeleteStatus>,
+    ) -> Router {
+        let creators = HashMap::from([("ticket".to_string(), creator)]);
+        Router::new()
+            .route(
+                "/tickets/{id}",
+                get(|| async { "descriptor" }).delete(|| async { "hard delete" }),
+            )
+            .merge(restore_routes(&creators, &config(), None, status.clone()))
+            .layer(middleware::from_fn_with_state(
+                SoftDeleteState::new(Arc::new(creators), &config(), status),
+                soft_delete_middleware,
+            ))
+    }
+
+    async fn send(app: &Router, method: Method, uri: String) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_then_restore() {
+        let service = Arc::new(InMemoryDataService::<Ticket>::new());
+        let ticket = service
+            .create(Ticket::new("T-1".to_string(), "open".to_string()))
+            .await
+            .unwrap();
+        let app = app(Arc::new(TicketCreator(service.clone())));
+
+        let (status, _) = send(
+            &app,
+            Method::DELETE,
+            format!("/tickets/{}?soft=true", ticket.id),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(service.list().await.unwrap().is_empty());
+        let tombstone = service.get_with_deleted(&ticket.id).await.unwrap().unwrap();
+        assert!(tombstone.deleted_at.is_some());
+
+        // Already gone
+        let (status, _) = send(
+            &app,
+            Method::DELETE,
+            format!("/tickets/{}?soft=true", ticket.id),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            format!("/tickets/{}/restore", ticket.id),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["deleted_at"], Value::Null);
+        assert_eq!(service.list().await.unwrap().len(), 1);
+
+        // Restoring a live entity is a no-op
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            format!("/tickets/{}/restore", ticket.id),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["id"], ticket.id.to_string());
+        assert_eq!(service.list().await.unwrap().len(), 1);
+
+        let (status, _) = send(
+            &app,
+            Method::POST,
+            format!("/tickets/{}/restore", Uuid::new_v4()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_status_transition() {
+        let service = Arc::new(InMemoryDataService::<Ticket>::new());
+        let ticket = service
+            .create(Ticket::new("T-1".to_string(), "active".to_string()))
+            .await
+            .unwrap();
+        let app = app_with_status(
+            Arc::new(TicketCreator(service.clone())),
+            Some(SoftDeleteStatus::new("deleted", "active")),
+        );
+
+        let (status, _) = send(
+            &app,
+            Method::DELETE,
+            format!("/tickets/{}?soft=true", ticket.id),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let tombstone = service.get_with_deleted(&ticket.id).await.unwrap().unwrap();
+        assert!(tombstone.deleted_at.is_some());
+        assert_eq!(tombstone.status, "deleted");
+
+        let (status, body) = send(
+            &app,
+            Method::POST,
+            format!("/tickets/{}/restore", ticket.id),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["deleted_at"], Value::Null);
+        assert_eq!(body["status"], "active");
+    }
+
+    #[tokio::test]
+    async fn test_plain_delete_and_unsupported_soft_delete() {
+        let app = app(Arc::new(PlainCreator));
+        let id = Uuid::new_v4();
+
+        let (status, _) = send(&app, Method::DELETE, format!("/tickets/{id}?soft=true")).await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+        let (status, _) = send(&app, Method::POST, format!("/tickets/{id}/restore")).await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+
+        // Without the flag, deletes reach the descriptor's handler
+        let (status, _) = send(&app, Method::DELETE, format!("/tickets/{id}?soft=false")).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send(&app, Method::DELETE, format!("/tickets/{id}")).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
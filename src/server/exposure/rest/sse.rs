@@ -111,6 +111,7 @@ fn matches_filter(envelope: &EventEnvelope, filter: &SseFilter) -> bool {
             },
             FrameworkEvent::Link(l) => match l {
                 LinkEvent::Created { link_type: lt, .. }
+                | LinkEvent::Updated { link_type: lt, .. }
                 | LinkEvent::Deleted { link_type: lt, .. } => lt == entity_type,
             },
         };
@@ -168,9 +169,16 @@ fn envelope_to_sse_event(envelope: &EventEnvelope) -> Option<Event> {
                 source_id,
                 target_id,
                 metadata,
+            }
+            | LinkEvent::Updated {
+                link_type,
+                link_id,
+                source_id,
+                target_id,
+                metadata,
             } => json!({
                 "kind": "link",
-                "action": "created",
+                "action": envelope.event.action(),
                 "link_type": link_type,
                 "link_id": link_id,
                 "source_id": source_id,
@@ -0,0 +1,498 @@
+//! Tenant header enforcement on REST entity and link routes
+//!
+//! Mounted when the host has a tenant header (see
+//! [`ServerBuilder::with_tenancy`](crate::server::ServerBuilder::with_tenancy)).
+//! Requests without the header, or whose header is not a UUID, are answered
+//! with `400 Bad Request`; the others carry a [`TenantContext`] in their
+//! extensions for the handlers to scope their queries with.
+//!
+//! Requests for one entity (`/{entity_type}/{id}` and the routes below it)
+//! are also checked here, since the layers serving PATCH, soft deletes,
+//! restores, history and conditional requests answer without reaching the
+//! entity handlers: an entity of another tenant is answered with
+//! `404 Not Found`, as if it did not exist. Create and update bodies are
+//! bound to the request's tenant, so an entity cannot be written into, or
+//! moved to, another tenant.
+
+use crate::config::LinksConfig;
+use crate::core::extractors::error_response;
+use crate::core::module::EntityFetcher;
+use crate::core::tenant::TenantContext;
+use crate::server::body::JsonRequest;
+use axum::extract::{Request, State};
+use axum::http::{HeaderName, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Shared state for the tenancy middleware
+#[derive(Clone)]
+pub struct TenancyState {
+    header: HeaderName,
+}
+
+impl TenancyState {
+    pub fn new(header: HeaderName) -> Self {
+        Self { header }
+    }
+}
+
+/// Middleware requiring the tenant header and exposing it as a [`TenantContext`]
+pub async fn tenancy_middleware(
+    State(state): State<TenancyState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    match TenantContext::from_headers(request.headers(), &state.header) {
+        Ok(tenant) => {
+            request.extensions_mut().insert(tenant);
+            next.run(request).await
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+/// Shared state for the tenant entity middleware
+#[derive(Clone)]
+pub struct TenantEntityState {
+    /// Plural route segment -> fetcher of the stored entities
+    fetchers: Arc<HashMap<String, Arc<dyn EntityFetcher>>>,
+}
+
+impl TenantEntityState {
+    pub fn new(fetchers: &HashMap<String, Arc<dyn EntityFetcher>>, config: &LinksConfig) -> Self {
+        let fetchers = config
+            .entities
+            .iter()
+            .filter_map(|e| Some((e.plural.clone(), fetchers.get(&e.singular)?.clone())))
+            .collect();
+        Self {
+            fetchers: Arc::new(fetchers),
+        }
+    }
+}
+
+/// Middleware keeping per-entity routes and entity writes within the tenant
+///
+/// Entities of other tenants, soft-deleted ones included so they cannot be
+/// restored across tenants, are answered with `404 Not Found`, as are ids
+/// whose entity cannot be fetched (except on the history routes, which check
+/// the tenant of the recorded snapshots). Create and update bodies get the
+/// context's `tenant_id`; one naming another tenant is refused with
+/// `403 Forbidden`. Malformed ids are left to the handlers.
+pub async fn tenant_entity_middleware(
+    State(state): State<TenantEntityState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(tenant) = request.extensions().get::<TenantContext>().copied() else {
+        return next.run(request).await;
+    };
+    let path = request.uri().path().to_string();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let Some(fetcher) = state.fetchers.get(segments[0]) else {
+        return next.run(request).await;
+    };
+    let writes = matches!(
+        (segments.len(), request.method()),
+        (1, &Method::POST) | (2, &Method::PUT | &Method::PATCH)
+    );
+    // The history outlives the entity; its handlers check the tenant of the snapshots
+    let history = matches!(segments.get(2), Some(&"history" | &"versions"))
+        || request
+            .uri()
+            .query()
+            .is_some_and(|query| query.split('&').any(|pair| pair.starts_with("version=")));
+
+    if let Some(Ok(id)) = segments.get(1).map(|id| Uuid::parse_str(id)) {
+        match fetcher.fetch_with_deleted_as_json(&id).await {
+            Ok(stored) if tenant.owns_json(&stored) => {}
+            Err(_) if history => {}
+            _ => {
+                return error_response(
+                    StatusCode::NOT_FOUND,
+                    "ENTITY_NOT_FOUND",
+                    format!("Entity not found: {}", id),
+                );
+            }
+        }
+    }
+    if !writes {
+        return next.run(request).await;
+    }
+
+    let mut body = match JsonRequest::read(request).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    // Malformed JSON is left for the handler to report as usual
+    if let Some(payload) = body.json_mut()
+        && !tenant.bind_json(payload)
+    {
+        return error_response(
+            StatusCode::FORBIDDEN,
+            "TENANT_MISMATCH",
+            "tenant_id does not match the request's tenant",
+        );
+    }
+    next.run(body.into_request()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{Router, middleware};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    async fn send(tenant: Option<&str>) -> Response {
+        let app = Router::new()
+            .route(
+                "/orders",
+                get(|tenant: TenantContext| async move { tenant.tenant_id.to_string() }),
+            )
+            .layer(middleware::from_fn_with_state(
+                TenancyState::new(HeaderName::from_static("x-org")),
+                tenancy_middleware,
+            ));
+        let mut request = axum::http::Request::get("/orders");
+        if let Some(tenant) = tenant {
+            request = request.header("x-org", tenant);
+        }
+        app.oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_requests_need_a_tenant_header() {
+        for (tenant, code) in [
+            (None, "MISSING_TENANT"),
+            (Some("acme"), "INVALID_TENANT_ID"),
+        ] {
+            let response = send(tenant).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["code"], code);
+            assert_eq!(body["error"], body["message"]);
+        }
+
+        let tenant = Uuid::new_v4().to_string();
+        let response = send(Some(&tenant)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, tenant.as_bytes());
+    }
+
+    mod cross_tenant {
+        use super::super::*;
+        use crate::config::{EntityAuthConfig, EntityConfig};
+        use crate::core::history::HistoryService;
+        use crate::core::module::EntityCreator;
+        use crate::core::soft_delete::SoftDeleteError;
+        use crate::server::entity_registry::{EntityDescriptor, EntityRegistry};
+        use crate::server::exposure::rest::RestExposure;
+        use crate::server::host::ServerHost;
+        use crate::storage::{InMemoryHistoryService, InMemoryLinkService};
+        use axum::Router;
+        use axum::body::{Body, to_bytes};
+        use axum::http::header::IF_NONE_MATCH;
+        use axum::routing::{get, post};
+        use serde_json::{Value, json};
+        use std::sync::Mutex;
+        use tower::ServiceExt;
+
+        /// Orders stored as JSON, patched, soft-deleted and restored in place
+        #[derive(Default)]
+        struct OrderStore(Mutex<HashMap<Uuid, Value>>);
+
+        #[async_trait::async_trait]
+        impl EntityFetcher for OrderStore {
+            async fn fetch_as_json(&self, entity_id: &Uuid) -> anyhow::Result<Value> {
+                self.fetch_with_deleted_as_json(entity_id)
+                    .await
+                    .ok()
+                    .filter(|order| order["deleted_at"].is_null())
+                    .ok_or_else(|| anyhow::anyhow!("not found"))
+            }
+
+            async fn fetch_with_deleted_as_json(&self, entity_id: &Uuid) -> anyhow::Result<Value> {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .get(entity_id)
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("not found"))
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl EntityCreator for OrderStore {
+            async fn create_from_json(&self, entity_data: Value) -> anyhow::Result<Value> {
+                let id = Uuid::new_v4();
+                let mut order = entity_data;
+                order["id"] = json!(id);
+                self.0.lock().unwrap().insert(id, order.clone());
+                Ok(order)
+            }
+
+            async fn patch_from_json(
+                &self,
+                entity_id: &Uuid,
+                partial: Value,
+            ) -> anyhow::Result<Value> {
+                let mut orders = self.0.lock().unwrap();
+                let order = orders.get_mut(entity_id).unwrap();
+                for (field, value) in partial.as_object().unwrap() {
+                    order[field] = value.clone();
+                }
+                Ok(order.clone())
+            }
+
+            async fn restore(&self, entity_id: &Uuid) -> anyhow::Result<Value> {
+                let mut orders = self.0.lock().unwrap();
+                let order = orders
+                    .get_mut(entity_id)
+                    .ok_or(SoftDeleteError::NotFound(*entity_id))?;
+                order["deleted_at"] = Value::Null;
+                Ok(order.clone())
+            }
+        }
+
+        struct OrderDescriptor(Arc<OrderStore>);
+
+        impl EntityDescriptor for OrderDescriptor {
+            fn entity_type(&self) -> &str {
+                "order"
+            }
+
+            fn plural(&self) -> &str {
+                "orders"
+            }
+
+            fn build_routes(&self) -> Router {
+                let (store, creator) = (self.0.clone(), self.0.clone());
+                Router::new()
+                    .route(
+                        "/orders/{id}",
+                        get(
+                            |axum::extract::Path(id): axum::extract::Path<Uuid>| async move {
+                                axum::Json(store.fetch_as_json(&id).await.unwrap())
+                            },
+                        ),
+                    )
+                    .route(
+                        "/orders",
+                        post(|axum::Json(order): axum::Json<Value>| async move {
+                            axum::Json(creator.create_from_json(order).await.unwrap())
+                        }),
+                    )
+            }
+        }
+
+        struct Fixture {
+            router: Router,
+            store: Arc<OrderStore>,
+            order: Uuid,
+            owner: Uuid,
+        }
+
+        /// A router with one order of tenant `owner`, soft-deleted if `deleted`
+        async fn fixture(deleted: bool) -> Fixture {
+            let (order, owner) = (Uuid::new_v4(), Uuid::new_v4());
+            let store = Arc::new(OrderStore::default());
+            store.0.lock().unwrap().insert(
+                order,
+                json!({
+                    "id": order,
+                    "tenant_id": owner,
+                    "name": "A-1",
+                    "updated_at": "2024-01-01T00:00:00Z",
+                    "deleted_at": if deleted { json!("2024-01-02T00:00:00Z") } else { Value::Null },
+                }),
+            );
+            let history = Arc::new(InMemoryHistoryService::new());
+            history
+                .record(
+                    "order",
+                    &order,
+                    json!({ "tenant_id": owner, "name": "A-0" }),
+                    None,
+                )
+                .await
+                .unwrap();
+
+            let config = LinksConfig {
+                entities: vec![EntityConfig {
+                    singular: "order".to_string(),
+                    plural: "orders".to_string(),
+                    auth: EntityAuthConfig::default(),
+                    id_policy: Default::default(),
+                    fields: Default::default(),
+                    min_update_interval: None,
+                }],
+                links: vec![],
+                validation_rules: None,
+                events: None,
+                sinks: None,
+                pluralization: None,
+            };
+            let mut registry = EntityRegistry::new();
+            registry.register(Box::new(OrderDescriptor(store.clone())));
+            let host = ServerHost::from_builder_components(
+                Arc::new(InMemoryLinkService::new()),
+                config,
+                registry,
+                HashMap::from([("order".to_string(), store.clone() as Arc<dyn EntityFetcher>)]),
+                HashMap::from([("order".to_string(), store.clone() as Arc<dyn EntityCreator>)]),
+            )
+            .unwrap()
+            .with_tenant_header(HeaderName::from_static("x-tenant-id"))
+            .with_history_service(history);
+            Fixture {
+                router: RestExposure::build_router(Arc::new(host), vec![]).unwrap(),
+                store,
+                order,
+                owner,
+            }
+        }
+
+        async fn send(
+            router: &Router,
+            request: axum::http::request::Builder,
+            tenant: Uuid,
+            body: Body,
+        ) -> (StatusCode, Value) {
+            let request = request
+                .header("x-tenant-id", tenant.to_string())
+                .header("content-type", "application/json")
+                .body(body)
+                .unwrap();
+            let response = router.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+        }
+
+        #[tokio::test]
+        async fn test_patch_is_scoped_to_the_tenant() {
+            let f = fixture(false).await;
+            let uri = format!("/orders/{}", f.order);
+            let patch = || axum::http::Request::patch(&uri);
+
+            let body = || Body::from(json!({ "name": "B-1" }).to_string());
+            let (status, error) = send(&f.router, patch(), Uuid::new_v4(), body()).await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            assert_eq!(error["code"], "ENTITY_NOT_FOUND");
+            assert_eq!(
+                f.store.fetch_as_json(&f.order).await.unwrap()["name"],
+                "A-1"
+            );
+
+            let (status, order) = send(&f.router, patch(), f.owner, body()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(order["name"], "B-1");
+        }
+
+        #[tokio::test]
+        async fn test_restore_is_scoped_to_the_tenant() {
+            let f = fixture(true).await;
+            let uri = format!("/orders/{}/restore", f.order);
+            let restore = || axum::http::Request::post(&uri);
+
+            let (status, _) = send(&f.router, restore(), Uuid::new_v4(), Body::empty()).await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            assert!(f.store.fetch_as_json(&f.order).await.is_err());
+
+            let (status, _) = send(&f.router, restore(), f.owner, Body::empty()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert!(f.store.fetch_as_json(&f.order).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn test_history_is_scoped_to_the_tenant() {
+            let f = fixture(false).await;
+            for uri in [
+                format!("/orders/{}/history", f.order),
+                format!("/orders/{}/versions/1", f.order),
+                format!("/orders/{}?version=1", f.order),
+            ] {
+                let get = || axum::http::Request::get(&uri);
+                let (status, _) = send(&f.router, get(), Uuid::new_v4(), Body::empty()).await;
+                assert_eq!(status, StatusCode::NOT_FOUND, "{}", uri);
+                let (status, _) = send(&f.router, get(), f.owner, Body::empty()).await;
+                assert_eq!(status, StatusCode::OK, "{}", uri);
+            }
+
+            // The history outlives the entity, and stays with its tenant
+            f.store.0.lock().unwrap().clear();
+            let uri = format!("/orders/{}/history", f.order);
+            let get = || axum::http::Request::get(&uri);
+            let (status, _) = send(&f.router, get(), Uuid::new_v4(), Body::empty()).await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            let (status, body) = send(&f.router, get(), f.owner, Body::empty()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["total"], 1);
+        }
+
+        #[tokio::test]
+        async fn test_conditional_get_is_scoped_to_the_tenant() {
+            let f = fixture(false).await;
+            let uri = format!("/orders/{}", f.order);
+            let get = || axum::http::Request::get(&uri).header(IF_NONE_MATCH, "*");
+
+            let (status, _) = send(&f.router, get(), Uuid::new_v4(), Body::empty()).await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            let (status, _) = send(&f.router, get(), f.owner, Body::empty()).await;
+            assert_eq!(status, StatusCode::NOT_MODIFIED);
+        }
+
+        #[tokio::test]
+        async fn test_writes_cannot_name_another_tenant() {
+            let f = fixture(false).await;
+            let other = Uuid::new_v4();
+            let create = || axum::http::Request::post("/orders");
+            let uri = format!("/orders/{}", f.order);
+            let patch = || axum::http::Request::patch(&uri);
+
+            let body = || Body::from(json!({ "name": "B-1", "tenant_id": other }).to_string());
+            let (status, error) = send(&f.router, create(), f.owner, body()).await;
+            assert_eq!(status, StatusCode::FORBIDDEN);
+            assert_eq!(error["code"], "TENANT_MISMATCH");
+            let (status, _) = send(&f.router, patch(), f.owner, body()).await;
+            assert_eq!(status, StatusCode::FORBIDDEN);
+            assert_eq!(f.store.0.lock().unwrap().len(), 1);
+            assert_eq!(
+                f.store.fetch_as_json(&f.order).await.unwrap()["tenant_id"],
+                json!(f.owner)
+            );
+
+            // Without a tenant_id, new entities get the request's tenant
+            let body = Body::from(json!({ "name": "A-2" }).to_string());
+            let (status, order) = send(&f.router, create(), f.owner, body).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(order["tenant_id"], json!(f.owner));
+        }
+
+        #[tokio::test]
+        async fn test_unknown_entities_are_not_found() {
+            let f = fixture(false).await;
+            let uri = format!("/orders/{}", Uuid::new_v4());
+            let body = Body::from(json!({ "name": "B-1" }).to_string());
+            let (status, error) =
+                send(&f.router, axum::http::Request::patch(&uri), f.owner, body).await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            assert_eq!(error["code"], "ENTITY_NOT_FOUND");
+        }
+    }
+}
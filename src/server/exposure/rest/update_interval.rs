@@ -0,0 +1,165 @@
+//! Enforcement of per-entity `min_update_interval` on REST updates
+//!
+//! `PUT` and `PATCH /{entity_type}/{id}` for an entity updated less than its
+//! minimum interval ago are answered with `429 Too Many Requests` and a
+//! `Retry-After` header (whole seconds), without reaching the entity
+//! handlers. The stored `updated_at` is read through the entity's
+//! `EntityFetcher`; requests for entities that cannot be fetched pass through.
+//!
+//! Only mounted when some entity declares an interval.
+
+use crate::config::LinksConfig;
+use crate::core::extractors::error_body;
+use crate::core::module::EntityFetcher;
+use crate::core::update_interval::check_update_interval;
+use axum::Json;
+use axum::extract::{Request, State};
+use axum::http::header::RETRY_AFTER;
+use axum::http::{Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Interval of an entity type and the fetcher reading its `updated_at`
+struct Guard {
+    fetcher: Arc<dyn EntityFetcher>,
+    interval: Duration,
+}
+
+/// Shared state for the update interval middleware
+#[derive(Clone)]
+pub struct UpdateIntervalState {
+    /// Plural route segment -> guard, for entities with an interval
+    guarded: Arc<HashMap<String, Guard>>,
+}
+
+impl UpdateIntervalState {
+    pub fn new(fetchers: &HashMap<String, Arc<dyn EntityFetcher>>, config: &LinksConfig) -> Self {
+        let guarded = config
+            .entities
+            .iter()
+            .filter_map(|e| {
+                let guard = Guard {
+                    fetcher: fetchers.get(&e.singular)?.clone(),
+                    interval: e.min_update_interval?,
+                };
+                Some((e.plural.clone(), guard))
+            })
+            .collect();
+        Self {
+            guarded: Arc::new(guarded),
+        }
+    }
+
+    /// Whether any entity needs enforcement
+    pub fn is_empty(&self) -> bool {
+        self.guarded.is_empty()
+    }
+
+    fn target(&self, method: &Method, path: &str) -> Option<(&Guard, Uuid)> {
+        if method != Method::PUT && method != Method::PATCH {
+            return None;
+        }
+        let (plural, id) = path.trim_matches('/').split_once('/')?;
+        Some((self.guarded.get(plural)?, Uuid::parse_str(id).ok()?))
+    }
+}
+
+/// Middleware refusing updates that arrive within the entity's minimum interval
+pub async fn update_interval_middleware(
+    State(state): State<UpdateIntervalState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((guard, id)) = state.target(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    if let Ok(stored) = guard.fetcher.fetch_as_json(&id).await
+        && let Err(e) = check_update_interval(&stored, guard.interval, Utc::now())
+    {
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(RETRY_AFTER, e.retry_after_secs().to_string())],
+            Json(error_body("UPDATE_TOO_SOON", &e.to_string(), None)),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EntityAuthConfig, EntityConfig};
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::routing::put;
+    use axum::{Router, middleware};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    /// Fetcher whose entities were all updated `age` ago
+    struct AgedFetcher(chrono::Duration);
+
+    #[async_trait]
+    impl EntityFetcher for AgedFetcher {
+        async fn fetch_as_json(&self, entity_id: &Uuid) -> anyhow::Result<Value> {
+            Ok(json!({ "id": entity_id, "updated_at": (Utc::now() - self.0).to_rfc3339() }))
+        }
+    }
+
+    async fn send_update(age: chrono::Duration) -> Response {
+        let config = LinksConfig {
+            entities: vec![EntityConfig {
+                singular: "device".to_string(),
+                plural: "devices".to_string(),
+                auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: Some(Duration::from_secs(10)),
+            }],
+            links: vec![],
+            validation_rules: None,
+            events: None,
+            sinks: None,
+            pluralization: None,
+        };
+        let fetchers: HashMap<String, Arc<dyn EntityFetcher>> =
+            HashMap::from([("device".to_string(), Arc::new(AgedFetcher(age)) as _)]);
+
+        Router::new()
+            .route("/devices/{id}", put(|| async { StatusCode::OK }))
+            .layer(middleware::from_fn_with_state(
+                UpdateIntervalState::new(&fetchers, &config),
+                update_interval_middleware,
+            ))
+            .oneshot(
+                Request::put(format!("/devices/{}", Uuid::new_v4()))
+                    .body(Body::from("{}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_update_too_soon_is_rejected_with_retry_after() {
+        let response = send_update(chrono::Duration::seconds(3)).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let retry_after: u64 = response.headers()[RETRY_AFTER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((6..=7).contains(&retry_after));
+
+        let response = send_update(chrono::Duration::seconds(11)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -126,21 +126,35 @@ async fn handle_client_message(manager: &ConnectionManager, connection_id: &str,
     };
 
     match msg {
-        ClientMessage::Subscribe { filter } => {
-            match manager.subscribe(connection_id, filter.clone()).await {
-                Ok(sub_id) => {
-                    let response = ServerMessage::Subscribed {
-                        subscription_id: sub_id,
-                        filter,
-                    };
-                    manager.send_to(connection_id, response).await;
-                }
-                Err(e) => {
-                    let error_msg = ServerMessage::Error { message: e };
-                    manager.send_to(connection_id, error_msg).await;
-                }
+        ClientMessage::Subscribe {
+            filter,
+            since_seq: Some(since_seq),
+        } => {
+            // Confirmation and backlog are sent by the manager, ahead of live events
+            if let Err(e) = manager
+                .subscribe_since(connection_id, filter, since_seq)
+                .await
+            {
+                let error_msg = ServerMessage::Error { message: e };
+                manager.send_to(connection_id, error_msg).await;
             }
         }
+        ClientMessage::Subscribe {
+            filter,
+            since_seq: None,
+        } => match manager.subscribe(connection_id, filter.clone()).await {
+            Ok(sub_id) => {
+                let response = ServerMessage::Subscribed {
+                    subscription_id: sub_id,
+                    filter,
+                };
+                manager.send_to(connection_id, response).await;
+            }
+            Err(e) => {
+                let error_msg = ServerMessage::Error { message: e };
+                manager.send_to(connection_id, error_msg).await;
+            }
+        },
         ClientMessage::Unsubscribe { subscription_id } => {
             match manager.unsubscribe(connection_id, &subscription_id).await {
                 Ok(removed) => {
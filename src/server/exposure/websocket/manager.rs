@@ -11,15 +11,15 @@
 //!                          │
 //!                    for each connection
 //!                          │
-//!                    for each subscription
+//!                    first subscription where
+//!                    filter.matches(event)
 //!                          │
-//!                    filter.matches(event)?
-//!                          │
-//!                    ──yes──▶ send to client via mpsc channel
+//!                    ──found──▶ send once to client via mpsc channel
 //! ```
 
 use super::protocol::{ServerMessage, Subscription, SubscriptionFilter};
 use crate::core::events::EventEnvelope;
+use crate::events::types::SeqNo;
 use crate::server::host::ServerHost;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -47,8 +47,8 @@ struct ConnectionHandle {
 /// Thread-safe via `RwLock` — reads (dispatch) are frequent, writes
 /// (connect/disconnect/subscribe) are infrequent.
 pub struct ConnectionManager {
-    /// Reference to the server host (for future use, e.g. auth)
-    _host: Arc<ServerHost>,
+    /// Reference to the server host (event bus replay buffer)
+    host: Arc<ServerHost>,
     /// All active connections indexed by connection ID
     connections: RwLock<HashMap<String, ConnectionHandle>>,
 }
@@ -63,7 +63,7 @@ impl ConnectionManager {
     /// Create a new ConnectionManager
     pub fn new(host: Arc<ServerHost>) -> Self {
         Self {
-            _host: host,
+            host,
             connections: RwLock::new(HashMap::new()),
         }
     }
@@ -125,6 +125,58 @@ impl ConnectionManager {
         Ok(sub_id)
     }
 
+    /// Add a subscription and replay the events it missed since `since_seq`
+    ///
+    /// Confirms the subscription with a `Subscribed` message, then sends the
+    /// buffered events newer than `since_seq` that match the filter, oldest
+    /// first. Both happen while no live event can be dispatched, so the
+    /// backlog is delivered in order ahead of live events, and events it
+    /// contains are not delivered twice.
+    pub async fn subscribe_since(
+        &self,
+        connection_id: &str,
+        filter: SubscriptionFilter,
+        since_seq: SeqNo,
+    ) -> Result<String, String> {
+        let mut connections = self.connections.write().await;
+        let conn = connections
+            .get_mut(connection_id)
+            .ok_or_else(|| format!("Connection {} not found", connection_id))?;
+
+        let backlog = self
+            .host
+            .event_bus()
+            .map(|bus| bus.replay_since(since_seq))
+            .unwrap_or_default();
+
+        let mut subscription = Subscription::new(filter.clone());
+        subscription.replayed_through = backlog.last().and_then(|e| e.seq_no);
+        let sub_id = subscription.id.clone();
+
+        let _ = conn.tx.send(ServerMessage::Subscribed {
+            subscription_id: sub_id.clone(),
+            filter,
+        });
+        for envelope in backlog {
+            if subscription.filter.matches(&envelope.event) {
+                let _ = conn.tx.send(ServerMessage::Event {
+                    subscription_id: sub_id.clone(),
+                    data: envelope,
+                });
+            }
+        }
+        conn.subscriptions.push(subscription);
+
+        tracing::debug!(
+            connection_id = %connection_id,
+            subscription_id = %sub_id,
+            since_seq = since_seq,
+            "Subscription added with replay"
+        );
+
+        Ok(sub_id)
+    }
+
     /// Remove a subscription from a connection
     ///
     /// Returns `true` if the subscription was found and removed.
@@ -239,30 +291,32 @@ impl ConnectionManager {
         count
     }
 
-    /// Dispatch an event to all matching subscriptions across all connections
+    /// Dispatch an event to all connections with a matching subscription
     ///
-    /// For each connection, check every subscription filter against the event.
-    /// If a subscription matches, send the event to that connection with the
-    /// subscription ID attached.
+    /// Each connection receives the event at most once, tagged with the ID of
+    /// its first subscription (in subscription order) whose filter matches,
+    /// even when several of its subscriptions overlap.
     async fn dispatch_event(&self, envelope: &EventEnvelope) {
         let connections = self.connections.read().await;
 
         for (connection_id, handle) in connections.iter() {
-            for subscription in &handle.subscriptions {
-                if subscription.filter.matches(&envelope.event) {
-                    let message = ServerMessage::Event {
-                        subscription_id: subscription.id.clone(),
-                        data: envelope.clone(),
-                    };
-
-                    if handle.tx.send(message).is_err() {
-                        tracing::debug!(
-                            connection_id = %connection_id,
-                            "Failed to send event to connection (likely disconnected)"
-                        );
-                        break; // No need to check other subscriptions for this dead connection
-                    }
-                }
+            let Some(subscription) = handle
+                .subscriptions
+                .iter()
+                .find(|subscription| subscription.accepts(envelope))
+            else {
+                continue;
+            };
+
+            let message = ServerMessage::Event {
+                subscription_id: subscription.id.clone(),
+                data: envelope.clone(),
+            };
+            if handle.tx.send(message).is_err() {
+                tracing::debug!(
+                    connection_id = %connection_id,
+                    "Failed to send event to connection (likely disconnected)"
+                );
             }
         }
     }
@@ -308,7 +362,7 @@ impl ConnectionManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::events::{EntityEvent, EventBus, FrameworkEvent};
+    use crate::core::events::{EntityEvent, EventBus, FrameworkEvent, LinkEvent};
     use serde_json::json;
 
     /// Helper to create a minimal ServerHost for testing
@@ -617,15 +671,16 @@ mod tests {
 
         // Two subscriptions that both match the same event:
         // 1. Subscribe to all "order" events
-        cm.subscribe(
-            &conn_id,
-            SubscriptionFilter {
-                entity_type: Some("order".to_string()),
-                ..Default::default()
-            },
-        )
-        .await
-        .expect("first subscribe should succeed");
+        let first = cm
+            .subscribe(
+                &conn_id,
+                SubscriptionFilter {
+                    entity_type: Some("order".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("first subscribe should succeed");
 
         // 2. Subscribe to all "created" events (regardless of entity type)
         cm.subscribe(
@@ -638,7 +693,7 @@ mod tests {
         .await
         .expect("second subscribe should succeed");
 
-        // Dispatch an order created event — should match BOTH subscriptions
+        // Dispatch an order created event — matches BOTH subscriptions
         let envelope = EventEnvelope::new(FrameworkEvent::Entity(EntityEvent::Created {
             entity_type: "order".to_string(),
             entity_id: Uuid::new_v4(),
@@ -646,26 +701,183 @@ mod tests {
         }));
         cm.dispatch_event(&envelope).await;
 
-        // Should receive two messages (one per matching subscription)
-        let msg1 = rx.try_recv().expect("should receive first matching event");
-        let msg2 = rx.try_recv().expect("should receive second matching event");
+        // ...but is delivered once, for the first matching subscription
+        match rx.try_recv().expect("should receive the event") {
+            ServerMessage::Event {
+                subscription_id,
+                data,
+            } => {
+                assert_eq!(subscription_id, first);
+                assert_eq!(data.id, envelope.id);
+            }
+            other => panic!("Expected Event message, got {:?}", other),
+        }
+        assert!(rx.try_recv().is_err(), "no duplicate frame");
+    }
 
-        // Both should be Event messages with the same envelope ID
-        match (&msg1, &msg2) {
-            (
-                ServerMessage::Event {
-                    subscription_id: sub1,
-                    data: d1,
-                },
-                ServerMessage::Event {
-                    subscription_id: sub2,
-                    data: d2,
-                },
-            ) => {
-                assert_ne!(sub1, sub2, "subscription IDs should differ");
-                assert_eq!(d1.id, d2.id, "both should carry the same event envelope");
+    #[tokio::test]
+    async fn test_dispatch_loop_filters_mixed_events() {
+        let cm = Arc::new(ConnectionManager::new(test_host()));
+
+        let subscribe = |filter: SubscriptionFilter| {
+            let cm = cm.clone();
+            async move {
+                let (conn_id, rx) = cm.connect().await;
+                cm.subscribe(&conn_id, filter).await.unwrap();
+                rx
+            }
+        };
+        let mut everything = subscribe(SubscriptionFilter::default()).await;
+        let mut order_updates = subscribe(SubscriptionFilter {
+            entity_type: Some("order".to_string()),
+            event_type: Some("entity.updated".to_string()),
+            ..Default::default()
+        })
+        .await;
+        let mut invoice_links = subscribe(SubscriptionFilter {
+            link_type: Some("has_invoice".to_string()),
+            ..Default::default()
+        })
+        .await;
+
+        let event_bus = EventBus::new(16);
+        let bus_rx = event_bus.subscribe();
+        let cm_clone = cm.clone();
+        let handle = tokio::spawn(async move { cm_clone.run_dispatch_loop(bus_rx).await });
+
+        let order_id = Uuid::new_v4();
+        let link = |link_type: &str| {
+            FrameworkEvent::Link(LinkEvent::Created {
+                link_type: link_type.to_string(),
+                link_id: Uuid::new_v4(),
+                source_id: order_id,
+                target_id: Uuid::new_v4(),
+                metadata: None,
+            })
+        };
+        let events = vec![
+            FrameworkEvent::Entity(EntityEvent::Created {
+                entity_type: "order".to_string(),
+                entity_id: order_id,
+                data: json!({}),
+            }),
+            FrameworkEvent::Entity(EntityEvent::Updated {
+                entity_type: "order".to_string(),
+                entity_id: order_id,
+                data: json!({}),
+            }),
+            FrameworkEvent::Entity(EntityEvent::Updated {
+                entity_type: "invoice".to_string(),
+                entity_id: Uuid::new_v4(),
+                data: json!({}),
+            }),
+            link("has_invoice"),
+            link("owner"),
+        ];
+        for event in events {
+            event_bus.publish(event);
+        }
+
+        // Closing the bus ends the loop once every event was dispatched
+        drop(event_bus);
+        tokio::time::timeout(std::time::Duration::from_secs(1), handle)
+            .await
+            .expect("dispatch loop should stop")
+            .unwrap();
+
+        let received = |rx: &mut mpsc::UnboundedReceiver<ServerMessage>| {
+            let mut events = Vec::new();
+            while let Ok(ServerMessage::Event { data, .. }) = rx.try_recv() {
+                events.push(format!(
+                    "{}.{}:{}",
+                    data.event.event_kind(),
+                    data.event.action(),
+                    data.event
+                        .entity_type()
+                        .or(data.event.link_type())
+                        .unwrap_or_default()
+                ));
             }
-            _ => panic!("Expected two Event messages"),
+            events
+        };
+        assert_eq!(received(&mut everything).len(), 5);
+        assert_eq!(received(&mut order_updates), vec!["entity.updated:order"]);
+        assert_eq!(
+            received(&mut invoice_links),
+            vec!["link.created:has_invoice"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_since_replays_backlog_in_order() {
+        use crate::config::LinksConfig;
+        use crate::server::entity_registry::EntityRegistry;
+        use crate::storage::InMemoryLinkService;
+        use std::collections::HashMap;
+
+        let host = ServerHost::from_builder_components(
+            Arc::new(InMemoryLinkService::new()),
+            LinksConfig::default_config(),
+            EntityRegistry::new(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap()
+        .with_event_bus(EventBus::new(16).with_replay_buffer(8));
+        let bus = host.event_bus().unwrap().clone();
+        let cm = ConnectionManager::new(Arc::new(host));
+
+        let updated = |entity_type: &str, n: i64| {
+            FrameworkEvent::Entity(EntityEvent::Updated {
+                entity_type: entity_type.to_string(),
+                entity_id: Uuid::new_v4(),
+                data: json!({ "n": n }),
+            })
+        };
+        // Published while the client was disconnected (seq 1..=4)
+        bus.publish(updated("order", 1));
+        bus.publish(updated("order", 2));
+        bus.publish(updated("invoice", 3));
+        bus.publish(updated("order", 4));
+
+        let (conn_id, mut rx) = cm.connect().await;
+        let filter = SubscriptionFilter {
+            entity_type: Some("order".to_string()),
+            ..Default::default()
+        };
+        let sub_id = cm.subscribe_since(&conn_id, filter, 1).await.unwrap();
+
+        match rx.try_recv().unwrap() {
+            ServerMessage::Subscribed {
+                subscription_id, ..
+            } => assert_eq!(subscription_id, sub_id),
+            other => panic!("Expected Subscribed, got {:?}", other),
+        }
+        let mut backlog = Vec::new();
+        while let Ok(ServerMessage::Event {
+            subscription_id,
+            data,
+        }) = rx.try_recv()
+        {
+            assert_eq!(subscription_id, sub_id);
+            backlog.push(data.seq_no.unwrap());
+        }
+        assert_eq!(backlog, vec![2, 4]);
+
+        // A replayed event reaching the dispatch loop late is not sent twice,
+        // newer ones are delivered live
+        for envelope in bus.replay_since(3) {
+            cm.dispatch_event(&envelope).await;
+        }
+        assert!(rx.try_recv().is_err(), "no duplicate of a replayed event");
+
+        bus.publish(updated("order", 5));
+        for envelope in bus.replay_since(4) {
+            cm.dispatch_event(&envelope).await;
+        }
+        match rx.try_recv().unwrap() {
+            ServerMessage::Event { data, .. } => assert_eq!(data.seq_no, Some(5)),
+            other => panic!("Expected Event, got {:?}", other),
         }
     }
 
@@ -31,6 +31,7 @@
 //!
 //! Client → Server (JSON):
 //! - `{"type": "subscribe", "filter": {"entity_type": "order"}}`
+//! - `{"type": "subscribe", "filter": {...}, "since_seq": 42}` (replay, see `EventBus::with_replay_buffer`)
 //! - `{"type": "unsubscribe", "subscription_id": "..."}`
 //! - `{"type": "ping"}`
 //!
@@ -146,7 +147,8 @@ impl WebSocketExposure {
             None => return,
         };
 
-        let sink_configs = match &host.config.sinks {
+        let config = host.config();
+        let sink_configs = match &config.sinks {
             Some(configs) => configs,
             None => return,
         };
@@ -8,6 +8,9 @@
 //! // Subscribe to events
 //! {"type": "subscribe", "filter": {"entity_type": "order", "event_type": "created"}}
 //!
+//! // Resubscribe after a reconnect, replaying buffered events newer than seq 42
+//! {"type": "subscribe", "filter": {"entity_type": "order"}, "since_seq": 42}
+//!
 //! // Unsubscribe
 //! {"type": "unsubscribe", "subscription_id": "sub_abc123"}
 //!
@@ -35,6 +38,7 @@
 //! ```
 
 use crate::core::events::{EventEnvelope, FrameworkEvent};
+use crate::events::types::SeqNo;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
@@ -45,8 +49,15 @@ use uuid::Uuid;
 pub enum ClientMessage {
     /// Subscribe to events matching a filter
     Subscribe {
-        /// Filter criteria for events
+        /// Filter criteria for events (omitted = all events)
+        #[serde(default)]
         filter: SubscriptionFilter,
+        /// Replay buffered events with a greater `seq_no` before live ones
+        ///
+        /// Only effective when the event bus has a replay buffer; events
+        /// already evicted from it are not replayed.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        since_seq: Option<SeqNo>,
     },
     /// Unsubscribe from a specific subscription
     Unsubscribe {
@@ -134,6 +145,16 @@ pub enum ServerMessage {
 /// ```json
 /// {"entity_type": "order", "event_type": "created"}
 /// ```
+///
+/// Subscribe to entity updates only (not link events):
+/// ```json
+/// {"event_type": "entity.updated"}
+/// ```
+///
+/// Subscribe to changes of one link type:
+/// ```json
+/// {"link_type": "has_invoice"}
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SubscriptionFilter {
     /// Filter by entity type (e.g., "order", "invoice")
@@ -146,7 +167,8 @@ pub struct SubscriptionFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entity_id: Option<Uuid>,
 
-    /// Filter by event type: "created", "updated", "deleted"
+    /// Filter by event type: "created", "updated", "deleted", or qualified
+    /// by kind as in "entity.updated" or "link.created"
     /// None = match all event types
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_type: Option<String>,
@@ -155,6 +177,11 @@ pub struct SubscriptionFilter {
     /// None = match both entity and link events
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kind: Option<String>,
+
+    /// Filter by link type (e.g., "has_invoice"); entity events never match
+    /// None = match all link types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_type: Option<String>,
 }
 
 impl SubscriptionFilter {
@@ -188,9 +215,20 @@ impl SubscriptionFilter {
             }
         }
 
-        // Check event_type (action) filter
-        if let Some(ref event_type) = self.event_type
-            && event.action() != event_type
+        // Check event_type (action) filter, optionally qualified by kind
+        if let Some(ref event_type) = self.event_type {
+            let matches = match event_type.split_once('.') {
+                Some((kind, action)) => event.event_kind() == kind && event.action() == action,
+                None => event.action() == event_type,
+            };
+            if !matches {
+                return false;
+            }
+        }
+
+        // Check link_type filter
+        if let Some(ref link_type) = self.link_type
+            && event.link_type() != Some(link_type.as_str())
         {
             return false;
         }
@@ -206,6 +244,9 @@ pub struct Subscription {
     pub id: String,
     /// The filter for this subscription
     pub filter: SubscriptionFilter,
+    /// Last sequence number replayed on subscribe; live events up to it are
+    /// not delivered again
+    pub replayed_through: Option<SeqNo>,
 }
 
 impl Subscription {
@@ -214,8 +255,18 @@ impl Subscription {
         Self {
             id: format!("sub_{}", Uuid::new_v4().simple()),
             filter,
+            replayed_through: None,
         }
     }
+
+    /// Check if a live event should be delivered through this subscription
+    pub fn accepts(&self, envelope: &EventEnvelope) -> bool {
+        let replayed = match (self.replayed_through, envelope.seq_no) {
+            (Some(through), Some(seq_no)) => seq_no <= through,
+            _ => false,
+        };
+        !replayed && self.filter.matches(&envelope.event)
+    }
 }
 
 #[cfg(test)]
@@ -234,11 +285,14 @@ mod tests {
                 entity_id: None,
                 event_type: Some("created".to_string()),
                 kind: None,
+                link_type: None,
             },
+            since_seq: None,
         };
 
         let json = serde_json::to_value(&msg).unwrap();
         assert_eq!(json["type"], "subscribe");
+        assert!(json.get("since_seq").is_none());
         assert_eq!(json["filter"]["entity_type"], "order");
         assert_eq!(json["filter"]["event_type"], "created");
     }
@@ -307,7 +361,8 @@ mod tests {
         let msg: ClientMessage = serde_json::from_str(json_str).unwrap();
 
         match msg {
-            ClientMessage::Subscribe { filter } => {
+            ClientMessage::Subscribe { filter, since_seq } => {
+                assert!(since_seq.is_none());
                 assert_eq!(filter.entity_type.as_deref(), Some("order"));
                 assert_eq!(filter.event_type.as_deref(), Some("created"));
                 assert!(filter.entity_id.is_none());
@@ -317,6 +372,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_client_message_subscribe_since_seq_without_filter() {
+        let msg: ClientMessage =
+            serde_json::from_str(r#"{"type":"subscribe","since_seq":42}"#).unwrap();
+
+        match msg {
+            ClientMessage::Subscribe { filter, since_seq } => {
+                assert_eq!(since_seq, Some(42));
+                assert!(filter.entity_type.is_none());
+            }
+            _ => panic!("Expected Subscribe"),
+        }
+    }
+
     #[test]
     fn test_client_message_ping_roundtrip() {
         let json_str = r#"{"type":"ping"}"#;
@@ -463,6 +532,63 @@ mod tests {
         assert!(filter.matches(&link_event));
     }
 
+    #[test]
+    fn test_filter_by_qualified_event_type() {
+        let filter = SubscriptionFilter {
+            event_type: Some("entity.updated".to_string()),
+            ..Default::default()
+        };
+
+        let updated = FrameworkEvent::Entity(EntityEvent::Updated {
+            entity_type: "order".to_string(),
+            entity_id: Uuid::new_v4(),
+            data: json!({}),
+        });
+        let link_created = FrameworkEvent::Link(LinkEvent::Created {
+            link_type: "has_invoice".to_string(),
+            link_id: Uuid::new_v4(),
+            source_id: Uuid::new_v4(),
+            target_id: Uuid::new_v4(),
+            metadata: None,
+        });
+
+        assert!(filter.matches(&updated));
+        assert!(!filter.matches(&link_created));
+
+        let filter = SubscriptionFilter {
+            event_type: Some("link.created".to_string()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&updated));
+        assert!(filter.matches(&link_created));
+    }
+
+    #[test]
+    fn test_filter_by_link_type() {
+        let filter = SubscriptionFilter {
+            link_type: Some("has_invoice".to_string()),
+            ..Default::default()
+        };
+
+        let link = |link_type: &str| {
+            FrameworkEvent::Link(LinkEvent::Deleted {
+                link_type: link_type.to_string(),
+                link_id: Uuid::new_v4(),
+                source_id: Uuid::new_v4(),
+                target_id: Uuid::new_v4(),
+            })
+        };
+        let entity_event = FrameworkEvent::Entity(EntityEvent::Created {
+            entity_type: "has_invoice".to_string(),
+            entity_id: Uuid::new_v4(),
+            data: json!({}),
+        });
+
+        assert!(filter.matches(&link("has_invoice")));
+        assert!(!filter.matches(&link("owner")));
+        assert!(!filter.matches(&entity_event));
+    }
+
     #[test]
     fn test_filter_combined_entity_type_and_action() {
         let filter = SubscriptionFilter {
@@ -522,12 +648,12 @@ mod tests {
 
     #[test]
     fn test_missing_required_fields_deserialization_error() {
-        // Subscribe requires a "filter" field
-        let missing_filter = r#"{"type": "subscribe"}"#;
-        let result = serde_json::from_str::<ClientMessage>(missing_filter);
+        // Subscribe may omit "filter" (all events), but not send a malformed one
+        let invalid_filter = r#"{"type": "subscribe", "filter": "order"}"#;
+        let result = serde_json::from_str::<ClientMessage>(invalid_filter);
         assert!(
             result.is_err(),
-            "subscribe without filter should fail to deserialize"
+            "subscribe with a non-object filter should fail to deserialize"
         );
 
         // Unsubscribe requires a "subscription_id" field
@@ -566,6 +692,7 @@ mod tests {
                 entity_id: None,
                 event_type: Some("created".to_string()),
                 kind: None,
+                link_type: None,
             },
         };
 
@@ -7,18 +7,46 @@
 //! single source of truth for the application state.
 
 use crate::config::LinksConfig;
-use crate::core::events::EventBus;
-use crate::core::{EntityCreator, EntityFetcher, service::LinkService};
+use crate::core::audit::{AuditEntry, AuditOperation, LINK_ENTITY_TYPE};
+use crate::core::events::{EventBus, FrameworkEvent, LinkEvent};
+use crate::core::query::PaginationConfig;
+use crate::core::soft_delete::SoftDeleteStatus;
+#[cfg(feature = "json-schema")]
+use crate::core::validation::EntitySchemas;
+use crate::core::{
+    AuthContext, AuthPolicy, AuthProvider, DefaultIdNormalizer, EntityCreator, EntityFetcher,
+    HealthCheck, IdNormalizer, IdStrategy, Module,
+    audit::AuditLogService,
+    history::HistoryService,
+    link::{LinkDefinition, LinkEntity, LinkError},
+    outbox::OutboxService,
+    ownership,
+    service::LinkService,
+    tenant::TenantContext,
+};
 use crate::events::log::EventLog;
 use crate::events::sinks::SinkRegistry;
 use crate::events::sinks::device_tokens::DeviceTokenStore;
 use crate::events::sinks::in_app::NotificationStore;
 use crate::events::sinks::preferences::NotificationPreferencesStore;
-use crate::links::registry::LinkRouteRegistry;
+use crate::links::handlers::AppState;
+use crate::links::registry::{LinkDirection, LinkRouteRegistry};
+use crate::links::{DEFAULT_ENRICHMENT_CONCURRENCY, EnrichmentFallback};
+use crate::server::body::DEFAULT_MAX_BODY_SIZE;
+use crate::server::config_watch::LinkTables;
 use crate::server::entity_registry::EntityRegistry;
+use crate::server::exposure::rest::idempotency::{
+    DEFAULT_IDEMPOTENCY_CACHE_SIZE, DEFAULT_IDEMPOTENCY_TTL,
+};
 use anyhow::Result;
+use arc_swap::ArcSwap;
+use axum::http::HeaderName;
+use axum::http::request::Parts;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
 
 /// Host context containing all framework state
 ///
@@ -42,15 +70,13 @@ use std::sync::Arc;
 /// let graphql_app = GraphQLExposure::build_router(host_arc)?;
 /// ```
 pub struct ServerHost {
-    /// Merged configuration from all modules
-    pub config: Arc<LinksConfig>,
+    /// Merged configuration from all modules and the link route registry
+    /// built from it, swapped together when the configuration is reloaded
+    links: Arc<ArcSwap<LinkTables>>,
 
     /// Link service for relationship management
     pub link_service: Arc<dyn LinkService>,
 
-    /// Link route registry for semantic URL resolution
-    pub registry: Arc<LinkRouteRegistry>,
-
     /// Entity registry for CRUD routes
     pub entity_registry: EntityRegistry,
 
@@ -95,6 +121,75 @@ pub struct ServerHost {
     /// Stores per-user notification preferences (mute, disable types).
     /// Used by sinks to filter notifications and by preference endpoints.
     pub preferences_store: Option<Arc<NotificationPreferencesStore>>,
+
+    /// Optional entity history store
+    ///
+    /// When present, REST exposes `/{entity}/{id}/history` and
+    /// `/{entity}/{id}/versions/{n}`.
+    pub history_service: Option<Arc<dyn HistoryService>>,
+
+    /// Optional audit log
+    ///
+    /// When present, REST entity and link mutations are recorded in it.
+    pub audit_log: Option<Arc<dyn AuditLogService>>,
+
+    /// Optional event outbox
+    ///
+    /// When present, the events recorded by the outbox-writing services
+    /// reach the event bus only through the outbox poller.
+    pub outbox: Option<Arc<dyn OutboxService>>,
+
+    /// How enriched links report source/target entities that could not be loaded
+    pub enrichment_fallback: EnrichmentFallback,
+
+    /// How many entity types are fetched at once during enrichment
+    pub enrichment_concurrency: usize,
+
+    /// Canonicalizes entity ids received in paths and bodies
+    pub id_normalizer: Arc<dyn IdNormalizer>,
+
+    /// Optional auth provider enforcing link auth policies
+    pub auth_provider: Option<Arc<dyn AuthProvider>>,
+
+    /// Header naming the tenant of each request, when tenancy is enabled
+    pub tenant_header: Option<HeaderName>,
+
+    /// Whether the REST exposure logs every request (on by default)
+    pub request_logging: bool,
+
+    /// How many `Idempotency-Key`s the REST exposure remembers
+    pub idempotency_cache_size: usize,
+
+    /// How long the response to an `Idempotency-Key` is replayed
+    pub idempotency_ttl: Duration,
+
+    /// Default and maximum page size of REST list endpoints
+    pub pagination: PaginationConfig,
+
+    /// Largest request or response body the REST exposure buffers, in bytes
+    pub max_body_size: usize,
+
+    /// Status transition of REST soft deletes and restores, when configured
+    pub soft_delete_status: Option<SoftDeleteStatus>,
+
+    /// Whether REST link routes accept a link identical to a live one
+    pub allow_duplicate_links: bool,
+
+    /// How ids of new links, and of entities created without one, are minted
+    pub id_strategy: IdStrategy,
+
+    /// Path the REST router is nested under, e.g. `/api/v1`
+    pub route_prefix: Option<String>,
+
+    /// Backend checks run by the `GET /health` readiness probe
+    pub health_checks: Vec<Arc<dyn HealthCheck>>,
+
+    /// Module owning each entity type, whose lifecycle hooks run on mutations
+    pub entity_modules: Arc<HashMap<String, Arc<dyn Module>>>,
+
+    /// Optional JSON Schemas validating entity create/update payloads
+    #[cfg(feature = "json-schema")]
+    pub entity_schemas: Option<Arc<EntitySchemas>>,
 }
 
 impl ServerHost {
@@ -121,13 +216,9 @@ impl ServerHost {
         fetchers: HashMap<String, Arc<dyn EntityFetcher>>,
         creators: HashMap<String, Arc<dyn EntityCreator>>,
     ) -> Result<Self> {
-        let config = Arc::new(config);
-        let registry = Arc::new(LinkRouteRegistry::new(config.clone()));
-
         Ok(Self {
-            config,
+            links: Arc::new(ArcSwap::from_pointee(LinkTables::new(config))),
             link_service,
-            registry,
             entity_registry,
             entity_fetchers: Arc::new(fetchers),
             entity_creators: Arc::new(creators),
@@ -137,9 +228,290 @@ impl ServerHost {
             notification_store: None,
             device_token_store: None,
             preferences_store: None,
+            history_service: None,
+            audit_log: None,
+            outbox: None,
+            enrichment_fallback: EnrichmentFallback::default(),
+            enrichment_concurrency: DEFAULT_ENRICHMENT_CONCURRENCY,
+            id_normalizer: Arc::new(DefaultIdNormalizer),
+            auth_provider: None,
+            tenant_header: None,
+            request_logging: true,
+            idempotency_cache_size: DEFAULT_IDEMPOTENCY_CACHE_SIZE,
+            idempotency_ttl: DEFAULT_IDEMPOTENCY_TTL,
+            pagination: PaginationConfig::default(),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            soft_delete_status: None,
+            allow_duplicate_links: false,
+            id_strategy: IdStrategy::default(),
+            route_prefix: None,
+            health_checks: Vec::new(),
+            entity_modules: Arc::new(HashMap::new()),
+            #[cfg(feature = "json-schema")]
+            entity_schemas: None,
         })
     }
 
+    /// Current merged configuration
+    pub fn config(&self) -> Arc<LinksConfig> {
+        self.links.load().config.clone()
+    }
+
+    /// Current link route registry for semantic URL resolution
+    pub fn registry(&self) -> Arc<LinkRouteRegistry> {
+        self.links.load().registry.clone()
+    }
+
+    /// Create a link, enforcing the cardinality of its definition
+    ///
+    /// The definition is the configured link of the same `link_type` whose
+    /// entity types match those the link records, if any; the link takes
+    /// the definition's entity types. Links without a definition are
+    /// many-to-many. A link the cardinality rejects fails with
+    /// [`LinkError::AlreadyExists`], as on the REST routes. The link gets a
+    /// fresh id from the host's [`IdStrategy`].
+    pub async fn create_link(&self, mut link: LinkEntity) -> Result<LinkEntity> {
+        link.id = self.id_strategy.generate();
+        let definition = self.link_definition(&link);
+        if let Some(def) = &definition {
+            link = link.with_entity_types(&def.source_type, &def.target_type);
+        }
+        let cardinality = definition
+            .and_then(|def| def.cardinality)
+            .unwrap_or_default();
+        let link_type = link.link_type.clone();
+        self.link_service
+            .create_with_cardinality(link, cardinality, self.allow_duplicate_links)
+            .await?
+            .ok_or_else(|| {
+                LinkError::AlreadyExists {
+                    link_type,
+                    cardinality,
+                }
+                .into()
+            })
+    }
+
+    /// Update a link's metadata, with the checks of the REST link routes
+    ///
+    /// `metadata` replaces the stored metadata or, with `merge`, is merged
+    /// into it (see [`LinkEntity::apply_metadata`]). The link must belong to
+    /// `tenant`, if given, and the caller must pass the `update` policy of
+    /// the link's definition when an auth provider is configured; a rejected
+    /// caller fails with [`OwnershipError`](ownership::OwnershipError). The
+    /// merged metadata must keep the definition's required fields and
+    /// `metadata_schema` ([`ValidationError`](crate::core::validation::ValidationError)
+    /// otherwise). The update is audited and published as
+    /// [`LinkEvent::Updated`]. Returns `None` if no such link is visible.
+    pub async fn update_link(
+        &self,
+        link_id: &Uuid,
+        metadata: Option<Value>,
+        merge: bool,
+        context: &AuthContext,
+        tenant: Option<&TenantContext>,
+    ) -> Result<Option<LinkEntity>> {
+        let Some(mut link) = self.link_service.get(link_id).await? else {
+            return Ok(None);
+        };
+        if tenant.is_some_and(|tenant| !tenant.owns(link.tenant_id)) {
+            return Ok(None);
+        }
+
+        let definition = self.link_definition(&link);
+        if let (Some(provider), Some(def)) = (&self.auth_provider, &definition)
+            && let Some(policy) = AppState::get_link_auth_policy(def, "update")
+        {
+            let route = format!("update link {}", def.link_type);
+            let allowed = AuthPolicy::parse_policy(&policy)
+                .evaluate_for(
+                    context,
+                    provider.as_ref(),
+                    &def.source_type,
+                    &link.source_id,
+                    &route,
+                )
+                .await?;
+            if !allowed {
+                return Err(match context {
+                    AuthContext::Anonymous => ownership::OwnershipError::Unauthenticated,
+                    _ => ownership::OwnershipError::Forbidden(route),
+                }
+                .into());
+            }
+        }
+
+        let before = link.clone();
+        link.apply_metadata(metadata, merge);
+        if let Some(def) = &definition {
+            def.validate_metadata(LinkDirection::Forward, link.metadata.as_ref())?;
+        }
+        link.touch();
+        let updated = self.link_service.update(link_id, link).await?;
+
+        if let Some(bus) = &self.event_bus {
+            bus.publish(FrameworkEvent::Link(LinkEvent::Updated {
+                link_type: updated.link_type.clone(),
+                link_id: updated.id,
+                source_id: updated.source_id,
+                target_id: updated.target_id,
+                metadata: updated.metadata.clone(),
+            }));
+        }
+        if let Some(audit_log) = &self.audit_log {
+            let snapshot = |link: &LinkEntity| serde_json::to_value(link).ok();
+            let entry = AuditEntry::new(
+                LINK_ENTITY_TYPE,
+                updated.id,
+                AuditOperation::Update,
+                self.auth_provider.as_ref().map(|_| context.subject()),
+                snapshot(&before),
+                snapshot(&updated),
+            );
+            if let Err(e) = audit_log.record(entry).await {
+                tracing::warn!(error = %e, "failed to record audit entry");
+            }
+        }
+        Ok(Some(updated))
+    }
+
+    /// The configured definition of `link`: the link of the same type whose
+    /// entity types match those the link records, if any
+    fn link_definition(&self, link: &LinkEntity) -> Option<LinkDefinition> {
+        self.config()
+            .links
+            .iter()
+            .find(|def| {
+                def.link_type == link.link_type
+                    && link
+                        .source_type
+                        .as_deref()
+                        .is_none_or(|t| t == def.source_type)
+                    && link
+                        .target_type
+                        .as_deref()
+                        .is_none_or(|t| t == def.target_type)
+            })
+            .cloned()
+    }
+
+    /// The caller's auth context, read with the auth provider
+    ///
+    /// Without a provider, and for credentials it rejects, the caller is
+    /// anonymous.
+    pub async fn auth_context(&self, parts: &Parts) -> AuthContext {
+        match &self.auth_provider {
+            Some(provider) => provider
+                .extract_context(parts)
+                .await
+                .unwrap_or(AuthContext::Anonymous),
+            None => AuthContext::Anonymous,
+        }
+    }
+
+    /// Check the `owner` part of an entity policy for the caller
+    ///
+    /// `operation` is `"get"`, `"update"` or `"delete"`; the entity type's
+    /// policy for it is checked only when it involves `owner` and an auth
+    /// provider is configured, as on the REST routes. Returns the stored
+    /// owner of the entity (`None` if it has none, cannot be read, or no
+    /// provider is configured); a caller the policy rejects fails with
+    /// [`OwnershipError`](ownership::OwnershipError).
+    pub async fn authorize_owner(
+        &self,
+        entity_type: &str,
+        operation: &str,
+        entity_id: &Uuid,
+        context: &AuthContext,
+    ) -> Result<Option<Uuid>> {
+        if self.auth_provider.is_none() {
+            return Ok(None);
+        }
+        let stored = match self.entity_fetchers.get(entity_type) {
+            Some(fetcher) => fetcher.fetch_as_json(entity_id).await.ok(),
+            None => None,
+        };
+        let owner_id = stored.as_ref().and_then(ownership::stored_owner);
+
+        let config = self.config();
+        let policy = config
+            .entities
+            .iter()
+            .find(|e| e.singular == entity_type)
+            .and_then(|e| match operation {
+                "get" => ownership::owner_policy(&e.auth.get),
+                "update" => ownership::owner_policy(&e.auth.update),
+                "delete" => ownership::owner_policy(&e.auth.delete),
+                _ => None,
+            });
+        if let (Some(policy), Some(_)) = (policy, &stored) {
+            let route = format!("{} {} {}", operation, entity_type, entity_id);
+            ownership::check_owner(&policy, context, owner_id, &route)?;
+        }
+        Ok(owner_id)
+    }
+
+    /// Create an entity of `entity_type`, owned by the caller
+    ///
+    /// A client-supplied `owner_id` is dropped when an auth provider is
+    /// configured; see [`ownership`].
+    pub async fn create_entity(
+        &self,
+        entity_type: &str,
+        data: Value,
+        context: &AuthContext,
+    ) -> Result<Value> {
+        let creator = self.entity_creator(entity_type)?;
+        if self.auth_provider.is_none() {
+            return creator.create_from_json(data).await;
+        }
+        ownership::create_owned(creator.as_ref(), data, context.user_id()).await
+    }
+
+    /// Update an entity of `entity_type`, enforcing its `owner` policy
+    ///
+    /// The stored `owner_id` is kept whatever `data` says.
+    pub async fn update_entity(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        mut data: Value,
+        context: &AuthContext,
+    ) -> Result<Value> {
+        let creator = self.entity_creator(entity_type)?;
+        let owner_id = self
+            .authorize_owner(entity_type, "update", entity_id, context)
+            .await?;
+        if self.auth_provider.is_some() {
+            ownership::set_owner(&mut data, owner_id);
+        }
+        creator.update_from_json(entity_id, data).await
+    }
+
+    /// Delete an entity of `entity_type`, enforcing its `owner` policy
+    pub async fn delete_entity(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        context: &AuthContext,
+    ) -> Result<()> {
+        let creator = self.entity_creator(entity_type)?;
+        self.authorize_owner(entity_type, "delete", entity_id, context)
+            .await?;
+        creator.delete(entity_id).await
+    }
+
+    fn entity_creator(&self, entity_type: &str) -> Result<&Arc<dyn EntityCreator>> {
+        self.entity_creators
+            .get(entity_type)
+            .ok_or_else(|| anyhow::anyhow!("Unknown entity type: {}", entity_type))
+    }
+
+    /// Live configuration and registry, for readers that must follow reloads
+    pub fn link_tables(&self) -> &Arc<ArcSwap<LinkTables>> {
+        &self.links
+    }
+
     /// Get entity types registered in the host
     pub fn entity_types(&self) -> Vec<&str> {
         self.entity_registry.entity_types()
@@ -216,6 +588,140 @@ impl ServerHost {
         self.preferences_store.as_ref()
     }
 
+    /// Set the entity history store
+    pub fn with_history_service(mut self, service: Arc<dyn HistoryService>) -> Self {
+        self.history_service = Some(service);
+        self
+    }
+
+    /// Get a reference to the entity history store (if configured)
+    pub fn history_service(&self) -> Option<&Arc<dyn HistoryService>> {
+        self.history_service.as_ref()
+    }
+
+    /// Set the audit log
+    pub fn with_audit_log(mut self, service: Arc<dyn AuditLogService>) -> Self {
+        self.audit_log = Some(service);
+        self
+    }
+
+    /// Get a reference to the audit log (if configured)
+    pub fn audit_log(&self) -> Option<&Arc<dyn AuditLogService>> {
+        self.audit_log.as_ref()
+    }
+
+    /// Set the event outbox
+    pub fn with_outbox(mut self, outbox: Arc<dyn OutboxService>) -> Self {
+        self.outbox = Some(outbox);
+        self
+    }
+
+    /// Get a reference to the event outbox (if configured)
+    pub fn outbox(&self) -> Option<&Arc<dyn OutboxService>> {
+        self.outbox.as_ref()
+    }
+
+    /// Set how enriched links report entities that could not be loaded
+    pub fn with_enrichment_fallback(mut self, fallback: EnrichmentFallback) -> Self {
+        self.enrichment_fallback = fallback;
+        self
+    }
+
+    /// Set how many entity types are fetched at once during enrichment
+    pub fn with_enrichment_concurrency(mut self, concurrency: usize) -> Self {
+        self.enrichment_concurrency = concurrency;
+        self
+    }
+
+    /// Set how entity ids received from clients are canonicalized
+    pub fn with_id_normalizer(mut self, normalizer: Arc<dyn IdNormalizer>) -> Self {
+        self.id_normalizer = normalizer;
+        self
+    }
+
+    /// Set the module owning each entity type, for lifecycle hooks
+    pub fn with_entity_modules(mut self, modules: HashMap<String, Arc<dyn Module>>) -> Self {
+        self.entity_modules = Arc::new(modules);
+        self
+    }
+
+    /// Set the auth provider enforcing link auth policies
+    pub fn with_auth_provider(mut self, provider: Arc<dyn AuthProvider>) -> Self {
+        self.auth_provider = Some(provider);
+        self
+    }
+
+    /// Require every entity and link request to name its tenant in `header`
+    pub fn with_tenant_header(mut self, header: HeaderName) -> Self {
+        self.tenant_header = Some(header);
+        self
+    }
+
+    /// Turn the REST request log on or off
+    pub fn with_request_logging(mut self, enabled: bool) -> Self {
+        self.request_logging = enabled;
+        self
+    }
+
+    /// Set the size and entry lifetime of the REST idempotency cache
+    pub fn with_idempotency_cache(mut self, size: usize, ttl: Duration) -> Self {
+        self.idempotency_cache_size = size;
+        self.idempotency_ttl = ttl;
+        self
+    }
+
+    /// Set the default and maximum page size of REST list endpoints
+    pub fn with_pagination(mut self, pagination: PaginationConfig) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    /// Set the largest request or response body the REST exposure buffers
+    pub fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = bytes;
+        self
+    }
+
+    /// Set the status transition of soft deletes and restores
+    pub fn with_soft_delete_status(mut self, status: SoftDeleteStatus) -> Self {
+        self.soft_delete_status = Some(status);
+        self
+    }
+
+    /// Accept or reject links identical to a live one
+    pub fn with_allow_duplicate_links(mut self, allow: bool) -> Self {
+        self.allow_duplicate_links = allow;
+        self
+    }
+
+    /// Set how ids of new links are minted
+    ///
+    /// Entity ids are minted by the creators; the server builder wraps them
+    /// in an [`IdStrategyCreator`](crate::core::ids::IdStrategyCreator).
+    pub fn with_id_strategy(mut self, strategy: IdStrategy) -> Self {
+        self.id_strategy = strategy;
+        self
+    }
+
+    /// Nest the REST router under `prefix`
+    pub fn with_route_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.route_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Set the backend checks run by the readiness probe
+    pub fn with_health_checks(mut self, checks: Vec<Arc<dyn HealthCheck>>) -> Self {
+        self.health_checks = checks;
+        self
+    }
+
+    /// Set the JSON Schemas used to validate entity payloads
+    #[cfg(feature = "json-schema")]
+    pub fn with_entity_schemas(mut self, schemas: Arc<EntitySchemas>) -> Self {
+        self.entity_schemas = Some(schemas);
+        self
+    }
+
     /// Create a minimal `ServerHost` for unit tests.
     ///
     /// Has empty registries and a mock `LinkService`. Useful for testing
@@ -274,14 +780,11 @@ impl ServerHost {
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         };
-        let config = Arc::new(config);
-        let registry = Arc::new(LinkRouteRegistry::new(config.clone()));
-
         Self {
-            config,
+            links: Arc::new(ArcSwap::from_pointee(LinkTables::new(config))),
             link_service: Arc::new(NoopLinkService),
-            registry,
             entity_registry: EntityRegistry::new(),
             entity_fetchers: Arc::new(HashMap::new()),
             entity_creators: Arc::new(HashMap::new()),
@@ -291,6 +794,27 @@ impl ServerHost {
             notification_store: None,
             device_token_store: None,
             preferences_store: None,
+            history_service: None,
+            audit_log: None,
+            outbox: None,
+            enrichment_fallback: EnrichmentFallback::default(),
+            enrichment_concurrency: DEFAULT_ENRICHMENT_CONCURRENCY,
+            id_normalizer: Arc::new(DefaultIdNormalizer),
+            auth_provider: None,
+            tenant_header: None,
+            request_logging: true,
+            idempotency_cache_size: DEFAULT_IDEMPOTENCY_CACHE_SIZE,
+            idempotency_ttl: DEFAULT_IDEMPOTENCY_TTL,
+            pagination: PaginationConfig::default(),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            soft_delete_status: None,
+            allow_duplicate_links: false,
+            id_strategy: IdStrategy::default(),
+            route_prefix: None,
+            health_checks: Vec::new(),
+            entity_modules: Arc::new(HashMap::new()),
+            #[cfg(feature = "json-schema")]
+            entity_schemas: None,
         }
     }
 }
@@ -299,7 +823,6 @@ impl ServerHost {
 mod tests {
     use super::*;
     use crate::config::{EntityAuthConfig, EntityConfig};
-    use crate::core::link::LinkEntity;
 
     /// Minimal mock LinkService for testing
     struct MockLinkService;
@@ -348,11 +871,15 @@ mod tests {
                 singular: "order".to_string(),
                 plural: "orders".to_string(),
                 auth: EntityAuthConfig::default(),
+                id_policy: Default::default(),
+                fields: Default::default(),
+                min_update_interval: None,
             }],
             links: vec![],
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         }
     }
 
@@ -402,15 +929,15 @@ mod tests {
     #[test]
     fn test_config_accessible_from_host() {
         let host = make_host();
-        assert_eq!(host.config.entities.len(), 1);
-        assert_eq!(host.config.entities[0].singular, "order");
+        assert_eq!(host.config().entities.len(), 1);
+        assert_eq!(host.config().entities[0].singular, "order");
     }
 
     #[test]
     fn test_registry_built_from_config() {
         let host = make_host();
         // Registry should exist and be built from config
-        let routes = host.registry.list_routes_for_entity("order");
+        let routes = host.registry().list_routes_for_entity("order");
         // No links → no routes, but it shouldn't panic
         assert!(routes.is_empty());
     }
@@ -446,6 +973,304 @@ mod tests {
         assert!(host.is_ready());
     }
 
+    #[tokio::test]
+    async fn test_create_link_enforces_the_definition_cardinality() {
+        let config = LinksConfig::from_yaml_str(
+            r#"
+entities:
+  - singular: user
+    plural: users
+  - singular: car
+    plural: cars
+links:
+  - link_type: owner
+    source_type: user
+    target_type: car
+    forward_route_name: cars-owned
+    reverse_route_name: users-owners
+    cardinality: one_to_many
+  - link_type: driver
+    source_type: user
+    target_type: car
+    forward_route_name: cars-driven
+    reverse_route_name: users-drivers
+"#,
+        )
+        .unwrap();
+        let host = ServerHost::from_builder_components(
+            Arc::new(crate::storage::InMemoryLinkService::new()),
+            config,
+            EntityRegistry::new(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .expect("should build host");
+        let car = uuid::Uuid::new_v4();
+
+        let owner = host
+            .create_link(LinkEntity::new("owner", uuid::Uuid::new_v4(), car, None))
+            .await
+            .expect("first owner should be linked");
+        assert_eq!(owner.source_type.as_deref(), Some("user"));
+        assert_eq!(owner.target_type.as_deref(), Some("car"));
+
+        let err = host
+            .create_link(LinkEntity::new("owner", uuid::Uuid::new_v4(), car, None))
+            .await
+            .expect_err("a car has one owner");
+        assert!(matches!(
+            err.downcast_ref::<LinkError>(),
+            Some(LinkError::AlreadyExists { .. })
+        ));
+
+        for _ in 0..2 {
+            host.create_link(LinkEntity::new("driver", uuid::Uuid::new_v4(), car, None))
+                .await
+                .expect("a car has any number of drivers");
+        }
+    }
+
+    /// Notes kept as JSON, for the ownership tests
+    #[derive(Default)]
+    struct NoteStore(std::sync::Mutex<HashMap<Uuid, Value>>);
+
+    #[async_trait::async_trait]
+    impl EntityFetcher for NoteStore {
+        async fn fetch_as_json(&self, entity_id: &Uuid) -> anyhow::Result<Value> {
+            self.0
+                .lock()
+                .unwrap()
+                .get(entity_id)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("not found"))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl EntityCreator for NoteStore {
+        async fn create_from_json(&self, mut data: Value) -> anyhow::Result<Value> {
+            let id = Uuid::new_v4();
+            data["id"] = serde_json::json!(id);
+            self.0.lock().unwrap().insert(id, data.clone());
+            Ok(data)
+        }
+
+        async fn update_from_json(
+            &self,
+            entity_id: &Uuid,
+            mut data: Value,
+        ) -> anyhow::Result<Value> {
+            data["id"] = serde_json::json!(entity_id);
+            self.0.lock().unwrap().insert(*entity_id, data.clone());
+            Ok(data)
+        }
+
+        async fn delete(&self, entity_id: &Uuid) -> anyhow::Result<()> {
+            self.0.lock().unwrap().remove(entity_id);
+            Ok(())
+        }
+    }
+
+    /// Accepts every request as the user named by `x-user-id`
+    struct HeaderAuthProvider;
+
+    #[async_trait::async_trait]
+    impl AuthProvider for HeaderAuthProvider {
+        async fn extract_context(&self, parts: &Parts) -> anyhow::Result<AuthContext> {
+            let Some(user_id) = parts.headers.get("x-user-id") else {
+                return Ok(AuthContext::Anonymous);
+            };
+            Ok(AuthContext::User {
+                user_id: user_id.to_str()?.parse()?,
+                tenant_id: Uuid::nil(),
+                roles: vec![],
+            })
+        }
+
+        async fn is_owner(&self, _: &Uuid, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+
+        async fn has_role(&self, _: &Uuid, _: &str) -> anyhow::Result<bool> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entity_mutations_enforce_owner_policies() {
+        let mut config = test_config();
+        config.entities[0].singular = "note".to_string();
+        config.entities[0].plural = "notes".to_string();
+        config.entities[0].auth = EntityAuthConfig {
+            update: "owner".to_string(),
+            delete: "owner".to_string(),
+            ..EntityAuthConfig::default()
+        };
+        let store = Arc::new(NoteStore::default());
+        let host = ServerHost::from_builder_components(
+            Arc::new(MockLinkService),
+            config,
+            EntityRegistry::new(),
+            HashMap::from([("note".to_string(), store.clone() as Arc<dyn EntityFetcher>)]),
+            HashMap::from([("note".to_string(), store as Arc<dyn EntityCreator>)]),
+        )
+        .unwrap()
+        .with_auth_provider(Arc::new(HeaderAuthProvider));
+        let user = |user_id| AuthContext::User {
+            user_id,
+            tenant_id: Uuid::nil(),
+            roles: vec![],
+        };
+        let (alice, mallory) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let note = host
+            .create_entity(
+                "note",
+                serde_json::json!({ "text": "hi", "owner_id": mallory }),
+                &user(alice),
+            )
+            .await
+            .unwrap();
+        assert_eq!(note["owner_id"], serde_json::json!(alice));
+        let id: Uuid = note["id"].as_str().unwrap().parse().unwrap();
+
+        let err = host
+            .update_entity(
+                "note",
+                &id,
+                serde_json::json!({ "text": "x" }),
+                &user(mallory),
+            )
+            .await
+            .expect_err("only the owner may update");
+        assert!(matches!(
+            err.downcast_ref::<ownership::OwnershipError>(),
+            Some(ownership::OwnershipError::Forbidden(_))
+        ));
+        let err = host
+            .delete_entity("note", &id, &AuthContext::Anonymous)
+            .await
+            .expect_err("anonymous callers own nothing");
+        assert!(matches!(
+            err.downcast_ref::<ownership::OwnershipError>(),
+            Some(ownership::OwnershipError::Unauthenticated)
+        ));
+
+        let updated = host
+            .update_entity(
+                "note",
+                &id,
+                serde_json::json!({ "text": "bye", "owner_id": mallory }),
+                &user(alice),
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated["text"], "bye");
+        assert_eq!(updated["owner_id"], serde_json::json!(alice));
+        host.delete_entity("note", &id, &user(alice)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_link_applies_the_link_route_checks() {
+        let mut config = test_config();
+        config.links = vec![
+            serde_json::from_value(serde_json::json!({
+                "link_type": "owner",
+                "source_type": "user",
+                "target_type": "car",
+                "forward_route_name": "cars-owned",
+                "reverse_route_name": "users-owners",
+                "required_fields": ["since"],
+                "auth": {"update": "authenticated"}
+            }))
+            .unwrap(),
+        ];
+        let links = Arc::new(crate::storage::InMemoryLinkService::new());
+        let host = ServerHost::from_builder_components(
+            links.clone(),
+            config,
+            EntityRegistry::new(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap()
+        .with_auth_provider(Arc::new(HeaderAuthProvider))
+        .with_event_bus(EventBus::new(16));
+        let mut events = host.event_bus().unwrap().subscribe();
+
+        let tenant = TenantContext::new(Uuid::new_v4());
+        let mut link = LinkEntity::new(
+            "owner",
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Some(serde_json::json!({"since": 2020})),
+        )
+        .with_entity_types("user", "car");
+        link.tenant_id = Some(tenant.tenant_id);
+        let link = links.create(link).await.unwrap();
+        let user = AuthContext::User {
+            user_id: Uuid::new_v4(),
+            tenant_id: Uuid::nil(),
+            roles: vec![],
+        };
+        let update = |metadata: Value, context: AuthContext, tenant: TenantContext| {
+            let host = &host;
+            async move {
+                host.update_link(&link.id, Some(metadata), true, &context, Some(&tenant))
+                    .await
+            }
+        };
+
+        let err = update(
+            serde_json::json!({"since": 2021}),
+            AuthContext::Anonymous,
+            tenant,
+        )
+        .await
+        .expect_err("the update policy requires a caller");
+        assert!(matches!(
+            err.downcast_ref::<ownership::OwnershipError>(),
+            Some(ownership::OwnershipError::Unauthenticated)
+        ));
+
+        let other_tenant = TenantContext::new(Uuid::new_v4());
+        let hidden = update(
+            serde_json::json!({"since": 2021}),
+            user.clone(),
+            other_tenant,
+        )
+        .await
+        .unwrap();
+        assert!(hidden.is_none());
+
+        let err = update(serde_json::json!({"since": null}), user.clone(), tenant)
+            .await
+            .expect_err("`since` is required");
+        assert!(
+            err.downcast_ref::<crate::core::validation::ValidationError>()
+                .is_some()
+        );
+        assert_eq!(
+            links.get(&link.id).await.unwrap().unwrap().metadata,
+            Some(serde_json::json!({"since": 2020}))
+        );
+        assert!(events.try_recv().is_err());
+
+        let updated = update(serde_json::json!({"plate": "AB-123"}), user, tenant)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated.metadata,
+            Some(serde_json::json!({"since": 2020, "plate": "AB-123"}))
+        );
+        let envelope = events.try_recv().expect("the update is published");
+        assert!(matches!(
+            envelope.event,
+            FrameworkEvent::Link(LinkEvent::Updated { link_id, .. }) if link_id == link.id
+        ));
+    }
+
     #[test]
     fn test_entity_creators_accessible() {
         let host = make_host();
@@ -12,16 +12,24 @@
 //! - WebSocket (available with 'websocket' feature)
 //! - OpenAPI (planned)
 
+pub mod body;
 pub mod builder;
+pub mod config_watch;
+pub mod cors;
 pub mod entity_registry;
 pub mod exposure;
 pub mod host;
+pub mod rate_limit;
 pub mod router;
+pub mod timestamps;
 
 pub use builder::ServerBuilder;
+pub use cors::CorsConfig;
 pub use entity_registry::{EntityDescriptor, EntityRegistry};
 pub use exposure::RestExposure;
 pub use host::ServerHost;
+pub use rate_limit::RateKey;
+pub use timestamps::TimestampFormat;
 
 #[cfg(feature = "graphql")]
 pub use exposure::GraphQLExposure;
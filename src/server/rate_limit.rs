@@ -0,0 +1,179 @@
+//! Per-client rate limiting
+//!
+//! Each client gets a token bucket holding up to `requests` tokens, refilled
+//! continuously so that a full bucket is regained over `per`. A request takes
+//! one token; a client with an empty bucket gets `429 Too Many Requests`, with
+//! a `RATE_LIMITED` error body and a `Retry-After` header saying when the next
+//! token arrives. Clients are told
+//! apart by IP address or by a request header such as an API key, see
+//! [`RateKey`]. Buckets left idle long enough to be full again are dropped,
+//! which bounds memory to the clients active within the last window.
+
+use crate::core::extractors::error_response;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// What identifies a client for rate limiting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RateKey {
+    /// The peer IP address
+    ///
+    /// Needs the connection info of
+    /// [`ServerBuilder::serve`](crate::server::ServerBuilder::serve); behind
+    /// a proxy every request shares the proxy's address.
+    Ip,
+    /// The value of a request header, e.g. `x-api-key`
+    ///
+    /// Requests without the header are keyed by IP address.
+    Header(String),
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Token buckets of the clients seen recently
+pub struct RateLimiter {
+    capacity: f64,
+    per: Duration,
+    key: RateKey,
+    buckets: Mutex<Buckets>,
+}
+
+struct Buckets {
+    by_client: HashMap<String, Bucket>,
+    swept_at: Instant,
+}
+
+impl RateLimiter {
+    /// Allow `requests` requests per `per` to each client
+    pub fn new(requests: u32, per: Duration, key: RateKey) -> Self {
+        Self {
+            capacity: f64::from(requests.max(1)),
+            per: per.max(Duration::from_millis(1)),
+            key,
+            buckets: Mutex::new(Buckets {
+                by_client: HashMap::new(),
+                swept_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// The client of `request`, or `None` if it cannot be identified
+    fn client(&self, request: &Request) -> Option<String> {
+        if let RateKey::Header(name) = &self.key
+            && let Some(value) = request.headers().get(name.as_str())
+        {
+            return Some(format!(
+                "header:{}",
+                String::from_utf8_lossy(value.as_bytes())
+            ));
+        }
+        request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| format!("ip:{}", addr.ip()))
+    }
+
+    /// Take a token from `client`'s bucket, or tell how long until one is available
+    fn acquire(&self, client: String, now: Instant) -> Result<(), Duration> {
+        let refill_per_sec = self.capacity / self.per.as_secs_f64();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        // An idle bucket refilled completely: forgetting it changes nothing
+        if now.duration_since(buckets.swept_at) >= self.per {
+            let per = self.per;
+            buckets
+                .by_client
+                .retain(|_, bucket| now.duration_since(bucket.updated_at) < per);
+            buckets.swept_at = now;
+        }
+
+        let bucket = buckets.by_client.entry(client).or_insert(Bucket {
+            tokens: self.capacity,
+            updated_at: now,
+        });
+        let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_sec).min(self.capacity);
+        bucket.updated_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / refill_per_sec,
+            ))
+        }
+    }
+}
+
+/// Middleware answering `429 Too Many Requests` to clients over their limit
+pub async fn rate_limit_middleware(
+    State(limiter): State<std::sync::Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let client = limiter.client(&request).unwrap_or_default();
+    match limiter.acquire(client, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(wait) => {
+            let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+            let mut response = error_response(
+                StatusCode::TOO_MANY_REQUESTS,
+                "RATE_LIMITED",
+                "Too many requests",
+            );
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_refills_over_the_window() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(10), RateKey::Ip);
+        let start = Instant::now();
+        assert!(limiter.acquire("a".into(), start).is_ok());
+        assert!(limiter.acquire("a".into(), start).is_ok());
+        assert_eq!(
+            limiter.acquire("a".into(), start),
+            Err(Duration::from_secs(5))
+        );
+        // Other clients have their own bucket
+        assert!(limiter.acquire("b".into(), start).is_ok());
+
+        assert!(
+            limiter
+                .acquire("a".into(), start + Duration::from_secs(5))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_idle_buckets_are_dropped() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(1), RateKey::Ip);
+        let start = Instant::now();
+        for client in ["a", "b", "c"] {
+            limiter.acquire(client.into(), start).unwrap();
+        }
+        limiter
+            .acquire("d".into(), start + Duration::from_secs(2))
+            .unwrap();
+        let buckets = limiter.buckets.lock().unwrap();
+        assert_eq!(buckets.by_client.len(), 1);
+    }
+}
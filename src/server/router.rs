@@ -2,10 +2,21 @@
 
 use crate::core::query::QueryParams;
 use crate::links::handlers::{
-    AppState, create_link, create_linked_entity, delete_link, get_link, get_link_by_route,
-    handle_nested_path_get, list_available_links, list_links, update_link,
+    AppState, RequestAuth, create_link, create_link_by_type, create_linked_entity, delete_link,
+    delete_links_by_route, delete_links_where, get_link, get_link_by_route, handle_nested_path_get,
+    list_available_links, list_links, list_relations, update_link,
 };
-use axum::{Router, extract::Query, routing::get};
+use crate::server::config_watch::LinkTables;
+use arc_swap::ArcSwap;
+use axum::{
+    Router,
+    extract::{FromRef, MatchedPath, Query, Request},
+    middleware::Next,
+    response::Response,
+    routing::{get, post},
+};
+use std::sync::Arc;
+use std::time::Instant;
 
 /// Combine a REST router and a gRPC router into a single router.
 ///
@@ -51,14 +62,17 @@ pub fn combine_rest_and_grpc(rest_router: Router, grpc_router: Router) -> Router
 /// Build link routes from configuration
 ///
 /// These routes are generic and work for all entities using semantic route_names:
+/// - POST /links - Create a link by link_type (`{link_type, source_id, target_id, metadata}`)
 /// - GET /links/{link_id} - Get a specific link by ID
 /// - GET /{entity_type}/{entity_id}/{route_name} - List links (e.g., /users/123/cars-owned)
 /// - POST /{entity_type}/{entity_id}/{route_name} - Create new entity + link (entity + metadata in body)
+/// - DELETE /{entity_type}/{entity_id}/{route_name} - Delete every link of the route for the entity
 /// - GET /{source_type}/{source_id}/{route_name}/{target_id} - Get a specific link (e.g., /users/123/cars-owned/456)
 /// - POST /{source_type}/{source_id}/{route_name}/{target_id} - Create link between existing entities
 /// - PUT /{source_type}/{source_id}/{route_name}/{target_id} - Update link metadata
 /// - DELETE /{source_type}/{source_id}/{route_name}/{target_id} - Delete link
 /// - GET /{entity_type}/{entity_id}/links - List available link types
+/// - GET /{entity_type}/{entity_id}/relations - List all links of an entity (`?direction=in|out|both`)
 ///
 /// NOTE: Nested routes are supported up to 2 levels automatically:
 /// - GET /{entity_type}/{entity_id}/{route_name} - List linked entities
@@ -69,6 +83,45 @@ pub fn combine_rest_and_grpc(rest_router: Router, grpc_router: Router) -> Router
 /// The route_name (e.g., "cars-owned", "cars-driven") is resolved to the appropriate
 /// link_type (e.g., "owner", "driver") automatically by the LinkRouteRegistry.
 pub fn build_link_routes(state: AppState) -> Router {
+    link_router().with_state(state)
+}
+
+/// Link routes whose config and registry are read from `tables` on every request
+///
+/// Same routes as [`build_link_routes`]; `state` provides everything else.
+/// Used when the link configuration is hot-reloaded (see
+/// [`config_watch`](crate::server::config_watch)).
+pub fn build_live_link_routes(state: AppState, tables: Arc<ArcSwap<LinkTables>>) -> Router {
+    link_router().with_state(LiveLinkState {
+        base: state,
+        tables,
+    })
+}
+
+/// [`AppState`] with a swappable config and registry
+#[derive(Clone)]
+struct LiveLinkState {
+    base: AppState,
+    tables: Arc<ArcSwap<LinkTables>>,
+}
+
+impl FromRef<LiveLinkState> for AppState {
+    fn from_ref(live: &LiveLinkState) -> Self {
+        let tables = live.tables.load();
+        AppState {
+            config: tables.config.clone(),
+            registry: tables.registry.clone(),
+            ..live.base.clone()
+        }
+    }
+}
+
+fn link_router<S>() -> Router<S>
+where
+    AppState: FromRef<S>,
+    S: Clone + Send + Sync + 'static,
+{
+    use crate::core::TenantContext;
     use axum::extract::{Path as AxumPath, Request, State as AxumState};
     use axum::response::IntoResponse;
     use uuid::Uuid;
@@ -80,21 +133,31 @@ pub fn build_link_routes(state: AppState) -> Router {
         Uuid,
         String,
     )>,
+                         auth: RequestAuth,
                          Query(params): Query<QueryParams>,
                          req: Request| async move {
         let path = req.uri().path();
         let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let tenant = req.extensions().get::<TenantContext>().copied();
 
         // Si plus de 3 segments, c'est une route imbriquée à 3+ niveaux
         if segments.len() >= 5 {
             // Utiliser le handler générique pour chemins profonds (with pagination)
-            handle_nested_path_get(AxumState(state), AxumPath(path.to_string()), Query(params))
-                .await
-                .map(|r| r.into_response())
+            handle_nested_path_get(
+                AxumState(state),
+                auth,
+                tenant,
+                AxumPath(path.to_string()),
+                Query(params),
+            )
+            .await
+            .map(|r| r.into_response())
         } else {
             // Route classique à 2 niveaux - with pagination
             list_links(
                 AxumState(state),
+                auth,
+                tenant,
                 AxumPath((entity_type_plural, entity_id, route_name)),
                 Query(params),
             )
@@ -105,19 +168,33 @@ pub fn build_link_routes(state: AppState) -> Router {
 
     // Handler fallback pour les autres cas (with pagination)
     let fallback_handler = |AxumState(state): AxumState<AppState>,
+                            auth: RequestAuth,
                             Query(params): Query<QueryParams>,
                             req: Request| async move {
         let path = req.uri().path().to_string();
-        handle_nested_path_get(AxumState(state), AxumPath(path), Query(params))
-            .await
-            .map(|r| r.into_response())
+        let tenant = req.extensions().get::<TenantContext>().copied();
+        handle_nested_path_get(
+            AxumState(state),
+            auth,
+            tenant,
+            AxumPath(path),
+            Query(params),
+        )
+        .await
+        .map(|r| r.into_response())
     };
 
     Router::new()
+        .route(
+            "/links",
+            post(create_link_by_type).delete(delete_links_where),
+        )
         .route("/links/{link_id}", get(get_link))
         .route(
             "/{entity_type}/{entity_id}/{route_name}",
-            get(smart_handler).post(create_linked_entity),
+            get(smart_handler)
+                .post(create_linked_entity)
+                .delete(delete_links_by_route),
         )
         .route(
             "/{source_type}/{source_id}/{route_name}/{target_id}",
@@ -130,14 +207,61 @@ pub fn build_link_routes(state: AppState) -> Router {
             "/{entity_type}/{entity_id}/links",
             get(list_available_links),
         )
+        .route("/{entity_type}/{entity_id}/relations", get(list_relations))
         .fallback(fallback_handler)
-        .with_state(state)
+}
+
+/// Log each request with its status and duration
+///
+/// Emits one `info` event per request with `method`, `path`, `status` and
+/// `elapsed_ms`. When routing matched a route, `route` holds its template
+/// (e.g. `/orders/{id}`) and, for link routes, `route_name` the link route
+/// segment of the path. Installed by
+/// [`RestExposure::build_router`](crate::server::exposure::RestExposure::build_router)
+/// unless disabled with
+/// [`ServerBuilder::with_request_logging`](crate::server::ServerBuilder::with_request_logging).
+pub async fn request_logging_middleware(request: Request, next: Next) -> Response {
+    let started = Instant::now();
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string());
+    let route_name = route
+        .as_deref()
+        .and_then(|template| path_param(template, &path, "route_name"))
+        .map(str::to_string);
+
+    let response = next.run(request).await;
+
+    tracing::info!(
+        method = %method,
+        path = %path,
+        status = response.status().as_u16(),
+        elapsed_ms = started.elapsed().as_secs_f64() * 1000.0,
+        route = %route.as_deref().unwrap_or(""),
+        route_name = %route_name.as_deref().unwrap_or(""),
+        "request"
+    );
+    response
+}
+
+/// The segment of `path` at the position of `{name}` in the route `template`
+fn path_param<'a>(template: &str, path: &'a str, name: &str) -> Option<&'a str> {
+    let placeholder = format!("{{{}}}", name);
+    template
+        .split('/')
+        .zip(path.split('/'))
+        .find(|(segment, _)| *segment == placeholder)
+        .map(|(_, value)| value)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::LinksConfig;
+    use crate::core::IdStrategy;
     use crate::core::events::EventBus;
     use crate::links::handlers::AppState;
     use crate::links::registry::LinkRouteRegistry;
@@ -156,6 +280,13 @@ mod tests {
             entity_fetchers: Arc::new(HashMap::new()),
             entity_creators: Arc::new(HashMap::new()),
             event_bus: None,
+            enrichment_fallback: Default::default(),
+            enrichment_concurrency: crate::links::DEFAULT_ENRICHMENT_CONCURRENCY,
+            auth_provider: None,
+            audit_log: None,
+            allow_duplicate_links: false,
+            id_strategy: IdStrategy::default(),
+            route_prefix: String::new(),
         }
     }
 
@@ -178,6 +309,13 @@ mod tests {
             entity_fetchers: Arc::new(HashMap::new()),
             entity_creators: Arc::new(HashMap::new()),
             event_bus: Some(Arc::new(EventBus::new(16))),
+            enrichment_fallback: Default::default(),
+            enrichment_concurrency: crate::links::DEFAULT_ENRICHMENT_CONCURRENCY,
+            auth_provider: None,
+            audit_log: None,
+            allow_duplicate_links: false,
+            id_strategy: IdStrategy::default(),
+            route_prefix: String::new(),
         };
         let router = build_link_routes(state);
         let _ = router;
@@ -191,6 +329,7 @@ mod tests {
             validation_rules: None,
             events: None,
             sinks: None,
+            pluralization: None,
         });
         let registry = Arc::new(LinkRouteRegistry::new(config.clone()));
         let state = AppState {
@@ -200,11 +339,126 @@ mod tests {
             entity_fetchers: Arc::new(HashMap::new()),
             entity_creators: Arc::new(HashMap::new()),
             event_bus: None,
+            enrichment_fallback: Default::default(),
+            enrichment_concurrency: crate::links::DEFAULT_ENRICHMENT_CONCURRENCY,
+            auth_provider: None,
+            audit_log: None,
+            allow_duplicate_links: false,
+            id_strategy: IdStrategy::default(),
+            route_prefix: String::new(),
         };
         let router = build_link_routes(state);
         let _ = router;
     }
 
+    /// Log output shared with a test subscriber
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl CapturedLogs {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for CapturedLogs {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_logging_records_status_path_and_route() {
+        use axum::body::Body;
+        use axum::http::StatusCode;
+        use tower::ServiceExt;
+
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(logs.clone())
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let app = Router::new()
+            .route("/health", get(|| async { "ok" }))
+            .route(
+                "/{entity_type}/{entity_id}/{route_name}",
+                get(|| async { StatusCode::NOT_FOUND }),
+            )
+            .layer(axum::middleware::from_fn(request_logging_middleware));
+
+        let response = app
+            .clone()
+            .oneshot(Request::get("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let text = logs.text();
+        assert!(text.contains("method=GET"), "{text}");
+        assert!(text.contains("path=/health"), "{text}");
+        assert!(text.contains("status=200"), "{text}");
+        assert!(text.contains("elapsed_ms="), "{text}");
+
+        app.oneshot(
+            Request::get("/users/42/cars-owned")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+        let text = logs.text();
+        assert!(text.contains("status=404"), "{text}");
+        assert!(
+            text.contains("route=/{entity_type}/{entity_id}/{route_name}"),
+            "{text}"
+        );
+        assert!(text.contains("route_name=cars-owned"), "{text}");
+    }
+
+    #[tokio::test]
+    async fn test_missing_link_answers_a_structured_error() {
+        use axum::body::{Body, to_bytes};
+        use axum::http::StatusCode;
+        use tower::ServiceExt;
+
+        let router = build_link_routes(test_app_state());
+        let uri = format!("/links/{}", uuid::Uuid::new_v4());
+        let response = router
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "LINK_NOT_FOUND");
+        assert_eq!(body["message"], "Link not found");
+    }
+
+    #[test]
+    fn test_path_param_follows_the_template() {
+        let template = "/{source_type}/{source_id}/{route_name}/{target_id}";
+        assert_eq!(
+            path_param(template, "/users/1/cars-owned/2", "route_name"),
+            Some("cars-owned")
+        );
+        assert_eq!(path_param("/health", "/health", "route_name"), None);
+    }
+
     #[cfg(feature = "grpc")]
     mod grpc_tests {
         use super::super::combine_rest_and_grpc;
@@ -0,0 +1,246 @@
+//! Timestamp wire format for REST responses
+//!
+//! Entities and links serialize their timestamps with chrono's RFC3339
+//! representation. Some clients prefer Unix epoch milliseconds instead, so
+//! the REST layer can rewrite the `created_at`, `updated_at` and `deleted_at`
+//! fields of JSON bodies on the way out. Request bodies are normalized the
+//! other way, which lets clients send either form regardless of the
+//! configured output format. Other fields, such as an entity's own `*_at`
+//! counters or dates, are left as they are. Storage is never affected.
+
+use crate::server::body::{BodyLimit, JsonRequest, JsonResponse};
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, header};
+use axum::middleware::Next;
+use axum::response::Response;
+use chrono::{DateTime, SecondsFormat};
+use serde_json::Value;
+
+/// How timestamp fields are rendered in JSON responses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// RFC3339 strings, e.g. `"2024-01-01T00:00:00Z"` (chrono's default)
+    #[default]
+    Rfc3339,
+    /// Integer milliseconds since the Unix epoch, e.g. `1704067200000`
+    EpochMillis,
+}
+
+/// Whether a JSON key names one of the framework's timestamp fields
+fn is_timestamp_key(key: &str) -> bool {
+    matches!(key, "created_at" | "updated_at" | "deleted_at")
+}
+
+/// Rewrite RFC3339 timestamp fields of a JSON value in place
+///
+/// Only string values of timestamp keys that parse as RFC3339 are touched;
+/// `Rfc3339` leaves the value unchanged.
+pub fn format_timestamps(value: &mut Value, format: TimestampFormat) {
+    if format == TimestampFormat::Rfc3339 {
+        return;
+    }
+    match value {
+        Value::Object(obj) => {
+            for (key, field) in obj.iter_mut() {
+                if is_timestamp_key(key)
+                    && let Value::String(s) = field
+                    && let Ok(ts) = DateTime::parse_from_rfc3339(s)
+                {
+                    *field = Value::from(ts.timestamp_millis());
+                } else {
+                    format_timestamps(field, format);
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| format_timestamps(item, format)),
+        _ => {}
+    }
+}
+
+/// Convert epoch-millisecond timestamp fields of a JSON value to RFC3339
+///
+/// Used on request bodies so handlers always receive chrono-compatible input.
+/// Integers under any other key, `*_at` or not, are left alone.
+pub fn normalize_timestamps(value: &mut Value) {
+    match value {
+        Value::Object(obj) => {
+            for (key, field) in obj.iter_mut() {
+                if is_timestamp_key(key)
+                    && let Some(millis) = field.as_i64()
+                    && let Some(ts) = DateTime::from_timestamp_millis(millis)
+                {
+                    *field = Value::String(ts.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+                } else {
+                    normalize_timestamps(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize_timestamps),
+        _ => {}
+    }
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"))
+}
+
+/// Middleware applying a [`TimestampFormat`] to JSON request and response bodies
+///
+/// Non-JSON bodies (SSE streams, plain text, gRPC) pass through untouched.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let app = router.layer(axum::middleware::from_fn_with_state(
+///     TimestampFormat::EpochMillis,
+///     timestamp_middleware,
+/// ));
+/// ```
+pub async fn timestamp_middleware(
+    State(format): State<TimestampFormat>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let limit = BodyLimit::of(request.extensions());
+    let request = if is_json(request.headers()) {
+        let mut body = match JsonRequest::read(request).await {
+            Ok(body) => body,
+            Err(response) => return response,
+        };
+        // Malformed JSON is left for the handler to report as usual
+        if let Some(json) = body.json_mut() {
+            normalize_timestamps(json);
+        }
+        body.into_request()
+    } else {
+        request
+    };
+
+    let response = next.run(request).await;
+
+    if format == TimestampFormat::Rfc3339 || !is_json(response.headers()) {
+        return response;
+    }
+
+    let mut body = match JsonResponse::read(response, limit).await {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    if let Some(json) = body.json_mut() {
+        format_timestamps(json, format);
+    }
+    body.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::routing::{get, post};
+    use axum::{Json, middleware};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_format_timestamps_epoch_millis_nested() {
+        let mut value = json!({
+            "id": "abc",
+            "created_at": "2024-01-01T00:00:00Z",
+            "name": "2024-01-01T00:00:00Z",
+            "expires_at": "2024-01-01T00:00:00Z",
+            "data": [{"updated_at": "2024-01-01T00:00:01.500Z", "deleted_at": null}]
+        });
+        format_timestamps(&mut value, TimestampFormat::EpochMillis);
+
+        assert_eq!(value["created_at"], json!(1_704_067_200_000_i64));
+        assert_eq!(value["data"][0]["updated_at"], json!(1_704_067_201_500_i64));
+        assert_eq!(value["data"][0]["deleted_at"], Value::Null);
+        // Non-timestamp keys are never rewritten
+        assert_eq!(value["name"], "2024-01-01T00:00:00Z");
+        assert_eq!(value["expires_at"], "2024-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_format_timestamps_rfc3339_is_noop() {
+        let mut value = json!({"created_at": "2024-01-01T00:00:00Z"});
+        let original = value.clone();
+        format_timestamps(&mut value, TimestampFormat::Rfc3339);
+        assert_eq!(value, original);
+    }
+
+    #[test]
+    fn test_normalize_timestamps_accepts_epoch_millis() {
+        let mut value = json!({
+            "created_at": 1_704_067_200_000_i64,
+            "updated_at": "2024-01-01T00:00:00Z",
+            "count": 5,
+            "retry_at": 3,
+            "items": [{"deleted_at": 1_704_067_200_000_i64, "seen_at": 1_704_067_200_000_i64}]
+        });
+        normalize_timestamps(&mut value);
+
+        assert_eq!(value["created_at"], "2024-01-01T00:00:00Z");
+        assert_eq!(value["updated_at"], "2024-01-01T00:00:00Z");
+        assert_eq!(value["items"][0]["deleted_at"], "2024-01-01T00:00:00Z");
+        assert_eq!(value["count"], 5);
+        // Integer fields of the entity itself are not mistaken for timestamps
+        assert_eq!(value["retry_at"], 3);
+        assert_eq!(value["items"][0]["seen_at"], 1_704_067_200_000_i64);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_rewrites_request_and_response() {
+        let app = Router::new()
+            .route(
+                "/item",
+                get(|| async { Json(json!({"created_at": "2024-01-01T00:00:00Z"})) }),
+            )
+            .route(
+                "/echo",
+                post(|Json(body): Json<Value>| async { Json(body) }),
+            )
+            .route("/text", get(|| async { "2024-01-01T00:00:00Z" }))
+            .layer(middleware::from_fn_with_state(
+                TimestampFormat::EpochMillis,
+                timestamp_middleware,
+            ));
+
+        let response = app
+            .clone()
+            .oneshot(Request::get("/item").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["created_at"], json!(1_704_067_200_000_i64));
+
+        // Epoch input is accepted and comes back in the configured format
+        let response = app
+            .clone()
+            .oneshot(
+                Request::post("/echo")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(r#"{"created_at": 1704067200000}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["created_at"], json!(1_704_067_200_000_i64));
+
+        // Non-JSON responses pass through
+        let response = app
+            .oneshot(Request::get("/text").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&bytes[..], b"2024-01-01T00:00:00Z");
+    }
+}
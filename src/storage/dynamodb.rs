@@ -1,13 +1,54 @@
 //! DynamoDB implementation of DataService and LinkService
 
+use crate::core::actor;
 use crate::core::{Data, DataService, LinkService, link::LinkEntity};
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use aws_sdk_dynamodb::Client as DynamoDBClient;
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::error::SdkError;
+use aws_sdk_dynamodb::operation::transact_write_items::TransactWriteItemsError;
+use aws_sdk_dynamodb::types::{AttributeValue, Delete, Put, Select, TransactWriteItem};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use std::collections::HashMap;
 use uuid::Uuid;
 
+type Item = HashMap<String, AttributeValue>;
+
+/// Encode a DynamoDB key (e.g. `LastEvaluatedKey`) as an opaque page token
+///
+/// Only string and number attributes can be key attributes here.
+fn encode_start_key(key: &Item) -> Result<String> {
+    let mut json = serde_json::Map::new();
+    for (name, value) in key {
+        let value = match value {
+            AttributeValue::S(s) => serde_json::json!({ "S": s }),
+            AttributeValue::N(n) => serde_json::json!({ "N": n }),
+            _ => return Err(anyhow!("Unsupported key attribute type for '{}'", name)),
+        };
+        json.insert(name.clone(), value);
+    }
+    Ok(URL_SAFE_NO_PAD.encode(serde_json::Value::Object(json).to_string()))
+}
+
+/// Decode a token produced by [`encode_start_key`] into an `ExclusiveStartKey`
+fn decode_start_key(token: &str) -> Result<Item> {
+    let invalid = || anyhow!("invalid page token: {}", token);
+    let raw = URL_SAFE_NO_PAD.decode(token).map_err(|_| invalid())?;
+    let json: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_slice(&raw).map_err(|_| invalid())?;
+    json.into_iter()
+        .map(|(name, value)| {
+            let value = match (value.get("S"), value.get("N")) {
+                (Some(serde_json::Value::String(s)), None) => AttributeValue::S(s.clone()),
+                (None, Some(serde_json::Value::String(n))) => AttributeValue::N(n.clone()),
+                _ => return Err(invalid()),
+            };
+            Ok((name, value))
+        })
+        .collect()
+}
+
 /// DynamoDB implementation of DataService
 #[derive(Clone)]
 pub struct DynamoDBDataService<T: Data + serde::Serialize + for<'de> serde::Deserialize<'de>> {
@@ -242,7 +283,9 @@ impl<T: Data + serde::Serialize + for<'de> serde::Deserialize<'de>> DynamoDBData
 impl<T: Data + serde::Serialize + for<'de> serde::Deserialize<'de>> DataService<T>
     for DynamoDBDataService<T>
 {
-    async fn create(&self, entity: T) -> Result<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
         let item = self.entity_to_item(&entity).await?;
 
         self.client
@@ -255,7 +298,7 @@ impl<T: Data + serde::Serialize + for<'de> serde::Deserialize<'de>> DataService<
         Ok(entity)
     }
 
-    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
         let key = HashMap::from([("id".to_string(), AttributeValue::S(id.to_string()))]);
 
         let result = self
@@ -272,7 +315,7 @@ impl<T: Data + serde::Serialize + for<'de> serde::Deserialize<'de>> DataService<
         }
     }
 
-    async fn list(&self) -> Result<Vec<T>> {
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
         let result = self
             .client
             .scan()
@@ -283,13 +326,100 @@ impl<T: Data + serde::Serialize + for<'de> serde::Deserialize<'de>> DataService<
         let mut entities = Vec::new();
         if let Some(items) = result.items {
             for item in items {
-                entities.push(self.item_to_entity(&item).await?);
+                let entity = self.item_to_entity(&item).await?;
+                if include_deleted || entity.deleted_at().is_none() {
+                    entities.push(entity);
+                }
             }
         }
         Ok(entities)
     }
 
-    async fn update(&self, _id: &Uuid, entity: T) -> Result<T> {
+    /// Count live entities with a `Select::Count` scan, following
+    /// `LastEvaluatedKey` so tables larger than one scan page are fully counted.
+    ///
+    /// `deleted_at` is only written when set, so live items are the ones
+    /// without the attribute.
+    async fn count(&self) -> Result<usize> {
+        let mut total = 0usize;
+        let mut start_key = None;
+        loop {
+            let result = self
+                .client
+                .scan()
+                .table_name(&self.table_name)
+                .select(Select::Count)
+                .filter_expression("attribute_not_exists(deleted_at)")
+                .set_exclusive_start_key(start_key)
+                .send()
+                .await?;
+
+            total += result.count() as usize;
+            match result.last_evaluated_key {
+                Some(key) if !key.is_empty() => start_key = Some(key),
+                _ => return Ok(total),
+            }
+        }
+    }
+
+    /// Page through the table in scan order with `LastEvaluatedKey` tokens
+    ///
+    /// Each call scans from the item named by `after` until it has `limit`
+    /// live entities plus one more, so the returned token is `None` exactly
+    /// on the last page. The token is the key of the page's last item,
+    /// base64-encoded, and is handed back to DynamoDB as the next
+    /// `ExclusiveStartKey`; deep pages never rescan earlier ones. Pages do
+    /// not follow the newest-first order of `list_after`.
+    async fn list_page(
+        &self,
+        limit: usize,
+        after: Option<String>,
+    ) -> Result<(Vec<T>, Option<String>)> {
+        let mut start_key = after.as_deref().map(decode_start_key).transpose()?;
+        let mut items: Vec<Item> = Vec::new();
+        while items.len() <= limit {
+            let wanted = (limit + 1 - items.len()).min(i32::MAX as usize) as i32;
+            let result = self
+                .client
+                .scan()
+                .table_name(&self.table_name)
+                .filter_expression("attribute_not_exists(deleted_at)")
+                .limit(wanted)
+                .set_exclusive_start_key(start_key)
+                .send()
+                .await?;
+
+            items.extend(result.items.unwrap_or_default());
+            match result.last_evaluated_key {
+                Some(key) if !key.is_empty() => start_key = Some(key),
+                _ => break,
+            }
+        }
+
+        let next = if items.len() > limit {
+            items.truncate(limit);
+            let last = items.last().and_then(|item| item.get("id"));
+            match last {
+                Some(id) => Some(encode_start_key(&Item::from([(
+                    "id".to_string(),
+                    id.clone(),
+                )]))?),
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let mut entities = Vec::with_capacity(items.len());
+        for item in &items {
+            entities.push(self.item_to_entity(item).await?);
+        }
+        Ok((entities, next))
+    }
+
+    async fn update(&self, _id: &Uuid, mut entity: T) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
         let item = self.entity_to_item(&entity).await?;
 
         self.client
@@ -329,22 +459,126 @@ impl<T: Data + serde::Serialize + for<'de> serde::Deserialize<'de>> DataService<
         let mut entities = Vec::new();
         if let Some(items) = result.items {
             for item in items {
-                entities.push(self.item_to_entity(&item).await?);
+                let entity = self.item_to_entity(&item).await?;
+                if entity.deleted_at().is_none() {
+                    entities.push(entity);
+                }
             }
         }
         Ok(entities)
     }
 }
 
+/// Global secondary index of a single-table links table, keyed on the target
+///
+/// See [`DynamoDBLinkService::with_single_table`].
+pub const LINK_TARGET_INDEX: &str = "GSI1";
+
+/// Key attributes of the single-table layout, never part of a link
+const KEY_ATTRIBUTES: [&str; 4] = ["PK", "SK", "GSI1PK", "GSI1SK"];
+
+/// Partition key of the links leaving `source_id`
+fn source_key(source_id: &Uuid) -> String {
+    format!("SRC#{}", source_id)
+}
+
+/// Partition key of the links arriving at `target_id` (on [`LINK_TARGET_INDEX`])
+fn target_key(target_id: &Uuid) -> String {
+    format!("TGT#{}", target_id)
+}
+
+/// Sort key of a `link_type` link to (or, on the index, from) `other_id`
+fn link_sort_key(link_type: &str, other_id: &Uuid) -> String {
+    format!("LNK#{}#{}", link_type, other_id)
+}
+
+/// Primary key of the pointer item resolving a link id to its main item
+fn pointer_key(id: &Uuid) -> Item {
+    let key = format!("LINK#{}", id);
+    HashMap::from([
+        ("PK".to_string(), AttributeValue::S(key.clone())),
+        ("SK".to_string(), AttributeValue::S(key)),
+    ])
+}
+
+/// Primary key of the main item of a link
+fn link_key(link_type: &str, source_id: &Uuid, target_id: &Uuid) -> Item {
+    HashMap::from([
+        ("PK".to_string(), AttributeValue::S(source_key(source_id))),
+        (
+            "SK".to_string(),
+            AttributeValue::S(link_sort_key(link_type, target_id)),
+        ),
+    ])
+}
+
+/// Read a string attribute of `item`
+fn string_attribute<'a>(item: &'a Item, name: &str) -> Result<&'a str> {
+    match item.get(name) {
+        Some(AttributeValue::S(s)) => Ok(s),
+        _ => Err(anyhow!("item has no string attribute '{}'", name)),
+    }
+}
+
+/// Whether a transaction was cancelled by one of its condition checks
+fn failed_condition(err: &SdkError<TransactWriteItemsError>) -> bool {
+    match err.as_service_error() {
+        Some(TransactWriteItemsError::TransactionCanceledException(e)) => e
+            .cancellation_reasons()
+            .iter()
+            .any(|r| r.code() == Some("ConditionalCheckFailed")),
+        _ => false,
+    }
+}
+
 /// DynamoDB implementation of LinkService
+///
+/// [`new`](Self::new) stores one item per link keyed on `id`, so
+/// `find_by_source` and `find_by_target` scan the table.
+/// [`with_single_table`](Self::with_single_table) lays links out so both
+/// are queries.
 pub struct DynamoDBLinkService {
     client: DynamoDBClient,
     table_name: String,
+    single_table: bool,
 }
 
 impl DynamoDBLinkService {
     pub fn new(client: DynamoDBClient, table_name: String) -> Self {
-        Self { client, table_name }
+        Self {
+            client,
+            table_name,
+            single_table: false,
+        }
+    }
+
+    /// Store links in a single-table layout with composite keys
+    ///
+    /// Each link is an item keyed `PK = SRC#{source_id}`,
+    /// `SK = LNK#{link_type}#{target_id}`, so `find_by_source` is a Query on
+    /// `PK`. It also carries `GSI1PK = TGT#{target_id}` and
+    /// `GSI1SK = LNK#{link_type}#{source_id}`, so `find_by_target` is a
+    /// Query on the [`LINK_TARGET_INDEX`] index. A small pointer item keyed
+    /// `PK = SK = LINK#{id}` resolves ids for `get`, `update` and `delete`.
+    ///
+    /// # Table Structure Requirements
+    /// - Partition key: `PK` (string), Sort key: `SK` (string)
+    /// - GSI `GSI1` with partition key `GSI1PK` and sort key `GSI1SK` (strings)
+    ///
+    /// The key leaves room for one link of a type between two entities:
+    /// `create` fails on a live duplicate, and a soft-deleted one is replaced.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let service = DynamoDBLinkService::with_single_table(client, "links".to_string());
+    /// let links = service.find_by_source(&user_id, Some("owns"), None).await?;
+    /// ```
+    pub fn with_single_table(client: DynamoDBClient, table_name: String) -> Self {
+        Self {
+            client,
+            table_name,
+            single_table: true,
+        }
     }
 
     /// List links by tenant ID using DynamoDB Query (efficient for multi-tenant link tables)
@@ -493,6 +727,9 @@ impl DynamoDBLinkService {
         let mut json = serde_json::Map::new();
 
         for (key, value) in item {
+            if KEY_ATTRIBUTES.contains(&key.as_str()) {
+                continue;
+            }
             match value {
                 AttributeValue::S(s) => {
                     // Try to parse as JSON for nested objects
@@ -525,11 +762,165 @@ impl DynamoDBLinkService {
 
         Ok(serde_json::from_value(serde_json::Value::Object(json))?)
     }
+
+    /// Main item of `link` in the single-table layout
+    async fn link_to_single_table_item(&self, link: &LinkEntity) -> Result<Item> {
+        let mut item = self.link_to_item(link).await?;
+        item.extend(link_key(&link.link_type, &link.source_id, &link.target_id));
+        item.insert(
+            "GSI1PK".to_string(),
+            AttributeValue::S(target_key(&link.target_id)),
+        );
+        item.insert(
+            "GSI1SK".to_string(),
+            AttributeValue::S(link_sort_key(&link.link_type, &link.source_id)),
+        );
+        Ok(item)
+    }
+
+    /// Resolve the link `id` through its pointer item
+    ///
+    /// Returns the key of the link's main item and the link stored there,
+    /// which is `None` when a newer link has replaced it under that key.
+    async fn resolve_pointer(&self, id: &Uuid) -> Result<Option<(Item, Option<LinkEntity>)>> {
+        let pointer = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .set_key(Some(pointer_key(id)))
+            .send()
+            .await?;
+        let Some(pointer) = pointer.item() else {
+            return Ok(None);
+        };
+
+        let key = link_key(
+            string_attribute(pointer, "link_type")?,
+            &string_attribute(pointer, "source_id")?.parse()?,
+            &string_attribute(pointer, "target_id")?.parse()?,
+        );
+        let main = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .set_key(Some(key.clone()))
+            .send()
+            .await?;
+        let link = match main.item() {
+            Some(item) => Some(self.item_to_link(item).await?).filter(|l| l.id == *id),
+            None => None,
+        };
+        Ok(Some((key, link)))
+    }
+
+    /// Write the main and pointer items of `link` in one transaction
+    ///
+    /// The main item may only take the place of the link itself or of a
+    /// soft-deleted one. `previous` is the main key the link moves away
+    /// from, deleted in the same transaction. Returns `false`, writing
+    /// nothing, when another live link holds the key.
+    async fn put_single_table(&self, link: &LinkEntity, previous: Option<Item>) -> Result<bool> {
+        let main = Put::builder()
+            .table_name(&self.table_name)
+            .set_item(Some(self.link_to_single_table_item(link).await?))
+            .condition_expression(
+                "attribute_not_exists(PK) OR attribute_exists(deleted_at) OR id = :id",
+            )
+            .expression_attribute_values(":id", AttributeValue::S(link.id.to_string()))
+            .build()?;
+
+        let mut pointer = pointer_key(&link.id);
+        pointer.insert(
+            "link_type".to_string(),
+            AttributeValue::S(link.link_type.clone()),
+        );
+        pointer.insert(
+            "source_id".to_string(),
+            AttributeValue::S(link.source_id.to_string()),
+        );
+        pointer.insert(
+            "target_id".to_string(),
+            AttributeValue::S(link.target_id.to_string()),
+        );
+        let pointer = Put::builder()
+            .table_name(&self.table_name)
+            .set_item(Some(pointer))
+            .build()?;
+
+        let mut transaction = self
+            .client
+            .transact_write_items()
+            .transact_items(TransactWriteItem::builder().put(main).build())
+            .transact_items(TransactWriteItem::builder().put(pointer).build());
+        if let Some(key) = previous {
+            let delete = Delete::builder()
+                .table_name(&self.table_name)
+                .set_key(Some(key))
+                .build()?;
+            transaction =
+                transaction.transact_items(TransactWriteItem::builder().delete(delete).build());
+        }
+
+        match transaction.send().await {
+            Ok(_) => Ok(true),
+            Err(e) if failed_condition(&e) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Query the links of one partition, on the table or on [`LINK_TARGET_INDEX`]
+    async fn query_single_table(
+        &self,
+        index: Option<&str>,
+        partition: String,
+        link_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        let (pk, sk) = match index {
+            Some(_) => ("GSI1PK", "GSI1SK"),
+            None => ("PK", "SK"),
+        };
+        let mut query = self
+            .client
+            .query()
+            .table_name(&self.table_name)
+            .set_index_name(index.map(str::to_string))
+            .expression_attribute_values(":pk", AttributeValue::S(partition));
+        query = match link_type {
+            Some(lt) => query
+                .key_condition_expression(format!("{} = :pk AND begins_with({}, :sk)", pk, sk))
+                .expression_attribute_values(":sk", AttributeValue::S(format!("LNK#{}#", lt))),
+            None => query.key_condition_expression(format!("{} = :pk", pk)),
+        };
+
+        let items = query.into_paginator().items().send().try_collect().await?;
+        let mut links = Vec::new();
+        for item in &items {
+            links.push(self.item_to_link(item).await?);
+        }
+        Ok(links)
+    }
+}
+
+/// Error for a link whose single-table key is held by another live link
+fn duplicate_link(link: &LinkEntity) -> anyhow::Error {
+    anyhow!(
+        "a '{}' link from {} to {} already exists",
+        link.link_type,
+        link.source_id,
+        link.target_id
+    )
 }
 
 #[async_trait]
 impl LinkService for DynamoDBLinkService {
     async fn create(&self, link: LinkEntity) -> Result<LinkEntity> {
+        if self.single_table {
+            if !self.put_single_table(&link, None).await? {
+                return Err(duplicate_link(&link));
+            }
+            return Ok(link);
+        }
+
         let item = self.link_to_item(&link).await?;
 
         self.client
@@ -542,7 +933,28 @@ impl LinkService for DynamoDBLinkService {
         Ok(link)
     }
 
+    async fn create_unique(&self, link: LinkEntity) -> Result<Option<LinkEntity>> {
+        if self.single_table {
+            // The main item is keyed on type, source and target, so the
+            // conditional write is the duplicate check
+            let created = self.put_single_table(&link, None).await?;
+            return Ok(created.then_some(link));
+        }
+
+        let existing = self
+            .find_by_source(&link.source_id, Some(&link.link_type), None)
+            .await?;
+        if existing.iter().any(|l| l.duplicates(&link)) {
+            return Ok(None);
+        }
+        self.create(link).await.map(Some)
+    }
+
     async fn get(&self, id: &Uuid) -> Result<Option<LinkEntity>> {
+        if self.single_table {
+            return Ok(self.resolve_pointer(id).await?.and_then(|(_, link)| link));
+        }
+
         let key = HashMap::from([("id".to_string(), AttributeValue::S(id.to_string()))]);
 
         let result = self
@@ -559,7 +971,69 @@ impl LinkService for DynamoDBLinkService {
         }
     }
 
+    /// In the single-table layout the pair is the main item's key, so this
+    /// is a single `GetItem`; otherwise a filtered scan
+    async fn get_between(
+        &self,
+        source_id: &Uuid,
+        target_id: &Uuid,
+        link_type: &str,
+    ) -> Result<Option<LinkEntity>> {
+        let item = if self.single_table {
+            self.client
+                .get_item()
+                .table_name(&self.table_name)
+                .set_key(Some(link_key(link_type, source_id, target_id)))
+                .send()
+                .await?
+                .item
+        } else {
+            let items: Vec<Item> = self
+                .client
+                .scan()
+                .table_name(&self.table_name)
+                .filter_expression(
+                    "source_id = :source_id AND target_id = :target_id \
+                     AND link_type = :link_type AND attribute_not_exists(deleted_at)",
+                )
+                .expression_attribute_values(":source_id", AttributeValue::S(source_id.to_string()))
+                .expression_attribute_values(":target_id", AttributeValue::S(target_id.to_string()))
+                .expression_attribute_values(":link_type", AttributeValue::S(link_type.to_string()))
+                .into_paginator()
+                .items()
+                .send()
+                .try_collect()
+                .await?;
+            items.into_iter().next()
+        };
+
+        match item {
+            Some(item) => Ok(Some(self.item_to_link(&item).await?).filter(|l| !l.is_deleted())),
+            None => Ok(None),
+        }
+    }
+
     async fn list(&self) -> Result<Vec<LinkEntity>> {
+        if self.single_table {
+            // Skip pointer items
+            let items = self
+                .client
+                .scan()
+                .table_name(&self.table_name)
+                .filter_expression("begins_with(PK, :src)")
+                .expression_attribute_values(":src", AttributeValue::S("SRC#".to_string()))
+                .into_paginator()
+                .items()
+                .send()
+                .try_collect()
+                .await?;
+            let mut links = Vec::new();
+            for item in &items {
+                links.push(self.item_to_link(item).await?);
+            }
+            return Ok(links);
+        }
+
         let result = self
             .client
             .scan()
@@ -580,8 +1054,18 @@ impl LinkService for DynamoDBLinkService {
         &self,
         source_id: &Uuid,
         link_type: Option<&str>,
-        _target_type: Option<&str>,
+        target_type: Option<&str>,
     ) -> Result<Vec<LinkEntity>> {
+        if self.single_table {
+            let links = self
+                .query_single_table(None, source_key(source_id), link_type)
+                .await?;
+            return Ok(links
+                .into_iter()
+                .filter(|l| l.matches_target_type(target_type))
+                .collect());
+        }
+
         // Use scan with filter
         let mut filter_expr = "source_id = :source_id".to_string();
         let mut attr_values = HashMap::new();
@@ -620,8 +1104,18 @@ impl LinkService for DynamoDBLinkService {
         &self,
         target_id: &Uuid,
         link_type: Option<&str>,
-        _source_type: Option<&str>,
+        source_type: Option<&str>,
     ) -> Result<Vec<LinkEntity>> {
+        if self.single_table {
+            let links = self
+                .query_single_table(Some(LINK_TARGET_INDEX), target_key(target_id), link_type)
+                .await?;
+            return Ok(links
+                .into_iter()
+                .filter(|l| l.matches_source_type(source_type))
+                .collect());
+        }
+
         // Use scan with filter
         let mut filter_expr = "target_id = :target_id".to_string();
         let mut attr_values = HashMap::new();
@@ -657,6 +1151,22 @@ impl LinkService for DynamoDBLinkService {
     }
 
     async fn update(&self, id: &Uuid, updated_link: LinkEntity) -> Result<LinkEntity> {
+        if self.single_table {
+            let Some((key, Some(_))) = self.resolve_pointer(id).await? else {
+                return Err(anyhow!("Link not found"));
+            };
+            let moved = link_key(
+                &updated_link.link_type,
+                &updated_link.source_id,
+                &updated_link.target_id,
+            );
+            let previous = (moved != key).then_some(key);
+            if !self.put_single_table(&updated_link, previous).await? {
+                return Err(duplicate_link(&updated_link));
+            }
+            return Ok(updated_link);
+        }
+
         // Verify the link exists first
         self.get(id)
             .await?
@@ -675,6 +1185,28 @@ impl LinkService for DynamoDBLinkService {
     }
 
     async fn delete(&self, id: &Uuid) -> Result<()> {
+        if self.single_table {
+            let Some((key, link)) = self.resolve_pointer(id).await? else {
+                return Ok(());
+            };
+            let mut keys = vec![pointer_key(id)];
+            // A newer link may have replaced this one under its key
+            if link.is_some() {
+                keys.push(key);
+            }
+            let mut transaction = self.client.transact_write_items();
+            for key in keys {
+                let delete = Delete::builder()
+                    .table_name(&self.table_name)
+                    .set_key(Some(key))
+                    .build()?;
+                transaction =
+                    transaction.transact_items(TransactWriteItem::builder().delete(delete).build());
+            }
+            transaction.send().await?;
+            return Ok(());
+        }
+
         let key = HashMap::from([("id".to_string(), AttributeValue::S(id.to_string()))]);
 
         self.client
@@ -693,7 +1225,7 @@ impl LinkService for DynamoDBLinkService {
         let target_links = self.find_by_target(entity_id, None, None).await?;
 
         // Delete all found links
-        for link in source_links.into_iter().chain(target_links.into_iter()) {
+        for link in source_links.into_iter().chain(target_links) {
             self.delete(&link.id).await?;
         }
 
@@ -728,6 +1260,19 @@ mod tests {
         DynamoDBLinkService::new(test_client(), "test_links".to_string())
     }
 
+    // ── Page tokens ──────────────────────────────────────────────────
+
+    #[test]
+    fn start_key_round_trips_through_its_token() {
+        let key = Item::from([
+            ("id".to_string(), AttributeValue::S("abc".to_string())),
+            ("n".to_string(), AttributeValue::N("42".to_string())),
+        ]);
+        let token = encode_start_key(&key).unwrap();
+        assert_eq!(decode_start_key(&token).unwrap(), key);
+        assert!(decode_start_key("not a token").is_err());
+    }
+
     // ── DynamoDBDataService: entity_to_item ──────────────────────────
 
     #[tokio::test]
@@ -1001,4 +1546,31 @@ mod tests {
         assert_eq!(meta["role"], "admin");
         assert_eq!(meta["level"], 5);
     }
+
+    // ── DynamoDBLinkService: single-table layout ─────────────────────
+
+    #[tokio::test]
+    async fn single_table_item_is_keyed_on_source_and_target() {
+        let svc = DynamoDBLinkService::with_single_table(test_client(), "links".to_string());
+        let src = Uuid::new_v4();
+        let tgt = Uuid::new_v4();
+        let link = LinkEntity::new("owns", src, tgt, None);
+
+        let item = svc.link_to_single_table_item(&link).await.unwrap();
+
+        let key = |name: &str| match &item[name] {
+            AttributeValue::S(s) => s.clone(),
+            other => panic!("expected S for {}, got {:?}", name, other),
+        };
+        assert_eq!(key("PK"), format!("SRC#{}", src));
+        assert_eq!(key("SK"), format!("LNK#owns#{}", tgt));
+        assert_eq!(key("GSI1PK"), format!("TGT#{}", tgt));
+        assert_eq!(key("GSI1SK"), format!("LNK#owns#{}", src));
+
+        // Key attributes do not leak back into the link
+        let recovered = svc.item_to_link(&item).await.unwrap();
+        assert_eq!(recovered.id, link.id);
+        assert_eq!(recovered.source_id, src);
+        assert_eq!(recovered.target_id, tgt);
+    }
 }
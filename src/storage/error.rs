@@ -0,0 +1,170 @@
+//! Errors shared by the storage backends
+
+use crate::core::Data;
+use crate::core::etag;
+use crate::core::extractors::error_response;
+use crate::core::field::FieldValue;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use uuid::Uuid;
+
+/// Error returned by a backend when a write cannot be applied as requested
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum StorageError {
+    /// The entity changed since it was read: its stored version no longer
+    /// matches the one the update was based on
+    #[error("entity {entity_id} was modified concurrently (expected version {expected_version})")]
+    IntegrityError {
+        entity_id: Uuid,
+        expected_version: u64,
+    },
+
+    /// Another live entity of the same type already holds this value of a
+    /// unique field (see [`Data::unique_fields`])
+    #[error("{entity_type} with {field} '{value}' already exists")]
+    AlreadyExists {
+        entity_type: String,
+        field: String,
+        value: String,
+    },
+
+    /// The entity changed since the `If-Match` check of the current request
+    /// (see [`etag::if_match`])
+    #[error("entity {entity_id} was modified since the given ETag")]
+    PreconditionFailed { entity_id: Uuid },
+}
+
+/// Fail unless the current `If-Match` precondition, if any, still holds
+/// for the entity stored as of `updated_at` and `version`
+///
+/// The refusal is also recorded on the enclosing [`etag::if_match`] scope.
+pub fn check_precondition(
+    id: &Uuid,
+    updated_at: &chrono::DateTime<chrono::Utc>,
+    version: u64,
+) -> Result<(), StorageError> {
+    match etag::precondition() {
+        Some(expected) if !expected.holds(updated_at, version) => {
+            etag::reject_precondition();
+            Err(StorageError::PreconditionFailed { entity_id: *id })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check `entity` against the other live entities of its type
+///
+/// Fails on the first unique field whose non-null value one of `others`
+/// (other than `entity` itself) also has. Soft-deleted entities release
+/// their values.
+pub fn check_unique_fields<'a, T: Data + 'a>(
+    entity: &T,
+    others: impl IntoIterator<Item = &'a T>,
+) -> Result<(), StorageError> {
+    let values: Vec<(&str, FieldValue)> = T::unique_fields()
+        .iter()
+        .filter_map(|field| Some((*field, entity.field_value(field)?)))
+        .filter(|(_, value)| !value.is_null())
+        .collect();
+    if values.is_empty() || entity.deleted_at().is_some() {
+        return Ok(());
+    }
+    for other in others {
+        if other.id() == entity.id() || other.deleted_at().is_some() {
+            continue;
+        }
+        for (field, value) in &values {
+            if other.field_value(field).as_ref() == Some(value) {
+                return Err(StorageError::AlreadyExists {
+                    entity_type: T::resource_name_singular().to_string(),
+                    field: field.to_string(),
+                    value: display_value(value),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A field value as it appears in error messages
+fn display_value(value: &FieldValue) -> String {
+    match value {
+        FieldValue::String(s) => s.clone(),
+        FieldValue::Integer(i) => i.to_string(),
+        FieldValue::Float(f) => f.to_string(),
+        FieldValue::Boolean(b) => b.to_string(),
+        FieldValue::Uuid(u) => u.to_string(),
+        FieldValue::DateTime(dt) => dt.to_rfc3339(),
+        FieldValue::Null => "null".to_string(),
+    }
+}
+
+/// Rendered as `409 Conflict`, or `412 Precondition Failed` for
+/// [`StorageError::PreconditionFailed`]
+impl IntoResponse for StorageError {
+    fn into_response(self) -> Response {
+        match self {
+            StorageError::PreconditionFailed { .. } => error_response(
+                StatusCode::PRECONDITION_FAILED,
+                "PRECONDITION_FAILED",
+                self.to_string(),
+            ),
+            _ => error_response(StatusCode::CONFLICT, "CONFLICT", self.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integrity_error_is_a_conflict() {
+        let error = StorageError::IntegrityError {
+            entity_id: Uuid::nil(),
+            expected_version: 3,
+        };
+        assert!(error.to_string().contains("expected version 3"));
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_already_exists_is_a_conflict() {
+        let error = StorageError::AlreadyExists {
+            entity_type: "user".to_string(),
+            field: "email".to_string(),
+            value: "ada@example.com".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "user with email 'ada@example.com' already exists"
+        );
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_stale_precondition_is_refused() {
+        let at = chrono::Utc::now();
+        let expected = etag::Precondition {
+            updated_at: at,
+            version: 0,
+        };
+        let id = Uuid::new_v4();
+
+        let (result, failed) =
+            etag::if_match(expected, async { check_precondition(&id, &at, 0) }).await;
+        assert!(result.is_ok() && !failed);
+
+        let (result, failed) = etag::if_match(expected, async {
+            check_precondition(&id, &(at + chrono::Duration::seconds(1)), 0)
+        })
+        .await;
+        let error = result.unwrap_err();
+        assert!(failed);
+        assert_eq!(
+            error.into_response().status(),
+            StatusCode::PRECONDITION_FAILED
+        );
+        assert!(check_precondition(&id, &at, 7).is_ok());
+    }
+}
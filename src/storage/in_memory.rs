@@ -1,10 +1,27 @@
 //! In-memory implementations of DataService and LinkService for testing and development
 
+use crate::core::actor;
+use crate::core::audit::{AuditEntry, AuditLogService};
+use crate::core::auth::AuthContext;
+use crate::core::entity::{ComputedFields, to_read_json};
+use crate::core::events::FrameworkEvent;
 use crate::core::field::FieldValue;
-use crate::core::{Data, DataService, LinkService, link::LinkEntity};
+use crate::core::history::{EntityVersion, HistoryError, HistoryService, state_as_of, state_at};
+use crate::core::outbox::{OutboxEntry, OutboxService};
+use crate::core::soft_delete::{SoftDeleteError, SoftDeleteStatus, with_deletion_state};
+use crate::core::{
+    CacheResult, Cursor, Data, DataService, EntityFetcher, HealthCheck, LinkService, Query,
+    QueryableStore,
+    link::{LinkEntity, LinkLimit},
+};
+use crate::storage::error::{check_precondition, check_unique_fields};
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
-use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
@@ -26,21 +43,49 @@ use uuid::Uuid;
 /// ```
 pub struct InMemoryDataService<T: Data> {
     data: Arc<RwLock<HashMap<Uuid, T>>>,
+    /// Superseded states, when history is on
+    versions: Option<Arc<RwLock<SupersededMap<T>>>>,
+    /// Status of each entity soft-deleted with a status, before its deletion
+    deleted_statuses: Arc<RwLock<HashMap<Uuid, String>>>,
 }
 
+/// Superseded states of each entity, oldest first, with when each was superseded
+type SupersededMap<T> = HashMap<Uuid, Vec<(T, DateTime<Utc>)>>;
+
 impl<T: Data> InMemoryDataService<T> {
     /// Create a new empty in-memory data service
     pub fn new() -> Self {
         Self {
             data: Arc::new(RwLock::new(HashMap::new())),
+            versions: None,
+            deleted_statuses: Arc::default(),
         }
     }
+
+    /// Keep the previous state of an entity on every update
+    ///
+    /// Enables [`DataService::get_at`] and [`DataService::get_as_of`].
+    pub fn with_history(mut self) -> Self {
+        self.versions = Some(Arc::default());
+        self
+    }
+
+    /// Recorded states of an entity, oldest first
+    fn snapshots(&self, id: &Uuid) -> Result<Vec<(T, DateTime<Utc>)>> {
+        let versions = self.versions.as_ref().ok_or(HistoryError::NotEnabled)?;
+        let versions = versions
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock: {}", e))?;
+        Ok(versions.get(id).cloned().unwrap_or_default())
+    }
 }
 
 impl<T: Data> Clone for InMemoryDataService<T> {
     fn clone(&self) -> Self {
         Self {
             data: Arc::clone(&self.data),
+            versions: self.versions.clone(),
+            deleted_statuses: Arc::clone(&self.deleted_statuses),
         }
     }
 }
@@ -53,18 +98,40 @@ impl<T: Data> Default for InMemoryDataService<T> {
 
 #[async_trait]
 impl<T: Data> DataService<T> for InMemoryDataService<T> {
-    async fn create(&self, entity: T) -> Result<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
         let mut data = self
             .data
             .write()
             .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?;
 
+        check_unique_fields(&entity, data.values())?;
         data.insert(entity.id(), entity.clone());
 
         Ok(entity)
     }
 
-    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
+    async fn create_many(&self, mut entities: Vec<T>) -> Result<Vec<T>> {
+        entities.iter_mut().for_each(actor::stamp_create);
+
+        let mut data = self
+            .data
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?;
+
+        // Checked against the batch too, before anything is inserted
+        for (i, entity) in entities.iter().enumerate() {
+            check_unique_fields(entity, data.values().chain(&entities[..i]))?;
+        }
+        for entity in &entities {
+            data.insert(entity.id(), entity.clone());
+        }
+
+        Ok(entities)
+    }
+
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
         let data = self
             .data
             .read()
@@ -73,29 +140,73 @@ impl<T: Data> DataService<T> for InMemoryDataService<T> {
         Ok(data.get(id).cloned())
     }
 
-    async fn list(&self) -> Result<Vec<T>> {
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
+        let data = self
+            .data
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock: {}", e))?;
+
+        Ok(data
+            .values()
+            .filter(|entity| include_deleted || entity.deleted_at().is_none())
+            .cloned()
+            .collect())
+    }
+
+    async fn count(&self) -> Result<usize> {
         let data = self
             .data
             .read()
             .map_err(|e| anyhow!("Failed to acquire read lock: {}", e))?;
 
-        Ok(data.values().cloned().collect())
+        Ok(data
+            .values()
+            .filter(|entity| entity.deleted_at().is_none())
+            .count())
     }
 
-    async fn update(&self, id: &Uuid, entity: T) -> Result<T> {
+    async fn update(&self, id: &Uuid, mut entity: T) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
         let mut data = self
             .data
             .write()
             .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?;
 
-        data.get(id)
+        let stored = data
+            .get(id)
             .ok_or_else(|| anyhow!("Entity not found: {}", id))?;
-
-        data.insert(*id, entity.clone());
+        check_precondition(id, &stored.updated_at(), stored.version())?;
+
+        check_unique_fields(&entity, data.values().filter(|other| other.id() != *id))?;
+        let previous = data.insert(*id, entity.clone());
+
+        if let (Some(versions), Some(previous)) = (&self.versions, previous) {
+            versions
+                .write()
+                .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?
+                .entry(*id)
+                .or_default()
+                .push((previous, Utc::now()));
+        }
 
         Ok(entity)
     }
 
+    async fn get_at(&self, id: &Uuid, version: i64) -> Result<Option<T>> {
+        let snapshots = self.snapshots(id)?.into_iter().map(|(s, _)| s).collect();
+        let current = self.get_with_deleted(id).await?;
+        Ok(state_at(snapshots, current, version))
+    }
+
+    async fn get_as_of(&self, id: &Uuid, at: DateTime<Utc>) -> Result<Option<T>> {
+        let snapshots = self.snapshots(id)?;
+        let current = self.get_with_deleted(id).await?;
+        Ok(state_as_of(snapshots, current, at, |e| {
+            Some(e.created_at())
+        }))
+    }
+
     async fn delete(&self, id: &Uuid) -> Result<()> {
         let mut data = self
             .data
@@ -103,10 +214,49 @@ impl<T: Data> DataService<T> for InMemoryDataService<T> {
             .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?;
 
         data.remove(id);
+        self.deleted_statuses
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?
+            .remove(id);
 
         Ok(())
     }
 
+    async fn soft_delete_with_status(&self, id: &Uuid, status: &SoftDeleteStatus) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let existing = self.get(id).await?.ok_or(SoftDeleteError::NotFound(*id))?;
+        let tombstone = with_deletion_state(&existing, Some(Utc::now()), Some(&status.on_delete))?;
+        let deleted = self.update(id, tombstone).await?;
+        self.deleted_statuses
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?
+            .insert(*id, existing.status().to_string());
+        Ok(deleted)
+    }
+
+    async fn restore_with_status(&self, id: &Uuid, status: &SoftDeleteStatus) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let existing = self
+            .get_with_deleted(id)
+            .await?
+            .ok_or(SoftDeleteError::NotFound(*id))?;
+        if existing.deleted_at().is_none() {
+            return Ok(existing);
+        }
+        let previous = self
+            .deleted_statuses
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?
+            .remove(id);
+        let previous = previous.as_deref().unwrap_or(&status.on_restore);
+        self.update(id, with_deletion_state(&existing, None, Some(previous))?)
+            .await
+    }
+
     async fn search(&self, field: &str, value: &str) -> Result<Vec<T>> {
         let data = self
             .data
@@ -115,6 +265,7 @@ impl<T: Data> DataService<T> for InMemoryDataService<T> {
 
         Ok(data
             .values()
+            .filter(|entity| entity.deleted_at().is_none())
             .filter(|entity| {
                 entity.field_value(field).is_some_and(|fv| match &fv {
                     FieldValue::String(s) => s == value,
@@ -131,6 +282,92 @@ impl<T: Data> DataService<T> for InMemoryDataService<T> {
     }
 }
 
+/// Evaluates queries in memory over the live entities
+#[async_trait]
+impl<T: Data> QueryableStore<T> for InMemoryDataService<T> {
+    async fn query(&self, query: &Query) -> Result<Vec<T>> {
+        Ok(query.apply(self.list().await?))
+    }
+}
+
+/// Serves the entities to link enrichment and the REST search route
+#[async_trait]
+impl<T: Data + Serialize> EntityFetcher for InMemoryDataService<T> {
+    async fn fetch_as_json(&self, entity_id: &Uuid) -> Result<Value> {
+        let entity = DataService::get(self, entity_id)
+            .await?
+            .ok_or_else(|| anyhow!("Entity not found: {}", entity_id))?;
+        Ok(to_read_json(&entity)?)
+    }
+
+    async fn fetch_with_deleted_as_json(&self, entity_id: &Uuid) -> Result<Value> {
+        let entity = DataService::get_with_deleted(self, entity_id)
+            .await?
+            .ok_or_else(|| anyhow!("Entity not found: {}", entity_id))?;
+        Ok(to_read_json(&entity)?)
+    }
+
+    async fn fetch_if_modified_as_json(
+        &self,
+        entity_id: &Uuid,
+        etag: Option<&str>,
+    ) -> Result<CacheResult<Value>> {
+        Ok(
+            match DataService::get_if_modified(self, entity_id, etag).await? {
+                CacheResult::Modified(entity) => CacheResult::Modified(to_read_json(&entity)?),
+                CacheResult::NotModified => CacheResult::NotModified,
+                CacheResult::NotFound => CacheResult::NotFound,
+            },
+        )
+    }
+
+    async fn list_as_json(&self, limit: Option<i32>, offset: Option<i32>) -> Result<Vec<Value>> {
+        let offset = usize::try_from(offset.unwrap_or(0)).unwrap_or(0);
+        let limit = usize::try_from(limit.unwrap_or(50)).unwrap_or(0);
+        DataService::list(self)
+            .await?
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|e| Ok(to_read_json(&e)?))
+            .collect()
+    }
+
+    async fn list_after_as_json(&self, cursor: Option<Cursor>, limit: usize) -> Result<Vec<Value>> {
+        DataService::list_after(self, cursor, limit)
+            .await?
+            .into_iter()
+            .map(|e| Ok(to_read_json(&e)?))
+            .collect()
+    }
+
+    fn searchable_fields(&self) -> &'static [&'static str] {
+        T::indexed_fields()
+    }
+
+    fn redacted_fields(&self, context: &AuthContext) -> &'static [&'static str] {
+        T::redacted_fields(context)
+    }
+
+    fn computed_fields(&self, entity: &Value) -> ComputedFields {
+        // Computed from the stored entity with this ID
+        let id = entity.get("id").and_then(Value::as_str);
+        let Some(id) = id.and_then(|id| id.parse::<Uuid>().ok()) else {
+            return ComputedFields::new();
+        };
+        let data = self.data.read().unwrap_or_else(|e| e.into_inner());
+        data.get(&id).map(Data::computed_fields).unwrap_or_default()
+    }
+
+    async fn search_as_json(&self, field: &str, value: &str) -> Result<Vec<Value>> {
+        DataService::search(self, field, value)
+            .await?
+            .into_iter()
+            .map(|e| Ok(to_read_json(&e)?))
+            .collect()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // LinkService
 // ---------------------------------------------------------------------------
@@ -158,6 +395,28 @@ impl Default for InMemoryLinkService {
     }
 }
 
+#[async_trait]
+impl<T: Data> HealthCheck for InMemoryDataService<T> {
+    fn name(&self) -> &str {
+        "in_memory"
+    }
+
+    async fn check(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HealthCheck for InMemoryLinkService {
+    fn name(&self) -> &str {
+        "in_memory"
+    }
+
+    async fn check(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl LinkService for InMemoryLinkService {
     async fn create(&self, link: LinkEntity) -> Result<LinkEntity> {
@@ -171,6 +430,42 @@ impl LinkService for InMemoryLinkService {
         Ok(link)
     }
 
+    /// Counts and inserts under a single write lock, so concurrent bounded
+    /// creates are serialized and can never overshoot the limit.
+    async fn create_within_limit(
+        &self,
+        link: LinkEntity,
+        limit: LinkLimit,
+    ) -> Result<Option<LinkEntity>> {
+        let mut links = self
+            .links
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?;
+
+        let (source_count, target_count) = LinkLimit::count(&link, links.values());
+        if !limit.allows(source_count, target_count) {
+            return Ok(None);
+        }
+        links.insert(link.id, link.clone());
+
+        Ok(Some(link))
+    }
+
+    /// Scans and inserts under a single write lock
+    async fn create_unique(&self, link: LinkEntity) -> Result<Option<LinkEntity>> {
+        let mut links = self
+            .links
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?;
+
+        if links.values().any(|l| l.duplicates(&link)) {
+            return Ok(None);
+        }
+        links.insert(link.id, link.clone());
+
+        Ok(Some(link))
+    }
+
     async fn get(&self, id: &Uuid) -> Result<Option<LinkEntity>> {
         let links = self
             .links
@@ -205,7 +500,7 @@ impl LinkService for InMemoryLinkService {
             .filter(|link| {
                 &link.source_id == source_id
                     && link_type.is_none_or(|lt| link.link_type == lt)
-                    && target_type.is_none_or(|_tt| true) // TODO: Add target type to Link if needed
+                    && link.matches_target_type(target_type)
             })
             .cloned()
             .collect())
@@ -227,7 +522,53 @@ impl LinkService for InMemoryLinkService {
             .filter(|link| {
                 &link.target_id == target_id
                     && link_type.is_none_or(|lt| link.link_type == lt)
-                    && source_type.is_none_or(|_st| true) // TODO: Add source type to Link if needed
+                    && link.matches_source_type(source_type)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_sources(
+        &self,
+        source_ids: &[Uuid],
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        let source_ids: HashSet<&Uuid> = source_ids.iter().collect();
+        let links = self
+            .links
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock: {}", e))?;
+
+        Ok(links
+            .values()
+            .filter(|link| {
+                source_ids.contains(&link.source_id)
+                    && link_type.is_none_or(|lt| link.link_type == lt)
+                    && link.matches_target_type(target_type)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_targets(
+        &self,
+        target_ids: &[Uuid],
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        let target_ids: HashSet<&Uuid> = target_ids.iter().collect();
+        let links = self
+            .links
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock: {}", e))?;
+
+        Ok(links
+            .values()
+            .filter(|link| {
+                target_ids.contains(&link.target_id)
+                    && link_type.is_none_or(|lt| link.link_type == lt)
+                    && link.matches_source_type(source_type)
             })
             .cloned()
             .collect())
@@ -269,11 +610,186 @@ impl LinkService for InMemoryLinkService {
     }
 }
 
+/// In-memory entity history store
+///
+/// Keeps every recorded snapshot in process memory. Useful for tests and
+/// for recording versions manually when the data backend has no native
+/// history support.
+#[derive(Clone, Default)]
+pub struct InMemoryHistoryService {
+    versions: Arc<RwLock<VersionMap>>,
+}
+
+/// Versions keyed by (entity_type, entity_id)
+type VersionMap = HashMap<(String, Uuid), Vec<EntityVersion>>;
+
+impl InMemoryHistoryService {
+    /// Create a new empty history store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl HistoryService for InMemoryHistoryService {
+    async fn record(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        snapshot: serde_json::Value,
+        actor: Option<&str>,
+    ) -> Result<EntityVersion> {
+        let mut versions = self
+            .versions
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?;
+
+        let entry = versions
+            .entry((entity_type.to_string(), *entity_id))
+            .or_default();
+        let version = EntityVersion {
+            entity_id: *entity_id,
+            entity_type: entity_type.to_string(),
+            version: entry.len() as i64 + 1,
+            snapshot,
+            changed_at: chrono::Utc::now(),
+            actor: actor.map(str::to_string),
+        };
+        entry.push(version.clone());
+
+        Ok(version)
+    }
+
+    async fn list_versions(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+    ) -> Result<Vec<EntityVersion>> {
+        let versions = self
+            .versions
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock: {}", e))?;
+
+        Ok(versions
+            .get(&(entity_type.to_string(), *entity_id))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn get_version(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        version: i64,
+    ) -> Result<Option<EntityVersion>> {
+        Ok(self
+            .list_versions(entity_type, entity_id)
+            .await?
+            .into_iter()
+            .find(|v| v.version == version))
+    }
+}
+
+/// In-memory audit log
+///
+/// Keeps every entry in process memory. Useful for tests and development.
+#[derive(Clone, Default)]
+pub struct InMemoryAuditLogService {
+    entries: Arc<RwLock<Vec<AuditEntry>>>,
+}
+
+impl InMemoryAuditLogService {
+    /// Create a new empty audit log
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AuditLogService for InMemoryAuditLogService {
+    async fn record(&self, entry: AuditEntry) -> Result<()> {
+        self.entries
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?
+            .push(entry);
+        Ok(())
+    }
+
+    async fn list(&self, entity_type: &str, entity_id: &Uuid) -> Result<Vec<AuditEntry>> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock: {}", e))?;
+
+        Ok(entries
+            .iter()
+            .filter(|e| e.entity_type == entity_type && e.entity_id == *entity_id)
+            .cloned()
+            .collect())
+    }
+}
+
+/// In-memory outbox
+///
+/// Rows live in process memory, so they do not survive a restart; useful
+/// for tests and development.
+#[derive(Clone, Default)]
+pub struct InMemoryOutboxService {
+    entries: Arc<RwLock<Vec<OutboxEntry>>>,
+}
+
+impl InMemoryOutboxService {
+    /// Create a new empty outbox
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OutboxService for InMemoryOutboxService {
+    async fn enqueue(&self, event: FrameworkEvent) -> Result<OutboxEntry> {
+        let entry = OutboxEntry::new(event);
+        self.entries
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?
+            .push(entry.clone());
+        Ok(entry)
+    }
+
+    async fn unsent(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|e| anyhow!("Failed to acquire read lock: {}", e))?;
+
+        Ok(entries
+            .iter()
+            .filter(|e| e.sent_at.is_none())
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn mark_sent(&self, ids: &[Uuid]) -> Result<()> {
+        let mut entries = self
+            .entries
+            .write()
+            .map_err(|e| anyhow!("Failed to acquire write lock: {}", e))?;
+
+        let now = Utc::now();
+        for entry in entries.iter_mut().filter(|e| ids.contains(&e.id)) {
+            entry.sent_at.get_or_insert(now);
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::entity::Entity;
     use crate::core::field::FieldValue;
+    use crate::core::tenant::TenantContext;
     use chrono::{DateTime, Utc};
 
     // -----------------------------------------------------------------------
@@ -287,6 +803,7 @@ mod tests {
         status: String,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
+        tenant_id: Option<Uuid>,
     }
 
     impl TestDataEntity {
@@ -298,6 +815,14 @@ mod tests {
                 status: "active".to_string(),
                 created_at: now,
                 updated_at: now,
+                tenant_id: None,
+            }
+        }
+
+        fn owned_by(name: &str, tenant: Uuid) -> Self {
+            Self {
+                tenant_id: Some(tenant),
+                ..Self::new(name)
             }
         }
     }
@@ -342,6 +867,10 @@ mod tests {
         fn status(&self) -> &str {
             &self.status
         }
+
+        fn tenant_id(&self) -> Option<Uuid> {
+            self.tenant_id
+        }
     }
 
     impl crate::core::Data for TestDataEntity {
@@ -419,6 +948,34 @@ mod tests {
         assert!(all.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_data_get_if_modified() {
+        use crate::core::etag::{CacheResult, etag_of};
+
+        let service = InMemoryDataService::<TestDataEntity>::new();
+        let entity = TestDataEntity::new("Alice");
+        service.create(entity.clone()).await.unwrap();
+
+        let etag = etag_of(&entity);
+        let cached = service
+            .get_if_modified(&entity.id, Some(&etag))
+            .await
+            .unwrap();
+        assert_eq!(cached, CacheResult::NotModified);
+
+        let fresh = service
+            .get_if_modified(&entity.id, Some("\"0\""))
+            .await
+            .unwrap();
+        assert_eq!(fresh, CacheResult::Modified(entity.clone()));
+
+        let missing = service
+            .get_if_modified(&Uuid::new_v4(), Some(&etag))
+            .await
+            .unwrap();
+        assert_eq!(missing, CacheResult::NotFound);
+    }
+
     #[tokio::test]
     async fn test_data_update_entity() {
         let service = InMemoryDataService::<TestDataEntity>::new();
@@ -524,6 +1081,51 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_data_tenant_scoping_hides_other_tenants() {
+        let service = InMemoryDataService::<TestDataEntity>::new();
+        let (tenant_a, tenant_b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let owned = service
+            .create(TestDataEntity::owned_by("Alice", tenant_a))
+            .await
+            .unwrap();
+        service
+            .create(TestDataEntity::owned_by("Alice", tenant_b))
+            .await
+            .unwrap();
+        service.create(TestDataEntity::new("Alice")).await.unwrap();
+
+        let a = TenantContext::new(tenant_a);
+        let b = TenantContext::new(tenant_b);
+
+        let listed = service.list_for_tenant(&a).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, owned.id);
+
+        let found = service
+            .search_for_tenant("entity_name", "Alice", &a)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, owned.id);
+
+        assert!(
+            service
+                .get_for_tenant(&owned.id, &a)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            service
+                .get_for_tenant(&owned.id, &b)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[tokio::test]
     async fn test_data_clone_shares_state() {
         let service = InMemoryDataService::<TestDataEntity>::new();
@@ -546,8 +1148,12 @@ mod tests {
         entity_name: String,
         status: String,
         ref_id: Uuid,
+        quantity: i64,
+        price: f64,
+        in_stock: bool,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
+        deleted_at: Option<DateTime<Utc>>,
     }
 
     impl ExtendedTestEntity {
@@ -558,8 +1164,12 @@ mod tests {
                 entity_name: name.to_string(),
                 status: "active".to_string(),
                 ref_id,
+                quantity: 1,
+                price: 9.5,
+                in_stock: true,
                 created_at: now,
                 updated_at: now,
+                deleted_at: None,
             }
         }
     }
@@ -598,7 +1208,7 @@ mod tests {
         }
 
         fn deleted_at(&self) -> Option<DateTime<Utc>> {
-            None
+            self.deleted_at
         }
 
         fn status(&self) -> &str {
@@ -612,7 +1222,15 @@ mod tests {
         }
 
         fn indexed_fields() -> &'static [&'static str] {
-            &["entity_name", "status", "ref_id", "created_at"]
+            &[
+                "entity_name",
+                "status",
+                "ref_id",
+                "quantity",
+                "price",
+                "in_stock",
+                "created_at",
+            ]
         }
 
         fn field_value(&self, field: &str) -> Option<FieldValue> {
@@ -620,7 +1238,11 @@ mod tests {
                 "entity_name" => Some(FieldValue::String(self.entity_name.clone())),
                 "status" => Some(FieldValue::String(self.status.clone())),
                 "ref_id" => Some(FieldValue::Uuid(self.ref_id)),
+                "quantity" => Some(FieldValue::Integer(self.quantity)),
+                "price" => Some(FieldValue::Float(self.price)),
+                "in_stock" => Some(FieldValue::Boolean(self.in_stock)),
                 "created_at" => Some(FieldValue::DateTime(self.created_at)),
+                "discontinued_at" => Some(FieldValue::Null),
                 _ => None,
             }
         }
@@ -677,6 +1299,289 @@ mod tests {
         assert_eq!(results[0].entity_name, "Timed");
     }
 
+    #[tokio::test]
+    async fn test_data_search_matches_every_field_value_variant() {
+        let service = InMemoryDataService::<ExtendedTestEntity>::new();
+        let mut entity = ExtendedTestEntity::new("Widget", Uuid::new_v4());
+        entity.quantity = 42;
+        entity.price = 12.25;
+        entity.in_stock = false;
+        let (ref_id, created_at) = (entity.ref_id, entity.created_at);
+        service.create(entity).await.expect("create should succeed");
+        service
+            .create(ExtendedTestEntity::new("Other", Uuid::new_v4()))
+            .await
+            .expect("create should succeed");
+
+        for (field, value) in [
+            ("entity_name", "Widget".to_string()),
+            ("quantity", "42".to_string()),
+            ("price", "12.25".to_string()),
+            ("in_stock", "false".to_string()),
+            ("ref_id", ref_id.to_string()),
+            ("created_at", created_at.to_rfc3339()),
+        ] {
+            let results = service.search(field, &value).await.unwrap();
+            assert_eq!(results.len(), 1, "{field} = {value}");
+            assert_eq!(results[0].entity_name, "Widget");
+        }
+
+        // Null never matches, not even the literal "null"
+        assert!(
+            service
+                .search("discontinued_at", "null")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_data_list_and_search_skip_soft_deleted() {
+        let service = InMemoryDataService::<ExtendedTestEntity>::new();
+        let mut deleted = ExtendedTestEntity::new("Gone", Uuid::new_v4());
+        deleted.deleted_at = Some(Utc::now());
+        let deleted_id = deleted.id;
+        service.create(deleted).await.unwrap();
+        service
+            .create(ExtendedTestEntity::new("Kept", Uuid::new_v4()))
+            .await
+            .unwrap();
+
+        let listed = service.list().await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].entity_name, "Kept");
+        assert_eq!(service.list_with_deleted().await.unwrap().len(), 2);
+        assert_eq!(service.count().await.unwrap(), 1);
+
+        assert!(service.get(&deleted_id).await.unwrap().is_none());
+        assert!(
+            service
+                .get_with_deleted(&deleted_id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            service
+                .search("entity_name", "Gone")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    crate::impl_data_entity!(
+        TestAccount,
+        "test_account",
+        ["name"],
+        {
+            email: String,
+        },
+        unique: ["email"]
+    );
+
+    fn account(email: &str) -> TestAccount {
+        TestAccount::new("Ada".to_string(), "active".to_string(), email.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_data_unique_field_rejects_duplicates_with_409() {
+        use axum::response::IntoResponse;
+
+        let service = InMemoryDataService::<TestAccount>::new();
+        let first = service.create(account("ada@example.com")).await.unwrap();
+
+        let err = service
+            .create(account("ada@example.com"))
+            .await
+            .unwrap_err();
+        let err = err.downcast::<crate::storage::StorageError>().unwrap();
+        assert_eq!(
+            err,
+            crate::storage::StorageError::AlreadyExists {
+                entity_type: "test_account".to_string(),
+                field: "email".to_string(),
+                value: "ada@example.com".to_string(),
+            }
+        );
+        assert_eq!(
+            err.into_response().status(),
+            axum::http::StatusCode::CONFLICT
+        );
+        assert_eq!(service.count().await.unwrap(), 1);
+
+        // Updating another account onto the value fails, re-saving the holder does not
+        let second = service.create(account("bob@example.com")).await.unwrap();
+        let err = service
+            .patch(&second.id, serde_json::json!({"email": "ada@example.com"}))
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<crate::storage::StorageError>().is_some());
+        service.update(&first.id, first.clone()).await.unwrap();
+
+        // A soft-deleted account releases its value
+        service.soft_delete(&first.id).await.unwrap();
+        service.create(account("ada@example.com")).await.unwrap();
+        assert!(
+            service
+                .create_many(vec![account("eve@example.com"), account("eve@example.com")])
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_data_soft_delete_with_status_reverts_previous_status() {
+        let service = InMemoryDataService::<TestAccount>::new();
+        let transition = SoftDeleteStatus::new("deleted", "archived");
+        let live = service.create(account("ada@example.com")).await.unwrap();
+
+        let deleted = service
+            .soft_delete_with_status(&live.id, &transition)
+            .await
+            .unwrap();
+        assert!(deleted.deleted_at.is_some());
+        assert_eq!(deleted.status, "deleted");
+
+        // The status before the deletion wins over on_restore
+        let restored = service
+            .restore_with_status(&live.id, &transition)
+            .await
+            .unwrap();
+        assert!(restored.deleted_at.is_none());
+        assert_eq!(restored.status, "active");
+
+        // Without a recorded status, on_restore is used
+        service.soft_delete(&live.id).await.unwrap();
+        let restored = service
+            .restore_with_status(&live.id, &transition)
+            .await
+            .unwrap();
+        assert_eq!(restored.status, "archived");
+    }
+
+    #[tokio::test]
+    async fn test_data_list_page_walks_every_entity_once() {
+        let service = InMemoryDataService::<TestDataEntity>::new();
+        for i in 0..5 {
+            service
+                .create(TestDataEntity::new(&format!("e{}", i)))
+                .await
+                .unwrap();
+        }
+
+        let mut seen = HashSet::new();
+        let mut token = None;
+        for expected in [2, 2, 1] {
+            let (page, next) = service.list_page(2, token).await.unwrap();
+            assert_eq!(page.len(), expected);
+            seen.extend(page.iter().map(|e| e.id));
+            token = next;
+        }
+        assert!(token.is_none());
+        assert_eq!(seen.len(), 5);
+        assert!(service.list_page(2, Some("bogus".into())).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_data_query_filters_sorts_and_windows() {
+        use crate::core::{QueryBuilder, QueryOp, SortDirection};
+
+        let service = InMemoryDataService::<TestDataEntity>::new();
+        for (name, status) in [
+            ("bob", "active"),
+            ("tom", "active"),
+            ("joe", "archived"),
+            ("amy", "active"),
+            ("rob", "active"),
+        ] {
+            let mut entity = TestDataEntity::new(name);
+            entity.status = status.to_string();
+            service.create(entity).await.unwrap();
+        }
+
+        let names = |entities: Vec<TestDataEntity>| {
+            entities
+                .into_iter()
+                .map(|e| e.entity_name)
+                .collect::<Vec<_>>()
+        };
+        let query = QueryBuilder::new()
+            .filter("status", QueryOp::Eq, "active")
+            .filter("entity_name", QueryOp::Contains, "o")
+            .sort("entity_name", SortDirection::Desc)
+            .build()
+            .unwrap();
+        assert_eq!(
+            names(service.query(&query).await.unwrap()),
+            ["tom", "rob", "bob"]
+        );
+
+        let window = QueryBuilder::new()
+            .filter("status", QueryOp::Eq, "active")
+            .sort("entity_name", SortDirection::Asc)
+            .offset(1)
+            .limit(2)
+            .build()
+            .unwrap();
+        assert_eq!(names(service.query(&window).await.unwrap()), ["bob", "rob"]);
+
+        assert!(
+            QueryBuilder::new()
+                .filter("status", QueryOp::In, "active")
+                .build()
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_data_get_at_returns_each_recorded_state() {
+        let service = InMemoryDataService::<TestDataEntity>::new().with_history();
+        let entity = service.create(TestDataEntity::new("v1")).await.unwrap();
+        for name in ["v2", "v3", "v4"] {
+            let mut next = entity.clone();
+            next.entity_name = name.to_string();
+            service.update(&entity.id, next).await.unwrap();
+        }
+
+        let name_at = |version| {
+            let service = service.clone();
+            async move {
+                service
+                    .get_at(&entity.id, version)
+                    .await
+                    .unwrap()
+                    .map(|e| e.entity_name)
+            }
+        };
+        assert_eq!(name_at(1).await.as_deref(), Some("v1"));
+        assert_eq!(name_at(2).await.as_deref(), Some("v2"));
+        assert_eq!(name_at(4).await.as_deref(), Some("v4"));
+        assert_eq!(name_at(5).await, None);
+
+        let now = service.get_as_of(&entity.id, Utc::now()).await.unwrap();
+        assert_eq!(now.map(|e| e.entity_name).as_deref(), Some("v4"));
+        let before_creation = entity.created_at - chrono::Duration::seconds(1);
+        assert!(
+            service
+                .get_as_of(&entity.id, before_creation)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // Without history there is nothing to read back
+        let err = InMemoryDataService::<TestDataEntity>::new()
+            .get_at(&entity.id, 1)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<HistoryError>(),
+            Some(&HistoryError::NotEnabled)
+        );
+    }
+
     #[tokio::test]
     async fn test_data_default_creates_empty_service() {
         let service = InMemoryDataService::<TestDataEntity>::default();
@@ -706,6 +1611,45 @@ mod tests {
         assert_eq!(created.target_id, car_id);
     }
 
+    #[tokio::test]
+    async fn test_create_unique_skips_live_duplicates() {
+        let service = InMemoryLinkService::new();
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+
+        let first = service
+            .create_unique(LinkEntity::new("owner", user_id, car_id, None))
+            .await
+            .unwrap()
+            .expect("first link should be created");
+        let duplicate = service
+            .create_unique(LinkEntity::new("owner", user_id, car_id, None))
+            .await
+            .unwrap();
+        assert!(duplicate.is_none());
+
+        // Another type between the same entities is not a duplicate
+        assert!(
+            service
+                .create_unique(LinkEntity::new("driver", user_id, car_id, None))
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        // Nor is a link replacing a soft-deleted one
+        let mut removed = service.get(&first.id).await.unwrap().unwrap();
+        removed.soft_delete();
+        service.update(&removed.id, removed.clone()).await.unwrap();
+        assert!(
+            service
+                .create_unique(LinkEntity::new("owner", user_id, car_id, None))
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
     #[tokio::test]
     async fn test_get_link() {
         let service = InMemoryLinkService::new();
@@ -718,6 +1662,85 @@ mod tests {
         assert_eq!(retrieved.unwrap().id, link.id);
     }
 
+    #[tokio::test]
+    async fn test_find_links_by_page() {
+        let service = InMemoryLinkService::new();
+        let user_id = Uuid::new_v4();
+        let start = Utc::now();
+        let mut links = Vec::new();
+        for i in 0..25 {
+            let mut link = LinkEntity::new("owner", user_id, Uuid::new_v4(), None);
+            link.created_at = start + chrono::Duration::seconds(i);
+            links.push(service.create(link).await.unwrap());
+        }
+
+        // Newest first: page 2 of 10 holds the 11th to 20th newest
+        let (page, total) = service
+            .find_by_source_page(&user_id, Some("owner"), None, 10, 10)
+            .await
+            .unwrap();
+        assert_eq!(total, 25);
+        let expected: Vec<Uuid> = links[5..15].iter().rev().map(|l| l.id).collect();
+        assert_eq!(page.iter().map(|l| l.id).collect::<Vec<_>>(), expected);
+
+        let (page, total) = service
+            .find_by_target_page(&links[0].target_id, None, None, 10, 10)
+            .await
+            .unwrap();
+        assert_eq!((page.len(), total), (0, 1));
+    }
+
+    #[tokio::test]
+    async fn test_get_between() {
+        let service = InMemoryLinkService::new();
+        let (user_id, car_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let link = service
+            .create(LinkEntity::new("owner", user_id, car_id, None))
+            .await
+            .unwrap();
+
+        let found = service
+            .get_between(&user_id, &car_id, "owner")
+            .await
+            .unwrap();
+        assert_eq!(found.map(|l| l.id), Some(link.id));
+
+        // Wrong direction, wrong type, unknown target
+        assert!(
+            service
+                .get_between(&car_id, &user_id, "owner")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            service
+                .get_between(&user_id, &car_id, "driver")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            service
+                .get_between(&user_id, &Uuid::new_v4(), "owner")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // Soft-deleted links are not found
+        let mut removed = link.clone();
+        removed.soft_delete();
+        service.update(&link.id, removed).await.unwrap();
+        assert!(
+            service
+                .get_between(&user_id, &car_id, "owner")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[tokio::test]
     async fn test_list_links() {
         let service = InMemoryLinkService::new();
@@ -764,6 +1787,84 @@ mod tests {
         assert_eq!(owner_links[0].link_type, "owner");
     }
 
+    #[tokio::test]
+    async fn test_find_links_for_tenant() {
+        let service = InMemoryLinkService::new();
+        let (tenant_a, tenant_b) = (Uuid::new_v4(), Uuid::new_v4());
+        let user_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+
+        service
+            .create(LinkEntity::new_with_tenant(
+                tenant_a, "owner", user_id, car_id, None,
+            ))
+            .await
+            .unwrap();
+        service
+            .create(LinkEntity::new_with_tenant(
+                tenant_b, "driver", user_id, car_id, None,
+            ))
+            .await
+            .unwrap();
+
+        let a = TenantContext::new(tenant_a);
+        let from_user = service
+            .find_by_source_for_tenant(&user_id, None, None, &a)
+            .await
+            .unwrap();
+        assert_eq!(from_user.len(), 1);
+        assert_eq!(from_user[0].link_type, "owner");
+
+        let to_car = service
+            .find_by_target_for_tenant(&car_id, None, None, &TenantContext::new(tenant_b))
+            .await
+            .unwrap();
+        assert_eq!(to_car.len(), 1);
+        assert_eq!(to_car[0].link_type, "driver");
+
+        let other = TenantContext::new(Uuid::new_v4());
+        assert!(
+            service
+                .find_by_source_for_tenant(&user_id, None, None, &other)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_by_sources_and_targets() {
+        let service = InMemoryLinkService::new();
+        let user1_id = Uuid::new_v4();
+        let user2_id = Uuid::new_v4();
+        let user3_id = Uuid::new_v4();
+        let car_id = Uuid::new_v4();
+
+        for user_id in [user1_id, user2_id, user3_id] {
+            service
+                .create(LinkEntity::new("owner", user_id, car_id, None))
+                .await
+                .unwrap();
+        }
+        service
+            .create(LinkEntity::new("driver", user1_id, car_id, None))
+            .await
+            .unwrap();
+
+        let links = service
+            .find_by_sources(&[user1_id, user2_id], Some("owner"), None)
+            .await
+            .unwrap();
+        assert_eq!(links.len(), 2);
+        assert!(links.iter().all(|l| l.source_id != user3_id));
+
+        let links = service
+            .find_by_targets(&[car_id], None, None)
+            .await
+            .unwrap();
+        assert_eq!(links.len(), 4);
+    }
+
     #[tokio::test]
     async fn test_find_by_target() {
         let service = InMemoryLinkService::new();
@@ -796,6 +1897,70 @@ mod tests {
         assert_eq!(driver_links[0].link_type, "driver");
     }
 
+    #[tokio::test]
+    async fn test_find_by_source_honors_target_type() {
+        let service = InMemoryLinkService::new();
+        let user_id = Uuid::new_v4();
+
+        service
+            .create(
+                LinkEntity::new("owner", user_id, Uuid::new_v4(), None)
+                    .with_entity_types("user", "car"),
+            )
+            .await
+            .unwrap();
+        service
+            .create(
+                LinkEntity::new("owner", user_id, Uuid::new_v4(), None)
+                    .with_entity_types("user", "house"),
+            )
+            .await
+            .unwrap();
+        // Untyped links match any type filter
+        service
+            .create(LinkEntity::new("owner", user_id, Uuid::new_v4(), None))
+            .await
+            .unwrap();
+
+        let cars = service
+            .find_by_source(&user_id, Some("owner"), Some("car"))
+            .await
+            .unwrap();
+        assert_eq!(cars.len(), 2);
+        assert!(
+            cars.iter()
+                .all(|l| l.target_type.as_deref() != Some("house"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_by_target_honors_source_type() {
+        let service = InMemoryLinkService::new();
+        let car_id = Uuid::new_v4();
+
+        service
+            .create(
+                LinkEntity::new("owner", Uuid::new_v4(), car_id, None)
+                    .with_entity_types("user", "car"),
+            )
+            .await
+            .unwrap();
+        service
+            .create(
+                LinkEntity::new("owner", Uuid::new_v4(), car_id, None)
+                    .with_entity_types("company", "car"),
+            )
+            .await
+            .unwrap();
+
+        let owners = service
+            .find_by_target(&car_id, None, Some("company"))
+            .await
+            .unwrap();
+        assert_eq!(owners.len(), 1);
+        assert_eq!(owners[0].source_type.as_deref(), Some("company"));
+    }
+
     #[tokio::test]
     async fn test_update_link() {
         let service = InMemoryLinkService::new();
@@ -869,4 +2034,50 @@ mod tests {
         assert_ne!(remaining[0].source_id, user_id);
         assert_ne!(remaining[0].target_id, user_id);
     }
+
+    #[tokio::test]
+    async fn test_history_versions_are_numbered_per_entity() {
+        let history = InMemoryHistoryService::new();
+        let order_id = Uuid::new_v4();
+
+        history
+            .record("order", &order_id, serde_json::json!({"total": 10}), None)
+            .await
+            .unwrap();
+        let second = history
+            .record(
+                "order",
+                &order_id,
+                serde_json::json!({"total": 12}),
+                Some("alice"),
+            )
+            .await
+            .unwrap();
+        history
+            .record("order", &Uuid::new_v4(), serde_json::json!({}), None)
+            .await
+            .unwrap();
+
+        assert_eq!(second.version, 2);
+        let versions = history.list_versions("order", &order_id).await.unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].snapshot, serde_json::json!({"total": 10}));
+
+        let v2 = history.get_version("order", &order_id, 2).await.unwrap();
+        assert_eq!(v2.and_then(|v| v.actor), Some("alice".to_string()));
+        assert!(
+            history
+                .get_version("order", &order_id, 3)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            history
+                .list_versions("invoice", &order_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
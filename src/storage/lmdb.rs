@@ -29,8 +29,9 @@
 //!
 //! Enable with `--features lmdb`. Requires the `heed` crate.
 
+use crate::core::actor;
 use crate::core::field::FieldValue;
-use crate::core::link::LinkEntity;
+use crate::core::link::{LinkEntity, LinkLimit};
 use crate::core::{Data, DataService, LinkService};
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
@@ -122,7 +123,9 @@ impl<T: Data> Clone for LmdbDataService<T> {
 impl<T: Data + serde::Serialize + serde::de::DeserializeOwned> DataService<T>
     for LmdbDataService<T>
 {
-    async fn create(&self, entity: T) -> Result<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
         let env = self.env.clone();
         let db = self.db;
         let key = entity.id().to_string();
@@ -137,7 +140,7 @@ impl<T: Data + serde::Serialize + serde::de::DeserializeOwned> DataService<T>
         .await?
     }
 
-    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
         let env = self.env.clone();
         let db = self.db;
         let key = id.to_string();
@@ -152,7 +155,7 @@ impl<T: Data + serde::Serialize + serde::de::DeserializeOwned> DataService<T>
         .await?
     }
 
-    async fn list(&self) -> Result<Vec<T>> {
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
         let env = self.env.clone();
         let db = self.db;
 
@@ -161,14 +164,19 @@ impl<T: Data + serde::Serialize + serde::de::DeserializeOwned> DataService<T>
             let mut results = Vec::new();
             for item in db.iter(&rtxn)? {
                 let (_key, bytes) = item?;
-                results.push(lmdb_decode(bytes)?);
+                let entity: T = lmdb_decode(bytes)?;
+                if include_deleted || entity.deleted_at().is_none() {
+                    results.push(entity);
+                }
             }
             Ok(results)
         })
         .await?
     }
 
-    async fn update(&self, id: &Uuid, entity: T) -> Result<T> {
+    async fn update(&self, id: &Uuid, mut entity: T) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
         let env = self.env.clone();
         let db = self.db;
         let key = id.to_string();
@@ -213,6 +221,9 @@ impl<T: Data + serde::Serialize + serde::de::DeserializeOwned> DataService<T>
             for item in db.iter(&rtxn)? {
                 let (_key, bytes) = item?;
                 let entity: T = lmdb_decode(bytes)?;
+                if entity.deleted_at().is_some() {
+                    continue;
+                }
                 if entity.field_value(&field).is_some_and(|fv| match &fv {
                     FieldValue::String(s) => s == &value,
                     FieldValue::Integer(i) => i.to_string() == value,
@@ -311,6 +322,60 @@ impl LinkService for LmdbLinkService {
         .await?
     }
 
+    /// Counts and inserts in one write transaction; LMDB allows a single
+    /// writer at a time, so concurrent bounded creates are serialized.
+    async fn create_within_limit(
+        &self,
+        link: LinkEntity,
+        limit: LinkLimit,
+    ) -> Result<Option<LinkEntity>> {
+        let env = self.env.clone();
+        let links_db = self.links_db;
+        let by_source_db = self.by_source_db;
+        let by_target_db = self.by_target_db;
+        let bytes = lmdb_encode(&link)?;
+        let key = link.id.to_string();
+        let source_key = composite_key(&link.source_id, &link.id);
+        let target_key = composite_key(&link.target_id, &link.id);
+
+        tokio::task::spawn_blocking(move || {
+            let mut wtxn = env.write_txn()?;
+
+            let mut existing: Vec<LinkEntity> = Vec::new();
+            for (bounded, index, entity_id) in [
+                (limit.per_source.is_some(), by_source_db, link.source_id),
+                (limit.per_target.is_some(), by_target_db, link.target_id),
+            ] {
+                if !bounded {
+                    continue;
+                }
+                let prefix = format!("{}:", entity_id);
+                for item in index.prefix_iter(&wtxn, &prefix)? {
+                    let (composite, _) = item?;
+                    if let Some(bytes) = links_db.get(&wtxn, &composite[prefix.len()..])? {
+                        let found: LinkEntity = lmdb_decode(bytes)?;
+                        // A link sharing both endpoints is found through both indexes
+                        if !existing.iter().any(|l| l.id == found.id) {
+                            existing.push(found);
+                        }
+                    }
+                }
+            }
+
+            let (source_count, target_count) = LinkLimit::count(&link, &existing);
+            if !limit.allows(source_count, target_count) {
+                return Ok(None);
+            }
+
+            links_db.put(&mut wtxn, &key, &bytes)?;
+            by_source_db.put(&mut wtxn, &source_key, &[])?;
+            by_target_db.put(&mut wtxn, &target_key, &[])?;
+            wtxn.commit()?;
+            Ok(Some(link))
+        })
+        .await?
+    }
+
     async fn get(&self, id: &Uuid) -> Result<Option<LinkEntity>> {
         let env = self.env.clone();
         let links_db = self.links_db;
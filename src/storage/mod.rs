@@ -2,6 +2,7 @@
 
 #[cfg(feature = "dynamodb")]
 pub mod dynamodb;
+pub mod error;
 pub mod in_memory;
 #[cfg(feature = "lmdb")]
 pub mod lmdb;
@@ -13,21 +14,36 @@ pub mod mysql;
 pub mod neo4j;
 #[cfg(feature = "postgres")]
 pub mod postgres;
+#[cfg(feature = "redis")]
+pub mod redis;
 #[cfg(feature = "scylladb")]
 pub mod scylladb;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
 #[cfg(feature = "lmdb")]
 pub use self::lmdb::{LmdbDataService, LmdbLinkService};
 #[cfg(feature = "mongodb_backend")]
 pub use self::mongodb::{MongoDataService, MongoLinkService};
 #[cfg(feature = "mysql")]
-pub use self::mysql::{MysqlDataService, MysqlLinkService};
+pub use self::mysql::{
+    MysqlAuditLogService, MysqlDataService, MysqlHistoryService, MysqlLinkService,
+    MysqlOutboxService,
+};
 #[cfg(feature = "neo4j")]
 pub use self::neo4j::{Neo4jDataService, Neo4jLinkService};
+#[cfg(feature = "redis")]
+pub use self::redis::RedisLinkService;
 #[cfg(feature = "dynamodb")]
 pub use dynamodb::{DynamoDBDataService, DynamoDBLinkService};
-pub use in_memory::{InMemoryDataService, InMemoryLinkService};
+pub use error::StorageError;
+pub use in_memory::{
+    InMemoryAuditLogService, InMemoryDataService, InMemoryHistoryService, InMemoryLinkService,
+    InMemoryOutboxService,
+};
 #[cfg(feature = "postgres")]
-pub use postgres::{PostgresDataService, PostgresLinkService};
+pub use postgres::{PostgresDataService, PostgresHistoryService, PostgresLinkService};
 #[cfg(feature = "scylladb")]
 pub use scylladb::{ScyllaDataService, ScyllaLinkService};
+#[cfg(feature = "sqlite")]
+pub use sqlite::{SqliteDataService, SqliteLinkService};
@@ -27,6 +27,7 @@
 //! UUID (stored as strings) and DateTime (stored as ISO 8601 strings) types.
 //! The `id` field is mapped to MongoDB's `_id` convention.
 
+use crate::core::actor;
 use crate::core::link::LinkEntity;
 use crate::core::{Data, DataService, LinkService};
 use anyhow::{Result, anyhow};
@@ -153,7 +154,9 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MongoDataService
     /// Insert a new entity into the collection.
     ///
     /// Inserts the document and reads it back to return the stored version.
-    async fn create(&self, entity: T) -> Result<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
         let doc = Self::entity_to_document(&entity)?;
         let id_bson = uuid_bson(&entity.id());
 
@@ -173,10 +176,10 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MongoDataService
         Self::document_to_entity(result)
     }
 
-    /// Fetch an entity by UUID.
+    /// Fetch an entity by UUID, including soft-deleted ones.
     ///
     /// Returns `Ok(None)` if the entity does not exist.
-    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
         let doc = self
             .collection()
             .find_one(doc! { "_id": uuid_bson(id) })
@@ -189,11 +192,18 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MongoDataService
         }
     }
 
-    /// List all entities, ordered by creation time (newest first).
-    async fn list(&self) -> Result<Vec<T>> {
+    /// List entities, ordered by creation time (newest first).
+    ///
+    /// A `deleted_at: null` filter matches both unset and null fields.
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
+        let filter = if include_deleted {
+            doc! {}
+        } else {
+            doc! { "deleted_at": Bson::Null }
+        };
         let cursor = self
             .collection()
-            .find(doc! {})
+            .find(filter)
             .sort(doc! { "created_at": -1 })
             .await
             .map_err(|e| anyhow!("Failed to list entities: {}", e))?;
@@ -209,7 +219,9 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MongoDataService
     /// Update an existing entity.
     ///
     /// Returns `Err` if the entity does not exist (no document matched).
-    async fn update(&self, id: &Uuid, entity: T) -> Result<T> {
+    async fn update(&self, id: &Uuid, mut entity: T) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
         let doc = Self::entity_to_document(&entity)?;
         let id_bson = uuid_bson(id);
 
@@ -273,11 +285,12 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MongoDataService
             }
         }
 
-        let filter = if variants.len() == 1 {
+        let mut filter = if variants.len() == 1 {
             doc! { field: variants.into_iter().next().unwrap() }
         } else {
             doc! { field: { "$in": variants } }
         };
+        filter.insert("deleted_at", Bson::Null);
 
         let cursor = self
             .collection()
@@ -25,17 +25,103 @@
 //! - No `RETURNING *` — uses SELECT after INSERT/UPDATE
 //! - `JSON_EXTRACT(data, '$.field')` instead of `data->>field`
 //! - `DATETIME(6)` instead of `TIMESTAMPTZ`
-
-use crate::core::link::LinkEntity;
-use crate::core::{Data, DataService, LinkService};
+//!
+//! # Entity history
+//!
+//! [`MysqlDataService::with_history`] stores the previous version of an
+//! entity in `entity_versions` on every update, in the same transaction.
+//! [`MysqlHistoryService`] reads them back.
+//!
+//! # Audit log
+//!
+//! [`MysqlAuditLogService`] appends mutation records to the `audit_log`
+//! table; it never updates or deletes them.
+//!
+//! # Optimistic concurrency
+//!
+//! Entities serializing a `version` field (see [`Entity::version`]) are
+//! updated with `WHERE version = ?` and get their version bumped. An update
+//! based on a stale version fails with [`StorageError::IntegrityError`].
+//! Writes under an `If-Match` precondition are also conditioned on the
+//! checked `updated_at` and version, and fail with
+//! [`StorageError::PreconditionFailed`] once the row moved on.
+//!
+//! # Unique fields
+//!
+//! [`MysqlDataService::ensure_unique_indexes`] backs the entity's
+//! [`Data::unique_fields`] with unique indexes; a write repeating a value
+//! fails with [`StorageError::AlreadyExists`].
+//!
+//! # Duplicate links
+//!
+//! Links inserted by `create_unique` are flagged `unique_pair`; the
+//! generated `live_pair` column is 1 for flagged links that are not
+//! soft-deleted and `NULL` otherwise. The unique `idx_link_pair` index on
+//! `(link_type, source_id, target_id, live_pair)` therefore rejects a second
+//! live flagged link between the same entities, while soft-deleted links
+//! and links created with duplicates allowed stay out of it. A rejected
+//! insert fails with [`LinkError::AlreadyExists`].
+//!
+//! [`Entity::version`]: crate::core::Entity::version
+//! [`LinkError::AlreadyExists`]: crate::core::link::LinkError::AlreadyExists
+
+use crate::core::actor;
+use crate::core::audit::{AuditEntry, AuditLogService};
+use crate::core::etag::{self, CacheResult, etag_for, etag_matches};
+use crate::core::events::{EntityEvent, FrameworkEvent, LinkEvent};
+use crate::core::field::FieldValue;
+use crate::core::history::{EntityVersion, HistoryError, HistoryService, state_as_of, state_at};
+use crate::core::link::{
+    LinkCardinality, LinkEntity, LinkError, LinkFilterCondition, LinkFilterField, LinkLimit,
+    RelationDirection,
+};
+use crate::core::outbox::{OutboxEntry, OutboxService};
+use crate::core::query::{Cursor, FilterClause, FilterOp};
+use crate::core::soft_delete::{SoftDeleteError, SoftDeleteStatus};
+use crate::core::store::{Query, QueryableStore};
+use crate::core::tenant::TenantContext;
+use crate::core::validation::constraints::check_column_widths;
+use crate::core::{Data, DataService, HealthCheck, LinkService};
+use crate::storage::StorageError;
+use crate::storage::error::check_precondition;
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use sqlx::MySqlPool;
+use sqlx::{MySql, MySqlConnection, MySqlPool, QueryBuilder};
 use uuid::Uuid;
 
+/// Rows per multi-row `INSERT` in `create_many` (10 placeholders each, well
+/// under MySQL's 65,535 limit)
+const CREATE_MANY_CHUNK: usize = 500;
+
+/// Width of the `entities.name` column (`VARCHAR(255)`)
+const NAME_COLUMN_WIDTH: usize = 255;
+
+/// Width of the `entities.status` column (`VARCHAR(50)`)
+const STATUS_COLUMN_WIDTH: usize = 50;
+
+/// Longest identifier MySQL accepts for a column or index
+const MAX_IDENTIFIER_LENGTH: usize = 64;
+
+/// Value of the generated `entities.owner_id` column, read from the
+/// `owner_id` the entity serializes into `data`
+const OWNER_COLUMN_EXPR: &str = "IF(JSON_TYPE(JSON_EXTRACT(data, '$.owner_id')) = 'STRING', \
+     JSON_UNQUOTE(JSON_EXTRACT(data, '$.owner_id')), NULL)";
+
+/// Fields the entity serializes into `data` that are mirrored as generated
+/// `VARCHAR(255)` columns recording who wrote the entity
+const ACTOR_COLUMNS: [&str; 2] = ["created_by", "updated_by"];
+
+/// Value of the generated column mirroring the string field `field` of `data`
+fn actor_column_expr(field: &str) -> String {
+    format!(
+        "IF(JSON_TYPE(JSON_EXTRACT(data, '$.{field}')) = 'STRING', \
+         JSON_UNQUOTE(JSON_EXTRACT(data, '$.{field}')), NULL)"
+    )
+}
+
 // ---------------------------------------------------------------------------
 // Schema management
 // ---------------------------------------------------------------------------
@@ -43,12 +129,18 @@ use uuid::Uuid;
 /// Apply the required tables and indexes (idempotent).
 ///
 /// This creates:
-/// - `entities` table with common columns + JSON data column
+/// - `entities` table with common columns + JSON data column (adding the
+///   `version` column to tables created without it)
 /// - `links` table with indexed source/target columns
+/// - `entity_versions` table for optional entity history
+/// - `audit_log` table for the optional audit log
+/// - `outbox` table for the optional transactional outbox
+/// - `link_type_locks` table, one row per link type, locked by
+///   `create_within_limit`
 ///
 /// Safe to call on every startup.
 pub async fn ensure_schema(pool: &MySqlPool) -> Result<()> {
-    sqlx::query(
+    sqlx::query(&format!(
         "CREATE TABLE IF NOT EXISTS entities (
             id CHAR(36) NOT NULL PRIMARY KEY,
             entity_type VARCHAR(255) NOT NULL,
@@ -59,14 +151,98 @@ pub async fn ensure_schema(pool: &MySqlPool) -> Result<()> {
             created_at DATETIME(6) NOT NULL,
             updated_at DATETIME(6) NOT NULL,
             deleted_at DATETIME(6) NULL,
+            status_before_delete VARCHAR(50) NULL,
+            version BIGINT NOT NULL DEFAULT 0,
+            owner_id CHAR(36) GENERATED ALWAYS AS ({owner}) STORED,
+            created_by VARCHAR(255) GENERATED ALWAYS AS ({created_by}) STORED,
+            updated_by VARCHAR(255) GENERATED ALWAYS AS ({updated_by}) STORED,
             INDEX idx_entity_type (entity_type),
-            INDEX idx_name (name)
+            INDEX idx_name (name),
+            INDEX idx_owner (owner_id)
         )",
-    )
+        owner = OWNER_COLUMN_EXPR,
+        created_by = actor_column_expr("created_by"),
+        updated_by = actor_column_expr("updated_by"),
+    ))
     .execute(pool)
     .await
     .map_err(|e| anyhow!("Failed to create entities table: {}", e))?;
 
+    // Tables created before optimistic concurrency lack the version column
+    let has_version: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM information_schema.COLUMNS \
+         WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'entities' AND COLUMN_NAME = 'version'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to inspect entities table: {}", e))?;
+    if has_version == 0 {
+        sqlx::query("ALTER TABLE entities ADD COLUMN version BIGINT NOT NULL DEFAULT 0")
+            .execute(pool)
+            .await
+            .map_err(|e| anyhow!("Failed to add entities.version column: {}", e))?;
+    }
+
+    // Tables created before entity ownership lack the owner column
+    let has_owner: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM information_schema.COLUMNS \
+         WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'entities' AND COLUMN_NAME = 'owner_id'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to inspect entities table: {}", e))?;
+    if has_owner == 0 {
+        let sql = format!(
+            "ALTER TABLE entities \
+             ADD COLUMN owner_id CHAR(36) GENERATED ALWAYS AS ({}) STORED, \
+             ADD INDEX idx_owner (owner_id)",
+            OWNER_COLUMN_EXPR
+        );
+        sqlx::query(&sql)
+            .execute(pool)
+            .await
+            .map_err(|e| anyhow!("Failed to add entities.owner_id column: {}", e))?;
+    }
+
+    // Tables created before audit stamping lack the actor columns
+    for column in ACTOR_COLUMNS {
+        let has_column: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM information_schema.COLUMNS \
+             WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'entities' AND COLUMN_NAME = ?",
+        )
+        .bind(column)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| anyhow!("Failed to inspect entities table: {}", e))?;
+        if has_column == 0 {
+            let sql = format!(
+                "ALTER TABLE entities \
+                 ADD COLUMN {column} VARCHAR(255) GENERATED ALWAYS AS ({}) STORED",
+                actor_column_expr(column)
+            );
+            sqlx::query(&sql)
+                .execute(pool)
+                .await
+                .map_err(|e| anyhow!("Failed to add entities.{} column: {}", column, e))?;
+        }
+    }
+
+    // Tables created before soft-delete status transitions lack this column
+    let has_status_before_delete: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM information_schema.COLUMNS \
+         WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'entities' \
+         AND COLUMN_NAME = 'status_before_delete'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to inspect entities table: {}", e))?;
+    if has_status_before_delete == 0 {
+        sqlx::query("ALTER TABLE entities ADD COLUMN status_before_delete VARCHAR(50) NULL")
+            .execute(pool)
+            .await
+            .map_err(|e| anyhow!("Failed to add entities.status_before_delete column: {}", e))?;
+    }
+
     sqlx::query(
         "CREATE TABLE IF NOT EXISTS links (
             id CHAR(36) NOT NULL PRIMARY KEY,
@@ -82,14 +258,114 @@ pub async fn ensure_schema(pool: &MySqlPool) -> Result<()> {
             created_at DATETIME(6) NOT NULL,
             updated_at DATETIME(6) NOT NULL,
             deleted_at DATETIME(6) NULL,
+            unique_pair BOOLEAN NOT NULL DEFAULT FALSE,
+            live_pair TINYINT AS (IF(unique_pair AND deleted_at IS NULL, 1, NULL)) STORED,
             INDEX idx_source (source_id, link_type),
-            INDEX idx_target (target_id, link_type)
+            INDEX idx_target (target_id, link_type),
+            UNIQUE INDEX idx_link_pair (link_type, source_id, target_id, live_pair)
         )",
     )
     .execute(pool)
     .await
     .map_err(|e| anyhow!("Failed to create links table: {}", e))?;
 
+    // Tables created before duplicate link checks lack the pair columns,
+    // and their pair index is missing or not unique
+    let has_live_pair: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM information_schema.COLUMNS \
+         WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'links' AND COLUMN_NAME = 'live_pair'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to inspect links table: {}", e))?;
+    if has_live_pair == 0 {
+        sqlx::query(
+            "ALTER TABLE links \
+             ADD COLUMN unique_pair BOOLEAN NOT NULL DEFAULT FALSE, \
+             ADD COLUMN live_pair TINYINT AS (IF(unique_pair AND deleted_at IS NULL, 1, NULL)) STORED",
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| anyhow!("Failed to add links pair columns: {}", e))?;
+    }
+    let pair_index_non_unique: Option<i64> = sqlx::query_scalar(
+        "SELECT MIN(NON_UNIQUE) FROM information_schema.STATISTICS \
+         WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'links' \
+         AND INDEX_NAME = 'idx_link_pair'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to inspect links table: {}", e))?;
+    if pair_index_non_unique != Some(0) {
+        let drop = if pair_index_non_unique.is_some() {
+            "DROP INDEX idx_link_pair, "
+        } else {
+            ""
+        };
+        sqlx::query(&format!(
+            "ALTER TABLE links {}ADD UNIQUE INDEX idx_link_pair (link_type, source_id, target_id, live_pair)",
+            drop
+        ))
+        .execute(pool)
+        .await
+        .map_err(|e| anyhow!("Failed to add links.idx_link_pair index: {}", e))?;
+    }
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS entity_versions (
+            entity_id CHAR(36) NOT NULL,
+            entity_type VARCHAR(255) NOT NULL,
+            version BIGINT NOT NULL,
+            snapshot JSON NOT NULL,
+            changed_at DATETIME(6) NOT NULL,
+            actor VARCHAR(255) NULL,
+            PRIMARY KEY (entity_type, entity_id, version)
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to create entity_versions table: {}", e))?;
+
+    // `BEFORE` is a reserved word
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS audit_log (
+            id CHAR(36) NOT NULL PRIMARY KEY,
+            entity_type VARCHAR(255) NOT NULL,
+            entity_id CHAR(36) NOT NULL,
+            operation VARCHAR(16) NOT NULL,
+            actor VARCHAR(255) NULL,
+            `before` JSON NULL,
+            `after` JSON NULL,
+            at DATETIME(6) NOT NULL,
+            INDEX idx_audit_entity (entity_type, entity_id, at)
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to create audit_log table: {}", e))?;
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS outbox (
+            id CHAR(36) NOT NULL PRIMARY KEY,
+            event JSON NOT NULL,
+            created_at DATETIME(6) NOT NULL,
+            sent_at DATETIME(6) NULL,
+            INDEX idx_outbox_unsent (sent_at, created_at)
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to create outbox table: {}", e))?;
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS link_type_locks (
+            link_type VARCHAR(255) NOT NULL PRIMARY KEY
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to create link_type_locks table: {}", e))?;
+
     Ok(())
 }
 
@@ -111,6 +387,207 @@ const ENTITY_COMMON_FIELDS: &[&str] = &[
 /// Common entity fields that can be searched via direct SQL column comparison.
 const SEARCHABLE_COLUMNS: &[&str] = &["name", "status"];
 
+/// Entity columns a list filter compares directly; other fields are read
+/// from the `data` JSON column.
+const FILTER_TEXT_COLUMNS: &[&str] = &["id", "name", "status"];
+const FILTER_TIME_COLUMNS: &[&str] = &["created_at", "updated_at"];
+
+/// JSON types an ordering comparison on a numeric operand is restricted to
+const JSON_NUMBER_TYPES: &str = "('INTEGER', 'UNSIGNED INTEGER', 'DOUBLE', 'DECIMAL')";
+
+/// A value bound into the conditions built by [`filter_where_sql`]
+#[derive(Debug, Clone, PartialEq)]
+enum FilterBind {
+    Text(String),
+    Int(i64),
+    Float(f64),
+    Time(DateTime<Utc>),
+}
+
+/// Build the ` AND ...` conditions used by `list_where`, with `?`
+/// placeholders bound in order from the returned values.
+///
+/// Column names come from [`FILTER_TEXT_COLUMNS`] and
+/// [`FILTER_TIME_COLUMNS`]; JSON paths are bound, never interpolated.
+/// Comparisons between mismatched types are false, as in
+/// [`FilterClause::matches`], except that string operands are compared as
+/// text against whatever the JSON field holds.
+fn filter_where_sql(clauses: &[FilterClause]) -> (String, Vec<FilterBind>) {
+    let mut sql = String::new();
+    let mut binds = Vec::new();
+    for clause in clauses {
+        let field = clause.field.as_str();
+        let condition = match &clause.op {
+            FilterOp::Eq(v) => filter_compare_sql(field, "=", v, &mut binds),
+            FilterOp::Ne(v) => format!(
+                "NOT COALESCE({}, FALSE)",
+                filter_compare_sql(field, "=", v, &mut binds)
+            ),
+            FilterOp::Gt(v) => filter_compare_sql(field, ">", v, &mut binds),
+            FilterOp::Gte(v) => filter_compare_sql(field, ">=", v, &mut binds),
+            FilterOp::Lt(v) => filter_compare_sql(field, "<", v, &mut binds),
+            FilterOp::Lte(v) => filter_compare_sql(field, "<=", v, &mut binds),
+            FilterOp::In(set) if set.is_empty() => "FALSE".to_string(),
+            FilterOp::In(set) => {
+                let alternatives: Vec<String> = set
+                    .iter()
+                    .map(|v| filter_compare_sql(field, "=", v, &mut binds))
+                    .collect();
+                format!("({})", alternatives.join(" OR "))
+            }
+            FilterOp::Contains(needle) => filter_contains_sql(field, needle, &mut binds),
+        };
+        sql.push_str(" AND ");
+        sql.push_str(&condition);
+    }
+    (sql, binds)
+}
+
+/// Path of a field inside the `data` column, or `None` if it cannot be quoted
+fn filter_json_path(field: &str) -> Option<String> {
+    (!field.contains(['"', '\\'])).then(|| format!("$.\"{}\"", field))
+}
+
+fn filter_compare_sql(
+    field: &str,
+    op: &str,
+    operand: &FieldValue,
+    binds: &mut Vec<FilterBind>,
+) -> String {
+    if FILTER_TEXT_COLUMNS.contains(&field) {
+        let text = match (field, operand) {
+            ("id", FieldValue::String(s)) => Uuid::parse_str(s).ok().map(|u| u.to_string()),
+            (_, FieldValue::String(s)) => Some(s.clone()),
+            (_, FieldValue::Uuid(u)) => Some(u.to_string()),
+            _ => None,
+        };
+        return match text {
+            Some(text) => {
+                binds.push(FilterBind::Text(text));
+                format!("{} {} ?", field, op)
+            }
+            None => "FALSE".to_string(),
+        };
+    }
+
+    if FILTER_TIME_COLUMNS.contains(&field) {
+        let time = match operand {
+            FieldValue::DateTime(t) => Some(*t),
+            FieldValue::String(s) => DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|t| t.with_timezone(&Utc)),
+            _ => None,
+        };
+        return match time {
+            Some(time) => {
+                binds.push(FilterBind::Time(time));
+                format!("{} {} ?", field, op)
+            }
+            None => "FALSE".to_string(),
+        };
+    }
+
+    let Some(path) = filter_json_path(field) else {
+        return "FALSE".to_string();
+    };
+    match operand {
+        FieldValue::Null if op == "=" => {
+            binds.push(FilterBind::Text(path));
+            "COALESCE(JSON_TYPE(JSON_EXTRACT(data, ?)), 'NULL') = 'NULL'".to_string()
+        }
+        FieldValue::Null => "FALSE".to_string(),
+        FieldValue::Integer(_) | FieldValue::Float(_) => {
+            binds.push(FilterBind::Text(path.clone()));
+            binds.push(FilterBind::Text(path));
+            binds.push(match operand {
+                FieldValue::Integer(i) => FilterBind::Int(*i),
+                _ => FilterBind::Float(operand.as_f64().unwrap_or_default()),
+            });
+            format!(
+                "(JSON_TYPE(JSON_EXTRACT(data, ?)) IN {} AND JSON_EXTRACT(data, ?) {} ?)",
+                JSON_NUMBER_TYPES, op
+            )
+        }
+        FieldValue::Boolean(b) => {
+            binds.push(FilterBind::Text(path.clone()));
+            binds.push(FilterBind::Text(path));
+            binds.push(FilterBind::Text(b.to_string()));
+            format!(
+                "(JSON_TYPE(JSON_EXTRACT(data, ?)) = 'BOOLEAN' \
+                 AND JSON_EXTRACT(data, ?) {} CAST(? AS JSON))",
+                op
+            )
+        }
+        FieldValue::String(_) | FieldValue::Uuid(_) | FieldValue::DateTime(_) => {
+            let text = match operand {
+                FieldValue::String(s) => s.clone(),
+                FieldValue::Uuid(u) => u.to_string(),
+                FieldValue::DateTime(t) => t.to_rfc3339(),
+                _ => unreachable!(),
+            };
+            binds.push(FilterBind::Text(path));
+            binds.push(FilterBind::Text(text));
+            format!("JSON_UNQUOTE(JSON_EXTRACT(data, ?)) {} ?", op)
+        }
+    }
+}
+
+fn filter_contains_sql(field: &str, needle: &str, binds: &mut Vec<FilterBind>) -> String {
+    let escaped = needle
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    let pattern = FilterBind::Text(format!("%{}%", escaped));
+
+    if FILTER_TEXT_COLUMNS.contains(&field) {
+        binds.push(pattern);
+        return format!("{} LIKE ?", field);
+    }
+    match filter_json_path(field) {
+        Some(path) if !FILTER_TIME_COLUMNS.contains(&field) => {
+            binds.push(FilterBind::Text(path.clone()));
+            binds.push(FilterBind::Text(path));
+            binds.push(pattern);
+            "(JSON_TYPE(JSON_EXTRACT(data, ?)) = 'STRING' \
+             AND JSON_UNQUOTE(JSON_EXTRACT(data, ?)) LIKE ?)"
+                .to_string()
+        }
+        _ => "FALSE".to_string(),
+    }
+}
+
+/// Build the ` ORDER BY ... LIMIT ? OFFSET ?` tail of a [`Query`], binding
+/// JSON paths and the window into `binds`.
+///
+/// Like [`sort_entities`](crate::core::query::sort_entities), missing and
+/// null fields sort last in either direction; JSON values otherwise follow
+/// MySQL's JSON ordering. Ties keep the newest first.
+fn query_tail_sql(query: &Query, binds: &mut Vec<FilterBind>) -> String {
+    let mut order = Vec::new();
+    for key in &query.sort {
+        let direction = if key.descending { "DESC" } else { "ASC" };
+        let field = key.field.as_str();
+        if FILTER_TEXT_COLUMNS.contains(&field) || FILTER_TIME_COLUMNS.contains(&field) {
+            order.push(format!("{} {}", field, direction));
+        } else if let Some(path) = filter_json_path(field) {
+            binds.push(FilterBind::Text(path.clone()));
+            binds.push(FilterBind::Text(path));
+            order.push(format!(
+                "COALESCE(JSON_TYPE(JSON_EXTRACT(data, ?)), 'NULL') = 'NULL', \
+                 JSON_EXTRACT(data, ?) {}",
+                direction
+            ));
+        }
+    }
+    order.push("created_at DESC".to_string());
+
+    // MySQL has no OFFSET without LIMIT
+    let limit = query.limit.map_or(i64::MAX, |limit| limit as i64);
+    binds.push(FilterBind::Int(limit));
+    binds.push(FilterBind::Int(query.offset as i64));
+    format!(" ORDER BY {} LIMIT ? OFFSET ?", order.join(", "))
+}
+
 // ---------------------------------------------------------------------------
 // MysqlDataService<T>
 // ---------------------------------------------------------------------------
@@ -134,6 +611,8 @@ const SEARCHABLE_COLUMNS: &[&str] = &["name", "status"];
 #[derive(Clone, Debug)]
 pub struct MysqlDataService<T> {
     pool: MySqlPool,
+    track_history: bool,
+    outbox: bool,
     _marker: std::marker::PhantomData<T>,
 }
 
@@ -141,10 +620,30 @@ impl<T> MysqlDataService<T> {
     pub fn new(pool: MySqlPool) -> Self {
         Self {
             pool,
+            track_history: false,
+            outbox: false,
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Record the previous version of an entity on every update
+    ///
+    /// The snapshot is written to `entity_versions` in the same transaction
+    /// as the update. Read versions back with [`MysqlHistoryService`].
+    pub fn with_history(mut self) -> Self {
+        self.track_history = true;
+        self
+    }
+
+    /// Record an entity event in the `outbox` table on every mutation
+    ///
+    /// The row is written in the same transaction as the change; publish
+    /// it with [`MysqlOutboxService`] and `ServerBuilder::with_outbox`.
+    pub fn with_outbox(mut self) -> Self {
+        self.outbox = true;
+        self
+    }
+
     pub fn pool(&self) -> &MySqlPool {
         &self.pool
     }
@@ -155,11 +654,259 @@ impl<T: Data + Serialize + DeserializeOwned> MysqlDataService<T> {
         T::resource_name_singular()
     }
 
+    /// Read one entity, soft-deleted or not
+    async fn read_row<'e, E>(executor: E, id: &Uuid) -> Result<Option<T>>
+    where
+        E: sqlx::Executor<'e, Database = MySql>,
+    {
+        let row = sqlx::query_as::<_, (String, String, String, String, Option<String>, serde_json::Value, DateTime<Utc>, DateTime<Utc>, Option<DateTime<Utc>>)>(
+            "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
+             FROM entities WHERE id = ? AND entity_type = ?",
+        )
+        .bind(id.to_string())
+        .bind(Self::entity_type_name())
+        .fetch_optional(executor)
+        .await
+        .map_err(|e| anyhow!("Failed to get entity: {}", e))?;
+
+        match row {
+            Some((id, etype, name, status, tid, data, cat, uat, dat)) => Ok(Some(
+                Self::reconstruct_entity(id, etype, name, status, tid, data, cat, uat, dat)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Record an `Updated` event for the row as `conn` now sees it
+    async fn record_updated(conn: &mut MySqlConnection, id: &Uuid) -> Result<()> {
+        let entity = Self::read_row(&mut *conn, id)
+            .await?
+            .ok_or_else(|| anyhow!("Entity not found: {}", id))?;
+        let event = EntityEvent::Updated {
+            entity_type: Self::entity_type_name().to_string(),
+            entity_id: *id,
+            data: serde_json::to_value(&entity)?,
+        };
+        insert_outbox_event(conn, &FrameworkEvent::Entity(event)).await?;
+        Ok(())
+    }
+
+    /// Soft-delete a live entity, moving its status to `status` when given
+    ///
+    /// The status it replaces is kept in `status_before_delete` for
+    /// [`restore_row`](Self::restore_row).
+    async fn soft_delete_row(&self, id: &Uuid, status: Option<&str>) -> Result<T> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+        // Assignments run left to right: the old status is saved first
+        let result = match status {
+            None => sqlx::query(
+                "UPDATE entities SET deleted_at = ? \
+                 WHERE id = ? AND entity_type = ? AND deleted_at IS NULL",
+            )
+            .bind(Utc::now()),
+            Some(status) => sqlx::query(
+                "UPDATE entities SET deleted_at = ?, status_before_delete = status, status = ? \
+                 WHERE id = ? AND entity_type = ? AND deleted_at IS NULL",
+            )
+            .bind(Utc::now())
+            .bind(status),
+        }
+        .bind(id.to_string())
+        .bind(Self::entity_type_name())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| anyhow!("Failed to soft-delete entity: {}", e))?;
+        if result.rows_affected() == 0 {
+            return Err(SoftDeleteError::NotFound(*id).into());
+        }
+        if self.outbox {
+            let event = EntityEvent::Deleted {
+                entity_type: Self::entity_type_name().to_string(),
+                entity_id: *id,
+            };
+            insert_outbox_event(&mut tx, &FrameworkEvent::Entity(event)).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit entity soft-delete: {}", e))?;
+
+        self.get_with_deleted(id)
+            .await?
+            .ok_or_else(|| anyhow!("Failed to read back soft-deleted entity"))
+    }
+
+    /// Restore an entity; with a `fallback` status, also revert its status
+    ///
+    /// The status reverts to `status_before_delete`, or to `fallback` when
+    /// the entity was soft-deleted without a status.
+    async fn restore_row(&self, id: &Uuid, fallback: Option<&str>) -> Result<T> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+        // Affects no row when the entity is not deleted, which is fine
+        let result = match fallback {
+            None => sqlx::query(
+                "UPDATE entities SET deleted_at = NULL WHERE id = ? AND entity_type = ?",
+            ),
+            Some(fallback) => sqlx::query(
+                "UPDATE entities SET deleted_at = NULL, \
+                 status = COALESCE(status_before_delete, ?), status_before_delete = NULL \
+                 WHERE id = ? AND entity_type = ? AND deleted_at IS NOT NULL",
+            )
+            .bind(fallback),
+        }
+        .bind(id.to_string())
+        .bind(Self::entity_type_name())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| anyhow!("Failed to restore entity: {}", e))?;
+        if self.outbox && result.rows_affected() > 0 {
+            Self::record_updated(&mut tx, id).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit entity restore: {}", e))?;
+
+        self.get_with_deleted(id)
+            .await?
+            .ok_or_else(|| SoftDeleteError::NotFound(*id).into())
+    }
+
+    /// Recorded versions of an entity, oldest first, with when each was superseded
+    async fn snapshots(&self, id: &Uuid) -> Result<Vec<(T, DateTime<Utc>)>> {
+        if !self.track_history {
+            return Err(HistoryError::NotEnabled.into());
+        }
+        let rows: Vec<(serde_json::Value, DateTime<Utc>)> = sqlx::query_as(
+            "SELECT snapshot, changed_at FROM entity_versions \
+             WHERE entity_type = ? AND entity_id = ? ORDER BY version",
+        )
+        .bind(Self::entity_type_name())
+        .bind(id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to list entity versions: {}", e))?;
+
+        rows.into_iter()
+            .map(|(snapshot, changed_at)| Ok((serde_json::from_value(snapshot)?, changed_at)))
+            .collect()
+    }
+
+    /// Generated column backing the unique index of `field`
+    fn unique_column(field: &str) -> Result<String> {
+        let column = format!("uq_{}_{}", Self::entity_type_name(), field);
+        if column.len() > MAX_IDENTIFIER_LENGTH
+            || !column
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return Err(anyhow!(
+                "Cannot index unique field '{}' of {}: '{}' is not a valid column name",
+                field,
+                Self::entity_type_name(),
+                column
+            ));
+        }
+        Ok(column)
+    }
+
+    /// Add a unique index for each of [`Data::unique_fields`] (idempotent).
+    ///
+    /// Entities of all types share one table, so each field gets a stored
+    /// generated column `uq_{entity_type}_{field}` holding the field's value
+    /// for live entities of this type and `NULL` for every other row, with a
+    /// unique index on it. Writes repeating a value then fail with
+    /// [`StorageError::AlreadyExists`]. Values longer than 255 characters
+    /// cannot be stored. Call after [`ensure_schema`].
+    pub async fn ensure_unique_indexes(&self) -> Result<()> {
+        for field in T::unique_fields() {
+            let column = Self::unique_column(field)?;
+            let exists: i64 = sqlx::query_scalar(
+                "SELECT COUNT(*) FROM information_schema.COLUMNS \
+                 WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'entities' AND COLUMN_NAME = ?",
+            )
+            .bind(&column)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to inspect entities table: {}", e))?;
+            if exists > 0 {
+                continue;
+            }
+
+            // The names were checked by `unique_column`, safe to interpolate
+            let value = if ENTITY_COMMON_FIELDS.contains(field) {
+                field.to_string()
+            } else {
+                let path = format!("JSON_EXTRACT(data, '$.{}')", field);
+                format!(
+                    "IF(JSON_TYPE({path}) = 'NULL', NULL, JSON_UNQUOTE({path}))",
+                    path = path
+                )
+            };
+            let sql = format!(
+                "ALTER TABLE entities \
+                 ADD COLUMN {column} VARCHAR(255) GENERATED ALWAYS AS \
+                 (IF(entity_type = '{entity_type}' AND deleted_at IS NULL, {value}, NULL)) STORED, \
+                 ADD UNIQUE INDEX {column} ({column})",
+                column = column,
+                entity_type = Self::entity_type_name(),
+                value = value
+            );
+            sqlx::query(&sql)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| anyhow!("Failed to add unique index on {}: {}", field, e))?;
+        }
+        Ok(())
+    }
+
+    /// Report a duplicate key on a unique field's index as
+    /// [`StorageError::AlreadyExists`], any other error with `context`
+    fn write_error(e: sqlx::Error, context: &str) -> anyhow::Error {
+        if let sqlx::Error::Database(db) = &e
+            && db.is_unique_violation()
+        {
+            // "Duplicate entry '<value>' for key '[entities.]<index>'"
+            let message = db.message();
+            let index = message.trim_end_matches('\'');
+            let field = T::unique_fields().iter().find(|field| {
+                Self::unique_column(field).is_ok_and(|column| index.ends_with(&column))
+            });
+            if let Some(field) = field {
+                let value = message
+                    .strip_prefix("Duplicate entry '")
+                    .and_then(|rest| rest.rsplit_once("' for key "))
+                    .map(|(value, _)| value.to_string())
+                    .unwrap_or_default();
+                return StorageError::AlreadyExists {
+                    entity_type: Self::entity_type_name().to_string(),
+                    field: field.to_string(),
+                    value,
+                }
+                .into();
+            }
+        }
+        anyhow!("{}: {}", context, e)
+    }
+
     /// Convert a domain entity into column values for INSERT/UPDATE.
     ///
     /// Serializes the full entity to JSON, extracts common fields into
     /// dedicated columns, and stores remaining fields in the JSON `data` column.
+    /// Computed fields are left out.
+    /// A name or status wider than its column is rejected as `TOO_LONG`.
     fn extract_data(entity: &T) -> Result<serde_json::Value> {
+        check_column_widths(&[
+            ("name", entity.name(), NAME_COLUMN_WIDTH),
+            ("status", entity.status(), STATUS_COLUMN_WIDTH),
+        ])?;
+
         let mut data = serde_json::to_value(entity)
             .map_err(|e| anyhow!("Failed to serialize entity: {}", e))?;
 
@@ -168,11 +915,165 @@ impl<T: Data + Serialize + DeserializeOwned> MysqlDataService<T> {
             for field in ENTITY_COMMON_FIELDS {
                 obj.remove(*field);
             }
+            // Computed fields are derived on reads, never stored
+            for field in entity.computed_fields().keys() {
+                obj.remove(field);
+            }
         }
 
         Ok(data)
     }
 
+    /// Keyset page on `(created_at, id)`, returned newest first.
+    ///
+    /// Walks towards older rows from `cursor`, or towards newer rows when
+    /// `newer` is set; in that case the closest rows are fetched in
+    /// ascending order and reversed.
+    async fn list_keyset(
+        &self,
+        cursor: Option<Cursor>,
+        newer: bool,
+        limit: usize,
+    ) -> Result<Vec<T>> {
+        let mut sql = String::from(
+            "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
+             FROM entities WHERE entity_type = ? AND deleted_at IS NULL",
+        );
+        if cursor.is_some() {
+            sql.push_str(if newer {
+                " AND (created_at, id) > (?, ?)"
+            } else {
+                " AND (created_at, id) < (?, ?)"
+            });
+        }
+        sql.push_str(if newer {
+            " ORDER BY created_at ASC, id ASC LIMIT ?"
+        } else {
+            " ORDER BY created_at DESC, id DESC LIMIT ?"
+        });
+
+        let mut query = sqlx::query_as::<
+            _,
+            (
+                String,
+                String,
+                String,
+                String,
+                Option<String>,
+                serde_json::Value,
+                DateTime<Utc>,
+                DateTime<Utc>,
+                Option<DateTime<Utc>>,
+            ),
+        >(&sql)
+        .bind(Self::entity_type_name());
+        if let Some(cursor) = cursor {
+            query = query.bind(cursor.created_at).bind(cursor.id.to_string());
+        }
+        let rows = query
+            .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to list entities: {}", e))?;
+
+        let mut entities = rows
+            .into_iter()
+            .map(|(id, etype, name, status, tid, data, cat, uat, dat)| {
+                Self::reconstruct_entity(id, etype, name, status, tid, data, cat, uat, dat)
+            })
+            .collect::<Result<Vec<T>>>()?;
+        if newer {
+            entities.reverse();
+        }
+        Ok(entities)
+    }
+
+    /// Live entities of this type matching `conditions`, newest first.
+    ///
+    /// `conditions` is appended to the `WHERE` clause; its `?` placeholders
+    /// are bound to `binds` in order.
+    async fn select_live(&self, conditions: &str, binds: &[String]) -> Result<Vec<T>> {
+        let sql = format!(
+            "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
+             FROM entities WHERE entity_type = ? AND deleted_at IS NULL{} ORDER BY created_at DESC",
+            conditions
+        );
+        let mut query = sqlx::query_as::<
+            _,
+            (
+                String,
+                String,
+                String,
+                String,
+                Option<String>,
+                serde_json::Value,
+                DateTime<Utc>,
+                DateTime<Utc>,
+                Option<DateTime<Utc>>,
+            ),
+        >(&sql)
+        .bind(Self::entity_type_name());
+        for value in binds {
+            query = query.bind(value);
+        }
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to query entities: {}", e))?;
+
+        rows.into_iter()
+            .map(|(id, etype, name, status, tid, data, cat, uat, dat)| {
+                Self::reconstruct_entity(id, etype, name, status, tid, data, cat, uat, dat)
+            })
+            .collect()
+    }
+
+    /// Live entities of this type selected by `tail`.
+    ///
+    /// `tail` follows `deleted_at IS NULL` in the `WHERE` clause, typically
+    /// ` AND` conditions then `ORDER BY`; its `?` placeholders are bound to
+    /// `binds` in order.
+    async fn select_filtered(&self, tail: &str, binds: Vec<FilterBind>) -> Result<Vec<T>> {
+        let sql = format!(
+            "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
+             FROM entities WHERE entity_type = ? AND deleted_at IS NULL{}",
+            tail
+        );
+        let mut query = sqlx::query_as::<
+            _,
+            (
+                String,
+                String,
+                String,
+                String,
+                Option<String>,
+                serde_json::Value,
+                DateTime<Utc>,
+                DateTime<Utc>,
+                Option<DateTime<Utc>>,
+            ),
+        >(&sql)
+        .bind(Self::entity_type_name());
+        for bind in binds {
+            query = match bind {
+                FilterBind::Text(text) => query.bind(text),
+                FilterBind::Int(i) => query.bind(i),
+                FilterBind::Float(f) => query.bind(f),
+                FilterBind::Time(t) => query.bind(t),
+            };
+        }
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to filter entities: {}", e))?;
+
+        rows.into_iter()
+            .map(|(id, etype, name, status, tid, data, cat, uat, dat)| {
+                Self::reconstruct_entity(id, etype, name, status, tid, data, cat, uat, dat)
+            })
+            .collect()
+    }
+
     /// Reconstruct a domain entity from a row's columns.
     ///
     /// Merges common columns back into the JSON data, then deserializes
@@ -216,11 +1117,154 @@ impl<T: Data + Serialize + DeserializeOwned> MysqlDataService<T> {
         serde_json::from_value::<T>(json)
             .map_err(|e| anyhow!("Failed to deserialize entity from row: {}", e))
     }
+
+    /// Update an existing entity, attributing the change to `actor`
+    ///
+    /// Identical to `DataService::update`; `actor` is stored with the
+    /// history snapshot when history is enabled.
+    pub async fn update_as(&self, id: &Uuid, mut entity: T, actor: Option<&str>) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+
+        if !self.track_history {
+            if Self::update_row(&mut tx, id, &entity).await? == 0 {
+                let stored: Option<(DateTime<Utc>, i64)> = sqlx::query_as(
+                    "SELECT updated_at, version FROM entities WHERE id = ? AND entity_type = ?",
+                )
+                .bind(id.to_string())
+                .bind(Self::entity_type_name())
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| anyhow!("Failed to update entity: {}", e))?;
+                let Some((updated_at, version)) = stored else {
+                    return Err(anyhow!("Entity not found: {}", id));
+                };
+                check_precondition(id, &updated_at, version as u64)?;
+                if version as u64 != entity.version() {
+                    return Err(conflict(id, &entity).into());
+                }
+                // Same version, nothing changed
+            }
+        } else {
+            let previous = sqlx::query_as::<_, (String, String, String, String, Option<String>, serde_json::Value, DateTime<Utc>, DateTime<Utc>, Option<DateTime<Utc>>)>(
+                "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
+                 FROM entities WHERE id = ? AND entity_type = ? FOR UPDATE",
+            )
+            .bind(id.to_string())
+            .bind(Self::entity_type_name())
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to update entity: {}", e))?
+            .ok_or_else(|| anyhow!("Entity not found: {}", id))?;
+
+            let (pid, etype, name, status, tid, data, cat, uat, dat) = previous;
+            let previous =
+                Self::reconstruct_entity(pid, etype, name, status, tid, data, cat, uat, dat)?;
+            check_precondition(id, &previous.updated_at(), previous.version())?;
+            if previous.version() != entity.version() {
+                return Err(conflict(id, &entity).into());
+            }
+            let snapshot = serde_json::to_value(previous)?;
+            insert_version(&mut tx, Self::entity_type_name(), id, &snapshot, actor).await?;
+
+            // The row is locked and its version checked, so a zero count
+            // only means nothing changed
+            Self::update_row(&mut tx, id, &entity).await?;
+        }
+
+        if self.outbox {
+            Self::record_updated(&mut tx, id).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit entity update: {}", e))?;
+
+        // Re-read the entity (it may have just been soft-deleted)
+        self.get_with_deleted(id)
+            .await?
+            .ok_or_else(|| anyhow!("Failed to read back updated entity"))
+    }
+
+    /// Write the entity's columns, returning the number of affected rows
+    ///
+    /// Versioned entities are only written while the stored version is still
+    /// `entity.version()`, and leave with it incremented. Under an `If-Match`
+    /// precondition (see [`etag::if_match`]) the row must also still have the
+    /// checked `updated_at` and version.
+    async fn update_row(conn: &mut MySqlConnection, id: &Uuid, entity: &T) -> Result<u64> {
+        let data = Self::extract_data(entity)?;
+        let tenant_id = entity.tenant_id().map(|u| u.to_string());
+        let versioned = data.get("version").is_some();
+        let precondition = etag::precondition();
+
+        // Assignments apply left to right: `data` still sees the old version
+        let mut sql = String::from(if versioned {
+            "UPDATE entities \
+             SET name = ?, status = ?, tenant_id = ?, data = JSON_SET(?, '$.version', version + 1), \
+             updated_at = ?, deleted_at = ?, version = version + 1 \
+             WHERE id = ? AND entity_type = ? AND version = ?"
+        } else {
+            "UPDATE entities \
+             SET name = ?, status = ?, tenant_id = ?, data = ?, updated_at = ?, deleted_at = ? \
+             WHERE id = ? AND entity_type = ?"
+        });
+        if precondition.is_some() {
+            sql.push_str(" AND updated_at = ? AND version = ?");
+        }
+        let mut query = sqlx::query(&sql)
+            .bind(entity.name())
+            .bind(entity.status())
+            .bind(&tenant_id)
+            .bind(&data)
+            .bind(entity.updated_at())
+            .bind(entity.deleted_at())
+            .bind(id.to_string())
+            .bind(Self::entity_type_name());
+        if versioned {
+            query = query.bind(entity.version());
+        }
+        if let Some(expected) = precondition {
+            query = query.bind(expected.updated_at).bind(expected.version);
+        }
+        let result = query
+            .execute(conn)
+            .await
+            .map_err(|e| Self::write_error(e, "Failed to update entity"))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// Round trip used by the health checks of the mysql services
+async fn ping(pool: &MySqlPool) -> Result<()> {
+    sqlx::query("SELECT 1")
+        .execute(pool)
+        .await
+        .map_err(|e| anyhow!("mysql is unreachable: {}", e))?;
+    Ok(())
+}
+
+#[async_trait]
+impl<T: Send + Sync> HealthCheck for MysqlDataService<T> {
+    fn name(&self) -> &str {
+        "mysql"
+    }
+
+    async fn check(&self) -> Result<()> {
+        ping(&self.pool).await
+    }
 }
 
 #[async_trait]
 impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MysqlDataService<T> {
-    async fn create(&self, entity: T) -> Result<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
         let data = Self::extract_data(&entity)?;
         let id = entity.id().to_string();
         let entity_type = Self::entity_type_name().to_string();
@@ -231,9 +1275,14 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MysqlDataService
         let updated_at = entity.updated_at();
         let deleted_at = entity.deleted_at();
 
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
         sqlx::query(
-            "INSERT INTO entities (id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at) \
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO entities (id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at, version) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&id)
         .bind(&entity_type)
@@ -244,40 +1293,160 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MysqlDataService
         .bind(created_at)
         .bind(updated_at)
         .bind(deleted_at)
-        .execute(&self.pool)
+        .bind(entity.version())
+        .execute(&mut *tx)
         .await
-        .map_err(|e| anyhow!("Failed to create entity: {}", e))?;
+        .map_err(|e| Self::write_error(e, "Failed to create entity"))?;
+        if self.outbox {
+            let event = EntityEvent::Created {
+                entity_type,
+                entity_id: entity.id(),
+                data: serde_json::to_value(&entity)?,
+            };
+            insert_outbox_event(&mut tx, &FrameworkEvent::Entity(event)).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit entity: {}", e))?;
 
         // MySQL doesn't support RETURNING — re-read the entity
-        self.get(&entity.id())
+        self.get_with_deleted(&entity.id())
             .await?
             .ok_or_else(|| anyhow!("Failed to read back created entity"))
     }
 
-    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
-        let row = sqlx::query_as::<_, (String, String, String, String, Option<String>, serde_json::Value, DateTime<Utc>, DateTime<Utc>, Option<DateTime<Utc>>)>(
-            "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
-             FROM entities WHERE id = ? AND entity_type = ?",
-        )
-        .bind(id.to_string())
-        .bind(Self::entity_type_name())
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| anyhow!("Failed to get entity: {}", e))?;
+    /// Insert all entities with multi-row `INSERT`s in one transaction.
+    ///
+    /// Rows are written in chunks of [`CREATE_MANY_CHUNK`] to stay under
+    /// MySQL's placeholder limit, then read back in input order.
+    async fn create_many(&self, mut entities: Vec<T>) -> Result<Vec<T>> {
+        entities.iter_mut().for_each(actor::stamp_create);
 
-        match row {
-            Some((id, etype, name, status, tid, data, cat, uat, dat)) => Ok(Some(
-                Self::reconstruct_entity(id, etype, name, status, tid, data, cat, uat, dat)?,
-            )),
-            None => Ok(None),
+        if entities.is_empty() {
+            return Ok(Vec::new());
         }
+        let entity_type = Self::entity_type_name();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+        for chunk in entities.chunks(CREATE_MANY_CHUNK) {
+            let rows = chunk
+                .iter()
+                .map(|entity| Ok((entity, Self::extract_data(entity)?)))
+                .collect::<Result<Vec<_>>>()?;
+
+            let mut builder = QueryBuilder::<MySql>::new(
+                "INSERT INTO entities (id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at, version) ",
+            );
+            builder.push_values(rows, |mut row, (entity, data)| {
+                row.push_bind(entity.id().to_string())
+                    .push_bind(entity_type)
+                    .push_bind(entity.name().to_string())
+                    .push_bind(entity.status().to_string())
+                    .push_bind(entity.tenant_id().map(|u| u.to_string()))
+                    .push_bind(data)
+                    .push_bind(entity.created_at())
+                    .push_bind(entity.updated_at())
+                    .push_bind(entity.deleted_at())
+                    .push_bind(entity.version());
+            });
+            builder
+                .build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| Self::write_error(e, "Failed to create entities"))?;
+        }
+        if self.outbox {
+            for entity in &entities {
+                let event = EntityEvent::Created {
+                    entity_type: entity_type.to_string(),
+                    entity_id: entity.id(),
+                    data: serde_json::to_value(entity)?,
+                };
+                insert_outbox_event(&mut tx, &FrameworkEvent::Entity(event)).await?;
+            }
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit entities: {}", e))?;
+
+        // Re-read so timestamps reflect stored precision, keeping input order
+        let mut stored = std::collections::HashMap::with_capacity(entities.len());
+        for chunk in entities.chunks(CREATE_MANY_CHUNK) {
+            let mut builder = QueryBuilder::<MySql>::new(
+                "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
+                 FROM entities WHERE entity_type = ",
+            );
+            builder.push_bind(entity_type).push(" AND id IN (");
+            let mut ids = builder.separated(", ");
+            for entity in chunk {
+                ids.push_bind(entity.id().to_string());
+            }
+            builder.push(")");
+
+            let rows = builder
+                .build_query_as::<(
+                    String,
+                    String,
+                    String,
+                    String,
+                    Option<String>,
+                    serde_json::Value,
+                    DateTime<Utc>,
+                    DateTime<Utc>,
+                    Option<DateTime<Utc>>,
+                )>()
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| anyhow!("Failed to read back created entities: {}", e))?;
+            for (id, etype, name, status, tid, data, cat, uat, dat) in rows {
+                let entity =
+                    Self::reconstruct_entity(id, etype, name, status, tid, data, cat, uat, dat)?;
+                stored.insert(entity.id(), entity);
+            }
+        }
+
+        entities
+            .iter()
+            .map(|entity| {
+                stored
+                    .remove(&entity.id())
+                    .ok_or_else(|| anyhow!("Failed to read back created entity {}", entity.id()))
+            })
+            .collect()
+    }
+
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
+        Self::read_row(&self.pool, id).await
     }
 
-    async fn list(&self) -> Result<Vec<T>> {
-        let rows = sqlx::query_as::<_, (String, String, String, String, Option<String>, serde_json::Value, DateTime<Utc>, DateTime<Utc>, Option<DateTime<Utc>>)>(
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
+        let sql = format!(
             "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
-             FROM entities WHERE entity_type = ? ORDER BY created_at DESC",
-        )
+             FROM entities WHERE entity_type = ?{} ORDER BY created_at DESC",
+            if include_deleted {
+                ""
+            } else {
+                " AND deleted_at IS NULL"
+            }
+        );
+        let rows = sqlx::query_as::<
+            _,
+            (
+                String,
+                String,
+                String,
+                String,
+                Option<String>,
+                serde_json::Value,
+                DateTime<Utc>,
+                DateTime<Utc>,
+                Option<DateTime<Utc>>,
+            ),
+        >(&sql)
         .bind(Self::entity_type_name())
         .fetch_all(&self.pool)
         .await
@@ -290,50 +1459,88 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MysqlDataService
             .collect()
     }
 
-    async fn update(&self, id: &Uuid, entity: T) -> Result<T> {
-        let data = Self::extract_data(&entity)?;
-        let name = entity.name().to_string();
-        let status = entity.status().to_string();
-        let tenant_id = entity.tenant_id().map(|u| u.to_string());
-        let updated_at = entity.updated_at();
-        let deleted_at = entity.deleted_at();
+    async fn list_after(&self, cursor: Option<Cursor>, limit: usize) -> Result<Vec<T>> {
+        self.list_keyset(cursor, false, limit).await
+    }
 
-        let result = sqlx::query(
-            "UPDATE entities \
-             SET name = ?, status = ?, tenant_id = ?, data = ?, updated_at = ?, deleted_at = ? \
-             WHERE id = ? AND entity_type = ?",
+    async fn list_before(&self, cursor: Cursor, limit: usize) -> Result<Vec<T>> {
+        self.list_keyset(Some(cursor), true, limit).await
+    }
+
+    async fn count(&self) -> Result<usize> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM entities WHERE entity_type = ? AND deleted_at IS NULL",
         )
-        .bind(&name)
-        .bind(&status)
-        .bind(&tenant_id)
-        .bind(&data)
-        .bind(updated_at)
-        .bind(deleted_at)
-        .bind(id.to_string())
         .bind(Self::entity_type_name())
-        .execute(&self.pool)
+        .fetch_one(&self.pool)
         .await
-        .map_err(|e| anyhow!("Failed to update entity: {}", e))?;
+        .map_err(|e| anyhow!("Failed to count entities: {}", e))?;
 
-        if result.rows_affected() == 0 {
-            return Err(anyhow!("Entity not found: {}", id));
-        }
+        Ok(count as usize)
+    }
 
-        // Re-read the entity
-        self.get(id)
-            .await?
-            .ok_or_else(|| anyhow!("Failed to read back updated entity"))
+    async fn update(&self, id: &Uuid, entity: T) -> Result<T> {
+        self.update_as(id, entity, None).await
+    }
+
+    async fn get_at(&self, id: &Uuid, version: i64) -> Result<Option<T>> {
+        let snapshots = self.snapshots(id).await?;
+        let current = self.get_with_deleted(id).await?;
+        Ok(state_at(
+            snapshots.into_iter().map(|(s, _)| s).collect(),
+            current,
+            version,
+        ))
+    }
+
+    async fn get_as_of(&self, id: &Uuid, at: DateTime<Utc>) -> Result<Option<T>> {
+        let snapshots = self.snapshots(id).await?;
+        let current = self.get_with_deleted(id).await?;
+        Ok(state_as_of(snapshots, current, at, |e| {
+            Some(e.created_at())
+        }))
     }
 
     async fn delete(&self, id: &Uuid) -> Result<()> {
-        sqlx::query("DELETE FROM entities WHERE id = ? AND entity_type = ?")
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+        let result = sqlx::query("DELETE FROM entities WHERE id = ? AND entity_type = ?")
             .bind(id.to_string())
             .bind(Self::entity_type_name())
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| anyhow!("Failed to delete entity: {}", e))?;
+        if self.outbox && result.rows_affected() > 0 {
+            let event = EntityEvent::Deleted {
+                entity_type: Self::entity_type_name().to_string(),
+                entity_id: *id,
+            };
+            insert_outbox_event(&mut tx, &FrameworkEvent::Entity(event)).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit entity delete: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn soft_delete(&self, id: &Uuid) -> Result<T> {
+        self.soft_delete_row(id, None).await
+    }
+
+    async fn soft_delete_with_status(&self, id: &Uuid, status: &SoftDeleteStatus) -> Result<T> {
+        self.soft_delete_row(id, Some(&status.on_delete)).await
+    }
 
-        Ok(())
+    async fn restore(&self, id: &Uuid) -> Result<T> {
+        self.restore_row(id, None).await
+    }
+
+    async fn restore_with_status(&self, id: &Uuid, status: &SoftDeleteStatus) -> Result<T> {
+        self.restore_row(id, Some(&status.on_restore)).await
     }
 
     async fn search(&self, field: &str, value: &str) -> Result<Vec<T>> {
@@ -341,7 +1548,7 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MysqlDataService
             // Direct column search (field name is whitelisted, safe to interpolate)
             let sql = format!(
                 "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
-                 FROM entities WHERE entity_type = ? AND {} = ?",
+                 FROM entities WHERE entity_type = ? AND deleted_at IS NULL AND {} = ?",
                 field
             );
             sqlx::query_as::<
@@ -369,7 +1576,8 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MysqlDataService
             let json_path = format!("$.{}", field);
             sqlx::query_as::<_, (String, String, String, String, Option<String>, serde_json::Value, DateTime<Utc>, DateTime<Utc>, Option<DateTime<Utc>>)>(
                 "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at \
-                 FROM entities WHERE entity_type = ? AND JSON_UNQUOTE(JSON_EXTRACT(data, ?)) = ?",
+                 FROM entities WHERE entity_type = ? AND deleted_at IS NULL \
+                 AND JSON_UNQUOTE(JSON_EXTRACT(data, ?)) = ?",
             )
             .bind(Self::entity_type_name())
             .bind(&json_path)
@@ -385,6 +1593,94 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MysqlDataService
             })
             .collect()
     }
+
+    async fn get_for_tenant(&self, id: &Uuid, tenant: &TenantContext) -> Result<Option<T>> {
+        let binds = [id.to_string(), tenant.tenant_id.to_string()];
+        Ok(self
+            .select_live(" AND id = ? AND tenant_id = ?", &binds)
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    async fn list_for_tenant(&self, tenant: &TenantContext) -> Result<Vec<T>> {
+        self.select_live(" AND tenant_id = ?", &[tenant.tenant_id.to_string()])
+            .await
+    }
+
+    async fn search_for_tenant(
+        &self,
+        field: &str,
+        value: &str,
+        tenant: &TenantContext,
+    ) -> Result<Vec<T>> {
+        let tenant_id = tenant.tenant_id.to_string();
+        if SEARCHABLE_COLUMNS.contains(&field) {
+            // Whitelisted column name, safe to interpolate
+            let conditions = format!(" AND {} = ? AND tenant_id = ?", field);
+            self.select_live(&conditions, &[value.to_string(), tenant_id])
+                .await
+        } else {
+            self.select_live(
+                " AND JSON_UNQUOTE(JSON_EXTRACT(data, ?)) = ? AND tenant_id = ?",
+                &[format!("$.{}", field), value.to_string(), tenant_id],
+            )
+            .await
+        }
+    }
+
+    async fn list_where(&self, clauses: &[FilterClause]) -> Result<Vec<T>> {
+        let (conditions, binds) = filter_where_sql(clauses);
+        self.select_filtered(&format!("{} ORDER BY created_at DESC", conditions), binds)
+            .await
+    }
+
+    /// Conditional get keyed on the ETag of `id`, `updated_at` and version.
+    ///
+    /// Only those columns are read first; the full row is loaded and
+    /// deserialized only when the ETag no longer matches.
+    async fn get_if_modified(&self, id: &Uuid, etag: Option<&str>) -> Result<CacheResult<T>> {
+        if let Some(tag) = etag {
+            let stored: Option<(DateTime<Utc>, i64)> = sqlx::query_as(
+                "SELECT updated_at, version FROM entities \
+                 WHERE id = ? AND entity_type = ? AND deleted_at IS NULL",
+            )
+            .bind(id.to_string())
+            .bind(Self::entity_type_name())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to check entity version: {}", e))?;
+
+            match stored {
+                None => return Ok(CacheResult::NotFound),
+                Some((updated_at, version))
+                    if etag_matches(tag, &etag_for(id, &updated_at, version as u64)) =>
+                {
+                    return Ok(CacheResult::NotModified);
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(match self.get(id).await? {
+            Some(entity) => CacheResult::Modified(entity),
+            None => CacheResult::NotFound,
+        })
+    }
+
+    fn writes_outbox(&self) -> bool {
+        self.outbox
+    }
+}
+
+/// Translates queries into a `SELECT` over the `entities` table
+#[async_trait]
+impl<T: Data + Serialize + DeserializeOwned> QueryableStore<T> for MysqlDataService<T> {
+    async fn query(&self, query: &Query) -> Result<Vec<T>> {
+        let (mut tail, mut binds) = filter_where_sql(&query.filters);
+        tail.push_str(&query_tail_sql(query, &mut binds));
+        self.select_filtered(&tail, binds).await
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -409,17 +1705,382 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for MysqlDataService
 #[derive(Clone, Debug)]
 pub struct MysqlLinkService {
     pool: MySqlPool,
+    outbox: bool,
 }
 
 impl MysqlLinkService {
     pub fn new(pool: MySqlPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            outbox: false,
+        }
+    }
+
+    /// Record a link event in the `outbox` table on every create and delete
+    ///
+    /// The row is written in the same transaction as the change, including
+    /// one row per link removed by `delete_where`.
+    pub fn with_outbox(mut self) -> Self {
+        self.outbox = true;
+        self
     }
 
     pub fn pool(&self) -> &MySqlPool {
         &self.pool
     }
 
+    /// Insert `link` while holding the row lock of its type in `link_type_locks`
+    ///
+    /// Backs `create_within_limit`. The lock belongs to the transaction: the
+    /// commit releases it, and so does the rollback when the insert fails or
+    /// the future is dropped. Waits are bounded by `innodb_lock_wait_timeout`.
+    async fn create_locked(
+        &self,
+        link: LinkEntity,
+        limit: LinkLimit,
+    ) -> Result<Option<LinkEntity>> {
+        // Created outside the transaction: two transactions holding the shared
+        // lock of a duplicate insert would deadlock when locking the row
+        sqlx::query("INSERT IGNORE INTO link_type_locks (link_type) VALUES (?)")
+            .bind(&link.link_type)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to create link type lock: {}", e))?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+        sqlx::query("SELECT link_type FROM link_type_locks WHERE link_type = ? FOR UPDATE")
+            .bind(&link.link_type)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to lock link type: {}", e))?;
+
+        if !Self::insert_within_limit(&mut tx, &link, limit, self.outbox).await? {
+            return Ok(None);
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit link: {}", e))?;
+        self.get(&link.id).await
+    }
+
+    /// Count live links and insert `link` if `limit` allows it
+    ///
+    /// Returns whether the link was inserted. The caller holds the lock of
+    /// the link type and commits.
+    async fn insert_within_limit(
+        conn: &mut MySqlConnection,
+        link: &LinkEntity,
+        limit: LinkLimit,
+        outbox: bool,
+    ) -> Result<bool> {
+        let mut counts = [0usize; 2];
+        for (count, (max, column, id)) in counts.iter_mut().zip([
+            (limit.per_source, "source_id", link.source_id),
+            (limit.per_target, "target_id", link.target_id),
+        ]) {
+            if max.is_some() {
+                let sql = format!(
+                    "SELECT COUNT(*) FROM links WHERE link_type = ? AND {} = ? AND deleted_at IS NULL",
+                    column
+                );
+                let n: i64 = sqlx::query_scalar(&sql)
+                    .bind(&link.link_type)
+                    .bind(id.to_string())
+                    .fetch_one(&mut *conn)
+                    .await
+                    .map_err(|e| anyhow!("Failed to count links: {}", e))?;
+                *count = n as usize;
+            }
+        }
+        if !limit.allows(counts[0], counts[1]) {
+            return Ok(false);
+        }
+
+        Self::insert_row(conn, link, false).await?;
+        if outbox {
+            insert_outbox_event(conn, &link_created(link)).await?;
+        }
+        Ok(true)
+    }
+
+    /// Insert and commit `link`, recording it in the outbox if enabled
+    async fn insert(&self, link: &LinkEntity, unique_pair: bool) -> Result<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+        Self::insert_row(&mut tx, link, unique_pair).await?;
+        if self.outbox {
+            insert_outbox_event(&mut tx, &link_created(link)).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit link: {}", e))
+    }
+
+    /// Insert the row of `link`
+    ///
+    /// With `unique_pair`, the row enters `idx_link_pair`, and a live
+    /// flagged link between the same entities fails the insert with
+    /// [`LinkError::AlreadyExists`].
+    async fn insert_row(
+        conn: &mut MySqlConnection,
+        link: &LinkEntity,
+        unique_pair: bool,
+    ) -> Result<()> {
+        let metadata = link.metadata.clone().unwrap_or(serde_json::json!({}));
+        sqlx::query(
+            "INSERT INTO links (id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, metadata, created_at, updated_at, deleted_at, unique_pair) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(link.id.to_string())
+        .bind(&link.entity_type)
+        .bind(&link.link_type)
+        .bind(link.source_id.to_string())
+        .bind(link.target_id.to_string())
+        .bind(&link.source_type)
+        .bind(&link.target_type)
+        .bind(&link.status)
+        .bind(link.tenant_id.map(|u| u.to_string()))
+        .bind(&metadata)
+        .bind(link.created_at)
+        .bind(link.updated_at)
+        .bind(link.deleted_at)
+        .bind(unique_pair)
+        .execute(conn)
+        .await
+        .map_err(|e| match e {
+            // ER_DUP_ENTRY; the primary key is a fresh id, so only the pair index
+            sqlx::Error::Database(db) if db.is_unique_violation() && unique_pair => {
+                LinkError::AlreadyExists {
+                    link_type: link.link_type.clone(),
+                    cardinality: LinkCardinality::ManyToMany,
+                }
+                .into()
+            }
+            e => anyhow!("Failed to create link: {}", e),
+        })?;
+        Ok(())
+    }
+
+    /// Delete the links of `link_type` matching `filter`, limited to `tenant`
+    /// when given
+    async fn delete_links_where(
+        &self,
+        link_type: &str,
+        filter: Option<&serde_json::Value>,
+        tenant: Option<&TenantContext>,
+    ) -> Result<u64> {
+        let conditions = match filter {
+            Some(filter) => LinkFilterCondition::parse_all(filter).map_err(|e| anyhow!(e))?,
+            None => Vec::new(),
+        };
+
+        let sql = delete_where_sql(&conditions, tenant.is_some());
+        let mut binds = vec![link_type.to_string()];
+        binds.extend(tenant.map(|tenant| tenant.tenant_id.to_string()));
+        for condition in &conditions {
+            if let LinkFilterField::Metadata(key) = &condition.field {
+                binds.push(format!("$.\"{}\"", key));
+            }
+            binds.extend(condition.values.iter().cloned());
+        }
+
+        if self.outbox {
+            let condition = sql.trim_start_matches("DELETE FROM links WHERE ");
+            return self.delete_recorded(condition, &binds).await;
+        }
+
+        let mut query = sqlx::query(&sql);
+        for bind in &binds {
+            query = query.bind(bind);
+        }
+        let result = query
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete links by filter: {}", e))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Delete the links matching `condition`, recording a `Deleted` event for
+    /// each, and return how many were removed
+    async fn delete_recorded(&self, condition: &str, binds: &[String]) -> Result<u64> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+
+        let sql = format!(
+            "SELECT id, link_type, source_id, target_id FROM links WHERE {} FOR UPDATE",
+            condition
+        );
+        let mut select = sqlx::query_as::<_, (String, String, String, String)>(&sql);
+        for bind in binds {
+            select = select.bind(bind);
+        }
+        let rows = select
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete links: {}", e))?;
+
+        let sql = format!("DELETE FROM links WHERE {}", condition);
+        let mut delete = sqlx::query(&sql);
+        for bind in binds {
+            delete = delete.bind(bind);
+        }
+        delete
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete links: {}", e))?;
+
+        let deleted = rows.len() as u64;
+        for (id, link_type, source_id, target_id) in rows {
+            let event = LinkEvent::Deleted {
+                link_type,
+                link_id: Uuid::parse_str(&id)?,
+                source_id: Uuid::parse_str(&source_id)?,
+                target_id: Uuid::parse_str(&target_id)?,
+            };
+            insert_outbox_event(&mut tx, &FrameworkEvent::Link(event)).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit link delete: {}", e))?;
+        Ok(deleted)
+    }
+
+    /// Shared traversal query on `source_id` or `target_id`.
+    ///
+    /// `column` and `type_column` are always hardcoded column names, never
+    /// user input. Links whose type column is NULL (created without a known
+    /// type) match any type filter. When `metadata_fields` is set, only those
+    /// metadata keys are selected.
+    #[allow(clippy::too_many_arguments)]
+    async fn find_links(
+        &self,
+        column: &str,
+        type_column: &str,
+        entity_id: &Uuid,
+        link_type: Option<&str>,
+        entity_type: Option<&str>,
+        metadata_fields: Option<&[String]>,
+        tenant: Option<&TenantContext>,
+    ) -> sqlx::Result<Vec<LinkEntity>> {
+        let select = match metadata_fields {
+            Some(fields) => projected_link_select(fields),
+            None => LINK_SELECT.to_string(),
+        };
+        let mut sql = format!("{} WHERE {} = ?", select, column);
+        if link_type.is_some() {
+            sql.push_str(" AND link_type = ?");
+        }
+        if entity_type.is_some() {
+            sql.push_str(&format!(" AND ({0} IS NULL OR {0} = ?)", type_column));
+        }
+        if tenant.is_some() {
+            sql.push_str(" AND tenant_id = ?");
+        }
+        sql.push_str(" ORDER BY created_at DESC");
+
+        let mut query = sqlx::query_as::<_, LinkTuple>(&sql);
+        if let Some(fields) = metadata_fields {
+            query = bind_metadata_fields(query, fields);
+        }
+        query = query.bind(entity_id.to_string());
+        if let Some(lt) = link_type {
+            query = query.bind(lt);
+        }
+        if let Some(et) = entity_type {
+            query = query.bind(et);
+        }
+        if let Some(tenant) = tenant {
+            query = query.bind(tenant.tenant_id.to_string());
+        }
+
+        let rows = query.fetch_all(&self.pool).await?;
+
+        rows.into_iter()
+            .map(
+                |(id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat)| {
+                    Self::row_to_link(
+                        id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat,
+                    )
+                    .map(|link| match metadata_fields {
+                        Some(_) => strip_missing_metadata(link),
+                        None => link,
+                    })
+                },
+            )
+            .collect::<Result<_>>()
+            .map_err(|e| sqlx::Error::Decode(e.into()))
+    }
+
+    /// One page of [`find_links`](Self::find_links), with the number of matches
+    ///
+    /// Runs a `COUNT(*)` and a `LIMIT`/`OFFSET` query on the same filter.
+    #[allow(clippy::too_many_arguments)]
+    async fn find_links_page(
+        &self,
+        column: &str,
+        type_column: &str,
+        entity_id: &Uuid,
+        link_type: Option<&str>,
+        entity_type: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<LinkEntity>, usize)> {
+        let mut filter = format!("{} = ?", column);
+        if link_type.is_some() {
+            filter.push_str(" AND link_type = ?");
+        }
+        if entity_type.is_some() {
+            filter.push_str(&format!(" AND ({0} IS NULL OR {0} = ?)", type_column));
+        }
+        let mut binds = vec![entity_id.to_string()];
+        binds.extend(link_type.map(str::to_string));
+        binds.extend(entity_type.map(str::to_string));
+
+        let sql = format!("SELECT COUNT(*) FROM links WHERE {}", filter);
+        let mut count = sqlx::query_scalar::<_, i64>(&sql);
+        for bind in &binds {
+            count = count.bind(bind);
+        }
+        let total = count.fetch_one(&self.pool).await?;
+
+        let sql = format!(
+            "{} WHERE {} ORDER BY created_at DESC, id LIMIT ? OFFSET ?",
+            LINK_SELECT, filter
+        );
+        let mut query = sqlx::query_as::<_, LinkTuple>(&sql);
+        for bind in &binds {
+            query = query.bind(bind);
+        }
+        let rows = query
+            .bind(limit as u64)
+            .bind(offset as u64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let links = rows
+            .into_iter()
+            .map(
+                |(id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat)| {
+                    Self::row_to_link(
+                        id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat,
+                    )
+                },
+            )
+            .collect::<Result<_>>()?;
+        Ok((links, total as usize))
+    }
+
     /// Parse a link row tuple into a LinkEntity.
     #[allow(clippy::too_many_arguments)]
     fn row_to_link(
@@ -428,8 +2089,8 @@ impl MysqlLinkService {
         link_type: String,
         source_id: String,
         target_id: String,
-        _source_type: Option<String>,
-        _target_type: Option<String>,
+        source_type: Option<String>,
+        target_type: Option<String>,
         status: String,
         tenant_id: Option<String>,
         metadata: serde_json::Value,
@@ -454,6 +2115,8 @@ impl MysqlLinkService {
             target_id: target_id
                 .parse()
                 .map_err(|e| anyhow!("Invalid UUID for target_id: {}", e))?,
+            source_type,
+            target_type,
             metadata: if metadata == serde_json::json!({}) {
                 None
             } else {
@@ -481,31 +2144,85 @@ type LinkTuple = (
 
 const LINK_SELECT: &str = "SELECT id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, metadata, created_at, updated_at, deleted_at FROM links";
 
+/// Build a `links` SELECT where `metadata` is replaced by a `JSON_OBJECT` of
+/// the requested keys only.
+///
+/// Each key contributes two `?` placeholders (key name, then JSON path),
+/// bound in order by [`bind_metadata_fields`].
+fn projected_link_select(metadata_fields: &[String]) -> String {
+    let pairs = vec!["?, JSON_EXTRACT(metadata, ?)"; metadata_fields.len()].join(", ");
+    format!(
+        "SELECT id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, \
+         JSON_OBJECT({}) AS metadata, created_at, updated_at, deleted_at FROM links",
+        pairs
+    )
+}
+
+/// Bind the key/path pairs produced by [`projected_link_select`].
+fn bind_metadata_fields<'q>(
+    mut query: sqlx::query::QueryAs<'q, sqlx::MySql, LinkTuple, sqlx::mysql::MySqlArguments>,
+    metadata_fields: &'q [String],
+) -> sqlx::query::QueryAs<'q, sqlx::MySql, LinkTuple, sqlx::mysql::MySqlArguments> {
+    for key in metadata_fields {
+        query = query.bind(key.as_str()).bind(format!("$.\"{}\"", key));
+    }
+    query
+}
+
+/// Build the DELETE used by `delete_where`, with `?` placeholders bound in
+/// order: link type, the tenant id when `for_tenant`, then per condition the
+/// metadata JSON path and each accepted value.
+///
+/// Column names come from [`LinkFilterCondition::COLUMNS`], never from user
+/// input.
+fn delete_where_sql(conditions: &[LinkFilterCondition], for_tenant: bool) -> String {
+    let mut sql = "DELETE FROM links WHERE link_type = ?".to_string();
+    if for_tenant {
+        sql.push_str(" AND tenant_id = ?");
+    }
+    for condition in conditions {
+        let placeholders = vec!["?"; condition.values.len()].join(", ");
+        match &condition.field {
+            LinkFilterField::Column(column) => {
+                sql.push_str(&format!(" AND {} IN ({})", column, placeholders));
+            }
+            LinkFilterField::Metadata(_) => {
+                sql.push_str(&format!(
+                    " AND JSON_UNQUOTE(JSON_EXTRACT(metadata, ?)) IN ({})",
+                    placeholders
+                ));
+            }
+        }
+    }
+    sql
+}
+
+/// Drop keys that `JSON_OBJECT` filled with `null` because the path was absent.
+fn strip_missing_metadata(mut link: LinkEntity) -> LinkEntity {
+    if let Some(serde_json::Value::Object(obj)) = link.metadata.as_mut() {
+        obj.retain(|_, v| !v.is_null());
+        if obj.is_empty() {
+            link.metadata = None;
+        }
+    }
+    link
+}
+
+#[async_trait]
+impl HealthCheck for MysqlLinkService {
+    fn name(&self) -> &str {
+        "mysql"
+    }
+
+    async fn check(&self) -> Result<()> {
+        ping(&self.pool).await
+    }
+}
+
 #[async_trait]
 impl LinkService for MysqlLinkService {
     async fn create(&self, link: LinkEntity) -> Result<LinkEntity> {
-        let metadata = link.metadata.clone().unwrap_or(serde_json::json!({}));
-
-        sqlx::query(
-            "INSERT INTO links (id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, metadata, created_at, updated_at, deleted_at) \
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
-        )
-        .bind(link.id.to_string())
-        .bind(&link.entity_type)
-        .bind(&link.link_type)
-        .bind(link.source_id.to_string())
-        .bind(link.target_id.to_string())
-        .bind(None::<String>)  // source_type
-        .bind(None::<String>)  // target_type
-        .bind(&link.status)
-        .bind(link.tenant_id.map(|u| u.to_string()))
-        .bind(&metadata)
-        .bind(link.created_at)
-        .bind(link.updated_at)
-        .bind(link.deleted_at)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| anyhow!("Failed to create link: {}", e))?;
+        self.insert(&link, false).await?;
 
         // Re-read
         self.get(&link.id)
@@ -513,6 +2230,34 @@ impl LinkService for MysqlLinkService {
             .ok_or_else(|| anyhow!("Failed to read back created link"))
     }
 
+    /// Count and insert while holding the `link_type_locks` row of the link
+    /// type (`SELECT ... FOR UPDATE`), which serializes bounded inserts
+    /// regardless of isolation level.
+    async fn create_within_limit(
+        &self,
+        link: LinkEntity,
+        limit: LinkLimit,
+    ) -> Result<Option<LinkEntity>> {
+        self.create_locked(link, limit).await
+    }
+
+    /// Insert into the unique `idx_link_pair` index, without locking the link type
+    ///
+    /// A live link already stored between the entities (including one
+    /// created with duplicates allowed) gives `Ok(None)`; one inserted
+    /// concurrently fails the insert with [`LinkError::AlreadyExists`].
+    async fn create_unique(&self, link: LinkEntity) -> Result<Option<LinkEntity>> {
+        if self
+            .get_between(&link.source_id, &link.target_id, &link.link_type)
+            .await?
+            .is_some()
+        {
+            return Ok(None);
+        }
+        self.insert(&link, true).await?;
+        self.get(&link.id).await
+    }
+
     async fn get(&self, id: &Uuid) -> Result<Option<LinkEntity>> {
         let sql = format!("{} WHERE id = ?", LINK_SELECT);
         let row = sqlx::query_as::<_, LinkTuple>(&sql)
@@ -531,6 +2276,36 @@ impl LinkService for MysqlLinkService {
         }
     }
 
+    /// Look the pair up through `idx_link_pair`
+    async fn get_between(
+        &self,
+        source_id: &Uuid,
+        target_id: &Uuid,
+        link_type: &str,
+    ) -> Result<Option<LinkEntity>> {
+        let sql = format!(
+            "{} WHERE link_type = ? AND source_id = ? AND target_id = ? \
+             AND deleted_at IS NULL LIMIT 1",
+            LINK_SELECT
+        );
+        let row = sqlx::query_as::<_, LinkTuple>(&sql)
+            .bind(link_type)
+            .bind(source_id.to_string())
+            .bind(target_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to get link: {}", e))?;
+
+        row.map(
+            |(id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat)| {
+                Self::row_to_link(
+                    id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat,
+                )
+            },
+        )
+        .transpose()
+    }
+
     async fn list(&self) -> Result<Vec<LinkEntity>> {
         let sql = format!("{} ORDER BY created_at DESC", LINK_SELECT);
         let rows = sqlx::query_as::<_, LinkTuple>(&sql)
@@ -549,62 +2324,190 @@ impl LinkService for MysqlLinkService {
             .collect()
     }
 
-    async fn find_by_source(
+    async fn find_by_source(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "source_id",
+            "target_type",
+            source_id,
+            link_type,
+            target_type,
+            None,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by source: {}", e))
+    }
+
+    async fn find_by_target(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "target_id",
+            "source_type",
+            target_id,
+            link_type,
+            source_type,
+            None,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by target: {}", e))
+    }
+
+    async fn find_by_source_page(
         &self,
         source_id: &Uuid,
         link_type: Option<&str>,
-        _target_type: Option<&str>,
-    ) -> Result<Vec<LinkEntity>> {
-        let mut sql = format!("{} WHERE source_id = ?", LINK_SELECT);
-        if link_type.is_some() {
-            sql.push_str(" AND link_type = ?");
-        }
-        sql.push_str(" ORDER BY created_at DESC");
+        target_type: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<LinkEntity>, usize)> {
+        self.find_links_page(
+            "source_id",
+            "target_type",
+            source_id,
+            link_type,
+            target_type,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by source: {}", e))
+    }
 
-        let mut query = sqlx::query_as::<_, LinkTuple>(&sql).bind(source_id.to_string());
+    async fn find_by_target_page(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<LinkEntity>, usize)> {
+        self.find_links_page(
+            "target_id",
+            "source_type",
+            target_id,
+            link_type,
+            source_type,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by target: {}", e))
+    }
 
-        if let Some(lt) = link_type {
-            query = query.bind(lt);
-        }
+    async fn find_by_source_for_tenant(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+        tenant: &TenantContext,
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "source_id",
+            "target_type",
+            source_id,
+            link_type,
+            target_type,
+            None,
+            Some(tenant),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by source: {}", e))
+    }
 
-        let rows = query
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| anyhow!("Failed to find links by source: {}", e))?;
+    async fn find_by_target_for_tenant(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+        tenant: &TenantContext,
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "target_id",
+            "source_type",
+            target_id,
+            link_type,
+            source_type,
+            None,
+            Some(tenant),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by target: {}", e))
+    }
 
-        rows.into_iter()
-            .map(
-                |(id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat)| {
-                    Self::row_to_link(
-                        id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat,
-                    )
-                },
-            )
-            .collect()
+    async fn find_by_source_projected(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+        metadata_fields: &[String],
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "source_id",
+            "target_type",
+            source_id,
+            link_type,
+            target_type,
+            Some(metadata_fields),
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by source: {}", e))
     }
 
-    async fn find_by_target(
+    async fn find_by_target_projected(
         &self,
         target_id: &Uuid,
         link_type: Option<&str>,
-        _source_type: Option<&str>,
+        source_type: Option<&str>,
+        metadata_fields: &[String],
     ) -> Result<Vec<LinkEntity>> {
-        let mut sql = format!("{} WHERE target_id = ?", LINK_SELECT);
-        if link_type.is_some() {
-            sql.push_str(" AND link_type = ?");
-        }
-        sql.push_str(" ORDER BY created_at DESC");
+        self.find_links(
+            "target_id",
+            "source_type",
+            target_id,
+            link_type,
+            source_type,
+            Some(metadata_fields),
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by target: {}", e))
+    }
 
-        let mut query = sqlx::query_as::<_, LinkTuple>(&sql).bind(target_id.to_string());
+    async fn find_relations(
+        &self,
+        entity_id: &Uuid,
+        direction: RelationDirection,
+    ) -> Result<Vec<LinkEntity>> {
+        let predicate = match direction {
+            RelationDirection::Out => "source_id = ?",
+            RelationDirection::In => "target_id = ?",
+            RelationDirection::Both => "(source_id = ? OR target_id = ?)",
+        };
+        let sql = format!(
+            "{} WHERE {} ORDER BY created_at DESC",
+            LINK_SELECT, predicate
+        );
 
-        if let Some(lt) = link_type {
-            query = query.bind(lt);
+        let mut query = sqlx::query_as::<_, LinkTuple>(&sql).bind(entity_id.to_string());
+        if direction == RelationDirection::Both {
+            query = query.bind(entity_id.to_string());
         }
 
         let rows = query
             .fetch_all(&self.pool)
             .await
-            .map_err(|e| anyhow!("Failed to find links by target: {}", e))?;
+            .map_err(|e| anyhow!("Failed to find relations: {}", e))?;
 
         rows.into_iter()
             .map(
@@ -649,6 +2552,10 @@ impl LinkService for MysqlLinkService {
     }
 
     async fn delete(&self, id: &Uuid) -> Result<()> {
+        if self.outbox {
+            self.delete_recorded("id = ?", &[id.to_string()]).await?;
+            return Ok(());
+        }
         sqlx::query("DELETE FROM links WHERE id = ?")
             .bind(id.to_string())
             .execute(&self.pool)
@@ -660,6 +2567,11 @@ impl LinkService for MysqlLinkService {
 
     async fn delete_by_entity(&self, entity_id: &Uuid) -> Result<()> {
         let eid = entity_id.to_string();
+        if self.outbox {
+            self.delete_recorded("source_id = ? OR target_id = ?", &[eid.clone(), eid])
+                .await?;
+            return Ok(());
+        }
         sqlx::query("DELETE FROM links WHERE source_id = ? OR target_id = ?")
             .bind(&eid)
             .bind(&eid)
@@ -669,6 +2581,367 @@ impl LinkService for MysqlLinkService {
 
         Ok(())
     }
+
+    async fn delete_where(
+        &self,
+        link_type: &str,
+        filter: Option<&serde_json::Value>,
+    ) -> Result<u64> {
+        self.delete_links_where(link_type, filter, None).await
+    }
+
+    async fn delete_where_for_tenant(
+        &self,
+        link_type: &str,
+        filter: Option<&serde_json::Value>,
+        tenant: &TenantContext,
+    ) -> Result<u64> {
+        self.delete_links_where(link_type, filter, Some(tenant))
+            .await
+    }
+
+    fn writes_outbox(&self) -> bool {
+        self.outbox
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Outbox
+// ---------------------------------------------------------------------------
+
+/// The `Created` event of `link`
+fn link_created(link: &LinkEntity) -> FrameworkEvent {
+    FrameworkEvent::Link(LinkEvent::Created {
+        link_type: link.link_type.clone(),
+        link_id: link.id,
+        source_id: link.source_id,
+        target_id: link.target_id,
+        metadata: link.metadata.clone(),
+    })
+}
+
+/// Append `event` to the outbox on the caller's connection (and transaction)
+async fn insert_outbox_event(
+    conn: &mut MySqlConnection,
+    event: &FrameworkEvent,
+) -> Result<OutboxEntry> {
+    let entry = OutboxEntry::new(event.clone());
+    sqlx::query("INSERT INTO outbox (id, event, created_at, sent_at) VALUES (?, ?, ?, NULL)")
+        .bind(entry.id.to_string())
+        .bind(serde_json::to_value(&entry.event)?)
+        .bind(entry.created_at)
+        .execute(conn)
+        .await
+        .map_err(|e| anyhow!("Failed to record outbox event: {}", e))?;
+    Ok(entry)
+}
+
+/// MySQL-backed outbox stored in the `outbox` table (see [`ensure_schema`])
+///
+/// Rows are written by [`MysqlDataService::with_outbox`] and
+/// [`MysqlLinkService::with_outbox`]; pass this service to
+/// `ServerBuilder::with_outbox` to publish them.
+#[derive(Clone, Debug)]
+pub struct MysqlOutboxService {
+    pool: MySqlPool,
+}
+
+impl MysqlOutboxService {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OutboxService for MysqlOutboxService {
+    async fn enqueue(&self, event: FrameworkEvent) -> Result<OutboxEntry> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| anyhow!("Failed to acquire connection: {}", e))?;
+        insert_outbox_event(&mut conn, &event).await
+    }
+
+    async fn unsent(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+        let rows = sqlx::query_as::<_, (String, serde_json::Value, DateTime<Utc>)>(
+            "SELECT id, event, created_at FROM outbox \
+             WHERE sent_at IS NULL ORDER BY created_at, id LIMIT ?",
+        )
+        .bind(limit.min(i64::MAX as usize) as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to list outbox events: {}", e))?;
+
+        rows.into_iter()
+            .map(|(id, event, created_at)| {
+                Ok(OutboxEntry {
+                    id: Uuid::parse_str(&id)?,
+                    event: serde_json::from_value(event)?,
+                    created_at,
+                    sent_at: None,
+                })
+            })
+            .collect()
+    }
+
+    async fn mark_sent(&self, ids: &[Uuid]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let mut builder = QueryBuilder::<MySql>::new("UPDATE outbox SET sent_at = ");
+        builder
+            .push_bind(Utc::now())
+            .push(" WHERE sent_at IS NULL AND id IN (");
+        let mut separated = builder.separated(", ");
+        for id in ids {
+            separated.push_bind(id.to_string());
+        }
+        builder.push(")");
+        builder
+            .build()
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to mark outbox events sent: {}", e))?;
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Entity history
+// ---------------------------------------------------------------------------
+
+/// Stale-version error for an update of `entity`
+fn conflict<T: Data>(id: &Uuid, entity: &T) -> StorageError {
+    StorageError::IntegrityError {
+        entity_id: *id,
+        expected_version: entity.version(),
+    }
+}
+
+/// Column tuple of the `entity_versions` table, in declaration order.
+type VersionColumns = (
+    String,
+    String,
+    i64,
+    serde_json::Value,
+    DateTime<Utc>,
+    Option<String>,
+);
+
+const VERSION_COLUMNS: &str = "entity_id, entity_type, version, snapshot, changed_at, actor";
+
+fn columns_to_version(columns: VersionColumns) -> Result<EntityVersion> {
+    let (entity_id, entity_type, version, snapshot, changed_at, actor) = columns;
+    Ok(EntityVersion {
+        entity_id: Uuid::parse_str(&entity_id)
+            .map_err(|e| anyhow!("Invalid entity_id in entity_versions: {}", e))?,
+        entity_type,
+        version,
+        snapshot,
+        changed_at,
+        actor,
+    })
+}
+
+/// Append `snapshot` as the next version of an entity.
+///
+/// Callers updating the entity hold its row lock (`SELECT ... FOR UPDATE`),
+/// which serializes version numbering per entity.
+async fn insert_version(
+    conn: &mut MySqlConnection,
+    entity_type: &str,
+    entity_id: &Uuid,
+    snapshot: &serde_json::Value,
+    actor: Option<&str>,
+) -> Result<EntityVersion> {
+    sqlx::query(
+        "INSERT INTO entity_versions (entity_id, entity_type, version, snapshot, changed_at, actor) \
+         SELECT ?, ?, COALESCE(MAX(version), 0) + 1, ?, ?, ? \
+         FROM entity_versions WHERE entity_type = ? AND entity_id = ?",
+    )
+    .bind(entity_id.to_string())
+    .bind(entity_type)
+    .bind(snapshot)
+    .bind(Utc::now())
+    .bind(actor)
+    .bind(entity_type)
+    .bind(entity_id.to_string())
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| anyhow!("Failed to record entity version: {}", e))?;
+
+    let sql = format!(
+        "SELECT {} FROM entity_versions WHERE entity_type = ? AND entity_id = ? \
+         ORDER BY version DESC LIMIT 1",
+        VERSION_COLUMNS
+    );
+    let columns = sqlx::query_as::<_, VersionColumns>(&sql)
+        .bind(entity_type)
+        .bind(entity_id.to_string())
+        .fetch_one(conn)
+        .await
+        .map_err(|e| anyhow!("Failed to read back entity version: {}", e))?;
+
+    columns_to_version(columns)
+}
+
+/// Entity history backed by the MySQL `entity_versions` table.
+///
+/// Versions are written by [`MysqlDataService::with_history`]; this
+/// service exposes them to the history endpoints.
+#[derive(Clone, Debug)]
+pub struct MysqlHistoryService {
+    pool: MySqlPool,
+}
+
+impl MysqlHistoryService {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HistoryService for MysqlHistoryService {
+    async fn record(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        snapshot: serde_json::Value,
+        actor: Option<&str>,
+    ) -> Result<EntityVersion> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| anyhow!("Failed to record entity version: {}", e))?;
+        insert_version(&mut conn, entity_type, entity_id, &snapshot, actor).await
+    }
+
+    async fn list_versions(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+    ) -> Result<Vec<EntityVersion>> {
+        let sql = format!(
+            "SELECT {} FROM entity_versions WHERE entity_type = ? AND entity_id = ? ORDER BY version",
+            VERSION_COLUMNS
+        );
+        let rows = sqlx::query_as::<_, VersionColumns>(&sql)
+            .bind(entity_type)
+            .bind(entity_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to list entity versions: {}", e))?;
+
+        rows.into_iter().map(columns_to_version).collect()
+    }
+
+    async fn get_version(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        version: i64,
+    ) -> Result<Option<EntityVersion>> {
+        let sql = format!(
+            "SELECT {} FROM entity_versions WHERE entity_type = ? AND entity_id = ? AND version = ?",
+            VERSION_COLUMNS
+        );
+        let row = sqlx::query_as::<_, VersionColumns>(&sql)
+            .bind(entity_type)
+            .bind(entity_id.to_string())
+            .bind(version)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to get entity version: {}", e))?;
+
+        row.map(columns_to_version).transpose()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Audit log
+// ---------------------------------------------------------------------------
+
+/// Column tuple of the `audit_log` table, in declaration order.
+type AuditColumns = (
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<serde_json::Value>,
+    Option<serde_json::Value>,
+    DateTime<Utc>,
+);
+
+const AUDIT_COLUMNS: &str = "id, entity_type, entity_id, operation, actor, `before`, `after`, at";
+
+fn columns_to_audit_entry(columns: AuditColumns) -> Result<AuditEntry> {
+    let (id, entity_type, entity_id, operation, actor, before, after, at) = columns;
+    Ok(AuditEntry {
+        id: Uuid::parse_str(&id).map_err(|e| anyhow!("Invalid id in audit_log: {}", e))?,
+        entity_type,
+        entity_id: Uuid::parse_str(&entity_id)
+            .map_err(|e| anyhow!("Invalid entity_id in audit_log: {}", e))?,
+        operation: operation.parse()?,
+        actor,
+        before,
+        after,
+        at,
+    })
+}
+
+/// Audit log backed by the MySQL `audit_log` table.
+///
+/// The table is created by [`ensure_schema`]. Entries are only ever inserted.
+#[derive(Clone, Debug)]
+pub struct MysqlAuditLogService {
+    pool: MySqlPool,
+}
+
+impl MysqlAuditLogService {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditLogService for MysqlAuditLogService {
+    async fn record(&self, entry: AuditEntry) -> Result<()> {
+        let sql = format!(
+            "INSERT INTO audit_log ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            AUDIT_COLUMNS
+        );
+        sqlx::query(&sql)
+            .bind(entry.id.to_string())
+            .bind(&entry.entity_type)
+            .bind(entry.entity_id.to_string())
+            .bind(entry.operation.as_str())
+            .bind(&entry.actor)
+            .bind(&entry.before)
+            .bind(&entry.after)
+            .bind(entry.at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to record audit entry: {}", e))?;
+        Ok(())
+    }
+
+    async fn list(&self, entity_type: &str, entity_id: &Uuid) -> Result<Vec<AuditEntry>> {
+        let sql = format!(
+            "SELECT {} FROM audit_log WHERE entity_type = ? AND entity_id = ? ORDER BY at",
+            AUDIT_COLUMNS
+        );
+        let rows = sqlx::query_as::<_, AuditColumns>(&sql)
+            .bind(entity_type)
+            .bind(entity_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to list audit entries: {}", e))?;
+
+        rows.into_iter().map(columns_to_audit_entry).collect()
+    }
 }
 
 #[cfg(test)]
@@ -688,6 +2961,47 @@ mod tests {
     // extract_data
     // -----------------------------------------------------------------------
 
+    #[test]
+    fn extract_data_rejects_values_wider_than_their_column() {
+        let product = TestProduct::new("x".repeat(256), "active".to_string(), 9.99);
+        let err = MysqlDataService::<TestProduct>::extract_data(&product).unwrap_err();
+        let err = err
+            .downcast_ref::<crate::core::validation::ValidationError>()
+            .expect("should be a validation error");
+        assert_eq!(err.field_errors()[0].field, "/name");
+        assert_eq!(err.field_errors()[0].code, Some("TOO_LONG"));
+
+        let product = TestProduct::new("x".repeat(255), "active".to_string(), 9.99);
+        assert!(MysqlDataService::<TestProduct>::extract_data(&product).is_ok());
+    }
+
+    crate::impl_data_entity!(TestPerson, "test_person", ["name"], {
+        first_name: String,
+        last_name: String,
+    }, computed: [full_name]);
+
+    impl TestPerson {
+        fn full_name(&self) -> String {
+            format!("{} {}", self.first_name, self.last_name)
+        }
+    }
+
+    #[test]
+    fn extract_data_leaves_computed_fields_out() {
+        let person = TestPerson::new(
+            "ada".to_string(),
+            "active".to_string(),
+            "Ada".to_string(),
+            "Lovelace".to_string(),
+        );
+        let read = crate::core::entity::to_read_json(&person).unwrap();
+        assert_eq!(read["full_name"], "Ada Lovelace");
+
+        let data = MysqlDataService::<TestPerson>::extract_data(&person).unwrap();
+        assert_eq!(data["first_name"], "Ada");
+        assert!(data.get("full_name").is_none());
+    }
+
     #[test]
     fn extract_data_strips_common_fields() {
         let product = TestProduct::new("Widget".to_string(), "active".to_string(), 9.99);
@@ -1028,4 +3342,76 @@ mod tests {
             "non-empty metadata should be preserved as Some"
         );
     }
+
+    // -----------------------------------------------------------------------
+    // metadata projection
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn projected_link_select_has_placeholder_pair_per_key() {
+        let fields = vec!["priority".to_string(), "role".to_string()];
+        let sql = projected_link_select(&fields);
+        assert!(sql.contains(
+            "JSON_OBJECT(?, JSON_EXTRACT(metadata, ?), ?, JSON_EXTRACT(metadata, ?)) AS metadata"
+        ));
+        assert!(sql.ends_with("FROM links"));
+    }
+
+    #[test]
+    fn strip_missing_metadata_drops_null_keys() {
+        let mut link = LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None);
+        link.metadata = Some(json!({"priority": 3, "role": null}));
+        let link = strip_missing_metadata(link);
+        assert_eq!(link.metadata, Some(json!({"priority": 3})));
+
+        let mut empty = LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None);
+        empty.metadata = Some(json!({"role": null}));
+        assert!(strip_missing_metadata(empty).metadata.is_none());
+    }
+
+    #[test]
+    fn filter_where_sql_translates_operators() {
+        let clauses = FilterClause::parse_all(&json!({"age": {"$gte": 18}})).unwrap();
+        let (sql, binds) = filter_where_sql(&clauses);
+        assert_eq!(
+            sql,
+            " AND (JSON_TYPE(JSON_EXTRACT(data, ?)) IN ('INTEGER', 'UNSIGNED INTEGER', 'DOUBLE', 'DECIMAL') \
+             AND JSON_EXTRACT(data, ?) >= ?)"
+        );
+        assert_eq!(binds[2], FilterBind::Int(18));
+
+        let clauses = FilterClause::parse_all(&json!({"status": {"$in": ["a", "b"]}})).unwrap();
+        let (sql, binds) = filter_where_sql(&clauses);
+        assert_eq!(sql, " AND (status = ? OR status = ?)");
+        assert_eq!(binds.len(), 2);
+
+        let clauses = FilterClause::parse_all(&json!({"email": {"$contains": "50%"}})).unwrap();
+        let (sql, binds) = filter_where_sql(&clauses);
+        assert_eq!(
+            sql,
+            " AND (JSON_TYPE(JSON_EXTRACT(data, ?)) = 'STRING' AND JSON_UNQUOTE(JSON_EXTRACT(data, ?)) LIKE ?)"
+        );
+        assert_eq!(binds[2], FilterBind::Text("%50\\%%".to_string()));
+    }
+
+    #[test]
+    fn delete_where_sql_has_placeholder_per_value() {
+        let filter = json!({"metadata.role": ["a", "b"]});
+        let conditions = LinkFilterCondition::parse_all(&filter).unwrap();
+        assert_eq!(
+            delete_where_sql(&conditions, false),
+            "DELETE FROM links WHERE link_type = ? \
+             AND JSON_UNQUOTE(JSON_EXTRACT(metadata, ?)) IN (?, ?)"
+        );
+    }
+
+    #[test]
+    fn delete_where_sql_binds_tenant_after_link_type() {
+        let filter = json!({"status": "expired"});
+        let conditions = LinkFilterCondition::parse_all(&filter).unwrap();
+        assert_eq!(
+            delete_where_sql(&conditions, true),
+            "DELETE FROM links WHERE link_type = ? AND tenant_id = ? AND status IN (?)"
+        );
+    }
 }
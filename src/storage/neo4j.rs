@@ -22,6 +22,7 @@
 //! to maintain compatibility with the `LinkService` contract — which allows
 //! creating links without requiring source/target entities to exist in the store.
 
+use crate::core::actor;
 use crate::core::link::LinkEntity;
 use crate::core::{Data, DataService, LinkService};
 use anyhow::{Result, anyhow};
@@ -203,7 +204,9 @@ impl<T: Data + Serialize + DeserializeOwned> Neo4jDataService<T> {
 
 #[async_trait]
 impl<T: Data + Serialize + DeserializeOwned> DataService<T> for Neo4jDataService<T> {
-    async fn create(&self, entity: T) -> Result<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
         let props = entity_to_bolt_props(&entity)?;
         let id = entity.id().to_string();
 
@@ -234,7 +237,7 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for Neo4jDataService
         node_to_entity(&node)
     }
 
-    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
         let cypher = format!("MATCH (n:`{}` {{id: $id}}) RETURN n", Self::label());
 
         let mut result = self
@@ -258,10 +261,15 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for Neo4jDataService
         }
     }
 
-    async fn list(&self) -> Result<Vec<T>> {
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
         let cypher = format!(
-            "MATCH (n:`{}`) RETURN n ORDER BY n.created_at DESC",
-            Self::label()
+            "MATCH (n:`{}`){} RETURN n ORDER BY n.created_at DESC",
+            Self::label(),
+            if include_deleted {
+                ""
+            } else {
+                " WHERE n.deleted_at IS NULL"
+            }
         );
 
         let mut result = self
@@ -285,7 +293,9 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for Neo4jDataService
         Ok(entities)
     }
 
-    async fn update(&self, id: &Uuid, entity: T) -> Result<T> {
+    async fn update(&self, id: &Uuid, mut entity: T) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
         let props = entity_to_bolt_props(&entity)?;
         let cypher = format!(
             "MATCH (n:`{}` {{id: $id}}) SET n = $props RETURN n",
@@ -332,7 +342,7 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for Neo4jDataService
         // Build Cypher with field name interpolated (safe: field comes from our code)
         // and value as a parameterized query argument with type-smart parsing
         let cypher = format!(
-            "MATCH (n:`{}`) WHERE n.`{}` = $value RETURN n",
+            "MATCH (n:`{}`) WHERE n.`{}` = $value AND n.deleted_at IS NULL RETURN n",
             Self::label(),
             field
         );
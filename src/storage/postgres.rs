@@ -18,7 +18,17 @@
 //! for type-specific fields. See `migrations/001_create_entities.up.sql`.
 //!
 //! Links are stored in a `links` table with dedicated columns for
-//! relationship traversal. See `migrations/002_create_links.up.sql`.
+//! relationship traversal. See `migrations/002_create_links.up.sql`. Its
+//! `source_type` and `target_type` columns are filled from the
+//! `LinkDefinition` when a link is created through the link handlers, and
+//! stay NULL for links created without a known definition.
+//!
+//! With history enabled ([`PostgresDataService::with_history`]), prior
+//! versions are stored in `entity_versions`. See
+//! `migrations/003_create_entity_versions.up.sql`.
+//!
+//! [`DataService::search_text`] reads the generated `search_vector` column
+//! added by `migrations/004_add_entities_search_vector.up.sql`.
 //!
 //! # Entity type convention
 //!
@@ -26,16 +36,29 @@
 //! All query filters (get, list, update, delete, search) use this value
 //! to scope operations to the correct entity type.
 
-use crate::core::link::LinkEntity;
-use crate::core::{Data, DataService, LinkService};
+use crate::core::actor;
+use crate::core::etag::{self, CacheResult, etag_for, etag_matches};
+use crate::core::history::{EntityVersion, HistoryError, HistoryService, state_as_of, state_at};
+use crate::core::link::{
+    LinkEntity, LinkFilterCondition, LinkFilterField, LinkLimit, RelationDirection,
+};
+use crate::core::validation::constraints::check_column_widths;
+use crate::core::{Cursor, Data, DataService, HealthCheck, LinkService};
+use crate::storage::error::check_precondition;
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use sqlx::{FromRow, PgPool};
+use sqlx::{FromRow, PgConnection, PgPool};
 use uuid::Uuid;
 
+/// Width of the `entities.name` column (`VARCHAR(512)`, see migrations)
+const NAME_COLUMN_WIDTH: usize = 512;
+
+/// Width of the `entities.status` column (`VARCHAR(64)`, see migrations)
+const STATUS_COLUMN_WIDTH: usize = 64;
+
 // ---------------------------------------------------------------------------
 // EntityRow — intermediate struct for DB row mapping
 // ---------------------------------------------------------------------------
@@ -107,6 +130,7 @@ const SEARCHABLE_COLUMNS: &[&str] = &["name", "status"];
 #[derive(Clone, Debug)]
 pub struct PostgresDataService<T> {
     pool: PgPool,
+    track_history: bool,
     _marker: std::marker::PhantomData<T>,
 }
 
@@ -115,10 +139,20 @@ impl<T> PostgresDataService<T> {
     pub fn new(pool: PgPool) -> Self {
         Self {
             pool,
+            track_history: false,
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Record the previous version of an entity on every update
+    ///
+    /// The snapshot is written to `entity_versions` in the same transaction
+    /// as the update. Read versions back with [`PostgresHistoryService`].
+    pub fn with_history(mut self) -> Self {
+        self.track_history = true;
+        self
+    }
+
     /// Get a reference to the underlying connection pool.
     pub fn pool(&self) -> &PgPool {
         &self.pool
@@ -133,11 +167,86 @@ impl<T: Data + Serialize + DeserializeOwned> PostgresDataService<T> {
         T::resource_name_singular()
     }
 
+    /// Keyset query behind `list_after` (`newer = false`) and `list_before`.
+    ///
+    /// Returns up to `limit` live entities on the requested side of
+    /// `cursor`, newest first.
+    async fn list_keyset(
+        &self,
+        cursor: Option<Cursor>,
+        newer: bool,
+        limit: usize,
+    ) -> Result<Vec<T>> {
+        let mut sql =
+            String::from("SELECT * FROM entities WHERE entity_type = $1 AND deleted_at IS NULL");
+        let limit_param = if cursor.is_some() {
+            sql.push_str(if newer {
+                " AND (created_at, id) > ($2, $3)"
+            } else {
+                " AND (created_at, id) < ($2, $3)"
+            });
+            "$4"
+        } else {
+            "$2"
+        };
+        sql.push_str(if newer {
+            " ORDER BY created_at ASC, id ASC LIMIT "
+        } else {
+            " ORDER BY created_at DESC, id DESC LIMIT "
+        });
+        sql.push_str(limit_param);
+
+        let mut query = sqlx::query_as::<_, EntityRow>(&sql).bind(Self::entity_type_name());
+        if let Some(cursor) = cursor {
+            query = query.bind(cursor.created_at).bind(cursor.id);
+        }
+        let rows = query
+            .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to list entities: {}", e))?;
+
+        let mut entities = rows
+            .into_iter()
+            .map(Self::row_to_entity)
+            .collect::<Result<Vec<T>>>()?;
+        if newer {
+            entities.reverse();
+        }
+        Ok(entities)
+    }
+
+    /// Recorded versions of an entity, oldest first, with when each was superseded
+    async fn snapshots(&self, id: &Uuid) -> Result<Vec<(T, DateTime<Utc>)>> {
+        if !self.track_history {
+            return Err(HistoryError::NotEnabled.into());
+        }
+        let rows: Vec<(serde_json::Value, DateTime<Utc>)> = sqlx::query_as(
+            "SELECT snapshot, changed_at FROM entity_versions \
+             WHERE entity_type = $1 AND entity_id = $2 ORDER BY version",
+        )
+        .bind(Self::entity_type_name())
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to list entity versions: {}", e))?;
+
+        rows.into_iter()
+            .map(|(snapshot, changed_at)| Ok((serde_json::from_value(snapshot)?, changed_at)))
+            .collect()
+    }
+
     /// Convert a domain entity into a database row.
     ///
     /// Serializes the full entity to JSON, extracts common fields into
     /// dedicated columns, and stores remaining fields in the JSONB `data` column.
+    /// A name or status wider than its column is rejected as `TOO_LONG`.
     fn entity_to_row(entity: &T) -> Result<EntityRow> {
+        check_column_widths(&[
+            ("name", entity.name(), NAME_COLUMN_WIDTH),
+            ("status", entity.status(), STATUS_COLUMN_WIDTH),
+        ])?;
+
         // Serialize the full entity to JSON
         let mut data = serde_json::to_value(entity)
             .map_err(|e| anyhow!("Failed to serialize entity: {}", e))?;
@@ -147,6 +256,10 @@ impl<T: Data + Serialize + DeserializeOwned> PostgresDataService<T> {
             for field in ENTITY_COMMON_FIELDS {
                 obj.remove(*field);
             }
+            // Computed fields are derived on reads, never stored
+            for field in entity.computed_fields().keys() {
+                obj.remove(field);
+            }
         }
 
         Ok(EntityRow {
@@ -203,6 +316,127 @@ impl<T: Data + Serialize + DeserializeOwned> PostgresDataService<T> {
         serde_json::from_value::<T>(json)
             .map_err(|e| anyhow!("Failed to deserialize entity from row: {}", e))
     }
+
+    /// Update an existing entity, attributing the change to `actor`
+    ///
+    /// Identical to `DataService::update`; `actor` is stored with the
+    /// history snapshot when history is enabled.
+    pub async fn update_as(&self, id: &Uuid, mut entity: T, actor: Option<&str>) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
+        let row = Self::entity_to_row(&entity)?;
+
+        if !self.track_history {
+            let mut conn = self
+                .pool
+                .acquire()
+                .await
+                .map_err(|e| anyhow!("Failed to update entity: {}", e))?;
+            return match Self::update_row(&mut conn, id, &row).await? {
+                Some(r) => Self::row_to_entity(r),
+                None => match self.get_with_deleted(id).await? {
+                    Some(stored) => {
+                        check_precondition(id, &stored.updated_at(), stored.version())?;
+                        Err(anyhow!("Failed to update entity: {}", id))
+                    }
+                    None => Err(anyhow!("Entity not found: {}", id)),
+                },
+            };
+        }
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+
+        let previous = sqlx::query_as::<_, EntityRow>(
+            "SELECT * FROM entities WHERE id = $1 AND entity_type = $2 FOR UPDATE",
+        )
+        .bind(id)
+        .bind(Self::entity_type_name())
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| anyhow!("Failed to update entity: {}", e))?
+        .ok_or_else(|| anyhow!("Entity not found: {}", id))?;
+
+        let previous = Self::row_to_entity(previous)?;
+        check_precondition(id, &previous.updated_at(), previous.version())?;
+        let snapshot = serde_json::to_value(previous)?;
+        insert_version(&mut tx, Self::entity_type_name(), id, &snapshot, actor).await?;
+
+        let updated = Self::update_row(&mut tx, id, &row)
+            .await?
+            .ok_or_else(|| anyhow!("Entity not found: {}", id))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit entity update: {}", e))?;
+
+        Self::row_to_entity(updated)
+    }
+
+    /// Write the entity's columns, returning the updated row
+    ///
+    /// Under an `If-Match` precondition (see [`etag::if_match`]) the row must
+    /// still have the checked `updated_at` and version.
+    async fn update_row(
+        conn: &mut PgConnection,
+        id: &Uuid,
+        row: &EntityRow,
+    ) -> Result<Option<EntityRow>> {
+        let precondition = etag::precondition();
+        let condition = if precondition.is_some() {
+            " AND updated_at = $9 AND COALESCE((data->>'version')::BIGINT, 0) = $10"
+        } else {
+            ""
+        };
+        let sql = format!(
+            "UPDATE entities \
+             SET name = $1, status = $2, tenant_id = $3, data = $4, updated_at = $5, deleted_at = $6 \
+             WHERE id = $7 AND entity_type = $8{} \
+             RETURNING *",
+            condition
+        );
+        let mut query = sqlx::query_as::<_, EntityRow>(&sql)
+            .bind(&row.name)
+            .bind(&row.status)
+            .bind(row.tenant_id)
+            .bind(&row.data)
+            .bind(row.updated_at)
+            .bind(row.deleted_at)
+            .bind(id)
+            .bind(Self::entity_type_name());
+        if let Some(expected) = precondition {
+            query = query
+                .bind(expected.updated_at)
+                .bind(expected.version as i64);
+        }
+        query
+            .fetch_optional(conn)
+            .await
+            .map_err(|e| anyhow!("Failed to update entity: {}", e))
+    }
+}
+
+/// Round trip used by the health checks of the postgres services
+async fn ping(pool: &PgPool) -> Result<()> {
+    sqlx::query("SELECT 1")
+        .execute(pool)
+        .await
+        .map_err(|e| anyhow!("postgres is unreachable: {}", e))?;
+    Ok(())
+}
+
+#[async_trait]
+impl<T: Send + Sync> HealthCheck for PostgresDataService<T> {
+    fn name(&self) -> &str {
+        "postgres"
+    }
+
+    async fn check(&self) -> Result<()> {
+        ping(&self.pool).await
+    }
 }
 
 #[async_trait]
@@ -210,7 +444,9 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for PostgresDataServ
     /// Insert a new entity into the `entities` table.
     ///
     /// Returns the created entity as read back from the database.
-    async fn create(&self, entity: T) -> Result<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
         let row = Self::entity_to_row(&entity)?;
 
         let result = sqlx::query_as::<_, EntityRow>(
@@ -234,10 +470,11 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for PostgresDataServ
         Self::row_to_entity(result)
     }
 
-    /// Fetch an entity by UUID, scoped to entity type `T`.
+    /// Fetch an entity by UUID, scoped to entity type `T`, including
+    /// soft-deleted rows.
     ///
     /// Returns `Ok(None)` if the entity does not exist.
-    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
         let row = sqlx::query_as::<_, EntityRow>(
             "SELECT * FROM entities WHERE id = $1 AND entity_type = $2",
         )
@@ -253,47 +490,69 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for PostgresDataServ
         }
     }
 
-    /// List all entities of type `T`, ordered by creation time (newest first).
-    async fn list(&self) -> Result<Vec<T>> {
-        let rows = sqlx::query_as::<_, EntityRow>(
-            "SELECT * FROM entities WHERE entity_type = $1 ORDER BY created_at DESC",
+    /// List entities of type `T`, ordered by creation time (newest first).
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
+        let sql = format!(
+            "SELECT * FROM entities WHERE entity_type = $1{} ORDER BY created_at DESC",
+            if include_deleted {
+                ""
+            } else {
+                " AND deleted_at IS NULL"
+            }
+        );
+        let rows = sqlx::query_as::<_, EntityRow>(&sql)
+            .bind(Self::entity_type_name())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to list entities: {}", e))?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+
+    async fn list_after(&self, cursor: Option<Cursor>, limit: usize) -> Result<Vec<T>> {
+        self.list_keyset(cursor, false, limit).await
+    }
+
+    async fn list_before(&self, cursor: Cursor, limit: usize) -> Result<Vec<T>> {
+        self.list_keyset(Some(cursor), true, limit).await
+    }
+
+    /// Count live (not soft-deleted) entities of this type.
+    async fn count(&self) -> Result<usize> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM entities WHERE entity_type = $1 AND deleted_at IS NULL",
         )
         .bind(Self::entity_type_name())
-        .fetch_all(&self.pool)
+        .fetch_one(&self.pool)
         .await
-        .map_err(|e| anyhow!("Failed to list entities: {}", e))?;
+        .map_err(|e| anyhow!("Failed to count entities: {}", e))?;
 
-        rows.into_iter().map(Self::row_to_entity).collect()
+        Ok(count as usize)
     }
 
     /// Update an existing entity.
     ///
     /// Returns `Err` if the entity does not exist (no row matched).
     async fn update(&self, id: &Uuid, entity: T) -> Result<T> {
-        let row = Self::entity_to_row(&entity)?;
+        self.update_as(id, entity, None).await
+    }
 
-        let result = sqlx::query_as::<_, EntityRow>(
-            "UPDATE entities \
-             SET name = $1, status = $2, tenant_id = $3, data = $4, updated_at = $5, deleted_at = $6 \
-             WHERE id = $7 AND entity_type = $8 \
-             RETURNING *",
-        )
-        .bind(&row.name)
-        .bind(&row.status)
-        .bind(row.tenant_id)
-        .bind(&row.data)
-        .bind(row.updated_at)
-        .bind(row.deleted_at)
-        .bind(id)
-        .bind(Self::entity_type_name())
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| anyhow!("Failed to update entity: {}", e))?;
+    async fn get_at(&self, id: &Uuid, version: i64) -> Result<Option<T>> {
+        let snapshots = self.snapshots(id).await?;
+        let current = self.get_with_deleted(id).await?;
+        Ok(state_at(
+            snapshots.into_iter().map(|(s, _)| s).collect(),
+            current,
+            version,
+        ))
+    }
 
-        match result {
-            Some(r) => Self::row_to_entity(r),
-            None => Err(anyhow!("Entity not found: {}", id)),
-        }
+    async fn get_as_of(&self, id: &Uuid, at: DateTime<Utc>) -> Result<Option<T>> {
+        let snapshots = self.snapshots(id).await?;
+        let current = self.get_with_deleted(id).await?;
+        Ok(state_as_of(snapshots, current, at, |e| {
+            Some(e.created_at())
+        }))
     }
 
     /// Delete an entity by UUID.
@@ -314,12 +573,12 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for PostgresDataServ
     ///
     /// For common fields (`name`, `status`), uses direct column comparison.
     /// For custom fields, uses JSONB text extraction (`data->>field = value`).
-    /// All searches are scoped to entity type `T`.
+    /// All searches are scoped to entity type `T` and skip soft-deleted rows.
     async fn search(&self, field: &str, value: &str) -> Result<Vec<T>> {
         let rows = if SEARCHABLE_COLUMNS.contains(&field) {
             // Search by dedicated column (field name is whitelisted, safe to interpolate)
             let sql = format!(
-                "SELECT * FROM entities WHERE entity_type = $1 AND {} = $2",
+                "SELECT * FROM entities WHERE entity_type = $1 AND deleted_at IS NULL AND {} = $2",
                 field
             );
             sqlx::query_as::<_, EntityRow>(&sql)
@@ -331,7 +590,8 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for PostgresDataServ
         } else {
             // Search by JSONB field: data->>field_name returns text for comparison
             sqlx::query_as::<_, EntityRow>(
-                "SELECT * FROM entities WHERE entity_type = $1 AND data->>$2 = $3",
+                "SELECT * FROM entities \
+                 WHERE entity_type = $1 AND deleted_at IS NULL AND data->>$2 = $3",
             )
             .bind(Self::entity_type_name())
             .bind(field)
@@ -343,6 +603,106 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for PostgresDataServ
 
         rows.into_iter().map(Self::row_to_entity).collect()
     }
+
+    /// Conditional get keyed on the ETag of `id`, `updated_at` and version.
+    ///
+    /// Only those columns are read first; the full row is loaded and
+    /// deserialized only when the ETag no longer matches.
+    /// The version is read from the `data` document, 0 when absent.
+    async fn get_if_modified(&self, id: &Uuid, etag: Option<&str>) -> Result<CacheResult<T>> {
+        if let Some(tag) = etag {
+            let stored: Option<(DateTime<Utc>, i64)> = sqlx::query_as(
+                "SELECT updated_at, COALESCE((data->>'version')::BIGINT, 0) FROM entities \
+                 WHERE id = $1 AND entity_type = $2 AND deleted_at IS NULL",
+            )
+            .bind(id)
+            .bind(Self::entity_type_name())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to check entity version: {}", e))?;
+
+            match stored {
+                None => return Ok(CacheResult::NotFound),
+                Some((updated_at, version))
+                    if etag_matches(tag, &etag_for(id, &updated_at, version as u64)) =>
+                {
+                    return Ok(CacheResult::NotModified);
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(match self.get(id).await? {
+            Some(entity) => CacheResult::Modified(entity),
+            None => CacheResult::NotFound,
+        })
+    }
+
+    /// Full-text search ranked with `ts_rank` over the `search_vector`
+    /// column (see `migrations/004_add_entities_search_vector.up.sql`).
+    ///
+    /// Entities whose indexed fields contain `query` as a substring the
+    /// tokenizer does not split out (inside an e-mail address, a word
+    /// prefix) are kept too, ranked after the full-text matches.
+    async fn search_text(&self, query: &str) -> Result<Vec<T>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (sql, json_fields) = search_text_sql(T::indexed_fields());
+        let mut select = sqlx::query_as::<_, EntityRow>(&sql)
+            .bind(Self::entity_type_name())
+            .bind(query)
+            .bind(like_pattern(query));
+        for field in json_fields {
+            select = select.bind(field);
+        }
+        let rows = select
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to search entities by text: {}", e))?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+}
+
+/// Build the SELECT used by [`PostgresDataService::search_text`].
+///
+/// Parameters are numbered in bind order: entity type, text query, `ILIKE`
+/// pattern, then the returned JSONB keys. Fields stored in their own column
+/// are compared directly (their names are whitelisted in
+/// [`SEARCHABLE_COLUMNS`]); the others through `data->>key`.
+fn search_text_sql<'a>(indexed_fields: &[&'a str]) -> (String, Vec<&'a str>) {
+    let mut json_fields = Vec::new();
+    let mut substring = Vec::new();
+    for field in indexed_fields {
+        if SEARCHABLE_COLUMNS.contains(field) {
+            substring.push(format!("{} ILIKE $3", field));
+        } else {
+            json_fields.push(*field);
+            substring.push(format!("data->>${} ILIKE $3", 3 + json_fields.len()));
+        }
+    }
+    let mut matches = "search_vector @@ plainto_tsquery('simple', $2)".to_string();
+    for condition in substring {
+        matches.push_str(" OR ");
+        matches.push_str(&condition);
+    }
+    let sql = format!(
+        "SELECT * FROM entities WHERE entity_type = $1 AND deleted_at IS NULL AND ({}) \
+         ORDER BY ts_rank(search_vector, plainto_tsquery('simple', $2)) DESC, created_at DESC",
+        matches
+    );
+    (sql, json_fields)
+}
+
+/// `ILIKE` pattern matching `text` anywhere, with its wildcards escaped
+fn like_pattern(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
 }
 
 // ---------------------------------------------------------------------------
@@ -376,8 +736,8 @@ impl LinkRow {
             link_type: link.link_type.clone(),
             source_id: link.source_id,
             target_id: link.target_id,
-            source_type: None, // LinkEntity doesn't carry source_type
-            target_type: None, // LinkEntity doesn't carry target_type
+            source_type: link.source_type.clone(),
+            target_type: link.target_type.clone(),
             status: link.status.clone(),
             tenant_id: link.tenant_id,
             metadata: link.metadata.clone().unwrap_or(serde_json::json!({})),
@@ -400,6 +760,8 @@ impl LinkRow {
             link_type: self.link_type,
             source_id: self.source_id,
             target_id: self.target_id,
+            source_type: self.source_type,
+            target_type: self.target_type,
             metadata: if self.metadata == serde_json::json!({}) {
                 None
             } else {
@@ -443,6 +805,126 @@ impl PostgresLinkService {
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
+
+    /// Shared traversal query on `source_id` or `target_id`.
+    ///
+    /// When `metadata_fields` is set, only those metadata keys are selected.
+    async fn find_links(
+        &self,
+        column: &str,
+        type_column: &str,
+        entity_id: &Uuid,
+        link_type: Option<&str>,
+        entity_type: Option<&str>,
+        metadata_fields: Option<&[String]>,
+    ) -> sqlx::Result<Vec<LinkEntity>> {
+        let sql = link_traversal_sql(
+            column,
+            type_column,
+            metadata_fields.is_some(),
+            link_type.is_some(),
+            entity_type.is_some(),
+        );
+
+        let mut query = sqlx::query_as::<_, LinkRow>(&sql).bind(entity_id);
+
+        if let Some(fields) = metadata_fields {
+            query = query.bind(fields);
+        }
+        if let Some(lt) = link_type {
+            query = query.bind(lt);
+        }
+        if let Some(et) = entity_type {
+            query = query.bind(et);
+        }
+
+        let rows = query.fetch_all(&self.pool).await?;
+        Ok(rows.into_iter().map(LinkRow::into_link).collect())
+    }
+}
+
+/// Build the SELECT used by [`PostgresLinkService::find_links`].
+///
+/// `column` and `type_column` are always hardcoded column names, never user
+/// input. Parameters are numbered in bind order: entity id, metadata keys,
+/// link type, entity type. Rows whose type column is NULL (links created
+/// without a known type) match any type filter.
+fn link_traversal_sql(
+    column: &str,
+    type_column: &str,
+    projected: bool,
+    with_link_type: bool,
+    with_entity_type: bool,
+) -> String {
+    let mut sql = if projected {
+        format!(
+            "SELECT {} FROM links WHERE {} = $1",
+            projected_link_columns("$2"),
+            column
+        )
+    } else {
+        format!("SELECT * FROM links WHERE {} = $1", column)
+    };
+    let mut next = if projected { 3 } else { 2 };
+
+    if with_link_type {
+        sql.push_str(&format!(" AND link_type = ${}", next));
+        next += 1;
+    }
+    if with_entity_type {
+        sql.push_str(&format!(
+            " AND ({0} IS NULL OR {0} = ${1})",
+            type_column, next
+        ));
+    }
+    sql.push_str(" ORDER BY created_at DESC");
+    sql
+}
+
+/// Build the DELETE used by [`PostgresLinkService::delete_where`].
+///
+/// Column names come from [`LinkFilterCondition::COLUMNS`], never from user
+/// input. Parameters are numbered in bind order: link type, then per
+/// condition the `TEXT[]` of accepted values, preceded by the key for
+/// metadata conditions.
+fn delete_where_sql(conditions: &[LinkFilterCondition]) -> String {
+    let mut sql = "DELETE FROM links WHERE link_type = $1".to_string();
+    let mut next = 2;
+    for condition in conditions {
+        match &condition.field {
+            LinkFilterField::Column(column) => {
+                sql.push_str(&format!(" AND {}::text = ANY(${})", column, next));
+                next += 1;
+            }
+            LinkFilterField::Metadata(_) => {
+                sql.push_str(&format!(" AND metadata->>${} = ANY(${})", next, next + 1));
+                next += 2;
+            }
+        }
+    }
+    sql
+}
+
+/// Column list for a `links` SELECT where `metadata` only keeps the keys
+/// listed in the `TEXT[]` parameter `keys_param`.
+fn projected_link_columns(keys_param: &str) -> String {
+    format!(
+        "id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, \
+         COALESCE((SELECT jsonb_object_agg(key, value) FROM jsonb_each(metadata) WHERE key = ANY({})), '{{}}'::jsonb) AS metadata, \
+         created_at, updated_at, deleted_at",
+        keys_param
+    )
+}
+
+#[async_trait]
+impl HealthCheck for PostgresLinkService {
+    fn name(&self) -> &str {
+        "postgres"
+    }
+
+    async fn check(&self) -> Result<()> {
+        ping(&self.pool).await
+    }
 }
 
 #[async_trait]
@@ -476,6 +958,78 @@ impl LinkService for PostgresLinkService {
         Ok(result.into_link())
     }
 
+    /// Insert a link only if the counts allow it, in one `INSERT ... SELECT`.
+    ///
+    /// A transaction-scoped advisory lock on the link type serializes bounded
+    /// inserts, since under READ COMMITTED two concurrent statements could
+    /// otherwise both see a count below the cap.
+    async fn create_within_limit(
+        &self,
+        link: LinkEntity,
+        limit: LinkLimit,
+    ) -> Result<Option<LinkEntity>> {
+        let row = LinkRow::from_link(&link);
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+            .bind(format!("this:links:{}", link.link_type))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to lock link type: {}", e))?;
+
+        let mut sql = String::from(
+            "INSERT INTO links (id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, metadata, created_at, updated_at, deleted_at) \
+             SELECT $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13 WHERE TRUE",
+        );
+        let mut param = 14;
+        // $4 is source_id and $5 is target_id in the select list
+        for (max, column, id_param) in [
+            (limit.per_source, "source_id", 4),
+            (limit.per_target, "target_id", 5),
+        ] {
+            if max.is_some() {
+                sql.push_str(&format!(
+                    " AND (SELECT COUNT(*) FROM links WHERE link_type = $3 AND {column} = ${id_param} \
+                     AND deleted_at IS NULL) < ${param}"
+                ));
+                param += 1;
+            }
+        }
+        sql.push_str(" RETURNING *");
+
+        let mut query = sqlx::query_as::<_, LinkRow>(&sql)
+            .bind(row.id)
+            .bind(&row.entity_type)
+            .bind(&row.link_type)
+            .bind(row.source_id)
+            .bind(row.target_id)
+            .bind(&row.source_type)
+            .bind(&row.target_type)
+            .bind(&row.status)
+            .bind(row.tenant_id)
+            .bind(&row.metadata)
+            .bind(row.created_at)
+            .bind(row.updated_at)
+            .bind(row.deleted_at);
+        for max in [limit.per_source, limit.per_target].into_iter().flatten() {
+            query = query.bind(max as i64);
+        }
+
+        let created = query
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to create link: {}", e))?;
+        tx.commit()
+            .await
+            .map_err(|e| anyhow!("Failed to commit link: {}", e))?;
+
+        Ok(created.map(LinkRow::into_link))
+    }
+
     /// Fetch a link by UUID.
     async fn get(&self, id: &Uuid) -> Result<Option<LinkEntity>> {
         let row = sqlx::query_as::<_, LinkRow>("SELECT * FROM links WHERE id = $1")
@@ -499,70 +1053,117 @@ impl LinkService for PostgresLinkService {
 
     /// Find links by source entity, with optional filters.
     ///
-    /// Dynamically builds WHERE clauses for link_type filter.
-    ///
-    /// **Note:** `target_type` is currently ignored because `LinkEntity` does not
-    /// carry entity-type metadata — the `target_type` column is always NULL.
-    /// This matches the `InMemoryLinkService` behavior. When/if `LinkEntity`
-    /// gains a `target_type` field, re-enable the SQL filter here.
+    /// `target_type` matches the `target_type` column; links stored without a
+    /// type are always included.
     async fn find_by_source(
         &self,
         source_id: &Uuid,
         link_type: Option<&str>,
-        _target_type: Option<&str>,
+        target_type: Option<&str>,
     ) -> Result<Vec<LinkEntity>> {
-        let mut sql = String::from("SELECT * FROM links WHERE source_id = $1");
-
-        if link_type.is_some() {
-            sql.push_str(" AND link_type = $2");
-        }
-        sql.push_str(" ORDER BY created_at DESC");
-
-        let mut query = sqlx::query_as::<_, LinkRow>(&sql).bind(source_id);
-
-        if let Some(lt) = link_type {
-            query = query.bind(lt);
-        }
-
-        let rows = query
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| anyhow!("Failed to find links by source: {}", e))?;
-
-        Ok(rows.into_iter().map(LinkRow::into_link).collect())
+        self.find_links(
+            "source_id",
+            "target_type",
+            source_id,
+            link_type,
+            target_type,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by source: {}", e))
     }
 
     /// Find links by target entity, with optional filters.
     ///
-    /// Dynamically builds WHERE clauses for link_type filter.
-    ///
-    /// **Note:** `source_type` is currently ignored because `LinkEntity` does not
-    /// carry entity-type metadata — the `source_type` column is always NULL.
-    /// This matches the `InMemoryLinkService` behavior. When/if `LinkEntity`
-    /// gains a `source_type` field, re-enable the SQL filter here.
+    /// `source_type` matches the `source_type` column; links stored without a
+    /// type are always included.
     async fn find_by_target(
         &self,
         target_id: &Uuid,
         link_type: Option<&str>,
-        _source_type: Option<&str>,
+        source_type: Option<&str>,
     ) -> Result<Vec<LinkEntity>> {
-        let mut sql = String::from("SELECT * FROM links WHERE target_id = $1");
+        self.find_links(
+            "target_id",
+            "source_type",
+            target_id,
+            link_type,
+            source_type,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by target: {}", e))
+    }
 
-        if link_type.is_some() {
-            sql.push_str(" AND link_type = $2");
-        }
-        sql.push_str(" ORDER BY created_at DESC");
+    /// Find links by source entity, selecting only the requested metadata keys.
+    ///
+    /// The projection is computed in SQL so the full JSONB blob never leaves
+    /// the database.
+    async fn find_by_source_projected(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+        metadata_fields: &[String],
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "source_id",
+            "target_type",
+            source_id,
+            link_type,
+            target_type,
+            Some(metadata_fields),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by source: {}", e))
+    }
 
-        let mut query = sqlx::query_as::<_, LinkRow>(&sql).bind(target_id);
+    /// Find links by target entity, selecting only the requested metadata keys.
+    async fn find_by_target_projected(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+        metadata_fields: &[String],
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "target_id",
+            "source_type",
+            target_id,
+            link_type,
+            source_type,
+            Some(metadata_fields),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by target: {}", e))
+    }
 
-        if let Some(lt) = link_type {
-            query = query.bind(lt);
-        }
+    /// Find links touching an entity, newest first.
+    ///
+    /// Each direction is a single query on its own index; `Both` uses
+    /// `source_id = $1 OR target_id = $1`.
+    async fn find_relations(
+        &self,
+        entity_id: &Uuid,
+        direction: RelationDirection,
+    ) -> Result<Vec<LinkEntity>> {
+        let sql = match direction {
+            RelationDirection::Out => {
+                "SELECT * FROM links WHERE source_id = $1 ORDER BY created_at DESC"
+            }
+            RelationDirection::In => {
+                "SELECT * FROM links WHERE target_id = $1 ORDER BY created_at DESC"
+            }
+            RelationDirection::Both => {
+                "SELECT * FROM links WHERE source_id = $1 OR target_id = $1 ORDER BY created_at DESC"
+            }
+        };
 
-        let rows = query
+        let rows = sqlx::query_as::<_, LinkRow>(sql)
+            .bind(entity_id)
             .fetch_all(&self.pool)
             .await
-            .map_err(|e| anyhow!("Failed to find links by target: {}", e))?;
+            .map_err(|e| anyhow!("Failed to find relations: {}", e))?;
 
         Ok(rows.into_iter().map(LinkRow::into_link).collect())
     }
@@ -624,6 +1225,170 @@ impl LinkService for PostgresLinkService {
 
         Ok(())
     }
+
+    async fn delete_where(
+        &self,
+        link_type: &str,
+        filter: Option<&serde_json::Value>,
+    ) -> Result<u64> {
+        let conditions = match filter {
+            Some(filter) => LinkFilterCondition::parse_all(filter).map_err(|e| anyhow!(e))?,
+            None => Vec::new(),
+        };
+
+        let sql = delete_where_sql(&conditions);
+        let mut query = sqlx::query(&sql).bind(link_type);
+        for condition in &conditions {
+            if let LinkFilterField::Metadata(key) = &condition.field {
+                query = query.bind(key);
+            }
+            query = query.bind(&condition.values);
+        }
+
+        let result = query
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete links by filter: {}", e))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Entity history
+// ---------------------------------------------------------------------------
+
+/// Database row representation for the `entity_versions` table.
+#[derive(Debug, FromRow)]
+struct VersionRow {
+    entity_id: Uuid,
+    entity_type: String,
+    version: i64,
+    snapshot: serde_json::Value,
+    changed_at: DateTime<Utc>,
+    actor: Option<String>,
+}
+
+impl From<VersionRow> for EntityVersion {
+    fn from(row: VersionRow) -> Self {
+        EntityVersion {
+            entity_id: row.entity_id,
+            entity_type: row.entity_type,
+            version: row.version,
+            snapshot: row.snapshot,
+            changed_at: row.changed_at,
+            actor: row.actor,
+        }
+    }
+}
+
+/// Append `snapshot` as the next version of an entity.
+///
+/// Callers updating the entity hold its row lock (`SELECT ... FOR UPDATE`),
+/// which serializes version numbering per entity.
+async fn insert_version(
+    conn: &mut PgConnection,
+    entity_type: &str,
+    entity_id: &Uuid,
+    snapshot: &serde_json::Value,
+    actor: Option<&str>,
+) -> Result<EntityVersion> {
+    let row = sqlx::query_as::<_, VersionRow>(
+        "INSERT INTO entity_versions (entity_id, entity_type, version, snapshot, changed_at, actor) \
+         SELECT $1, $2, COALESCE(MAX(version), 0) + 1, $3, $4, $5 \
+         FROM entity_versions WHERE entity_id = $1 AND entity_type = $2 \
+         RETURNING *",
+    )
+    .bind(entity_id)
+    .bind(entity_type)
+    .bind(snapshot)
+    .bind(Utc::now())
+    .bind(actor)
+    .fetch_one(conn)
+    .await
+    .map_err(|e| anyhow!("Failed to record entity version: {}", e))?;
+
+    Ok(row.into())
+}
+
+/// Entity history backed by the PostgreSQL `entity_versions` table.
+///
+/// Versions are written by [`PostgresDataService::with_history`]; this
+/// service exposes them to the history endpoints.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let orders = PostgresDataService::<Order>::new(pool.clone()).with_history();
+/// let builder = ServerBuilder::new()
+///     .with_history_service(PostgresHistoryService::new(pool));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PostgresHistoryService {
+    pool: PgPool,
+}
+
+impl PostgresHistoryService {
+    /// Create a new `PostgresHistoryService` with the given connection pool.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HistoryService for PostgresHistoryService {
+    async fn record(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        snapshot: serde_json::Value,
+        actor: Option<&str>,
+    ) -> Result<EntityVersion> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| anyhow!("Failed to record entity version: {}", e))?;
+        insert_version(&mut conn, entity_type, entity_id, &snapshot, actor).await
+    }
+
+    async fn list_versions(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+    ) -> Result<Vec<EntityVersion>> {
+        let rows = sqlx::query_as::<_, VersionRow>(
+            "SELECT * FROM entity_versions \
+             WHERE entity_type = $1 AND entity_id = $2 ORDER BY version",
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to list entity versions: {}", e))?;
+
+        Ok(rows.into_iter().map(EntityVersion::from).collect())
+    }
+
+    async fn get_version(
+        &self,
+        entity_type: &str,
+        entity_id: &Uuid,
+        version: i64,
+    ) -> Result<Option<EntityVersion>> {
+        let row = sqlx::query_as::<_, VersionRow>(
+            "SELECT * FROM entity_versions \
+             WHERE entity_type = $1 AND entity_id = $2 AND version = $3",
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .bind(version)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to get entity version: {}", e))?;
+
+        Ok(row.map(EntityVersion::from))
+    }
 }
 
 #[cfg(test)]
@@ -659,6 +1424,17 @@ mod tests {
         assert_eq!(obj.get("amount").and_then(|v| v.as_f64()), Some(42.5));
     }
 
+    #[test]
+    fn entity_to_row_rejects_values_wider_than_their_column() {
+        let order = TestOrder::new("Widget".into(), "s".repeat(65), 1.0);
+        let err = PostgresDataService::<TestOrder>::entity_to_row(&order).unwrap_err();
+        let err = err
+            .downcast_ref::<crate::core::validation::ValidationError>()
+            .expect("should be a validation error");
+        assert_eq!(err.field_errors()[0].field, "/status");
+        assert_eq!(err.field_errors()[0].code, Some("TOO_LONG"));
+    }
+
     #[test]
     fn entity_to_row_preserves_entity_type() {
         let order = TestOrder::new("Gadget".into(), "active".into(), 10.0);
@@ -776,6 +1552,8 @@ mod tests {
             link_type: "owns".into(),
             source_id: Uuid::new_v4(),
             target_id: Uuid::new_v4(),
+            source_type: Some("user".into()),
+            target_type: Some("car".into()),
             metadata: Some(json!({"priority": "high"})),
         }
     }
@@ -797,9 +1575,8 @@ mod tests {
         assert_eq!(row.deleted_at, link.deleted_at);
         // metadata: Some({...}) -> stored as the inner value
         assert_eq!(row.metadata, json!({"priority": "high"}));
-        // source_type / target_type are always None
-        assert!(row.source_type.is_none());
-        assert!(row.target_type.is_none());
+        assert_eq!(row.source_type, link.source_type);
+        assert_eq!(row.target_type, link.target_type);
     }
 
     #[test]
@@ -851,4 +1628,67 @@ mod tests {
             "non-empty metadata should survive roundtrip"
         );
     }
+
+    #[test]
+    fn projected_link_columns_aliases_metadata() {
+        let cols = projected_link_columns("$2");
+        assert!(cols.contains("key = ANY($2)"));
+        assert!(cols.contains("AS metadata"));
+        assert!(cols.starts_with("id, entity_type, link_type"));
+        assert!(cols.ends_with("created_at, updated_at, deleted_at"));
+    }
+
+    #[test]
+    fn link_traversal_sql_numbers_params_in_bind_order() {
+        let sql = link_traversal_sql("source_id", "target_type", false, true, true);
+        assert_eq!(
+            sql,
+            "SELECT * FROM links WHERE source_id = $1 AND link_type = $2 \
+             AND (target_type IS NULL OR target_type = $3) ORDER BY created_at DESC"
+        );
+
+        let projected = link_traversal_sql("target_id", "source_type", true, false, true);
+        assert!(projected.contains("key = ANY($2)"));
+        assert!(projected.contains("AND (source_type IS NULL OR source_type = $3)"));
+        assert!(!projected.contains("link_type = $"));
+    }
+
+    #[test]
+    fn search_text_sql_numbers_params_in_bind_order() {
+        let (sql, json_fields) = search_text_sql(&["name", "email", "city"]);
+        assert_eq!(json_fields, ["email", "city"]);
+        assert!(sql.contains(
+            "(search_vector @@ plainto_tsquery('simple', $2) OR name ILIKE $3 \
+             OR data->>$4 ILIKE $3 OR data->>$5 ILIKE $3)"
+        ));
+        assert!(sql.ends_with(
+            "ORDER BY ts_rank(search_vector, plainto_tsquery('simple', $2)) DESC, created_at DESC"
+        ));
+    }
+
+    #[test]
+    fn like_pattern_escapes_wildcards() {
+        assert_eq!(like_pattern("alice"), "%alice%");
+        assert_eq!(like_pattern("50%_off\\"), "%50\\%\\_off\\\\%");
+    }
+
+    #[test]
+    fn delete_where_sql_numbers_params_in_bind_order() {
+        let by_status = LinkFilterCondition::parse_all(&json!({"status": "expired"})).unwrap();
+        assert_eq!(
+            delete_where_sql(&by_status),
+            "DELETE FROM links WHERE link_type = $1 AND status::text = ANY($2)"
+        );
+
+        let by_role =
+            LinkFilterCondition::parse_all(&json!({"metadata.role": ["a", "b"]})).unwrap();
+        assert_eq!(
+            delete_where_sql(&by_role),
+            "DELETE FROM links WHERE link_type = $1 AND metadata->>$2 = ANY($3)"
+        );
+        assert_eq!(
+            delete_where_sql(&[]),
+            "DELETE FROM links WHERE link_type = $1"
+        );
+    }
 }
@@ -0,0 +1,322 @@
+//! Redis storage backend for links.
+//!
+//! `RedisLinkService` shares links between every instance of a horizontally
+//! scaled deployment, on top of a `redis::aio::ConnectionManager` (which
+//! reconnects on its own and can be cloned freely).
+//!
+//! # Keys
+//!
+//! - `link:{id}` — the link as a JSON string
+//! - `src:{source_id}` — set of the ids of the links leaving an entity
+//! - `tgt:{target_id}` — set of the ids of the links reaching an entity
+//! - `links` — set of every link id, for `list`
+//!
+//! Every write updates the link and its index sets in one `MULTI`
+//! transaction, so readers never see a link missing from its indexes.
+//! Bounded creates run as a Lua script, which Redis executes atomically.
+//!
+//! # Feature flag
+//!
+//! Enable with `--features redis`. Requires the `redis` crate.
+
+use crate::core::link::{LinkEntity, LinkLimit};
+use crate::core::{HealthCheck, LinkService};
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Set of every link id
+const ALL_LINKS_KEY: &str = "links";
+
+fn link_key(id: &Uuid) -> String {
+    format!("link:{}", id)
+}
+
+fn source_key(source_id: &Uuid) -> String {
+    format!("src:{}", source_id)
+}
+
+fn target_key(target_id: &Uuid) -> String {
+    format!("tgt:{}", target_id)
+}
+
+/// Counts the live links of the new link's type in its source and target
+/// sets, and stores it only if both counts are under their caps (`-1` for
+/// no cap). Returns 1 when stored, 0 when refused.
+const CREATE_WITHIN_LIMIT_SCRIPT: &str = r#"
+local function live_of_type(set)
+  local count = 0
+  for _, id in ipairs(redis.call('SMEMBERS', set)) do
+    local raw = redis.call('GET', 'link:' .. id)
+    if raw then
+      local link = cjson.decode(raw)
+      if link.link_type == ARGV[2] and (link.deleted_at == nil or link.deleted_at == cjson.null) then
+        count = count + 1
+      end
+    end
+  end
+  return count
+end
+
+local per_source = tonumber(ARGV[4])
+local per_target = tonumber(ARGV[5])
+if per_source >= 0 and live_of_type(KEYS[2]) >= per_source then
+  return 0
+end
+if per_target >= 0 and live_of_type(KEYS[3]) >= per_target then
+  return 0
+end
+redis.call('SET', KEYS[1], ARGV[1])
+redis.call('SADD', KEYS[2], ARGV[3])
+redis.call('SADD', KEYS[3], ARGV[3])
+redis.call('SADD', KEYS[4], ARGV[3])
+return 1
+"#;
+
+/// Link storage service backed by Redis.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use this::storage::RedisLinkService;
+///
+/// let client = redis::Client::open("redis://127.0.0.1/")?;
+/// let service = RedisLinkService::new(client.get_connection_manager().await?);
+/// ```
+#[derive(Clone)]
+pub struct RedisLinkService {
+    conn: ConnectionManager,
+}
+
+impl RedisLinkService {
+    /// Create a new service on an established connection manager
+    pub fn new(conn: ConnectionManager) -> Self {
+        Self { conn }
+    }
+
+    /// Load the links whose ids are stored in the set at `key`
+    async fn links_in(&self, key: &str) -> Result<Vec<LinkEntity>> {
+        let mut conn = self.conn.clone();
+        let ids: Vec<String> = conn
+            .smembers(key)
+            .await
+            .map_err(|e| anyhow!("Failed to read link index {}: {}", key, e))?;
+        self.load(ids).await
+    }
+
+    /// Load links by id, skipping ids whose link is gone
+    async fn load(&self, ids: Vec<String>) -> Result<Vec<LinkEntity>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let keys: Vec<String> = ids.iter().map(|id| format!("link:{}", id)).collect();
+        let mut conn = self.conn.clone();
+        let values: Vec<Option<String>> = redis::cmd("MGET")
+            .arg(&keys)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to load links: {}", e))?;
+        values
+            .into_iter()
+            .flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| anyhow!("Failed to decode link: {}", e))
+            })
+            .collect()
+    }
+
+    /// Queue the removal of `link` and its index entries on `pipe`
+    fn queue_removal(pipe: &mut redis::Pipeline, link: &LinkEntity) {
+        let id = link.id.to_string();
+        pipe.del(link_key(&link.id))
+            .srem(source_key(&link.source_id), &id)
+            .srem(target_key(&link.target_id), &id)
+            .srem(ALL_LINKS_KEY, &id);
+    }
+}
+
+#[async_trait]
+impl HealthCheck for RedisLinkService {
+    fn name(&self) -> &str {
+        "redis"
+    }
+
+    async fn check(&self) -> Result<()> {
+        let mut conn = self.conn.clone();
+        redis::cmd("PING")
+            .query_async::<()>(&mut conn)
+            .await
+            .map_err(|e| anyhow!("redis is unreachable: {}", e))
+    }
+}
+
+#[async_trait]
+impl LinkService for RedisLinkService {
+    async fn create(&self, link: LinkEntity) -> Result<LinkEntity> {
+        let json = serde_json::to_string(&link)?;
+        let id = link.id.to_string();
+        let mut conn = self.conn.clone();
+        redis::pipe()
+            .atomic()
+            .set(link_key(&link.id), json)
+            .sadd(source_key(&link.source_id), &id)
+            .sadd(target_key(&link.target_id), &id)
+            .sadd(ALL_LINKS_KEY, &id)
+            .query_async::<()>(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to create link: {}", e))?;
+
+        Ok(link)
+    }
+
+    /// Counts and inserts in one Lua script, which Redis runs without
+    /// interleaving other commands, so concurrent bounded creates from any
+    /// number of instances cannot overshoot the cap.
+    async fn create_within_limit(
+        &self,
+        link: LinkEntity,
+        limit: LinkLimit,
+    ) -> Result<Option<LinkEntity>> {
+        let cap = |max: Option<usize>| max.map_or(-1, |max| max as i64);
+        let mut conn = self.conn.clone();
+        let stored: i64 = redis::Script::new(CREATE_WITHIN_LIMIT_SCRIPT)
+            .key(link_key(&link.id))
+            .key(source_key(&link.source_id))
+            .key(target_key(&link.target_id))
+            .key(ALL_LINKS_KEY)
+            .arg(serde_json::to_string(&link)?)
+            .arg(&link.link_type)
+            .arg(link.id.to_string())
+            .arg(cap(limit.per_source))
+            .arg(cap(limit.per_target))
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to create link: {}", e))?;
+
+        Ok((stored == 1).then_some(link))
+    }
+
+    async fn get(&self, id: &Uuid) -> Result<Option<LinkEntity>> {
+        let mut conn = self.conn.clone();
+        let json: Option<String> = conn
+            .get(link_key(id))
+            .await
+            .map_err(|e| anyhow!("Failed to get link: {}", e))?;
+        json.map(|json| {
+            serde_json::from_str(&json).map_err(|e| anyhow!("Failed to decode link: {}", e))
+        })
+        .transpose()
+    }
+
+    async fn list(&self) -> Result<Vec<LinkEntity>> {
+        self.links_in(ALL_LINKS_KEY).await
+    }
+
+    async fn find_by_source(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = self.links_in(&source_key(source_id)).await?;
+        links.retain(|link| {
+            link_type.is_none_or(|lt| link.link_type == lt) && link.matches_target_type(target_type)
+        });
+        Ok(links)
+    }
+
+    async fn find_by_target(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        let mut links = self.links_in(&target_key(target_id)).await?;
+        links.retain(|link| {
+            link_type.is_none_or(|lt| link.link_type == lt) && link.matches_source_type(source_type)
+        });
+        Ok(links)
+    }
+
+    async fn update(&self, id: &Uuid, updated_link: LinkEntity) -> Result<LinkEntity> {
+        let old = self
+            .get(id)
+            .await?
+            .ok_or_else(|| anyhow!("Link not found: {}", id))?;
+
+        // Re-index in case the endpoints changed
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        Self::queue_removal(&mut pipe, &old);
+        let key = id.to_string();
+        pipe.set(link_key(id), serde_json::to_string(&updated_link)?)
+            .sadd(source_key(&updated_link.source_id), &key)
+            .sadd(target_key(&updated_link.target_id), &key)
+            .sadd(ALL_LINKS_KEY, &key);
+        let mut conn = self.conn.clone();
+        pipe.query_async::<()>(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to update link: {}", e))?;
+
+        Ok(updated_link)
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<()> {
+        let Some(link) = self.get(id).await? else {
+            return Ok(());
+        };
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        Self::queue_removal(&mut pipe, &link);
+        let mut conn = self.conn.clone();
+        pipe.query_async::<()>(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to delete link: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Removes the links found through both index sets of the entity, along
+    /// with their entries in the other endpoint's sets.
+    async fn delete_by_entity(&self, entity_id: &Uuid) -> Result<()> {
+        let mut conn = self.conn.clone();
+        let (outgoing, incoming): (Vec<String>, Vec<String>) = redis::pipe()
+            .smembers(source_key(entity_id))
+            .smembers(target_key(entity_id))
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to read link indexes: {}", e))?;
+        // A self-link is in both sets
+        let ids: HashSet<String> = outgoing.into_iter().chain(incoming).collect();
+        let links = self.load(ids.into_iter().collect()).await?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for link in &links {
+            Self::queue_removal(&mut pipe, link);
+        }
+        pipe.del(source_key(entity_id))
+            .del(target_key(entity_id))
+            .query_async::<()>(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to delete links of entity {}: {}", entity_id, e))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_follow_the_documented_layout() {
+        let id = Uuid::nil();
+        assert_eq!(link_key(&id), format!("link:{}", id));
+        assert_eq!(source_key(&id), format!("src:{}", id));
+        assert_eq!(target_key(&id), format!("tgt:{}", id));
+    }
+}
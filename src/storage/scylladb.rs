@@ -22,15 +22,21 @@
 //! Secondary indexes on `source_id` and `target_id` enable efficient
 //! `find_by_source` and `find_by_target` queries.
 
+use crate::core::actor;
+use crate::core::auth::AuthContext;
+use crate::core::entity::{ComputedFields, to_read_json};
 use crate::core::field::FieldValue;
+use crate::core::ids::new_id;
 use crate::core::link::LinkEntity;
 use crate::core::module::{EntityCreator, EntityFetcher};
-use crate::core::{Data, DataService, LinkService};
+use crate::core::soft_delete::SoftDeleteStatus;
+use crate::core::{Cursor, Data, DataService, LinkService};
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use scylla::client::session::Session;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -171,7 +177,9 @@ impl<T: Data + Serialize + DeserializeOwned> ScyllaDataService<T> {
 
 #[async_trait]
 impl<T: Data + Serialize + DeserializeOwned> DataService<T> for ScyllaDataService<T> {
-    async fn create(&self, entity: T) -> Result<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
         let json_str = serde_json::to_string(&entity)
             .map_err(|e| anyhow!("Failed to serialize entity: {}", e))?;
         let json_val: serde_json::Value = serde_json::to_value(&entity)
@@ -225,7 +233,7 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for ScyllaDataServic
         Ok(entity)
     }
 
-    async fn get(&self, id: &Uuid) -> Result<Option<T>> {
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
         let cql = format!(
             "SELECT entity_data FROM {}.entities WHERE entity_type = ? AND id = ?",
             self.keyspace
@@ -257,7 +265,7 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for ScyllaDataServic
         }
     }
 
-    async fn list(&self) -> Result<Vec<T>> {
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
         let cql = format!(
             "SELECT entity_data FROM {}.entities WHERE entity_type = ?",
             self.keyspace
@@ -283,7 +291,9 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for ScyllaDataServic
         for (data,) in &rows {
             let entity: T = serde_json::from_str(data)
                 .map_err(|e| anyhow!("Failed to deserialize entity: {}", e))?;
-            entities.push(entity);
+            if include_deleted || entity.deleted_at().is_none() {
+                entities.push(entity);
+            }
         }
 
         // Sort by created_at DESC (CQL doesn't support ORDER BY on non-clustering columns)
@@ -292,9 +302,11 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for ScyllaDataServic
         Ok(entities)
     }
 
-    async fn update(&self, id: &Uuid, entity: T) -> Result<T> {
+    async fn update(&self, id: &Uuid, mut entity: T) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
         // Verify entity exists first
-        let existing = self.get(id).await?;
+        let existing = self.get_with_deleted(id).await?;
         if existing.is_none() {
             return Err(anyhow!("Entity not found: {}", id));
         }
@@ -386,7 +398,9 @@ impl<T: Data + Serialize + DeserializeOwned> DataService<T> for ScyllaDataServic
             for (data,) in &rows {
                 let entity: T = serde_json::from_str(data)
                     .map_err(|e| anyhow!("Failed to deserialize entity: {}", e))?;
-                entities.push(entity);
+                if entity.deleted_at().is_none() {
+                    entities.push(entity);
+                }
             }
             return Ok(entities);
         }
@@ -462,7 +476,7 @@ fn normalize_json_numbers(value: &mut serde_json::Value) {
 fn prepare_create_json(data: &mut serde_json::Value, entity_type_name: &str) -> Result<()> {
     if let Some(obj) = data.as_object_mut() {
         if !obj.contains_key("id") {
-            obj.insert("id".to_string(), serde_json::to_value(Uuid::new_v4())?);
+            obj.insert("id".to_string(), serde_json::to_value(new_id())?);
         }
         if !obj.contains_key("type") {
             obj.insert(
@@ -520,7 +534,52 @@ impl<T: Data + Serialize + DeserializeOwned> EntityFetcher for ScyllaDataService
         let entity = DataService::get(self, entity_id)
             .await?
             .ok_or_else(|| anyhow!("{} not found: {}", Self::entity_type_name(), entity_id))?;
-        serde_json::to_value(entity).map_err(|e| anyhow!("Failed to serialize entity: {}", e))
+        to_read_json(&entity).map_err(|e| anyhow!("Failed to serialize entity: {}", e))
+    }
+
+    async fn fetch_with_deleted_as_json(&self, entity_id: &Uuid) -> Result<serde_json::Value> {
+        let entity = DataService::get_with_deleted(self, entity_id)
+            .await?
+            .ok_or_else(|| anyhow!("{} not found: {}", Self::entity_type_name(), entity_id))?;
+        to_read_json(&entity).map_err(|e| anyhow!("Failed to serialize entity: {}", e))
+    }
+
+    async fn fetch_many_as_json(&self, ids: &[Uuid]) -> Result<HashMap<Uuid, serde_json::Value>> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let cql = format!(
+            "SELECT entity_data FROM {}.entities WHERE entity_type = ? AND id IN ?",
+            self.keyspace
+        );
+        let ids: Vec<String> = ids.iter().map(Uuid::to_string).collect();
+
+        let result = self
+            .session
+            .query_unpaged(cql, (Self::entity_type_name(), ids))
+            .await
+            .map_err(|e| anyhow!("Failed to get entities: {}", e))?;
+
+        let rows_result = result
+            .into_rows_result()
+            .map_err(|e| anyhow!("Failed to parse result: {}", e))?;
+
+        let rows: Vec<(String,)> = rows_result
+            .rows()
+            .map_err(|e| anyhow!("Failed to deserialize rows: {}", e))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("Failed to collect rows: {}", e))?;
+
+        let mut entities = HashMap::new();
+        for (data,) in rows {
+            let entity: T = serde_json::from_str(&data)
+                .map_err(|e| anyhow!("Failed to deserialize entity: {}", e))?;
+            // Soft-deleted entities are absent, as with `get`
+            if entity.deleted_at().is_none() {
+                entities.insert(entity.id(), to_read_json(&entity)?);
+            }
+        }
+        Ok(entities)
     }
 
     async fn list_as_json(
@@ -535,7 +594,41 @@ impl<T: Data + Serialize + DeserializeOwned> EntityFetcher for ScyllaDataService
         all.into_iter()
             .skip(offset)
             .take(limit)
-            .map(|e| serde_json::to_value(e).map_err(|err| anyhow!("serialize: {}", err)))
+            .map(|e| to_read_json(&e).map_err(|err| anyhow!("serialize: {}", err)))
+            .collect()
+    }
+
+    async fn list_after_as_json(
+        &self,
+        cursor: Option<Cursor>,
+        limit: usize,
+    ) -> Result<Vec<serde_json::Value>> {
+        DataService::list_after(self, cursor, limit)
+            .await?
+            .into_iter()
+            .map(|e| to_read_json(&e).map_err(|err| anyhow!("serialize: {}", err)))
+            .collect()
+    }
+
+    fn searchable_fields(&self) -> &'static [&'static str] {
+        T::indexed_fields()
+    }
+
+    fn redacted_fields(&self, context: &AuthContext) -> &'static [&'static str] {
+        T::redacted_fields(context)
+    }
+
+    fn computed_fields(&self, entity: &serde_json::Value) -> ComputedFields {
+        serde_json::from_value::<T>(entity.clone())
+            .map(|entity| entity.computed_fields())
+            .unwrap_or_default()
+    }
+
+    async fn search_as_json(&self, field: &str, value: &str) -> Result<Vec<serde_json::Value>> {
+        DataService::search(self, field, value)
+            .await?
+            .into_iter()
+            .map(|e| to_read_json(&e).map_err(|err| anyhow!("serialize: {}", err)))
             .collect()
     }
 }
@@ -569,9 +662,47 @@ impl<T: Data + Serialize + DeserializeOwned> EntityCreator for ScyllaDataService
         serde_json::to_value(result).map_err(|e| anyhow!("Failed to serialize: {}", e))
     }
 
+    async fn patch_from_json(
+        &self,
+        entity_id: &Uuid,
+        mut partial: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        normalize_json_numbers(&mut partial);
+        let result = DataService::patch(self, entity_id, partial).await?;
+        serde_json::to_value(result).map_err(|e| anyhow!("Failed to serialize: {}", e))
+    }
+
     async fn delete(&self, entity_id: &Uuid) -> Result<()> {
         DataService::delete(self, entity_id).await
     }
+
+    async fn soft_delete(&self, entity_id: &Uuid) -> Result<serde_json::Value> {
+        let result = DataService::soft_delete(self, entity_id).await?;
+        serde_json::to_value(result).map_err(|e| anyhow!("Failed to serialize: {}", e))
+    }
+
+    async fn restore(&self, entity_id: &Uuid) -> Result<serde_json::Value> {
+        let result = DataService::restore(self, entity_id).await?;
+        serde_json::to_value(result).map_err(|e| anyhow!("Failed to serialize: {}", e))
+    }
+
+    async fn soft_delete_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<serde_json::Value> {
+        let result = DataService::soft_delete_with_status(self, entity_id, status).await?;
+        serde_json::to_value(result).map_err(|e| anyhow!("Failed to serialize: {}", e))
+    }
+
+    async fn restore_with_status(
+        &self,
+        entity_id: &Uuid,
+        status: &SoftDeleteStatus,
+    ) -> Result<serde_json::Value> {
+        let result = DataService::restore_with_status(self, entity_id, status).await?;
+        serde_json::to_value(result).map_err(|e| anyhow!("Failed to serialize: {}", e))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -667,8 +798,8 @@ impl LinkService for ScyllaLinkService {
             self.keyspace
         );
 
-        let source_type = link.entity_type.clone();
-        let target_type = "".to_string(); // LinkEntity doesn't store source/target types separately
+        let source_type = link.source_type.clone().unwrap_or_default();
+        let target_type = link.target_type.clone().unwrap_or_default();
 
         self.session
             .query_unpaged(
@@ -0,0 +1,1561 @@
+//! SQLite storage backend using sqlx.
+//!
+//! Provides `SqliteDataService<T>` and `SqliteLinkService` implementations
+//! backed by an embedded SQLite database via `sqlx::SqlitePool`.
+//!
+//! # Feature flag
+//!
+//! This module is gated behind the `sqlite` feature flag:
+//! ```toml
+//! [dependencies]
+//! this-rs = { version = "0.0.7", features = ["sqlite"] }
+//! ```
+//!
+//! # Schema
+//!
+//! Entities are stored in a shared `entities` table with common columns
+//! (id, entity_type, name, status, timestamps) and a JSON `data` column
+//! for type-specific fields. Links are stored in a `links` table.
+//!
+//! # Differences from MySQL backend
+//!
+//! - UUID and JSON both stored as `TEXT`
+//! - `json_extract(data, '$.field')` instead of `JSON_EXTRACT`/`JSON_UNQUOTE`
+//! - Timestamps stored as fixed-width RFC 3339 text (microsecond precision)
+//!   so that `ORDER BY created_at` sorts chronologically
+
+use crate::core::actor;
+use crate::core::etag::{self, CacheResult, etag_for, etag_matches};
+use crate::core::link::{
+    LinkEntity, LinkFilterCondition, LinkFilterField, LinkLimit, RelationDirection,
+};
+use crate::core::{Cursor, Data, DataService, HealthCheck, LinkService};
+use crate::storage::error::check_precondition;
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use sqlx::SqlitePool;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+// ---------------------------------------------------------------------------
+// Schema management
+// ---------------------------------------------------------------------------
+
+/// Apply the required tables and indexes (idempotent).
+///
+/// This creates:
+/// - `entities` table with common columns + JSON data column
+/// - `links` table with indexed source/target columns
+///
+/// Safe to call on every startup.
+pub async fn ensure_schema(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS entities (
+            id TEXT NOT NULL PRIMARY KEY,
+            entity_type TEXT NOT NULL,
+            name TEXT NOT NULL DEFAULT '',
+            status TEXT NOT NULL DEFAULT '',
+            tenant_id TEXT NULL,
+            data TEXT NOT NULL DEFAULT '{}',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            deleted_at TEXT NULL
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to create entities table: {}", e))?;
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS links (
+            id TEXT NOT NULL PRIMARY KEY,
+            entity_type TEXT NOT NULL DEFAULT '',
+            link_type TEXT NOT NULL,
+            source_id TEXT NOT NULL,
+            target_id TEXT NOT NULL,
+            source_type TEXT NULL,
+            target_type TEXT NULL,
+            status TEXT NOT NULL DEFAULT '',
+            tenant_id TEXT NULL,
+            metadata TEXT NOT NULL DEFAULT '{}',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            deleted_at TEXT NULL
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| anyhow!("Failed to create links table: {}", e))?;
+
+    for statement in [
+        "CREATE INDEX IF NOT EXISTS idx_entities_type ON entities (entity_type)",
+        "CREATE INDEX IF NOT EXISTS idx_entities_name ON entities (name)",
+        "CREATE INDEX IF NOT EXISTS idx_links_source ON links (source_id, link_type)",
+        "CREATE INDEX IF NOT EXISTS idx_links_target ON links (target_id, link_type)",
+    ] {
+        sqlx::query(statement)
+            .execute(pool)
+            .await
+            .map_err(|e| anyhow!("Failed to create index: {}", e))?;
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Common field definitions
+// ---------------------------------------------------------------------------
+
+/// Common entity fields stored in dedicated columns (excluded from JSON data).
+const ENTITY_COMMON_FIELDS: &[&str] = &[
+    "id",
+    "name",
+    "status",
+    "tenant_id",
+    "created_at",
+    "updated_at",
+    "deleted_at",
+];
+
+/// Common entity fields that can be searched via direct SQL column comparison.
+const SEARCHABLE_COLUMNS: &[&str] = &["name", "status"];
+
+/// Encode a timestamp as fixed-width RFC 3339 text.
+///
+/// sqlx's default encoding drops trailing zero fractions, which breaks
+/// lexicographic ordering; a fixed width keeps `ORDER BY` chronological.
+fn encode_timestamp(ts: DateTime<Utc>) -> String {
+    ts.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+type EntityTuple = (
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    Json<serde_json::Value>,
+    DateTime<Utc>,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+);
+
+const ENTITY_SELECT: &str = "SELECT id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at FROM entities";
+
+// ---------------------------------------------------------------------------
+// SqliteDataService<T>
+// ---------------------------------------------------------------------------
+
+/// Generic data storage service backed by SQLite.
+///
+/// Stores entities in a shared `entities` table with common columns
+/// (id, entity_type, name, status, timestamps) and a JSON `data`
+/// column for type-specific fields.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use sqlx::SqlitePool;
+/// use this::storage::SqliteDataService;
+///
+/// let pool = SqlitePool::connect("sqlite://data.db?mode=rwc").await?;
+/// this::storage::sqlite::ensure_schema(&pool).await?;
+/// let service = SqliteDataService::<MyEntity>::new(pool);
+/// let entity = service.create(my_entity).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct SqliteDataService<T> {
+    pool: SqlitePool,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> SqliteDataService<T> {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+}
+
+impl<T: Data + Serialize + DeserializeOwned> SqliteDataService<T> {
+    fn entity_type_name() -> &'static str {
+        T::resource_name_singular()
+    }
+
+    /// Convert a domain entity into column values for INSERT/UPDATE.
+    ///
+    /// Serializes the full entity to JSON, extracts common fields into
+    /// dedicated columns, and stores remaining fields in the JSON `data` column.
+    /// Computed fields are left out.
+    fn extract_data(entity: &T) -> Result<serde_json::Value> {
+        let mut data = serde_json::to_value(entity)
+            .map_err(|e| anyhow!("Failed to serialize entity: {}", e))?;
+
+        // Remove common fields from data (they're stored in dedicated columns)
+        if let Some(obj) = data.as_object_mut() {
+            for field in ENTITY_COMMON_FIELDS {
+                obj.remove(*field);
+            }
+            // Computed fields are derived on reads, never stored
+            for field in entity.computed_fields().keys() {
+                obj.remove(field);
+            }
+        }
+
+        Ok(data)
+    }
+
+    /// Reconstruct a domain entity from a row's columns.
+    ///
+    /// Merges common columns back into the JSON data, then deserializes
+    /// the combined JSON into the target type `T`.
+    #[allow(clippy::too_many_arguments)]
+    fn reconstruct_entity(
+        id: String,
+        entity_type: String,
+        name: String,
+        status: String,
+        tenant_id: Option<String>,
+        data: serde_json::Value,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> Result<T> {
+        let mut json = if data.is_object() {
+            data
+        } else {
+            serde_json::json!({})
+        };
+
+        if let Some(obj) = json.as_object_mut() {
+            obj.insert("id".into(), serde_json::json!(id));
+            if !obj.contains_key("entity_type") {
+                obj.insert("entity_type".into(), serde_json::json!(entity_type));
+            }
+            if !obj.contains_key("type") {
+                obj.insert("type".into(), serde_json::json!(entity_type));
+            }
+            obj.insert("name".into(), serde_json::json!(name));
+            obj.insert("status".into(), serde_json::json!(status));
+            obj.insert("created_at".into(), serde_json::to_value(created_at)?);
+            obj.insert("updated_at".into(), serde_json::to_value(updated_at)?);
+            obj.insert("deleted_at".into(), serde_json::to_value(deleted_at)?);
+            if let Some(tid) = tenant_id {
+                obj.insert("tenant_id".into(), serde_json::json!(tid));
+            }
+        }
+
+        serde_json::from_value::<T>(json)
+            .map_err(|e| anyhow!("Failed to deserialize entity from row: {}", e))
+    }
+
+    fn row_to_entity(row: EntityTuple) -> Result<T> {
+        let (id, etype, name, status, tid, Json(data), cat, uat, dat) = row;
+        Self::reconstruct_entity(id, etype, name, status, tid, data, cat, uat, dat)
+    }
+
+    /// Keyset query behind `list_after` (`newer = false`) and `list_before`.
+    ///
+    /// Returns up to `limit` live entities on the requested side of
+    /// `cursor`, newest first. Timestamps are stored as fixed-width text, so
+    /// the row-value comparison orders them chronologically.
+    async fn list_keyset(
+        &self,
+        cursor: Option<Cursor>,
+        newer: bool,
+        limit: usize,
+    ) -> Result<Vec<T>> {
+        let mut sql = format!(
+            "{} WHERE entity_type = ? AND deleted_at IS NULL",
+            ENTITY_SELECT
+        );
+        if cursor.is_some() {
+            sql.push_str(if newer {
+                " AND (created_at, id) > (?, ?)"
+            } else {
+                " AND (created_at, id) < (?, ?)"
+            });
+        }
+        sql.push_str(if newer {
+            " ORDER BY created_at ASC, id ASC LIMIT ?"
+        } else {
+            " ORDER BY created_at DESC, id DESC LIMIT ?"
+        });
+
+        let mut query = sqlx::query_as::<_, EntityTuple>(&sql).bind(Self::entity_type_name());
+        if let Some(cursor) = cursor {
+            query = query
+                .bind(encode_timestamp(cursor.created_at))
+                .bind(cursor.id.to_string());
+        }
+        let rows = query
+            .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to list entities: {}", e))?;
+
+        let mut entities = rows
+            .into_iter()
+            .map(Self::row_to_entity)
+            .collect::<Result<Vec<T>>>()?;
+        if newer {
+            entities.reverse();
+        }
+        Ok(entities)
+    }
+}
+
+/// Round trip used by the health checks of the sqlite services
+async fn ping(pool: &SqlitePool) -> Result<()> {
+    sqlx::query("SELECT 1")
+        .execute(pool)
+        .await
+        .map_err(|e| anyhow!("sqlite is unreachable: {}", e))?;
+    Ok(())
+}
+
+#[async_trait]
+impl<T: Send + Sync> HealthCheck for SqliteDataService<T> {
+    fn name(&self) -> &str {
+        "sqlite"
+    }
+
+    async fn check(&self) -> Result<()> {
+        ping(&self.pool).await
+    }
+}
+
+#[async_trait]
+impl<T: Data + Serialize + DeserializeOwned> DataService<T> for SqliteDataService<T> {
+    async fn create(&self, mut entity: T) -> Result<T> {
+        actor::stamp_create(&mut entity);
+
+        let data = Self::extract_data(&entity)?;
+
+        sqlx::query(
+            "INSERT INTO entities (id, entity_type, name, status, tenant_id, data, created_at, updated_at, deleted_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(entity.id().to_string())
+        .bind(Self::entity_type_name())
+        .bind(entity.name())
+        .bind(entity.status())
+        .bind(entity.tenant_id().map(|u| u.to_string()))
+        .bind(data.to_string())
+        .bind(encode_timestamp(entity.created_at()))
+        .bind(encode_timestamp(entity.updated_at()))
+        .bind(entity.deleted_at().map(encode_timestamp))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to create entity: {}", e))?;
+
+        // Re-read so the returned entity reflects stored precision
+        self.get_with_deleted(&entity.id())
+            .await?
+            .ok_or_else(|| anyhow!("Failed to read back created entity"))
+    }
+
+    async fn get_with_deleted(&self, id: &Uuid) -> Result<Option<T>> {
+        let sql = format!("{} WHERE id = ? AND entity_type = ?", ENTITY_SELECT);
+        let row = sqlx::query_as::<_, EntityTuple>(&sql)
+            .bind(id.to_string())
+            .bind(Self::entity_type_name())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to get entity: {}", e))?;
+
+        row.map(Self::row_to_entity).transpose()
+    }
+
+    async fn list_filtered(&self, include_deleted: bool) -> Result<Vec<T>> {
+        let sql = format!(
+            "{} WHERE entity_type = ?{} ORDER BY created_at DESC",
+            ENTITY_SELECT,
+            if include_deleted {
+                ""
+            } else {
+                " AND deleted_at IS NULL"
+            }
+        );
+        let rows = sqlx::query_as::<_, EntityTuple>(&sql)
+            .bind(Self::entity_type_name())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to list entities: {}", e))?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+
+    async fn list_after(&self, cursor: Option<Cursor>, limit: usize) -> Result<Vec<T>> {
+        self.list_keyset(cursor, false, limit).await
+    }
+
+    async fn list_before(&self, cursor: Cursor, limit: usize) -> Result<Vec<T>> {
+        self.list_keyset(Some(cursor), true, limit).await
+    }
+
+    async fn count(&self) -> Result<usize> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM entities WHERE entity_type = ? AND deleted_at IS NULL",
+        )
+        .bind(Self::entity_type_name())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to count entities: {}", e))?;
+
+        Ok(count as usize)
+    }
+
+    async fn update(&self, id: &Uuid, mut entity: T) -> Result<T> {
+        actor::stamp_update(&mut entity);
+
+        let data = Self::extract_data(&entity)?;
+        let precondition = etag::precondition();
+
+        let mut sql = String::from(
+            "UPDATE entities \
+             SET name = ?, status = ?, tenant_id = ?, data = ?, updated_at = ?, deleted_at = ? \
+             WHERE id = ? AND entity_type = ?",
+        );
+        if precondition.is_some() {
+            // Same columns as the ETag of `get_if_modified`
+            sql.push_str(
+                " AND updated_at = ? AND COALESCE(json_extract(data, '$.version'), 0) = ?",
+            );
+        }
+        let mut query = sqlx::query(&sql)
+            .bind(entity.name())
+            .bind(entity.status())
+            .bind(entity.tenant_id().map(|u| u.to_string()))
+            .bind(data.to_string())
+            .bind(encode_timestamp(entity.updated_at()))
+            .bind(entity.deleted_at().map(encode_timestamp))
+            .bind(id.to_string())
+            .bind(Self::entity_type_name());
+        if let Some(expected) = precondition {
+            query = query
+                .bind(encode_timestamp(expected.updated_at))
+                .bind(expected.version as i64);
+        }
+        let result = query
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update entity: {}", e))?;
+
+        if result.rows_affected() == 0 {
+            return match self.get_with_deleted(id).await? {
+                Some(stored) => {
+                    check_precondition(id, &stored.updated_at(), stored.version())?;
+                    Err(anyhow!("Failed to update entity: {}", id))
+                }
+                None => Err(anyhow!("Entity not found: {}", id)),
+            };
+        }
+
+        self.get_with_deleted(id)
+            .await?
+            .ok_or_else(|| anyhow!("Failed to read back updated entity"))
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM entities WHERE id = ? AND entity_type = ?")
+            .bind(id.to_string())
+            .bind(Self::entity_type_name())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete entity: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn search(&self, field: &str, value: &str) -> Result<Vec<T>> {
+        let rows = if SEARCHABLE_COLUMNS.contains(&field) {
+            // Direct column search (field name is whitelisted, safe to interpolate)
+            let sql = format!(
+                "{} WHERE entity_type = ? AND deleted_at IS NULL AND {} = ?",
+                ENTITY_SELECT, field
+            );
+            sqlx::query_as::<_, EntityTuple>(&sql)
+                .bind(Self::entity_type_name())
+                .bind(value)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| anyhow!("Failed to search entities: {}", e))?
+        } else {
+            // Search in JSON data column using json_extract. json_extract
+            // returns booleans as 0/1, so they are mapped back to their JSON
+            // text; everything else is compared as text, like MySQL's
+            // JSON_UNQUOTE
+            let sql = format!(
+                "{} WHERE entity_type = ? AND deleted_at IS NULL AND \
+                 CASE json_type(data, ?) \
+                     WHEN 'true' THEN 'true' \
+                     WHEN 'false' THEN 'false' \
+                     ELSE CAST(json_extract(data, ?) AS TEXT) \
+                 END = ?",
+                ENTITY_SELECT
+            );
+            let json_path = format!("$.{}", field);
+            sqlx::query_as::<_, EntityTuple>(&sql)
+                .bind(Self::entity_type_name())
+                .bind(&json_path)
+                .bind(&json_path)
+                .bind(value)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| anyhow!("Failed to search entities by JSON field: {}", e))?
+        };
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+
+    /// Conditional get keyed on the ETag of `id`, `updated_at` and version.
+    ///
+    /// Only those columns are read first; the full row is loaded and
+    /// deserialized only when the ETag no longer matches.
+    /// The version is read from the `data` document, 0 when absent.
+    async fn get_if_modified(&self, id: &Uuid, etag: Option<&str>) -> Result<CacheResult<T>> {
+        if let Some(tag) = etag {
+            let stored: Option<(DateTime<Utc>, i64)> = sqlx::query_as(
+                "SELECT updated_at, COALESCE(json_extract(data, '$.version'), 0) FROM entities \
+                 WHERE id = ? AND entity_type = ? AND deleted_at IS NULL",
+            )
+            .bind(id.to_string())
+            .bind(Self::entity_type_name())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to check entity version: {}", e))?;
+
+            match stored {
+                None => return Ok(CacheResult::NotFound),
+                Some((updated_at, version))
+                    if etag_matches(tag, &etag_for(id, &updated_at, version as u64)) =>
+                {
+                    return Ok(CacheResult::NotModified);
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(match self.get(id).await? {
+            Some(entity) => CacheResult::Modified(entity),
+            None => CacheResult::NotFound,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SqliteLinkService
+// ---------------------------------------------------------------------------
+
+/// Link storage service backed by SQLite.
+///
+/// Stores links in a `links` table with indexed columns for
+/// efficient source/target traversal queries.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use sqlx::SqlitePool;
+/// use this::storage::SqliteLinkService;
+///
+/// let pool = SqlitePool::connect("sqlite://data.db?mode=rwc").await?;
+/// this::storage::sqlite::ensure_schema(&pool).await?;
+/// let service = SqliteLinkService::new(pool);
+/// let link = service.create(my_link).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct SqliteLinkService {
+    pool: SqlitePool,
+}
+
+impl SqliteLinkService {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// Shared traversal query on `source_id` or `target_id`.
+    ///
+    /// `column` and `type_column` are always hardcoded column names, never
+    /// user input. Links whose type column is NULL (created without a known
+    /// type) match any type filter. When `metadata_fields` is set, only those
+    /// metadata keys are selected.
+    async fn find_links(
+        &self,
+        column: &str,
+        type_column: &str,
+        entity_id: &Uuid,
+        link_type: Option<&str>,
+        entity_type: Option<&str>,
+        metadata_fields: Option<&[String]>,
+    ) -> sqlx::Result<Vec<LinkEntity>> {
+        let select = match metadata_fields {
+            Some(fields) => projected_link_select(fields),
+            None => LINK_SELECT.to_string(),
+        };
+        let mut sql = format!("{} WHERE {} = ?", select, column);
+        if link_type.is_some() {
+            sql.push_str(" AND link_type = ?");
+        }
+        if entity_type.is_some() {
+            sql.push_str(&format!(" AND ({0} IS NULL OR {0} = ?)", type_column));
+        }
+        sql.push_str(" ORDER BY created_at DESC");
+
+        let mut query = sqlx::query_as::<_, LinkTuple>(&sql);
+        if let Some(fields) = metadata_fields {
+            query = bind_metadata_fields(query, fields);
+        }
+        query = query.bind(entity_id.to_string());
+        if let Some(lt) = link_type {
+            query = query.bind(lt);
+        }
+        if let Some(et) = entity_type {
+            query = query.bind(et);
+        }
+
+        let rows = query.fetch_all(&self.pool).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Self::tuple_to_link(row).map(|link| match metadata_fields {
+                    Some(_) => strip_missing_metadata(link),
+                    None => link,
+                })
+            })
+            .collect::<Result<_>>()
+            .map_err(|e| sqlx::Error::Decode(e.into()))
+    }
+
+    /// Parse a link row tuple into a LinkEntity.
+    #[allow(clippy::too_many_arguments)]
+    fn row_to_link(
+        id: String,
+        entity_type: String,
+        link_type: String,
+        source_id: String,
+        target_id: String,
+        source_type: Option<String>,
+        target_type: Option<String>,
+        status: String,
+        tenant_id: Option<String>,
+        metadata: serde_json::Value,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> Result<LinkEntity> {
+        Ok(LinkEntity {
+            id: id
+                .parse()
+                .map_err(|e| anyhow!("Invalid UUID for link id: {}", e))?,
+            entity_type,
+            created_at,
+            updated_at,
+            deleted_at,
+            status,
+            tenant_id: tenant_id.and_then(|t| t.parse().ok()),
+            link_type,
+            source_id: source_id
+                .parse()
+                .map_err(|e| anyhow!("Invalid UUID for source_id: {}", e))?,
+            target_id: target_id
+                .parse()
+                .map_err(|e| anyhow!("Invalid UUID for target_id: {}", e))?,
+            source_type,
+            target_type,
+            metadata: if metadata == serde_json::json!({}) {
+                None
+            } else {
+                Some(metadata)
+            },
+        })
+    }
+
+    fn tuple_to_link(row: LinkTuple) -> Result<LinkEntity> {
+        let (id, etype, lt, sid, tid, st, tt, status, tenant, Json(meta), cat, uat, dat) = row;
+        Self::row_to_link(
+            id, etype, lt, sid, tid, st, tt, status, tenant, meta, cat, uat, dat,
+        )
+    }
+}
+
+type LinkTuple = (
+    String,
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    String,
+    Option<String>,
+    Json<serde_json::Value>,
+    DateTime<Utc>,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+);
+
+const LINK_SELECT: &str = "SELECT id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, metadata, created_at, updated_at, deleted_at FROM links";
+
+/// Build a `links` SELECT where `metadata` is replaced by a `json_object` of
+/// the requested keys only.
+///
+/// Each key contributes two `?` placeholders (key name, then JSON path),
+/// bound in order by [`bind_metadata_fields`].
+fn projected_link_select(metadata_fields: &[String]) -> String {
+    let pairs = vec!["?, json_extract(metadata, ?)"; metadata_fields.len()].join(", ");
+    format!(
+        "SELECT id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, \
+         json_object({}) AS metadata, created_at, updated_at, deleted_at FROM links",
+        pairs
+    )
+}
+
+/// Bind the key/path pairs produced by [`projected_link_select`].
+fn bind_metadata_fields<'q>(
+    mut query: sqlx::query::QueryAs<'q, sqlx::Sqlite, LinkTuple, sqlx::sqlite::SqliteArguments<'q>>,
+    metadata_fields: &'q [String],
+) -> sqlx::query::QueryAs<'q, sqlx::Sqlite, LinkTuple, sqlx::sqlite::SqliteArguments<'q>> {
+    for key in metadata_fields {
+        query = query.bind(key.as_str()).bind(format!("$.\"{}\"", key));
+    }
+    query
+}
+
+/// Build the DELETE used by `delete_where`, with `?` placeholders bound in
+/// order: link type, then per condition the metadata JSON path (twice) and each accepted value.
+///
+/// Column names come from [`LinkFilterCondition::COLUMNS`], never from user
+/// input.
+fn delete_where_sql(conditions: &[LinkFilterCondition]) -> String {
+    let mut sql = "DELETE FROM links WHERE link_type = ?".to_string();
+    for condition in conditions {
+        let placeholders = vec!["?"; condition.values.len()].join(", ");
+        match &condition.field {
+            LinkFilterField::Column(column) => {
+                sql.push_str(&format!(" AND {} IN ({})", column, placeholders));
+            }
+            LinkFilterField::Metadata(_) => {
+                sql.push_str(&format!(" AND CASE json_type(metadata, ?) WHEN 'true' THEN 'true' WHEN 'false' THEN 'false' ELSE CAST(json_extract(metadata, ?) AS TEXT) END IN ({})", placeholders));
+            }
+        }
+    }
+    sql
+}
+
+/// Drop keys that `json_object` filled with `null` because the path was absent.
+fn strip_missing_metadata(mut link: LinkEntity) -> LinkEntity {
+    if let Some(serde_json::Value::Object(obj)) = link.metadata.as_mut() {
+        obj.retain(|_, v| !v.is_null());
+        if obj.is_empty() {
+            link.metadata = None;
+        }
+    }
+    link
+}
+
+#[async_trait]
+impl HealthCheck for SqliteLinkService {
+    fn name(&self) -> &str {
+        "sqlite"
+    }
+
+    async fn check(&self) -> Result<()> {
+        ping(&self.pool).await
+    }
+}
+
+#[async_trait]
+impl LinkService for SqliteLinkService {
+    async fn create(&self, link: LinkEntity) -> Result<LinkEntity> {
+        let metadata = link.metadata.clone().unwrap_or(serde_json::json!({}));
+
+        sqlx::query(
+            "INSERT INTO links (id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, metadata, created_at, updated_at, deleted_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(link.id.to_string())
+        .bind(&link.entity_type)
+        .bind(&link.link_type)
+        .bind(link.source_id.to_string())
+        .bind(link.target_id.to_string())
+        .bind(&link.source_type)
+        .bind(&link.target_type)
+        .bind(&link.status)
+        .bind(link.tenant_id.map(|u| u.to_string()))
+        .bind(metadata.to_string())
+        .bind(encode_timestamp(link.created_at))
+        .bind(encode_timestamp(link.updated_at))
+        .bind(link.deleted_at.map(encode_timestamp))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to create link: {}", e))?;
+
+        // Re-read
+        self.get(&link.id)
+            .await?
+            .ok_or_else(|| anyhow!("Failed to read back created link"))
+    }
+
+    /// Insert a link only if the counts allow it, in one `INSERT ... SELECT`.
+    ///
+    /// SQLite runs each write statement under the database write lock, so
+    /// the count and the insert cannot interleave with another writer.
+    async fn create_within_limit(
+        &self,
+        link: LinkEntity,
+        limit: LinkLimit,
+    ) -> Result<Option<LinkEntity>> {
+        let metadata = link.metadata.clone().unwrap_or(serde_json::json!({}));
+
+        let mut sql = String::from(
+            "INSERT INTO links (id, entity_type, link_type, source_id, target_id, source_type, target_type, status, tenant_id, metadata, created_at, updated_at, deleted_at) \
+             SELECT ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ? WHERE 1",
+        );
+        let mut bounds = Vec::new();
+        for (max, column, id) in [
+            (limit.per_source, "source_id", link.source_id),
+            (limit.per_target, "target_id", link.target_id),
+        ] {
+            if let Some(max) = max {
+                sql.push_str(&format!(
+                    " AND (SELECT COUNT(*) FROM links WHERE link_type = ? AND {column} = ? \
+                     AND deleted_at IS NULL) < ?"
+                ));
+                bounds.push((id, max as i64));
+            }
+        }
+
+        let mut query = sqlx::query(&sql)
+            .bind(link.id.to_string())
+            .bind(&link.entity_type)
+            .bind(&link.link_type)
+            .bind(link.source_id.to_string())
+            .bind(link.target_id.to_string())
+            .bind(&link.source_type)
+            .bind(&link.target_type)
+            .bind(&link.status)
+            .bind(link.tenant_id.map(|u| u.to_string()))
+            .bind(metadata.to_string())
+            .bind(encode_timestamp(link.created_at))
+            .bind(encode_timestamp(link.updated_at))
+            .bind(link.deleted_at.map(encode_timestamp));
+        for (id, max) in bounds {
+            query = query.bind(&link.link_type).bind(id.to_string()).bind(max);
+        }
+
+        let result = query
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to create link: {}", e))?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        self.get(&link.id).await
+    }
+
+    async fn get(&self, id: &Uuid) -> Result<Option<LinkEntity>> {
+        let sql = format!("{} WHERE id = ?", LINK_SELECT);
+        let row = sqlx::query_as::<_, LinkTuple>(&sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to get link: {}", e))?;
+
+        row.map(Self::tuple_to_link).transpose()
+    }
+
+    async fn list(&self) -> Result<Vec<LinkEntity>> {
+        let sql = format!("{} ORDER BY created_at DESC", LINK_SELECT);
+        let rows = sqlx::query_as::<_, LinkTuple>(&sql)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to list links: {}", e))?;
+
+        rows.into_iter().map(Self::tuple_to_link).collect()
+    }
+
+    async fn find_by_source(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "source_id",
+            "target_type",
+            source_id,
+            link_type,
+            target_type,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by source: {}", e))
+    }
+
+    async fn find_by_target(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "target_id",
+            "source_type",
+            target_id,
+            link_type,
+            source_type,
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by target: {}", e))
+    }
+
+    async fn find_by_source_projected(
+        &self,
+        source_id: &Uuid,
+        link_type: Option<&str>,
+        target_type: Option<&str>,
+        metadata_fields: &[String],
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "source_id",
+            "target_type",
+            source_id,
+            link_type,
+            target_type,
+            Some(metadata_fields),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by source: {}", e))
+    }
+
+    async fn find_by_target_projected(
+        &self,
+        target_id: &Uuid,
+        link_type: Option<&str>,
+        source_type: Option<&str>,
+        metadata_fields: &[String],
+    ) -> Result<Vec<LinkEntity>> {
+        self.find_links(
+            "target_id",
+            "source_type",
+            target_id,
+            link_type,
+            source_type,
+            Some(metadata_fields),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to find links by target: {}", e))
+    }
+
+    async fn find_relations(
+        &self,
+        entity_id: &Uuid,
+        direction: RelationDirection,
+    ) -> Result<Vec<LinkEntity>> {
+        let predicate = match direction {
+            RelationDirection::Out => "source_id = ?",
+            RelationDirection::In => "target_id = ?",
+            RelationDirection::Both => "(source_id = ? OR target_id = ?)",
+        };
+        let sql = format!(
+            "{} WHERE {} ORDER BY created_at DESC",
+            LINK_SELECT, predicate
+        );
+
+        let mut query = sqlx::query_as::<_, LinkTuple>(&sql).bind(entity_id.to_string());
+        if direction == RelationDirection::Both {
+            query = query.bind(entity_id.to_string());
+        }
+
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to find relations: {}", e))?;
+
+        rows.into_iter().map(Self::tuple_to_link).collect()
+    }
+
+    async fn update(&self, id: &Uuid, link: LinkEntity) -> Result<LinkEntity> {
+        let metadata = link.metadata.clone().unwrap_or(serde_json::json!({}));
+
+        let result = sqlx::query(
+            "UPDATE links \
+             SET link_type = ?, source_id = ?, target_id = ?, status = ?, \
+                 tenant_id = ?, metadata = ?, updated_at = ?, deleted_at = ? \
+             WHERE id = ?",
+        )
+        .bind(&link.link_type)
+        .bind(link.source_id.to_string())
+        .bind(link.target_id.to_string())
+        .bind(&link.status)
+        .bind(link.tenant_id.map(|u| u.to_string()))
+        .bind(metadata.to_string())
+        .bind(encode_timestamp(link.updated_at))
+        .bind(link.deleted_at.map(encode_timestamp))
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to update link: {}", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(anyhow!("Link not found: {}", id));
+        }
+
+        self.get(id)
+            .await?
+            .ok_or_else(|| anyhow!("Failed to read back updated link"))
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM links WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete link: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete_by_entity(&self, entity_id: &Uuid) -> Result<()> {
+        let eid = entity_id.to_string();
+        sqlx::query("DELETE FROM links WHERE source_id = ? OR target_id = ?")
+            .bind(&eid)
+            .bind(&eid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete links by entity: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete_where(
+        &self,
+        link_type: &str,
+        filter: Option<&serde_json::Value>,
+    ) -> Result<u64> {
+        let conditions = match filter {
+            Some(filter) => LinkFilterCondition::parse_all(filter).map_err(|e| anyhow!(e))?,
+            None => Vec::new(),
+        };
+
+        let sql = delete_where_sql(&conditions);
+        let mut query = sqlx::query(&sql).bind(link_type);
+        for condition in &conditions {
+            if let LinkFilterField::Metadata(key) = &condition.field {
+                let path = format!("$.\"{}\"", key);
+                query = query.bind(path.clone()).bind(path);
+            }
+            for value in &condition.values {
+                query = query.bind(value);
+            }
+        }
+
+        let result = query
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete links by filter: {}", e))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "sqlite")]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    // Create a test entity using the macro
+    crate::impl_data_entity!(TestProduct, "test_product", ["name"], {
+        price: f64,
+    });
+
+    // -----------------------------------------------------------------------
+    // extract_data
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn extract_data_strips_common_fields() {
+        let product = TestProduct::new("Widget".to_string(), "active".to_string(), 9.99);
+        let data = SqliteDataService::<TestProduct>::extract_data(&product).unwrap();
+
+        let obj = data.as_object().expect("data should be a JSON object");
+        assert!(!obj.contains_key("id"), "id should be stripped");
+        assert!(!obj.contains_key("name"), "name should be stripped");
+        assert!(!obj.contains_key("status"), "status should be stripped");
+        assert!(
+            !obj.contains_key("tenant_id"),
+            "tenant_id should be stripped"
+        );
+        assert!(
+            !obj.contains_key("created_at"),
+            "created_at should be stripped"
+        );
+        assert!(
+            !obj.contains_key("updated_at"),
+            "updated_at should be stripped"
+        );
+        assert!(
+            !obj.contains_key("deleted_at"),
+            "deleted_at should be stripped"
+        );
+    }
+
+    #[test]
+    fn extract_data_preserves_custom_fields() {
+        let product = TestProduct::new("Widget".to_string(), "active".to_string(), 42.50);
+        let data = SqliteDataService::<TestProduct>::extract_data(&product).unwrap();
+
+        let obj = data.as_object().expect("data should be a JSON object");
+        assert!(
+            obj.contains_key("price"),
+            "custom field 'price' should remain"
+        );
+        assert_eq!(obj["price"].as_f64().unwrap(), 42.50);
+    }
+
+    // -----------------------------------------------------------------------
+    // reconstruct_entity
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn reconstruct_entity_merges_columns() {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now();
+        let data = json!({"price": 19.99});
+
+        let product = SqliteDataService::<TestProduct>::reconstruct_entity(
+            id.clone(),
+            "test_product".to_string(),
+            "Gadget".to_string(),
+            "active".to_string(),
+            None,
+            data,
+            now,
+            now,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(product.id.to_string(), id);
+        assert_eq!(product.name, "Gadget");
+        assert_eq!(product.status, "active");
+        assert_eq!(product.price, 19.99);
+        assert_eq!(product.created_at, now);
+        assert_eq!(product.updated_at, now);
+        assert!(product.deleted_at.is_none());
+    }
+
+    #[test]
+    fn reconstruct_entity_non_object_data_uses_empty() {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now();
+
+        // Passing null data should fall back to an empty object,
+        // and deserialize with the default for 'price' (0.0 for f64).
+        let result = SqliteDataService::<TestProduct>::reconstruct_entity(
+            id,
+            "test_product".to_string(),
+            "NullData".to_string(),
+            "draft".to_string(),
+            None,
+            json!(null),
+            now,
+            now,
+            None,
+        );
+
+        // The entity may or may not deserialize depending on whether 'price'
+        // has a default. With serde, missing f64 fields fail deserialization,
+        // but the important point is that the code does NOT panic—it returns
+        // a Result. If it deserializes, the data object was treated as empty.
+        // If it fails, the error should be about a missing field, not about
+        // "not an object".
+        match result {
+            Ok(product) => {
+                assert_eq!(product.name, "NullData");
+                assert_eq!(product.status, "draft");
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                assert!(
+                    msg.contains("missing field") || msg.contains("deserialize"),
+                    "error should be about missing field, got: {}",
+                    msg
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reconstruct_entity_entity_type_fallback() {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now();
+        // data does NOT contain "entity_type" or "type", so reconstruct
+        // should inject the column value.
+        let data = json!({"price": 5.0});
+
+        let product = SqliteDataService::<TestProduct>::reconstruct_entity(
+            id,
+            "test_product".to_string(),
+            "Fallback".to_string(),
+            "active".to_string(),
+            None,
+            data,
+            now,
+            now,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(product.entity_type, "test_product");
+    }
+
+    #[test]
+    fn reconstruct_entity_with_tenant_id() {
+        let id = Uuid::new_v4().to_string();
+        let tenant_uuid = Uuid::new_v4();
+        let now = Utc::now();
+        let data = json!({"price": 1.0});
+
+        let product = SqliteDataService::<TestProduct>::reconstruct_entity(
+            id,
+            "test_product".to_string(),
+            "Tenant".to_string(),
+            "active".to_string(),
+            Some(tenant_uuid.to_string()),
+            data,
+            now,
+            now,
+            None,
+        )
+        .unwrap();
+
+        // The entity should deserialize. The tenant_id column value
+        // gets inserted into the JSON; it is available for types that
+        // have a tenant_id field. TestProduct does not, but it should
+        // still deserialize successfully (serde ignores unknown fields
+        // by default when deny_unknown_fields is not set).
+        assert_eq!(product.name, "Tenant");
+    }
+
+    #[test]
+    fn reconstruct_entity_without_tenant_id() {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now();
+        let data = json!({"price": 2.0});
+
+        let product = SqliteDataService::<TestProduct>::reconstruct_entity(
+            id,
+            "test_product".to_string(),
+            "NoTenant".to_string(),
+            "active".to_string(),
+            None,
+            data,
+            now,
+            now,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(product.name, "NoTenant");
+        assert_eq!(product.price, 2.0);
+    }
+
+    // -----------------------------------------------------------------------
+    // row_to_link
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn row_to_link_valid_uuids() {
+        let id = Uuid::new_v4();
+        let source = Uuid::new_v4();
+        let target = Uuid::new_v4();
+        let now = Utc::now();
+
+        let link = SqliteLinkService::row_to_link(
+            id.to_string(),
+            "link".to_string(),
+            "owner".to_string(),
+            source.to_string(),
+            target.to_string(),
+            None,
+            None,
+            "active".to_string(),
+            None,
+            json!({}),
+            now,
+            now,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(link.id, id);
+        assert_eq!(link.source_id, source);
+        assert_eq!(link.target_id, target);
+        assert_eq!(link.link_type, "owner");
+        assert_eq!(link.entity_type, "link");
+        assert_eq!(link.status, "active");
+        assert!(link.tenant_id.is_none());
+        assert!(link.deleted_at.is_none());
+    }
+
+    #[test]
+    fn row_to_link_invalid_source_uuid() {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let result = SqliteLinkService::row_to_link(
+            id.to_string(),
+            "link".to_string(),
+            "owner".to_string(),
+            "not-a-uuid".to_string(),
+            Uuid::new_v4().to_string(),
+            None,
+            None,
+            "active".to_string(),
+            None,
+            json!({}),
+            now,
+            now,
+            None,
+        );
+
+        assert!(result.is_err());
+        let msg = result.unwrap_err().to_string();
+        assert!(
+            msg.contains("source_id"),
+            "error should mention source_id, got: {}",
+            msg
+        );
+    }
+
+    #[test]
+    fn row_to_link_invalid_link_uuid() {
+        let now = Utc::now();
+
+        let result = SqliteLinkService::row_to_link(
+            "not-a-uuid".to_string(),
+            "link".to_string(),
+            "owner".to_string(),
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4().to_string(),
+            None,
+            None,
+            "active".to_string(),
+            None,
+            json!({}),
+            now,
+            now,
+            None,
+        );
+
+        assert!(result.is_err());
+        let msg = result.unwrap_err().to_string();
+        assert!(
+            msg.contains("link id"),
+            "error should mention link id, got: {}",
+            msg
+        );
+    }
+
+    #[test]
+    fn row_to_link_empty_metadata_becomes_none() {
+        let now = Utc::now();
+
+        let link = SqliteLinkService::row_to_link(
+            Uuid::new_v4().to_string(),
+            "link".to_string(),
+            "owner".to_string(),
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4().to_string(),
+            None,
+            None,
+            "active".to_string(),
+            None,
+            json!({}),
+            now,
+            now,
+            None,
+        )
+        .unwrap();
+
+        assert!(
+            link.metadata.is_none(),
+            "empty object metadata should become None"
+        );
+    }
+
+    #[test]
+    fn row_to_link_with_metadata() {
+        let now = Utc::now();
+        let meta = json!({"k": "v"});
+
+        let link = SqliteLinkService::row_to_link(
+            Uuid::new_v4().to_string(),
+            "link".to_string(),
+            "owner".to_string(),
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4().to_string(),
+            None,
+            None,
+            "active".to_string(),
+            None,
+            meta.clone(),
+            now,
+            now,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            link.metadata,
+            Some(meta),
+            "non-empty metadata should be preserved as Some"
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // metadata projection
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn projected_link_select_has_placeholder_pair_per_key() {
+        let fields = vec!["priority".to_string(), "role".to_string()];
+        let sql = projected_link_select(&fields);
+        assert!(sql.contains(
+            "json_object(?, json_extract(metadata, ?), ?, json_extract(metadata, ?)) AS metadata"
+        ));
+        assert!(sql.ends_with("FROM links"));
+    }
+
+    #[test]
+    fn strip_missing_metadata_drops_null_keys() {
+        let mut link = LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None);
+        link.metadata = Some(json!({"priority": 3, "role": null}));
+        let link = strip_missing_metadata(link);
+        assert_eq!(link.metadata, Some(json!({"priority": 3})));
+
+        let mut empty = LinkEntity::new("owner", Uuid::new_v4(), Uuid::new_v4(), None);
+        empty.metadata = Some(json!({"role": null}));
+        assert!(strip_missing_metadata(empty).metadata.is_none());
+    }
+
+    // -----------------------------------------------------------------------
+    // conformance
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn sqlite_services_pass_conformance_suites() {
+        use crate::testing::conformance::*;
+
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        ensure_schema(&pool).await.unwrap();
+
+        run_data_service_conformance(&SqliteDataService::<ConformanceEntity>::new(pool.clone()))
+            .await
+            .unwrap();
+        run_link_service_conformance(&SqliteLinkService::new(pool))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn update_under_a_stale_precondition_is_refused() {
+        use crate::core::etag::{Precondition, if_match};
+        use crate::storage::StorageError;
+        use crate::testing::conformance::ConformanceEntity;
+
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        ensure_schema(&pool).await.unwrap();
+        let service = SqliteDataService::<ConformanceEntity>::new(pool);
+
+        let entity = ConformanceEntity::new("a".into(), "active".into(), String::new(), 1);
+        let id = entity.id;
+        let stored = service.create(entity).await.unwrap();
+        let checked = Precondition {
+            updated_at: stored.updated_at,
+            version: 0,
+        };
+
+        // A rival write lands after the check
+        let mut rival = stored.clone();
+        rival.updated_at += chrono::Duration::seconds(1);
+        service.update(&id, rival).await.unwrap();
+
+        let (result, refused) = if_match(checked, service.update(&id, stored.clone())).await;
+        assert!(refused);
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<StorageError>(),
+            Some(StorageError::PreconditionFailed { .. })
+        ));
+
+        let current = service.get(&id).await.unwrap().unwrap();
+        let checked = Precondition {
+            updated_at: current.updated_at,
+            version: 0,
+        };
+        let (result, refused) = if_match(checked, service.update(&id, stored)).await;
+        assert!(result.is_ok() && !refused);
+    }
+
+    #[tokio::test]
+    async fn delete_where_removes_only_matching_links() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        ensure_schema(&pool).await.unwrap();
+        let service = SqliteLinkService::new(pool);
+
+        let car = Uuid::new_v4();
+        for (link_type, expired) in [("driver", true), ("driver", false), ("owner", true)] {
+            let link = LinkEntity::new(
+                link_type,
+                Uuid::new_v4(),
+                car,
+                Some(json!({"expired": expired})),
+            );
+            service.create(link).await.unwrap();
+        }
+
+        let filter = json!({"metadata.expired": true});
+        assert_eq!(
+            service.delete_where("driver", Some(&filter)).await.unwrap(),
+            1
+        );
+        assert_eq!(service.list().await.unwrap().len(), 2);
+        assert_eq!(service.delete_where("driver", None).await.unwrap(), 1);
+        assert!(
+            service
+                .delete_where("owner", Some(&json!({"unknown": 1})))
+                .await
+                .is_err()
+        );
+    }
+}
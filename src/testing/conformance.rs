@@ -0,0 +1,430 @@
+//! Conformance suites for `DataService` and `LinkService` implementations
+//!
+//! Authors of custom storage backends can run these against their own
+//! implementation to check it honors the same contract as the built-in
+//! backends. Each suite only inspects records it creates itself (every name
+//! and ID is unique), so it can run against a shared or non-empty store.
+//!
+//! Contract violations are reported as `Err` with a description of the
+//! failed expectation; backend errors are propagated as-is.
+//!
+//! Pagination and sorting of list endpoints happen above the service traits
+//! (see [`QueryParams`](crate::core::query::QueryParams)), so the only
+//! orderings checked here are the keyset order of
+//! [`DataService::list_after`] and the newest-first contract of
+//! [`LinkService::find_relations`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use this::testing::conformance::*;
+//!
+//! #[tokio::test]
+//! async fn my_backend_conforms() -> anyhow::Result<()> {
+//!     run_data_service_conformance(&MyDataService::<ConformanceEntity>::new()).await?;
+//!     run_link_service_conformance(&MyLinkService::new()).await
+//! }
+//! ```
+
+use anyhow::{Context, Result, ensure};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::core::entity::Entity;
+use crate::core::etag::{CacheResult, etag_of};
+use crate::core::link::{LinkEntity, RelationDirection};
+use crate::core::query::Cursor;
+use crate::core::service::{DataService, LinkService};
+
+crate::impl_data_entity!(ConformanceEntity, "conformance_entity", ["name", "status"], {
+    email: String,
+    score: i64,
+});
+
+fn unique(prefix: &str) -> String {
+    format!("{}-{}", prefix, Uuid::new_v4())
+}
+
+/// Run the `DataService` contract checks against `service`
+///
+/// Covers create/get/list/update/delete, `search` on indexed fields,
+/// soft-delete round-trips (hidden from `get`/`list`/`search`, visible
+/// through the `*_with_deleted` variants), `get_if_modified` and the keyset
+/// order of `list_after` / `list_before`.
+pub async fn run_data_service_conformance<S>(service: &S) -> Result<()>
+where
+    S: DataService<ConformanceEntity> + ?Sized,
+{
+    // create + get
+    let entity = ConformanceEntity::new(
+        unique("alice"),
+        "active".to_string(),
+        "alice@example.com".to_string(),
+        42,
+    );
+    let id = entity.id;
+    let created = service.create(entity.clone()).await.context("create")?;
+    ensure!(created.id == id, "create must keep the provided id");
+    ensure!(created.name == entity.name, "create must keep the name");
+
+    let fetched = service
+        .get(&id)
+        .await
+        .context("get")?
+        .context("get must return a created entity")?;
+    ensure!(
+        fetched.name == entity.name && fetched.email == entity.email && fetched.score == 42,
+        "get must return the stored fields, got {:?}",
+        fetched
+    );
+    ensure!(
+        service.get(&Uuid::new_v4()).await?.is_none(),
+        "get of an unknown id must return None"
+    );
+
+    // list
+    let listed = service.list().await.context("list")?;
+    ensure!(
+        listed.iter().any(|e| e.id == id),
+        "list must include created entities"
+    );
+    ensure!(
+        service.count().await.context("count")? == listed.len(),
+        "count must equal list().len()"
+    );
+
+    // update
+    let mut changed = fetched.clone();
+    changed.email = "alice@changed.example.com".to_string();
+    changed.score = 43;
+    changed.touch();
+    let updated = service.update(&id, changed).await.context("update")?;
+    ensure!(updated.score == 43, "update must return the new fields");
+    let fetched = service.get(&id).await?.context("entity vanished")?;
+    ensure!(
+        fetched.email == "alice@changed.example.com" && fetched.score == 43,
+        "update must persist the new fields"
+    );
+    let ghost = ConformanceEntity::new(unique("ghost"), "active".to_string(), String::new(), 0);
+    ensure!(
+        service.update(&ghost.id, ghost.clone()).await.is_err(),
+        "update of an unknown id must fail"
+    );
+
+    // search
+    let found = service
+        .search("name", &fetched.name)
+        .await
+        .context("search")?;
+    ensure!(
+        found.len() == 1 && found[0].id == id,
+        "search on an indexed field must return exact matches, got {} results",
+        found.len()
+    );
+    ensure!(
+        service.search("name", &unique("nobody")).await?.is_empty(),
+        "search without matches must return an empty list"
+    );
+    ensure!(
+        service
+            .search("no_such_field", "anything")
+            .await?
+            .is_empty(),
+        "search on an unknown field must return an empty list"
+    );
+
+    // soft-delete is a regular update that must round-trip deleted_at
+    let mut deleted = fetched.clone();
+    deleted.soft_delete();
+    service.update(&id, deleted).await.context("soft delete")?;
+    ensure!(
+        service.get(&id).await?.is_none(),
+        "get must hide soft-deleted entities"
+    );
+    ensure!(
+        !service.list().await?.iter().any(|e| e.id == id),
+        "list must hide soft-deleted entities"
+    );
+    ensure!(
+        service
+            .list_with_deleted()
+            .await?
+            .iter()
+            .any(|e| e.id == id),
+        "list_with_deleted must include soft-deleted entities"
+    );
+    ensure!(
+        service.count().await? == service.list().await?.len(),
+        "count must skip soft-deleted entities"
+    );
+    ensure!(
+        service.search("name", &fetched.name).await?.is_empty(),
+        "search must hide soft-deleted entities"
+    );
+    let fetched = service
+        .get_with_deleted(&id)
+        .await?
+        .context("soft-deleted entity vanished")?;
+    ensure!(
+        fetched.deleted_at().is_some(),
+        "soft-deleted entities must stay retrievable with deleted_at set"
+    );
+    let mut restored = fetched.clone();
+    restored.restore();
+    service.update(&id, restored).await.context("restore")?;
+    let fetched = service
+        .get(&id)
+        .await?
+        .context("restored entity vanished")?;
+    ensure!(
+        fetched.deleted_at().is_none(),
+        "restore must clear deleted_at"
+    );
+
+    // get_if_modified
+    let etag = etag_of(&fetched);
+    ensure!(
+        matches!(
+            service.get_if_modified(&id, Some(&etag)).await?,
+            CacheResult::NotModified
+        ),
+        "get_if_modified with the current etag must return NotModified"
+    );
+    ensure!(
+        matches!(
+            service.get_if_modified(&id, Some("\"stale\"")).await?,
+            CacheResult::Modified(e) if e.id == id
+        ),
+        "get_if_modified with a stale etag must return the entity"
+    );
+    ensure!(
+        matches!(
+            service.get_if_modified(&Uuid::new_v4(), None).await?,
+            CacheResult::NotFound
+        ),
+        "get_if_modified of an unknown id must return NotFound"
+    );
+
+    // list_after / list_before walk (created_at, id) newest first; the
+    // entities are backdated so that no other record sits between them
+    let base = Utc::now() - Duration::days(365 * 30);
+    let mut keyset = Vec::new();
+    for offset in 0..3 {
+        let mut entity = ConformanceEntity::new(
+            unique("keyset"),
+            "active".to_string(),
+            String::new(),
+            offset,
+        );
+        entity.created_at = base + Duration::seconds(offset);
+        keyset.push(service.create(entity).await.context("create")?);
+    }
+    let [oldest, middle, newest] = [&keyset[0], &keyset[1], &keyset[2]];
+    let ids = |entities: Vec<ConformanceEntity>| entities.iter().map(|e| e.id).collect::<Vec<_>>();
+    ensure!(
+        ids(service
+            .list_after(Some(Cursor::of(newest)), 2)
+            .await
+            .context("list_after")?)
+            == [middle.id, oldest.id],
+        "list_after must return the older entities, newest first"
+    );
+    ensure!(
+        ids(service
+            .list_before(Cursor::of(oldest), 2)
+            .await
+            .context("list_before")?)
+            == [newest.id, middle.id],
+        "list_before must return the closest newer entities, newest first"
+    );
+    for entity in &keyset {
+        service.delete(&entity.id).await.context("delete")?;
+    }
+
+    // delete
+    service.delete(&id).await.context("delete")?;
+    ensure!(
+        service.get(&id).await?.is_none(),
+        "get after delete must return None"
+    );
+
+    Ok(())
+}
+
+/// Run the `LinkService` contract checks against `service`
+///
+/// Covers create/get/list/update/delete, `find_by_source` / `find_by_target`
+/// with link and entity type filters, metadata projection, soft-delete
+/// round-trips, `find_relations` ordering and `delete_by_entity`.
+pub async fn run_link_service_conformance<S>(service: &S) -> Result<()>
+where
+    S: LinkService + ?Sized,
+{
+    let user = Uuid::new_v4();
+    let car = Uuid::new_v4();
+    let order = Uuid::new_v4();
+    let bystander = Uuid::new_v4();
+    let now = Utc::now();
+
+    // create + get, with explicit creation times to check ordering later
+    let mut owner = LinkEntity::new(
+        "owner",
+        user,
+        car,
+        Some(serde_json::json!({"role": "primary", "since": 2020})),
+    )
+    .with_entity_types("user", "car");
+    owner.created_at = now - Duration::seconds(20);
+    let mut buyer = LinkEntity::new("buyer", user, order, None).with_entity_types("user", "order");
+    buyer.created_at = now - Duration::seconds(10);
+    let mut driver =
+        LinkEntity::new("driver", bystander, car, None).with_entity_types("user", "car");
+    driver.created_at = now;
+
+    for link in [&owner, &buyer, &driver] {
+        let created = service.create(link.clone()).await.context("create")?;
+        ensure!(created.id == link.id, "create must keep the provided id");
+    }
+
+    let fetched = service
+        .get(&owner.id)
+        .await
+        .context("get")?
+        .context("get must return a created link")?;
+    ensure!(
+        fetched.source_id == user
+            && fetched.target_id == car
+            && fetched.link_type == "owner"
+            && fetched.metadata == owner.metadata,
+        "get must return the stored fields, got {:?}",
+        fetched
+    );
+    ensure!(
+        service.get(&Uuid::new_v4()).await?.is_none(),
+        "get of an unknown id must return None"
+    );
+    ensure!(
+        service.list().await?.iter().any(|l| l.id == owner.id),
+        "list must include created links"
+    );
+
+    // find_by_source / find_by_target
+    let from_user = service.find_by_source(&user, None, None).await?;
+    ensure!(
+        from_user.len() == 2 && from_user.iter().all(|l| l.source_id == user),
+        "find_by_source must return every link from the source"
+    );
+    let owned = service.find_by_source(&user, Some("owner"), None).await?;
+    ensure!(
+        owned.len() == 1 && owned[0].id == owner.id,
+        "find_by_source must honor the link_type filter"
+    );
+    let orders = service.find_by_source(&user, None, Some("order")).await?;
+    ensure!(
+        orders.len() == 1 && orders[0].id == buyer.id,
+        "find_by_source must honor the target_type filter"
+    );
+    let to_car = service.find_by_target(&car, None, None).await?;
+    ensure!(
+        to_car.len() == 2 && to_car.iter().all(|l| l.target_id == car),
+        "find_by_target must return every link to the target"
+    );
+    let drivers = service
+        .find_by_target(&car, Some("driver"), Some("user"))
+        .await?;
+    ensure!(
+        drivers.len() == 1 && drivers[0].id == driver.id,
+        "find_by_target must honor link_type and source_type filters"
+    );
+
+    // metadata projection
+    let projected = service
+        .find_by_source_projected(&user, Some("owner"), None, &["role".to_string()])
+        .await?;
+    ensure!(
+        projected.len() == 1
+            && projected[0].metadata == Some(serde_json::json!({"role": "primary"})),
+        "projection must keep only the requested metadata keys"
+    );
+
+    // find_relations ordering
+    let relations = service
+        .find_relations(&car, RelationDirection::Both)
+        .await?;
+    let ids: Vec<Uuid> = relations.iter().map(|l| l.id).collect();
+    ensure!(
+        ids == vec![driver.id, owner.id],
+        "find_relations must return links touching the entity newest first"
+    );
+
+    // update
+    let mut changed = fetched.clone();
+    changed.metadata = Some(serde_json::json!({"role": "secondary"}));
+    changed.touch();
+    service.update(&owner.id, changed).await.context("update")?;
+    let fetched = service.get(&owner.id).await?.context("link vanished")?;
+    ensure!(
+        fetched.metadata == Some(serde_json::json!({"role": "secondary"})),
+        "update must persist new metadata"
+    );
+
+    // soft-delete round-trip
+    let mut deleted = fetched.clone();
+    deleted.soft_delete();
+    service
+        .update(&owner.id, deleted)
+        .await
+        .context("soft delete")?;
+    let fetched = service
+        .get(&owner.id)
+        .await?
+        .context("soft-deleted link vanished")?;
+    ensure!(
+        fetched.is_deleted(),
+        "soft-deleted links must stay retrievable with deleted_at set"
+    );
+
+    // delete + delete_by_entity
+    service.delete(&buyer.id).await.context("delete")?;
+    ensure!(
+        service.get(&buyer.id).await?.is_none(),
+        "get after delete must return None"
+    );
+    service
+        .delete_by_entity(&car)
+        .await
+        .context("delete_by_entity")?;
+    for link in [&owner, &driver] {
+        ensure!(
+            service.get(&link.id).await?.is_none(),
+            "delete_by_entity must remove links on both sides of the entity"
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{InMemoryDataService, InMemoryLinkService};
+
+    #[tokio::test]
+    async fn test_in_memory_data_service_conforms() {
+        let service = InMemoryDataService::<ConformanceEntity>::new();
+        run_data_service_conformance(&service).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_link_service_conforms() {
+        let service = InMemoryLinkService::new();
+        run_link_service_conformance(&service).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_suites_tolerate_existing_records() {
+        let service = InMemoryLinkService::new();
+        run_link_service_conformance(&service).await.unwrap();
+        run_link_service_conformance(&service).await.unwrap();
+    }
+}
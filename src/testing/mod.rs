@@ -0,0 +1,14 @@
+//! Test utilities for applications and custom storage backends
+//!
+//! Enabled with the `test-utils` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! this-rs = { version = "*", features = ["test-utils"] }
+//! ```
+
+pub mod conformance;
+
+pub use conformance::{
+    ConformanceEntity, run_data_service_conformance, run_link_service_conformance,
+};
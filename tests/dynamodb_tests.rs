@@ -0,0 +1,326 @@
+//! Integration tests for the DynamoDB storage backend against DynamoDB Local.
+//!
+//! # Requirements
+//!
+//! - Docker must be running (testcontainers launches DynamoDB Local)
+//! - Feature flag `dynamodb` must be enabled
+//!
+//! # Running
+//!
+//! ```sh
+//! cargo test --features dynamodb --test dynamodb_tests
+//! ```
+
+#![cfg(feature = "dynamodb")]
+
+use aws_sdk_dynamodb::Client;
+use aws_sdk_dynamodb::config::interceptors::BeforeTransmitInterceptorContextRef;
+use aws_sdk_dynamodb::config::{
+    BehaviorVersion, ConfigBag, Credentials, Intercept, Region, RuntimeComponents,
+};
+use aws_sdk_dynamodb::error::BoxError;
+use aws_sdk_dynamodb::types::{
+    AttributeDefinition, BillingMode, GlobalSecondaryIndex, KeySchemaElement, KeyType, Projection,
+    ProjectionType, ScalarAttributeType,
+};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use testcontainers::ContainerAsync;
+use testcontainers::core::IntoContainerPort;
+use testcontainers::runners::AsyncRunner;
+use testcontainers_modules::dynamodb_local::DynamoDb;
+use this::core::link::LinkEntity;
+use this::core::{DataService, LinkService};
+use this::storage::dynamodb::LINK_TARGET_INDEX;
+use this::storage::{DynamoDBDataService, DynamoDBLinkService};
+
+this::impl_data_entity!(Widget, "widget", ["name"], {
+    quantity: f64,
+});
+
+/// Records the DynamoDB operation (`Query`, `Scan`, ...) of every request
+#[derive(Debug, Default, Clone)]
+struct OperationLog(Arc<Mutex<Vec<String>>>);
+
+impl OperationLog {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+impl Intercept for OperationLog {
+    fn name(&self) -> &'static str {
+        "OperationLog"
+    }
+
+    fn read_before_transmit(
+        &self,
+        context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        // e.g. `DynamoDB_20120810.Query`
+        if let Some(target) = context.request().headers().get("x-amz-target") {
+            let operation = target.rsplit('.').next().unwrap_or(target);
+            self.0.lock().unwrap().push(operation.to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Start DynamoDB Local, returning a client recording its operations in `log`
+async fn dynamodb_local(log: OperationLog) -> (ContainerAsync<DynamoDb>, Client) {
+    let container = DynamoDb::default()
+        .start()
+        .await
+        .expect("Failed to start DynamoDB Local — is Docker running?");
+    let host = container.get_host().await.unwrap();
+    let port = container.get_host_port_ipv4(8000.tcp()).await.unwrap();
+
+    let config = aws_sdk_dynamodb::Config::builder()
+        .behavior_version(BehaviorVersion::latest())
+        .region(Region::new("us-east-1"))
+        .credentials_provider(Credentials::new("test", "test", None, None, "test"))
+        .endpoint_url(format!("http://{}:{}", host, port))
+        .interceptor(log)
+        .build();
+    (container, Client::from_conf(config))
+}
+
+/// Start DynamoDB Local with an empty `table` keyed on `id`
+async fn dynamodb_table(table: &str) -> (ContainerAsync<DynamoDb>, Client) {
+    let (container, client) = dynamodb_local(OperationLog::default()).await;
+
+    client
+        .create_table()
+        .table_name(table)
+        .attribute_definitions(
+            AttributeDefinition::builder()
+                .attribute_name("id")
+                .attribute_type(ScalarAttributeType::S)
+                .build()
+                .unwrap(),
+        )
+        .key_schema(
+            KeySchemaElement::builder()
+                .attribute_name("id")
+                .key_type(KeyType::Hash)
+                .build()
+                .unwrap(),
+        )
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .expect("Failed to create table");
+
+    (container, client)
+}
+
+#[tokio::test]
+async fn test_dynamodb_list_page_follows_the_start_key() {
+    let (_container, client) = dynamodb_table("widgets").await;
+    let service = DynamoDBDataService::<Widget>::new(client, "widgets".to_string());
+
+    let mut created = HashSet::new();
+    for i in 0..30 {
+        let widget = Widget::new(format!("widget-{}", i), "active".to_string(), i as f64);
+        created.insert(service.create(widget).await.unwrap().id);
+    }
+
+    let mut seen = HashSet::new();
+    let mut token = None;
+    for call in 0..3 {
+        let (page, next) = service.list_page(10, token).await.unwrap();
+        assert_eq!(page.len(), 10, "page {} should be full", call);
+        seen.extend(page.iter().map(|w| w.id));
+        assert_eq!(next.is_some(), call < 2, "only the last page has no token");
+        token = next;
+    }
+    assert_eq!(seen, created);
+
+    assert!(
+        service
+            .list_page(10, Some("not a token".to_string()))
+            .await
+            .is_err()
+    );
+}
+
+/// Start DynamoDB Local with an empty single-table links `table`
+async fn single_table_links(
+    table: &str,
+) -> (ContainerAsync<DynamoDb>, DynamoDBLinkService, OperationLog) {
+    let log = OperationLog::default();
+    let (container, client) = dynamodb_local(log.clone()).await;
+
+    let attribute = |name: &str| {
+        AttributeDefinition::builder()
+            .attribute_name(name)
+            .attribute_type(ScalarAttributeType::S)
+            .build()
+            .unwrap()
+    };
+    let key = |name: &str, key_type: KeyType| {
+        KeySchemaElement::builder()
+            .attribute_name(name)
+            .key_type(key_type)
+            .build()
+            .unwrap()
+    };
+    client
+        .create_table()
+        .table_name(table)
+        .attribute_definitions(attribute("PK"))
+        .attribute_definitions(attribute("SK"))
+        .attribute_definitions(attribute("GSI1PK"))
+        .attribute_definitions(attribute("GSI1SK"))
+        .key_schema(key("PK", KeyType::Hash))
+        .key_schema(key("SK", KeyType::Range))
+        .global_secondary_indexes(
+            GlobalSecondaryIndex::builder()
+                .index_name(LINK_TARGET_INDEX)
+                .key_schema(key("GSI1PK", KeyType::Hash))
+                .key_schema(key("GSI1SK", KeyType::Range))
+                .projection(
+                    Projection::builder()
+                        .projection_type(ProjectionType::All)
+                        .build(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .expect("Failed to create table");
+
+    let service = DynamoDBLinkService::with_single_table(client, table.to_string());
+    log.take();
+    (container, service, log)
+}
+
+#[tokio::test]
+async fn test_dynamodb_single_table_finds_links_with_queries() {
+    let (_container, service, log) = single_table_links("links").await;
+    let (alice, bob) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+    let (car, bike) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+
+    let owns_car = service
+        .create(LinkEntity::new("owns", alice, car, None))
+        .await
+        .unwrap();
+    let drives_car = service
+        .create(LinkEntity::new("drives", alice, car, None))
+        .await
+        .unwrap();
+    let owns_bike = service
+        .create(LinkEntity::new("owns", alice, bike, None))
+        .await
+        .unwrap();
+    let bob_drives = service
+        .create(LinkEntity::new("drives", bob, car, None))
+        .await
+        .unwrap();
+    log.take();
+
+    let ids = |links: Vec<LinkEntity>| links.iter().map(|l| l.id).collect::<HashSet<_>>();
+
+    let from_alice = service.find_by_source(&alice, None, None).await.unwrap();
+    assert_eq!(
+        ids(from_alice),
+        HashSet::from([owns_car.id, drives_car.id, owns_bike.id])
+    );
+    let owned = service
+        .find_by_source(&alice, Some("owns"), None)
+        .await
+        .unwrap();
+    assert_eq!(ids(owned), HashSet::from([owns_car.id, owns_bike.id]));
+
+    let to_car = service.find_by_target(&car, None, None).await.unwrap();
+    assert_eq!(
+        ids(to_car),
+        HashSet::from([owns_car.id, drives_car.id, bob_drives.id])
+    );
+    let driven = service
+        .find_by_target(&car, Some("drives"), None)
+        .await
+        .unwrap();
+    assert_eq!(ids(driven), HashSet::from([drives_car.id, bob_drives.id]));
+
+    assert_eq!(log.take(), vec!["Query"; 4], "lookups must not scan");
+
+    // Ids still resolve through the pointer items
+    let fetched = service.get(&owns_bike.id).await.unwrap().unwrap();
+    assert_eq!(fetched.target_id, bike);
+    service.delete(&owns_bike.id).await.unwrap();
+    assert!(service.get(&owns_bike.id).await.unwrap().is_none());
+    assert_eq!(service.list().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_dynamodb_single_table_gets_links_between_entities() {
+    let (_container, service, log) = single_table_links("links").await;
+    let (alice, car) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+    let link = service
+        .create(LinkEntity::new("owns", alice, car, None))
+        .await
+        .unwrap();
+    log.take();
+
+    let found = service.get_between(&alice, &car, "owns").await.unwrap();
+    assert_eq!(found.map(|l| l.id), Some(link.id));
+    assert!(
+        service
+            .get_between(&car, &alice, "owns")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        service
+            .get_between(&alice, &car, "drives")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(log.take(), vec!["GetItem"; 3], "lookups must not scan");
+}
+
+#[tokio::test]
+async fn test_dynamodb_single_table_rejects_duplicate_links() {
+    let (_container, service, _log) = single_table_links("links").await;
+    let (alice, car) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+
+    let first = service
+        .create_unique(LinkEntity::new("owns", alice, car, None))
+        .await
+        .unwrap()
+        .expect("first link is created");
+    let again = service
+        .create_unique(LinkEntity::new("owns", alice, car, None))
+        .await
+        .unwrap();
+    assert!(again.is_none());
+    assert!(
+        service
+            .create(LinkEntity::new("owns", alice, car, None))
+            .await
+            .is_err()
+    );
+
+    // Moving the link to another target frees its old key
+    let bike = uuid::Uuid::new_v4();
+    let mut moved = first.clone();
+    moved.target_id = bike;
+    service.update(&first.id, moved).await.unwrap();
+    let links = service.find_by_target(&bike, None, None).await.unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].id, first.id);
+    assert!(
+        service
+            .create_unique(LinkEntity::new("owns", alice, car, None))
+            .await
+            .unwrap()
+            .is_some()
+    );
+}
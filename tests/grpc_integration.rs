@@ -4,10 +4,11 @@
 //! the full request/response flow using generated tonic clients.
 //!
 //! Coverage:
-//! - Entity CRUD via gRPC (Create, Get, List, Update, Delete)
-//! - Link management via gRPC (Create, Get, FindBySource, FindByTarget, Delete)
+//! - Entity CRUD via gRPC (Create, Get, List, Stream, Update, Delete)
+//! - Link management via gRPC (Create, Get, FindBySource, FindByTarget, Update, Delete)
 //! - REST + gRPC cohabitation on the same server
 //! - Proto export endpoint
+//! - Refusal of hosts with tenancy enabled
 
 #![cfg(feature = "grpc")]
 
@@ -433,6 +434,45 @@ async fn test_grpc_list_entities() {
     assert_eq!(response.total, 3);
 }
 
+#[tokio::test]
+async fn test_grpc_stream_entities() {
+    use this::server::exposure::grpc::proto::{CreateEntityRequest, StreamEntitiesRequest};
+
+    let (addr, _host, _order_store, _invoice_store) = start_grpc_server().await;
+    let mut client = entity_client(addr).await;
+
+    for i in 1..=50 {
+        let data = json_to_struct(&json!({
+            "number": format!("ORD-{:03}", i),
+            "status": "active"
+        }));
+        client
+            .create_entity(CreateEntityRequest {
+                entity_type: "order".to_string(),
+                data: Some(data),
+            })
+            .await
+            .unwrap();
+    }
+
+    // A page size that does not divide 50 exercises the last short page
+    let mut stream = client
+        .stream_entities(StreamEntitiesRequest {
+            entity_type: "order".to_string(),
+            page_size: 7,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let mut count = 0;
+    while let Some(entity) = stream.message().await.unwrap() {
+        assert!(entity.data.is_some());
+        count += 1;
+    }
+    assert_eq!(count, 50);
+}
+
 #[tokio::test]
 async fn test_grpc_list_entities_with_pagination() {
     use this::server::exposure::grpc::proto::{CreateEntityRequest, ListEntitiesRequest};
@@ -644,6 +684,140 @@ async fn test_grpc_invalid_uuid() {
     assert_eq!(status.code(), tonic::Code::InvalidArgument);
 }
 
+// ============================================================================
+// Authentication
+// ============================================================================
+
+/// Accepts the bearer token `valid-token` as the `billing` service
+struct TokenAuthProvider;
+
+#[async_trait::async_trait]
+impl this::core::AuthProvider for TokenAuthProvider {
+    async fn extract_context(
+        &self,
+        parts: &axum::http::request::Parts,
+    ) -> Result<this::core::AuthContext> {
+        match parts.headers.get("authorization") {
+            Some(value) if value == "Bearer valid-token" => Ok(this::core::AuthContext::Service {
+                service_name: "billing".to_string(),
+                tenant_id: None,
+            }),
+            _ => anyhow::bail!("unknown token"),
+        }
+    }
+
+    async fn is_owner(&self, _: &Uuid, _: &Uuid, _: &str) -> Result<bool> {
+        Ok(false)
+    }
+
+    async fn has_role(&self, _: &Uuid, _: &str) -> Result<bool> {
+        Ok(false)
+    }
+}
+
+#[tokio::test]
+async fn test_grpc_auth_rejects_calls_without_a_valid_token() {
+    use this::server::exposure::grpc::proto::CreateEntityRequest;
+
+    let (host, order_store, _invoice_store) = build_test_host();
+    let router = GrpcExposure::build_router_with_auth(host, Arc::new(TokenAuthProvider)).unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let mut client = entity_client(addr).await;
+    let create = |token: Option<&str>| {
+        let mut request = tonic::Request::new(CreateEntityRequest {
+            entity_type: "order".to_string(),
+            data: Some(json_to_struct(&json!({ "number": "ORD-001" }))),
+        });
+        if let Some(token) = token {
+            request
+                .metadata_mut()
+                .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        }
+        request
+    };
+
+    let status = client.create_entity(create(None)).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    let status = client
+        .create_entity(create(Some("forged-token")))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    assert!(order_store.entities.read().await.is_empty());
+
+    let response = client
+        .create_entity(create(Some("valid-token")))
+        .await
+        .unwrap()
+        .into_inner();
+    let entity = response.data.unwrap();
+    assert_eq!(get_string_field(&entity, "number").unwrap(), "ORD-001");
+    assert_eq!(order_store.entities.read().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_grpc_reflection_and_health_services() {
+    use tonic_health::pb::health_check_response::ServingStatus;
+    use tonic_health::pb::{HealthCheckRequest, health_client::HealthClient};
+    use tonic_reflection::pb::v1::ServerReflectionRequest;
+    use tonic_reflection::pb::v1::server_reflection_client::ServerReflectionClient;
+    use tonic_reflection::pb::v1::server_reflection_request::MessageRequest;
+    use tonic_reflection::pb::v1::server_reflection_response::MessageResponse;
+
+    let (addr, _host, _order_store, _invoice_store) = start_grpc_server().await;
+    let channel = tonic::transport::Channel::from_shared(format!("http://{}", addr))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+
+    let mut reflection = ServerReflectionClient::new(channel.clone());
+    let request = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(MessageRequest::ListServices(String::new())),
+    };
+    let mut responses = reflection
+        .server_reflection_info(tokio_stream::iter(vec![request]))
+        .await
+        .unwrap()
+        .into_inner();
+    let response = responses.message().await.unwrap().unwrap();
+    let Some(MessageResponse::ListServicesResponse(list)) = response.message_response else {
+        panic!(
+            "expected a service list, got {:?}",
+            response.message_response
+        );
+    };
+    let services: Vec<_> = list.service.into_iter().map(|s| s.name).collect();
+    for expected in [
+        "this_grpc.EntityService",
+        "this_grpc.LinkService",
+        "grpc.health.v1.Health",
+    ] {
+        assert!(
+            services.iter().any(|s| s == expected),
+            "{expected} in {services:?}"
+        );
+    }
+
+    let mut health = HealthClient::new(channel);
+    for service in ["", "this_grpc.EntityService", "this_grpc.LinkService"] {
+        let response = health
+            .check(HealthCheckRequest {
+                service: service.to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.status(), ServingStatus::Serving, "{service:?}");
+    }
+}
+
 // ============================================================================
 // Link Service Tests
 // ============================================================================
@@ -988,6 +1162,85 @@ async fn test_grpc_find_links_with_type_filter() {
     assert_eq!(links.links[0].link_type, "has_invoice");
 }
 
+#[tokio::test]
+async fn test_grpc_update_link_metadata() {
+    use this::server::exposure::grpc::proto::{
+        CreateEntityRequest, CreateLinkRequest, GetLinkRequest, UpdateLinkRequest,
+    };
+
+    let (addr, _host, _order_store, _invoice_store) = start_grpc_server().await;
+    let mut eclient = entity_client(addr).await;
+    let mut lclient = link_client(addr).await;
+
+    // Create entities and link
+    let order = eclient
+        .create_entity(CreateEntityRequest {
+            entity_type: "order".to_string(),
+            data: Some(json_to_struct(&json!({"number": "ORD-UPD-LNK"}))),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let invoice = eclient
+        .create_entity(CreateEntityRequest {
+            entity_type: "invoice".to_string(),
+            data: Some(json_to_struct(&json!({"number": "INV-UPD-LNK"}))),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let order_id = get_string_field(order.data.as_ref().unwrap(), "id").unwrap();
+    let invoice_id = get_string_field(invoice.data.as_ref().unwrap(), "id").unwrap();
+
+    let link = lclient
+        .create_link(CreateLinkRequest {
+            link_type: "has_invoice".to_string(),
+            source_id: order_id,
+            target_id: invoice_id,
+            metadata: Some(json_to_struct(&json!({"priority": "low"}))),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    // Update the metadata
+    let updated = lclient
+        .update_link(UpdateLinkRequest {
+            link_id: link.id.clone(),
+            metadata: Some(json_to_struct(&json!({"priority": "high"}))),
+            merge: false,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(updated.created_at, link.created_at);
+    assert_ne!(updated.updated_at, link.updated_at);
+
+    // Read it back
+    let fetched = lclient
+        .get_link(GetLinkRequest {
+            link_id: link.id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    let meta = fetched.metadata.unwrap();
+    assert_eq!(get_string_field(&meta, "priority").unwrap(), "high");
+
+    // Unknown links are reported as NotFound
+    let status = lclient
+        .update_link(UpdateLinkRequest {
+            link_id: Uuid::new_v4().to_string(),
+            metadata: None,
+            merge: false,
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
+
 #[tokio::test]
 async fn test_grpc_delete_link() {
     use this::server::exposure::grpc::proto::{
@@ -1467,6 +1720,12 @@ async fn test_grpc_proto_export_endpoint() {
     assert!(body.contains("package this_api"));
     // Should have typed services for our registered entity types
     assert!(body.contains("service LinkService"));
+    assert!(body.contains("rpc StreamOrder(StreamOrderRequest) returns (stream Order);"));
+    assert!(body.contains("message StreamOrderRequest {\n  int32 page_size = 1;\n}"));
+    assert!(body.contains("rpc UpdateLink(UpdateLinkRequest) returns (Link);"));
+    assert!(body.contains(
+        "message UpdateLinkRequest {\n  string id = 1;\n  string metadata = 2;\n  bool merge = 3;\n}"
+    ));
 }
 
 #[tokio::test]
@@ -1519,3 +1778,16 @@ async fn test_grpc_create_entities_of_different_types() {
     assert_eq!(orders.entities.len(), 1);
     assert_eq!(invoices.entities.len(), 1);
 }
+
+#[test]
+fn test_grpc_refuses_hosts_with_tenancy() {
+    let (host, _, _) = build_test_host();
+    let Ok(host) = Arc::try_unwrap(host) else {
+        panic!("host is not shared yet");
+    };
+    let host =
+        Arc::new(host.with_tenant_header(axum::http::HeaderName::from_static("x-tenant-id")));
+
+    assert!(GrpcExposure::build_router(host.clone()).is_err());
+    assert!(GrpcExposure::build_router_no_fallback(host).is_err());
+}
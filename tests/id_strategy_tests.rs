@@ -0,0 +1,150 @@
+//! Integration tests for `ServerBuilder::with_id_strategy`
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use this::prelude::*;
+use this::server::entity_registry::EntityRegistry;
+use this::server::host::ServerHost;
+
+impl_data_entity!(Order, "order", ["name"], {
+    amount: f64,
+});
+
+/// Creator storing orders under the `id` of the payload, when there is one
+struct OrderCreator(Arc<InMemoryDataService<Order>>);
+
+#[async_trait]
+impl EntityCreator for OrderCreator {
+    async fn create_from_json(&self, entity_data: Value) -> anyhow::Result<Value> {
+        let mut order = Order::new(
+            entity_data["name"].as_str().unwrap_or_default().to_string(),
+            "active".to_string(),
+            entity_data["amount"].as_f64().unwrap_or_default(),
+        );
+        if let Some(id) = entity_data["id"].as_str() {
+            order.id = Uuid::parse_str(id)?;
+        }
+        Ok(serde_json::to_value(self.0.create(order).await?)?)
+    }
+
+    async fn update_from_json(&self, _: &Uuid, entity_data: Value) -> anyhow::Result<Value> {
+        Ok(entity_data)
+    }
+}
+
+struct OrderModule;
+
+#[async_trait]
+impl Module for OrderModule {
+    fn name(&self) -> &str {
+        "orders"
+    }
+
+    fn entity_types(&self) -> Vec<&str> {
+        vec!["order"]
+    }
+
+    fn links_config(&self) -> anyhow::Result<LinksConfig> {
+        Ok(LinksConfig::default_config())
+    }
+
+    fn register_entities(&self, _registry: &mut EntityRegistry) {}
+
+    fn get_entity_fetcher(&self, _entity_type: &str) -> Option<Arc<dyn EntityFetcher>> {
+        None
+    }
+
+    fn get_entity_creator(&self, _entity_type: &str) -> Option<Arc<dyn EntityCreator>> {
+        Some(Arc::new(OrderCreator(Arc::new(InMemoryDataService::new()))))
+    }
+}
+
+fn builder_with(strategy: IdStrategy) -> ServerBuilder {
+    ServerBuilder::new()
+        .with_link_service(InMemoryLinkService::new())
+        .register_module(OrderModule)
+        .expect("register_module should succeed")
+        .with_id_strategy(strategy)
+}
+
+fn host_with(strategy: IdStrategy) -> ServerHost {
+    builder_with(strategy)
+        .build_host()
+        .expect("build_host should succeed")
+}
+
+async fn create_orders(host: &ServerHost, count: usize) -> Vec<Uuid> {
+    let creator = &host.entity_creators["order"];
+    let mut ids = Vec::new();
+    for i in 0..count {
+        let order = creator
+            .create_from_json(serde_json::json!({ "name": format!("order-{i}"), "amount": 1.0 }))
+            .await
+            .unwrap();
+        ids.push(Uuid::parse_str(order["id"].as_str().unwrap()).unwrap());
+    }
+    ids
+}
+
+#[tokio::test]
+async fn test_uuid_v7_ids_increase_with_sequential_creates() {
+    let host = host_with(IdStrategy::UuidV7);
+
+    let order_ids = create_orders(&host, 100).await;
+    assert!(order_ids.iter().all(|id| id.get_version_num() == 7));
+    assert!(order_ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let mut link_ids = Vec::new();
+    for target in &order_ids {
+        let link = host
+            .create_link(LinkEntity::new("has_order", Uuid::new_v4(), *target, None))
+            .await
+            .unwrap();
+        link_ids.push(link.id);
+    }
+    assert!(link_ids.iter().all(|id| id.get_version_num() == 7));
+    assert!(link_ids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(order_ids.last() < link_ids.first());
+}
+
+#[tokio::test]
+async fn test_servers_keep_their_own_strategy() {
+    let v7 = host_with(IdStrategy::UuidV7);
+    let nil = host_with(IdStrategy::Custom(Uuid::nil));
+
+    assert_eq!(create_orders(&nil, 1).await, vec![Uuid::nil()]);
+    assert_eq!(create_orders(&v7, 1).await[0].get_version_num(), 7);
+    assert_eq!(
+        Order::new("direct".into(), "active".into(), 1.0)
+            .id
+            .get_version_num(),
+        4
+    );
+}
+
+#[tokio::test]
+async fn test_rest_links_get_ids_from_the_server_strategy() {
+    use axum::body::{Body, to_bytes};
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    let router = builder_with(IdStrategy::UuidV7)
+        .build()
+        .expect("build should succeed");
+    let request = Request::post(format!(
+        "/users/{}/cars-owned/{}",
+        Uuid::new_v4(),
+        Uuid::new_v4()
+    ))
+    .header("content-type", "application/json")
+    .body(Body::from("{}"))
+    .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let link: Value = serde_json::from_slice(&body).unwrap();
+    let id = Uuid::parse_str(link["id"].as_str().unwrap()).unwrap();
+    assert_eq!(id.get_version_num(), 7);
+}
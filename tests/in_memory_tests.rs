@@ -11,4 +11,5 @@ use this::storage::{InMemoryDataService, InMemoryLinkService};
 
 data_service_tests!(InMemoryDataService::<TestDataEntity>::new());
 link_service_tests!(InMemoryLinkService::new());
+bounded_link_tests!(InMemoryLinkService::new());
 rest_integration_tests!(InMemoryDataService::<TestDataEntity>::new());
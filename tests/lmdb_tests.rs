@@ -55,4 +55,5 @@ fn fresh_lmdb_link_service() -> LmdbLinkService {
 
 data_service_tests!(fresh_lmdb_data_service());
 link_service_tests!(fresh_lmdb_link_service());
+bounded_link_tests!(fresh_lmdb_link_service());
 rest_integration_tests!(fresh_lmdb_data_service());
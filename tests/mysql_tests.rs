@@ -16,14 +16,28 @@
 #[macro_use]
 mod storage_harness;
 
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use sqlx::MySqlPool;
 use sqlx::mysql::MySqlPoolOptions;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use storage_harness::*;
 use testcontainers::runners::AsyncRunner;
 use testcontainers_modules::mysql::Mysql;
+use this::core::entity::{Data, Entity};
+use this::core::events::EventBus;
+use this::core::field::FieldValue;
+use this::core::link::{LinkEntity, LinkError, LinkLimit};
+use this::core::outbox::poll_outbox;
+use this::core::soft_delete::SoftDeleteStatus;
+use this::core::{AuditEntry, AuditLogService, AuditOperation, OutboxService};
+use this::core::{DataService, LinkService, TenantContext};
+use this::core::{QueryBuilder, QueryOp, QueryableStore, SortDirection};
 use this::storage::mysql::ensure_schema;
-use this::storage::{MysqlDataService, MysqlLinkService};
+use this::storage::{
+    MysqlAuditLogService, MysqlDataService, MysqlLinkService, MysqlOutboxService, StorageError,
+};
+use uuid::Uuid;
 
 // ---------------------------------------------------------------------------
 // Shared test environment
@@ -136,4 +150,650 @@ async fn clean_mysql_link_service() -> MysqlLinkService {
 
 data_service_tests!(clean_mysql_data_service().await);
 link_service_tests!(clean_mysql_link_service().await);
+bounded_link_tests!(clean_mysql_link_service().await);
 rest_integration_tests!(clean_mysql_data_service().await);
+
+// ---------------------------------------------------------------------------
+// Optimistic concurrency
+// ---------------------------------------------------------------------------
+
+/// An entity opting into optimistic concurrency with a `version` field
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct VersionedDoc {
+    id: Uuid,
+    entity_type: String,
+    name: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    status: String,
+    version: u64,
+}
+
+impl Entity for VersionedDoc {
+    type Service = ();
+
+    fn resource_name() -> &'static str {
+        "versioned_docs"
+    }
+
+    fn resource_name_singular() -> &'static str {
+        "versioned_doc"
+    }
+
+    fn service_from_host(
+        _host: &Arc<dyn std::any::Any + Send + Sync>,
+    ) -> anyhow::Result<Arc<Self::Service>> {
+        Ok(Arc::new(()))
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn entity_type(&self) -> &str {
+        &self.entity_type
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+
+    fn status(&self) -> &str {
+        &self.status
+    }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+impl Data for VersionedDoc {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn indexed_fields() -> &'static [&'static str] {
+        &["name"]
+    }
+
+    fn field_value(&self, field: &str) -> Option<FieldValue> {
+        match field {
+            "name" => Some(FieldValue::String(self.name.clone())),
+            _ => None,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_mysql_stale_update_is_a_conflict() {
+    let service = MysqlDataService::<VersionedDoc>::new(mysql_pool().await);
+    let now = Utc::now();
+    let doc = service
+        .create(VersionedDoc {
+            id: Uuid::new_v4(),
+            entity_type: "versioned_doc".to_string(),
+            name: "draft".to_string(),
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            status: "active".to_string(),
+            version: 0,
+        })
+        .await
+        .unwrap();
+
+    // Two clients read the same version; the first update wins
+    let mut first = doc.clone();
+    first.name = "first".to_string();
+    let updated = service.update(&doc.id, first).await.unwrap();
+    assert_eq!(updated.version, 1);
+
+    let mut stale = doc.clone();
+    stale.name = "second".to_string();
+    let err = service.update(&doc.id, stale).await.unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<StorageError>(),
+        Some(&StorageError::IntegrityError {
+            entity_id: doc.id,
+            expected_version: 0,
+        })
+    );
+
+    let stored = service.get(&doc.id).await.unwrap().unwrap();
+    assert_eq!((stored.name.as_str(), stored.version), ("first", 1));
+}
+
+// ---------------------------------------------------------------------------
+// Unique fields
+// ---------------------------------------------------------------------------
+
+this::impl_data_entity!(
+    UniqueUser,
+    "unique_user",
+    ["name"],
+    {
+        email: String,
+    },
+    unique: ["email"]
+);
+
+#[tokio::test]
+async fn test_mysql_duplicate_unique_field_already_exists() {
+    let service = MysqlDataService::<UniqueUser>::new(mysql_pool().await);
+    service.ensure_unique_indexes().await.unwrap();
+    // Idempotent
+    service.ensure_unique_indexes().await.unwrap();
+
+    let email = format!("{}@example.com", Uuid::new_v4());
+    let user =
+        |email: &str| UniqueUser::new("Ada".to_string(), "active".to_string(), email.to_string());
+    let first = service.create(user(&email)).await.unwrap();
+
+    let err = service.create(user(&email)).await.unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<StorageError>(),
+        Some(&StorageError::AlreadyExists {
+            entity_type: "unique_user".to_string(),
+            field: "email".to_string(),
+            value: email.clone(),
+        })
+    );
+
+    // A soft-deleted user releases its email
+    service.soft_delete(&first.id).await.unwrap();
+    service.create(user(&email)).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mysql_owner_id_is_stored_in_its_column() {
+    let service = MysqlDataService::<UniqueUser>::new(mysql_pool().await);
+    let owner_id = Uuid::new_v4();
+    let email = format!("{}@example.com", Uuid::new_v4());
+    let user = UniqueUser::new("Ada".to_string(), "active".to_string(), email).with_owner(owner_id);
+    let created = service.create(user).await.unwrap();
+
+    let stored = service.get(&created.id).await.unwrap().unwrap();
+    assert_eq!(stored.owner_id(), Some(owner_id));
+    let column: Option<String> = sqlx::query_scalar("SELECT owner_id FROM entities WHERE id = ?")
+        .bind(created.id.to_string())
+        .fetch_one(service.pool())
+        .await
+        .unwrap();
+    assert_eq!(column, Some(owner_id.to_string()));
+}
+
+// ---------------------------------------------------------------------------
+// Audit log
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_mysql_audit_log_records_create_and_update() {
+    let service = clean_mysql_data_service().await;
+    let audit_log = MysqlAuditLogService::new(mysql_pool().await);
+
+    let created = service
+        .create(create_test_entity(
+            "Audited",
+            "audited@example.com",
+            30,
+            1.0,
+            true,
+        ))
+        .await
+        .unwrap();
+    let after_create = serde_json::to_value(&created).unwrap();
+    audit_log
+        .record(AuditEntry::new(
+            "test_data_entity",
+            created.id,
+            AuditOperation::Create,
+            Some("user:alice".to_string()),
+            None,
+            Some(after_create.clone()),
+        ))
+        .await
+        .unwrap();
+
+    let mut renamed = created.clone();
+    renamed.name = "Renamed".to_string();
+    let updated = service.update(&created.id, renamed).await.unwrap();
+    audit_log
+        .record(AuditEntry::new(
+            "test_data_entity",
+            created.id,
+            AuditOperation::Update,
+            Some("user:alice".to_string()),
+            Some(after_create.clone()),
+            Some(serde_json::to_value(&updated).unwrap()),
+        ))
+        .await
+        .unwrap();
+
+    let entries = audit_log
+        .list("test_data_entity", &created.id)
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].operation, AuditOperation::Create);
+    assert_eq!(entries[0].after, Some(after_create));
+    assert_eq!(entries[1].operation, AuditOperation::Update);
+    assert_eq!(entries[1].actor.as_deref(), Some("user:alice"));
+}
+
+// ---------------------------------------------------------------------------
+// Outbox
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_mysql_outbox_publishes_mutations_once() {
+    let service = clean_mysql_data_service().await.with_outbox();
+    let pool = service.pool().clone();
+    sqlx::query("TRUNCATE TABLE outbox")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let outbox = MysqlOutboxService::new(pool);
+    let bus = EventBus::new(16);
+    let mut rx = bus.subscribe();
+
+    let created = service
+        .create(create_test_entity(
+            "Boxed",
+            "boxed@example.com",
+            30,
+            1.0,
+            true,
+        ))
+        .await
+        .unwrap();
+    service.delete(&created.id).await.unwrap();
+    assert_eq!(outbox.unsent(10).await.unwrap().len(), 2);
+
+    assert_eq!(poll_outbox(&outbox, &bus, 10).await.unwrap(), 2);
+    for action in ["created", "deleted"] {
+        let envelope = rx.try_recv().unwrap();
+        assert_eq!(envelope.event.action(), action);
+        assert_eq!(envelope.event.entity_id(), Some(created.id));
+    }
+    assert!(outbox.unsent(10).await.unwrap().is_empty());
+    assert_eq!(poll_outbox(&outbox, &bus, 10).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_mysql_outbox_records_bulk_link_deletes() {
+    let service = clean_mysql_link_service().await.with_outbox();
+    assert!(service.writes_outbox());
+    let pool = service.pool().clone();
+    sqlx::query("TRUNCATE TABLE outbox")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let outbox = MysqlOutboxService::new(pool);
+
+    let user = Uuid::new_v4();
+    let kept = service
+        .create(LinkEntity::new("driver", user, Uuid::new_v4(), None))
+        .await
+        .unwrap();
+    for _ in 0..2 {
+        service
+            .create(LinkEntity::new("owner", user, Uuid::new_v4(), None))
+            .await
+            .unwrap();
+    }
+    assert_eq!(service.delete_where("owner", None).await.unwrap(), 2);
+
+    let actions: Vec<_> = outbox
+        .unsent(10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.event.action().to_string())
+        .collect();
+    assert_eq!(
+        actions,
+        ["created", "created", "created", "deleted", "deleted"]
+    );
+    assert!(service.get(&kept.id).await.unwrap().is_some());
+}
+
+// ---------------------------------------------------------------------------
+// Tenant scoping
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_mysql_links_are_scoped_to_tenant() {
+    let service = clean_mysql_link_service().await;
+    let (tenant_a, tenant_b) = (Uuid::new_v4(), Uuid::new_v4());
+    let (user, car) = (Uuid::new_v4(), Uuid::new_v4());
+    for (tenant, link_type) in [(tenant_a, "owner"), (tenant_b, "driver")] {
+        service
+            .create(LinkEntity::new_with_tenant(
+                tenant, link_type, user, car, None,
+            ))
+            .await
+            .unwrap();
+    }
+
+    let a = TenantContext::new(tenant_a);
+    let from_user = service
+        .find_by_source_for_tenant(&user, None, None, &a)
+        .await
+        .unwrap();
+    assert_eq!(from_user.len(), 1);
+    assert_eq!(from_user[0].link_type, "owner");
+
+    let b = TenantContext::new(tenant_b);
+    let to_car = service
+        .find_by_target_for_tenant(&car, None, None, &b)
+        .await
+        .unwrap();
+    assert_eq!(to_car.len(), 1);
+    assert_eq!(to_car[0].link_type, "driver");
+}
+
+#[tokio::test]
+async fn test_mysql_create_unique_skips_live_duplicates() {
+    let service = clean_mysql_link_service().await;
+    let (user, car) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let first = service
+        .create_unique(LinkEntity::new("owner", user, car, None))
+        .await
+        .unwrap()
+        .expect("first link should be created");
+    assert!(
+        service
+            .create_unique(LinkEntity::new("owner", user, car, None))
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    // A soft-deleted link does not block its replacement
+    let mut removed = first.clone();
+    removed.soft_delete();
+    service.update(&first.id, removed).await.unwrap();
+    assert!(
+        service
+            .create_unique(LinkEntity::new("owner", user, car, None))
+            .await
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn test_mysql_concurrent_create_unique_inserts_one_link() {
+    let service = Arc::new(clean_mysql_link_service().await);
+    let (user, car) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let attempts = (0..8).map(|_| {
+        let service = service.clone();
+        tokio::spawn(async move {
+            service
+                .create_unique(LinkEntity::new("owner", user, car, None))
+                .await
+        })
+    });
+    let mut created = 0;
+    for attempt in futures::future::join_all(attempts).await {
+        match attempt.unwrap() {
+            Ok(Some(_)) => created += 1,
+            Ok(None) => {}
+            Err(e) => assert!(matches!(
+                e.downcast_ref::<LinkError>(),
+                Some(LinkError::AlreadyExists { .. })
+            )),
+        }
+    }
+    assert_eq!(created, 1);
+
+    // Links created with duplicates allowed stay out of the unique index
+    for _ in 0..2 {
+        service
+            .create(LinkEntity::new("owner", user, car, None))
+            .await
+            .unwrap();
+    }
+    assert_eq!(
+        service
+            .find_by_source(&user, Some("owner"), None)
+            .await
+            .unwrap()
+            .len(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn test_mysql_cancelled_create_within_limit_releases_the_lock() {
+    let service = clean_mysql_link_service().await;
+    let user = Uuid::new_v4();
+    let limit = LinkLimit::per_source(2);
+
+    // The first insert creates the lock row of the link type
+    service
+        .create_within_limit(LinkEntity::new("member", user, Uuid::new_v4(), None), limit)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Hold the lock so the next insert waits on it, then cancel that insert
+    let mut holder = service.pool().begin().await.unwrap();
+    sqlx::query("SELECT link_type FROM link_type_locks WHERE link_type = ? FOR UPDATE")
+        .bind("member")
+        .execute(&mut *holder)
+        .await
+        .unwrap();
+    let cancelled = tokio::time::timeout(
+        std::time::Duration::from_millis(200),
+        service.create_within_limit(LinkEntity::new("member", user, Uuid::new_v4(), None), limit),
+    )
+    .await;
+    assert!(cancelled.is_err(), "the insert should wait for the lock");
+    holder.rollback().await.unwrap();
+
+    // Neither the cancelled insert nor its lock survive
+    let created = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        service.create_within_limit(LinkEntity::new("member", user, Uuid::new_v4(), None), limit),
+    )
+    .await
+    .expect("the lock should be free")
+    .unwrap();
+    assert!(created.is_some());
+    assert_eq!(
+        service
+            .find_by_source(&user, Some("member"), None)
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_mysql_find_links_by_page() {
+    let service = clean_mysql_link_service().await;
+    let user = Uuid::new_v4();
+    let start = Utc::now();
+    let mut links = Vec::new();
+    for i in 0..25 {
+        let mut link = LinkEntity::new("owner", user, Uuid::new_v4(), None);
+        link.created_at = start + chrono::Duration::seconds(i);
+        links.push(service.create(link).await.unwrap());
+    }
+
+    let (page, total) = service
+        .find_by_source_page(&user, Some("owner"), None, 10, 10)
+        .await
+        .unwrap();
+    assert_eq!(total, 25);
+    let expected: Vec<Uuid> = links[5..15].iter().rev().map(|l| l.id).collect();
+    assert_eq!(page.iter().map(|l| l.id).collect::<Vec<_>>(), expected);
+
+    let (page, total) = service
+        .find_by_target_page(&links[0].target_id, Some("owner"), None, 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(total, 1);
+    assert_eq!(page[0].id, links[0].id);
+}
+
+#[tokio::test]
+async fn test_mysql_get_between() {
+    let service = clean_mysql_link_service().await;
+    let (user, car) = (Uuid::new_v4(), Uuid::new_v4());
+    let link = service
+        .create(LinkEntity::new("owner", user, car, None))
+        .await
+        .unwrap();
+
+    let found = service.get_between(&user, &car, "owner").await.unwrap();
+    assert_eq!(found.map(|l| l.id), Some(link.id));
+    assert!(
+        service
+            .get_between(&car, &user, "owner")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        service
+            .get_between(&user, &car, "driver")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let mut removed = link.clone();
+    removed.soft_delete();
+    service.update(&link.id, removed).await.unwrap();
+    assert!(
+        service
+            .get_between(&user, &car, "owner")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Soft delete
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_mysql_soft_delete_and_restore() {
+    let service = clean_mysql_data_service().await;
+    let entity = service
+        .create(create_test_entity("Alice", "alice@test.com", 30, 4.5, true))
+        .await
+        .unwrap();
+
+    let tombstone = service.soft_delete(&entity.id).await.unwrap();
+    assert!(tombstone.deleted_at.is_some());
+    assert!(service.list().await.unwrap().is_empty());
+    assert!(service.get(&entity.id).await.unwrap().is_none());
+    assert!(service.soft_delete(&entity.id).await.is_err());
+
+    let restored = service.restore(&entity.id).await.unwrap();
+    assert!(restored.deleted_at.is_none());
+    assert_eq!(service.list().await.unwrap().len(), 1);
+
+    // Restoring a live entity changes nothing
+    let again = service.restore(&entity.id).await.unwrap();
+    assert_eq!(again.updated_at, restored.updated_at);
+    assert!(service.restore(&Uuid::new_v4()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mysql_soft_delete_with_status_reverts_previous_status() {
+    let service = clean_mysql_data_service().await;
+    let transition = SoftDeleteStatus::new("deleted", "archived");
+    let entity = service
+        .create(create_test_entity("Alice", "alice@test.com", 30, 4.5, true))
+        .await
+        .unwrap();
+
+    let tombstone = service
+        .soft_delete_with_status(&entity.id, &transition)
+        .await
+        .unwrap();
+    assert!(tombstone.deleted_at.is_some());
+    assert_eq!(tombstone.status, "deleted");
+
+    let restored = service
+        .restore_with_status(&entity.id, &transition)
+        .await
+        .unwrap();
+    assert!(restored.deleted_at.is_none());
+    assert_eq!(restored.status, "active");
+
+    // Restoring a live entity leaves its status alone
+    let again = service
+        .restore_with_status(&entity.id, &transition)
+        .await
+        .unwrap();
+    assert_eq!(again.status, "active");
+
+    // Deleted without a status: nothing to revert to
+    service.soft_delete(&entity.id).await.unwrap();
+    let restored = service
+        .restore_with_status(&entity.id, &transition)
+        .await
+        .unwrap();
+    assert_eq!(restored.status, "archived");
+}
+
+// ---------------------------------------------------------------------------
+// Typed queries
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_mysql_query_translates_filters_sort_and_window() {
+    let service = clean_mysql_data_service().await;
+    for entity in sample_batch(10) {
+        service.create(entity).await.unwrap();
+    }
+
+    // Active entities (even indexes) aged 24 or more, oldest first
+    let query = QueryBuilder::new()
+        .filter("active", QueryOp::Eq, true)
+        .filter("age", QueryOp::Gte, 24)
+        .sort("age", SortDirection::Desc)
+        .build()
+        .unwrap();
+    let names: Vec<String> = service
+        .query(&query)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|e| e.name)
+        .collect();
+    assert_eq!(names, ["Entity_8", "Entity_6", "Entity_4"]);
+
+    let window = QueryBuilder::new()
+        .sort("name", SortDirection::Asc)
+        .offset(2)
+        .limit(3)
+        .build()
+        .unwrap();
+    let names: Vec<String> = service
+        .query(&window)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|e| e.name)
+        .collect();
+    assert_eq!(names, ["Entity_2", "Entity_3", "Entity_4"]);
+}
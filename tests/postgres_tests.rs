@@ -31,7 +31,9 @@ use std::sync::OnceLock;
 use storage_harness::*;
 use testcontainers::runners::AsyncRunner;
 use testcontainers_modules::postgres::Postgres;
-use this::storage::{PostgresDataService, PostgresLinkService};
+use this::core::history::HistoryService;
+use this::core::service::DataService;
+use this::storage::{PostgresDataService, PostgresHistoryService, PostgresLinkService};
 
 // ---------------------------------------------------------------------------
 // Shared test environment (single container, fresh pool per test)
@@ -145,4 +147,56 @@ async fn clean_pg_link_service() -> PostgresLinkService {
 
 data_service_tests!(clean_pg_data_service().await);
 link_service_tests!(clean_pg_link_service().await);
+bounded_link_tests!(clean_pg_link_service().await);
 rest_integration_tests!(clean_pg_data_service().await);
+
+// ---------------------------------------------------------------------------
+// Version history
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_pg_update_with_history_records_prior_snapshot() {
+    let pool = pg_pool().await;
+    sqlx::query("TRUNCATE entities, entity_versions CASCADE")
+        .execute(&pool)
+        .await
+        .expect("Failed to truncate tables");
+    let service = PostgresDataService::<TestDataEntity>::new(pool.clone()).with_history();
+    let history = PostgresHistoryService::new(pool);
+
+    let entity = create_test_entity("Alice", "alice@example.com", 30, 1.0, true);
+    let id = entity.id;
+    service.create(entity.clone()).await.unwrap();
+
+    let mut changed = entity.clone();
+    changed.age = 31;
+    service
+        .update_as(&id, changed.clone(), Some("bob"))
+        .await
+        .unwrap();
+    changed.age = 32;
+    service.update(&id, changed).await.unwrap();
+
+    let versions = history
+        .list_versions("test_data_entity", &id)
+        .await
+        .unwrap();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0].version, 1);
+    assert_eq!(versions[0].snapshot["age"], 30);
+    assert_eq!(versions[0].actor.as_deref(), Some("bob"));
+    assert_eq!(versions[1].snapshot["age"], 31);
+
+    let second = history
+        .get_version("test_data_entity", &id, 2)
+        .await
+        .unwrap();
+    assert_eq!(second.unwrap().snapshot["age"], 31);
+    assert!(
+        history
+            .get_version("test_data_entity", &id, 3)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
@@ -0,0 +1,171 @@
+//! Integration tests for the Redis link backend using the storage test harness.
+//!
+//! # Requirements
+//!
+//! - Docker must be running (testcontainers launches a Redis container)
+//! - Feature flag `redis` must be enabled
+//!
+//! # Running
+//!
+//! ```sh
+//! cargo test --features redis --test redis_tests -- --test-threads=1
+//! ```
+
+#![cfg(feature = "redis")]
+
+#[macro_use]
+mod storage_harness;
+
+use redis::aio::ConnectionManager;
+use std::sync::OnceLock;
+use storage_harness::*;
+use testcontainers::runners::AsyncRunner;
+use testcontainers_modules::redis::Redis;
+use this::core::LinkService;
+use this::storage::RedisLinkService;
+use uuid::Uuid;
+
+// ---------------------------------------------------------------------------
+// Shared test environment
+// ---------------------------------------------------------------------------
+
+struct RedisTestEnv {
+    _container: testcontainers::ContainerAsync<Redis>,
+    connection_url: String,
+}
+
+static TEST_ENV: OnceLock<RedisTestEnv> = OnceLock::new();
+
+async fn init_redis_env() -> &'static RedisTestEnv {
+    if let Some(env) = TEST_ENV.get() {
+        return env;
+    }
+
+    let container = Redis::default()
+        .start()
+        .await
+        .expect("Failed to start Redis container — is Docker running?");
+
+    let host = container.get_host().await.unwrap();
+    let port = container.get_host_port_ipv4(6379).await.unwrap();
+
+    let env = RedisTestEnv {
+        _container: container,
+        connection_url: format!("redis://{}:{}", host, port),
+    };
+
+    let _ = TEST_ENV.set(env);
+    TEST_ENV.get().unwrap()
+}
+
+async fn redis_connection() -> ConnectionManager {
+    let env = init_redis_env().await;
+    redis::Client::open(env.connection_url.as_str())
+        .expect("Invalid Redis URL")
+        .get_connection_manager()
+        .await
+        .expect("Failed to connect to Redis")
+}
+
+// ---------------------------------------------------------------------------
+// Factory helpers (flush the database before each test for isolation)
+// ---------------------------------------------------------------------------
+
+async fn clean_redis_link_service() -> RedisLinkService {
+    let mut conn = redis_connection().await;
+    redis::cmd("FLUSHDB")
+        .query_async::<()>(&mut conn)
+        .await
+        .expect("Failed to flush Redis");
+    RedisLinkService::new(conn)
+}
+
+// ---------------------------------------------------------------------------
+// Test suites via macros
+// ---------------------------------------------------------------------------
+
+link_service_tests!(clean_redis_link_service().await);
+bounded_link_tests!(clean_redis_link_service().await);
+
+// ---------------------------------------------------------------------------
+// Index maintenance
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_redis_delete_by_entity_cleans_every_index() {
+    let service = clean_redis_link_service().await;
+    let (user, car, garage) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+    let owner = service
+        .create(create_test_link(user, car, "owner"))
+        .await
+        .unwrap();
+    let parked = service
+        .create(create_test_link(car, garage, "parked_in"))
+        .await
+        .unwrap();
+    let kept = service
+        .create(create_test_link(user, garage, "owner"))
+        .await
+        .unwrap();
+
+    service.delete_by_entity(&car).await.unwrap();
+
+    assert!(service.get(&owner.id).await.unwrap().is_none());
+    assert!(service.get(&parked.id).await.unwrap().is_none());
+    let remaining: Vec<Uuid> = service.list().await.unwrap().iter().map(|l| l.id).collect();
+    assert_eq!(remaining, vec![kept.id]);
+
+    // The other endpoints no longer reference the deleted links
+    let mut conn = redis_connection().await;
+    let user_links: Vec<String> = redis::cmd("SMEMBERS")
+        .arg(format!("src:{}", user))
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(user_links, vec![kept.id.to_string()]);
+    let garage_links: Vec<String> = redis::cmd("SMEMBERS")
+        .arg(format!("tgt:{}", garage))
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(garage_links, vec![kept.id.to_string()]);
+    let car_indexes: u32 = redis::cmd("EXISTS")
+        .arg(format!("src:{}", car))
+        .arg(format!("tgt:{}", car))
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(car_indexes, 0);
+}
+
+#[tokio::test]
+async fn test_redis_find_filters_by_link_type() {
+    let service = clean_redis_link_service().await;
+    let (user, car) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let owner = service
+        .create(create_test_link(user, car, "owner"))
+        .await
+        .unwrap();
+    service
+        .create(create_test_link(user, car, "driver"))
+        .await
+        .unwrap();
+
+    let outgoing = service
+        .find_by_source(&user, Some("owner"), None)
+        .await
+        .unwrap();
+    assert_eq!(outgoing.len(), 1);
+    assert_eq!(outgoing[0].id, owner.id);
+
+    let incoming = service.find_by_target(&car, None, None).await.unwrap();
+    assert_eq!(incoming.len(), 2);
+    let incoming = service
+        .find_by_target(&car, Some("driver"), None)
+        .await
+        .unwrap();
+    assert_eq!(incoming.len(), 1);
+    assert_eq!(incoming[0].link_type, "driver");
+}
@@ -0,0 +1,81 @@
+//! Integration tests for SQLite storage backends using the storage test harness.
+//!
+//! Invokes `data_service_tests!`, `link_service_tests!`, and `rest_integration_tests!`
+//! to validate that SQLite storage backends fully conform to their contracts.
+//!
+//! # Requirements
+//!
+//! - Feature flag `sqlite` must be enabled (no external database needed)
+//!
+//! # Running
+//!
+//! ```sh
+//! cargo test --features sqlite --test sqlite_tests
+//! ```
+//!
+//! # Test isolation
+//!
+//! Each test gets its own in-memory database. In-memory SQLite databases are
+//! per-connection, so pools are limited to a single connection.
+
+#![cfg(feature = "sqlite")]
+
+#[macro_use]
+mod storage_harness;
+
+use sqlx::SqlitePool;
+use sqlx::sqlite::SqlitePoolOptions;
+use storage_harness::*;
+use this::storage::sqlite::ensure_schema;
+use this::storage::{SqliteDataService, SqliteLinkService};
+
+// ---------------------------------------------------------------------------
+// Factory helpers (fresh in-memory database per test)
+// ---------------------------------------------------------------------------
+
+/// Create a single-connection pool on a fresh in-memory database.
+async fn sqlite_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to open in-memory SQLite database");
+    ensure_schema(&pool)
+        .await
+        .expect("Failed to create SQLite schema");
+    pool
+}
+
+async fn sqlite_data_service() -> SqliteDataService<TestDataEntity> {
+    SqliteDataService::new(sqlite_pool().await)
+}
+
+async fn sqlite_link_service() -> SqliteLinkService {
+    SqliteLinkService::new(sqlite_pool().await)
+}
+
+// ---------------------------------------------------------------------------
+// Test suites via macros
+// ---------------------------------------------------------------------------
+
+data_service_tests!(sqlite_data_service().await);
+link_service_tests!(sqlite_link_service().await);
+bounded_link_tests!(sqlite_link_service().await);
+rest_integration_tests!(sqlite_data_service().await);
+
+// ---------------------------------------------------------------------------
+// Health checks
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_sqlite_health_check_fails_once_the_pool_is_closed() {
+    use this::core::HealthCheck;
+
+    let pool = sqlite_pool().await;
+    let service = SqliteLinkService::new(pool.clone());
+    assert_eq!(service.name(), "sqlite");
+    service.check().await.unwrap();
+
+    pool.close().await;
+    assert!(service.check().await.is_err());
+}
@@ -28,6 +28,12 @@
 //! - `test_update_nonexistent` — update unknown ID returns Err
 //! - `test_delete_existing` — delete then get returns None
 //! - `test_delete_nonexistent` — delete unknown ID (Ok or Err, both accepted)
+//! - `test_soft_deleted_hidden_from_list` — soft-deleted entity leaves `list()`/`get()`
+//!   but stays visible through `list_with_deleted()`/`get_with_deleted()`
+//! - `test_count_matches_list` — `count()` equals `list().len()` after inserts and soft-deletes
+//! - `test_create_many_preserves_order` — batch-create 100 entities, order and count preserved
+//! - `test_cursor_walk_has_no_duplicates_or_gaps` — page through 23 entities with
+//!   `list_after` and back with `list_before`
 //!
 //! ## Search
 //! - `test_search_string_field` — search by email (FieldValue::String)
@@ -36,6 +42,10 @@
 //! - `test_search_boolean_field` — search by active (FieldValue::Boolean)
 //! - `test_search_no_results` — search with non-matching value
 //! - `test_search_unknown_field` — search on nonexistent field
+//! - `test_list_where_numeric_range` — `$gt`/`$lte` over age and score
+//! - `test_list_where_in_over_strings` — `$in` over email, combined with `$ne`
+//! - `test_sort_by_several_keys` — `active:desc,age:asc` over a sample batch;
+//!   unknown keys are ignored
 //!
 //! ## Edge Cases
 //! - `test_create_duplicate_id` — insert twice with same UUID (overwrite or error)
@@ -238,6 +248,155 @@ macro_rules! data_service_tests {
                 }
             }
 
+            // ==================================================================
+            // CRUD — Soft delete
+            // ==================================================================
+
+            #[tokio::test]
+            async fn test_soft_deleted_hidden_from_list() {
+                let service = $factory;
+                let kept = create_test_entity("Kept", "kept@test.com", 20, 1.0, true);
+                let mut gone = create_test_entity("Gone", "gone@test.com", 21, 1.5, true);
+                let gone_id = gone.id;
+
+                service.create(kept).await.unwrap();
+                service.create(gone.clone()).await.unwrap();
+
+                gone.deleted_at = Some(chrono::Utc::now());
+                service.update(&gone_id, gone).await.unwrap();
+
+                let listed = service.list().await.unwrap();
+                assert_eq!(listed.len(), 1, "Soft-deleted entity should be hidden");
+                assert!(listed.iter().all(|e| e.id() != gone_id));
+                assert!(service.get(&gone_id).await.unwrap().is_none());
+                assert!(
+                    service
+                        .search("email", "gone@test.com")
+                        .await
+                        .unwrap()
+                        .is_empty()
+                );
+
+                let all = service.list_with_deleted().await.unwrap();
+                assert_eq!(all.len(), 2, "list_with_deleted should return both");
+                let fetched = service
+                    .get_with_deleted(&gone_id)
+                    .await
+                    .unwrap()
+                    .expect("Soft-deleted entity should stay retrievable");
+                assert!(fetched.deleted_at.is_some());
+            }
+
+            #[tokio::test]
+            async fn test_count_matches_list() {
+                let service = $factory;
+                assert_eq!(service.count().await.unwrap(), 0);
+
+                let mut ids = Vec::new();
+                for i in 0..3 {
+                    let entity = create_test_entity(
+                        &format!("Count_{}", i),
+                        &format!("count{}@test.com", i),
+                        30 + i as i64,
+                        1.0,
+                        true,
+                    );
+                    ids.push(entity.id);
+                    service.create(entity).await.unwrap();
+                }
+                assert_eq!(service.count().await.unwrap(), 3);
+                assert_eq!(
+                    service.count().await.unwrap(),
+                    service.list().await.unwrap().len()
+                );
+
+                let mut deleted = service.get(&ids[0]).await.unwrap().unwrap();
+                deleted.deleted_at = Some(chrono::Utc::now());
+                service.update(&ids[0], deleted).await.unwrap();
+
+                assert_eq!(service.count().await.unwrap(), 2);
+                assert_eq!(
+                    service.count().await.unwrap(),
+                    service.list().await.unwrap().len(),
+                    "count() must skip soft-deleted entities like list()"
+                );
+            }
+
+            #[tokio::test]
+            async fn test_create_many_preserves_order() {
+                let service = $factory;
+                let entities: Vec<TestDataEntity> = (0..100)
+                    .map(|i| {
+                        create_test_entity(
+                            &format!("Batch_{:03}", i),
+                            &format!("batch{}@test.com", i),
+                            i,
+                            i as f64,
+                            i % 2 == 0,
+                        )
+                    })
+                    .collect();
+                let ids: Vec<Uuid> = entities.iter().map(|e| e.id).collect();
+
+                let created = service.create_many(entities).await.unwrap();
+
+                assert_eq!(service.count().await.unwrap(), 100);
+                assert_eq!(
+                    created.iter().map(|e| e.id()).collect::<Vec<_>>(),
+                    ids,
+                    "create_many must return entities in input order"
+                );
+                assert_eq!(created[42].name, "Batch_042");
+                assert_eq!(created[42].age, 42);
+                assert!(service.get(&ids[99]).await.unwrap().is_some());
+            }
+
+            #[tokio::test]
+            async fn test_cursor_walk_has_no_duplicates_or_gaps() {
+                use this::core::query::Cursor;
+
+                let service = $factory;
+                // Pairs share a timestamp so the ID tie-break is exercised
+                let base = chrono::SubsecRound::trunc_subsecs(chrono::Utc::now(), 0);
+                for i in 0..23 {
+                    let mut entity = create_test_entity(
+                        &format!("Cursor_{:02}", i),
+                        &format!("cursor{}@test.com", i),
+                        i,
+                        0.0,
+                        true,
+                    );
+                    entity.created_at = base - chrono::Duration::seconds(i / 2);
+                    service.create(entity).await.unwrap();
+                }
+
+                let mut forward = Vec::new();
+                let mut cursor = None;
+                loop {
+                    let page = service.list_after(cursor, 5).await.unwrap();
+                    assert!(page.len() <= 5);
+                    let Some(last) = page.last() else { break };
+                    cursor = Some(Cursor::of(last));
+                    forward.extend(page.iter().map(Cursor::of));
+                }
+                assert_eq!(forward.len(), 23, "forward walk must visit every entity");
+                assert!(
+                    forward.windows(2).all(|w| w[0] > w[1]),
+                    "forward walk must be strictly newest first, without duplicates"
+                );
+
+                // Walking back from the oldest entity visits the rest in reverse
+                let mut backward = Vec::new();
+                let mut cursor = *forward.last().unwrap();
+                loop {
+                    let page = service.list_before(cursor, 5).await.unwrap();
+                    let Some(first) = page.first() else { break };
+                    cursor = Cursor::of(first);
+                    backward.splice(0..0, page.iter().map(Cursor::of));
+                }
+                assert_eq!(backward, forward[..22]);
+            }
+
             // ==================================================================
             // Search — String field (email)
             // ==================================================================
@@ -275,6 +434,34 @@ macro_rules! data_service_tests {
             // Search — Integer field (age)
             // ==================================================================
 
+            // ==================================================================
+            // Search — full text
+            // ==================================================================
+
+            #[tokio::test]
+            async fn test_search_text_ranks_name_match_above_partial_email() {
+                let service = $factory;
+
+                service
+                    .create(create_test_entity("Bob", "alice.b@test.com", 30, 3.5, true))
+                    .await
+                    .unwrap();
+                service
+                    .create(create_test_entity("Alice", "al@test.com", 25, 4.0, true))
+                    .await
+                    .unwrap();
+                service
+                    .create(create_test_entity("Charlie", "charlie@test.com", 35, 5.0, false))
+                    .await
+                    .unwrap();
+
+                let results = service.search_text("alice").await.unwrap();
+                let names: Vec<&str> = results.iter().map(|e| e.name()).collect();
+                assert_eq!(names, ["Alice", "Bob"]);
+
+                assert!(service.search_text("zoe").await.unwrap().is_empty());
+            }
+
             #[tokio::test]
             async fn test_search_integer_field() {
                 let service = $factory;
@@ -408,6 +595,93 @@ macro_rules! data_service_tests {
                 );
             }
 
+            // ==================================================================
+            // List — Filter operators
+            // ==================================================================
+
+            async fn create_filter_fixtures<S: DataService<TestDataEntity>>(service: &S) {
+                for (name, age, score) in [("Ann", 17, 2.5), ("Ben", 30, 4.5), ("Cid", 65, 4.8)] {
+                    let email = format!("{}@test.com", name.to_lowercase());
+                    service
+                        .create(create_test_entity(name, &email, age, score, true))
+                        .await
+                        .unwrap();
+                }
+            }
+
+            fn clauses(filter: serde_json::Value) -> Vec<this::core::query::FilterClause> {
+                this::core::query::FilterClause::parse_all(&filter).unwrap()
+            }
+
+            fn sorted_names(entities: &[TestDataEntity]) -> Vec<String> {
+                let mut names: Vec<String> = entities.iter().map(|e| e.name().to_string()).collect();
+                names.sort();
+                names
+            }
+
+            #[tokio::test]
+            async fn test_list_where_numeric_range() {
+                let service = $factory;
+                create_filter_fixtures(&service).await;
+
+                let adults = service
+                    .list_where(&clauses(serde_json::json!({"age": {"$gt": 17, "$lte": 65}})))
+                    .await
+                    .unwrap();
+                assert_eq!(sorted_names(&adults), ["Ben", "Cid"]);
+
+                let rated = service
+                    .list_where(&clauses(serde_json::json!({"score": {"$lte": 4.5}})))
+                    .await
+                    .unwrap();
+                assert_eq!(sorted_names(&rated), ["Ann", "Ben"]);
+            }
+
+            #[tokio::test]
+            async fn test_list_where_in_over_strings() {
+                let service = $factory;
+                create_filter_fixtures(&service).await;
+
+                let found = service
+                    .list_where(&clauses(serde_json::json!({
+                        "email": {"$in": ["ann@test.com", "cid@test.com", "zed@test.com"]},
+                        "name": {"$ne": "Cid"},
+                    })))
+                    .await
+                    .unwrap();
+                assert_eq!(sorted_names(&found), ["Ann"]);
+
+                let none = service
+                    .list_where(&clauses(serde_json::json!({"email": {"$in": []}})))
+                    .await
+                    .unwrap();
+                assert!(none.is_empty());
+            }
+
+            #[tokio::test]
+            async fn test_sort_by_several_keys() {
+                use this::core::query::{SortKey, sort_entities};
+
+                let service = $factory;
+                for entity in sample_batch(6) {
+                    service.create(entity).await.unwrap();
+                }
+                let names = |entities: &[TestDataEntity]| -> Vec<String> {
+                    entities.iter().map(|e| e.name().to_string()).collect()
+                };
+                let expected = [
+                    "Entity_0", "Entity_2", "Entity_4", "Entity_1", "Entity_3", "Entity_5",
+                ];
+
+                let mut all = service.list().await.unwrap();
+                sort_entities(&mut all, &SortKey::parse_all("active:desc,age:asc"));
+                assert_eq!(names(&all), expected);
+
+                let mut all = service.list().await.unwrap();
+                sort_entities(&mut all, &SortKey::parse_all("active:desc,nope:asc,age"));
+                assert_eq!(names(&all), expected, "unknown keys are ignored");
+            }
+
             // ==================================================================
             // Edge case — Duplicate ID
             // ==================================================================
@@ -30,13 +30,15 @@ pub mod ws_tests;
 use super::{TestDataEntity, create_test_entity};
 use axum::Router;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, StatusCode, header};
 use axum::response::{IntoResponse, Json};
 use axum::routing::get;
 use serde_json::Value;
 use std::sync::Arc;
-use this::core::entity::Data;
-use this::core::query::{PaginatedResponse, PaginationMeta, QueryParams};
+use this::core::etag::{CacheResult, etag_of};
+use this::core::query::{
+    FilterClause, PaginatedResponse, PaginationMeta, QueryParams, sort_entities,
+};
 use this::core::service::DataService;
 use uuid::Uuid;
 
@@ -86,10 +88,12 @@ async fn create_handler(
 
 /// GET /test_data_entities/{id} — Get a single entity by UUID.
 ///
-/// Returns: 200 + JSON entity, or 404 if not found
+/// Honors `If-None-Match` through `DataService::get_if_modified`.
+/// Returns: 200 + JSON entity + `ETag`, 304 if unchanged, or 404 if not found
 async fn get_handler(
     State(state): State<TestApiState>,
     Path(id): Path<String>,
+    headers: HeaderMap,
 ) -> impl IntoResponse {
     let id = match Uuid::parse_str(&id) {
         Ok(id) => id,
@@ -102,12 +106,18 @@ async fn get_handler(
         }
     };
 
-    match state.data_service.get(&id).await {
-        Ok(Some(entity)) => {
+    let if_none_match = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok());
+
+    match state.data_service.get_if_modified(&id, if_none_match).await {
+        Ok(CacheResult::Modified(entity)) => {
+            let etag = etag_of(&entity);
             let json = serde_json::to_value(entity).unwrap();
-            (StatusCode::OK, Json(json)).into_response()
+            (StatusCode::OK, [(header::ETAG, etag)], Json(json)).into_response()
         }
-        Ok(None) => (
+        Ok(CacheResult::NotModified) => StatusCode::NOT_MODIFIED.into_response(),
+        Ok(CacheResult::NotFound) => (
             StatusCode::NOT_FOUND,
             Json(serde_json::json!({"error": "Not found"})),
         )
@@ -122,7 +132,9 @@ async fn get_handler(
 
 /// GET /test_data_entities — List all entities with pagination.
 ///
-/// Query params: `?page=1&limit=20&filter={"status":"active"}&sort=name:asc`
+/// Query params: `?page=1&limit=20&filter={"status":"active"}&sort=name:asc,age:desc`,
+/// plus field params such as `?filter[name]=A&filter[name]=B`. Filter values may use
+/// operators (`{"age":{"$gt":18}}`), evaluated through `DataService::list_where`.
 /// Returns: 200 + PaginatedResponse<Value>
 async fn list_handler(
     State(state): State<TestApiState>,
@@ -131,44 +143,46 @@ async fn list_handler(
     let page = params.page();
     let limit = params.limit();
 
-    match state.data_service.list().await {
-        Ok(mut entities) => {
-            // Apply filter if provided
-            if let Some(filter) = params.filter_value()
-                && let Some(obj) = filter.as_object()
-            {
-                for (key, value) in obj {
-                    entities.retain(|e| {
-                        e.field_value(key)
-                            .map(|fv| match &fv {
-                                this::core::field::FieldValue::String(s) => {
-                                    value.as_str().is_some_and(|v| s == v)
-                                }
-                                this::core::field::FieldValue::Integer(i) => {
-                                    value.as_i64().is_some_and(|v| *i == v)
-                                }
-                                this::core::field::FieldValue::Boolean(b) => {
-                                    value.as_bool().is_some_and(|v| *b == v)
-                                }
-                                _ => false,
-                            })
-                            .unwrap_or(false)
-                    });
-                }
-            }
+    // Parse filter operators (repeated params act as an IN set)
+    let filter = match params
+        .filter_value()
+        .map(|filter| FilterClause::parse_all(&filter))
+        .transpose()
+    {
+        Ok(filter) => filter,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e})),
+            )
+                .into_response();
+        }
+    };
+    let listed = match &filter {
+        Some(clauses) => state.data_service.list_where(clauses).await,
+        None => state.data_service.list().await,
+    };
 
+    match listed {
+        Ok(mut entities) => {
             // Apply sort if provided
-            if let Some(sort) = &params.sort {
-                match sort.as_str() {
-                    "name" | "name:asc" => entities.sort_by(|a, b| a.name.cmp(&b.name)),
-                    "name:desc" => entities.sort_by(|a, b| b.name.cmp(&a.name)),
-                    "age" | "age:asc" => entities.sort_by(|a, b| a.age.cmp(&b.age)),
-                    "age:desc" => entities.sort_by(|a, b| b.age.cmp(&a.age)),
-                    _ => {}
-                }
-            }
+            sort_entities(&mut entities, &params.sort_keys());
 
-            let total = entities.len();
+            // Unfiltered totals come straight from the backend count
+            let total = if filter.is_some() {
+                entities.len()
+            } else {
+                match state.data_service.count().await {
+                    Ok(total) => total,
+                    Err(e) => {
+                        return (
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            Json(serde_json::json!({"error": e.to_string()})),
+                        )
+                            .into_response();
+                    }
+                }
+            };
             let start = (page - 1) * limit;
 
             let paginated: Vec<Value> = entities
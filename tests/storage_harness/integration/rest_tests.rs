@@ -13,6 +13,7 @@
 /// ## CRUD (5 tests)
 /// - `test_rest_create` — POST 201 + correct JSON body
 /// - `test_rest_get` — GET 200 + correct entity
+/// - `test_rest_get_if_none_match` — GET with matching ETag → 304, stale → 200
 /// - `test_rest_list` — GET 200 + paginated array
 /// - `test_rest_update` — PUT 200 + updated fields
 /// - `test_rest_delete` — DELETE 204, then GET 404
@@ -20,7 +21,10 @@
 /// ## Pagination / Filter / Sort (3 tests)
 /// - `test_rest_list_pagination` — page=2&limit=2 returns correct slice
 /// - `test_rest_list_filter` — filter={"active":true} returns only active
+/// - `test_rest_list_repeated_params` — filter[name]=A&filter[name]=B acts as an IN set
+/// - `test_rest_list_filter_operators` — range and `$in` operators; unknown operator → 400
 /// - `test_rest_list_sort` — sort=name:asc returns sorted results
+/// - `test_rest_list_multi_field_sort` — sort=active:desc,age:asc orders by both keys
 ///
 /// ## Error handling (2 tests)
 /// - `test_rest_error_not_found` — GET unknown ID → 404
@@ -113,6 +117,44 @@ macro_rules! rest_integration_tests {
                 assert_eq!(body["active"], false);
             }
 
+            #[tokio::test]
+            async fn test_rest_get_if_none_match() {
+                let server = make_server().await;
+
+                let create_resp = server
+                    .post("/test_data_entities")
+                    .json(&json!({
+                        "name": "Cached",
+                        "email": "cached@test.com",
+                        "age": 40,
+                        "score": 1.0,
+                        "active": true
+                    }))
+                    .await;
+                let created: serde_json::Value = create_resp.json();
+                let id = created["id"].as_str().unwrap();
+
+                let first = server
+                    .get(&format!("/test_data_entities/{}", id))
+                    .await;
+                first.assert_status(axum::http::StatusCode::OK);
+                let etag = first.header("etag").to_str().unwrap().to_string();
+
+                let cached = server
+                    .get(&format!("/test_data_entities/{}", id))
+                    .add_header("if-none-match", etag)
+                    .await;
+                cached.assert_status(axum::http::StatusCode::NOT_MODIFIED);
+
+                let stale = server
+                    .get(&format!("/test_data_entities/{}", id))
+                    .add_header("if-none-match", "\"0\"")
+                    .await;
+                stale.assert_status(axum::http::StatusCode::OK);
+                let body: serde_json::Value = stale.json();
+                assert_eq!(body["name"], "Cached");
+            }
+
             // ==============================================================
             // CRUD — List
             // ==============================================================
@@ -297,6 +339,72 @@ macro_rules! rest_integration_tests {
                 assert_eq!(body["data"][0]["name"], "Active1");
             }
 
+            #[tokio::test]
+            async fn test_rest_list_repeated_params() {
+                let server = make_server().await;
+
+                for (name, age) in [("Ann", 20), ("Ben", 30), ("Cid", 40)] {
+                    server
+                        .post("/test_data_entities")
+                        .json(&json!({"name": name, "email": "x@t.com", "age": age, "score": 1.0, "active": true}))
+                        .await;
+                }
+
+                let resp = server
+                    .get("/test_data_entities?filter[name]=Ann&filter[name]=Cid&sort=name:asc")
+                    .await;
+                resp.assert_status(axum::http::StatusCode::OK);
+                let body: serde_json::Value = resp.json();
+                assert_eq!(body["pagination"]["total"], 2);
+                assert_eq!(body["data"][0]["name"], "Ann");
+                assert_eq!(body["data"][1]["name"], "Cid");
+
+                // Untyped params still match numeric fields
+                let resp = server.get("/test_data_entities?filter[age]=30").await;
+                let body: serde_json::Value = resp.json();
+                assert_eq!(body["pagination"]["total"], 1);
+                assert_eq!(body["data"][0]["name"], "Ben");
+            }
+
+            #[tokio::test]
+            async fn test_rest_list_filter_operators() {
+                let server = make_server().await;
+
+                for (name, age) in [("Ann", 17), ("Ben", 30), ("Cid", 70)] {
+                    server
+                        .post("/test_data_entities")
+                        .json(&json!({"name": name, "email": "x@t.com", "age": age, "score": 1.0, "active": true}))
+                        .await;
+                }
+
+                let filter = json!({"age": {"$gte": 18, "$lt": 65}}).to_string();
+                let resp = server
+                    .get("/test_data_entities")
+                    .add_query_param("filter", &filter)
+                    .await;
+                resp.assert_status(axum::http::StatusCode::OK);
+                let body: serde_json::Value = resp.json();
+                assert_eq!(body["pagination"]["total"], 1);
+                assert_eq!(body["data"][0]["name"], "Ben");
+
+                let filter = json!({"name": {"$in": ["Ann", "Cid"]}}).to_string();
+                let resp = server
+                    .get("/test_data_entities?sort=name:asc")
+                    .add_query_param("filter", &filter)
+                    .await;
+                let body: serde_json::Value = resp.json();
+                assert_eq!(body["pagination"]["total"], 2);
+                assert_eq!(body["data"][0]["name"], "Ann");
+                assert_eq!(body["data"][1]["name"], "Cid");
+
+                let filter = json!({"age": {"$between": [1, 2]}}).to_string();
+                let resp = server
+                    .get("/test_data_entities")
+                    .add_query_param("filter", &filter)
+                    .await;
+                resp.assert_status(axum::http::StatusCode::BAD_REQUEST);
+            }
+
             // ==============================================================
             // List — Sort
             // ==============================================================
@@ -333,6 +441,31 @@ macro_rules! rest_integration_tests {
                 assert_eq!(data[2]["name"], "Charlie");
             }
 
+            #[tokio::test]
+            async fn test_rest_list_multi_field_sort() {
+                let server = make_server().await;
+                for entity in sample_batch(4) {
+                    server
+                        .post("/test_data_entities")
+                        .json(&serde_json::to_value(&entity).unwrap())
+                        .await;
+                }
+
+                let resp = server
+                    .get("/test_data_entities?sort=active:desc,age:asc")
+                    .await;
+                resp.assert_status(axum::http::StatusCode::OK);
+
+                let body: serde_json::Value = resp.json();
+                let names: Vec<&str> = body["data"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|e| e["name"].as_str().unwrap())
+                    .collect();
+                assert_eq!(names, ["Entity_0", "Entity_2", "Entity_1", "Entity_3"]);
+            }
+
             // ==============================================================
             // Error — Not found
             // ==============================================================
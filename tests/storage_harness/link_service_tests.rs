@@ -529,3 +529,112 @@ macro_rules! link_service_tests {
         }
     };
 }
+
+/// Generate tests for `LinkService::create_within_limit`.
+///
+/// Only for backends that override it atomically; the trait's default
+/// implementation counts and inserts without locking and would let the
+/// concurrent test overshoot the cap.
+#[macro_export]
+macro_rules! bounded_link_tests {
+    ($factory:expr) => {
+        mod bounded_link_contract_tests {
+            use super::*;
+            use std::sync::Arc;
+            use this::core::link::LinkLimit;
+            use this::core::service::LinkService;
+            use uuid::Uuid;
+
+            #[tokio::test]
+            async fn test_create_within_limit_stops_at_cap() {
+                let service = $factory;
+                let source_id = Uuid::new_v4();
+
+                for _ in 0..2 {
+                    let link = create_test_link(source_id, Uuid::new_v4(), "member");
+                    let created = service
+                        .create_within_limit(link, LinkLimit::per_source(2))
+                        .await
+                        .unwrap();
+                    assert!(created.is_some());
+                }
+
+                let link = create_test_link(source_id, Uuid::new_v4(), "member");
+                let link_id = link.id;
+                let rejected = service
+                    .create_within_limit(link, LinkLimit::per_source(2))
+                    .await
+                    .unwrap();
+                assert!(rejected.is_none());
+                assert!(service.get(&link_id).await.unwrap().is_none());
+
+                // Other link types have their own count
+                let link = create_test_link(source_id, Uuid::new_v4(), "owner");
+                let created = service
+                    .create_within_limit(link, LinkLimit::per_source(2))
+                    .await
+                    .unwrap();
+                assert!(created.is_some());
+            }
+
+            #[tokio::test]
+            async fn test_create_within_limit_per_target() {
+                let service = $factory;
+                let target_id = Uuid::new_v4();
+
+                let first = create_test_link(Uuid::new_v4(), target_id, "assignee");
+                let second = create_test_link(Uuid::new_v4(), target_id, "assignee");
+                let limit = LinkLimit::per_target(1);
+
+                assert!(
+                    service
+                        .create_within_limit(first, limit)
+                        .await
+                        .unwrap()
+                        .is_some()
+                );
+                assert!(
+                    service
+                        .create_within_limit(second, limit)
+                        .await
+                        .unwrap()
+                        .is_none()
+                );
+            }
+
+            /// N + 1 simultaneous creates against a cap of N: exactly N succeed.
+            #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+            async fn test_create_within_limit_concurrent() {
+                const CAP: usize = 5;
+                let service = Arc::new($factory);
+                let source_id = Uuid::new_v4();
+
+                let handles: Vec<_> = (0..=CAP)
+                    .map(|_| {
+                        let service = service.clone();
+                        let link = create_test_link(source_id, Uuid::new_v4(), "member");
+                        tokio::spawn(async move {
+                            service
+                                .create_within_limit(link, LinkLimit::per_source(CAP))
+                                .await
+                        })
+                    })
+                    .collect();
+
+                let mut created = 0;
+                for handle in handles {
+                    if handle.await.unwrap().unwrap().is_some() {
+                        created += 1;
+                    }
+                }
+                assert_eq!(created, CAP);
+
+                let stored = service
+                    .find_by_source(&source_id, Some("member"), None)
+                    .await
+                    .unwrap();
+                assert_eq!(stored.len(), CAP);
+            }
+        }
+    };
+}